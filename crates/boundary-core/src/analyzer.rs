@@ -1,9 +1,9 @@
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use tree_sitter::Tree;
 
-use crate::types::{Component, Dependency};
+use crate::types::{Component, ComponentKind, Dependency, FactoryInfo, FieldAccess, ImplMethods};
 
 /// A parsed source file with its tree-sitter AST and original content.
 pub struct ParsedFile {
@@ -29,9 +29,102 @@ pub trait LanguageAnalyzer: Send + Sync {
     /// Extract dependencies (imports, type references, etc.) from a parsed file.
     fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency>;
 
+    /// Extract factory functions and their declared return types, for languages
+    /// where return types are captured.
+    fn extract_factories(&self, _parsed: &ParsedFile) -> Vec<FactoryInfo> {
+        Vec::new()
+    }
+
+    /// Extract field accesses on variables of a known type, for languages where
+    /// variable types are captured.
+    fn extract_field_accesses(&self, _parsed: &ParsedFile) -> Vec<FieldAccess> {
+        Vec::new()
+    }
+
+    /// Extract methods of types declared in another file, for languages where a
+    /// type's methods need not live with it.
+    fn extract_impl_methods(&self, _parsed: &ParsedFile) -> Vec<ImplMethods> {
+        Vec::new()
+    }
+
     /// Returns true if the given import path is a standard library import
     /// that should be excluded from architectural analysis.
     fn is_stdlib_import(&self, _import_path: &str) -> bool {
         false
     }
 }
+
+/// Something an analyzer's grammar self-test expects to extract from its snippet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extracted {
+    /// Interfaces or traits, extracted as ports.
+    Interfaces,
+    /// Methods declared on extracted ports.
+    InterfaceMethods,
+    /// Structs, classes and other non-port types.
+    Types,
+    /// Methods attached to extracted components.
+    Methods,
+    /// Import dependencies.
+    Imports,
+}
+
+impl Extracted {
+    fn label(self) -> &'static str {
+        match self {
+            Extracted::Interfaces => "interfaces",
+            Extracted::InterfaceMethods => "interface methods",
+            Extracted::Types => "types",
+            Extracted::Methods => "methods",
+            Extracted::Imports => "imports",
+        }
+    }
+}
+
+/// Run `analyzer` on a known-good `snippet` and check it extracts at least one of
+/// each `expected` item.
+///
+/// Analyzers call this from `new()`: when a tree-sitter grammar update renames the
+/// nodes a query matches, the query still compiles but silently stops matching, and
+/// components vanish without error. Failing at startup names the problem instead.
+pub fn grammar_self_test(
+    analyzer: &dyn LanguageAnalyzer,
+    grammar: &str,
+    file_name: &str,
+    snippet: &str,
+    expected: &[Extracted],
+) -> Result<()> {
+    let parsed = analyzer.parse_file(Path::new(file_name), snippet)?;
+    let components = analyzer.extract_components(&parsed);
+    let dependencies = analyzer.extract_dependencies(&parsed);
+
+    for &item in expected {
+        let count = match item {
+            Extracted::Interfaces => components
+                .iter()
+                .filter(|c| matches!(c.kind, ComponentKind::Port(_)))
+                .count(),
+            Extracted::InterfaceMethods => components
+                .iter()
+                .map(|c| match &c.kind {
+                    ComponentKind::Port(info) => info.methods.len(),
+                    _ => 0,
+                })
+                .sum(),
+            Extracted::Types => components
+                .iter()
+                .filter(|c| !matches!(c.kind, ComponentKind::Port(_)))
+                .count(),
+            Extracted::Methods => components.iter().map(|c| c.methods.len()).sum(),
+            Extracted::Imports => dependencies.len(),
+        };
+        if count == 0 {
+            bail!(
+                "{grammar} grammar produced 0 {} for a known-good snippet; \
+                 grammar version may be incompatible",
+                item.label()
+            );
+        }
+    }
+    Ok(())
+}
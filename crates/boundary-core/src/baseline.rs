@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::types::Violation;
+
+/// Default baseline file name, written in the project root.
+pub const BASELINE_FILE_NAME: &str = ".boundary-baseline.json";
+
+/// Version of the baseline file layout. Baselines from a newer version are rejected.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Known violations that `boundary check --baseline` does not fail on, so a
+/// brownfield project can gate CI on new violations only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    #[serde(default)]
+    pub violations: Vec<BaselineEntry>,
+}
+
+/// A known violation. Only `key` is matched; the rest is there for reviewers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub key: String,
+    pub rule: String,
+    pub file: String,
+    pub message: String,
+}
+
+impl Baseline {
+    /// Record `violations`, keyed relative to the project `root` so the baseline
+    /// matches whichever directory boundary is run from.
+    pub fn from_violations(violations: &[Violation], root: &Path) -> Self {
+        let mut entries: Vec<BaselineEntry> = violations
+            .iter()
+            .map(|v| {
+                let file = v
+                    .location
+                    .file
+                    .strip_prefix(root)
+                    .unwrap_or(&v.location.file);
+                BaselineEntry {
+                    key: v.fingerprint(root),
+                    rule: v.kind.rule_id().to_string(),
+                    file: file.to_string_lossy().replace('\\', "/"),
+                    message: v.message.clone(),
+                }
+            })
+            .collect();
+        entries.sort_by(|a, b| (&a.file, &a.rule, &a.key).cmp(&(&b.file, &b.rule, &b.key)));
+        Self {
+            version: BASELINE_VERSION,
+            violations: entries,
+        }
+    }
+
+    /// Read a baseline file, rejecting ones written by a newer version of boundary.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read baseline {}", path.display()))?;
+        let baseline: Self = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse baseline {}", path.display()))?;
+        if baseline.version > BASELINE_VERSION {
+            bail!(
+                "baseline {} has version {}, but this boundary supports up to \
+                 {BASELINE_VERSION}; it was written by a newer version of boundary",
+                path.display(),
+                baseline.version
+            );
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("failed to serialize baseline")?;
+        std::fs::write(path, json + "\n")
+            .with_context(|| format!("failed to write baseline {}", path.display()))
+    }
+
+    /// Remove the violations the baseline lists, returning how many were removed.
+    /// Each entry suppresses one violation, so a known violation that now occurs
+    /// more often still reports the extra occurrences.
+    pub fn suppress(&self, violations: &mut Vec<Violation>, root: &Path) -> usize {
+        let mut remaining: HashMap<&str, usize> = HashMap::new();
+        for entry in &self.violations {
+            *remaining.entry(entry.key.as_str()).or_insert(0) += 1;
+        }
+        let before = violations.len();
+        violations.retain(|v| {
+            let key = v.fingerprint(root);
+            match remaining.get_mut(key.as_str()) {
+                Some(n) if *n > 0 => {
+                    *n -= 1;
+                    false
+                }
+                _ => true,
+            }
+        });
+        before - violations.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ArchLayer, Severity, SourceLocation, ViolationKind};
+    use std::path::PathBuf;
+
+    fn violation(file: &str, line: usize, import: &str) -> Violation {
+        Violation {
+            kind: ViolationKind::LayerBoundary {
+                from_layer: ArchLayer::Domain,
+                to_layer: ArchLayer::Infrastructure,
+            },
+            severity: Severity::Error,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line,
+                column: 1,
+            },
+            message: format!("domain layer depends on infrastructure layer (import: {import})"),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_suppress_ignores_line_drift_and_run_directory() {
+        let baseline = Baseline::from_violations(
+            &[violation("./internal/domain/user.go", 4, "db")],
+            Path::new("."),
+        );
+        assert_eq!(baseline.violations[0].file, "internal/domain/user.go");
+
+        // Same violation, moved down and reported from the parent directory.
+        let mut current = vec![
+            violation("project/internal/domain/user.go", 12, "db"),
+            violation("project/internal/domain/user.go", 13, "cache"),
+        ];
+        let suppressed = baseline.suppress(&mut current, Path::new("project"));
+        assert_eq!(suppressed, 1);
+        assert_eq!(current.len(), 1);
+        assert!(current[0].message.contains("cache"));
+    }
+
+    #[test]
+    fn test_each_entry_suppresses_one_occurrence() {
+        let baseline = Baseline::from_violations(&[violation("a.go", 1, "db")], Path::new("."));
+        let mut current = vec![violation("a.go", 1, "db"), violation("a.go", 9, "db")];
+        assert_eq!(baseline.suppress(&mut current, Path::new(".")), 1);
+        assert_eq!(current.len(), 1);
+    }
+
+    #[test]
+    fn test_load_rejects_newer_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(BASELINE_FILE_NAME);
+        std::fs::write(&path, r#"{"version": 99, "violations": []}"#).unwrap();
+        let err = Baseline::load(&path).unwrap_err().to_string();
+        assert!(err.contains("newer version"), "{err}");
+
+        let baseline = Baseline::from_violations(&[violation("a.go", 1, "db")], Path::new("."));
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+    }
+}
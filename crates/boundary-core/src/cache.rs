@@ -5,28 +5,68 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
-use crate::types::{Component, Dependency};
+use crate::types::{Component, Dependency, FactoryInfo, FieldAccess, ImplMethods};
 
 /// Cache entry for a single file's analysis results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedFileResult {
+    /// SHA-256 of the file content the entry was extracted from.
     pub hash: String,
     pub components: Vec<Component>,
     pub dependencies: Vec<Dependency>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub factories: Vec<FactoryInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field_accesses: Vec<FieldAccess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub impl_methods: Vec<ImplMethods>,
 }
 
 /// Analysis cache stored in `.boundary/cache.json`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AnalysisCache {
+    /// Format version; caches written by another version are discarded on load.
+    #[serde(default)]
+    pub version: u32,
     pub files: HashMap<String, CachedFileResult>,
 }
 
+impl CachedFileResult {
+    /// An entry for `AnalysisCache::insert`, which hashes the file content.
+    pub fn new(
+        components: Vec<Component>,
+        dependencies: Vec<Dependency>,
+        factories: Vec<FactoryInfo>,
+        field_accesses: Vec<FieldAccess>,
+        impl_methods: Vec<ImplMethods>,
+    ) -> Self {
+        Self {
+            hash: String::new(),
+            components,
+            dependencies,
+            factories,
+            field_accesses,
+            impl_methods,
+        }
+    }
+}
+
 const CACHE_DIR: &str = ".boundary";
 const CACHE_FILE: &str = "cache.json";
+/// Bumped whenever cached components/dependencies change shape
+/// (v1: language-scoped component IDs, v2: value object fields, v3: method visibility,
+/// v4: interface method kinds, v5: Rust inline module paths, v6: factory functions,
+/// v7: field accesses, v8: Go embedding dependencies, v9: Java entity fields,
+/// v10: Java entity methods, v11: TypeScript factory functions, v12: Go parameter types,
+/// v13: Java method annotations, v14: TypeScript method signatures, v15: Rust impl methods,
+/// v16: field visibility, v17: TypeScript decorator kinds, v18: Java layer annotations,
+/// v19: resolved TypeScript relative imports, v20: case-sensitive Go Active Record methods).
+const CACHE_VERSION: u32 = 20;
 
 impl AnalysisCache {
     pub fn new() -> Self {
         Self {
+            version: CACHE_VERSION,
             files: HashMap::new(),
         }
     }
@@ -41,6 +81,9 @@ impl AnalysisCache {
             std::fs::read_to_string(&cache_path).context("failed to read analysis cache")?;
         let cache: Self =
             serde_json::from_str(&content).context("failed to parse analysis cache")?;
+        if cache.version != CACHE_VERSION {
+            return Ok(Self::new());
+        }
         Ok(cache)
     }
 
@@ -73,7 +116,8 @@ impl AnalysisCache {
         }
     }
 
-    /// Insert or update a file's cache entry.
+    /// Insert or update a file's cache entry, keyed to the hash of `content`
+    /// so `get` only returns it while the file is unchanged.
     pub fn insert(&mut self, rel_path: String, content: &str, result: CachedFileResult) {
         let mut entry = result;
         entry.hash = compute_hash(content);
@@ -167,6 +211,9 @@ mod tests {
                 hash: compute_hash("original content"),
                 components: vec![],
                 dependencies: vec![],
+                factories: vec![],
+                field_accesses: vec![],
+                impl_methods: vec![],
             },
         );
 
@@ -195,7 +242,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         };
 
         cache.insert(
@@ -205,6 +254,9 @@ mod tests {
                 hash: String::new(), // will be overwritten
                 components: vec![component],
                 dependencies: vec![],
+                factories: vec![],
+                field_accesses: vec![],
+                impl_methods: vec![],
             },
         );
 
@@ -225,6 +277,9 @@ mod tests {
                 hash: "h1".to_string(),
                 components: vec![],
                 dependencies: vec![],
+                factories: vec![],
+                field_accesses: vec![],
+                impl_methods: vec![],
             },
         );
         cache.files.insert(
@@ -233,6 +288,9 @@ mod tests {
                 hash: "h2".to_string(),
                 components: vec![],
                 dependencies: vec![],
+                factories: vec![],
+                field_accesses: vec![],
+                impl_methods: vec![],
             },
         );
 
@@ -252,6 +310,9 @@ mod tests {
                 hash: String::new(),
                 components: vec![],
                 dependencies: vec![],
+                factories: vec![],
+                field_accesses: vec![],
+                impl_methods: vec![],
             },
         );
 
@@ -0,0 +1,199 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::types::Violation;
+
+/// How often each source file has changed, from the commits that touched it in git.
+/// Files git has no record of (untracked, or no repository) have no commits.
+#[derive(Debug, Default)]
+pub struct FileChurn {
+    /// Canonical repository root that `commits` paths are relative to.
+    repo_root: Option<PathBuf>,
+    /// Number of commits that changed each file.
+    commits: HashMap<PathBuf, usize>,
+}
+
+impl FileChurn {
+    /// Count commits per file for every file in the repository containing `path`.
+    pub fn from_git(path: &Path) -> Self {
+        let Some(repo_root) = git_output(path, &["rev-parse", "--show-toplevel"])
+            .and_then(|out| PathBuf::from(out.trim()).canonicalize().ok())
+        else {
+            return Self::default();
+        };
+        let commits = git_output(
+            &repo_root,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "log",
+                "--format=%x00",
+                "--name-only",
+            ],
+        )
+        .map(|out| parse_commit_counts(&out))
+        .unwrap_or_default();
+        Self {
+            repo_root: Some(repo_root),
+            commits,
+        }
+    }
+
+    /// Number of commits that changed `file`; 0 when git has no record of it.
+    pub fn commits(&self, file: &Path) -> usize {
+        let Some(root) = &self.repo_root else {
+            return 0;
+        };
+        file.canonicalize()
+            .ok()
+            .and_then(|canonical| {
+                let rel = canonical.strip_prefix(root).ok()?;
+                self.commits.get(rel).copied()
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Parse `git log --format=%x00 --name-only` output into the number of commits
+/// that changed each path.
+fn parse_commit_counts(output: &str) -> HashMap<PathBuf, usize> {
+    let mut counts = HashMap::new();
+    let mut in_commit: HashSet<&str> = HashSet::new();
+    for line in output.lines() {
+        if line.starts_with('\0') {
+            in_commit.clear();
+        } else if !line.is_empty() && in_commit.insert(line) {
+            *counts.entry(PathBuf::from(line)).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// A file with violations, ranked by how often it changes.
+#[derive(Debug, Clone, Serialize)]
+pub struct Hotspot {
+    pub file: PathBuf,
+    pub commits: usize,
+    pub violations: usize,
+    /// `commits * violations`: files that are both changed often and in violation.
+    pub score: usize,
+}
+
+/// Rank every file with violations by churn times violation count, highest first.
+/// `commits` returns the number of commits that changed a file. Ties go to the file
+/// with more violations, then by path.
+pub fn rank_hotspots(
+    violations: &[Violation],
+    mut commits: impl FnMut(&Path) -> usize,
+) -> Vec<Hotspot> {
+    let mut per_file: HashMap<&Path, usize> = HashMap::new();
+    for v in violations {
+        *per_file.entry(v.location.file.as_path()).or_insert(0) += 1;
+    }
+    let mut hotspots: Vec<Hotspot> = per_file
+        .into_iter()
+        .map(|(file, violations)| {
+            let commits = commits(file);
+            Hotspot {
+                file: file.to_path_buf(),
+                commits,
+                violations,
+                score: commits * violations,
+            }
+        })
+        .collect();
+    hotspots.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(b.violations.cmp(&a.violations))
+            .then(a.file.cmp(&b.file))
+    });
+    hotspots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Severity, SourceLocation, ViolationKind};
+
+    fn violation(file: &str) -> Violation {
+        Violation {
+            kind: ViolationKind::CircularDependency {
+                cycle: vec![],
+                layers: vec![],
+                cross_layer: false,
+            },
+            severity: Severity::Error,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line: 1,
+                column: 1,
+            },
+            message: String::new(),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_high_churn_high_violation_file_ranks_first() {
+        let violations = vec![
+            violation("quiet.go"),
+            violation("quiet.go"),
+            violation("quiet.go"),
+            violation("hot.go"),
+            violation("hot.go"),
+            violation("busy.go"),
+        ];
+        let hotspots = rank_hotspots(&violations, |file| match file.to_str() {
+            Some("hot.go") => 20,
+            Some("busy.go") => 30,
+            Some("quiet.go") => 1,
+            _ => 0,
+        });
+
+        let ranked: Vec<(&str, usize, usize, usize)> = hotspots
+            .iter()
+            .map(|h| (h.file.to_str().unwrap(), h.commits, h.violations, h.score))
+            .collect();
+        assert_eq!(
+            ranked,
+            [
+                ("hot.go", 20, 2, 40),
+                ("busy.go", 30, 1, 30),
+                ("quiet.go", 1, 3, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_untracked_files_rank_by_violations() {
+        let violations = vec![violation("b.go"), violation("a.go"), violation("b.go")];
+        let hotspots = rank_hotspots(&violations, |_| 0);
+        let files: Vec<&str> = hotspots.iter().map(|h| h.file.to_str().unwrap()).collect();
+        assert_eq!(files, ["b.go", "a.go"]);
+    }
+
+    #[test]
+    fn test_parse_commit_counts_counts_each_commit_once() {
+        let log = "\u{0}\n\nsrc/a.go\nsrc/b.go\n\u{0}\n\nsrc/a.go\nsrc/a.go\n";
+        let counts = parse_commit_counts(log);
+        assert_eq!(counts[Path::new("src/a.go")], 2);
+        assert_eq!(counts[Path::new("src/b.go")], 1);
+    }
+}
@@ -0,0 +1,192 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::config::{ClassificationConfig, ClassifiedKind};
+use crate::types::{
+    AdapterConfidence, AdapterInfo, Component, ComponentKind, EntityInfo, EventInfo, PortInfo,
+    ValueObjectInfo,
+};
+
+/// A compiled `[[classification.rules]]` entry.
+struct CompiledClassificationRule {
+    name: Option<Regex>,
+    path: Option<Regex>,
+    kind: ClassifiedKind,
+}
+
+/// User-defined classification rules, compiled once per analysis run.
+#[derive(Default)]
+pub struct ComponentClassifier {
+    rules: Vec<CompiledClassificationRule>,
+}
+
+impl ComponentClassifier {
+    pub fn new(config: &ClassificationConfig) -> Result<Self> {
+        let compile = |pattern: &Option<String>, field: &str, i: usize| {
+            pattern
+                .as_deref()
+                .map(|p| {
+                    Regex::new(p).with_context(|| {
+                        format!("invalid {field} pattern in classification rule {}", i + 1)
+                    })
+                })
+                .transpose()
+        };
+        let rules = config
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                if rule.name.is_none() && rule.path.is_none() {
+                    anyhow::bail!("classification rule {} needs a name or path pattern", i + 1);
+                }
+                Ok(CompiledClassificationRule {
+                    name: compile(&rule.name, "name", i)?,
+                    path: compile(&rule.path, "path", i)?,
+                    kind: rule.kind,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Reclassify `comp` with the first rule matching its name and `rel_path`.
+    /// A component already of the rule's kind is left untouched, keeping what the
+    /// analyzer extracted for it.
+    pub fn apply(&self, comp: &mut Component, rel_path: &str) {
+        let Some(rule) = self.rules.iter().find(|rule| {
+            rule.name.as_ref().is_none_or(|re| re.is_match(&comp.name))
+                && rule.path.as_ref().is_none_or(|re| re.is_match(rel_path))
+        }) else {
+            return;
+        };
+        if kind_of(&comp.kind) == rule.kind {
+            return;
+        }
+
+        let name = comp.name.clone();
+        let fields = match &comp.kind {
+            ComponentKind::Entity(info) => info.fields.clone(),
+            ComponentKind::ValueObject(info) => info.fields.clone(),
+            ComponentKind::DomainEvent(info) => info.fields.clone(),
+            _ => Vec::new(),
+        };
+        comp.kind = match rule.kind {
+            ClassifiedKind::Port => ComponentKind::Port(PortInfo {
+                name,
+                methods: comp.methods.clone(),
+            }),
+            ClassifiedKind::Adapter => ComponentKind::Adapter(AdapterInfo {
+                name,
+                implements: Vec::new(),
+                // Medium: classified by a naming rule, no constructor proof.
+                confidence: AdapterConfidence::Medium,
+                returns_concrete: None,
+            }),
+            ClassifiedKind::Entity => ComponentKind::Entity(EntityInfo {
+                name,
+                fields,
+                methods: comp.methods.clone(),
+                is_active_record: false,
+                is_anemic_domain_model: false,
+            }),
+            ClassifiedKind::ValueObject => {
+                ComponentKind::ValueObject(ValueObjectInfo { name, fields })
+            }
+            ClassifiedKind::UseCase => ComponentKind::UseCase,
+            ClassifiedKind::Repository => ComponentKind::Repository,
+            ClassifiedKind::Service => ComponentKind::Service,
+            ClassifiedKind::DomainEvent => ComponentKind::DomainEvent(EventInfo { name, fields }),
+        };
+    }
+}
+
+fn kind_of(kind: &ComponentKind) -> ClassifiedKind {
+    match kind {
+        ComponentKind::Port(_) => ClassifiedKind::Port,
+        ComponentKind::Adapter(_) => ClassifiedKind::Adapter,
+        ComponentKind::Entity(_) => ClassifiedKind::Entity,
+        ComponentKind::ValueObject(_) => ClassifiedKind::ValueObject,
+        ComponentKind::UseCase => ClassifiedKind::UseCase,
+        ComponentKind::Repository => ClassifiedKind::Repository,
+        ComponentKind::Service => ClassifiedKind::Service,
+        ComponentKind::DomainEvent(_) => ClassifiedKind::DomainEvent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClassificationRuleConfig;
+    use crate::types::*;
+
+    fn component(name: &str) -> Component {
+        Component {
+            id: ComponentId::new("payments", name),
+            name: name.to_string(),
+            kind: ComponentKind::ValueObject(ValueObjectInfo {
+                name: name.to_string(),
+                fields: Vec::new(),
+            }),
+            layer: None,
+            location: SourceLocation::default(),
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
+        }
+    }
+
+    fn rule(
+        name: Option<&str>,
+        path: Option<&str>,
+        kind: ClassifiedKind,
+    ) -> ClassificationRuleConfig {
+        ClassificationRuleConfig {
+            name: name.map(String::from),
+            path: path.map(String::from),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let classifier = ComponentClassifier::new(&ClassificationConfig {
+            rules: vec![
+                rule(
+                    Some(".*Gateway$"),
+                    Some("^legacy/"),
+                    ClassifiedKind::Service,
+                ),
+                rule(Some(".*Gateway$"), None, ClassifiedKind::Adapter),
+                rule(Some("^Q.*Query$"), None, ClassifiedKind::UseCase),
+            ],
+        })
+        .unwrap();
+
+        let mut gateway = component("PaymentGateway");
+        classifier.apply(&mut gateway, "internal/payments/gateway.go");
+        assert!(matches!(gateway.kind, ComponentKind::Adapter(_)));
+
+        let mut legacy = component("PaymentGateway");
+        classifier.apply(&mut legacy, "legacy/gateway.go");
+        assert!(matches!(legacy.kind, ComponentKind::Service));
+
+        let mut unmatched = component("Money");
+        classifier.apply(&mut unmatched, "internal/payments/money.go");
+        assert!(matches!(unmatched.kind, ComponentKind::ValueObject(_)));
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        let bad_regex = ClassificationConfig {
+            rules: vec![rule(Some("("), None, ClassifiedKind::Adapter)],
+        };
+        assert!(ComponentClassifier::new(&bad_regex).is_err());
+
+        let no_pattern = ClassificationConfig {
+            rules: vec![rule(None, None, ClassifiedKind::Adapter)],
+        };
+        assert!(ComponentClassifier::new(&no_pattern).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use serde::Serialize;
+
+use crate::evolution::count_by_rule;
+use crate::metrics::{AnalysisResult, ArchitectureScore};
+
+/// Score differences smaller than this are reported as unchanged, so rounding noise
+/// below the one decimal place reports show is not called an improvement.
+const SCORE_TOLERANCE: f64 = 0.05;
+
+type ScoreGetter = fn(&ArchitectureScore) -> f64;
+
+/// Whether the second tree of a comparison is better or worse than the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Change {
+    Improved,
+    Regressed,
+    Unchanged,
+}
+
+/// One score dimension in both trees. A side is `None` when it has no score, or the
+/// dimension was not applicable there.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreComparison {
+    pub metric: String,
+    pub left: Option<f64>,
+    pub right: Option<f64>,
+    pub delta: Option<f64>,
+    pub change: Change,
+}
+
+/// A violation count in both trees, either the total or one rule's.
+#[derive(Debug, Clone, Serialize)]
+pub struct CountComparison {
+    pub rule: String,
+    pub left: usize,
+    pub right: usize,
+    pub delta: i64,
+    pub change: Change,
+}
+
+/// Side-by-side comparison of two analyses, `left` being the baseline.
+#[derive(Debug, Clone, Serialize)]
+pub struct Comparison {
+    pub left: String,
+    pub right: String,
+    pub scores: Vec<ScoreComparison>,
+    pub violations: CountComparison,
+    /// Per-rule counts for every rule reported in either tree, sorted by rule ID.
+    pub rules: Vec<CountComparison>,
+}
+
+/// Compare `right` against the baseline `left`. Labels name the trees in reports.
+pub fn compare_results(
+    left_label: &str,
+    left: &AnalysisResult,
+    right_label: &str,
+    right: &AnalysisResult,
+) -> Comparison {
+    let metrics: [(&str, ScoreGetter); 5] = [
+        ("overall", |s| s.overall),
+        ("structural_presence", |s| s.structural_presence),
+        ("layer_conformance", |s| s.layer_conformance),
+        ("dependency_compliance", |s| s.dependency_compliance),
+        ("interface_coverage", |s| s.interface_coverage),
+    ];
+    let value = |result: &AnalysisResult, metric: &str, get: ScoreGetter| {
+        result
+            .score
+            .as_ref()
+            .filter(|s| s.is_applicable(metric))
+            .map(get)
+    };
+    let scores = metrics
+        .into_iter()
+        .map(|(metric, get)| {
+            let (l, r) = (value(left, metric, get), value(right, metric, get));
+            let delta = l.zip(r).map(|(l, r)| r - l);
+            let change = match delta {
+                Some(d) if d > SCORE_TOLERANCE => Change::Improved,
+                Some(d) if d < -SCORE_TOLERANCE => Change::Regressed,
+                _ => Change::Unchanged,
+            };
+            ScoreComparison {
+                metric: metric.to_string(),
+                left: l,
+                right: r,
+                delta,
+                change,
+            }
+        })
+        .collect();
+
+    let left_by_rule = count_by_rule(&left.violations);
+    let right_by_rule = count_by_rule(&right.violations);
+    let mut rule_ids: Vec<&String> = left_by_rule.keys().chain(right_by_rule.keys()).collect();
+    rule_ids.sort();
+    rule_ids.dedup();
+    let rules = rule_ids
+        .into_iter()
+        .map(|rule| {
+            count_comparison(
+                rule,
+                left_by_rule.get(rule).copied().unwrap_or(0),
+                right_by_rule.get(rule).copied().unwrap_or(0),
+            )
+        })
+        .collect();
+
+    Comparison {
+        left: left_label.to_string(),
+        right: right_label.to_string(),
+        scores,
+        violations: count_comparison("total", left.violations.len(), right.violations.len()),
+        rules,
+    }
+}
+
+/// Fewer violations is an improvement.
+fn count_comparison(rule: &str, left: usize, right: usize) -> CountComparison {
+    let delta = right as i64 - left as i64;
+    CountComparison {
+        rule: rule.to_string(),
+        left,
+        right,
+        delta,
+        change: match delta {
+            d if d < 0 => Change::Improved,
+            d if d > 0 => Change::Regressed,
+            _ => Change::Unchanged,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Severity, SourceLocation, Violation, ViolationKind};
+
+    fn result(overall: f64, not_applicable: &[&str], violations: usize) -> AnalysisResult {
+        AnalysisResult {
+            score: Some(ArchitectureScore {
+                overall,
+                structural_presence: 100.0,
+                layer_conformance: overall,
+                dependency_compliance: overall,
+                interface_coverage: 0.0,
+                not_applicable: not_applicable.iter().map(|m| m.to_string()).collect(),
+            }),
+            violations: (0..violations)
+                .map(|_| Violation {
+                    kind: ViolationKind::CircularDependency {
+                        cycle: vec![],
+                        layers: vec![],
+                        cross_layer: false,
+                    },
+                    severity: Severity::Error,
+                    location: SourceLocation::default(),
+                    message: String::new(),
+                    suggestion: None,
+                    fix: None,
+                    doc_url: None,
+                })
+                .collect(),
+            component_count: 5,
+            dependency_count: 3,
+            files_analyzed: 5,
+            metrics: None,
+            package_metrics: vec![],
+            pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
+        }
+    }
+
+    #[test]
+    fn test_higher_scores_and_fewer_violations_improve() {
+        let comparison = compare_results("a", &result(60.0, &[], 2), "b", &result(75.0, &[], 1));
+
+        let overall = &comparison.scores[0];
+        assert_eq!(overall.metric, "overall");
+        assert_eq!(overall.delta, Some(15.0));
+        assert_eq!(overall.change, Change::Improved);
+        assert_eq!(comparison.violations.delta, -1);
+        assert_eq!(comparison.violations.change, Change::Improved);
+        assert_eq!(comparison.rules.len(), 1);
+        assert_eq!(comparison.rules[0].rule, "D001");
+    }
+
+    #[test]
+    fn test_rounding_noise_and_missing_metrics_are_unchanged() {
+        let comparison = compare_results(
+            "a",
+            &result(60.0, &["interface_coverage"], 1),
+            "b",
+            &result(60.01, &[], 3),
+        );
+
+        assert_eq!(comparison.scores[0].change, Change::Unchanged);
+        let coverage = comparison
+            .scores
+            .iter()
+            .find(|s| s.metric == "interface_coverage")
+            .unwrap();
+        assert_eq!(coverage.left, None);
+        assert_eq!(coverage.delta, None);
+        assert_eq!(coverage.change, Change::Unchanged);
+        assert_eq!(comparison.violations.change, Change::Regressed);
+    }
+}
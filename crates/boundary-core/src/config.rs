@@ -1,9 +1,9 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
-use crate::types::{ArchitectureMode, Severity, ViolationKind};
+use crate::types::{ArchLayer, ArchitectureMode, Severity, ViolationKind};
 
 /// Top-level configuration from `.boundary.toml`
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -16,6 +16,18 @@ pub struct Config {
     pub scoring: ScoringConfig,
     #[serde(default)]
     pub rules: RulesConfig,
+    #[serde(default)]
+    pub classification: ClassificationConfig,
+    #[serde(default)]
+    pub zones: Vec<ZoneConfig>,
+    /// Estimated remediation hours per violation, keyed like `[rules.severities]`
+    /// (rule ID or category name). Empty = no debt estimate.
+    #[serde(default)]
+    pub debt: HashMap<String, f64>,
+    /// Fitness functions: named assertions over analysis metrics that fail
+    /// `boundary check` when false, e.g. `"layer_conformance >= 90"`.
+    #[serde(default)]
+    pub fitness: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +38,120 @@ pub struct ProjectConfig {
     pub exclude_patterns: Vec<String>,
     #[serde(default)]
     pub services_pattern: Option<String>,
+    /// File that marks a service directory for `--per-service`, e.g. `go.mod`.
+    /// Takes precedence over `services_pattern` and finds services at any depth.
+    #[serde(default)]
+    pub service_marker: Option<String>,
+    /// Worker threads for parsing and extraction; one per CPU when unset.
+    #[serde(default)]
+    pub threads: Option<usize>,
+    /// Stream parsed files to the graph merge through a channel holding at most
+    /// this many results, freeing each file's source as it arrives. When unset,
+    /// every file is parsed before any is merged.
+    #[serde(default)]
+    pub max_in_flight_files: Option<usize>,
+    /// Globs for generated or vendored code that is analyzed but treated as external:
+    /// its components are marked `is_external` and left out of scoring.
+    #[serde(default)]
+    pub treat_as_external: Vec<String>,
+    /// Architecture style whose rule preset fills in `[rules]` keys the file leaves unset.
+    #[serde(default)]
+    pub style: Option<ArchitectureStyle>,
+}
+
+/// Overall architecture style, selecting which rules run and at what severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchitectureStyle {
+    /// Ports and adapters: every port/adapter rule is enabled.
+    Hexagonal,
+    /// Clean Architecture: ports, plus strict rings and no persistence in the domain.
+    Clean,
+    /// Classic n-tier layering: strict layer order, no ports expected.
+    Layered,
+    /// Model-view-controller: models own their persistence, no ports expected.
+    Mvc,
+}
+
+impl ArchitectureStyle {
+    /// `[rules]` flags this style sets.
+    fn rule_flags(self) -> &'static [(&'static str, bool)] {
+        match self {
+            ArchitectureStyle::Hexagonal => &[
+                ("detect_missing_ports", true),
+                ("detect_context_missing_ports", true),
+                ("detect_factory_concrete_returns", true),
+                ("detect_adapter_coupling", true),
+                ("detect_port_adapter_cycles", true),
+                ("detect_infra_port_parameters", true),
+            ],
+            ArchitectureStyle::Clean => &[
+                ("detect_missing_ports", true),
+                ("detect_layer_skips", true),
+                ("detect_infra_port_parameters", true),
+                ("detect_infra_field_types", true),
+                ("detect_persistence_annotations", true),
+            ],
+            ArchitectureStyle::Layered => &[
+                ("detect_missing_ports", false),
+                ("detect_layer_skips", true),
+            ],
+            ArchitectureStyle::Mvc => &[("detect_missing_ports", false)],
+        }
+    }
+
+    /// `[rules.severities]` entries this style sets.
+    fn severities(self) -> &'static [(&'static str, Severity)] {
+        match self {
+            ArchitectureStyle::Hexagonal | ArchitectureStyle::Clean => &[],
+            ArchitectureStyle::Layered => &[("constructor_concrete", Severity::Info)],
+            ArchitectureStyle::Mvc => &[
+                ("constructor_concrete", Severity::Info),
+                ("domain_infra_leak", Severity::Warning),
+            ],
+        }
+    }
+
+    /// Add this style's preset to the raw `[rules]` table of a config file, keeping
+    /// every key the file sets itself.
+    fn apply_preset(self, raw: &mut toml::Table) {
+        let rules = raw
+            .entry("rules")
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        let Some(rules) = rules.as_table_mut() else {
+            return;
+        };
+        for (key, value) in self.rule_flags() {
+            rules
+                .entry(key.to_string())
+                .or_insert(toml::Value::Boolean(*value));
+        }
+        if self.severities().is_empty() {
+            return;
+        }
+        // A `[rules.severities]` table replaces the defaults wholesale, so without one
+        // the preset starts from the defaults; with one, its entries win.
+        let explicit = rules.contains_key("severities");
+        let severities = rules.entry("severities").or_insert_with(|| {
+            toml::Value::Table(
+                default_severities()
+                    .into_iter()
+                    .map(|(k, v)| (k, toml::Value::String(v.to_string())))
+                    .collect(),
+            )
+        });
+        let Some(severities) = severities.as_table_mut() else {
+            return;
+        };
+        for (key, severity) in self.severities() {
+            let value = toml::Value::String(severity.to_string());
+            if explicit {
+                severities.entry(key.to_string()).or_insert(value);
+            } else {
+                severities.insert(key.to_string(), value);
+            }
+        }
+    }
 }
 
 fn default_languages() -> Vec<String> {
@@ -42,6 +168,11 @@ impl Default for ProjectConfig {
                 "**/testdata/**".to_string(),
             ],
             services_pattern: None,
+            service_marker: None,
+            threads: None,
+            max_in_flight_files: None,
+            treat_as_external: Vec::new(),
+            style: None,
         }
     }
 }
@@ -129,6 +260,19 @@ impl Default for LayersConfig {
     }
 }
 
+/// A named part of the project scored on its own, from `[[zones]]`.
+/// Omitted `layers` and `scoring` tables fall back to the top-level ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneConfig {
+    pub name: String,
+    /// Directory of the zone, relative to the analyzed path.
+    pub path: String,
+    #[serde(default)]
+    pub layers: Option<LayersConfig>,
+    #[serde(default)]
+    pub scoring: Option<ScoringConfig>,
+}
+
 /// Weights for scoring sub-components (should sum to ~1.0)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoringConfig {
@@ -138,6 +282,22 @@ pub struct ScoringConfig {
     pub dependency_compliance_weight: f64,
     #[serde(default = "default_interface_weight")]
     pub interface_coverage_weight: f64,
+    /// How sub-metrics with nothing to measure (no edges, no adapters, ...) are scored.
+    #[serde(default)]
+    pub empty_metric_policy: EmptyMetricPolicy,
+}
+
+/// Score given to a sub-metric that has no signal to measure.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmptyMetricPolicy {
+    /// Score it 100.
+    #[default]
+    Perfect,
+    /// Score it 50.
+    Neutral,
+    /// Leave it out of the overall score and report it as not applicable.
+    Na,
 }
 
 fn default_layer_weight() -> f64 {
@@ -156,10 +316,46 @@ impl Default for ScoringConfig {
             layer_conformance_weight: default_layer_weight(),
             dependency_compliance_weight: default_dep_weight(),
             interface_coverage_weight: default_interface_weight(),
+            empty_metric_policy: EmptyMetricPolicy::default(),
         }
     }
 }
 
+/// User-defined component classification from `[[classification.rules]]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClassificationConfig {
+    #[serde(default)]
+    pub rules: Vec<ClassificationRuleConfig>,
+}
+
+/// Reclassify components whose name and/or file path match a regex.
+/// Rules are tried in order and the first match wins; a rule with both patterns
+/// needs both to match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassificationRuleConfig {
+    /// Regex matched against the component name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Regex matched against the file path, relative to the project root.
+    #[serde(default)]
+    pub path: Option<String>,
+    pub kind: ClassifiedKind,
+}
+
+/// Component kinds a classification rule can assign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClassifiedKind {
+    Port,
+    Adapter,
+    Entity,
+    ValueObject,
+    UseCase,
+    Repository,
+    Service,
+    DomainEvent,
+}
+
 /// A custom rule defined in configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomRuleConfig {
@@ -189,6 +385,83 @@ pub struct IgnoreRuleConfig {
     pub paths: Vec<String>,
 }
 
+/// Path-scoped severity overrides from `[[rules.overrides]]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleOverrideConfig {
+    pub scope: String,
+    #[serde(default)]
+    pub severities: HashMap<String, Severity>,
+}
+
+/// PA001 settings from `[rules.missing_port]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MissingPortConfig {
+    /// Globs over adapter names or file paths exempt from PA001, e.g. `*Publisher`
+    /// for event publishers that legitimately have no port.
+    #[serde(default)]
+    pub exempt_patterns: Vec<String>,
+}
+
+/// Per-layer restrictions on external imports from `[rules.external_policy]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalPolicyConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<ExternalPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application: Option<ExternalPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub infrastructure: Option<ExternalPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub presentation: Option<ExternalPolicy>,
+}
+
+impl ExternalPolicyConfig {
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_none()
+            && self.application.is_none()
+            && self.infrastructure.is_none()
+            && self.presentation.is_none()
+    }
+
+    pub fn for_layer(&self, layer: ArchLayer) -> Option<&ExternalPolicy> {
+        match layer {
+            ArchLayer::Domain => self.domain.as_ref(),
+            ArchLayer::Application => self.application.as_ref(),
+            ArchLayer::Infrastructure => self.infrastructure.as_ref(),
+            ArchLayer::Presentation => self.presentation.as_ref(),
+        }
+    }
+}
+
+/// External import prefixes a layer may or may not use.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExternalPolicy {
+    /// When set, only imports starting with one of these prefixes are allowed;
+    /// an empty list forbids every external import.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow: Option<Vec<String>>,
+    /// Imports starting with one of these prefixes are forbidden, even if allowed.
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl ExternalPolicy {
+    /// Whether this policy lets a layer import `import_path`.
+    pub fn permits(&self, import_path: &str) -> bool {
+        if self
+            .deny
+            .iter()
+            .any(|p| import_path.starts_with(p.as_str()))
+        {
+            return false;
+        }
+        match &self.allow {
+            Some(allow) => allow.iter().any(|p| import_path.starts_with(p.as_str())),
+            None => true,
+        }
+    }
+}
+
 /// Rule configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RulesConfig {
@@ -198,27 +471,209 @@ pub struct RulesConfig {
     pub fail_on: Severity,
     #[serde(default)]
     pub min_score: Option<f64>,
+    /// D006: flag components that depend on more than this many others. Unset
+    /// disables the check.
+    #[serde(default)]
+    pub max_efferent_coupling: Option<usize>,
+    /// L010: minimum classification coverage percentage. Unset disables the check.
+    #[serde(default)]
+    pub min_classification_coverage: Option<f64>,
+    /// PA013: minimum interface coverage sub-score. Unset disables the check.
+    #[serde(default)]
+    pub min_interface_coverage: Option<f64>,
+    /// Page that violation documentation links point into, with the lowercase rule
+    /// ID appended as the anchor. Defaults to the rule catalog on the Boundary docs site.
+    #[serde(default)]
+    pub doc_base_url: Option<String>,
+    /// Let violations in generated files (`DO NOT EDIT`, `@generated`) fail `check`.
+    /// By default they are reported at info severity.
+    #[serde(default)]
+    pub fail_on_generated: bool,
     #[serde(default)]
     pub custom_rules: Vec<CustomRuleConfig>,
     #[serde(default = "default_true")]
     pub detect_init_functions: bool,
+    /// PA001: flag adapters without a matching port. On by default.
+    #[serde(default = "default_true")]
+    pub detect_missing_ports: bool,
+    #[serde(default)]
+    pub missing_port: MissingPortConfig,
+    /// Opt-in PA004: flag repositories carrying business logic.
+    #[serde(default)]
+    pub detect_fat_repositories: bool,
+    #[serde(default = "default_max_repository_methods")]
+    pub max_repository_methods: usize,
+    /// Opt-in PA005: flag ports declared more than once across packages.
+    #[serde(default)]
+    pub detect_duplicate_ports: bool,
+    /// Opt-in PA006: flag ports with more methods than `max_interface_methods`.
+    #[serde(default)]
+    pub detect_fat_interfaces: bool,
+    #[serde(default = "default_max_interface_methods")]
+    pub max_interface_methods: usize,
+    /// Opt-in PA010: flag ports with more than `max_port_default_methods` methods
+    /// that carry a body (Java `default` and `static` methods).
+    #[serde(default)]
+    pub detect_behavior_heavy_ports: bool,
+    #[serde(default = "default_max_port_default_methods")]
+    pub max_port_default_methods: usize,
+    /// Opt-in PA011: flag bounded contexts that have adapters or repositories but
+    /// no port.
+    #[serde(default)]
+    pub detect_context_missing_ports: bool,
+    /// Opt-in PA012: flag factory functions returning a concrete adapter where a
+    /// matching port exists.
+    #[serde(default)]
+    pub detect_factory_concrete_returns: bool,
+    /// Opt-in L006: flag infrastructure packages that domain depends on more than
+    /// they depend on domain.
+    #[serde(default)]
+    pub detect_module_direction: bool,
+    /// Opt-in L007: flag application services that only forward to one repository.
+    #[serde(default)]
+    pub detect_pass_through_services: bool,
+    /// Opt-in L005 check: flag domain port methods taking infrastructure-typed
+    /// parameters (e.g. `*sql.Tx`).
+    #[serde(default)]
+    pub detect_infra_port_parameters: bool,
+    /// Opt-in L005 check: flag domain entity and value-object fields typed as
+    /// infrastructure types (e.g. `*sql.DB`).
+    #[serde(default)]
+    pub detect_infra_field_types: bool,
+    /// Opt-in L005 check: flag domain methods carrying persistence annotations
+    /// (e.g. `@Transactional`, `@Query`).
+    #[serde(default)]
+    pub detect_persistence_annotations: bool,
+    /// Opt-in L008: flag bounded contexts whose components use more than one
+    /// architecture mode.
+    #[serde(default)]
+    pub detect_mixed_architecture_modes: bool,
+    /// Opt-in L009: flag dependencies that jump over one or more layers.
+    #[serde(default)]
+    pub detect_layer_skips: bool,
+    /// Opt-in D003: flag dependency cycles between packages of the same layer.
+    #[serde(default)]
+    pub detect_intra_layer_cycles: bool,
+    /// Opt-in D001 at package level: flag dependency cycles between packages of
+    /// different layers.
+    #[serde(default)]
+    pub detect_cross_layer_cycles: bool,
+    /// Opt-in D004: flag presentation components that depend on more than
+    /// `max_presentation_fan_out` other presentation components.
+    #[serde(default)]
+    pub detect_high_coupling: bool,
+    #[serde(default = "default_max_presentation_fan_out")]
+    pub max_presentation_fan_out: usize,
+    /// Opt-in PA008: flag infrastructure adapters that depend directly on other
+    /// infrastructure adapters instead of on a port.
+    #[serde(default)]
+    pub detect_adapter_coupling: bool,
+    /// Opt-in PA009: flag ports whose package depends on the package of an adapter
+    /// implementing them.
+    #[serde(default)]
+    pub detect_port_adapter_cycles: bool,
+    /// Opt-in L011: flag production code depending on mocks, fakes and stubs.
+    #[serde(default)]
+    pub detect_test_doubles: bool,
+    /// Component name globs identifying test doubles for L011.
+    #[serde(default = "default_test_double_patterns")]
+    pub test_double_patterns: Vec<String>,
+    /// Opt-in D007: flag code reading domain entity fields from another package.
+    #[serde(default)]
+    pub detect_cross_aggregate_field_access: bool,
+    /// Opt-in L012: flag domain entities with fields but no methods.
+    #[serde(default)]
+    pub detect_anemic_model: bool,
+    /// Keywords marking an import path or type name as database or driver code for
+    /// L005 and PA007, matched case-insensitively. Replaces the defaults, unless it
+    /// holds no non-empty keyword. Read through [`RulesConfig::infra_keywords`].
+    #[serde(default = "default_infra_keywords")]
+    pub infra_keywords: Vec<String>,
+    /// D002: external imports each layer may use.
+    #[serde(default)]
+    pub external_policy: ExternalPolicyConfig,
     #[serde(default)]
     pub ignore: Vec<IgnoreRuleConfig>,
+    #[serde(default)]
+    pub overrides: Vec<RuleOverrideConfig>,
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_max_repository_methods() -> usize {
+    10
+}
+
+fn default_max_interface_methods() -> usize {
+    7
+}
+
+fn default_max_port_default_methods() -> usize {
+    1
+}
+
+fn default_test_double_patterns() -> Vec<String> {
+    ["Mock[A-Z]*", "Fake[A-Z]*", "Stub[A-Z]*", "*Mock", "*Fake"]
+        .map(String::from)
+        .to_vec()
+}
+
+fn default_infra_keywords() -> Vec<String> {
+    [
+        "postgres",
+        "mysql",
+        "redis",
+        "mongo",
+        "database",
+        "sql",
+        "db",
+        "dynamodb",
+        "sqlite",
+        "cassandra",
+        "elasticsearch",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+fn default_max_presentation_fan_out() -> usize {
+    10
+}
+
 fn default_severities() -> HashMap<String, Severity> {
     let mut m = HashMap::new();
     m.insert("layer_boundary".to_string(), Severity::Error);
     m.insert("circular_dependency".to_string(), Severity::Error);
+    m.insert("external_dependency".to_string(), Severity::Error);
+    m.insert("intra_layer_cycle".to_string(), Severity::Warning);
+    m.insert("high_coupling".to_string(), Severity::Info);
+    m.insert("architecture_drift".to_string(), Severity::Error);
+    m.insert("excessive_fan_out".to_string(), Severity::Warning);
     m.insert("missing_port".to_string(), Severity::Warning);
     m.insert("init_coupling".to_string(), Severity::Warning);
     m.insert("domain_infra_leak".to_string(), Severity::Error);
     m.insert("constructor_concrete".to_string(), Severity::Warning);
     m.insert("missing_implementation".to_string(), Severity::Info);
+    m.insert("fat_repository".to_string(), Severity::Warning);
+    m.insert("duplicate_port".to_string(), Severity::Warning);
+    m.insert("fat_interface".to_string(), Severity::Warning);
+    m.insert("behavior_heavy_port".to_string(), Severity::Warning);
+    m.insert("context_missing_port".to_string(), Severity::Warning);
+    m.insert("factory_concrete".to_string(), Severity::Warning);
+    m.insert("adapter_misplaced".to_string(), Severity::Warning);
+    m.insert("adapter_coupling".to_string(), Severity::Warning);
+    m.insert("port_adapter_cycle".to_string(), Severity::Warning);
+    m.insert("module_direction".to_string(), Severity::Warning);
+    m.insert("pass_through_service".to_string(), Severity::Info);
+    m.insert("mixed_architecture_modes".to_string(), Severity::Info);
+    m.insert("layer_skip".to_string(), Severity::Warning);
+    m.insert("classification_coverage".to_string(), Severity::Error);
+    m.insert("interface_coverage".to_string(), Severity::Error);
+    m.insert("test_double".to_string(), Severity::Warning);
+    m.insert("cross_aggregate_access".to_string(), Severity::Warning);
+    m.insert("anemic_model".to_string(), Severity::Warning);
     m
 }
 
@@ -232,9 +687,45 @@ impl Default for RulesConfig {
             severities: default_severities(),
             fail_on: default_fail_on(),
             min_score: None,
+            max_efferent_coupling: None,
+            min_classification_coverage: None,
+            min_interface_coverage: None,
+            doc_base_url: None,
+            fail_on_generated: false,
             custom_rules: Vec::new(),
             detect_init_functions: true,
+            detect_missing_ports: true,
+            missing_port: MissingPortConfig::default(),
+            detect_fat_repositories: false,
+            max_repository_methods: default_max_repository_methods(),
+            detect_duplicate_ports: false,
+            detect_fat_interfaces: false,
+            max_interface_methods: default_max_interface_methods(),
+            detect_behavior_heavy_ports: false,
+            max_port_default_methods: default_max_port_default_methods(),
+            detect_context_missing_ports: false,
+            detect_factory_concrete_returns: false,
+            detect_module_direction: false,
+            detect_pass_through_services: false,
+            detect_infra_port_parameters: false,
+            detect_infra_field_types: false,
+            detect_persistence_annotations: false,
+            detect_mixed_architecture_modes: false,
+            detect_layer_skips: false,
+            detect_intra_layer_cycles: false,
+            detect_cross_layer_cycles: false,
+            detect_high_coupling: false,
+            max_presentation_fan_out: default_max_presentation_fan_out(),
+            detect_adapter_coupling: false,
+            detect_port_adapter_cycles: false,
+            detect_test_doubles: false,
+            test_double_patterns: default_test_double_patterns(),
+            detect_cross_aggregate_field_access: false,
+            detect_anemic_model: false,
+            infra_keywords: default_infra_keywords(),
+            external_policy: ExternalPolicyConfig::default(),
             ignore: Vec::new(),
+            overrides: Vec::new(),
         }
     }
 }
@@ -243,63 +734,152 @@ impl RulesConfig {
     /// Resolve severity for a violation kind.
     /// Precedence: rule ID (e.g. "L001") > category name (e.g. "layer_boundary") > default.
     pub fn resolve_severity(&self, kind: &ViolationKind, default: Severity) -> Severity {
-        let rule_id = kind.rule_id().to_string();
-        if let Some(&sev) = self.severities.get(&rule_id) {
-            return sev;
+        configured_severity(&self.severities, kind).unwrap_or(default)
+    }
+
+    /// The configured `infra_keywords`, lowercased for matching. An empty list, or one
+    /// of empty strings only, falls back to the defaults rather than turning L005 and
+    /// PA007 off.
+    pub fn infra_keywords(&self) -> Vec<String> {
+        let keywords: Vec<String> = self
+            .infra_keywords
+            .iter()
+            .filter(|kw| !kw.is_empty())
+            .map(|kw| kw.to_lowercase())
+            .collect();
+        if keywords.is_empty() {
+            default_infra_keywords()
+        } else {
+            keywords
         }
-        let category = match kind {
-            ViolationKind::LayerBoundary { .. } => "layer_boundary",
-            ViolationKind::CircularDependency { .. } => "circular_dependency",
-            ViolationKind::MissingPort { .. } => "missing_port",
-            ViolationKind::InitFunctionCoupling { .. } => "init_coupling",
-            ViolationKind::DomainInfrastructureLeak { .. } => "domain_infra_leak",
-            ViolationKind::ConstructorReturnsConcrete { .. } => "constructor_concrete",
-            ViolationKind::PortWithoutImplementation { .. } => "missing_implementation",
-            ViolationKind::CustomRule { .. } => return default,
-        };
-        self.severities.get(category).copied().unwrap_or(default)
+    }
+
+    /// Whether an import path contains one of the `infra_keywords`. Such imports are kept
+    /// even when they come from the standard library (e.g. Go's `database/sql`), so L005
+    /// can see direct database access.
+    pub fn is_infra_import(&self, import_path: &str) -> bool {
+        let path_lower = import_path.to_lowercase();
+        self.infra_keywords()
+            .iter()
+            .any(|kw| path_lower.contains(kw.as_str()))
     }
 }
 
+/// Look up a violation kind in a severity map by rule ID, then by category name.
+pub(crate) fn configured_severity(
+    severities: &HashMap<String, Severity>,
+    kind: &ViolationKind,
+) -> Option<Severity> {
+    lookup_by_kind(severities, kind)
+}
+
+/// Look up a violation kind in a map keyed by rule ID (e.g. "L001") or, failing
+/// that, by category name (e.g. "layer_boundary").
+pub(crate) fn lookup_by_kind<T: Copy>(map: &HashMap<String, T>, kind: &ViolationKind) -> Option<T> {
+    if let Some(&value) = map.get(kind.rule_id().as_str()) {
+        return Some(value);
+    }
+    let category = match kind {
+        ViolationKind::LayerBoundary { .. } => "layer_boundary",
+        ViolationKind::CircularDependency { .. } => "circular_dependency",
+        ViolationKind::DisallowedExternalDependency { .. } => "external_dependency",
+        ViolationKind::IntraLayerCycle { .. } => "intra_layer_cycle",
+        ViolationKind::HighCoupling { .. } => "high_coupling",
+        ViolationKind::ArchitectureDrift { .. } => "architecture_drift",
+        ViolationKind::ExcessiveFanOut { .. } => "excessive_fan_out",
+        ViolationKind::MissingPort { .. } => "missing_port",
+        ViolationKind::InitFunctionCoupling { .. } => "init_coupling",
+        ViolationKind::DomainInfrastructureLeak { .. } => "domain_infra_leak",
+        ViolationKind::ConstructorReturnsConcrete { .. } => "constructor_concrete",
+        ViolationKind::PortWithoutImplementation { .. } => "missing_implementation",
+        ViolationKind::FatRepository { .. } => "fat_repository",
+        ViolationKind::DuplicatePort { .. } => "duplicate_port",
+        ViolationKind::FatInterface { .. } => "fat_interface",
+        ViolationKind::BehaviorHeavyPort { .. } => "behavior_heavy_port",
+        ViolationKind::ContextMissingPort { .. } => "context_missing_port",
+        ViolationKind::FactoryReturnsConcrete { .. } => "factory_concrete",
+        ViolationKind::AdapterMisplaced { .. } => "adapter_misplaced",
+        ViolationKind::AdapterToAdapterCoupling { .. } => "adapter_coupling",
+        ViolationKind::PortAdapterCycle { .. } => "port_adapter_cycle",
+        ViolationKind::ModuleDirectionViolation { .. } => "module_direction",
+        ViolationKind::PassThroughService { .. } => "pass_through_service",
+        ViolationKind::MixedArchitectureModes { .. } => "mixed_architecture_modes",
+        ViolationKind::LayerSkip { .. } => "layer_skip",
+        ViolationKind::LowClassificationCoverage { .. } => "classification_coverage",
+        ViolationKind::LowInterfaceCoverage { .. } => "interface_coverage",
+        ViolationKind::TestDoubleInProduction { .. } => "test_double",
+        ViolationKind::CrossAggregateFieldAccess { .. } => "cross_aggregate_access",
+        ViolationKind::AnemicDomainModel { .. } => "anemic_model",
+        ViolationKind::CustomRule { .. } => return None,
+    };
+    map.get(category).copied()
+}
+
 impl Config {
     /// Load configuration from a `.boundary.toml` file.
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)
             .with_context(|| format!("failed to read config file '{}'", path.display()))?;
-        let config: Config = toml::from_str(&content).with_context(|| {
+        Self::parse(&content).with_context(|| {
             format!(
                 "failed to parse '{}'. Run `boundary init` to create a valid config file",
                 path.display()
             )
-        })?;
-        Ok(config)
+        })
+    }
+
+    /// Parse `.boundary.toml` content, resolving `[project].style` into the rule
+    /// defaults the file does not set explicitly.
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: Config = toml::from_str(content)?;
+        let Some(style) = config.project.style else {
+            return Ok(config);
+        };
+        let mut raw: toml::Table = toml::from_str(content)?;
+        style.apply_preset(&mut raw);
+        Ok(raw.try_into()?)
     }
 
     /// Load from `.boundary.toml` in the given directory or any ancestor, or return defaults.
     pub fn load_or_default(dir: &Path) -> Self {
+        let Some(config_path) = Self::find(dir) else {
+            return Self::default();
+        };
+        match Self::load(&config_path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!(
+                    "Warning: failed to load config from '{}': {e:#}. Using defaults.",
+                    config_path.display()
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Path of the `.boundary.toml` in the given directory or its nearest ancestor
+    /// that has one, the file `load_or_default` reads.
+    pub fn find(dir: &Path) -> Option<PathBuf> {
         // Walk up from dir to find .boundary.toml (similar to how git finds .git)
         let start = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
-        let mut current = start.as_path();
-        loop {
-            let config_path = current.join(".boundary.toml");
-            if config_path.exists() {
-                return match Self::load(&config_path) {
-                    Ok(config) => config,
-                    Err(e) => {
-                        eprintln!(
-                            "Warning: failed to load config from '{}': {e:#}. Using defaults.",
-                            config_path.display()
-                        );
-                        Self::default()
-                    }
-                };
-            }
-            match current.parent() {
-                Some(parent) => current = parent,
-                None => break,
-            }
+        start
+            .ancestors()
+            .map(|current| current.join(".boundary.toml"))
+            .find(|config_path| config_path.exists())
+    }
+
+    /// Configuration a zone is analyzed with: this one, with the zone's own layer
+    /// patterns and scoring in place of the top-level ones.
+    pub fn for_zone(&self, zone: &ZoneConfig) -> Config {
+        let mut config = self.clone();
+        if let Some(layers) = &zone.layers {
+            config.layers = layers.clone();
+        }
+        if let Some(scoring) = &zone.scoring {
+            config.scoring = scoring.clone();
         }
-        Self::default()
+        config.zones = Vec::new();
+        config
     }
 
     /// Generate default TOML content for `boundary init`.
@@ -357,6 +937,243 @@ domain_infra_leak = "error"
 # [[rules.ignore]]
 # rule = "PA001"
 # paths = ["infrastructure/**/*document.go"]
+"#
+        .to_string()
+    }
+
+    /// Complete default configuration, with every available key and a comment on each.
+    ///
+    /// Keys with defaults are set to them; optional keys and repeated tables are shown
+    /// commented out. Parsing this yields [`Config::default`].
+    pub fn reference_toml() -> String {
+        r#"# Boundary - complete configuration reference
+# Every key is listed with its default value. Optional keys are commented out.
+# See https://github.com/rebelopsio/boundary for documentation
+
+[project]
+# Languages to analyze: "go", "rust", "typescript", "java", "cpp", and "openapi" or "zig"
+# when built with those features. Empty = auto-detect (OpenAPI is never auto-detected).
+languages = []
+# Glob patterns for files to skip
+exclude_patterns = ["vendor/**", "**/*_test.go", "**/testdata/**"]
+# Glob for service directories, used by --per-service in monorepos
+# services_pattern = "services/*"
+# Or discover services as the directories containing a marker file, at any depth
+# service_marker = "go.mod"
+# Parsing threads (default: one per CPU) and, for memory-constrained CI, the number of
+# parsed files held before merging; set it to stream results instead of collecting them
+# threads = 4
+# max_in_flight_files = 256
+# Globs for generated or vendored code to treat as external: still analyzed, so
+# dependencies on it show up, but marked external and left out of scoring
+# treat_as_external = ["**/generated/**", "**/*.pb.go"]
+# Architecture style presetting which rules run: "hexagonal", "clean", "layered" or "mvc".
+# Keys set in [rules] and [rules.severities] take precedence over the preset.
+# style = "hexagonal"
+
+[layers]
+# Glob patterns to classify files into architectural layers
+domain = ["**/domain/**", "**/entity/**", "**/model/**"]
+application = ["**/application/**", "**/usecase/**", "**/service/**"]
+infrastructure = ["**/infrastructure/**", "**/adapter/**", "**/repository/**", "**/persistence/**"]
+presentation = ["**/presentation/**", "**/handler/**", "**/api/**", "**/cmd/**"]
+# Paths exempt from layer violation checks (cross-cutting concerns)
+cross_cutting = []
+# Global architecture mode: "ddd", "active-record" or "service-oriented"
+architecture_mode = "ddd"
+
+# Per-module overrides, matched by scope; first match wins.
+# Omitted layers fall back to the global patterns above.
+# [[layers.overrides]]
+# scope = "services/auth/**"
+# domain = ["services/auth/core/**"]
+# application = ["services/auth/app/**"]
+# infrastructure = ["services/auth/server/**"]
+# presentation = ["services/auth/http/**"]
+# architecture_mode = "active-record"
+
+# Custom component classification, applied after the analyzer's own heuristics.
+# Each rule matches a `name` and/or `path` regex; the first matching rule wins.
+# kind: "port", "adapter", "entity", "value_object", "use_case", "repository",
+# "service" or "domain_event"
+[classification]
+# [[classification.rules]]
+# name = ".*Gateway$"
+# kind = "adapter"
+
+[scoring]
+# Weights for score components (should sum to 1.0)
+layer_conformance_weight = 0.4
+dependency_compliance_weight = 0.4
+interface_coverage_weight = 0.2
+# Score for sub-metrics with nothing to measure: "perfect" (100), "neutral" (50)
+# or "na" (left out of the overall score)
+empty_metric_policy = "perfect"
+
+[rules]
+# Minimum severity that makes `boundary check` fail: "error", "warning" or "info"
+fail_on = "error"
+# Minimum overall architecture score
+# min_score = 70.0
+# Maximum number of components and packages one component may depend on (D006)
+# max_efferent_coupling = 15
+# Minimum percentage of components classified into a layer or as cross-cutting (L010)
+# min_classification_coverage = 90.0
+# Minimum interface coverage sub-score, failing on its own regardless of other rules (PA013)
+# min_interface_coverage = 80.0
+# Base URL for violation documentation links; the lowercase rule ID is appended as the anchor
+# doc_base_url = "https://rebelopsio.github.io/boundary/features/rules.html"
+# Let violations in generated files (`// Code generated ... DO NOT EDIT.`, `@generated`)
+# fail `boundary check`; by default they are downgraded to info
+fail_on_generated = false
+# Detect Go init() functions that couple layers (L004)
+detect_init_functions = true
+# Flag adapters without a matching port interface (PA001)
+detect_missing_ports = true
+# Flag repositories carrying business logic (PA004)
+detect_fat_repositories = false
+# Method count above which PA004 fires
+max_repository_methods = 10
+# Flag port interfaces declared in several packages (PA005)
+detect_duplicate_ports = false
+# Flag ports with too many methods, an Interface Segregation smell (PA006)
+detect_fat_interfaces = false
+# Method count above which PA006 fires
+max_interface_methods = 7
+# Flag ports full of default/static method logic rather than contract (PA010)
+detect_behavior_heavy_ports = false
+# Default/static method count above which PA010 fires
+max_port_default_methods = 1
+# Flag bounded contexts with adapters or repositories but no port (PA011)
+detect_context_missing_ports = false
+# Flag factory functions returning a concrete adapter where a port exists (PA012)
+detect_factory_concrete_returns = false
+# Flag infrastructure packages that domain depends on more than they depend on domain (L006)
+detect_module_direction = false
+# Flag application services that only forward to one repository (L007)
+detect_pass_through_services = false
+# Flag domain port methods whose parameters are infrastructure types, e.g. *sql.Tx (L005)
+detect_infra_port_parameters = false
+# Flag domain entity and value-object fields typed as infrastructure types, e.g. *sql.DB (L005)
+detect_infra_field_types = false
+# Flag domain methods with persistence annotations, e.g. @Transactional or @Query (L005)
+detect_persistence_annotations = false
+# Flag bounded contexts that mix architecture modes (L008)
+detect_mixed_architecture_modes = false
+# Flag dependencies that jump over a layer, e.g. domain -> presentation (L009)
+detect_layer_skips = false
+# Flag dependency cycles between packages of the same layer (D003)
+detect_intra_layer_cycles = false
+# Flag dependency cycles between packages of different layers (D001)
+detect_cross_layer_cycles = false
+# Flag presentation components wiring many other presentation components (D004)
+detect_high_coupling = false
+# Presentation fan-out above which D004 fires
+max_presentation_fan_out = 10
+# Flag infrastructure adapters depending directly on other adapters (PA008)
+detect_adapter_coupling = false
+# Flag ports whose package imports the package of one of their adapters (PA009)
+detect_port_adapter_cycles = false
+# Flag production code depending on mocks, fakes and stubs (L011)
+detect_test_doubles = false
+# Component name globs identifying test doubles
+test_double_patterns = ["Mock[A-Z]*", "Fake[A-Z]*", "Stub[A-Z]*", "*Mock", "*Fake"]
+# Flag direct reads of domain entity fields from other packages (D007)
+detect_cross_aggregate_field_access = false
+# Flag domain entities that hold fields but no methods (L012)
+detect_anemic_model = false
+# Import path and type name keywords marking database or driver code (L005, PA007)
+infra_keywords = ["postgres", "mysql", "redis", "mongo", "database", "sql", "db", "dynamodb", "sqlite", "cassandra", "elasticsearch"]
+
+[rules.severities]
+# Category names. Rule IDs (e.g. L001 = "error", PA001 = "info") are also accepted
+# and take precedence over categories.
+layer_boundary = "error"
+circular_dependency = "error"
+external_dependency = "error"
+intra_layer_cycle = "warning"
+high_coupling = "info"
+architecture_drift = "error"
+excessive_fan_out = "warning"
+missing_port = "warning"
+init_coupling = "warning"
+domain_infra_leak = "error"
+constructor_concrete = "warning"
+missing_implementation = "info"
+fat_repository = "warning"
+duplicate_port = "warning"
+fat_interface = "warning"
+behavior_heavy_port = "warning"
+context_missing_port = "warning"
+factory_concrete = "warning"
+adapter_misplaced = "warning"
+adapter_coupling = "warning"
+port_adapter_cycle = "warning"
+module_direction = "warning"
+pass_through_service = "info"
+mixed_architecture_modes = "info"
+layer_skip = "warning"
+classification_coverage = "error"
+interface_coverage = "error"
+test_double = "warning"
+cross_aggregate_access = "warning"
+anemic_model = "warning"
+
+# Adapters that need no port (PA001), by name or file path glob
+[rules.missing_port]
+exempt_patterns = []
+
+# External packages each layer may import (D002). Entries are import path prefixes.
+# `allow` limits a layer to matching imports (an empty list forbids all external
+# imports); `deny` forbids matching imports. Layers without a policy are unrestricted.
+[rules.external_policy]
+# domain = { allow = [] }
+# application = { deny = ["github.com/gin-gonic/"] }
+
+# Custom dependency rules
+# [[rules.custom_rules]]
+# name = "no-http-in-domain"
+# from_pattern = "**/domain/**"
+# to_pattern = "**/net/http**"
+# action = "deny"
+# severity = "error"
+# message = "Domain layer must not import HTTP packages"
+
+# Path-specific ignores
+# [[rules.ignore]]
+# rule = "PA001"
+# paths = ["infrastructure/**/*document.go"]
+
+# Path-scoped severity overrides; first matching scope wins
+# [[rules.overrides]]
+# scope = "legacy/**"
+# [rules.overrides.severities]
+# layer_boundary = "warning"
+
+# Architecture debt estimate: remediation hours per violation, keyed by rule ID or
+# category name like [rules.severities]. Reported as a total when any are set.
+[debt]
+# L001 = 4.0
+# missing_port = 1.5
+
+# Fitness functions: named assertions over metrics (overall, layer_conformance,
+# circular_dependency_count, ...) that fail `boundary check` when false. Comparisons
+# (>=, >, <=, <, ==, !=) combine with AND, OR and parentheses.
+[fitness]
+# layered = "layer_conformance >= 90 AND circular_dependency_count == 0"
+
+# Named zones, scored separately by --per-zone and then aggregated. Each zone is a
+# directory with its own layer patterns and scoring; omitted tables fall back to the
+# top-level [layers] and [scoring].
+# [[zones]]
+# name = "admin"
+# path = "tools/admin"
+# [zones.layers]
+# architecture_mode = "active-record"
+# [zones.scoring]
+# layer_conformance_weight = 0.6
+# dependency_compliance_weight = 0.4
+# interface_coverage_weight = 0.0
 "#
         .to_string()
     }
@@ -366,6 +1183,110 @@ domain_infra_leak = "error"
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_reference_toml_matches_defaults() {
+        let reference = Config::reference_toml();
+        let parsed: Config = toml::from_str(&reference).expect("reference config should parse");
+        let parsed = serde_json::to_value(&parsed).unwrap();
+        let defaults = serde_json::to_value(Config::default()).unwrap();
+        assert_eq!(parsed, defaults);
+
+        // Every key must be documented, even if only in a comment.
+        fn keys(value: &serde_json::Value, out: &mut Vec<String>) {
+            if let serde_json::Value::Object(map) = value {
+                for (key, child) in map {
+                    out.push(key.clone());
+                    keys(child, out);
+                }
+            }
+        }
+        let mut all = Vec::new();
+        keys(&defaults, &mut all);
+        for key in all {
+            let documented = reference.contains(&format!("\n{key} ="))
+                || reference.contains(&format!("# {key} ="))
+                || reference.contains(&format!("[{key}]"))
+                || reference.contains(&format!(".{key}]"));
+            assert!(documented, "key '{key}' missing from reference_toml");
+        }
+    }
+
+    #[test]
+    fn test_style_presets_missing_port_rule() {
+        let mvc = Config::parse("[project]\nstyle = \"mvc\"\n").unwrap();
+        assert_eq!(mvc.project.style, Some(ArchitectureStyle::Mvc));
+        assert!(!mvc.rules.detect_missing_ports);
+        assert_eq!(
+            mvc.rules.severities.get("constructor_concrete"),
+            Some(&Severity::Info)
+        );
+        // Severities the preset leaves alone keep their defaults.
+        assert_eq!(
+            mvc.rules.severities.get("layer_boundary"),
+            Some(&Severity::Error)
+        );
+
+        let hexagonal = Config::parse("[project]\nstyle = \"hexagonal\"\n").unwrap();
+        assert!(hexagonal.rules.detect_missing_ports);
+        assert!(hexagonal.rules.detect_factory_concrete_returns);
+    }
+
+    #[test]
+    fn test_explicit_rules_override_style_preset() {
+        let config = Config::parse(
+            r#"
+[project]
+style = "mvc"
+
+[rules]
+detect_missing_ports = true
+
+[rules.severities]
+constructor_concrete = "error"
+"#,
+        )
+        .unwrap();
+        assert!(config.rules.detect_missing_ports);
+        assert_eq!(
+            config.rules.severities.get("constructor_concrete"),
+            Some(&Severity::Error)
+        );
+        assert_eq!(
+            config.rules.severities.get("domain_infra_leak"),
+            Some(&Severity::Warning)
+        );
+    }
+
+    #[test]
+    fn test_unknown_style_is_rejected() {
+        assert!(Config::parse("[project]\nstyle = \"onion\"\n").is_err());
+    }
+
+    #[test]
+    fn test_external_policy_permits() {
+        let config: Config = toml::from_str(
+            r#"
+[rules.external_policy]
+domain = { allow = [] }
+application = { allow = ["github.com/google/uuid"] }
+infrastructure = { deny = ["github.com/gin-gonic/"] }
+"#,
+        )
+        .unwrap();
+        let policy = |layer| config.rules.external_policy.for_layer(layer).unwrap();
+
+        assert!(!policy(ArchLayer::Domain).permits("github.com/google/uuid"));
+        assert!(policy(ArchLayer::Application).permits("github.com/google/uuid"));
+        assert!(!policy(ArchLayer::Application).permits("github.com/gin-gonic/gin"));
+        assert!(!policy(ArchLayer::Infrastructure).permits("github.com/gin-gonic/gin"));
+        assert!(policy(ArchLayer::Infrastructure).permits("github.com/lib/pq"));
+        assert!(config
+            .rules
+            .external_policy
+            .for_layer(ArchLayer::Presentation)
+            .is_none());
+    }
+
     #[test]
     fn test_default_config() {
         let config = Config::default();
@@ -456,6 +1377,46 @@ domain = ["**/domain/**"]
         assert!(config.layers.overrides.is_empty());
     }
 
+    #[test]
+    fn test_zone_config_replaces_layers_and_scoring() {
+        let toml_str = r#"
+[layers]
+domain = ["**/core/**"]
+
+[scoring]
+interface_coverage_weight = 0.3
+
+[[zones]]
+name = "api"
+path = "services/api"
+
+[[zones]]
+name = "admin"
+path = "tools/admin"
+
+[zones.layers]
+architecture_mode = "active-record"
+
+[zones.scoring]
+interface_coverage_weight = 0.0
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.zones.len(), 2);
+
+        let api = config.for_zone(&config.zones[0]);
+        assert_eq!(api.layers.domain, vec!["**/core/**"]);
+        assert_eq!(api.scoring.interface_coverage_weight, 0.3);
+        assert!(api.zones.is_empty());
+
+        let admin = config.for_zone(&config.zones[1]);
+        assert_eq!(
+            admin.layers.architecture_mode,
+            ArchitectureMode::ActiveRecord
+        );
+        assert_eq!(admin.layers.domain, default_domain_patterns());
+        assert_eq!(admin.scoring.interface_coverage_weight, 0.0);
+    }
+
     #[test]
     fn test_deserialize_cross_cutting() {
         let toml_str = r#"
@@ -41,9 +41,9 @@ pub fn evaluate_custom_rules(
 ) -> Vec<Violation> {
     let mut violations = Vec::new();
 
-    for (src, tgt, edge) in graph.edges_with_nodes() {
-        let from_path = &src.id.0;
-        let to_path = edge.import_path.as_deref().unwrap_or(&tgt.id.0);
+    for (src, tgt, edge, location) in graph.edge_occurrences() {
+        let from_path = src.id.untagged();
+        let to_path = edge.import_path.as_deref().unwrap_or(tgt.id.untagged());
 
         for rule in rules {
             if rule.from_regex.is_match(from_path) && rule.to_regex.is_match(to_path) {
@@ -59,12 +59,14 @@ pub fn evaluate_custom_rules(
                         rule_name: rule.name.clone(),
                     },
                     severity: rule.severity,
-                    location: edge.location.clone(),
+                    location: location.clone(),
                     message,
                     suggestion: Some(format!(
                         "This dependency is forbidden by custom rule '{}'.",
                         rule.name
                     )),
+                    fix: None,
+                    doc_url: None,
                 });
             }
         }
@@ -99,7 +101,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -1,21 +1,81 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::{BufRead, Write};
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
-use crate::metrics::AnalysisResult;
-use crate::types::Violation;
+use crate::graph::DependencyGraph;
+use crate::metrics::{kind_name, AnalysisResult};
+use crate::types::{relative_to_root, ArchLayer, Violation};
+
+/// Version of the snapshot layout in `history.ndjson`. Bump when a change stops older
+/// snapshots from deserializing as-is, and upgrade them in [`migrate_snapshot`].
+pub const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
 
 /// A snapshot of an analysis run, stored for evolution tracking.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisSnapshot {
+    /// Snapshots written before versioning have no version and share version 1's layout.
+    #[serde(default = "unversioned_snapshot")]
+    pub schema_version: u32,
     pub timestamp: String,
     pub git_commit: Option<String>,
     pub git_branch: Option<String>,
     pub result: AnalysisResult,
+    /// Component classifications; empty in snapshots written before they were recorded.
+    #[serde(default)]
+    pub components: Vec<ComponentClassification>,
+}
+
+/// The layer and kind a component was classified as in a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentClassification {
+    pub id: String,
+    pub name: String,
+    pub layer: Option<ArchLayer>,
+    /// Snake-case kind name, as in `components_by_kind`.
+    pub kind: String,
+}
+
+impl ComponentClassification {
+    /// Classifications of the components of `graph`, analyzed at `root`, sorted by ID.
+    /// IDs are relative to `root`, so snapshots taken from different directories
+    /// compare. External components and import-level nodes are left out.
+    pub fn from_graph(graph: &DependencyGraph, root: &Path) -> Vec<Self> {
+        let mut components: Vec<Self> = graph
+            .nodes()
+            .into_iter()
+            .filter(|n| !n.is_external)
+            .filter_map(|n| {
+                Some(Self {
+                    id: relative_to_root(&n.id.0, root),
+                    name: n.name.clone(),
+                    layer: n.layer,
+                    kind: kind_name(n.kind.as_ref()?).to_string(),
+                })
+            })
+            .collect();
+        components.sort_by(|a, b| a.id.cmp(&b.id));
+        components
+    }
+}
+
+/// A component whose classification differs from the last snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassificationChange {
+    Added(ComponentClassification),
+    Removed(ComponentClassification),
+    /// Same component ID, different layer or kind.
+    Changed {
+        previous: ComponentClassification,
+        current: ComponentClassification,
+    },
+}
+
+fn unversioned_snapshot() -> u32 {
+    1
 }
 
 /// Per-rule violation count change between two snapshots.
@@ -39,12 +99,18 @@ pub struct TrendReport {
     pub rule_trends: Vec<RuleTrend>,
 }
 
-/// Save an analysis snapshot to `.boundary/history.ndjson`.
-pub fn save_snapshot(project_path: &Path, result: &AnalysisResult) -> Result<()> {
+/// Save an analysis snapshot, with the classification of each component, to
+/// `.boundary/history.ndjson`.
+pub fn save_snapshot(
+    project_path: &Path,
+    result: &AnalysisResult,
+    components: &[ComponentClassification],
+) -> Result<()> {
     let dir = project_path.join(".boundary");
     std::fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
 
     let snapshot = AnalysisSnapshot {
+        schema_version: SNAPSHOT_SCHEMA_VERSION,
         timestamp: Utc::now().to_rfc3339(),
         git_commit: get_git_commit(project_path),
         git_branch: get_git_branch(project_path),
@@ -57,7 +123,15 @@ pub fn save_snapshot(project_path: &Path, result: &AnalysisResult) -> Result<()>
             metrics: result.metrics.clone(),
             package_metrics: result.package_metrics.clone(),
             pattern_detection: result.pattern_detection.clone(),
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: result.debt.clone(),
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         },
+        components: components.to_vec(),
     };
 
     let line = serde_json::to_string(&snapshot).context("failed to serialize snapshot")?;
@@ -77,7 +151,7 @@ pub fn save_snapshot(project_path: &Path, result: &AnalysisResult) -> Result<()>
 }
 
 /// Count violations grouped by rule ID.
-fn count_by_rule(violations: &[Violation]) -> HashMap<String, usize> {
+pub(crate) fn count_by_rule(violations: &[Violation]) -> HashMap<String, usize> {
     let mut counts = HashMap::new();
     for v in violations {
         *counts.entry(v.kind.rule_id().to_string()).or_insert(0) += 1;
@@ -154,20 +228,90 @@ pub fn check_regression(
     }
 }
 
+/// Compare component classifications with the last snapshot, returning the components
+/// that appeared, disappeared, or changed layer or kind, sorted by ID.
+///
+/// Returns `None` when there is no snapshot, or the last one predates recorded
+/// classifications, so there is nothing to compare against.
+pub fn classification_diff(
+    project_path: &Path,
+    current: &[ComponentClassification],
+) -> Result<Option<Vec<ClassificationChange>>> {
+    let history_path = project_path.join(".boundary/history.ndjson");
+    if !history_path.exists() {
+        return Ok(None);
+    }
+    let Some(last) = load_last_snapshot(&history_path)? else {
+        return Ok(None);
+    };
+    if last.components.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(diff_classifications(&last.components, current)))
+}
+
+fn diff_classifications(
+    previous: &[ComponentClassification],
+    current: &[ComponentClassification],
+) -> Vec<ClassificationChange> {
+    let mut by_id: BTreeMap<
+        &str,
+        (
+            Option<&ComponentClassification>,
+            Option<&ComponentClassification>,
+        ),
+    > = BTreeMap::new();
+    for c in previous {
+        by_id.entry(&c.id).or_default().0 = Some(c);
+    }
+    for c in current {
+        by_id.entry(&c.id).or_default().1 = Some(c);
+    }
+
+    by_id
+        .into_values()
+        .filter_map(|pair| match pair {
+            (None, Some(current)) => Some(ClassificationChange::Added(current.clone())),
+            (Some(previous), None) => Some(ClassificationChange::Removed(previous.clone())),
+            (Some(previous), Some(current))
+                if previous.layer != current.layer || previous.kind != current.kind =>
+            {
+                Some(ClassificationChange::Changed {
+                    previous: previous.clone(),
+                    current: current.clone(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 /// Load the most recent snapshot from the NDJSON history file.
-fn load_last_snapshot(path: &Path) -> Result<Option<AnalysisSnapshot>> {
+///
+/// Older snapshots are migrated to the current layout; a snapshot written by a newer
+/// version of boundary is an error rather than a line to skip.
+pub fn load_last_snapshot(path: &Path) -> Result<Option<AnalysisSnapshot>> {
     let file =
         std::fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
     let reader = std::io::BufReader::new(file);
 
     let mut last: Option<AnalysisSnapshot> = None;
-    for line in reader.lines() {
+    for (i, line) in reader.lines().enumerate() {
         let line = line.context("failed to read line from history")?;
         let trimmed = line.trim();
         if trimmed.is_empty() {
             continue;
         }
-        match serde_json::from_str::<AnalysisSnapshot>(trimmed) {
+        let value = match serde_json::from_str::<serde_json::Value>(trimmed) {
+            Ok(value) => value,
+            Err(e) => {
+                eprintln!("Warning: skipping malformed history line: {e}");
+                continue;
+            }
+        };
+        let value = migrate_snapshot(value)
+            .with_context(|| format!("{}: line {}", path.display(), i + 1))?;
+        match serde_json::from_value::<AnalysisSnapshot>(value) {
             Ok(snapshot) => last = Some(snapshot),
             Err(e) => {
                 eprintln!("Warning: skipping malformed history line: {e}");
@@ -178,6 +322,28 @@ fn load_last_snapshot(path: &Path) -> Result<Option<AnalysisSnapshot>> {
     Ok(last)
 }
 
+/// Upgrade a raw snapshot to [`SNAPSHOT_SCHEMA_VERSION`], refusing newer versions.
+fn migrate_snapshot(mut value: serde_json::Value) -> Result<serde_json::Value> {
+    let version = match value.get("schema_version") {
+        Some(v) => v
+            .as_u64()
+            .with_context(|| format!("invalid snapshot schema version {v}"))?,
+        None => u64::from(unversioned_snapshot()),
+    };
+    if version > u64::from(SNAPSHOT_SCHEMA_VERSION) {
+        bail!(
+            "snapshot schema version {version} was written by a newer version of boundary \
+             (this version reads up to {SNAPSHOT_SCHEMA_VERSION}); upgrade boundary or move \
+             .boundary/history.ndjson aside"
+        );
+    }
+    // Each layout change adds a step here that upgrades version N to N + 1.
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("schema_version".to_string(), SNAPSHOT_SCHEMA_VERSION.into());
+    }
+    Ok(value)
+}
+
 /// Get the current git commit hash, if available.
 fn get_git_commit(project_path: &Path) -> Option<String> {
     std::process::Command::new("git")
@@ -225,6 +391,7 @@ mod tests {
                 layer_conformance: score,
                 dependency_compliance: score,
                 interface_coverage: score,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 5,
@@ -233,6 +400,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -247,6 +421,8 @@ mod tests {
             },
             message: "test".to_string(),
             suggestion: None,
+            fix: None,
+            doc_url: None,
         }
     }
 
@@ -259,6 +435,7 @@ mod tests {
                 layer_conformance: score,
                 dependency_compliance: score,
                 interface_coverage: score,
+                not_applicable: Vec::new(),
             }),
             violations,
             component_count: 5,
@@ -267,6 +444,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -274,7 +458,7 @@ mod tests {
     fn test_save_and_check_no_regression() {
         let dir = tempfile::tempdir().unwrap();
         let result = sample_result(80.0);
-        save_snapshot(dir.path(), &result).unwrap();
+        save_snapshot(dir.path(), &result, &[]).unwrap();
 
         let better_result = sample_result(90.0);
         let trend = check_regression(dir.path(), &better_result).unwrap();
@@ -285,7 +469,7 @@ mod tests {
     fn test_save_and_check_regression() {
         let dir = tempfile::tempdir().unwrap();
         let result = sample_result(90.0);
-        save_snapshot(dir.path(), &result).unwrap();
+        save_snapshot(dir.path(), &result, &[]).unwrap();
 
         let worse_result = sample_result(70.0);
         let trend = check_regression(dir.path(), &worse_result).unwrap();
@@ -296,6 +480,125 @@ mod tests {
         assert_eq!(trend.score_delta, -20.0);
     }
 
+    #[test]
+    fn test_load_unversioned_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join("history.ndjson");
+        let v1 = serde_json::json!({
+            "timestamp": "2025-01-01T00:00:00Z",
+            "git_commit": null,
+            "git_branch": null,
+            "result": sample_result(85.0),
+        });
+        std::fs::write(&history, format!("{v1}\n")).unwrap();
+
+        let snapshot = load_last_snapshot(&history).unwrap().unwrap();
+        assert_eq!(snapshot.schema_version, SNAPSHOT_SCHEMA_VERSION);
+        assert_eq!(snapshot.result.score.unwrap().overall, 85.0);
+    }
+
+    #[test]
+    fn test_future_snapshot_version_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        save_snapshot(dir.path(), &sample_result(80.0), &[]).unwrap();
+        let history = dir.path().join(".boundary/history.ndjson");
+        let future = serde_json::json!({
+            "schema_version": SNAPSHOT_SCHEMA_VERSION + 1,
+            "timestamp": "2030-01-01T00:00:00Z",
+            "git_commit": null,
+            "git_branch": null,
+            "result": sample_result(90.0),
+        });
+        let mut content = std::fs::read_to_string(&history).unwrap();
+        content.push_str(&format!("{future}\n"));
+        std::fs::write(&history, content).unwrap();
+
+        let err = check_regression(dir.path(), &sample_result(70.0)).unwrap_err();
+        let message = format!("{err:#}");
+        assert!(
+            message.contains("snapshot schema version 2") && message.contains("newer version"),
+            "unexpected error: {message}"
+        );
+    }
+
+    fn classification(id: &str, layer: Option<ArchLayer>, kind: &str) -> ComponentClassification {
+        ComponentClassification {
+            id: id.to_string(),
+            name: id.rsplit("::").next().unwrap().to_string(),
+            layer,
+            kind: kind.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_classification_diff() {
+        let dir = tempfile::tempdir().unwrap();
+        let previous = vec![
+            classification("order::Order", Some(ArchLayer::Domain), "entity"),
+            classification("order::Legacy", Some(ArchLayer::Domain), "service"),
+            classification("order::Money", Some(ArchLayer::Domain), "value_object"),
+        ];
+        save_snapshot(dir.path(), &sample_result(80.0), &previous).unwrap();
+
+        let current = vec![
+            classification("order::Order", Some(ArchLayer::Application), "service"),
+            classification("order::Money", Some(ArchLayer::Domain), "value_object"),
+            classification("order::Placed", Some(ArchLayer::Domain), "domain_event"),
+        ];
+        let changes = classification_diff(dir.path(), &current).unwrap().unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                ClassificationChange::Removed(previous[1].clone()),
+                ClassificationChange::Changed {
+                    previous: previous[0].clone(),
+                    current: current[0].clone(),
+                },
+                ClassificationChange::Added(current[2].clone()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classification_ids_are_relative_to_root() {
+        use crate::types::{ArchitectureMode, Component, ComponentId, ComponentKind, EntityInfo};
+        let graph = |package: &str| {
+            let mut graph = DependencyGraph::new();
+            graph.add_component(&Component {
+                id: ComponentId::with_language("go", package, "Order"),
+                name: "Order".to_string(),
+                kind: ComponentKind::Entity(EntityInfo {
+                    name: "Order".to_string(),
+                    fields: vec![],
+                    methods: vec![],
+                    is_active_record: false,
+                    is_anemic_domain_model: false,
+                }),
+                layer: Some(ArchLayer::Domain),
+                location: SourceLocation::default(),
+                is_cross_cutting: false,
+                is_generated: false,
+                architecture_mode: ArchitectureMode::Ddd,
+                methods: Vec::new(),
+            });
+            graph
+        };
+        let outside = ComponentClassification::from_graph(&graph("p/domain"), Path::new("p"));
+        let inside = ComponentClassification::from_graph(&graph("./domain"), Path::new("."));
+        assert_eq!(outside[0].id, "go:domain::Order");
+        assert_eq!(outside, inside);
+    }
+
+    #[test]
+    fn test_classification_diff_needs_recorded_classifications() {
+        let dir = tempfile::tempdir().unwrap();
+        let current = vec![classification("a::A", None, "service")];
+        assert!(classification_diff(dir.path(), &current).unwrap().is_none());
+
+        save_snapshot(dir.path(), &sample_result(80.0), &[]).unwrap();
+        assert!(classification_diff(dir.path(), &current).unwrap().is_none());
+    }
+
     #[test]
     fn test_no_history_file() {
         let dir = tempfile::tempdir().unwrap();
@@ -367,7 +670,7 @@ mod tests {
                 adapter_name: "X".into(),
             }],
         );
-        save_snapshot(dir.path(), &prev).unwrap();
+        save_snapshot(dir.path(), &prev, &[]).unwrap();
 
         // Current result is worse score with different violations
         let curr = sample_result_with_violations(
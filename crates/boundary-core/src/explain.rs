@@ -0,0 +1,301 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::graph::{DependencyGraph, GraphEdge, GraphNode};
+use crate::metrics::AnalysisResult;
+use crate::types::{ArchLayer, ComponentId, Severity, SourceLocation, Violation, ViolationKind};
+
+/// One dependency on the chain behind an explained violation.
+#[derive(Debug, Clone, Serialize)]
+pub struct Hop {
+    pub from: String,
+    pub from_layer: Option<ArchLayer>,
+    pub to: String,
+    pub to_layer: Option<ArchLayer>,
+    pub location: SourceLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_path: Option<String>,
+}
+
+/// A single violation, with the dependencies that cause it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Explanation {
+    /// The violation's fingerprint, as accepted by `boundary explain --id`.
+    pub id: String,
+    pub rule: String,
+    pub name: String,
+    pub severity: Severity,
+    pub location: SourceLocation,
+    pub message: String,
+    /// Layers the chain passes through, in order of first appearance.
+    pub layers: Vec<ArchLayer>,
+    /// Whether `chain` is a dependency cycle rather than a path from source to target.
+    pub is_cycle: bool,
+    /// Dependencies from the violation's source to its target, or around the cycle.
+    /// Empty for violations about a single component, e.g. a missing port.
+    pub chain: Vec<Hop>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_url: Option<String>,
+}
+
+/// Violations of `result` matching both selectors: `id` is a fingerprint or a prefix of
+/// one, and `kind` a rule ID (`L001`) or kind name (`circular-dependency`), ignoring case.
+pub fn select<'a>(
+    result: &'a AnalysisResult,
+    id: Option<&str>,
+    kind: Option<&str>,
+) -> Vec<&'a Violation> {
+    result
+        .violations
+        .iter()
+        .filter(|v| {
+            id.is_none_or(|id| v.fingerprint(result.root()).starts_with(&id.to_lowercase()))
+        })
+        .filter(|v| {
+            kind.is_none_or(|kind| {
+                v.kind.rule_id().as_str().eq_ignore_ascii_case(kind)
+                    || v.kind.name().eq_ignore_ascii_case(kind)
+            })
+        })
+        .collect()
+}
+
+/// Explain a violation found in `graph`, for the project at `root`.
+pub fn explain(violation: &Violation, root: &Path, graph: &DependencyGraph) -> Explanation {
+    let (chain, is_cycle) = match &violation.kind {
+        ViolationKind::CircularDependency { cycle, .. } => (cycle_chain(cycle, graph), true),
+        ViolationKind::IntraLayerCycle { packages, .. } => {
+            let cycle: Vec<ComponentId> = packages.iter().cloned().map(ComponentId).collect();
+            (cycle_chain(&cycle, graph), true)
+        }
+        _ => (dependency_chain(violation, graph), false),
+    };
+
+    let mut layers = Vec::new();
+    for hop in &chain {
+        for layer in [hop.from_layer, hop.to_layer].into_iter().flatten() {
+            if !layers.contains(&layer) {
+                layers.push(layer);
+            }
+        }
+    }
+    if chain.is_empty() {
+        layers.extend(
+            graph
+                .nodes()
+                .into_iter()
+                .find(|n| {
+                    n.location.file == violation.location.file
+                        && n.location.line == violation.location.line
+                })
+                .and_then(|n| n.layer),
+        );
+    }
+
+    Explanation {
+        id: violation.fingerprint(root),
+        rule: violation.kind.rule_id().to_string(),
+        name: violation.kind.name().to_string(),
+        severity: violation.severity,
+        location: violation.location.clone(),
+        message: violation.message.clone(),
+        layers,
+        is_cycle,
+        chain,
+        suggestion: violation.suggestion.clone(),
+        doc_url: violation.help_url(),
+    }
+}
+
+/// The dependency reported at the violation's location, followed through the graph
+/// from its source to its target.
+fn dependency_chain(violation: &Violation, graph: &DependencyGraph) -> Vec<Hop> {
+    let at = &violation.location;
+    let edges = graph.edges_with_nodes();
+    let candidates: Vec<_> = edges
+        .iter()
+        .filter(|(_, _, e)| {
+            e.locations
+                .iter()
+                .any(|l| l.file == at.file && l.line == at.line)
+        })
+        .collect();
+    // Several dependencies can share a line; prefer the one between the reported layers.
+    let Some((source, target, _)) = candidates
+        .iter()
+        .find(|(source, target, _)| match &violation.kind {
+            ViolationKind::LayerBoundary {
+                from_layer,
+                to_layer,
+            } => source.layer == Some(*from_layer) && target.layer == Some(*to_layer),
+            ViolationKind::LayerSkip { from, to, .. } => {
+                source.layer == Some(*from) && target.layer == Some(*to)
+            }
+            _ => true,
+        })
+        .or(candidates.first())
+    else {
+        return Vec::new();
+    };
+    let mut chain: Vec<Hop> = graph
+        .path_between(&source.id, &target.id)
+        .map(|path| path.into_iter().map(hop).collect())
+        .unwrap_or_default();
+    // A direct dependency occurring several times is shown where it was reported.
+    if let [direct] = chain.as_mut_slice() {
+        direct.location = at.clone();
+    }
+    chain
+}
+
+/// A walk around the cycle visiting every member: from the first member, the shortest
+/// path to the nearest member not yet visited, and finally back to the start. Cycles
+/// found between packages (IDs that are not components) are walked package by package.
+fn cycle_chain(cycle: &[ComponentId], graph: &DependencyGraph) -> Vec<Hop> {
+    let Some(start) = cycle.first() else {
+        return Vec::new();
+    };
+    let by_component = graph.nodes().iter().any(|n| &n.id == start);
+    let path = |from: &ComponentId, to: &ComponentId| {
+        if by_component {
+            graph.path_between(from, to)
+        } else {
+            graph.package_path_between(&from.0, &to.0)
+        }
+    };
+    let mut remaining: Vec<&ComponentId> = cycle[1..].iter().collect();
+    let mut current = start;
+    let mut walk = Vec::new();
+    while let Some((member, steps)) = remaining
+        .iter()
+        .filter_map(|&m| path(current, m).map(|p| (m, p)))
+        .min_by_key(|(_, p)| p.len())
+    {
+        remaining.retain(|&m| m != member);
+        // Components passed on the way are visited too. (Package members are only
+        // dropped when reached, since nodes name packages by import path.)
+        if by_component {
+            for (_, to, _) in &steps {
+                remaining.retain(|m| **m != to.id);
+            }
+        }
+        walk.extend(steps);
+        current = member;
+    }
+    walk.extend(path(current, start).unwrap_or_default());
+    walk.into_iter().map(hop).collect()
+}
+
+fn hop((from, to, edge): (&GraphNode, &GraphNode, &GraphEdge)) -> Hop {
+    Hop {
+        from: from.id.0.clone(),
+        from_layer: from.layer,
+        to: to.id.0.clone(),
+        to_layer: to.layer,
+        location: edge.location().clone(),
+        import_path: edge.import_path.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::metrics::build_result;
+    use crate::types::{
+        ArchitectureMode, Component, ComponentKind, Dependency, DependencyKind, EntityInfo,
+    };
+    use std::path::PathBuf;
+
+    fn component(id: &str, layer: ArchLayer) -> Component {
+        Component {
+            id: ComponentId(id.to_string()),
+            name: id.to_string(),
+            kind: ComponentKind::Entity(EntityInfo {
+                name: id.to_string(),
+                fields: vec![],
+                methods: vec![],
+                is_active_record: false,
+                is_anemic_domain_model: false,
+            }),
+            layer: Some(layer),
+            location: SourceLocation {
+                file: PathBuf::from(format!("{id}.go")),
+                line: 1,
+                column: 1,
+            },
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
+        }
+    }
+
+    fn dependency(from: &str, to: &str, line: usize) -> Dependency {
+        Dependency {
+            from: ComponentId(from.to_string()),
+            to: ComponentId(to.to_string()),
+            kind: DependencyKind::Import,
+            location: SourceLocation {
+                file: PathBuf::from(format!("{from}.go")),
+                line,
+                column: 1,
+            },
+            import_path: Some(to.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_layer_violation_chain_names_both_layers() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&component("user", ArchLayer::Domain));
+        graph.add_component(&component("postgres", ArchLayer::Infrastructure));
+        graph.add_dependency(&dependency("user", "postgres", 4));
+
+        let result = build_result(&graph, &Config::default(), 0, &[], 0, &[]);
+        let selected = select(&result, None, Some("l001"));
+        assert_eq!(selected.len(), 1);
+
+        let explanation = explain(selected[0], result.root(), &graph);
+        assert!(!explanation.is_cycle);
+        assert_eq!(
+            explanation.layers,
+            [ArchLayer::Domain, ArchLayer::Infrastructure]
+        );
+        assert_eq!(explanation.chain.len(), 1);
+        assert_eq!(explanation.chain[0].from, "user");
+        assert_eq!(explanation.chain[0].to, "postgres");
+        assert_eq!(explanation.chain[0].location.line, 4);
+        assert!(explanation.suggestion.is_some());
+
+        let by_id = select(&result, Some(&explanation.id[..8]), None);
+        assert_eq!(by_id.len(), 1);
+    }
+
+    #[test]
+    fn test_cycle_chain_visits_every_member() {
+        let mut graph = DependencyGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_component(&component(id, ArchLayer::Domain));
+        }
+        graph.add_dependency(&dependency("a", "c", 2));
+        graph.add_dependency(&dependency("c", "b", 3));
+        graph.add_dependency(&dependency("b", "a", 4));
+
+        let result = build_result(&graph, &Config::default(), 0, &[], 0, &[]);
+        let selected = select(&result, None, Some("circular-dependency"));
+        assert_eq!(selected.len(), 1);
+
+        let explanation = explain(selected[0], result.root(), &graph);
+        assert!(explanation.is_cycle);
+        assert_eq!(explanation.chain.len(), 3);
+        let first = &explanation.chain[0].from;
+        assert_eq!(&explanation.chain[2].to, first, "the walk closes the cycle");
+        for pair in explanation.chain.windows(2) {
+            assert_eq!(pair[0].to, pair[1].from);
+        }
+    }
+}
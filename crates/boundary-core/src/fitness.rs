@@ -0,0 +1,465 @@
+//! Architecture fitness functions: named assertions over analysis metrics, configured
+//! in `[fitness]` and evaluated by `boundary check`.
+//!
+//! An assertion compares metrics and numbers, and combines comparisons with `AND`,
+//! `OR` and parentheses:
+//!
+//! ```toml
+//! [fitness]
+//! layered = "layer_conformance >= 90 AND circular_dependency_count == 0"
+//! ```
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+
+use crate::metrics::{violation_kind_name, AnalysisResult, VIOLATION_KIND_NAMES};
+use crate::types::Severity;
+
+/// Metric names taken from the architecture score, absent when the project has none.
+const SCORE_METRICS: &[&str] = &[
+    "overall",
+    "structural_presence",
+    "layer_conformance",
+    "dependency_compliance",
+    "interface_coverage",
+];
+
+/// Numeric metrics of one analysis, by the names assertions refer to them by:
+/// the score components, `classification_coverage`, `component_count`,
+/// `dependency_count`, `violation_count`, `error_count`/`warning_count`/`info_count`
+/// and `<kind>_count` for each violation kind, named as in `violations_by_kind`.
+pub fn metrics_map(result: &AnalysisResult) -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::new();
+    if let Some(score) = &result.score {
+        for (name, value) in [
+            ("overall", score.overall),
+            ("structural_presence", score.structural_presence),
+            ("layer_conformance", score.layer_conformance),
+            ("dependency_compliance", score.dependency_compliance),
+            ("interface_coverage", score.interface_coverage),
+        ] {
+            metrics.insert(name.to_string(), value);
+        }
+    }
+
+    metrics.insert("component_count".into(), result.component_count as f64);
+    metrics.insert("dependency_count".into(), result.dependency_count as f64);
+    metrics.insert("violation_count".into(), result.violations.len() as f64);
+    for (name, severity) in [
+        ("error_count", Severity::Error),
+        ("warning_count", Severity::Warning),
+        ("info_count", Severity::Info),
+    ] {
+        let count = result
+            .violations
+            .iter()
+            .filter(|v| v.severity == severity)
+            .count();
+        metrics.insert(name.to_string(), count as f64);
+    }
+    // Counted from the violations rather than the metrics report, so violations
+    // dropped after analysis (`--ignore`, grace periods) are not counted.
+    for v in &result.violations {
+        *metrics
+            .entry(format!("{}_count", violation_kind_name(&v.kind)))
+            .or_insert(0.0) += 1.0;
+    }
+
+    let coverage = result
+        .metrics
+        .as_ref()
+        .and_then(|m| m.classification_coverage.as_ref());
+    if let Some(coverage) = coverage {
+        metrics.insert(
+            "classification_coverage".into(),
+            coverage.coverage_percentage,
+        );
+    }
+    metrics
+}
+
+/// A fitness assertion that evaluated to false.
+#[derive(Debug, Clone)]
+pub struct FitnessFailure {
+    pub name: String,
+    pub expression: String,
+    /// The metrics the expression refers to, with their values.
+    pub values: Vec<(String, f64)>,
+}
+
+/// Evaluate every `[fitness]` assertion against `result`, returning those that fail.
+/// A malformed expression or an unknown metric is an error, not a failure.
+pub fn evaluate_all(
+    assertions: &BTreeMap<String, String>,
+    result: &AnalysisResult,
+) -> Result<Vec<FitnessFailure>> {
+    let metrics = metrics_map(result);
+    let mut failures = Vec::new();
+    for (name, source) in assertions {
+        let assertion = Assertion::parse(source)
+            .with_context(|| format!("invalid [fitness] assertion '{name}'"))?;
+        let passed = assertion
+            .evaluate(&metrics)
+            .with_context(|| format!("cannot evaluate [fitness] assertion '{name}'"))?;
+        if !passed {
+            failures.push(FitnessFailure {
+                name: name.clone(),
+                expression: source.clone(),
+                values: assertion
+                    .metric_names()
+                    .into_iter()
+                    .map(|m| (m.to_string(), lookup(&metrics, m).unwrap_or_default()))
+                    .collect(),
+            });
+        }
+    }
+    Ok(failures)
+}
+
+/// Value of a metric. Counts of known violation kinds not present in the analysis
+/// are zero.
+fn lookup(metrics: &BTreeMap<String, f64>, name: &str) -> Result<f64> {
+    if let Some(&value) = metrics.get(name) {
+        return Ok(value);
+    }
+    if name
+        .strip_suffix("_count")
+        .is_some_and(|kind| VIOLATION_KIND_NAMES.contains(&kind))
+    {
+        return Ok(0.0);
+    }
+    if SCORE_METRICS.contains(&name) {
+        bail!("metric '{name}' is not available: no architecture score for this project");
+    }
+    let known: Vec<&str> = metrics.keys().map(String::as_str).collect();
+    bail!("unknown metric '{name}' (known: {})", known.join(", "))
+}
+
+/// A parsed fitness assertion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assertion {
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Compare(Operand, CompareOp, Operand),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Number(f64),
+    Metric(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Ge,
+    Gt,
+    Le,
+    Lt,
+    Eq,
+    Ne,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(CompareOp),
+    And,
+    Or,
+    Open,
+    Close,
+}
+
+impl Assertion {
+    /// Parse an expression such as `overall >= 80 AND (error_count == 0 OR overall > 95)`.
+    /// `AND` binds tighter than `OR`; both are case-insensitive, and `&&`/`||` work too.
+    pub fn parse(source: &str) -> Result<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            bail!("unexpected {token:?} after the end of the expression");
+        }
+        Ok(Self { expr })
+    }
+
+    /// Whether the assertion holds for `metrics`.
+    pub fn evaluate(&self, metrics: &BTreeMap<String, f64>) -> Result<bool> {
+        eval(&self.expr, metrics)
+    }
+
+    /// The metrics the assertion refers to, in order of first appearance.
+    pub fn metric_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        collect_metrics(&self.expr, &mut names);
+        names
+    }
+}
+
+fn eval(expr: &Expr, metrics: &BTreeMap<String, f64>) -> Result<bool> {
+    // Both sides are always evaluated, so an unknown metric is reported even in a
+    // branch that would not decide the result.
+    Ok(match expr {
+        Expr::And(a, b) => {
+            let (a, b) = (eval(a, metrics)?, eval(b, metrics)?);
+            a && b
+        }
+        Expr::Or(a, b) => {
+            let (a, b) = (eval(a, metrics)?, eval(b, metrics)?);
+            a || b
+        }
+        Expr::Compare(lhs, op, rhs) => {
+            let value = |operand: &Operand| match operand {
+                Operand::Number(n) => Ok(*n),
+                Operand::Metric(name) => lookup(metrics, name),
+            };
+            let (l, r) = (value(lhs)?, value(rhs)?);
+            match op {
+                CompareOp::Ge => l >= r,
+                CompareOp::Gt => l > r,
+                CompareOp::Le => l <= r,
+                CompareOp::Lt => l < r,
+                CompareOp::Eq => l == r,
+                CompareOp::Ne => l != r,
+            }
+        }
+    })
+}
+
+fn collect_metrics<'a>(expr: &'a Expr, names: &mut Vec<&'a str>) {
+    match expr {
+        Expr::And(a, b) | Expr::Or(a, b) => {
+            collect_metrics(a, names);
+            collect_metrics(b, names);
+        }
+        Expr::Compare(lhs, _, rhs) => {
+            for operand in [lhs, rhs] {
+                if let Operand::Metric(name) = operand {
+                    if !names.contains(&name.as_str()) {
+                        names.push(name);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '(' || c == ')' {
+            chars.next();
+            tokens.push(if c == '(' { Token::Open } else { Token::Close });
+        } else if c.is_ascii_digit() || c == '.' || c == '-' {
+            let mut end = start + c.len_utf8();
+            chars.next();
+            while let Some(&(i, d)) = chars.peek() {
+                if !(d.is_ascii_digit() || d == '.') {
+                    break;
+                }
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            let text = &source[start..end];
+            let n = text
+                .parse()
+                .with_context(|| format!("invalid number '{text}'"))?;
+            tokens.push(Token::Number(n));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek() {
+                if !(d.is_ascii_alphanumeric() || d == '_') {
+                    break;
+                }
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            let word = &source[start..end];
+            tokens.push(match word.to_ascii_uppercase().as_str() {
+                "AND" => Token::And,
+                "OR" => Token::Or,
+                _ => Token::Ident(word.to_string()),
+            });
+        } else {
+            let rest = &source[start..];
+            let (token, len) = [
+                (">=", Token::Op(CompareOp::Ge)),
+                ("<=", Token::Op(CompareOp::Le)),
+                ("==", Token::Op(CompareOp::Eq)),
+                ("!=", Token::Op(CompareOp::Ne)),
+                ("&&", Token::And),
+                ("||", Token::Or),
+                (">", Token::Op(CompareOp::Gt)),
+                ("<", Token::Op(CompareOp::Lt)),
+            ]
+            .into_iter()
+            .find(|(text, _)| rest.starts_with(text))
+            .map(|(text, token)| (token, text.len()))
+            .with_context(|| format!("unexpected '{c}'"))?;
+            tokens.push(token);
+            for _ in 0..len {
+                chars.next();
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent parser: `or := and (OR and)*`, `and := atom (AND atom)*`,
+/// `atom := '(' or ')' | operand op operand`.
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.atom()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.atom()?));
+        }
+        Ok(expr)
+    }
+
+    fn atom(&mut self) -> Result<Expr> {
+        if self.peek() == Some(&Token::Open) {
+            self.pos += 1;
+            let expr = self.or()?;
+            if self.next() != Some(Token::Close) {
+                bail!("expected ')'");
+            }
+            return Ok(expr);
+        }
+        let lhs = self.operand()?;
+        let Some(Token::Op(op)) = self.next() else {
+            bail!("expected a comparison (>=, >, <=, <, ==, !=) after {lhs:?}");
+        };
+        let rhs = self.operand()?;
+        Ok(Expr::Compare(lhs, op, rhs))
+    }
+
+    fn operand(&mut self) -> Result<Operand> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Operand::Number(n)),
+            Some(Token::Ident(name)) => Ok(Operand::Metric(name)),
+            Some(token) => bail!("expected a metric or number, found {token:?}"),
+            None => bail!("expected a metric or number, found the end of the expression"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(pairs: &[(&str, f64)]) -> BTreeMap<String, f64> {
+        pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    fn holds(source: &str, metrics: &BTreeMap<String, f64>) -> bool {
+        Assertion::parse(source).unwrap().evaluate(metrics).unwrap()
+    }
+
+    #[test]
+    fn test_comparisons_and_connectives() {
+        let m = metrics(&[
+            ("layer_conformance", 92.5),
+            ("circular_dependency_count", 1.0),
+        ]);
+        assert!(holds("layer_conformance >= 90", &m));
+        assert!(!holds("layer_conformance < 90", &m));
+        assert!(!holds(
+            "layer_conformance >= 90 AND circular_dependency_count == 0",
+            &m
+        ));
+        assert!(holds(
+            "layer_conformance >= 95 or circular_dependency_count != 0",
+            &m
+        ));
+        // AND binds tighter than OR; parentheses override it.
+        assert!(holds("1 == 1 OR 1 == 2 AND 1 == 2", &m));
+        assert!(!holds("(1 == 1 OR 1 == 2) && 1 == 2", &m));
+    }
+
+    #[test]
+    fn test_missing_violation_counts_are_zero() {
+        let m = metrics(&[("overall", 80.0)]);
+        assert!(holds("layer_skip_count == 0", &m));
+    }
+
+    #[test]
+    fn test_unknown_metric_is_an_error() {
+        let m = metrics(&[("overall", 80.0)]);
+        let err = Assertion::parse("overal >= 70")
+            .unwrap()
+            .evaluate(&m)
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown metric 'overal'"), "{err}");
+    }
+
+    #[test]
+    fn test_misspelled_violation_count_is_an_error() {
+        let m = metrics(&[("overall", 80.0)]);
+        let err = Assertion::parse("circular_dependncy_count == 0")
+            .unwrap()
+            .evaluate(&m)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown metric 'circular_dependncy_count'"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_malformed_expressions_are_rejected() {
+        for source in [
+            "overall >=",
+            "overall 80",
+            "(overall > 1",
+            "overall > 1 )",
+            "a ~ b",
+        ] {
+            assert!(
+                Assertion::parse(source).is_err(),
+                "{source} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_metric_names_in_order() {
+        let assertion =
+            Assertion::parse("overall > 70 AND (error_count == 0 OR overall > 90)").unwrap();
+        assert_eq!(assertion.metric_names(), ["overall", "error_count"]);
+    }
+}
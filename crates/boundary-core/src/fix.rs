@@ -0,0 +1,204 @@
+use crate::graph::GraphNode;
+use crate::types::{Fix, MethodInfo, SourceLocation};
+
+/// Name of the port interface generated for an adapter.
+pub fn port_name_for(adapter_name: &str) -> String {
+    format!("{adapter_name}Port")
+}
+
+/// Generate a port interface declaration exposing the given methods.
+///
+/// Returns `None` when the language (detected from the file extension) is not
+/// supported or when there are no methods a port could expose.
+pub fn generate_port_stub(port_name: &str, methods: &[MethodInfo], file: &str) -> Option<String> {
+    match file.rsplit('.').next() {
+        Some("go") => go_port_stub(port_name, methods),
+        _ => None,
+    }
+}
+
+/// Go: only exported methods can be part of an interface implemented across packages.
+fn go_port_stub(port_name: &str, methods: &[MethodInfo]) -> Option<String> {
+    let exported: Vec<&MethodInfo> = methods
+        .iter()
+        .filter(|m| m.name.starts_with(|c: char| c.is_ascii_uppercase()))
+        .collect();
+    if exported.is_empty() {
+        return None;
+    }
+
+    let mut out = format!("type {port_name} interface {{\n");
+    for m in exported {
+        let params = if m.parameters.is_empty() {
+            "()"
+        } else {
+            &m.parameters
+        };
+        if m.return_type.is_empty() {
+            out.push_str(&format!("\t{}{}\n", m.name, params));
+        } else {
+            out.push_str(&format!("\t{}{} {}\n", m.name, params, m.return_type));
+        }
+    }
+    out.push_str("}\n\n");
+    Some(out)
+}
+
+/// Derive a fix for a missing-port violation: insert a port interface built from
+/// the adapter's methods directly above the adapter declaration.
+pub fn missing_port_fix(node: &GraphNode) -> Option<Fix> {
+    let file = node.location.file.to_string_lossy();
+    if file.is_empty() || node.location.line == 0 {
+        return None;
+    }
+    let port_name = port_name_for(&node.name);
+    let insert_text = generate_port_stub(&port_name, &node.methods, &file)?;
+    Some(Fix {
+        description: format!(
+            "Extract port interface '{port_name}' from '{}', then move it into the domain layer",
+            node.name
+        ),
+        location: SourceLocation {
+            file: node.location.file.clone(),
+            line: node.location.line,
+            column: 1,
+        },
+        insert_text,
+    })
+}
+
+/// Fixes in `fixes` that can be applied to `source` unchanged: pure insertions
+/// before an existing line whose text is not already present.
+pub fn applicable_fixes<'a>(source: &str, fixes: &[&'a Fix]) -> Vec<&'a Fix> {
+    let line_count = source.lines().count();
+    let mut applicable: Vec<&Fix> = Vec::new();
+    for &fix in fixes {
+        let line = fix.location.line;
+        if line == 0 || line > line_count || source.contains(&fix.insert_text) {
+            continue;
+        }
+        if applicable.iter().any(|f| f.insert_text == fix.insert_text) {
+            continue;
+        }
+        applicable.push(fix);
+    }
+    applicable.sort_by_key(|f| f.location.line);
+    applicable
+}
+
+/// Apply insertions to `source`, each before its 1-based line. Existing lines are
+/// kept verbatim and in order; `fixes` should come from `applicable_fixes`.
+pub fn apply_fixes(source: &str, fixes: &[&Fix]) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut pending = fixes.iter().peekable();
+    for (i, line) in source.split_inclusive('\n').enumerate() {
+        while let Some(fix) = pending.next_if(|f| f.location.line == i + 1) {
+            out.push_str(&fix.insert_text);
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// A unified diff of the insertions `apply_fixes` would make to the file at `path`.
+pub fn insertion_diff(path: &str, fixes: &[&Fix]) -> String {
+    let mut out = format!("--- a/{path}\n+++ b/{path}\n");
+    let mut inserted = 0;
+    for fix in fixes {
+        let lines: Vec<&str> = fix.insert_text.lines().collect();
+        let before = fix.location.line - 1;
+        out.push_str(&format!(
+            "@@ -{before},0 +{},{} @@\n",
+            before + inserted + 1,
+            lines.len()
+        ));
+        for line in &lines {
+            out.push_str(&format!("+{line}\n"));
+        }
+        inserted += lines.len();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn method(name: &str, params: &str, ret: &str) -> MethodInfo {
+        MethodInfo {
+            name: name.to_string(),
+            parameters: params.to_string(),
+            return_type: ret.to_string(),
+            parameter_types: Vec::new(),
+            visibility: Default::default(),
+            interface_kind: Default::default(),
+            annotations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_go_port_stub_lists_exported_methods() {
+        let methods = vec![
+            method("Save", "(ctx context.Context, u *User)", "error"),
+            method("FindByID", "(id string)", "(*User, error)"),
+            method("connect", "()", ""),
+        ];
+        let stub = generate_port_stub("UserRepoPort", &methods, "infra/repo.go").unwrap();
+        assert!(stub.starts_with("type UserRepoPort interface {\n"));
+        assert!(stub.contains("\tSave(ctx context.Context, u *User) error\n"));
+        assert!(stub.contains("\tFindByID(id string) (*User, error)\n"));
+        assert!(!stub.contains("connect"));
+    }
+
+    #[test]
+    fn test_port_stub_none_without_exported_methods() {
+        let methods = vec![method("connect", "()", "")];
+        assert!(generate_port_stub("XPort", &methods, "x.go").is_none());
+        assert!(generate_port_stub("XPort", &[], "x.go").is_none());
+    }
+
+    fn insertion(line: usize, text: &str) -> Fix {
+        Fix {
+            description: String::new(),
+            location: SourceLocation {
+                file: "x.go".into(),
+                line,
+                column: 1,
+            },
+            insert_text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_fixes_inserts_without_touching_existing_lines() {
+        let source = "package x\n\ntype A struct{}\n\ntype B struct{}\n";
+        let a = insertion(3, "// A\n");
+        let b = insertion(5, "// B1\n// B2\n");
+        let fixes = applicable_fixes(source, &[&b, &a]);
+        assert_eq!(
+            apply_fixes(source, &fixes),
+            "package x\n\n// A\ntype A struct{}\n\n// B1\n// B2\ntype B struct{}\n"
+        );
+        assert_eq!(
+            insertion_diff("x.go", &fixes),
+            "--- a/x.go\n+++ b/x.go\n@@ -2,0 +3,1 @@\n+// A\n@@ -4,0 +6,2 @@\n+// B1\n+// B2\n"
+        );
+    }
+
+    #[test]
+    fn test_applicable_fixes_skips_stale_and_applied() {
+        let source = "package x\n// A\ntype A struct{}\n";
+        let past_end = insertion(10, "// late\n");
+        let present = insertion(3, "// A\n");
+        let duplicate = insertion(2, "// new\n");
+        let fixes = applicable_fixes(source, &[&past_end, &present, &duplicate, &duplicate]);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].insert_text, "// new\n");
+    }
+
+    #[test]
+    fn test_port_stub_none_for_unsupported_language() {
+        let methods = vec![method("Save", "()", "")];
+        assert!(generate_port_stub("XPort", &methods, "x.py").is_none());
+    }
+}
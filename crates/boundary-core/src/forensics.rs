@@ -1,8 +1,9 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use walkdir::WalkDir;
 
-use crate::metrics::ArchitectureScore;
+use crate::metrics::{noncompliant_edges, ArchitectureScore, NoncompliantEdge};
 use crate::pipeline::FullAnalysis;
 use crate::types::*;
 
@@ -18,10 +19,21 @@ pub struct ForensicsAnalysis {
     pub infrastructure_adapters: Vec<AdapterMapping>,
     pub violations: Vec<Violation>,
     pub score: Option<ArchitectureScore>,
+    pub score_breakdown: ScoreBreakdown,
     pub classified_imports: Vec<ClassifiedImport>,
     pub improvements: Vec<String>,
 }
 
+/// What lowers the module's score, in the terms the score is computed in.
+pub struct ScoreBreakdown {
+    /// Dependencies counted against dependency compliance, heaviest first.
+    pub noncompliant_edges: Vec<NoncompliantEdge>,
+    /// Components in no layer and not cross-cutting; each lowers structural presence.
+    pub unclassified_components: Vec<Component>,
+    /// Import paths at the location of the most violations, as `(import, violations)`.
+    pub violating_imports: Vec<(String, usize)>,
+}
+
 /// An entry in the directory tree.
 pub struct DirEntry {
     pub rel_path: String,
@@ -105,7 +117,7 @@ pub fn build_forensics(
             ComponentKind::DomainEvent(_) => domain_events.push(comp.clone()),
             ComponentKind::Port(_) => ports.push(comp.clone()),
             ComponentKind::Entity(_) => entities.push(comp.clone()),
-            ComponentKind::ValueObject => value_objects.push(comp.clone()),
+            ComponentKind::ValueObject(_) => value_objects.push(comp.clone()),
             ComponentKind::Service if comp.layer == Some(ArchLayer::Application) => {
                 application_services.push(comp.clone());
             }
@@ -138,6 +150,8 @@ pub fn build_forensics(
         &full_analysis.result.violations,
     );
 
+    let score_breakdown = build_score_breakdown(full_analysis);
+
     ForensicsAnalysis {
         module_name,
         module_path: module_path.to_path_buf(),
@@ -149,11 +163,49 @@ pub fn build_forensics(
         infrastructure_adapters,
         violations: full_analysis.result.violations.clone(),
         score: full_analysis.result.score.clone(),
+        score_breakdown,
         classified_imports,
         improvements,
     }
 }
 
+/// Number of violating imports listed in a [`ScoreBreakdown`].
+const TOP_VIOLATING_IMPORTS: usize = 10;
+
+fn build_score_breakdown(full_analysis: &FullAnalysis) -> ScoreBreakdown {
+    let unclassified_components = full_analysis
+        .components
+        .iter()
+        .filter(|c| c.layer.is_none() && !c.is_cross_cutting)
+        .cloned()
+        .collect();
+
+    // A violation is attributed to the import declared on the line it points at.
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for v in &full_analysis.result.violations {
+        let import = full_analysis.dependencies.iter().find_map(|dep| {
+            (dep.location.file == v.location.file && dep.location.line == v.location.line)
+                .then_some(dep.import_path.as_deref())
+                .flatten()
+        });
+        if let Some(import) = import {
+            *counts.entry(import).or_insert(0) += 1;
+        }
+    }
+    let mut violating_imports: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(import, n)| (import.to_string(), n))
+        .collect();
+    violating_imports.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    violating_imports.truncate(TOP_VIOLATING_IMPORTS);
+
+    ScoreBreakdown {
+        noncompliant_edges: noncompliant_edges(&full_analysis.graph),
+        unclassified_components,
+        violating_imports,
+    }
+}
+
 fn build_directory_tree(module_path: &Path) -> Vec<DirEntry> {
     let mut entries = Vec::new();
 
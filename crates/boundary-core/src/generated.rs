@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::metrics::AnalysisResult;
+use crate::types::{Severity, Violation};
+
+/// Whether a source file carries a generated-code marker in its header: Go's
+/// `// Code generated ... DO NOT EDIT.` line, or `@generated` in a comment (used by
+/// TypeScript, Java and Rust code generators).
+///
+/// Only the leading comments are searched, up to the first line of code, so a
+/// marker mentioned further down (in a string, say) does not count.
+pub fn is_generated_source(content: &str) -> bool {
+    let mut in_block = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let is_comment = in_block || line.starts_with("//") || line.starts_with("/*");
+        if !is_comment {
+            return false;
+        }
+        if line.starts_with("// Code generated ") && line.ends_with(" DO NOT EDIT.") {
+            return true;
+        }
+        if line.contains("@generated") {
+            return true;
+        }
+        if in_block {
+            in_block = !line.contains("*/");
+        } else if let Some(rest) = line.strip_prefix("/*") {
+            in_block = !rest.contains("*/");
+        }
+    }
+    false
+}
+
+/// Keep violations located in generated files out of the failing set of `check`, at
+/// any `fail_on`, and report them at info severity.
+/// Returns the number of violations downgraded.
+pub fn exempt_generated_violations(result: &mut AnalysisResult) -> usize {
+    result.generated_exempt = true;
+    downgrade_generated(&mut result.violations, &result.generated_files)
+}
+
+/// Downgrade violations located in generated files to info.
+fn downgrade_generated(violations: &mut [Violation], generated_files: &[PathBuf]) -> usize {
+    let generated: HashSet<&PathBuf> = generated_files.iter().collect();
+    let mut downgraded = 0;
+    for v in violations
+        .iter_mut()
+        .filter(|v| v.severity > Severity::Info && generated.contains(&v.location.file))
+    {
+        v.severity = Severity::Info;
+        downgraded += 1;
+    }
+    downgraded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SourceLocation, ViolationKind};
+
+    fn violation(file: &str, severity: Severity) -> Violation {
+        Violation {
+            kind: ViolationKind::CircularDependency {
+                cycle: vec![],
+                layers: vec![],
+                cross_layer: false,
+            },
+            severity,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line: 1,
+                column: 1,
+            },
+            message: String::new(),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_go_generated_marker() {
+        let src = "// Code generated by protoc-gen-go. DO NOT EDIT.\n// source: user.proto\n\npackage user\n";
+        assert!(is_generated_source(src));
+        // Must sit before the package clause.
+        let late = "package user\n\n// Code generated by protoc-gen-go. DO NOT EDIT.\n";
+        assert!(!is_generated_source(late));
+        assert!(!is_generated_source(
+            "// Code generated by hand.\npackage user\n"
+        ));
+    }
+
+    #[test]
+    fn test_at_generated_marker() {
+        assert!(is_generated_source("// @generated\nexport const x = 1;\n"));
+        assert!(is_generated_source(
+            "/*\n * Copyright Example\n *\n * @generated by openapi-generator\n */\npackage com.example;\n"
+        ));
+        assert!(!is_generated_source(
+            "export const marker = \"@generated\";\n"
+        ));
+    }
+
+    #[test]
+    fn test_exempt_generated_violations() {
+        let mut violations = vec![
+            violation("gen/user.pb.go", Severity::Error),
+            violation("gen/user.pb.go", Severity::Info),
+            violation("domain/user.go", Severity::Error),
+        ];
+        let downgraded = downgrade_generated(&mut violations, &[PathBuf::from("gen/user.pb.go")]);
+        assert_eq!(downgraded, 1);
+        assert_eq!(violations[0].severity, Severity::Info);
+        assert_eq!(violations[2].severity, Severity::Error);
+    }
+}
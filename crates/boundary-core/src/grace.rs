@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::Utc;
+
+use crate::types::{Severity, Violation};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Age of source files, from the commit that first added each one to git.
+/// Files git has no record of (untracked, or no repository) fall back to their mtime.
+/// In a shallow clone no age is known, since the grafted root commit appears to add
+/// every file.
+#[derive(Debug, Default)]
+pub struct FileAges {
+    /// Set for shallow clones, where every age is unknown.
+    shallow: bool,
+    /// Canonical repository root that `added` paths are relative to.
+    repo_root: Option<PathBuf>,
+    /// Unix timestamp of the commit that added each file.
+    added: HashMap<PathBuf, i64>,
+}
+
+impl FileAges {
+    /// Read first-commit dates for every file in the repository containing `path`.
+    pub fn from_git(path: &Path) -> Self {
+        let Some(repo_root) = git_output(path, &["rev-parse", "--show-toplevel"])
+            .and_then(|out| PathBuf::from(out.trim()).canonicalize().ok())
+        else {
+            return Self::default();
+        };
+        if git_output(&repo_root, &["rev-parse", "--is-shallow-repository"])
+            .is_some_and(|out| out.trim() == "true")
+        {
+            eprintln!(
+                "Warning: {} is a shallow clone, so file ages are unknown and \
+                 --grace-days has no effect; fetch the full history to use it",
+                repo_root.display()
+            );
+            return Self {
+                shallow: true,
+                ..Self::default()
+            };
+        }
+        let added = git_output(
+            &repo_root,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "log",
+                "--diff-filter=A",
+                "--format=%x00%ct",
+                "--name-only",
+            ],
+        )
+        .map(|out| parse_added_dates(&out))
+        .unwrap_or_default();
+        Self {
+            shallow: false,
+            repo_root: Some(repo_root),
+            added,
+        }
+    }
+
+    /// Whole days since `file` was added, or `None` when its age cannot be determined.
+    pub fn age_days(&self, file: &Path) -> Option<u64> {
+        if self.shallow {
+            return None;
+        }
+        let now = Utc::now().timestamp();
+        let canonical = file.canonicalize().ok()?;
+        let added = self
+            .repo_root
+            .as_ref()
+            .and_then(|root| canonical.strip_prefix(root).ok())
+            .and_then(|rel| self.added.get(rel).copied())
+            .or_else(|| {
+                let modified = std::fs::metadata(&canonical).ok()?.modified().ok()?;
+                let secs = modified.duration_since(SystemTime::UNIX_EPOCH).ok()?;
+                i64::try_from(secs.as_secs()).ok()
+            })?;
+        u64::try_from((now - added).max(0) / SECONDS_PER_DAY).ok()
+    }
+}
+
+/// Parse `git log --diff-filter=A --format=%x00%ct --name-only` output into the
+/// earliest add timestamp of each path. Log entries run newest first.
+fn parse_added_dates(output: &str) -> HashMap<PathBuf, i64> {
+    let mut added = HashMap::new();
+    let mut timestamp = None;
+    for line in output.lines() {
+        if let Some(ts) = line.strip_prefix('\0') {
+            timestamp = ts.trim().parse::<i64>().ok();
+        } else if let Some(ts) = timestamp.filter(|_| !line.is_empty()) {
+            // A file deleted and re-added keeps its oldest date.
+            added.insert(PathBuf::from(line), ts);
+        }
+    }
+    added
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Downgrade violations in files younger than `grace_days` to warnings.
+/// `age_days` returns a file's age in days; unknown ages keep their severity.
+/// Returns the number of violations downgraded.
+pub fn apply_grace_period(
+    violations: &mut [Violation],
+    grace_days: u64,
+    mut age_days: impl FnMut(&Path) -> Option<u64>,
+) -> usize {
+    let mut ages: HashMap<PathBuf, Option<u64>> = HashMap::new();
+    let mut downgraded = 0;
+    for v in violations
+        .iter_mut()
+        .filter(|v| v.severity > Severity::Warning)
+    {
+        let age = *ages
+            .entry(v.location.file.clone())
+            .or_insert_with(|| age_days(&v.location.file));
+        if age.is_some_and(|days| days < grace_days) {
+            v.severity = Severity::Warning;
+            downgraded += 1;
+        }
+    }
+    downgraded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SourceLocation, ViolationKind};
+
+    fn violation(file: &str, severity: Severity) -> Violation {
+        Violation {
+            kind: ViolationKind::CircularDependency {
+                cycle: vec![],
+                layers: vec![],
+                cross_layer: false,
+            },
+            severity,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line: 1,
+                column: 1,
+            },
+            message: String::new(),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_grace_period_downgrades_only_new_files() {
+        let mut violations = vec![
+            violation("new.go", Severity::Error),
+            violation("old.go", Severity::Error),
+            violation("unknown.go", Severity::Error),
+            violation("new.go", Severity::Info),
+        ];
+        let downgraded = apply_grace_period(&mut violations, 14, |file| match file.to_str() {
+            Some("new.go") => Some(3),
+            Some("old.go") => Some(90),
+            _ => None,
+        });
+
+        assert_eq!(downgraded, 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(violations[1].severity, Severity::Error);
+        assert_eq!(violations[2].severity, Severity::Error);
+        assert_eq!(violations[3].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_grace_period_boundary_is_exclusive() {
+        let mut violations = vec![violation("a.go", Severity::Error)];
+        apply_grace_period(&mut violations, 7, |_| Some(7));
+        assert_eq!(violations[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_parse_added_dates_keeps_oldest() {
+        let log = "\u{0}300\n\nsrc/b.go\nsrc/a.go\n\u{0}100\n\nsrc/a.go\n";
+        let added = parse_added_dates(log);
+        assert_eq!(added[Path::new("src/a.go")], 100);
+        assert_eq!(added[Path::new("src/b.go")], 300);
+    }
+}
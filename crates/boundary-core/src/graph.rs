@@ -1,13 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
 
-use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
 use petgraph::visit::EdgeRef;
 use serde::{Deserialize, Serialize};
 
-use crate::metrics_report::LayerCouplingMatrix;
+use crate::metrics::{main_sequence_zone, round2};
+use crate::metrics_report::{LayerCouplingMatrix, LayerMetric};
 use crate::types::{
     ArchLayer, ArchitectureMode, Component, ComponentId, ComponentKind, Dependency, DependencyKind,
-    SourceLocation,
+    FactoryInfo, FieldAccess, MethodInfo, SourceLocation,
 };
 
 /// Node in the dependency graph
@@ -25,20 +27,180 @@ pub struct GraphNode {
     pub kind: Option<ComponentKind>,
     #[serde(default)]
     pub is_external: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<MethodInfo>,
 }
 
-/// Edge in the dependency graph
+/// Edge in the dependency graph.
+///
+/// There is one edge per `(from, to, kind)`. Adding the same dependency again records
+/// another occurrence in `locations` instead of a parallel edge, so the
+/// [`weight`](Self::weight) of every edge sums to the number of dependencies added.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GraphEdge {
     pub kind: DependencyKind,
-    pub location: SourceLocation,
+    /// Import path of the first occurrence.
     pub import_path: Option<String>,
+    /// Where each occurrence was found, in the order added. Never empty.
+    pub locations: Vec<SourceLocation>,
+}
+
+impl GraphEdge {
+    /// Location of the first occurrence.
+    pub fn location(&self) -> &SourceLocation {
+        &self.locations[0]
+    }
+
+    /// Number of times this dependency occurs.
+    pub fn weight(&self) -> usize {
+        self.locations.len()
+    }
+}
+
+/// A directory/package node in a [`PackageGraph`].
+#[derive(Debug, Clone)]
+pub struct PackageNode {
+    pub package: String,
+    /// Most common layer among the package's components.
+    pub layer: Option<ArchLayer>,
+    /// Location of the package's first component (by file), for reporting.
+    pub location: SourceLocation,
+}
+
+/// Package-level view of a [`DependencyGraph`]: components collapsed into their
+/// packages, edges weighted by the number of component edges between packages.
+pub struct PackageGraph {
+    graph: DiGraph<PackageNode, usize>,
+    index: HashMap<String, NodeIndex>,
+}
+
+impl PackageGraph {
+    pub fn new() -> Self {
+        Self {
+            graph: DiGraph::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Add a package, or return the existing one.
+    pub fn add_package(
+        &mut self,
+        package: &str,
+        layer: Option<ArchLayer>,
+        location: SourceLocation,
+    ) -> NodeIndex {
+        if let Some(&idx) = self.index.get(package) {
+            return idx;
+        }
+        let idx = self.graph.add_node(PackageNode {
+            package: package.to_string(),
+            layer,
+            location,
+        });
+        self.index.insert(package.to_string(), idx);
+        idx
+    }
+
+    /// Record `weight` dependencies from `from` to `to`. Intra-package edges are dropped.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: usize) {
+        if from == to {
+            return;
+        }
+        let (Some(&a), Some(&b)) = (self.index.get(from), self.index.get(to)) else {
+            return;
+        };
+        match self.graph.find_edge(a, b) {
+            Some(e) => self.graph[e] += weight,
+            None => {
+                self.graph.add_edge(a, b, weight);
+            }
+        }
+    }
+
+    pub fn packages(&self) -> Vec<&PackageNode> {
+        self.graph.node_weights().collect()
+    }
+
+    /// Edges as (from, to, number of component-level edges).
+    pub fn edges(&self) -> Vec<(&PackageNode, &PackageNode, usize)> {
+        self.graph
+            .edge_references()
+            .map(|e| {
+                (
+                    &self.graph[e.source()],
+                    &self.graph[e.target()],
+                    *e.weight(),
+                )
+            })
+            .collect()
+    }
+
+    /// Cycles among packages of the same layer, with each cycle's packages sorted by
+    /// name. Edges between packages of different (or unknown) layers are ignored, so
+    /// these are exactly the strongly connected components of each layer's subgraph.
+    pub fn find_intra_layer_cycles(&self) -> Vec<Vec<&PackageNode>> {
+        let same_layer = petgraph::visit::EdgeFiltered::from_fn(&self.graph, |e| {
+            let layer = self.graph[e.source()].layer;
+            layer.is_some() && layer == self.graph[e.target()].layer
+        });
+        let mut cycles: Vec<Vec<&PackageNode>> = petgraph::algo::tarjan_scc(&same_layer)
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .map(|scc| {
+                let mut cycle: Vec<&PackageNode> =
+                    scc.into_iter().map(|idx| &self.graph[idx]).collect();
+                cycle.sort_by(|a, b| a.package.cmp(&b.package));
+                cycle
+            })
+            .collect();
+        cycles.sort_by(|a, b| a[0].package.cmp(&b[0].package));
+        cycles
+    }
+
+    /// Dependency cycles whose packages span more than one layer, each sorted by
+    /// package name. Unlike [`Self::find_intra_layer_cycles`], every edge counts.
+    pub fn find_cross_layer_cycles(&self) -> Vec<Vec<&PackageNode>> {
+        let mut cycles: Vec<Vec<&PackageNode>> = petgraph::algo::tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .map(|scc| {
+                let mut cycle: Vec<&PackageNode> =
+                    scc.into_iter().map(|idx| &self.graph[idx]).collect();
+                cycle.sort_by(|a, b| a.package.cmp(&b.package));
+                cycle
+            })
+            .filter(|cycle| {
+                let mut layers = cycle.iter().filter_map(|p| p.layer);
+                layers
+                    .next()
+                    .is_some_and(|first| layers.any(|layer| layer != first))
+            })
+            .collect();
+        cycles.sort_by(|a, b| a[0].package.cmp(&b[0].package));
+        cycles
+    }
+
+    /// Number of component-level edges from package `from` to package `to`.
+    pub fn weight(&self, from: &str, to: &str) -> usize {
+        match (self.index.get(from), self.index.get(to)) {
+            (Some(&a), Some(&b)) => self.graph.find_edge(a, b).map_or(0, |e| self.graph[e]),
+            _ => 0,
+        }
+    }
+}
+
+impl Default for PackageGraph {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Directed dependency graph of architectural components.
 pub struct DependencyGraph {
     graph: DiGraph<GraphNode, GraphEdge>,
     index: HashMap<ComponentId, NodeIndex>,
+    factories: Vec<FactoryInfo>,
+    field_accesses: Vec<FieldAccess>,
 }
 
 impl DependencyGraph {
@@ -46,12 +208,38 @@ impl DependencyGraph {
         Self {
             graph: DiGraph::new(),
             index: HashMap::new(),
+            factories: Vec::new(),
+            field_accesses: Vec::new(),
         }
     }
 
-    /// Add a component as a node. Returns the node index.
+    /// Record a factory function. Factories are not nodes; they are only checked
+    /// against the components they return.
+    pub fn add_factory(&mut self, factory: &FactoryInfo) {
+        self.factories.push(factory.clone());
+    }
+
+    /// All recorded factory functions.
+    pub fn factories(&self) -> &[FactoryInfo] {
+        &self.factories
+    }
+
+    /// Record a field access. Like factories, accesses are not edges; they are only
+    /// checked against the entities whose fields they read.
+    pub fn add_field_access(&mut self, access: &FieldAccess) {
+        self.field_accesses.push(access.clone());
+    }
+
+    /// All recorded field accesses.
+    pub fn field_accesses(&self) -> &[FieldAccess] {
+        &self.field_accesses
+    }
+
+    /// Add a component as a node. Returns the node index. A node created earlier for
+    /// a dependency on the component takes on its details.
     pub fn add_component(&mut self, component: &Component) -> NodeIndex {
-        if let Some(&idx) = self.index.get(&component.id) {
+        let existing = self.index.get(&component.id).copied();
+        if let Some(idx) = existing.filter(|&idx| self.graph[idx].kind.is_some()) {
             return idx;
         }
         let node = GraphNode {
@@ -63,12 +251,34 @@ impl DependencyGraph {
             location: component.location.clone(),
             kind: Some(component.kind.clone()),
             is_external: false,
+            methods: component.methods.clone(),
         };
+        if let Some(idx) = existing {
+            self.graph[idx] = node;
+            return idx;
+        }
         let idx = self.graph.add_node(node);
         self.index.insert(component.id.clone(), idx);
         idx
     }
 
+    /// Refresh a component's node after methods declared apart from it were added
+    /// to `component`.
+    pub fn update_methods(&mut self, component: &Component) {
+        if let Some(&idx) = self.index.get(&component.id) {
+            let node = &mut self.graph[idx];
+            node.methods = component.methods.clone();
+            node.kind = Some(component.kind.clone());
+        }
+    }
+
+    /// Whether `id` was added as a component, not only as a dependency end.
+    pub fn has_component(&self, id: &ComponentId) -> bool {
+        self.index
+            .get(id)
+            .is_some_and(|&idx| self.graph[idx].kind.is_some())
+    }
+
     /// Ensure a component ID exists as a node (create a minimal node if needed).
     pub fn ensure_node(
         &mut self,
@@ -92,32 +302,44 @@ impl DependencyGraph {
         }
         let node = GraphNode {
             id: id.clone(),
-            name: id.0.clone(),
+            name: id.untagged().to_string(),
             layer,
             is_cross_cutting,
             architecture_mode,
             location: SourceLocation::default(),
             kind: None,
             is_external: false,
+            methods: Vec::new(),
         };
         let idx = self.graph.add_node(node);
         self.index.insert(id.clone(), idx);
         idx
     }
 
-    /// Add a dependency as an edge.
+    /// Add a dependency as an edge. Adding the same `(from, to)` dependency of the
+    /// same kind again records the new location on the existing edge.
     pub fn add_dependency(&mut self, dep: &Dependency) {
         let from_idx = self.ensure_node(&dep.from, None, false);
         let to_idx = self.ensure_node(&dep.to, None, false);
+        let existing = self
+            .graph
+            .edges_connecting(from_idx, to_idx)
+            .find(|e| e.weight().kind == dep.kind)
+            .map(|e| e.id());
+        if let Some(e) = existing {
+            self.graph[e].locations.push(dep.location.clone());
+            return;
+        }
         let edge = GraphEdge {
             kind: dep.kind.clone(),
-            location: dep.location.clone(),
             import_path: dep.import_path.clone(),
+            locations: vec![dep.location.clone()],
         };
         self.graph.add_edge(from_idx, to_idx, edge);
     }
 
-    /// Iterate over all edges with their source and target nodes.
+    /// Iterate over all edges with their source and target nodes, one per
+    /// `(from, to, kind)`. Each edge carries its [`weight`](GraphEdge::weight).
     pub fn edges_with_nodes(&self) -> Vec<(&GraphNode, &GraphNode, &GraphEdge)> {
         self.graph
             .edge_references()
@@ -129,6 +351,20 @@ impl DependencyGraph {
             .collect()
     }
 
+    /// Every occurrence of every dependency, with its source and target nodes and the
+    /// location it was found at. Violations reported per dependency use this, so a
+    /// repeated import is reported everywhere it occurs.
+    pub fn edge_occurrences(&self) -> Vec<(&GraphNode, &GraphNode, &GraphEdge, &SourceLocation)> {
+        self.edges_with_nodes()
+            .into_iter()
+            .flat_map(|(src, tgt, edge)| {
+                edge.locations
+                    .iter()
+                    .map(move |location| (src, tgt, edge, location))
+            })
+            .collect()
+    }
+
     /// Find cycles using DFS. Returns groups of component IDs that form cycles.
     pub fn find_cycles(&self) -> Vec<Vec<ComponentId>> {
         let sccs = petgraph::algo::kosaraju_scc(&self.graph);
@@ -138,6 +374,77 @@ impl DependencyGraph {
             .collect()
     }
 
+    /// Shortest chain of edges from component `from` to component `to`, or `None` when
+    /// `to` is unreachable. When `from` and `to` are the same, the chain is the
+    /// shortest cycle through that component.
+    pub fn path_between(
+        &self,
+        from: &ComponentId,
+        to: &ComponentId,
+    ) -> Option<Vec<(&GraphNode, &GraphNode, &GraphEdge)>> {
+        self.shortest_path(|n| &n.id, from, to)
+    }
+
+    /// Like [`Self::path_between`], between packages as named by
+    /// [`Self::collapse_to_packages`]: each edge leaves a component of one package for a
+    /// component of another (or the same) package.
+    pub fn package_path_between(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Option<Vec<(&GraphNode, &GraphNode, &GraphEdge)>> {
+        self.shortest_path(self.package_resolver(), from.to_string(), to.to_string())
+    }
+
+    /// Breadth-first search over edges, treating nodes with the same `key` as one.
+    fn shortest_path<'a, K: Hash + Eq + Clone>(
+        &'a self,
+        key: impl Fn(&'a GraphNode) -> K,
+        from: K,
+        to: K,
+    ) -> Option<Vec<(&'a GraphNode, &'a GraphNode, &'a GraphEdge)>> {
+        let mut outgoing: HashMap<K, Vec<EdgeIndex>> = HashMap::new();
+        for e in self.graph.edge_references() {
+            outgoing
+                .entry(key(&self.graph[e.source()]))
+                .or_default()
+                .push(e.id());
+        }
+
+        // The edge each key was first reached through, to walk the path back.
+        let mut reached_by: HashMap<K, EdgeIndex> = HashMap::new();
+        let mut seen: HashSet<K> = HashSet::from([from.clone()]);
+        let mut queue: VecDeque<K> = VecDeque::from([from.clone()]);
+        while let Some(current) = queue.pop_front() {
+            for &e in outgoing.get(&current).into_iter().flatten() {
+                let (_, target) = self.graph.edge_endpoints(e)?;
+                let next = key(&self.graph[target]);
+                if next == to {
+                    let mut path = vec![e];
+                    let mut at = current;
+                    while at != from {
+                        let back = reached_by[&at];
+                        path.push(back);
+                        at = key(&self.graph[self.graph.edge_endpoints(back)?.0]);
+                    }
+                    path.reverse();
+                    return path
+                        .into_iter()
+                        .map(|e| {
+                            let (source, target) = self.graph.edge_endpoints(e)?;
+                            Some((&self.graph[source], &self.graph[target], &self.graph[e]))
+                        })
+                        .collect();
+                }
+                if seen.insert(next.clone()) {
+                    reached_by.insert(next.clone(), e);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
     /// Count ports and adapters in the graph.
     pub fn node_count(&self) -> usize {
         self.graph.node_count()
@@ -175,59 +482,283 @@ impl DependencyGraph {
         counts
     }
 
-    /// Build a layer coupling matrix from edge data.
+    /// Build a layer coupling matrix from edge data, counting each edge by its weight.
     pub fn layer_coupling_matrix(&self) -> LayerCouplingMatrix {
         let mut matrix = LayerCouplingMatrix::new();
         for edge in self.graph.edge_references() {
             let src = &self.graph[edge.source()];
             let tgt = &self.graph[edge.target()];
             if let (Some(from_layer), Some(to_layer)) = (src.layer, tgt.layer) {
-                matrix.increment(&from_layer, &to_layer);
+                matrix.add(&from_layer, &to_layer, edge.weight().weight());
             }
         }
         matrix
     }
 
-    /// Calculate max dependency depth using BFS from each root node.
-    pub fn max_dependency_depth(&self) -> usize {
-        use petgraph::visit::Bfs;
-        let mut max_depth = 0;
-        // Find root nodes (no incoming edges)
+    /// Afferent (Ca) and efferent (Ce) coupling of each layer, keyed by layer: Ca counts
+    /// the components in other layers that depend on the layer, Ce the layer's
+    /// components that depend on other layers. External, cross-cutting and
+    /// unclassified nodes are left out.
+    pub fn layer_couplings(&self) -> HashMap<ArchLayer, (usize, usize)> {
+        let mut afferent: HashSet<(ArchLayer, NodeIndex)> = HashSet::new();
+        let mut efferent: HashSet<(ArchLayer, NodeIndex)> = HashSet::new();
+        for edge in self.graph.edge_references() {
+            let (src, tgt) = (&self.graph[edge.source()], &self.graph[edge.target()]);
+            if [src, tgt]
+                .iter()
+                .any(|n| n.is_external || n.is_cross_cutting)
+            {
+                continue;
+            }
+            let (Some(from), Some(to)) = (src.layer, tgt.layer) else {
+                continue;
+            };
+            if from != to {
+                afferent.insert((to, edge.source()));
+                efferent.insert((from, edge.source()));
+            }
+        }
+
+        let mut couplings: HashMap<ArchLayer, (usize, usize)> = HashMap::new();
+        for (layer, _) in afferent {
+            couplings.entry(layer).or_default().0 += 1;
+        }
+        for (layer, _) in efferent {
+            couplings.entry(layer).or_default().1 += 1;
+        }
+        couplings
+    }
+
+    /// R.C. Martin metrics for every layer with components, innermost first.
+    /// Abstractness counts ports against all components extracted in the layer;
+    /// import-target nodes without a component kind are not counted.
+    pub fn layer_metrics(&self) -> Vec<LayerMetric> {
+        let mut types: HashMap<ArchLayer, (usize, usize)> = HashMap::new();
+        for node in self.graph.node_weights() {
+            if node.is_external || node.is_cross_cutting {
+                continue;
+            }
+            let (Some(layer), Some(kind)) = (node.layer, &node.kind) else {
+                continue;
+            };
+            let (ports, total) = types.entry(layer).or_default();
+            *total += 1;
+            if matches!(kind, ComponentKind::Port(_)) {
+                *ports += 1;
+            }
+        }
+
+        let couplings = self.layer_couplings();
+        let mut metrics: Vec<LayerMetric> = types
+            .into_iter()
+            .map(|(layer, (ports, total))| {
+                let (ca, ce) = couplings.get(&layer).copied().unwrap_or_default();
+                let a = ports as f64 / total as f64;
+                let i = if ca + ce == 0 {
+                    0.0
+                } else {
+                    ce as f64 / (ca + ce) as f64
+                };
+                let d = (a + i - 1.0).abs();
+                LayerMetric {
+                    layer,
+                    afferent_coupling: ca,
+                    efferent_coupling: ce,
+                    abstractness: round2(a),
+                    instability: round2(i),
+                    distance: round2(d),
+                    zone: main_sequence_zone(a, i, d),
+                }
+            })
+            .collect();
+        metrics.sort_by_key(|m| m.layer.depth());
+        metrics
+    }
+
+    /// Distinct nodes each internal node depends on, with the location of its first
+    /// dependency: the node's out-degree with parallel edges and self-loops folded.
+    /// External and cross-cutting nodes are left out on both ends, as are nodes
+    /// without dependencies.
+    pub fn out_degree(&self) -> Vec<(&GraphNode, Vec<&GraphNode>, &SourceLocation)> {
+        let counted = |n: &GraphNode| !n.is_external && !n.is_cross_cutting;
+
+        let mut out = Vec::new();
         for idx in self.graph.node_indices() {
-            let has_incoming = self
+            let node = &self.graph[idx];
+            if !counted(node) {
+                continue;
+            }
+            let mut edges: Vec<_> = self
                 .graph
-                .neighbors_directed(idx, petgraph::Direction::Incoming)
-                .next()
-                .is_some();
-            if !has_incoming {
-                let mut bfs = Bfs::new(&self.graph, idx);
-                let mut depth = 0;
-                let mut current_level_end = idx;
-                let mut next_level_end = idx;
-                while let Some(node) = bfs.next(&self.graph) {
-                    if node == current_level_end || node == idx {
-                        // Check neighbors to find end of next level
-                        for neighbor in self.graph.neighbors(node) {
-                            next_level_end = neighbor;
-                        }
-                    }
-                    for neighbor in self.graph.neighbors(node) {
-                        next_level_end = neighbor;
-                    }
-                    if node == current_level_end && node != idx {
-                        depth += 1;
-                        current_level_end = next_level_end;
-                    }
-                }
-                // Simple approach: count edges in longest path from root
-                max_depth = max_depth.max(depth);
+                .edges(idx)
+                .filter(|e| e.target() != idx && counted(&self.graph[e.target()]))
+                .collect();
+            let Some(first) = edges
+                .iter()
+                .flat_map(|e| &e.weight().locations)
+                .min_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)))
+            else {
+                continue;
+            };
+            edges.sort_unstable_by_key(|e| e.target());
+            edges.dedup_by_key(|e| e.target());
+            let targets = edges.iter().map(|e| &self.graph[e.target()]).collect();
+            out.push((node, targets, first));
+        }
+        out
+    }
+
+    /// Number of distinct `layer` nodes each internal `layer` node depends on, with the
+    /// location of its first such dependency. External and cross-cutting nodes are
+    /// left out, as are nodes without same-layer dependencies.
+    pub fn fan_out_within_layer(
+        &self,
+        layer: ArchLayer,
+    ) -> Vec<(&GraphNode, usize, &SourceLocation)> {
+        let in_layer =
+            |n: &GraphNode| n.layer == Some(layer) && !n.is_external && !n.is_cross_cutting;
+
+        let mut fan_out = Vec::new();
+        for idx in self.graph.node_indices() {
+            let node = &self.graph[idx];
+            if !in_layer(node) {
+                continue;
+            }
+            let mut edges: Vec<_> = self
+                .graph
+                .edges(idx)
+                .filter(|e| e.target() != idx && in_layer(&self.graph[e.target()]))
+                .collect();
+            let Some(first) = edges
+                .iter()
+                .flat_map(|e| &e.weight().locations)
+                .min_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)))
+            else {
+                continue;
+            };
+            edges.sort_unstable_by_key(|e| e.target());
+            edges.dedup_by_key(|e| e.target());
+            fan_out.push((node, edges.len(), first));
+        }
+        fan_out
+    }
+
+    /// Collapse the graph into packages (directories).
+    ///
+    /// External and cross-cutting nodes are left out. Import targets that name a
+    /// package by module path (e.g. Go `<package>` nodes) are folded into the source
+    /// package they refer to when one matches.
+    pub fn collapse_to_packages(&self) -> PackageGraph {
+        let included = |n: &GraphNode| !n.is_external && !n.is_cross_cutting;
+        let package_of = self.package_resolver();
+
+        // Majority layer and first location per package.
+        let mut layer_votes: HashMap<String, HashMap<ArchLayer, usize>> = HashMap::new();
+        let mut locations: HashMap<String, SourceLocation> = HashMap::new();
+        for node in self.graph.node_weights().filter(|n| included(n)) {
+            let pkg = package_of(node);
+            let votes = layer_votes.entry(pkg.clone()).or_default();
+            if let Some(layer) = node.layer {
+                *votes.entry(layer).or_insert(0) += 1;
+            }
+            let has_file = !node.location.file.as_os_str().is_empty();
+            let entry = locations.entry(pkg).or_default();
+            if has_file && (entry.file.as_os_str().is_empty() || node.location.file < entry.file) {
+                *entry = node.location.clone();
+            }
+        }
+
+        let mut names: Vec<&String> = layer_votes.keys().collect();
+        names.sort();
+        let mut packages = PackageGraph::new();
+        for pkg in names {
+            let layer = layer_votes[pkg]
+                .iter()
+                .max_by_key(|(layer, count)| (**count, std::cmp::Reverse(layer.depth())))
+                .map(|(layer, _)| *layer);
+            packages.add_package(pkg, layer, locations[pkg].clone());
+        }
+
+        for edge in self.graph.edge_references() {
+            let src = &self.graph[edge.source()];
+            let tgt = &self.graph[edge.target()];
+            if included(src) && included(tgt) {
+                packages.add_edge(&package_of(src), &package_of(tgt), edge.weight().weight());
+            }
+        }
+        packages
+    }
+
+    /// Maps a node to its package, resolving import paths (`github.com/acme/app/internal/order`)
+    /// to the source package they name (`./internal/order`).
+    pub(crate) fn package_resolver(&self) -> impl Fn(&GraphNode) -> String + '_ {
+        let mut source_packages: Vec<&str> = self
+            .graph
+            .node_weights()
+            .filter(|n| !n.is_external && !n.is_cross_cutting && n.kind.is_some())
+            .map(|n| n.id.package())
+            .collect();
+        source_packages.sort_unstable();
+        source_packages.dedup();
+        move |n: &GraphNode| -> String {
+            let pkg = n.id.package();
+            if source_packages.binary_search(&pkg).is_ok() {
+                return pkg.to_string();
+            }
+            source_packages
+                .iter()
+                .find(|src| pkg_import_match(src, pkg))
+                .map_or_else(|| pkg.to_string(), |src| src.to_string())
+        }
+    }
+
+    /// Longest dependency chain, in edges, from any root to any node.
+    ///
+    /// Computed over the condensation of the graph (each strongly connected component
+    /// collapsed to a single node), so it terminates on cyclic graphs. All nodes in a
+    /// cycle share their component's depth, and edges inside a cycle add nothing.
+    pub fn max_dependency_depth(&self) -> usize {
+        self.node_depths().into_iter().max().unwrap_or(0)
+    }
+
+    /// Mean of [`max_dependency_depth`](Self::max_dependency_depth)'s per-node depths.
+    pub fn avg_dependency_depth(&self) -> f64 {
+        let depths = self.node_depths();
+        if depths.is_empty() {
+            return 0.0;
+        }
+        depths.iter().sum::<usize>() as f64 / depths.len() as f64
+    }
+
+    /// Depth of every node, indexed by `NodeIndex`: the longest path reaching its
+    /// strongly connected component in the condensed DAG.
+    fn node_depths(&self) -> Vec<usize> {
+        // Tarjan yields components in reverse topological order.
+        let sccs = petgraph::algo::tarjan_scc(&self.graph);
+        let mut scc_of = vec![0; self.graph.node_count()];
+        for (i, scc) in sccs.iter().enumerate() {
+            for idx in scc {
+                scc_of[idx.index()] = i;
             }
         }
-        // Fallback: use edge count as rough depth estimate
-        if max_depth == 0 && self.graph.edge_count() > 0 {
-            max_depth = 1;
+
+        let mut scc_depth = vec![0usize; sccs.len()];
+        for (i, scc) in sccs.iter().enumerate().rev() {
+            let depth = scc
+                .iter()
+                .flat_map(|&idx| {
+                    self.graph
+                        .neighbors_directed(idx, petgraph::Direction::Incoming)
+                })
+                .map(|pred| scc_of[pred.index()])
+                .filter(|&pred_scc| pred_scc != i)
+                .map(|pred_scc| scc_depth[pred_scc] + 1)
+                .max()
+                .unwrap_or(0);
+            scc_depth[i] = depth;
         }
-        max_depth
+
+        scc_of.into_iter().map(|i| scc_depth[i]).collect()
     }
 }
 
@@ -237,6 +768,26 @@ impl Default for DependencyGraph {
     }
 }
 
+/// Returns true if a filesystem package path and an import path refer to the same package.
+///
+/// Matches by comparing trailing path segments:
+/// - If both have ≥ 2 segments, the last two must agree.
+/// - If either has only 1 segment, the last segment must agree.
+pub(crate) fn pkg_import_match(pkg_path: &str, import_path: &str) -> bool {
+    let pkg_last = pkg_path.split('/').next_back().unwrap_or("");
+    let imp_last = import_path.split('/').next_back().unwrap_or("");
+    if pkg_last.is_empty() || pkg_last != imp_last {
+        return false;
+    }
+    match (
+        pkg_path.split('/').rev().nth(1),
+        import_path.split('/').rev().nth(1),
+    ) {
+        (Some(p), Some(i)) => p == i,
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,7 +812,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -294,6 +847,26 @@ mod tests {
         assert_eq!(edges.len(), 1);
     }
 
+    #[test]
+    fn test_repeated_dependency_accumulates_weight() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("a", "A", Some(ArchLayer::Domain)));
+        graph.add_component(&make_component("b", "B", Some(ArchLayer::Infrastructure)));
+
+        let mut second = make_dep("a", "b");
+        second.location.file = PathBuf::from("b.go");
+        graph.add_dependency(&make_dep("a", "b"));
+        graph.add_dependency(&second);
+        let edges = graph.edges_with_nodes();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].2.weight(), 2);
+        let files: Vec<_> = edges[0].2.locations.iter().map(|l| &l.file).collect();
+        assert_eq!(files, [&PathBuf::from("test.go"), &PathBuf::from("b.go")]);
+
+        let matrix = graph.layer_coupling_matrix();
+        assert_eq!(matrix.matrix["domain"]["infrastructure"], 2);
+    }
+
     #[test]
     fn test_find_cycles() {
         let mut graph = DependencyGraph::new();
@@ -309,6 +882,105 @@ mod tests {
         assert!(!cycles.is_empty(), "should detect cycle");
     }
 
+    fn ids<'a>(path: &[(&'a GraphNode, &'a GraphNode, &'a GraphEdge)]) -> Vec<&'a str> {
+        let mut ids: Vec<&str> = path.iter().map(|(from, _, _)| from.id.0.as_str()).collect();
+        ids.extend(path.last().map(|(_, to, _)| to.id.0.as_str()));
+        ids
+    }
+
+    #[test]
+    fn test_path_between_takes_shortest_chain() {
+        let mut graph = DependencyGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_component(&make_component(id, id, None));
+        }
+        graph.add_dependency(&make_dep("a", "b"));
+        graph.add_dependency(&make_dep("b", "c"));
+        graph.add_dependency(&make_dep("c", "d"));
+        graph.add_dependency(&make_dep("b", "d"));
+
+        let a = ComponentId("a".to_string());
+        let d = ComponentId("d".to_string());
+        assert_eq!(ids(&graph.path_between(&a, &d).unwrap()), ["a", "b", "d"]);
+        assert!(graph.path_between(&d, &a).is_none());
+        assert!(graph.path_between(&a, &a).is_none(), "a is not on a cycle");
+    }
+
+    #[test]
+    fn test_path_between_same_component_is_cycle() {
+        let mut graph = DependencyGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_component(&make_component(id, id, None));
+        }
+        graph.add_dependency(&make_dep("a", "b"));
+        graph.add_dependency(&make_dep("b", "c"));
+        graph.add_dependency(&make_dep("c", "a"));
+
+        let a = ComponentId("a".to_string());
+        assert_eq!(
+            ids(&graph.path_between(&a, &a).unwrap()),
+            ["a", "b", "c", "a"]
+        );
+    }
+
+    #[test]
+    fn test_package_path_between_crosses_components() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("domain::User", "User", None));
+        graph.add_component(&make_component("domain::Order", "Order", None));
+        graph.add_component(&make_component("infra::Store", "Store", None));
+        graph.add_dependency(&make_dep("domain::Order", "infra::Store"));
+        graph.add_dependency(&make_dep("infra::Store", "domain::User"));
+
+        let cycle = graph.package_path_between("domain", "domain").unwrap();
+        assert_eq!(
+            ids(&cycle),
+            ["domain::Order", "infra::Store", "domain::User"]
+        );
+    }
+
+    #[test]
+    fn test_dependency_depth_on_chain() {
+        let mut graph = DependencyGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_component(&make_component(id, id, None));
+        }
+        graph.add_dependency(&make_dep("a", "b"));
+        graph.add_dependency(&make_dep("b", "c"));
+
+        assert_eq!(graph.max_dependency_depth(), 2);
+        assert!((graph.avg_dependency_depth() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dependency_depth_collapses_cycles() {
+        // root -> {a -> b -> c -> a} -> leaf: the cycle counts as one step.
+        let mut graph = DependencyGraph::new();
+        for id in ["root", "a", "b", "c", "leaf"] {
+            graph.add_component(&make_component(id, id, None));
+        }
+        graph.add_dependency(&make_dep("root", "a"));
+        graph.add_dependency(&make_dep("a", "b"));
+        graph.add_dependency(&make_dep("b", "c"));
+        graph.add_dependency(&make_dep("c", "a"));
+        graph.add_dependency(&make_dep("c", "leaf"));
+
+        assert_eq!(graph.max_dependency_depth(), 2);
+        // root 0, a/b/c share depth 1, leaf 2.
+        assert!((graph.avg_dependency_depth() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dependency_depth_of_pure_cycle_is_zero() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("a", "A", None));
+        graph.add_component(&make_component("b", "B", None));
+        graph.add_dependency(&make_dep("a", "b"));
+        graph.add_dependency(&make_dep("b", "a"));
+
+        assert_eq!(graph.max_dependency_depth(), 0);
+    }
+
     #[test]
     fn test_no_duplicate_nodes() {
         let mut graph = DependencyGraph::new();
@@ -318,4 +990,150 @@ mod tests {
         graph.add_component(&c1); // duplicate
         assert_eq!(graph.node_count(), 1);
     }
+
+    #[test]
+    fn test_same_name_components_in_different_languages_do_not_collide() {
+        let mut graph = DependencyGraph::new();
+        let mut go = make_component("", "User", Some(ArchLayer::Domain));
+        go.id = ComponentId::with_language("go", "domain/user", "User");
+        let mut ts = make_component("", "User", Some(ArchLayer::Domain));
+        ts.id = ComponentId::with_language("typescript", "domain/user", "User");
+
+        graph.add_component(&go);
+        graph.add_component(&ts);
+        assert_eq!(graph.node_count(), 2);
+        let ids: Vec<&ComponentId> = graph.nodes().iter().map(|n| &n.id).collect();
+        assert!(ids.contains(&&go.id));
+        assert!(ids.contains(&&ts.id));
+    }
+
+    #[test]
+    fn test_collapse_to_packages_folds_import_targets() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component(
+            "app/domain/user::User",
+            "User",
+            Some(ArchLayer::Domain),
+        ));
+        graph.add_component(&make_component(
+            "app/infra/db::Store",
+            "Store",
+            Some(ArchLayer::Infrastructure),
+        ));
+        // Go-style import target named by module path, not directory.
+        let import_target = ComponentId("github.com/acme/app/infra/db::<package>".to_string());
+        graph.ensure_node(&import_target, Some(ArchLayer::Infrastructure), false);
+        graph.add_dependency(&make_dep("app/domain/user::User", &import_target.0));
+        graph.add_dependency(&make_dep("app/domain/user::User", "app/infra/db::Store"));
+
+        let packages = graph.collapse_to_packages();
+        assert_eq!(packages.packages().len(), 2);
+        assert_eq!(packages.weight("app/domain/user", "app/infra/db"), 2);
+        assert_eq!(packages.weight("app/infra/db", "app/domain/user"), 0);
+    }
+
+    #[test]
+    fn test_intra_layer_cycles_ignore_cross_layer_edges() {
+        let mut packages = PackageGraph::new();
+        let loc = SourceLocation::default;
+        packages.add_package("domain/order", Some(ArchLayer::Domain), loc());
+        packages.add_package("domain/user", Some(ArchLayer::Domain), loc());
+        packages.add_package("infra/db", Some(ArchLayer::Infrastructure), loc());
+        packages.add_edge("domain/user", "domain/order", 1);
+        packages.add_edge("domain/order", "domain/user", 1);
+        // A cycle through another layer is a layer violation, not an intra-layer cycle.
+        packages.add_edge("domain/user", "infra/db", 1);
+        packages.add_edge("infra/db", "domain/user", 1);
+
+        let cycles = packages.find_intra_layer_cycles();
+        assert_eq!(cycles.len(), 1);
+        let names: Vec<&str> = cycles[0].iter().map(|p| p.package.as_str()).collect();
+        assert_eq!(names, ["domain/order", "domain/user"]);
+    }
+
+    #[test]
+    fn test_cross_layer_cycles_skip_single_layer_cycles() {
+        let mut packages = PackageGraph::new();
+        let loc = SourceLocation::default;
+        packages.add_package("domain/order", Some(ArchLayer::Domain), loc());
+        packages.add_package("domain/user", Some(ArchLayer::Domain), loc());
+        packages.add_package("domain/catalog", Some(ArchLayer::Domain), loc());
+        packages.add_package("infra/db", Some(ArchLayer::Infrastructure), loc());
+        packages.add_edge("domain/user", "domain/catalog", 1);
+        packages.add_edge("domain/catalog", "domain/user", 1);
+        packages.add_edge("domain/order", "infra/db", 1);
+        packages.add_edge("infra/db", "domain/order", 1);
+
+        let cycles = packages.find_cross_layer_cycles();
+        assert_eq!(cycles.len(), 1);
+        let names: Vec<&str> = cycles[0].iter().map(|p| p.package.as_str()).collect();
+        assert_eq!(names, ["domain/order", "infra/db"]);
+    }
+
+    /// Domain: entity `order` and port `repo`. Application: `svc` uses both.
+    /// Infrastructure: `pg` implements the port and also reaches into `svc`.
+    fn layered_graph() -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("order", "Order", Some(ArchLayer::Domain)));
+        let mut port = make_component("repo", "Repo", Some(ArchLayer::Domain));
+        port.kind = ComponentKind::Port(PortInfo {
+            name: "Repo".to_string(),
+            methods: vec![],
+        });
+        graph.add_component(&port);
+        graph.add_component(&make_component("svc", "Svc", Some(ArchLayer::Application)));
+        graph.add_component(&make_component("pg", "Pg", Some(ArchLayer::Infrastructure)));
+        graph.add_dependency(&make_dep("svc", "order"));
+        graph.add_dependency(&make_dep("svc", "repo"));
+        graph.add_dependency(&make_dep("pg", "repo"));
+        graph.add_dependency(&make_dep("pg", "svc"));
+        graph.add_dependency(&make_dep("order", "repo"));
+        graph
+    }
+
+    #[test]
+    fn test_layer_couplings_count_components() {
+        let couplings = layered_graph().layer_couplings();
+        // svc and pg depend on the domain; svc counts once despite two edges, and
+        // the intra-layer order -> repo edge is not coupling.
+        assert_eq!(couplings[&ArchLayer::Domain], (2, 0));
+        assert_eq!(couplings[&ArchLayer::Application], (1, 1));
+        assert_eq!(couplings[&ArchLayer::Infrastructure], (0, 1));
+    }
+
+    #[test]
+    fn test_layer_metrics() {
+        let metrics = layered_graph().layer_metrics();
+        let rows: Vec<(ArchLayer, f64, f64, f64)> = metrics
+            .iter()
+            .map(|m| (m.layer, m.abstractness, m.instability, m.distance))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (ArchLayer::Domain, 0.5, 0.0, 0.5),
+                (ArchLayer::Application, 0.0, 0.5, 0.5),
+                (ArchLayer::Infrastructure, 0.0, 1.0, 0.0),
+            ]
+        );
+        assert!(metrics.iter().all(|m| m.zone.is_none()));
+    }
+
+    #[test]
+    fn test_layer_metrics_skip_external_and_cross_cutting() {
+        let mut graph = layered_graph();
+        let mut logger = make_component("log", "Logger", Some(ArchLayer::Infrastructure));
+        logger.is_cross_cutting = true;
+        graph.add_component(&logger);
+        graph.add_dependency(&make_dep("order", "log"));
+        graph.add_component(&make_component(
+            "ext",
+            "Ext",
+            Some(ArchLayer::Infrastructure),
+        ));
+        graph.mark_external(&ComponentId("ext".to_string()));
+        graph.add_dependency(&make_dep("svc", "ext"));
+
+        assert_eq!(graph.layer_couplings(), layered_graph().layer_couplings());
+    }
 }
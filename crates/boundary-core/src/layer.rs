@@ -1,8 +1,36 @@
+use std::borrow::Cow;
+
 use globset::{Glob, GlobSet, GlobSetBuilder};
 
 use crate::config::LayersConfig;
 use crate::types::{ArchLayer, ArchitectureMode};
 
+/// Directory names that place an import in a layer when no glob pattern does, in
+/// priority order. A segment starting with one of the stems matches, which covers
+/// plurals like `services` and `adapters`; the words must match a whole segment.
+const IMPORT_KEYWORDS: &[(ArchLayer, &[&str], &[&str])] = &[
+    (
+        ArchLayer::Domain,
+        &["domain", "entity", "model"],
+        &["entities"],
+    ),
+    (
+        ArchLayer::Application,
+        &["application", "usecase", "service"],
+        &["use_cases", "use-cases"],
+    ),
+    (
+        ArchLayer::Infrastructure,
+        &["infrastructure", "adapter", "repository", "persistence"],
+        &["repositories", "store", "stores", "dao", "daos"],
+    ),
+    (
+        ArchLayer::Presentation,
+        &["presentation", "handler", "cmd"],
+        &["api"],
+    ),
+];
+
 /// A compiled per-module layer override.
 struct LayerOverride {
     scope: GlobSet,
@@ -28,7 +56,59 @@ pub struct LayerClassifier {
     default_mode: ArchitectureMode,
 }
 
-fn build_globset(patterns: &[String]) -> GlobSet {
+/// Layer named by a `boundary:layer <layer>` directive in a comment line, e.g.
+/// `// boundary:layer domain` or `# boundary:layer infrastructure`. The first
+/// directive naming a known layer wins.
+pub fn layer_annotation(content: &str) -> Option<ArchLayer> {
+    if !content.contains("boundary:layer") {
+        return None;
+    }
+    content.lines().find_map(|line| {
+        let line = line.trim_start();
+        let comment = ["//", "/*", "*", "#"]
+            .iter()
+            .find_map(|marker| line.strip_prefix(marker))?;
+        let directive = comment
+            .trim_start_matches(['/', '*', '!'])
+            .trim_start()
+            .strip_prefix("boundary:layer")?;
+        if !directive.starts_with(char::is_whitespace) {
+            return None;
+        }
+        directive.split_whitespace().next()?.parse().ok()
+    })
+}
+
+/// Resolve a relative import (`./user`, `../../domain/user`) against the directory
+/// of the importing file, so it matches the same layer patterns as the path it
+/// names. Other imports are returned unchanged.
+pub fn resolve_relative_import<'a>(rel_file: &str, import_path: &'a str) -> Cow<'a, str> {
+    let is_relative = import_path == "."
+        || import_path == ".."
+        || import_path.starts_with("./")
+        || import_path.starts_with("../");
+    if !is_relative {
+        return Cow::Borrowed(import_path);
+    }
+    let rel_file = rel_file.replace('\\', "/");
+    let mut segments: Vec<&str> = rel_file
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    segments.pop(); // the importing file itself
+    for segment in import_path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." if segments.last().is_some_and(|s| *s != "..") => {
+                segments.pop();
+            }
+            other => segments.push(other),
+        }
+    }
+    Cow::Owned(segments.join("/"))
+}
+
+pub(crate) fn build_globset(patterns: &[String]) -> GlobSet {
     let mut builder = GlobSetBuilder::new();
     for pattern in patterns {
         if let Ok(glob) = Glob::new(pattern) {
@@ -40,6 +120,44 @@ fn build_globset(patterns: &[String]) -> GlobSet {
         .unwrap_or_else(|_| GlobSetBuilder::new().build().unwrap())
 }
 
+/// `[layers]` patterns that are not valid globs, as `(key, pattern, error)`. The
+/// classifier skips them, so each one leaves its layer matching less than intended.
+pub fn invalid_layer_patterns(config: &LayersConfig) -> Vec<(String, String, String)> {
+    let mut keyed: Vec<(String, &String)> = Vec::new();
+    for (key, patterns) in [
+        ("domain", &config.domain),
+        ("application", &config.application),
+        ("infrastructure", &config.infrastructure),
+        ("presentation", &config.presentation),
+        ("cross_cutting", &config.cross_cutting),
+    ] {
+        keyed.extend(patterns.iter().map(|p| (key.to_string(), p)));
+    }
+    for (i, o) in config.overrides.iter().enumerate() {
+        keyed.push((format!("overrides[{i}].scope"), &o.scope));
+        for (key, patterns) in [
+            ("domain", &o.domain),
+            ("application", &o.application),
+            ("infrastructure", &o.infrastructure),
+            ("presentation", &o.presentation),
+        ] {
+            keyed.extend(
+                patterns
+                    .iter()
+                    .map(|p| (format!("overrides[{i}].{key}"), p)),
+            );
+        }
+    }
+    keyed
+        .into_iter()
+        .filter_map(|(key, pattern)| {
+            Glob::new(pattern)
+                .err()
+                .map(|e| (key, pattern.clone(), e.kind().to_string()))
+        })
+        .collect()
+}
+
 impl LayerClassifier {
     pub fn new(config: &LayersConfig) -> Self {
         let overrides = config
@@ -86,6 +204,12 @@ impl LayerClassifier {
         self.classify_global(normalized)
     }
 
+    /// Classify a source file: a `boundary:layer` directive in its content wins over
+    /// the path-based classification.
+    pub fn classify_file(&self, path: &str, content: &str) -> Option<ArchLayer> {
+        layer_annotation(content).or_else(|| self.classify(path))
+    }
+
     /// Get the architecture mode for a given file path.
     /// Checks overrides first (first scope match wins), falls back to global default.
     pub fn architecture_mode(&self, path: &str) -> ArchitectureMode {
@@ -133,42 +257,18 @@ impl LayerClassifier {
                 return Some(layer);
             }
         }
-        // Fallback: heuristic based on path segments.
+        // Fallback: heuristic based on path segments, checked layer by layer.
         // Also matches bare package aliases (e.g. "infrastructure" from init() call sites).
         let lower = import_path.to_lowercase();
-        let last = lower.split('/').next_back().unwrap_or(&lower);
-        if lower.contains("/domain")
-            || lower.contains("/entity")
-            || lower.contains("/model")
-            || matches!(last, "domain" | "entity" | "model")
-        {
-            Some(ArchLayer::Domain)
-        } else if lower.contains("/application")
-            || lower.contains("/usecase")
-            || lower.contains("/service")
-            || matches!(last, "application" | "usecase" | "service")
-        {
-            Some(ArchLayer::Application)
-        } else if lower.contains("/infrastructure")
-            || lower.contains("/adapter")
-            || lower.contains("/repository")
-            || lower.contains("/persistence")
-            || matches!(
-                last,
-                "infrastructure" | "adapter" | "repository" | "persistence"
-            )
-        {
-            Some(ArchLayer::Infrastructure)
-        } else if lower.contains("/presentation")
-            || lower.contains("/handler")
-            || lower.contains("/api/")
-            || lower.contains("/cmd")
-            || matches!(last, "presentation" | "handler" | "cmd")
-        {
-            Some(ArchLayer::Presentation)
-        } else {
-            None
-        }
+        let segments: Vec<&str> = lower.split('/').filter(|s| !s.is_empty()).collect();
+        IMPORT_KEYWORDS
+            .iter()
+            .find(|(_, stems, words)| {
+                segments.iter().any(|segment| {
+                    stems.iter().any(|stem| segment.starts_with(stem)) || words.contains(segment)
+                })
+            })
+            .map(|(layer, _, _)| *layer)
     }
 
     /// Classify using global patterns only.
@@ -265,6 +365,35 @@ mod tests {
         assert_eq!(classifier.classify("main.go"), None);
     }
 
+    #[test]
+    fn test_invalid_layer_patterns() {
+        let config = LayersConfig {
+            domain: vec!["**/domain/**".to_string(), "src/{domain".to_string()],
+            overrides: vec![LayerOverrideConfig {
+                scope: "services/[a-".to_string(),
+                domain: vec![],
+                application: vec![],
+                infrastructure: vec![],
+                presentation: vec![],
+                architecture_mode: None,
+            }],
+            ..LayersConfig::default()
+        };
+        let invalid = invalid_layer_patterns(&config);
+        let keys: Vec<_> = invalid
+            .iter()
+            .map(|(k, p, _)| (k.as_str(), p.as_str()))
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                ("domain", "src/{domain"),
+                ("overrides[0].scope", "services/[a-")
+            ]
+        );
+        assert!(invalid_layer_patterns(&LayersConfig::default()).is_empty());
+    }
+
     #[test]
     fn test_classify_import() {
         let classifier = LayerClassifier::new(&LayersConfig::default());
@@ -279,6 +408,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_classify_import_plural_and_synonym_directories() {
+        let classifier = LayerClassifier::new(&LayersConfig::default());
+
+        for (path, layer) in [
+            ("internal/repositories/", ArchLayer::Infrastructure),
+            ("internal/models/", ArchLayer::Domain),
+            ("internal/repository", ArchLayer::Infrastructure),
+            ("internal/model", ArchLayer::Domain),
+            ("internal/entities/user", ArchLayer::Domain),
+            ("internal/use_cases/checkout", ArchLayer::Application),
+            ("internal/persistence/sql", ArchLayer::Infrastructure),
+            ("internal/store", ArchLayer::Infrastructure),
+            ("internal/daos/user", ArchLayer::Infrastructure),
+            ("internal/handlers/http", ArchLayer::Presentation),
+        ] {
+            assert_eq!(classifier.classify_import(path), Some(layer), "{path}");
+        }
+        // Synonyms match whole directory names only.
+        assert_eq!(
+            classifier.classify_import("internal/storefront/pricing"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_relative_import() {
+        for (file, import, resolved) in [
+            (
+                "src/infrastructure/db/repo.ts",
+                "../../domain/user",
+                "src/domain/user",
+            ),
+            ("./src/domain/order.ts", "./money", "src/domain/money"),
+            ("src/app.ts", "../../shared/log", "../shared/log"),
+            ("src\\domain\\order.ts", "../core/x", "src/core/x"),
+            ("src/domain/order.ts", "@app/domain", "@app/domain"),
+            ("internal/user/user.go", "github.com/x/y", "github.com/x/y"),
+        ] {
+            assert_eq!(resolve_relative_import(file, import), resolved, "{import}");
+        }
+    }
+
+    #[test]
+    fn test_relative_import_matches_configured_globs() {
+        let config = LayersConfig {
+            domain: vec!["src/core/**".to_string()],
+            ..LayersConfig::default()
+        };
+        let classifier = LayerClassifier::new(&config);
+        let import = "../../core/user";
+        assert_eq!(classifier.classify_import(import), None);
+        let resolved = resolve_relative_import("src/adapters/db/repo.ts", import);
+        assert_eq!(
+            classifier.classify_import(&resolved),
+            Some(ArchLayer::Domain)
+        );
+    }
+
+    #[test]
+    fn test_classify_import_globs_win_over_keywords() {
+        let config = LayersConfig {
+            infrastructure: vec!["**/models/**".to_string()],
+            ..LayersConfig::default()
+        };
+        let classifier = LayerClassifier::new(&config);
+
+        assert_eq!(
+            classifier.classify_import("internal/models/user"),
+            Some(ArchLayer::Infrastructure)
+        );
+    }
+
     #[test]
     fn test_override_scoped_classification() {
         let config = config_with_overrides(vec![LayerOverrideConfig {
@@ -583,4 +785,26 @@ mod tests {
         );
         assert!(classifier.is_cross_cutting("internal/domain/user.go"));
     }
+
+    #[test]
+    fn test_layer_annotation_overrides_path() {
+        let classifier = LayerClassifier::new(&LayersConfig::default());
+        let annotated = "// boundary:layer domain\npackage shared\n";
+        assert_eq!(layer_annotation(annotated), Some(ArchLayer::Domain));
+        assert_eq!(
+            classifier.classify_file("internal/infrastructure/money.go", annotated),
+            Some(ArchLayer::Domain)
+        );
+
+        assert_eq!(
+            layer_annotation("# boundary:layer Infrastructure\n"),
+            Some(ArchLayer::Infrastructure)
+        );
+        assert_eq!(layer_annotation("// boundary:layer nowhere\n"), None);
+        assert_eq!(
+            layer_annotation("let s = \"boundary:layer domain\";\n"),
+            None,
+            "only comments carry the directive"
+        );
+    }
 }
@@ -1,16 +1,29 @@
 pub mod analyzer;
+pub mod baseline;
 pub mod cache;
+pub mod churn;
+pub mod classification;
+pub mod compare;
 pub mod config;
 pub mod custom_rules;
 pub mod evolution;
+pub mod explain;
+pub mod fitness;
+pub mod fix;
 pub mod forensics;
+pub mod generated;
+pub mod grace;
 pub mod graph;
 pub mod layer;
+pub mod lock;
 pub mod metrics;
 pub mod metrics_report;
 pub mod pattern_detection;
 pub mod pipeline;
+pub mod rule;
 pub mod rule_filter;
+pub mod rule_scope;
+pub mod timing;
 pub mod types;
 
 pub use analyzer::{LanguageAnalyzer, ParsedFile};
@@ -19,4 +32,5 @@ pub use graph::DependencyGraph;
 pub use layer::LayerClassifier;
 pub use metrics::{AnalysisResult, ArchitectureScore};
 pub use pattern_detection::{PatternDetection, PatternScore};
+pub use rule::Rule;
 pub use types::*;
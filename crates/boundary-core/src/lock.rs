@@ -0,0 +1,343 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::graph::DependencyGraph;
+use crate::rule::Rule;
+use crate::types::{
+    relative_to_root, ArchLayer, Severity, SourceLocation, Violation, ViolationKind,
+};
+
+/// Default lock file name, written next to `.boundary.toml`.
+pub const LOCK_FILE_NAME: &str = "boundary.lock";
+
+/// Version of the lock file layout. Lock files from a newer version are rejected.
+pub const LOCK_VERSION: u32 = 1;
+
+/// Approved architecture: the components and cross-layer package edges of an
+/// analysis, frozen so later runs can report drift from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchitectureLock {
+    pub version: u32,
+    #[serde(default)]
+    pub components: Vec<LockedComponent>,
+    #[serde(default)]
+    pub edges: Vec<LockedEdge>,
+}
+
+/// An approved component and the layer it belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedComponent {
+    pub id: String,
+    pub layer: ArchLayer,
+}
+
+/// An approved dependency between packages of different layers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedEdge {
+    pub from: String,
+    pub to: String,
+    pub from_layer: ArchLayer,
+    pub to_layer: ArchLayer,
+}
+
+impl ArchitectureLock {
+    /// Capture the classified components and cross-layer package edges of `graph`,
+    /// analyzed at `root`. IDs are stored relative to `root`, so the lock holds
+    /// whichever directory boundary is run from. External, cross-cutting and
+    /// unclassified components are left out.
+    pub fn from_graph(graph: &DependencyGraph, root: &Path) -> Self {
+        let mut components: Vec<LockedComponent> = graph
+            .nodes()
+            .into_iter()
+            .filter(|n| n.kind.is_some() && !n.is_external && !n.is_cross_cutting)
+            .filter_map(|n| {
+                Some(LockedComponent {
+                    id: relative_to_root(&n.id.0, root),
+                    layer: n.layer?,
+                })
+            })
+            .collect();
+        components.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut edges = cross_layer_edges(graph, root);
+        edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+
+        Self {
+            version: LOCK_VERSION,
+            components,
+            edges,
+        }
+    }
+
+    /// Read a lock file, rejecting ones written by a newer version of boundary.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read lock file {}", path.display()))?;
+        let lock: Self = toml::from_str(&content)
+            .with_context(|| format!("failed to parse lock file {}", path.display()))?;
+        if lock.version > LOCK_VERSION {
+            bail!(
+                "lock file {} has version {}, but this boundary supports up to {LOCK_VERSION}; \
+                 it was written by a newer version of boundary",
+                path.display(),
+                lock.version
+            );
+        }
+        Ok(lock)
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string(self).context("failed to serialize lock file")
+    }
+
+    /// Drift from this lock as a [`Rule`] over the project at `root`, so it is
+    /// reported with the built-in rules.
+    pub fn into_rule(self, root: &Path) -> LockDrift {
+        LockDrift {
+            lock: self,
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Compare `graph`, analyzed at `root`, against the lock. Reports locked components
+    /// now in a different layer and cross-layer package edges the lock does not list.
+    /// New components, and anything removed since locking, are not drift.
+    pub fn detect_drift(
+        &self,
+        graph: &DependencyGraph,
+        config: &Config,
+        root: &Path,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let mut push = |subject: String, detail: String, location: SourceLocation| {
+            let kind = ViolationKind::ArchitectureDrift {
+                subject: subject.clone(),
+                detail: detail.clone(),
+            };
+            let severity = config.rules.resolve_severity(&kind, Severity::Error);
+            violations.push(Violation {
+                kind,
+                severity,
+                location,
+                message: format!("Architecture drift: {subject} {detail}"),
+                suggestion: Some(
+                    "Revert the change, or regenerate the lock with `boundary lock --write` \
+                     if the new architecture is approved."
+                        .to_string(),
+                ),
+                fix: None,
+                doc_url: None,
+            });
+        };
+
+        // Locks written by older versions may still carry a leading `./`.
+        let current = Path::new(".");
+        let locked_layers: HashMap<String, ArchLayer> = self
+            .components
+            .iter()
+            .map(|c| (relative_to_root(&c.id, current), c.layer))
+            .collect();
+        let mut nodes = graph.nodes();
+        nodes.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+        for node in nodes {
+            if node.kind.is_none() || node.is_external || node.is_cross_cutting {
+                continue;
+            }
+            let (Some(&locked), Some(current)) = (
+                locked_layers.get(&relative_to_root(&node.id.0, root)),
+                node.layer,
+            ) else {
+                continue;
+            };
+            if locked != current {
+                push(
+                    format!("component '{}'", node.name),
+                    format!("moved from the {locked} layer to the {current} layer"),
+                    node.location.clone(),
+                );
+            }
+        }
+
+        let approved: HashSet<(String, String)> = self
+            .edges
+            .iter()
+            .map(|e| {
+                (
+                    relative_to_root(&e.from, current),
+                    relative_to_root(&e.to, current),
+                )
+            })
+            .collect();
+        let packages = graph.collapse_to_packages();
+        let mut edges: Vec<_> = packages
+            .edges()
+            .into_iter()
+            .filter(|(from, to, _)| {
+                matches!((from.layer, to.layer), (Some(a), Some(b)) if a != b)
+                    && !approved.contains(&(
+                        relative_to_root(&from.package, root),
+                        relative_to_root(&to.package, root),
+                    ))
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.0.package, &a.1.package).cmp(&(&b.0.package, &b.1.package)));
+        for (from, to, _) in edges {
+            let (Some(from_layer), Some(to_layer)) = (from.layer, to.layer) else {
+                continue;
+            };
+            push(
+                format!("package '{}'", from.package),
+                format!(
+                    "has an unapproved {from_layer} -> {to_layer} dependency on '{}'",
+                    to.package
+                ),
+                from.location.clone(),
+            );
+        }
+
+        violations
+    }
+}
+
+/// [`ArchitectureLock`] drift reported as a [`Rule`].
+pub struct LockDrift {
+    lock: ArchitectureLock,
+    root: PathBuf,
+}
+
+impl Rule for LockDrift {
+    fn evaluate(&self, graph: &DependencyGraph, config: &Config) -> Vec<Violation> {
+        self.lock.detect_drift(graph, config, &self.root)
+    }
+}
+
+fn cross_layer_edges(graph: &DependencyGraph, root: &Path) -> Vec<LockedEdge> {
+    graph
+        .collapse_to_packages()
+        .edges()
+        .into_iter()
+        .filter_map(|(from, to, _)| match (from.layer, to.layer) {
+            (Some(from_layer), Some(to_layer)) if from_layer != to_layer => Some(LockedEdge {
+                from: relative_to_root(&from.package, root),
+                to: relative_to_root(&to.package, root),
+                from_layer,
+                to_layer,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    fn component(package: &str, name: &str, layer: ArchLayer) -> Component {
+        Component {
+            id: ComponentId::new(package, name),
+            name: name.to_string(),
+            kind: ComponentKind::Service,
+            layer: Some(layer),
+            location: SourceLocation::default(),
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
+        }
+    }
+
+    fn depend(graph: &mut DependencyGraph, from: &Component, to: &Component) {
+        graph.add_dependency(&Dependency {
+            from: from.id.clone(),
+            to: to.id.clone(),
+            kind: DependencyKind::Import,
+            location: SourceLocation::default(),
+            import_path: None,
+        });
+    }
+
+    #[test]
+    fn test_unchanged_graph_has_no_drift() {
+        let app = component("app", "Checkout", ArchLayer::Application);
+        let domain = component("domain", "Order", ArchLayer::Domain);
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&app);
+        graph.add_component(&domain);
+        depend(&mut graph, &app, &domain);
+
+        let lock = ArchitectureLock::from_graph(&graph, Path::new("."));
+        assert_eq!(lock.components.len(), 2);
+        assert_eq!(lock.edges.len(), 1);
+        assert!(lock
+            .detect_drift(&graph, &Config::default(), Path::new("."))
+            .is_empty());
+
+        let round_trip: ArchitectureLock = toml::from_str(&lock.to_toml().unwrap()).unwrap();
+        assert_eq!(round_trip, lock);
+    }
+
+    #[test]
+    fn test_moved_component_and_new_edge_drift() {
+        let app = component("app", "Checkout", ArchLayer::Application);
+        let domain = component("domain", "Order", ArchLayer::Domain);
+        let infra = component("infra", "PgStore", ArchLayer::Infrastructure);
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&app);
+        graph.add_component(&domain);
+        graph.add_component(&infra);
+        let lock = ArchitectureLock::from_graph(&graph, Path::new("."));
+
+        let mut moved = DependencyGraph::new();
+        moved.add_component(&component("app", "Checkout", ArchLayer::Domain));
+        moved.add_component(&domain);
+        moved.add_component(&infra);
+        depend(&mut moved, &domain, &infra);
+
+        let drift = lock.detect_drift(&moved, &Config::default(), Path::new("."));
+        assert_eq!(drift.len(), 2, "{drift:?}");
+        assert!(drift
+            .iter()
+            .all(|v| v.kind.rule_id().as_str() == "D005" && v.severity == Severity::Error));
+        assert!(drift[0]
+            .message
+            .contains("moved from the application layer"));
+        assert!(drift[1].message.contains("domain -> infrastructure"));
+    }
+
+    #[test]
+    fn test_lock_ids_are_relative_to_root() {
+        let app = component("go:proj/app", "Checkout", ArchLayer::Application);
+        let domain = component("go:proj/domain", "Order", ArchLayer::Domain);
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&app);
+        graph.add_component(&domain);
+        depend(&mut graph, &app, &domain);
+
+        let lock = ArchitectureLock::from_graph(&graph, Path::new("proj"));
+        assert_eq!(lock.components[0].id, "go:app::Checkout");
+
+        // The same tree analyzed from inside the project.
+        let app = component("go:./app", "Checkout", ArchLayer::Application);
+        let domain = component("go:./domain", "Order", ArchLayer::Domain);
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&app);
+        graph.add_component(&domain);
+        depend(&mut graph, &app, &domain);
+        let drift = lock.detect_drift(&graph, &Config::default(), Path::new("."));
+        assert!(drift.is_empty(), "{drift:?}");
+    }
+
+    #[test]
+    fn test_newer_lock_version_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE_NAME);
+        std::fs::write(&path, "version = 99\n").unwrap();
+        let err = ArchitectureLock::load(&path).unwrap_err();
+        assert!(err.to_string().contains("newer version"), "{err}");
+    }
+}
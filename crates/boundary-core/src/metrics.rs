@@ -1,14 +1,22 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
+use globset::{Glob, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
 
-use crate::config::Config;
-use crate::graph::DependencyGraph;
-use crate::metrics_report::{ClassificationCoverage, DependencyDepthMetrics, MetricsReport};
+use crate::config::{Config, EmptyMetricPolicy};
+use crate::graph::{pkg_import_match, DependencyGraph, GraphEdge, GraphNode};
+use crate::layer::build_globset;
+use crate::metrics_report::{
+    ClassificationCoverage, DependencyDepthMetrics, MetricsReport, WorstLayerPair,
+};
 use crate::pattern_detection::{detect_patterns, PatternDetection};
+use crate::rule::Rule;
+use crate::timing::{Phase, PhaseTimer};
 use crate::types::{
-    ArchLayer, ArchitectureMode, Component, ComponentKind, Dependency, Severity, Violation,
-    ViolationKind,
+    is_crud_method_name, split_language_tag, ArchLayer, ArchitectureMode, Component, ComponentId,
+    ComponentKind, Dependency, DependencyKind, MethodInfo, Severity, SourceLocation, Violation,
+    ViolationKind, Visibility,
 };
 
 /// Result for a single service in a multi-service analysis.
@@ -24,6 +32,9 @@ pub struct MultiServiceResult {
     pub services: Vec<ServiceAnalysisResult>,
     pub aggregate: AnalysisResult,
     pub shared_modules: Vec<SharedModule>,
+    /// For each shared module, the services importing it and where.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_module_graph: Vec<SharedModuleUsage>,
 }
 
 /// A module shared between multiple services.
@@ -33,6 +44,30 @@ pub struct SharedModule {
     pub used_by: Vec<String>,
 }
 
+/// The services importing a shared module, with their import sites.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedModuleUsage {
+    pub path: String,
+    pub importers: Vec<SharedModuleImporter>,
+}
+
+/// One service's imports of a shared module.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedModuleImporter {
+    pub service: String,
+    pub locations: Vec<SourceLocation>,
+}
+
+impl SharedModuleUsage {
+    /// The module path and the names of the services using it.
+    pub fn summary(&self) -> SharedModule {
+        SharedModule {
+            path: self.path.clone(),
+            used_by: self.importers.iter().map(|i| i.service.clone()).collect(),
+        }
+    }
+}
+
 /// Aggregate multiple service results into a combined result.
 pub fn aggregate_results(services: &[ServiceAnalysisResult]) -> AnalysisResult {
     if services.is_empty() {
@@ -43,6 +78,7 @@ pub fn aggregate_results(services: &[ServiceAnalysisResult]) -> AnalysisResult {
                 layer_conformance: 100.0,
                 dependency_compliance: 100.0,
                 interface_coverage: 100.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 0,
@@ -51,6 +87,13 @@ pub fn aggregate_results(services: &[ServiceAnalysisResult]) -> AnalysisResult {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         };
     }
 
@@ -91,6 +134,7 @@ pub fn aggregate_results(services: &[ServiceAnalysisResult]) -> AnalysisResult {
             layer_conformance,
             dependency_compliance,
             interface_coverage,
+            not_applicable: Vec::new(),
         }),
         violations: all_violations,
         component_count: total_components,
@@ -99,9 +143,29 @@ pub fn aggregate_results(services: &[ServiceAnalysisResult]) -> AnalysisResult {
         metrics: None,
         package_metrics: vec![],
         pattern_detection: None,
+        violations_total: None,
+        truncated_violations: Vec::new(),
+        config: None,
+        debt: ArchitectureDebt::merge(services.iter().filter_map(|s| s.result.debt.as_ref())),
+        generated_files: services
+            .iter()
+            .flat_map(|s| s.result.generated_files.iter().cloned())
+            .collect(),
+        generated_exempt: false,
+        project_root: shared_root(services),
     }
 }
 
+/// The project root shared by every service, if they all have the same one.
+fn shared_root(services: &[ServiceAnalysisResult]) -> Option<PathBuf> {
+    let root = services.first()?.result.project_root.clone();
+    services
+        .iter()
+        .all(|s| s.result.project_root == root)
+        .then_some(root)
+        .flatten()
+}
+
 /// Breakdown of architecture scores.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchitectureScore {
@@ -110,8 +174,22 @@ pub struct ArchitectureScore {
     pub layer_conformance: f64,
     pub dependency_compliance: f64,
     pub interface_coverage: f64,
+    /// Sub-metrics left out of `overall` under `empty_metric_policy = "na"` because
+    /// there was nothing to measure; their values are 0.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub not_applicable: Vec<String>,
+}
+
+impl ArchitectureScore {
+    /// Whether `metric` (e.g. `"interface_coverage"`) was measured.
+    pub fn is_applicable(&self, metric: &str) -> bool {
+        !self.not_applicable.iter().any(|m| m == metric)
+    }
 }
 
+/// Score of an unmeasurable sub-metric under `empty_metric_policy = "neutral"`.
+const NEUTRAL_METRIC_SCORE: f64 = 50.0;
+
 /// R.C. Martin package-level coupling metrics (Instability, Abstractness, Distance).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PackageMetric {
@@ -132,6 +210,31 @@ pub struct PackageMetric {
     pub zone: Option<String>,
 }
 
+/// R.C. Martin coupling metrics for a single component (`boundary metrics --per-component`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentMetric {
+    pub id: String,
+    pub name: String,
+    /// Component kind, e.g. "entity" or "port".
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layer: Option<ArchLayer>,
+    pub location: SourceLocation,
+    /// Ca: components (or component-less files) in other packages that import this one.
+    pub afferent_coupling: usize,
+    /// Ce: components in other packages imported by this component's file.
+    pub efferent_coupling: usize,
+    /// 1.0 for ports, 0.0 for concrete types.
+    pub abstractness: f64,
+    /// I = Ce / (Ca + Ce), rounded to 2 decimal places.
+    pub instability: f64,
+    /// D = |A + I - 1|, rounded to 2 decimal places.
+    pub distance: f64,
+    /// "pain" or "uselessness" when far from the main sequence, as for packages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+}
+
 /// Full analysis result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisResult {
@@ -156,6 +259,195 @@ pub struct AnalysisResult {
     /// Pattern detection result (confidence distribution across architectural patterns).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pattern_detection: Option<PatternDetection>,
+    /// Violation count before truncation. `None` when `violations` is the complete list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub violations_total: Option<usize>,
+    /// Violations dropped by `truncate_violations`. Never serialized; kept so that
+    /// pass/fail still counts them.
+    #[serde(skip)]
+    pub truncated_violations: Vec<Violation>,
+    /// Effective configuration that produced this result. Attached only on request
+    /// (`--include-config`) so CI artifacts can record how a score was computed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<Config>,
+    /// Estimated remediation effort. Present only when `[debt]` hours are configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debt: Option<ArchitectureDebt>,
+    /// Source files marked as generated code. `check` exempts their violations from
+    /// failing unless `[rules].fail_on_generated` is set.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generated_files: Vec<PathBuf>,
+    /// Set by [`exempt_generated_violations`](crate::generated::exempt_generated_violations):
+    /// violations in `generated_files` never fail `check`, whatever its `fail_on`.
+    /// Never serialized.
+    #[serde(skip)]
+    pub generated_exempt: bool,
+    /// Directory the analysis ran on. Violation paths and fingerprints are reported
+    /// relative to it, so it is serialized for reports read back by `boundary merge`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_root: Option<PathBuf>,
+}
+
+/// Architecture debt: violations weighted by the configured remediation hours per kind.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchitectureDebt {
+    pub total_hours: f64,
+    /// One entry per rule with configured hours and at least one violation,
+    /// most expensive first.
+    pub by_rule: Vec<DebtEntry>,
+}
+
+/// Debt contributed by one rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtEntry {
+    pub rule: String,
+    pub count: usize,
+    pub hours_each: f64,
+    pub hours: f64,
+}
+
+impl ArchitectureDebt {
+    /// Sum `violations` weighted by `hours` (keyed by rule ID or category name).
+    ///
+    /// Returns `None` when no hours are configured. Kinds without configured hours cost nothing.
+    pub fn estimate(violations: &[Violation], hours: &HashMap<String, f64>) -> Option<Self> {
+        if hours.is_empty() {
+            return None;
+        }
+        let mut by_rule: BTreeMap<String, DebtEntry> = BTreeMap::new();
+        for v in violations {
+            let Some(hours_each) = crate::config::lookup_by_kind(hours, &v.kind) else {
+                continue;
+            };
+            let rule = v.kind.rule_id().as_str().to_string();
+            by_rule
+                .entry(rule.clone())
+                .or_insert(DebtEntry {
+                    rule,
+                    count: 0,
+                    hours_each,
+                    hours: 0.0,
+                })
+                .count += 1;
+        }
+        let entries = by_rule
+            .into_values()
+            .map(|mut e| {
+                e.hours = e.count as f64 * e.hours_each;
+                e
+            })
+            .collect();
+        Some(Self::from_entries(entries))
+    }
+
+    /// Drop the entries for `rules`, e.g. after `--ignore` removed their violations.
+    pub fn remove_rules(&mut self, rules: &[String]) {
+        let entries = std::mem::take(&mut self.by_rule)
+            .into_iter()
+            .filter(|e| !rules.contains(&e.rule))
+            .collect();
+        *self = Self::from_entries(entries);
+    }
+
+    /// Combine per-service estimates into one.
+    fn merge<'a>(debts: impl IntoIterator<Item = &'a ArchitectureDebt>) -> Option<Self> {
+        let mut by_rule: BTreeMap<String, DebtEntry> = BTreeMap::new();
+        let mut any = false;
+        for debt in debts {
+            any = true;
+            for e in &debt.by_rule {
+                let merged = by_rule.entry(e.rule.clone()).or_insert(DebtEntry {
+                    rule: e.rule.clone(),
+                    count: 0,
+                    hours_each: e.hours_each,
+                    hours: 0.0,
+                });
+                merged.count += e.count;
+                merged.hours += e.hours;
+            }
+        }
+        any.then(|| Self::from_entries(by_rule.into_values().collect()))
+    }
+
+    fn from_entries(mut by_rule: Vec<DebtEntry>) -> Self {
+        by_rule.retain(|e| e.count > 0 && e.hours > 0.0);
+        by_rule.sort_by(|a, b| {
+            b.hours
+                .total_cmp(&a.hours)
+                .then_with(|| a.rule.cmp(&b.rule))
+        });
+        Self {
+            total_hours: by_rule.iter().fold(0.0, |sum, e| sum + e.hours),
+            by_rule,
+        }
+    }
+}
+
+impl AnalysisResult {
+    /// The [`project_root`](Self::project_root), or `.` when none was recorded.
+    pub fn root(&self) -> &Path {
+        self.project_root.as_deref().unwrap_or(Path::new("."))
+    }
+
+    /// Keep only the `max` most severe violations, ordered by severity then location.
+    ///
+    /// Records the original count in `violations_total` when anything is dropped.
+    /// The dropped violations move to `truncated_violations`, so
+    /// [`violations_at_or_above`](Self::violations_at_or_above) still sees them.
+    pub fn truncate_violations(&mut self, max: usize) {
+        self.violations.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| a.location.file.cmp(&b.location.file))
+                .then_with(|| a.location.line.cmp(&b.location.line))
+                .then_with(|| a.location.column.cmp(&b.location.column))
+        });
+        let total = self.violations.len() + self.truncated_violations.len();
+        if self.violations.len() > max {
+            let dropped = self.violations.split_off(max);
+            self.truncated_violations.extend(dropped);
+            self.violations_total = Some(total);
+        }
+    }
+
+    /// Number of violations omitted by `truncate_violations`.
+    pub fn omitted_violations(&self) -> usize {
+        self.violations_total
+            .map(|total| total.saturating_sub(self.violations.len()))
+            .unwrap_or(0)
+    }
+
+    /// Violations of one kind, named as in the metrics' `violations_by_kind`
+    /// (e.g. `"circular_dependency"`).
+    pub fn violations_by_kind<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a Violation> {
+        self.violations
+            .iter()
+            .filter(move |v| violation_kind_name(&v.kind) == kind)
+    }
+
+    /// Violations at `severity` or above: the ones that fail `check` at that `fail_on`.
+    ///
+    /// Includes violations dropped by `truncate_violations`, so truncating the
+    /// reported list never changes whether `check` passes. Excludes violations in
+    /// generated files once they are exempted.
+    pub fn violations_at_or_above(&self, severity: Severity) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .chain(&self.truncated_violations)
+            .filter(move |v| v.severity >= severity)
+            .filter(move |v| {
+                !(self.generated_exempt && self.generated_files.contains(&v.location.file))
+            })
+    }
+
+    /// Violations located in `path`. A leading `./` on either side is ignored.
+    pub fn violations_in_file<'a>(&'a self, path: &'a Path) -> impl Iterator<Item = &'a Violation> {
+        let path = path.strip_prefix(".").unwrap_or(path);
+        self.violations.iter().filter(move |v| {
+            let file = &v.location.file;
+            file.strip_prefix(".").unwrap_or(file) == path
+        })
+    }
 }
 
 /// Calculate architecture score from the dependency graph.
@@ -165,76 +457,183 @@ pub fn calculate_score(
     components: &[Component],
     dependencies: &[Dependency],
 ) -> ArchitectureScore {
-    let layer_conformance_opt = calculate_layer_conformance(components, dependencies);
-    let dependency_compliance = calculate_dependency_compliance(graph);
-    let interface_coverage = calculate_interface_coverage(graph);
-
     let w = &config.scoring;
+    let policy = w.empty_metric_policy;
+    // Each sub-metric is `None` when there is nothing to measure; the policy decides
+    // its score, and under `na` it stays `None` and is left out.
+    let resolve = |measured: Option<f64>| match (measured, policy) {
+        (Some(score), _) => Some(score),
+        (None, EmptyMetricPolicy::Perfect) => Some(100.0),
+        (None, EmptyMetricPolicy::Neutral) => Some(NEUTRAL_METRIC_SCORE),
+        (None, EmptyMetricPolicy::Na) => None,
+    };
+
+    let layer_conformance_measured = calculate_layer_conformance(components, dependencies);
+    let layer_conformance = resolve(layer_conformance_measured);
+    let dependency_compliance = resolve(calculate_dependency_compliance(graph));
+    let interface_coverage = resolve(calculate_interface_coverage(graph));
 
-    // Redistribute weights for any undefined dimension (currently only layer_conformance
-    // can be undefined — when there are no classified packages).
-    let (total_weight, weighted_sum) = {
-        let mut tw = 0.0f64;
-        let mut ws = 0.0f64;
-        if let Some(lc) = layer_conformance_opt {
-            tw += w.layer_conformance_weight;
-            ws += lc * w.layer_conformance_weight;
-        }
-        tw += w.dependency_compliance_weight;
-        ws += dependency_compliance * w.dependency_compliance_weight;
-        tw += w.interface_coverage_weight;
-        ws += interface_coverage * w.interface_coverage_weight;
-        (tw, ws)
+    // Structural presence: what % of components are classified into a layer?
+    let coverage = compute_classification_coverage(graph);
+    let structural_presence =
+        resolve((coverage.total_components > 0).then_some(coverage.coverage_percentage));
+
+    // Redistribute weights for any excluded dimension. Under `perfect`, undefined layer
+    // conformance keeps its historical treatment: reported as 100 but not weighted.
+    let weighted_layer_conformance = match policy {
+        EmptyMetricPolicy::Perfect => layer_conformance_measured,
+        _ => layer_conformance,
     };
+    let (total_weight, weighted_sum) = [
+        (weighted_layer_conformance, w.layer_conformance_weight),
+        (dependency_compliance, w.dependency_compliance_weight),
+        (interface_coverage, w.interface_coverage_weight),
+    ]
+    .into_iter()
+    .filter_map(|(score, weight)| Some((score?, weight)))
+    .fold((0.0f64, 0.0f64), |(tw, ws), (score, weight)| {
+        (tw + weight, ws + score * weight)
+    });
 
     let correctness = if total_weight > 0.0 {
-        (weighted_sum / total_weight).clamp(0.0, 100.0)
+        Some((weighted_sum / total_weight).clamp(0.0, 100.0))
     } else {
-        100.0
+        resolve(None)
     };
 
-    // Structural presence: what % of components are classified into a layer?
-    let coverage = compute_classification_coverage(graph);
-    let structural_presence = coverage.coverage_percentage;
-
-    // Multiplicative gate: overall = presence * correctness / 100
-    let overall = (structural_presence * correctness / 100.0).clamp(0.0, 100.0);
+    // Multiplicative gate: overall = presence * correctness / 100. A side left out
+    // under `na` drops out of the product; with nothing measured there is no score.
+    let overall = match (structural_presence, correctness) {
+        (Some(presence), Some(correctness)) => presence * correctness / 100.0,
+        (Some(score), None) | (None, Some(score)) => score,
+        (None, None) => 0.0,
+    }
+    .clamp(0.0, 100.0);
+
+    let not_applicable = [
+        ("structural_presence", structural_presence),
+        ("layer_conformance", layer_conformance),
+        ("dependency_compliance", dependency_compliance),
+        ("interface_coverage", interface_coverage),
+    ]
+    .into_iter()
+    .filter(|(_, score)| score.is_none())
+    .map(|(metric, _)| metric.to_string())
+    .collect();
 
     ArchitectureScore {
         overall,
-        structural_presence,
-        layer_conformance: layer_conformance_opt.unwrap_or(100.0),
-        dependency_compliance,
-        interface_coverage,
+        structural_presence: structural_presence.unwrap_or(0.0),
+        layer_conformance: layer_conformance.unwrap_or(0.0),
+        dependency_compliance: dependency_compliance.unwrap_or(0.0),
+        interface_coverage: interface_coverage.unwrap_or(0.0),
+        not_applicable,
     }
 }
 
-/// Detect all violations in the dependency graph.
-pub fn detect_violations(graph: &DependencyGraph, config: &Config) -> Vec<Violation> {
-    let mut violations = Vec::new();
+/// A built-in detector, appending what it finds to the violations so far.
+struct BuiltinRule(fn(&DependencyGraph, &Config, &mut Vec<Violation>));
 
-    // Layer boundary violations
-    detect_layer_violations(graph, config, &mut violations);
+impl Rule for BuiltinRule {
+    fn evaluate(&self, graph: &DependencyGraph, config: &Config) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        (self.0)(graph, config, &mut violations);
+        violations
+    }
+}
 
+/// Built-in rules, in reporting order.
+const BUILTIN_RULES: &[BuiltinRule] = &[
+    // Layer boundary violations
+    BuiltinRule(detect_layer_violations),
+    // Dependencies jumping over a layer (opt-in)
+    BuiltinRule(detect_layer_skips),
     // Circular dependency violations
-    detect_circular_dependencies(graph, config, &mut violations);
-
+    BuiltinRule(detect_circular_dependencies),
+    // Package cycles within a single layer (opt-in)
+    BuiltinRule(detect_intra_layer_cycles),
+    // Presentation components wiring too many other presentation components (opt-in)
+    BuiltinRule(detect_high_coupling),
+    // Components depending on too many others (opt-in via `max_efferent_coupling`)
+    BuiltinRule(detect_excessive_fan_out),
+    // External imports forbidden by the layer's policy
+    BuiltinRule(detect_disallowed_external_dependencies),
     // Pattern violations (DDD structural checks)
-    detect_pattern_violations(graph, config, &mut violations);
-
+    BuiltinRule(detect_pattern_violations),
+    // Domain ports taking infrastructure-typed parameters (opt-in)
+    BuiltinRule(detect_infra_port_parameters),
+    // Domain entities and value objects holding infrastructure-typed fields (opt-in)
+    BuiltinRule(detect_infra_field_types),
+    // Domain methods annotated with persistence concerns (opt-in)
+    BuiltinRule(detect_persistence_annotations),
+    // Concrete infrastructure implementations outside infrastructure
+    BuiltinRule(detect_misplaced_adapters),
+    // Infrastructure adapters wired directly to each other (opt-in)
+    BuiltinRule(detect_adapter_coupling),
+    // Ports whose package imports one of their adapters' packages (opt-in)
+    BuiltinRule(detect_port_adapter_cycles),
     // Init function coupling violations
-    detect_init_violations(graph, config, &mut violations);
-
-    // Custom rules
-    if !config.rules.custom_rules.is_empty() {
-        match crate::custom_rules::compile_rules(&config.rules.custom_rules) {
-            Ok(compiled) => {
-                let custom_violations =
-                    crate::custom_rules::evaluate_custom_rules(graph, &compiled);
-                violations.extend(custom_violations);
-            }
-            Err(e) => {
-                eprintln!("Warning: failed to compile custom rules: {e:#}");
+    BuiltinRule(detect_init_violations),
+    // Factories handing out concrete adapters instead of ports (opt-in)
+    BuiltinRule(detect_factory_concrete_returns),
+    // Repositories carrying business logic (opt-in)
+    BuiltinRule(detect_fat_repositories),
+    // Copy-pasted port interfaces (opt-in)
+    BuiltinRule(detect_duplicate_ports),
+    // Ports too wide for their clients (opt-in)
+    BuiltinRule(detect_fat_interfaces),
+    // Ports carrying default-method logic instead of a contract (opt-in)
+    BuiltinRule(detect_behavior_heavy_ports),
+    // Package-level dependency direction (opt-in)
+    BuiltinRule(detect_module_direction_violations),
+    // Application services that only forward to a repository (opt-in)
+    BuiltinRule(detect_pass_through_services),
+    // Bounded contexts mixing architecture modes (opt-in)
+    BuiltinRule(detect_mixed_architecture_modes),
+    // Bounded contexts with adapters but no port (opt-in)
+    BuiltinRule(detect_context_missing_ports),
+    // Too few components classified into a layer (opt-in via `min_classification_coverage`)
+    BuiltinRule(detect_low_classification_coverage),
+    // Interface coverage below `min_interface_coverage` (opt-in)
+    BuiltinRule(detect_low_interface_coverage),
+    // Production code depending on mocks, fakes and stubs (opt-in)
+    BuiltinRule(detect_test_doubles_in_production),
+    // Domain entity fields accessed from other packages (opt-in)
+    BuiltinRule(detect_cross_aggregate_field_access),
+    // Domain entities holding data but no behavior (opt-in)
+    BuiltinRule(detect_anemic_domain),
+    // Custom rules from `[[rules.custom_rules]]`
+    BuiltinRule(detect_custom_rules),
+];
+
+/// Detect all violations in the dependency graph.
+pub fn detect_violations(graph: &DependencyGraph, config: &Config) -> Vec<Violation> {
+    detect_violations_with_rules(graph, config, &[], Path::new("."))
+}
+
+/// [`detect_violations`] for the project at `project_root`, also running `rules` after
+/// the built-in ones. Path-scoped overrides are matched relative to `project_root`,
+/// and each violation records it.
+pub fn detect_violations_with_rules(
+    graph: &DependencyGraph,
+    config: &Config,
+    rules: &[Box<dyn Rule>],
+    project_root: &Path,
+) -> Vec<Violation> {
+    let mut violations: Vec<Violation> = BUILTIN_RULES
+        .iter()
+        .map(|rule| rule as &dyn Rule)
+        .chain(rules.iter().map(|rule| rule.as_ref()))
+        .flat_map(|rule| rule.evaluate(graph, config))
+        .collect();
+
+    // Apply path-scoped severity overrides
+    let scoper = crate::rule_scope::RuleScoper::new(&config.rules.overrides);
+    if !scoper.is_empty() {
+        for v in &mut violations {
+            let file = v.location.file.to_string_lossy();
+            if let Some(severity) = scoper.resolve(&v.kind, &file, project_root) {
+                v.severity = severity;
             }
         }
     }
@@ -250,15 +649,42 @@ pub fn detect_violations(graph: &DependencyGraph, config: &Config) -> Vec<Violat
         });
     }
 
+    if let Some(base) = &config.rules.doc_base_url {
+        for v in &mut violations {
+            v.doc_url = v.kind.doc_url_with_base(base);
+        }
+    }
     violations
 }
 
+fn detect_custom_rules(graph: &DependencyGraph, config: &Config, violations: &mut Vec<Violation>) {
+    if config.rules.custom_rules.is_empty() {
+        return;
+    }
+    match crate::custom_rules::compile_rules(&config.rules.custom_rules) {
+        Ok(compiled) => {
+            violations.extend(crate::custom_rules::evaluate_custom_rules(graph, &compiled));
+        }
+        Err(e) => {
+            eprintln!("Warning: failed to compile custom rules: {e:#}");
+        }
+    }
+}
+
 fn detect_layer_violations(
     graph: &DependencyGraph,
     config: &Config,
     violations: &mut Vec<Violation>,
 ) {
-    for (src, tgt, edge) in graph.edges_with_nodes() {
+    let occurrences = graph.edge_occurrences();
+    let imports: HashSet<(&Path, &str)> = occurrences
+        .iter()
+        .filter(|(_, _, edge, _)| edge.kind == DependencyKind::Import)
+        .filter_map(|(_, _, edge, location)| {
+            Some((location.file.as_path(), edge.import_path.as_deref()?))
+        })
+        .collect();
+    for (src, tgt, edge, location) in occurrences.iter().copied() {
         if src.is_external || tgt.is_external {
             continue;
         }
@@ -266,6 +692,17 @@ fn detect_layer_violations(
             continue;
         }
 
+        // An embedded type from another package needs that package's import, which
+        // is checked itself; reporting the embedding too would repeat it.
+        if edge.kind == DependencyKind::Embedding
+            && edge
+                .import_path
+                .as_deref()
+                .is_some_and(|p| imports.contains(&(location.file.as_path(), p)))
+        {
+            continue;
+        }
+
         // Skip init() function deps — they are reported by detect_init_violations instead
         if src.id.0.contains("<init>") {
             continue;
@@ -304,7 +741,7 @@ fn detect_layer_violations(
             violations.push(Violation {
                 kind,
                 severity,
-                location: edge.location.clone(),
+                location: location.clone(),
                 message: format!(
                     "{} layer depends on {} layer{import_detail}",
                     from_layer, to_layer
@@ -314,11 +751,86 @@ fn detect_layer_violations(
                      Consider introducing a port interface in the {from_layer} layer \
                      and an adapter in the {to_layer} layer."
                 )),
+                fix: None,
+                doc_url: None,
             });
         }
     }
 }
 
+/// Ring of a layer for L009: domain, application, then the outer ring shared by
+/// infrastructure and presentation.
+fn layer_ring(layer: ArchLayer) -> u8 {
+    match layer {
+        ArchLayer::Domain => 0,
+        ArchLayer::Application => 1,
+        ArchLayer::Infrastructure | ArchLayer::Presentation => 2,
+    }
+}
+
+/// Flag dependency edges that jump over a ring, in either direction, naming the
+/// layers in between. Infrastructure depending on the domain is exempt: adapters
+/// implement the ports the domain declares.
+fn detect_layer_skips(graph: &DependencyGraph, config: &Config, violations: &mut Vec<Violation>) {
+    if !config.rules.detect_layer_skips {
+        return;
+    }
+
+    for (src, tgt, edge, location) in graph.edge_occurrences() {
+        if src.is_external || tgt.is_external || src.is_cross_cutting || tgt.is_cross_cutting {
+            continue;
+        }
+        if src.id.0.contains("<init>") || src.architecture_mode == ArchitectureMode::ServiceOriented
+        {
+            continue;
+        }
+        let (Some(from), Some(to)) = (src.layer, tgt.layer) else {
+            continue;
+        };
+        if from == ArchLayer::Infrastructure && to == ArchLayer::Domain {
+            continue;
+        }
+        let (low, high) = (
+            layer_ring(from).min(layer_ring(to)),
+            layer_ring(from).max(layer_ring(to)),
+        );
+        if high - low < 2 {
+            continue;
+        }
+        let skipped: Vec<ArchLayer> = [ArchLayer::Application]
+            .into_iter()
+            .filter(|l| layer_ring(*l) > low && layer_ring(*l) < high)
+            .collect();
+        let skipped_list = skipped
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let import_detail = edge
+            .import_path
+            .as_deref()
+            .map(|p| format!(" (import: {p})"))
+            .unwrap_or_default();
+
+        let kind = ViolationKind::LayerSkip { from, to, skipped };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: location.clone(),
+            message: format!(
+                "{from} layer depends on {to} layer, skipping {skipped_list}{import_detail}"
+            ),
+            suggestion: Some(format!(
+                "Route the dependency through the {skipped_list} layer instead of \
+                 reaching across it."
+            )),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
 fn detect_circular_dependencies(
     graph: &DependencyGraph,
     config: &Config,
@@ -326,102 +838,695 @@ fn detect_circular_dependencies(
 ) {
     let all_nodes = graph.nodes();
     for cycle in graph.find_cycles() {
-        let cycle_str = cycle
+        let members: Vec<&GraphNode> = cycle
             .iter()
-            .map(|c| c.0.as_str())
-            .collect::<Vec<_>>()
-            .join(" -> ");
+            .filter_map(|id| all_nodes.iter().find(|n| &n.id == id).copied())
+            .collect();
         // Use the location of the first component in the cycle
-        let location = cycle
+        let location = members
             .first()
-            .and_then(|id| all_nodes.iter().find(|n| &n.id == id))
             .map(|n| n.location.clone())
             .unwrap_or_default();
-        let kind = ViolationKind::CircularDependency {
-            cycle: cycle.clone(),
+        let layers = cycle_layers(
+            members
+                .iter()
+                .filter(|n| !n.is_cross_cutting)
+                .filter_map(|n| n.layer),
+        );
+        push_cycle_violation(cycle, layers, location, config, violations);
+    }
+
+    // Package-level cycles spanning layers (opt-in). Import edges rarely close a cycle
+    // between components, but often do between their packages.
+    if config.rules.detect_cross_layer_cycles {
+        for cycle in graph.collapse_to_packages().find_cross_layer_cycles() {
+            let location = cycle
+                .iter()
+                .map(|p| &p.location)
+                .find(|l| !l.file.as_os_str().is_empty())
+                .cloned()
+                .unwrap_or_default();
+            let layers = cycle_layers(cycle.iter().filter_map(|p| p.layer));
+            let ids = cycle
+                .iter()
+                .map(|p| ComponentId(p.package.clone()))
+                .collect();
+            push_cycle_violation(ids, layers, location, config, violations);
+        }
+    }
+}
+
+/// Distinct layers, innermost first.
+fn cycle_layers(layers: impl Iterator<Item = ArchLayer>) -> Vec<ArchLayer> {
+    let mut layers: Vec<ArchLayer> = layers.collect();
+    layers.sort_by_key(|l| l.depth());
+    layers.dedup();
+    layers
+}
+
+fn push_cycle_violation(
+    cycle: Vec<ComponentId>,
+    layers: Vec<ArchLayer>,
+    location: SourceLocation,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    let cycle_str = cycle
+        .iter()
+        .map(|c| c.0.as_str())
+        .collect::<Vec<_>>()
+        .join(" -> ");
+    let cross_layer = layers.len() > 1;
+    let (message, suggestion) = if cross_layer {
+        let layer_names = layers
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        (
+            format!("Circular dependency across layers ({layer_names}): {cycle_str}"),
+            format!(
+                "A cycle across layers always has an edge pointing outward. Make the {} \
+                 layer depend on an interface it owns, implemented in the outer layer.",
+                layers[0]
+            ),
+        )
+    } else {
+        (
+            format!("Circular dependency detected: {cycle_str}"),
+            "Break the cycle by introducing an interface or reorganizing dependencies.".to_string(),
+        )
+    };
+    let kind = ViolationKind::CircularDependency {
+        cycle,
+        layers,
+        cross_layer,
+    };
+    let severity = config.rules.resolve_severity(&kind, Severity::Error);
+    violations.push(Violation {
+        kind,
+        severity,
+        location,
+        message,
+        suggestion: Some(suggestion),
+        fix: None,
+        doc_url: None,
+    });
+}
+
+fn detect_high_coupling(graph: &DependencyGraph, config: &Config, violations: &mut Vec<Violation>) {
+    if !config.rules.detect_high_coupling {
+        return;
+    }
+
+    let threshold = config.rules.max_presentation_fan_out;
+    let mut routers: Vec<_> = graph
+        .fan_out_within_layer(ArchLayer::Presentation)
+        .into_iter()
+        .filter(|(_, fan_out, _)| *fan_out > threshold)
+        .collect();
+    routers.sort_by(|a, b| a.0.id.0.cmp(&b.0.id.0));
+
+    for (node, fan_out, edge_location) in routers {
+        // Import-level nodes (e.g. Go `<file>`) stand for their whole package.
+        let component = if node.kind.is_some() {
+            node.name.clone()
+        } else {
+            node.id.package().trim_start_matches("./").to_string()
         };
-        let severity = config.rules.resolve_severity(&kind, Severity::Error);
+        let location = if node.location.file.as_os_str().is_empty() {
+            edge_location.clone()
+        } else {
+            node.location.clone()
+        };
+
+        let kind = ViolationKind::HighCoupling {
+            layer: ArchLayer::Presentation,
+            component: component.clone(),
+            fan_out,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Info);
         violations.push(Violation {
             kind,
             severity,
             location,
-            message: format!("Circular dependency detected: {cycle_str}"),
+            message: format!(
+                "Presentation component '{component}' depends on {fan_out} other presentation \
+                 components (threshold {threshold})"
+            ),
             suggestion: Some(
-                "Break the cycle by introducing an interface or reorganizing dependencies."
+                "Split the router by feature area, or let each module register its own \
+                 routes, so no single component wires every handler."
                     .to_string(),
             ),
+            fix: None,
+            doc_url: None,
         });
     }
 }
 
-/// Infrastructure-related import path keywords.
-const INFRA_KEYWORDS: &[&str] = &[
-    "postgres",
-    "mysql",
-    "redis",
-    "mongo",
-    "database",
-    "sql",
-    "db",
-    "dynamodb",
-    "sqlite",
-    "cassandra",
-    "elasticsearch",
-];
-
-fn detect_pattern_violations(
+/// Flag components that depend on more than `max_efferent_coupling` distinct
+/// components or packages, listing what they depend on.
+fn detect_excessive_fan_out(
     graph: &DependencyGraph,
     config: &Config,
     violations: &mut Vec<Violation>,
 ) {
-    let nodes = graph.nodes();
+    let Some(max) = config.rules.max_efferent_coupling else {
+        return;
+    };
 
-    // Collect port names using ComponentKind first, then fall back to name heuristics
-    let port_names: Vec<String> = nodes
-        .iter()
-        .filter(|n| {
-            // Prefer ComponentKind::Port when available
-            if let Some(ComponentKind::Port(_)) = &n.kind {
-                return true;
-            }
-            let name_lower = n.name.to_lowercase();
-            name_lower.contains("port")
-                || name_lower.contains("interface")
-                || (name_lower.contains("repository") && n.layer == Some(ArchLayer::Domain))
-        })
-        .map(|n| n.name.clone())
+    let mut hubs: Vec<_> = graph
+        .out_degree()
+        .into_iter()
+        .filter(|(_, targets, _)| targets.len() > max)
         .collect();
+    hubs.sort_by(|a, b| a.0.id.0.cmp(&b.0.id.0));
 
-    // Check 1: Adapter without port
-    // Only check infrastructure-layer components that are actually adapters
-    for node in &nodes {
-        if node.is_cross_cutting {
-            continue;
+    // Import-level nodes (e.g. Go `<file>`, `<package>`) stand for their whole package.
+    let display = |node: &GraphNode| {
+        if node.kind.is_some() {
+            node.name.clone()
+        } else {
+            node.id.package().trim_start_matches("./").to_string()
         }
+    };
 
-        let name_lower = node.name.to_lowercase();
-
-        // Use ComponentKind to determine if this is an adapter.
-        // Only infrastructure-layer components are hex arch adapters — application-layer
-        // "handlers" are use cases/coordinators, not adapters.
-        let is_adapter = match &node.kind {
-            Some(ComponentKind::Adapter(_)) if node.layer == Some(ArchLayer::Infrastructure) => {
-                true
-            }
-            Some(ComponentKind::Repository) if node.layer == Some(ArchLayer::Infrastructure) => {
-                true
-            }
-            // Fall back to name heuristic only for infrastructure-layer components
-            None | Some(_) => {
-                node.layer == Some(ArchLayer::Infrastructure)
-                    && (name_lower.ends_with("handler")
-                        || name_lower.ends_with("controller")
-                        || name_lower.contains("adapter")
-                        || name_lower.contains("impl"))
-            }
-        };
-
-        if !is_adapter {
+    for (node, targets, edge_location) in hubs {
+        let component = display(node);
+        let mut targets: Vec<String> = targets.into_iter().map(display).collect();
+        targets.sort();
+        targets.dedup();
+        let fan_out = targets.len();
+        if fan_out <= max {
+            continue;
+        }
+        let location = if node.location.file.as_os_str().is_empty() {
+            edge_location.clone()
+        } else {
+            node.location.clone()
+        };
+
+        let message = format!(
+            "Component '{component}' depends on {fan_out} components (max {max}): {}",
+            targets.join(", ")
+        );
+        let kind = ViolationKind::ExcessiveFanOut {
+            component,
+            fan_out,
+            targets,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location,
+            message,
+            suggestion: Some(
+                "Split the component by responsibility, or depend on a narrower port that \
+                 hides the collaborators it coordinates."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Flag the project when fewer than `min_classification_coverage` percent of its
+/// components are classified into a layer or marked cross-cutting.
+fn detect_low_classification_coverage(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    let Some(minimum) = config.rules.min_classification_coverage else {
+        return;
+    };
+    let coverage = compute_classification_coverage(graph);
+    if coverage.coverage_percentage >= minimum {
+        return;
+    }
+
+    let mut message = format!(
+        "Classification coverage is {:.1}% (minimum {minimum:.1}%): {} of {} components unclassified",
+        coverage.coverage_percentage, coverage.unclassified, coverage.total_components
+    );
+    if !coverage.unclassified_paths.is_empty() {
+        message.push_str(&format!(" in {}", coverage.unclassified_paths.join(", ")));
+    }
+    // Point at the first unclassified component so editors have somewhere to show it.
+    let location = graph
+        .nodes()
+        .into_iter()
+        .filter(|n| !n.is_external && n.kind.is_some() && !n.is_cross_cutting && n.layer.is_none())
+        .min_by(|a, b| a.id.0.cmp(&b.id.0))
+        .map(|n| n.location.clone())
+        .unwrap_or_default();
+    let kind = ViolationKind::LowClassificationCoverage {
+        coverage: coverage.coverage_percentage,
+        minimum,
+        unclassified: coverage.unclassified,
+    };
+    let severity = config.rules.resolve_severity(&kind, Severity::Error);
+    violations.push(Violation {
+        kind,
+        severity,
+        location,
+        message,
+        suggestion: Some(
+            "Add layer patterns in [layers] for the unclassified directories, or mark shared \
+             code as cross_cutting."
+                .to_string(),
+        ),
+        fix: None,
+        doc_url: None,
+    });
+}
+
+/// Flag the project when its interface coverage sub-score is below
+/// `min_interface_coverage`, whatever the other rules find. Projects without
+/// infrastructure adapters have nothing to cover and are not flagged.
+fn detect_low_interface_coverage(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    let Some(minimum) = config.rules.min_interface_coverage else {
+        return;
+    };
+    let Some(coverage) = calculate_interface_coverage(graph) else {
+        return;
+    };
+    if coverage >= minimum {
+        return;
+    }
+
+    let (ports, adapters) = interface_coverage_counts(graph);
+    let message = format!(
+        "Interface coverage is {coverage:.1}% (minimum {minimum:.1}%): {ports} ports for {adapters} infrastructure adapters"
+    );
+    // Point at the first adapter so editors have somewhere to show it.
+    let location = graph
+        .nodes()
+        .into_iter()
+        .filter(|n| !n.is_cross_cutting && !n.is_external && is_coverage_adapter(n))
+        .min_by(|a, b| a.id.0.cmp(&b.id.0))
+        .map(|n| n.location.clone())
+        .unwrap_or_default();
+    let kind = ViolationKind::LowInterfaceCoverage {
+        coverage,
+        minimum,
+        ports,
+        adapters,
+    };
+    let severity = config.rules.resolve_severity(&kind, Severity::Error);
+    violations.push(Violation {
+        kind,
+        severity,
+        location,
+        message,
+        suggestion: Some(
+            "Define a port in the domain or application layer for each adapter and depend on \
+             the port instead."
+                .to_string(),
+        ),
+        fix: None,
+        doc_url: None,
+    });
+}
+
+/// Flag production code that depends on test doubles: components named like mocks,
+/// fakes or stubs per `test_double_patterns`. Defining a double outside test files is
+/// fine (generated mocks often are); depending on one from a package that is not
+/// itself a test-double package is what gets reported.
+fn detect_test_doubles_in_production(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_test_doubles {
+        return;
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &config.rules.test_double_patterns {
+        match Glob::new(pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(e) => {
+                eprintln!(
+                    "Warning: invalid glob pattern '{pattern}' in rules.test_double_patterns: {e}"
+                )
+            }
+        }
+    }
+    let Ok(patterns) = builder.build() else {
+        return;
+    };
+
+    // Test doubles by the package declaring them.
+    let mut doubles: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for node in graph.nodes() {
+        if !node.is_external && node.kind.is_some() && patterns.is_match(&node.name) {
+            doubles
+                .entry(node.id.package())
+                .or_default()
+                .push(node.name.as_str());
+        }
+    }
+    if doubles.is_empty() {
+        return;
+    }
+    // Package-level targets (e.g. Go imports) name an import path, not a source package.
+    let doubles_in = |node: &GraphNode| -> Vec<String> {
+        if node.kind.is_some() {
+            return if patterns.is_match(&node.name) {
+                vec![node.name.clone()]
+            } else {
+                Vec::new()
+            };
+        }
+        let package = node.id.package();
+        doubles
+            .iter()
+            .find(|(pkg, _)| **pkg == package || pkg_import_match(pkg, package))
+            .map(|(_, names)| names.iter().map(|n| n.to_string()).collect())
+            .unwrap_or_default()
+    };
+
+    let mut found: BTreeMap<(String, String), SourceLocation> = BTreeMap::new();
+    for (src, tgt, edge) in graph.edges_with_nodes() {
+        let package = src.id.package();
+        if src.is_external || doubles.contains_key(package) {
+            continue;
+        }
+        for double in doubles_in(tgt) {
+            found
+                .entry((package.trim_start_matches("./").to_string(), double))
+                .or_insert_with(|| edge.location().clone());
+        }
+    }
+
+    for ((component, test_double), location) in found {
+        let message = format!("'{component}' depends on test double '{test_double}'");
+        let kind = ViolationKind::TestDoubleInProduction {
+            component,
+            test_double,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location,
+            message,
+            suggestion: Some(
+                "Depend on the port the double stands in for and wire the real \
+                 implementation in production; keep doubles for tests."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Flag field accesses (`u.Name`) on domain entities from outside the entity's
+/// package. Only selector expressions on variables with a declared type are seen, so
+/// an access through an inferred local (`u := repo.Find(id)`) goes unreported.
+fn detect_cross_aggregate_field_access(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_cross_aggregate_field_access {
+        return;
+    }
+
+    let nodes = graph.nodes();
+    let mut seen: HashSet<(String, String, String)> = HashSet::new();
+    for access in graph.field_accesses() {
+        // An unqualified type lives in the accessor's own package.
+        if access.receiver_package.is_empty() {
+            continue;
+        }
+        let Some(entity) = nodes.iter().find(|n| {
+            !n.is_external
+                && n.name == access.receiver_type
+                && n.location
+                    .file
+                    .parent()
+                    .and_then(|d| d.file_name())
+                    .is_some_and(|d| d == access.receiver_package.as_str())
+        }) else {
+            continue;
+        };
+        let Some(ComponentKind::Entity(info)) = &entity.kind else {
+            continue;
+        };
+        if !info.fields.iter().any(|f| f.name == access.field) {
+            continue;
+        }
+        let accessor_dir = access.location.file.parent();
+        if accessor_dir == entity.location.file.parent() {
+            continue;
+        }
+        let accessor = accessor_dir
+            .map(|d| d.to_string_lossy().trim_start_matches("./").to_string())
+            .unwrap_or_default();
+        if !seen.insert((accessor.clone(), entity.name.clone(), access.field.clone())) {
+            continue;
+        }
+
+        let kind = ViolationKind::CrossAggregateFieldAccess {
+            entity: entity.name.clone(),
+            field: access.field.clone(),
+            accessor: accessor.clone(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: access.location.clone(),
+            message: format!(
+                "'{accessor}' accesses field '{}' of domain entity '{}' directly",
+                access.field, entity.name
+            ),
+            suggestion: Some(format!(
+                "Expose the behavior or value through a method on '{}' so the entity \
+                 keeps control of its state.",
+                entity.name
+            )),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Flag domain entities that have fields but no methods: data bags whose behavior
+/// lives in services. Active Record entities are skipped, and value objects are
+/// expected to be plain data, so they never fire.
+fn detect_anemic_domain(graph: &DependencyGraph, config: &Config, violations: &mut Vec<Violation>) {
+    if !config.rules.detect_anemic_model {
+        return;
+    }
+
+    for node in graph.nodes() {
+        if node.is_external
+            || node.layer != Some(ArchLayer::Domain)
+            || node.architecture_mode == ArchitectureMode::ActiveRecord
+        {
+            continue;
+        }
+        let Some(ComponentKind::Entity(info)) = &node.kind else {
+            continue;
+        };
+        if info.is_active_record || info.fields.is_empty() || !info.methods.is_empty() {
+            continue;
+        }
+
+        let kind = ViolationKind::AnemicDomainModel {
+            entity_name: node.name.clone(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: node.location.clone(),
+            message: format!(
+                "Domain entity '{}' has {} field(s) but no methods",
+                node.name,
+                info.fields.len()
+            ),
+            suggestion: Some(format!(
+                "Move the behavior that changes '{}' from the services using it into \
+                 methods on the entity, so it can protect its own invariants.",
+                node.name
+            )),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Collapse the graph to packages and flag cycles among packages of the same layer.
+/// Cross-layer edges are left to the layer rules, so only same-layer edges are
+/// considered.
+fn detect_intra_layer_cycles(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_intra_layer_cycles {
+        return;
+    }
+
+    let packages = graph.collapse_to_packages();
+    for cycle in packages.find_intra_layer_cycles() {
+        let Some(layer) = cycle[0].layer else {
+            continue;
+        };
+        let names: Vec<String> = cycle.iter().map(|p| p.package.clone()).collect();
+        let location = cycle
+            .iter()
+            .map(|p| &p.location)
+            .find(|l| !l.file.as_os_str().is_empty())
+            .cloned()
+            .unwrap_or_default();
+
+        let kind = ViolationKind::IntraLayerCycle {
+            layer,
+            packages: names.clone(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location,
+            message: format!(
+                "Dependency cycle between {layer} packages: {}",
+                names.join(", ")
+            ),
+            suggestion: Some(
+                "Merge the packages, or move the shared types into a package both can \
+                 depend on."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+fn detect_disallowed_external_dependencies(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    let policies = &config.rules.external_policy;
+    if policies.is_empty() {
+        return;
+    }
+
+    for (src, tgt, edge, location) in graph.edge_occurrences() {
+        if src.is_external || !tgt.is_external || src.is_cross_cutting {
+            continue;
+        }
+        let Some(layer) = src.layer else {
+            continue;
+        };
+        let Some(policy) = policies.for_layer(layer) else {
+            continue;
+        };
+        let Some(import_path) = edge.import_path.as_deref() else {
+            continue;
+        };
+        if policy.permits(import_path) {
+            continue;
+        }
+
+        let kind = ViolationKind::DisallowedExternalDependency {
+            layer,
+            import_path: import_path.to_string(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Error);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: location.clone(),
+            message: format!(
+                "{layer} layer imports external package '{import_path}', which its \
+                 external_policy does not allow"
+            ),
+            suggestion: Some(
+                "Depend on a port in this layer and move the third-party integration \
+                 into an infrastructure adapter."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+fn detect_pattern_violations(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    let nodes = graph.nodes();
+
+    // Collect port names using ComponentKind first, then fall back to name heuristics
+    let port_names: Vec<String> = nodes
+        .iter()
+        .filter(|n| {
+            // Prefer ComponentKind::Port when available
+            if let Some(ComponentKind::Port(_)) = &n.kind {
+                return true;
+            }
+            let name_lower = n.name.to_lowercase();
+            name_lower.contains("port")
+                || name_lower.contains("interface")
+                || (name_lower.contains("repository") && n.layer == Some(ArchLayer::Domain))
+        })
+        .map(|n| n.name.clone())
+        .collect();
+
+    // Check 1: Adapter without port
+    // Only check infrastructure-layer components that are actually adapters
+    let exempt = build_globset(&config.rules.missing_port.exempt_patterns);
+    for node in &nodes {
+        if node.is_cross_cutting || !config.rules.detect_missing_ports {
+            continue;
+        }
+        if exempt.is_match(&node.name) || exempt.is_match(&node.location.file) {
+            continue;
+        }
+
+        let name_lower = node.name.to_lowercase();
+
+        // Use ComponentKind to determine if this is an adapter.
+        // Only infrastructure-layer components are hex arch adapters — application-layer
+        // "handlers" are use cases/coordinators, not adapters.
+        let is_adapter = match &node.kind {
+            Some(ComponentKind::Adapter(_)) if node.layer == Some(ArchLayer::Infrastructure) => {
+                true
+            }
+            Some(ComponentKind::Repository) if node.layer == Some(ArchLayer::Infrastructure) => {
+                true
+            }
+            // Fall back to name heuristic only for infrastructure-layer components
+            None | Some(_) => {
+                node.layer == Some(ArchLayer::Infrastructure)
+                    && (name_lower.ends_with("handler")
+                        || name_lower.ends_with("controller")
+                        || name_lower.contains("adapter")
+                        || name_lower.contains("impl"))
+            }
+        };
+
+        if !is_adapter {
             continue;
         }
 
@@ -439,328 +1544,1521 @@ fn detect_pattern_violations(
             continue;
         }
 
-        // Fallback: check if there's a matching port name pattern (name-heuristic path).
-        let has_port = port_names.iter().any(|port| {
-            let port_lower = port.to_lowercase();
-
-            // Common prefix patterns for infrastructure implementations:
-            // e.g., "MongoInvoiceRepository" → strip "Mongo" prefix and "Repository" suffix
-            //       to match "InvoiceRepository" port
-            let adapter_base = name_lower
-                .trim_end_matches("handler")
-                .trim_end_matches("controller")
-                .trim_end_matches("adapter")
-                .trim_end_matches("impl");
-            let port_base = port_lower
-                .trim_end_matches("port")
-                .trim_end_matches("interface")
-                .trim_end_matches("repository")
-                .trim_end_matches("service");
-
-            // Direct base match (e.g., UserHandler → UserPort)
-            if !adapter_base.is_empty() && !port_base.is_empty() && adapter_base == port_base {
-                return true;
-            }
+        // Fallback: check if there's a matching port name pattern (name-heuristic path).
+        let has_port = port_names
+            .iter()
+            .any(|port| adapter_matches_port(&name_lower, port));
+
+        if !has_port {
+            let kind = ViolationKind::MissingPort {
+                adapter_name: node.name.clone(),
+            };
+            let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+            violations.push(Violation {
+                kind,
+                severity,
+                location: node.location.clone(),
+                message: format!(
+                    "Adapter '{}' has no matching port interface",
+                    node.name
+                ),
+                suggestion: Some(
+                    "Create a port interface that this adapter implements to maintain proper boundaries."
+                        .to_string(),
+                ),
+                fix: crate::fix::missing_port_fix(node),
+                doc_url: None,
+            });
+        }
+    }
+
+    // Check PA003: Constructor returns concrete type instead of port interface
+    for node in &nodes {
+        if let Some(ComponentKind::Adapter(info)) = &node.kind {
+            if let Some(concrete_type) = &info.returns_concrete {
+                let kind = ViolationKind::ConstructorReturnsConcrete {
+                    adapter_name: info.name.clone(),
+                    concrete_type: concrete_type.clone(),
+                };
+                let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+                violations.push(Violation {
+                    kind,
+                    severity,
+                    location: node.location.clone(),
+                    message: format!(
+                        "Constructor for '{}' returns concrete type '*{}' instead of port interface",
+                        info.name, concrete_type
+                    ),
+                    suggestion: Some(
+                        "Change constructor return type to a port interface to follow \
+                         the Dependency Inversion Principle."
+                            .to_string(),
+                    ),
+                    fix: None,
+                    doc_url: None,
+                });
+            }
+        }
+    }
+
+    // Check PA002: Port without implementation — domain port with no adapter implementing it
+    {
+        // Collect all port names that have at least one implementing adapter
+        let mut implemented_ports: std::collections::HashSet<String> =
+            std::collections::HashSet::new();
+        for node in &nodes {
+            if let Some(ComponentKind::Adapter(info)) = &node.kind {
+                for port_name in &info.implements {
+                    implemented_ports.insert(port_name.clone());
+                }
+            }
+        }
+
+        // Also check name-heuristic matching (inverse of PA001 logic).
+        // Include both Adapter and Repository components in infrastructure layer —
+        // a Repository is effectively an adapter implementing a port.
+        let adapter_names: Vec<String> = nodes
+            .iter()
+            .filter(|n| {
+                n.layer == Some(ArchLayer::Infrastructure)
+                    && matches!(
+                        &n.kind,
+                        Some(ComponentKind::Adapter(_)) | Some(ComponentKind::Repository)
+                    )
+            })
+            .map(|n| n.name.to_lowercase())
+            .collect();
+
+        for node in &nodes {
+            if node.is_cross_cutting {
+                continue;
+            }
+            let is_port = matches!(&node.kind, Some(ComponentKind::Port(_)))
+                && node.layer == Some(ArchLayer::Domain);
+            if !is_port {
+                continue;
+            }
+
+            // Check explicit implements relationships
+            if implemented_ports.contains(&node.name) {
+                continue;
+            }
+
+            // Fallback: name-heuristic matching
+            let port_lower = node.name.to_lowercase();
+            let has_adapter = adapter_names
+                .iter()
+                .any(|adapter_lower| adapter_name_matches_port(&port_lower, adapter_lower));
+
+            if !has_adapter {
+                let kind = ViolationKind::PortWithoutImplementation {
+                    port_name: node.name.clone(),
+                };
+                let severity = config.rules.resolve_severity(&kind, Severity::Info);
+                violations.push(Violation {
+                    kind,
+                    severity,
+                    location: node.location.clone(),
+                    message: format!(
+                        "Port '{}' has no infrastructure adapter implementing it",
+                        node.name
+                    ),
+                    suggestion: Some(
+                        "Create an infrastructure adapter that implements this port interface, \
+                         or remove the port if it is no longer needed."
+                            .to_string(),
+                    ),
+                    fix: None,
+                    doc_url: None,
+                });
+            }
+        }
+    }
+
+    // Check 2: DB access outside infrastructure (importing database/driver paths)
+    let infra_keywords = config.rules.infra_keywords();
+    for (src, _tgt, edge, location) in graph.edge_occurrences() {
+        if src.is_external {
+            continue;
+        }
+        if src.is_cross_cutting {
+            continue;
+        }
+        let Some(layer) = src.layer else {
+            continue;
+        };
+        if layer == ArchLayer::Infrastructure {
+            continue;
+        }
+        // ActiveRecord mode allows domain to import infrastructure
+        if layer == ArchLayer::Domain && src.architecture_mode == ArchitectureMode::ActiveRecord {
+            continue;
+        }
+
+        if let Some(ref import_path) = edge.import_path {
+            let path_lower = import_path.to_lowercase();
+            if infra_keywords
+                .iter()
+                .any(|kw| path_lower.contains(kw.as_str()))
+            {
+                let kind = ViolationKind::DomainInfrastructureLeak {
+                    detail: format!("{layer} imports infrastructure path: {import_path}"),
+                };
+                let severity = config.rules.resolve_severity(&kind, Severity::Error);
+                let suggestion = if layer == ArchLayer::Domain {
+                    "Domain should not reference infrastructure directly. \
+                     Use a repository interface (port) in the domain layer instead."
+                } else {
+                    "Only infrastructure should talk to the database. Depend on a repository \
+                     interface (port) and implement it in an infrastructure adapter."
+                };
+                violations.push(Violation {
+                    kind,
+                    severity,
+                    location: location.clone(),
+                    message: format!(
+                        "{layer} layer directly imports infrastructure dependency '{import_path}'"
+                    ),
+                    suggestion: Some(suggestion.to_string()),
+                    fix: None,
+                    doc_url: None,
+                });
+            }
+        }
+    }
+
+    // Check 3: Domain entity directly depending on infrastructure component
+    for (src, tgt, _, location) in graph.edge_occurrences() {
+        if src.is_external || tgt.is_external {
+            continue;
+        }
+        if src.is_cross_cutting || tgt.is_cross_cutting {
+            continue;
+        }
+        // ActiveRecord mode allows domain→infrastructure
+        if src.architecture_mode == ArchitectureMode::ActiveRecord {
+            continue;
+        }
+        if src.layer == Some(ArchLayer::Domain) && tgt.layer == Some(ArchLayer::Infrastructure) {
+            // Already covered by layer boundary violations, but add specific
+            // "missing repository pattern" detail if the target looks like a concrete impl
+            let tgt_lower = tgt.name.to_lowercase();
+            if tgt_lower.contains("postgres")
+                || tgt_lower.contains("mysql")
+                || tgt_lower.contains("redis")
+                || tgt_lower.contains("mongo")
+            {
+                let kind = ViolationKind::DomainInfrastructureLeak {
+                    detail: format!(
+                        "domain entity depends on concrete infrastructure: {}",
+                        tgt.name
+                    ),
+                };
+                let severity = config.rules.resolve_severity(&kind, Severity::Error);
+                violations.push(Violation {
+                    kind,
+                    severity,
+                    location: location.clone(),
+                    message: format!(
+                        "Domain component '{}' directly depends on infrastructure component '{}'",
+                        src.name, tgt.name
+                    ),
+                    suggestion: Some(
+                        "Introduce a repository interface in the domain layer and have the \
+                         infrastructure component implement it."
+                            .to_string(),
+                    ),
+                    fix: None,
+                    doc_url: None,
+                });
+            }
+        }
+    }
+}
+
+/// Flag concrete repository implementations placed in the domain or application
+/// layer: a `Repository` or adapter named after an infrastructure technology
+/// (e.g. `MongoUserRepository`), or an adapter implementing a repository port.
+fn detect_misplaced_adapters(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    let keywords = config.rules.infra_keywords();
+    for node in graph.nodes() {
+        if node.is_external || node.is_cross_cutting {
+            continue;
+        }
+        let Some(layer @ (ArchLayer::Domain | ArchLayer::Application)) = node.layer else {
+            continue;
+        };
+        let is_concrete_repository = match &node.kind {
+            Some(ComponentKind::Repository) => names_infra_technology(&node.name, &keywords),
+            Some(ComponentKind::Adapter(info)) => {
+                names_infra_technology(&node.name, &keywords)
+                    || info.implements.iter().any(|port| {
+                        let port = port.to_lowercase();
+                        port.ends_with("repository") || port.ends_with("repo")
+                    })
+            }
+            _ => false,
+        };
+        if !is_concrete_repository {
+            continue;
+        }
+
+        let kind = ViolationKind::AdapterMisplaced {
+            adapter: node.name.clone(),
+            layer,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: node.location.clone(),
+            message: format!(
+                "Concrete adapter '{}' is in the {layer} layer instead of infrastructure",
+                node.name
+            ),
+            suggestion: Some(
+                "Move the implementation to the infrastructure layer and keep only its \
+                 port interface in the domain."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Flag infrastructure adapters and repositories that depend directly on another
+/// infrastructure adapter or repository, resolved through package-level edges.
+fn detect_adapter_coupling(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_adapter_coupling {
+        return;
+    }
+
+    let adapters: Vec<&GraphNode> = graph
+        .nodes()
+        .into_iter()
+        .filter(|n| {
+            !n.is_external
+                && !n.is_cross_cutting
+                && n.layer == Some(ArchLayer::Infrastructure)
+                && matches!(
+                    n.kind,
+                    Some(ComponentKind::Adapter(_) | ComponentKind::Repository)
+                )
+        })
+        .collect();
+    let index_of = |n: &GraphNode| adapters.iter().position(|a| a.id == n.id);
+    let package_of = graph.package_resolver();
+
+    // One violation per adapter pair linked by an edge. An edge from a file-level
+    // node (e.g. a Go import) stands for the adapters declared in that file; an
+    // edge to a package-level node stands for the adapters in that package.
+    let mut pairs: BTreeSet<(&str, &str, usize, usize)> = BTreeSet::new();
+    for (src, tgt, edge) in graph.edges_with_nodes() {
+        if src.is_external || src.is_cross_cutting || tgt.is_external || tgt.is_cross_cutting {
+            continue;
+        }
+        let sources: Vec<usize> = match index_of(src) {
+            Some(i) => vec![i],
+            None => (0..adapters.len())
+                .filter(|&i| {
+                    edge.locations
+                        .iter()
+                        .any(|l| adapters[i].location.file == l.file)
+                })
+                .collect(),
+        };
+        if sources.is_empty() {
+            continue;
+        }
+        let targets: Vec<usize> = match index_of(tgt) {
+            Some(i) => vec![i],
+            None => {
+                let package = package_of(tgt);
+                (0..adapters.len())
+                    .filter(|&i| adapters[i].id.package() == package)
+                    .collect()
+            }
+        };
+        for &from in &sources {
+            for &to in &targets {
+                if adapters[from].id.package() != adapters[to].id.package() {
+                    pairs.insert((&adapters[from].name, &adapters[to].name, from, to));
+                }
+            }
+        }
+    }
+
+    for (_, _, from, to) in pairs {
+        let (source, target) = (adapters[from], adapters[to]);
+        let kind = ViolationKind::AdapterToAdapterCoupling {
+            from_adapter: source.name.clone(),
+            to_adapter: target.name.clone(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: source.location.clone(),
+            message: format!(
+                "Adapter '{}' depends directly on adapter '{}'",
+                source.name, target.name
+            ),
+            suggestion: Some(format!(
+                "Depend on a port that '{}' implements, so either adapter can be \
+                 swapped without touching the other.",
+                target.name
+            )),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Name heuristic pairing a port with an adapter when no explicit `implements`
+/// relationship is known. Both names must already be lowercased.
+fn adapter_name_matches_port(port_lower: &str, adapter_lower: &str) -> bool {
+    let port_base = port_lower
+        .trim_end_matches("port")
+        .trim_end_matches("interface")
+        .trim_end_matches("repository")
+        .trim_end_matches("service");
+    let adapter_base = adapter_lower
+        .trim_end_matches("handler")
+        .trim_end_matches("controller")
+        .trim_end_matches("adapter")
+        .trim_end_matches("impl");
+
+    // Direct base match
+    if !adapter_base.is_empty() && !port_base.is_empty() && adapter_base == port_base {
+        return true;
+    }
+    // Adapter contains (and so also covers ending with) the port name
+    if !port_lower.is_empty() && adapter_lower.contains(port_lower) {
+        return true;
+    }
+    // Adapter contains port base (e.g., "mongoinvoicerepository" contains "invoice")
+    !port_base.is_empty() && adapter_lower.contains(port_base)
+}
+
+/// PA009: a port whose package depends on the package of one of its adapters. The
+/// port's package and the adapter's package then depend on each other, so neither
+/// side can change or be replaced on its own.
+fn detect_port_adapter_cycles(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_port_adapter_cycles {
+        return;
+    }
+
+    let included = |n: &&GraphNode| !n.is_external && !n.is_cross_cutting;
+    let mut nodes: Vec<&GraphNode> = graph.nodes().into_iter().filter(included).collect();
+    nodes.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+    let ports: Vec<&GraphNode> = nodes
+        .iter()
+        .filter(|n| matches!(n.kind, Some(ComponentKind::Port(_))))
+        .copied()
+        .collect();
+    let adapters: Vec<&GraphNode> = nodes
+        .iter()
+        .filter(|n| {
+            matches!(
+                n.kind,
+                Some(ComponentKind::Adapter(_) | ComponentKind::Repository)
+            )
+        })
+        .copied()
+        .collect();
+    if ports.is_empty() || adapters.is_empty() {
+        return;
+    }
+
+    let packages = graph.collapse_to_packages();
+    let package_edges: std::collections::HashSet<(&str, &str)> = packages
+        .edges()
+        .into_iter()
+        .map(|(from, to, _)| (from.package.as_str(), to.package.as_str()))
+        .collect();
+
+    for port in ports {
+        let port_lower = port.name.to_lowercase();
+        for adapter in &adapters {
+            if adapter.id.package() == port.id.package()
+                || !package_edges.contains(&(port.id.package(), adapter.id.package()))
+            {
+                continue;
+            }
+            let implements = match &adapter.kind {
+                Some(ComponentKind::Adapter(info)) if !info.implements.is_empty() => {
+                    info.implements.contains(&port.name)
+                }
+                _ => adapter_name_matches_port(&port_lower, &adapter.name.to_lowercase()),
+            };
+            if !implements {
+                continue;
+            }
+
+            let kind = ViolationKind::PortAdapterCycle {
+                port: port.name.clone(),
+                adapter: adapter.name.clone(),
+            };
+            let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+            violations.push(Violation {
+                kind,
+                severity,
+                location: port.location.clone(),
+                message: format!(
+                    "Port '{}' is implemented by '{}', but the port's package '{}' also \
+                     depends on the adapter's package '{}'",
+                    port.name,
+                    adapter.name,
+                    port.id.package(),
+                    adapter.id.package()
+                ),
+                suggestion: Some(
+                    "Remove the dependency from the port's package on the adapter's package; \
+                     the port's side should only know the port, and the adapter should be \
+                     wired in from the outside."
+                        .to_string(),
+                ),
+                fix: None,
+                doc_url: None,
+            });
+        }
+    }
+}
+
+/// Whether a type name starts a word with one of the (lowercase) infrastructure
+/// keywords, e.g. `MongoUserRepository` or `UserDBStore` but not `FeedbackRepository`.
+fn names_infra_technology(name: &str, keywords: &[String]) -> bool {
+    let lower = name.to_ascii_lowercase();
+    keywords.iter().any(|kw| {
+        lower.match_indices(kw.as_str()).any(|(i, _)| {
+            i == 0
+                || name[i..].starts_with(|c: char| c.is_ascii_uppercase())
+                || name[..i].ends_with('_')
+        })
+    })
+}
+
+/// `(package, type)` pairs of the types declared by infrastructure components,
+/// keyed by the last segment of the package path.
+fn infrastructure_type_names<'a>(nodes: &[&'a GraphNode]) -> HashSet<(&'a str, &'a str)> {
+    nodes
+        .iter()
+        .filter(|n| !n.is_external && n.layer == Some(ArchLayer::Infrastructure))
+        .filter_map(|n| {
+            let package = n.id.package().rsplit('/').next()?;
+            Some((package, n.name.as_str()))
+        })
+        .collect()
+}
+
+/// Whether a type expression contains a package-qualified type that names an
+/// infrastructure technology (`*sql.Tx`) or is declared in `infra_types`.
+fn references_infra_type(
+    type_name: &str,
+    infra_types: &HashSet<(&str, &str)>,
+    keywords: &[String],
+) -> bool {
+    type_name
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .filter_map(|token| token.split_once('.'))
+        .any(|(qualifier, name)| {
+            names_infra_technology(qualifier, keywords) || infra_types.contains(&(qualifier, name))
+        })
+}
+
+/// Flag domain port methods whose parameter types reference infrastructure: a
+/// package qualifier naming an infrastructure technology (`*sql.Tx`), or a type
+/// declared by an infrastructure component in that package (`*postgres.UserRow`).
+fn detect_infra_port_parameters(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_infra_port_parameters {
+        return;
+    }
+
+    let nodes = graph.nodes();
+    let infra_types = infrastructure_type_names(&nodes);
+    let keywords = config.rules.infra_keywords();
+    let is_infra_type =
+        |param_type: &str| references_infra_type(param_type, &infra_types, &keywords);
+
+    for port in &nodes {
+        if port.is_external || port.is_cross_cutting || port.layer != Some(ArchLayer::Domain) {
+            continue;
+        }
+        if matches!(
+            port.architecture_mode,
+            ArchitectureMode::ActiveRecord | ArchitectureMode::ServiceOriented
+        ) {
+            continue;
+        }
+        let Some(ComponentKind::Port(info)) = &port.kind else {
+            continue;
+        };
+
+        for method in &info.methods {
+            for param_type in method.parameter_types.iter().filter(|t| is_infra_type(t)) {
+                let kind = ViolationKind::DomainInfrastructureLeak {
+                    detail: format!(
+                        "domain port {}.{} takes infrastructure type {param_type}",
+                        port.name, method.name
+                    ),
+                };
+                let severity = config.rules.resolve_severity(&kind, Severity::Error);
+                violations.push(Violation {
+                    kind,
+                    severity,
+                    location: port.location.clone(),
+                    message: format!(
+                        "Domain port '{}' method '{}' takes infrastructure type '{param_type}'",
+                        port.name, method.name
+                    ),
+                    suggestion: Some(
+                        "Keep infrastructure types out of port signatures. Pass a domain \
+                         type, or let the adapter manage transactions and connections."
+                            .to_string(),
+                    ),
+                    fix: None,
+                    doc_url: None,
+                });
+            }
+        }
+    }
+}
+
+/// Flag public fields of domain entities and value objects whose types reference
+/// infrastructure, e.g. a `User` exposing a `*sql.DB`. Private fields are the
+/// type's own business and are not checked.
+fn detect_infra_field_types(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_infra_field_types {
+        return;
+    }
+
+    let nodes = graph.nodes();
+    let infra_types = infrastructure_type_names(&nodes);
+    let keywords = config.rules.infra_keywords();
+
+    for node in &nodes {
+        if node.is_external || node.is_cross_cutting || node.layer != Some(ArchLayer::Domain) {
+            continue;
+        }
+        if matches!(
+            node.architecture_mode,
+            ArchitectureMode::ActiveRecord | ArchitectureMode::ServiceOriented
+        ) {
+            continue;
+        }
+        let fields = match &node.kind {
+            Some(ComponentKind::Entity(info)) => &info.fields,
+            Some(ComponentKind::ValueObject(info)) => &info.fields,
+            _ => continue,
+        };
+
+        for field in fields.iter().filter(|f| {
+            f.visibility == Visibility::Public
+                && references_infra_type(&f.type_name, &infra_types, &keywords)
+        }) {
+            let kind = ViolationKind::DomainInfrastructureLeak {
+                detail: format!(
+                    "domain type {}.{} has infrastructure type {}",
+                    node.name, field.name, field.type_name
+                ),
+            };
+            let severity = config.rules.resolve_severity(&kind, Severity::Error);
+            violations.push(Violation {
+                kind,
+                severity,
+                location: node.location.clone(),
+                message: format!(
+                    "Domain type '{}' field '{}' has infrastructure type '{}'",
+                    node.name, field.name, field.type_name
+                ),
+                suggestion: Some(
+                    "Keep infrastructure handles out of domain types. Move the field to an \
+                     infrastructure adapter behind a port."
+                        .to_string(),
+                ),
+                fix: None,
+                doc_url: None,
+            });
+        }
+    }
+}
+
+/// Method annotations that tie code to a transaction manager or persistence
+/// framework: Spring's transaction and repository annotations, and JPA's
+/// mapping and lifecycle annotations.
+const PERSISTENCE_ANNOTATIONS: &[&str] = &[
+    "Transactional",
+    "Query",
+    "Modifying",
+    "Lock",
+    "PersistenceContext",
+    "Column",
+    "Id",
+    "GeneratedValue",
+    "OneToOne",
+    "OneToMany",
+    "ManyToOne",
+    "ManyToMany",
+    "JoinColumn",
+    "PrePersist",
+    "PostPersist",
+    "PreUpdate",
+    "PostUpdate",
+    "PreRemove",
+    "PostRemove",
+    "PostLoad",
+];
+
+/// Flag methods of domain components carrying persistence annotations, e.g. a
+/// domain service method marked `@Transactional`.
+fn detect_persistence_annotations(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_persistence_annotations {
+        return;
+    }
+
+    for node in graph.nodes() {
+        if node.is_external || node.is_cross_cutting || node.layer != Some(ArchLayer::Domain) {
+            continue;
+        }
+        if matches!(
+            node.architecture_mode,
+            ArchitectureMode::ActiveRecord | ArchitectureMode::ServiceOriented
+        ) {
+            continue;
+        }
+        let port_methods = match &node.kind {
+            Some(ComponentKind::Port(info)) => info.methods.as_slice(),
+            _ => &[],
+        };
+
+        for method in node.methods.iter().chain(port_methods) {
+            for annotation in method
+                .annotations
+                .iter()
+                .filter(|a| PERSISTENCE_ANNOTATIONS.contains(&a.as_str()))
+            {
+                let kind = ViolationKind::DomainInfrastructureLeak {
+                    detail: format!(
+                        "domain method {}.{} is annotated @{annotation}",
+                        node.name, method.name
+                    ),
+                };
+                let severity = config.rules.resolve_severity(&kind, Severity::Error);
+                violations.push(Violation {
+                    kind,
+                    severity,
+                    location: node.location.clone(),
+                    message: format!(
+                        "Domain method '{}.{}' has persistence annotation '@{annotation}'",
+                        node.name, method.name
+                    ),
+                    suggestion: Some(
+                        "Keep transactions and persistence mapping out of the domain. Demarcate \
+                         transactions in the application layer and map entities in an \
+                         infrastructure adapter."
+                            .to_string(),
+                    ),
+                    fix: None,
+                    doc_url: None,
+                });
+            }
+        }
+    }
+}
+
+fn detect_init_violations(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_init_functions {
+        return;
+    }
+
+    for (src, tgt, _, location) in graph.edge_occurrences() {
+        // Only check edges from init functions (component ID contains "<init>")
+        if !src.id.0.contains("<init>") {
+            continue;
+        }
+
+        if src.is_cross_cutting || tgt.is_cross_cutting {
+            continue;
+        }
+
+        let (Some(from_layer), Some(to_layer)) = (src.layer, tgt.layer) else {
+            continue;
+        };
+
+        if from_layer.violates_dependency_on(&to_layer) {
+            let init_file = location.file.to_string_lossy().to_string();
+            let called_package = tgt.id.untagged().to_string();
+
+            let kind = ViolationKind::InitFunctionCoupling {
+                init_file: init_file.clone(),
+                called_package: called_package.clone(),
+                from_layer,
+                to_layer,
+            };
+            let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+
+            violations.push(Violation {
+                kind,
+                severity,
+                location: location.clone(),
+                message: format!(
+                    "init() function in {from_layer} layer calls into {to_layer} layer ({called_package})"
+                ),
+                suggestion: Some(
+                    "Move initialization logic out of init() or use dependency injection to avoid hidden cross-layer coupling."
+                        .to_string(),
+                ),
+                fix: None,
+                doc_url: None,
+            });
+        }
+    }
+}
+
+/// `name` with its first letter uppercased, so camelCase methods (`findById`) compare
+/// like Go's exported PascalCase ones.
+fn upper_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn detect_fat_repositories(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_fat_repositories {
+        return;
+    }
+
+    for node in graph.nodes() {
+        if node.is_cross_cutting || node.is_external {
+            continue;
+        }
+        if !matches!(node.kind, Some(ComponentKind::Repository)) {
+            continue;
+        }
+        // Private helpers are not part of the repository's surface.
+        let public: Vec<&MethodInfo> = node.methods.iter().filter(|m| m.is_public()).collect();
+        if public.is_empty() {
+            continue;
+        }
+
+        let method_count = public.len();
+        let non_crud_methods: Vec<String> = public
+            .iter()
+            .filter(|m| !is_crud_method_name(&upper_first(&m.name)))
+            .map(|m| m.name.clone())
+            .collect();
+        let too_many = method_count > config.rules.max_repository_methods;
+        if !too_many && non_crud_methods.is_empty() {
+            continue;
+        }
+
+        let message = if non_crud_methods.is_empty() {
+            format!(
+                "Repository '{}' has {method_count} methods (max {})",
+                node.name, config.rules.max_repository_methods
+            )
+        } else {
+            format!(
+                "Repository '{}' has non-CRUD methods: {}",
+                node.name,
+                non_crud_methods.join(", ")
+            )
+        };
+
+        let kind = ViolationKind::FatRepository {
+            repository_name: node.name.clone(),
+            method_count,
+            non_crud_methods,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: node.location.clone(),
+            message,
+            suggestion: Some(
+                "Keep repositories to persistence operations and move business logic into \
+                 domain entities or services."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+fn detect_duplicate_ports(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_duplicate_ports {
+        return;
+    }
+
+    let mut ports: Vec<(&GraphNode, BTreeSet<&str>)> = graph
+        .nodes()
+        .into_iter()
+        .filter(|n| !n.is_external && !n.is_cross_cutting)
+        .filter_map(|n| match &n.kind {
+            Some(ComponentKind::Port(info)) if !info.methods.is_empty() => {
+                let methods = info.methods.iter().map(|m| m.name.as_str()).collect();
+                Some((n, methods))
+            }
+            _ => None,
+        })
+        .collect();
+    ports.sort_by(|(a, _), (b, _)| a.location.file.cmp(&b.location.file));
+
+    let mut reported = vec![false; ports.len()];
+    for i in 0..ports.len() {
+        if reported[i] {
+            continue;
+        }
+        let (first, methods) = &ports[i];
+        let first_dir = first.location.file.parent();
+
+        let mut group = Vec::new();
+        for j in (i + 1)..ports.len() {
+            let (other, other_methods) = &ports[j];
+            if !reported[j]
+                && other.location.file.parent() != first_dir
+                && other_methods == methods
+                && similar_port_names(&first.name, &other.name)
+            {
+                reported[j] = true;
+                group.push(*other);
+            }
+        }
+        if group.is_empty() {
+            continue;
+        }
+
+        let duplicates: Vec<String> = group
+            .iter()
+            .map(|n| format!("{}:{}", n.location.file.display(), n.location.line))
+            .collect();
+        let kind = ViolationKind::DuplicatePort {
+            port_name: first.name.clone(),
+            duplicates: duplicates.clone(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: first.location.clone(),
+            message: format!(
+                "Port '{}' is also declared at {}",
+                first.name,
+                duplicates.join(", ")
+            ),
+            suggestion: Some(
+                "Consolidate duplicate port interfaces into a single definition that all \
+                 consumers import."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Collapse the graph to packages and flag infrastructure packages whose net
+/// dependency flow with the domain points the wrong way: domain packages depend on
+/// them more than they depend on domain packages.
+fn detect_module_direction_violations(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_module_direction {
+        return;
+    }
+
+    let packages = graph.collapse_to_packages();
+    let all = packages.packages();
+    let domain: Vec<&str> = all
+        .iter()
+        .filter(|p| p.layer == Some(ArchLayer::Domain))
+        .map(|p| p.package.as_str())
+        .collect();
+
+    for infra in all
+        .iter()
+        .filter(|p| p.layer == Some(ArchLayer::Infrastructure))
+    {
+        let mut inbound = 0;
+        let mut outbound = 0;
+        let mut dependents = Vec::new();
+        for &d in &domain {
+            let w = packages.weight(d, &infra.package);
+            if w > 0 {
+                dependents.push(d.to_string());
+            }
+            inbound += w;
+            outbound += packages.weight(&infra.package, d);
+        }
+        if inbound <= outbound {
+            continue;
+        }
+        dependents.sort();
+
+        let kind = ViolationKind::ModuleDirectionViolation {
+            module: infra.package.clone(),
+            dependent_modules: dependents.clone(),
+            inbound_from_domain: inbound,
+            outbound_to_domain: outbound,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: infra.location.clone(),
+            message: format!(
+                "Infrastructure module '{}' is depended on by domain ({}) more than it \
+                 depends on domain: {inbound} inbound vs {outbound} outbound edges",
+                infra.package,
+                dependents.join(", ")
+            ),
+            suggestion: Some(
+                "Invert the dependency: define ports in the domain and have this \
+                 infrastructure module implement them."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
 
-            // Check if the adapter name contains the port name (e.g., MongoInvoiceRepository contains InvoiceRepository)
-            if name_lower.contains(&port_lower) {
-                return true;
-            }
+fn detect_fat_interfaces(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_fat_interfaces {
+        return;
+    }
 
-            // Check if the adapter name ends with the port name after stripping a vendor prefix
-            // e.g., "stripepaymentprocessor" contains port base "paymentprocessor"
-            // e.g., "mongoinvoicerepository" ends_with "invoicerepository"
-            if !port_lower.is_empty() && name_lower.ends_with(&port_lower) {
-                return true;
-            }
+    let max = config.rules.max_interface_methods;
+    for node in graph.nodes() {
+        if node.is_cross_cutting || node.is_external {
+            continue;
+        }
+        let Some(ComponentKind::Port(info)) = &node.kind else {
+            continue;
+        };
+        let method_count = info.methods.iter().filter(|m| m.is_public()).count();
+        if method_count <= max {
+            continue;
+        }
 
-            false
+        let kind = ViolationKind::FatInterface {
+            port: node.name.clone(),
+            method_count,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: node.location.clone(),
+            message: format!(
+                "Port '{}' declares {method_count} methods (max {max})",
+                node.name
+            ),
+            suggestion: Some(
+                "Split the interface into smaller, client-specific ports \
+                 (Interface Segregation Principle)."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
         });
+    }
+}
 
-        if !has_port {
-            let kind = ViolationKind::MissingPort {
-                adapter_name: node.name.clone(),
-            };
-            let severity = config.rules.resolve_severity(&kind, Severity::Warning);
-            violations.push(Violation {
-                kind,
-                severity,
-                location: node.location.clone(),
-                message: format!(
-                    "Adapter '{}' has no matching port interface",
-                    node.name
-                ),
-                suggestion: Some(
-                    "Create a port interface that this adapter implements to maintain proper boundaries."
-                        .to_string(),
-                ),
-            });
-        }
+/// Whether adapter `name_lower` (lowercased) matches port `port` by name, e.g.
+/// `mongoinvoicerepository` and `InvoiceRepository`.
+fn adapter_matches_port(name_lower: &str, port: &str) -> bool {
+    let port_lower = port.to_lowercase();
+
+    // Common prefix patterns for infrastructure implementations:
+    // e.g., "MongoInvoiceRepository" → strip "Mongo" prefix and "Repository" suffix
+    //       to match "InvoiceRepository" port
+    let adapter_base = name_lower
+        .trim_end_matches("handler")
+        .trim_end_matches("controller")
+        .trim_end_matches("adapter")
+        .trim_end_matches("impl");
+    let port_base = port_lower
+        .trim_end_matches("port")
+        .trim_end_matches("interface")
+        .trim_end_matches("repository")
+        .trim_end_matches("service");
+
+    // Direct base match (e.g., UserHandler → UserPort)
+    if !adapter_base.is_empty() && !port_base.is_empty() && adapter_base == port_base {
+        return true;
     }
 
-    // Check PA003: Constructor returns concrete type instead of port interface
-    for node in &nodes {
-        if let Some(ComponentKind::Adapter(info)) = &node.kind {
-            if let Some(concrete_type) = &info.returns_concrete {
-                let kind = ViolationKind::ConstructorReturnsConcrete {
-                    adapter_name: info.name.clone(),
-                    concrete_type: concrete_type.clone(),
-                };
-                let severity = config.rules.resolve_severity(&kind, Severity::Warning);
-                violations.push(Violation {
-                    kind,
-                    severity,
-                    location: node.location.clone(),
-                    message: format!(
-                        "Constructor for '{}' returns concrete type '*{}' instead of port interface",
-                        info.name, concrete_type
-                    ),
-                    suggestion: Some(
-                        "Change constructor return type to a port interface to follow \
-                         the Dependency Inversion Principle."
-                            .to_string(),
-                    ),
-                });
-            }
-        }
+    // Check if the adapter name contains the port name (e.g., MongoInvoiceRepository contains InvoiceRepository)
+    if name_lower.contains(&port_lower) {
+        return true;
     }
 
-    // Check PA002: Port without implementation — domain port with no adapter implementing it
-    {
-        // Collect all port names that have at least one implementing adapter
-        let mut implemented_ports: std::collections::HashSet<String> =
-            std::collections::HashSet::new();
-        for node in &nodes {
-            if let Some(ComponentKind::Adapter(info)) = &node.kind {
-                for port_name in &info.implements {
-                    implemented_ports.insert(port_name.clone());
-                }
-            }
-        }
+    // Check if the adapter name ends with the port name after stripping a vendor prefix
+    // e.g., "stripepaymentprocessor" contains port base "paymentprocessor"
+    // e.g., "mongoinvoicerepository" ends_with "invoicerepository"
+    if !port_lower.is_empty() && name_lower.ends_with(&port_lower) {
+        return true;
+    }
 
-        // Also check name-heuristic matching (inverse of PA001 logic).
-        // Include both Adapter and Repository components in infrastructure layer —
-        // a Repository is effectively an adapter implementing a port.
-        let adapter_names: Vec<String> = nodes
-            .iter()
-            .filter(|n| {
-                n.layer == Some(ArchLayer::Infrastructure)
-                    && matches!(
-                        &n.kind,
-                        Some(ComponentKind::Adapter(_)) | Some(ComponentKind::Repository)
-                    )
-            })
-            .map(|n| n.name.to_lowercase())
-            .collect();
+    false
+}
 
-        for node in &nodes {
-            if node.is_cross_cutting {
-                continue;
+/// Flag factory functions whose declared return type is a concrete adapter or
+/// repository for which a matching port exists.
+///
+/// A factory returning an unqualified type is matched against components of its own
+/// package, a qualified one against the package named by the qualifier. Adapter
+/// constructors already reported by PA003 are skipped.
+fn detect_factory_concrete_returns(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_factory_concrete_returns {
+        return;
+    }
+
+    let nodes = graph.nodes();
+    let ports: Vec<&str> = nodes
+        .iter()
+        .filter(|n| !n.is_external && matches!(n.kind, Some(ComponentKind::Port(_))))
+        .map(|n| n.name.as_str())
+        .collect();
+
+    for factory in graph.factories() {
+        let factory_dir = factory.location.file.parent();
+        let Some(adapter) = nodes.iter().find(|n| {
+            if n.is_external || n.is_cross_cutting || n.name != factory.return_type {
+                return false;
             }
-            let is_port = matches!(&node.kind, Some(ComponentKind::Port(_)))
-                && node.layer == Some(ArchLayer::Domain);
-            if !is_port {
-                continue;
+            let dir = n.location.file.parent();
+            if factory.return_package.is_empty() {
+                dir == factory_dir
+            } else {
+                dir.and_then(|d| d.file_name())
+                    .is_some_and(|d| d == factory.return_package.as_str())
             }
-
-            // Check explicit implements relationships
-            if implemented_ports.contains(&node.name) {
-                continue;
+        }) else {
+            continue;
+        };
+        let port = match &adapter.kind {
+            Some(ComponentKind::Adapter(info)) => {
+                if info.returns_concrete.is_some() && adapter.location.file.parent() == factory_dir
+                {
+                    continue;
+                }
+                info.implements.first().map(String::as_str)
             }
+            Some(ComponentKind::Repository) => None,
+            _ => continue,
+        };
+        let name_lower = adapter.name.to_lowercase();
+        let Some(port) = port.or_else(|| {
+            ports
+                .iter()
+                .copied()
+                .find(|p| adapter_matches_port(&name_lower, p))
+        }) else {
+            continue;
+        };
 
-            // Fallback: name-heuristic matching
-            let port_lower = node.name.to_lowercase();
-            let port_base = port_lower
-                .trim_end_matches("port")
-                .trim_end_matches("interface")
-                .trim_end_matches("repository")
-                .trim_end_matches("service");
-
-            let has_adapter = adapter_names.iter().any(|adapter_lower| {
-                let adapter_base = adapter_lower
-                    .trim_end_matches("handler")
-                    .trim_end_matches("controller")
-                    .trim_end_matches("adapter")
-                    .trim_end_matches("impl");
-
-                // Direct base match
-                if !adapter_base.is_empty() && !port_base.is_empty() && adapter_base == port_base {
-                    return true;
-                }
-                // Adapter contains port name
-                if adapter_lower.contains(&port_lower) {
-                    return true;
-                }
-                // Adapter ends with port name
-                if !port_lower.is_empty() && adapter_lower.ends_with(&port_lower) {
-                    return true;
-                }
-                // Adapter contains port base (e.g., "mongoinvoicerepository" contains "invoice")
-                if !port_base.is_empty() && adapter_lower.contains(port_base) {
-                    return true;
-                }
-                false
-            });
+        let qualified = if factory.return_package.is_empty() {
+            factory.return_type.clone()
+        } else {
+            format!("{}.{}", factory.return_package, factory.return_type)
+        };
+        let kind = ViolationKind::FactoryReturnsConcrete {
+            factory: factory.name.clone(),
+            concrete_type: factory.return_type.clone(),
+            port: port.to_string(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: factory.location.clone(),
+            message: format!(
+                "Factory '{}' returns concrete type '{qualified}' although port '{port}' exists",
+                factory.name
+            ),
+            suggestion: Some(format!(
+                "Return '{port}' from '{}' so callers depend on the port, not the adapter.",
+                factory.name
+            )),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
 
-            if !has_adapter {
-                let kind = ViolationKind::PortWithoutImplementation {
-                    port_name: node.name.clone(),
-                };
-                let severity = config.rules.resolve_severity(&kind, Severity::Info);
-                violations.push(Violation {
-                    kind,
-                    severity,
-                    location: node.location.clone(),
-                    message: format!(
-                        "Port '{}' has no infrastructure adapter implementing it",
-                        node.name
-                    ),
-                    suggestion: Some(
-                        "Create an infrastructure adapter that implements this port interface, \
-                         or remove the port if it is no longer needed."
-                            .to_string(),
-                    ),
-                });
-            }
-        }
+/// Flag ports with more than `max_port_default_methods` methods that carry a body,
+/// i.e. Java `default` and `static` interface methods: behavior, not contract.
+fn detect_behavior_heavy_ports(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_behavior_heavy_ports {
+        return;
     }
 
-    // Check 2: DB access in domain layer (domain importing infrastructure paths)
-    for (src, _tgt, edge) in graph.edges_with_nodes() {
-        if src.is_external {
+    let max = config.rules.max_port_default_methods;
+    for node in graph.nodes() {
+        if node.is_cross_cutting || node.is_external {
             continue;
         }
-        if src.is_cross_cutting {
+        let Some(ComponentKind::Port(info)) = &node.kind else {
+            continue;
+        };
+        let methods: Vec<String> = info
+            .methods
+            .iter()
+            .filter(|m| m.has_body())
+            .map(|m| m.name.clone())
+            .collect();
+        if methods.len() <= max {
             continue;
         }
-        // ActiveRecord mode allows domain to import infrastructure
-        if src.architecture_mode == ArchitectureMode::ActiveRecord {
+
+        let message = format!(
+            "Port '{}' implements {} of its {} methods as default or static methods ({}; max {max})",
+            node.name,
+            methods.len(),
+            info.methods.len(),
+            methods.join(", ")
+        );
+        let kind = ViolationKind::BehaviorHeavyPort {
+            port: node.name.clone(),
+            methods,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: node.location.clone(),
+            message,
+            suggestion: Some(
+                "Keep the port a pure contract. Move the shared logic into a domain service \
+                 or an abstract base class of the adapters."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Flag application services and use cases whose package depends on nothing but
+/// a single repository, and which expose no more methods than that repository.
+///
+/// Method bodies are not analyzed, so "only delegates" is approximated by the
+/// method count: a service no wider than its one repository adds no logic of its own.
+fn detect_pass_through_services(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_pass_through_services {
+        return;
+    }
+
+    let nodes: Vec<&GraphNode> = graph
+        .nodes()
+        .into_iter()
+        .filter(|n| !n.is_external && !n.is_cross_cutting)
+        .collect();
+    let packages = graph.collapse_to_packages();
+    let edges = packages.edges();
+
+    for service in &nodes {
+        if service.layer != Some(ArchLayer::Application)
+            || !matches!(
+                service.kind,
+                Some(ComponentKind::Service | ComponentKind::UseCase)
+            )
+            || service.methods.is_empty()
+        {
             continue;
         }
-        if src.layer != Some(ArchLayer::Domain) {
+
+        let package = service.id.package();
+        let targets: Vec<&str> = edges
+            .iter()
+            .filter(|(from, _, _)| from.package == package)
+            .map(|(_, to, _)| to.package.as_str())
+            .collect();
+        let [target] = targets.as_slice() else {
+            continue;
+        };
+
+        let repositories: Vec<(&GraphNode, usize)> = nodes
+            .iter()
+            .filter(|n| n.id.package() == *target)
+            .filter_map(|n| repository_method_count(n).map(|count| (*n, count)))
+            .collect();
+        let [(repository, repository_methods)] = repositories.as_slice() else {
+            continue;
+        };
+
+        let method_count = service.methods.len();
+        if method_count > *repository_methods {
             continue;
         }
 
-        if let Some(ref import_path) = edge.import_path {
-            let path_lower = import_path.to_lowercase();
-            if INFRA_KEYWORDS.iter().any(|kw| path_lower.contains(kw)) {
-                let kind = ViolationKind::DomainInfrastructureLeak {
-                    detail: format!("domain imports infrastructure path: {import_path}"),
-                };
-                let severity = config.rules.resolve_severity(&kind, Severity::Error);
-                violations.push(Violation {
-                    kind,
-                    severity,
-                    location: edge.location.clone(),
-                    message: format!(
-                        "Domain layer directly imports infrastructure dependency '{import_path}'"
-                    ),
-                    suggestion: Some(
-                        "Domain should not reference infrastructure directly. \
-                         Use a repository interface (port) in the domain layer instead."
-                            .to_string(),
-                    ),
-                });
-            }
+        let kind = ViolationKind::PassThroughService {
+            service_name: service.name.clone(),
+            repository_name: repository.name.clone(),
+            method_count,
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Info);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: service.location.clone(),
+            message: format!(
+                "Application service '{}' depends only on repository '{}' and has \
+                 {method_count} method(s), no more than the repository's {repository_methods}",
+                service.name, repository.name
+            ),
+            suggestion: Some(
+                "If the service only forwards calls, let callers use the repository port \
+                 directly, or move the orchestration logic it should own into it."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
+
+/// Method count of a repository: a `Repository` component, or a port named like one.
+fn repository_method_count(node: &GraphNode) -> Option<usize> {
+    match &node.kind {
+        Some(ComponentKind::Repository) => Some(node.methods.len()),
+        Some(ComponentKind::Port(info)) if node.name.to_lowercase().ends_with("repository") => {
+            Some(info.methods.len())
         }
+        _ => None,
     }
+}
 
-    // Check 3: Domain entity directly depending on infrastructure component
-    for (src, tgt, edge) in graph.edges_with_nodes() {
-        if src.is_external || tgt.is_external {
+/// Layer directory names that mark where a bounded context's own tree begins.
+const LAYER_DIRS: &[&str] = &["domain", "application", "infrastructure", "presentation"];
+
+/// Bounded context of a source file: the directory holding its first layer directory,
+/// e.g. `services/billing` for `services/billing/domain/invoice.go`. `None` when the
+/// path has no layer directory.
+fn bounded_context(file: &std::path::Path) -> Option<String> {
+    let dirs: Vec<String> = file
+        .parent()?
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    let layer_at = dirs.iter().position(|d| LAYER_DIRS.contains(&d.as_str()))?;
+    Some(dirs[..layer_at].join("/"))
+}
+
+/// Group components by bounded context and flag contexts whose components were
+/// analyzed under more than one architecture mode.
+fn detect_mixed_architecture_modes(
+    graph: &DependencyGraph,
+    config: &Config,
+    violations: &mut Vec<Violation>,
+) {
+    if !config.rules.detect_mixed_architecture_modes {
+        return;
+    }
+
+    let mut contexts: BTreeMap<String, BTreeMap<ArchitectureMode, Vec<&GraphNode>>> =
+        BTreeMap::new();
+    for node in graph.nodes() {
+        if node.is_external || node.kind.is_none() {
             continue;
         }
-        if src.is_cross_cutting || tgt.is_cross_cutting {
+        let Some(context) = bounded_context(&node.location.file) else {
             continue;
-        }
-        // ActiveRecord mode allows domain→infrastructure
-        if src.architecture_mode == ArchitectureMode::ActiveRecord {
+        };
+        contexts
+            .entry(context)
+            .or_default()
+            .entry(node.architecture_mode)
+            .or_default()
+            .push(node);
+    }
+
+    for (context, by_mode) in contexts {
+        if by_mode.len() < 2 {
             continue;
         }
-        if src.layer == Some(ArchLayer::Domain) && tgt.layer == Some(ArchLayer::Infrastructure) {
-            // Already covered by layer boundary violations, but add specific
-            // "missing repository pattern" detail if the target looks like a concrete impl
-            let tgt_lower = tgt.name.to_lowercase();
-            if tgt_lower.contains("postgres")
-                || tgt_lower.contains("mysql")
-                || tgt_lower.contains("redis")
-                || tgt_lower.contains("mongo")
-            {
-                let kind = ViolationKind::DomainInfrastructureLeak {
-                    detail: format!(
-                        "domain entity depends on concrete infrastructure: {}",
-                        tgt.name
-                    ),
-                };
-                let severity = config.rules.resolve_severity(&kind, Severity::Error);
-                violations.push(Violation {
-                    kind,
-                    severity,
-                    location: edge.location.clone(),
-                    message: format!(
-                        "Domain component '{}' directly depends on infrastructure component '{}'",
-                        src.name, tgt.name
-                    ),
-                    suggestion: Some(
-                        "Introduce a repository interface in the domain layer and have the \
-                         infrastructure component implement it."
-                            .to_string(),
-                    ),
-                });
-            }
-        }
+        // Point at the first component outside the context's dominant mode.
+        let dominant = by_mode
+            .iter()
+            .max_by_key(|(_, nodes)| nodes.len())
+            .map(|(mode, _)| *mode);
+        let location = by_mode
+            .iter()
+            .filter(|(mode, _)| Some(**mode) != dominant)
+            .flat_map(|(_, nodes)| nodes.iter())
+            .map(|n| &n.location)
+            .min_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)))
+            .cloned()
+            .unwrap_or_default();
+        let counts = by_mode
+            .iter()
+            .map(|(mode, nodes)| format!("{mode} ({})", nodes.len()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let kind = ViolationKind::MixedArchitectureModes {
+            context: context.clone(),
+            modes: by_mode.keys().copied().collect(),
+        };
+        let severity = config.rules.resolve_severity(&kind, Severity::Info);
+        violations.push(Violation {
+            kind,
+            severity,
+            location,
+            message: format!("Bounded context '{context}' mixes architecture modes: {counts}"),
+            suggestion: Some(
+                "Pick one architecture mode per bounded context and widen or narrow the \
+                 `[[layers.overrides]]` scopes to match it."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
     }
 }
 
-fn detect_init_violations(
+/// Group components by bounded context and flag contexts that contain adapters or
+/// repositories but not a single port.
+fn detect_context_missing_ports(
     graph: &DependencyGraph,
     config: &Config,
     violations: &mut Vec<Violation>,
 ) {
-    if !config.rules.detect_init_functions {
+    if !config.rules.detect_context_missing_ports {
         return;
     }
 
-    for (src, tgt, edge) in graph.edges_with_nodes() {
-        // Only check edges from init functions (component ID contains "<init>")
-        if !src.id.0.contains("<init>") {
+    // Per context: whether it declares a port, and its adapters and repositories.
+    let mut contexts: BTreeMap<String, (bool, Vec<&GraphNode>)> = BTreeMap::new();
+    for node in graph.nodes() {
+        if node.is_external || node.is_cross_cutting {
             continue;
         }
-
-        if src.is_cross_cutting || tgt.is_cross_cutting {
+        let Some(kind) = &node.kind else {
+            continue;
+        };
+        let Some(context) = bounded_context(&node.location.file) else {
             continue;
+        };
+        let entry = contexts.entry(context).or_default();
+        match kind {
+            ComponentKind::Port(_) => entry.0 = true,
+            ComponentKind::Adapter(_) | ComponentKind::Repository => entry.1.push(node),
+            _ => {}
         }
+    }
 
-        let (Some(from_layer), Some(to_layer)) = (src.layer, tgt.layer) else {
+    for (context, (has_port, mut adapters)) in contexts {
+        if has_port || adapters.is_empty() {
             continue;
+        }
+        adapters.sort_by(|a, b| {
+            (&a.location.file, a.location.line).cmp(&(&b.location.file, b.location.line))
+        });
+        let names: Vec<String> = adapters.iter().map(|n| n.name.clone()).collect();
+        let message = format!(
+            "Bounded context '{context}' has adapters ({}) but no port",
+            names.join(", ")
+        );
+        let kind = ViolationKind::ContextMissingPort {
+            context: context.clone(),
+            adapters: names,
         };
+        let severity = config.rules.resolve_severity(&kind, Severity::Warning);
+        violations.push(Violation {
+            kind,
+            severity,
+            location: adapters[0].location.clone(),
+            message,
+            suggestion: Some(
+                "Declare the interfaces the context depends on as ports in its domain layer \
+                 and have the adapters implement them."
+                    .to_string(),
+            ),
+            fix: None,
+            doc_url: None,
+        });
+    }
+}
 
-        if from_layer.violates_dependency_on(&to_layer) {
-            let init_file = edge.location.file.to_string_lossy().to_string();
-            let called_package = tgt.id.0.clone();
-
-            let kind = ViolationKind::InitFunctionCoupling {
-                init_file: init_file.clone(),
-                called_package: called_package.clone(),
-                from_layer,
-                to_layer,
-            };
-            let severity = config.rules.resolve_severity(&kind, Severity::Warning);
-
-            violations.push(Violation {
-                kind,
-                severity,
-                location: edge.location.clone(),
-                message: format!(
-                    "init() function in {from_layer} layer calls into {to_layer} layer ({called_package})"
-                ),
-                suggestion: Some(
-                    "Move initialization logic out of init() or use dependency injection to avoid hidden cross-layer coupling."
-                        .to_string(),
-                ),
-            });
+/// Port names are similar when they match after dropping case and common
+/// `Port`/`Interface` affixes (e.g. `UserRepository` vs `IUserRepository`).
+fn similar_port_names(a: &str, b: &str) -> bool {
+    fn base(name: &str) -> String {
+        let lower = name.to_lowercase();
+        let lower = lower
+            .trim_end_matches("port")
+            .trim_end_matches("interface")
+            .to_string();
+        match name.strip_prefix('I') {
+            Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                lower[1..].to_string()
+            }
+            _ => lower,
         }
     }
+    base(a) == base(b)
 }
 
 /// Layer conformance: how well each package's (A, I) values match its assigned layer's
@@ -863,37 +3161,12 @@ fn euclidean_distance(p1: (f64, f64), p2: (f64, f64)) -> f64 {
     (dx * dx + dy * dy).sqrt()
 }
 
-/// Returns true if a filesystem package path and an import path refer to the same package.
-///
-/// Matches by comparing trailing path segments:
-/// - If both have ≥ 2 segments, the last two must agree.
-/// - If either has only 1 segment, the last segment must agree.
-fn pkg_import_match(pkg_path: &str, import_path: &str) -> bool {
-    let pkg_last = pkg_path.split('/').next_back().unwrap_or("");
-    let imp_last = import_path.split('/').next_back().unwrap_or("");
-    if pkg_last.is_empty() || pkg_last != imp_last {
-        return false;
-    }
-    match (
-        pkg_path.split('/').rev().nth(1),
-        import_path.split('/').rev().nth(1),
-    ) {
-        (Some(p), Some(i)) => p == i,
-        _ => true,
-    }
-}
-
-/// Dependency compliance: percentage of all cross-layer edges that flow in a valid direction.
-/// Edges involving unclassified components are not counted as correct — they
-/// represent unresolved architecture that needs classification.
-fn calculate_dependency_compliance(graph: &DependencyGraph) -> f64 {
-    let edges = graph.edges_with_nodes();
-    if edges.is_empty() {
-        return 100.0;
-    }
-
-    let non_cross_cutting: Vec<_> = edges
-        .iter()
+/// Edges counted by dependency compliance: internal, not cross-cutting, and not from
+/// service-oriented code.
+fn compliance_edges(graph: &DependencyGraph) -> Vec<(&GraphNode, &GraphNode, &GraphEdge)> {
+    graph
+        .edges_with_nodes()
+        .into_iter()
         .filter(|(src, tgt, _)| {
             !src.is_external
                 && !tgt.is_external
@@ -901,62 +3174,120 @@ fn calculate_dependency_compliance(graph: &DependencyGraph) -> f64 {
                 && !tgt.is_cross_cutting
                 && src.architecture_mode != ArchitectureMode::ServiceOriented
         })
-        .collect();
+        .collect()
+}
+
+/// Whether a counted edge flows in a valid direction. Edges involving unclassified
+/// components are not correct — they represent unresolved architecture that needs
+/// classification.
+fn edge_complies(src: &GraphNode, tgt: &GraphNode) -> bool {
+    match (src.layer, tgt.layer) {
+        (Some(from), Some(to)) => !from.violates_dependency_on(&to),
+        _ => false,
+    }
+}
 
-    if non_cross_cutting.is_empty() {
-        return 100.0;
+/// Dependency compliance: percentage of all cross-layer edges that flow in a valid direction.
+///
+/// Returns `None` when there are no internal edges to check.
+fn calculate_dependency_compliance(graph: &DependencyGraph) -> Option<f64> {
+    let edges = compliance_edges(graph);
+    if edges.is_empty() {
+        return None;
     }
 
-    let correct = non_cross_cutting
+    // Count every occurrence of a dependency, not just distinct edges.
+    let total: usize = edges.iter().map(|(_, _, e)| e.weight()).sum();
+    let correct: usize = edges
         .iter()
-        .filter(|(src, tgt, _)| match (src.layer, tgt.layer) {
-            (Some(from), Some(to)) => !from.violates_dependency_on(&to),
-            _ => false, // unclassified edges are not correct
-        })
-        .count();
+        .filter(|(src, tgt, _)| edge_complies(src, tgt))
+        .map(|(_, _, e)| e.weight())
+        .sum();
+
+    Some((correct as f64 / total as f64) * 100.0)
+}
+
+/// A dependency that lowers dependency compliance: it flows against the layer
+/// direction, or one of its ends has no layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoncompliantEdge {
+    pub from: ComponentId,
+    pub to: ComponentId,
+    pub from_layer: Option<ArchLayer>,
+    pub to_layer: Option<ArchLayer>,
+    pub import_path: Option<String>,
+    pub location: SourceLocation,
+    /// Occurrences of the dependency, each counted by the metric.
+    pub weight: usize,
+}
 
-    (correct as f64 / non_cross_cutting.len() as f64) * 100.0
+/// The edges [`calculate_score`] counts against dependency compliance, heaviest first.
+/// Each is listed at the location of its first occurrence.
+pub fn noncompliant_edges(graph: &DependencyGraph) -> Vec<NoncompliantEdge> {
+    let mut edges: Vec<NoncompliantEdge> = compliance_edges(graph)
+        .into_iter()
+        .filter(|(src, tgt, _)| !edge_complies(src, tgt))
+        .map(|(src, tgt, edge)| NoncompliantEdge {
+            from: src.id.clone(),
+            to: tgt.id.clone(),
+            from_layer: src.layer,
+            to_layer: tgt.layer,
+            import_path: edge.import_path.clone(),
+            location: edge.location().clone(),
+            weight: edge.weight(),
+        })
+        .collect();
+    edges.sort_by_key(|e| std::cmp::Reverse(e.weight));
+    edges
 }
 
 /// Interface coverage: ratio of ports to adapters/repositories (higher = better separation).
-fn calculate_interface_coverage(graph: &DependencyGraph) -> f64 {
-    let nodes = graph.nodes();
-    if nodes.is_empty() {
-        return 100.0;
+///
+/// Returns `None` when there are no infrastructure adapters to cover.
+fn calculate_interface_coverage(graph: &DependencyGraph) -> Option<f64> {
+    let (ports, adapters) = interface_coverage_counts(graph);
+    if adapters == 0 {
+        return None;
     }
 
-    let mut ports = 0u64;
-    let mut adapters = 0u64;
+    // Balanced coverage: both excess ports and excess adapters indicate imbalance.
+    // Score = min(ports, adapters) / max(ports, adapters) * 100
+    let min = ports.min(adapters) as f64;
+    let max = ports.max(adapters) as f64;
+    Some((min / max) * 100.0)
+}
+
+/// Whether a node counts as an adapter for interface coverage: an adapter, repository
+/// or service in the infrastructure layer.
+fn is_coverage_adapter(node: &GraphNode) -> bool {
+    node.layer == Some(ArchLayer::Infrastructure)
+        && matches!(
+            node.kind,
+            Some(ComponentKind::Adapter(_) | ComponentKind::Repository | ComponentKind::Service)
+        )
+}
 
-    for node in &nodes {
-        if node.is_cross_cutting {
+/// The ports and adapters interface coverage balances against each other.
+fn interface_coverage_counts(graph: &DependencyGraph) -> (usize, usize) {
+    let mut ports = 0;
+    let mut adapters = 0;
+
+    for node in graph.nodes() {
+        if node.is_cross_cutting || node.is_external {
             continue;
         }
-        if let Some(kind) = &node.kind {
-            if matches!(kind, ComponentKind::Port(_)) {
+        if let Some(ComponentKind::Port(info)) = &node.kind {
+            // A port whose methods are all private exposes no surface to implement.
+            if info.methods.is_empty() || info.methods.iter().any(|m| m.is_public()) {
                 ports += 1;
             }
-            // Count adapters and repositories in the infrastructure layer
-            if node.layer == Some(ArchLayer::Infrastructure)
-                && matches!(
-                    kind,
-                    ComponentKind::Adapter(_) | ComponentKind::Repository | ComponentKind::Service
-                )
-            {
-                adapters += 1;
-            }
+        }
+        if is_coverage_adapter(node) {
+            adapters += 1;
         }
     }
 
-    if adapters == 0 {
-        return 100.0;
-    }
-
-    // Balanced coverage: both excess ports and excess adapters indicate imbalance.
-    // Score = min(ports, adapters) / max(ports, adapters) * 100
-    let min = ports.min(adapters) as f64;
-    let max = ports.max(adapters) as f64;
-    (min / max) * 100.0
+    (ports, adapters)
 }
 
 /// Build a complete `AnalysisResult`.
@@ -971,18 +3302,49 @@ pub fn build_result(
     components: &[Component],
     files_analyzed: usize,
     dependencies: &[Dependency],
+) -> AnalysisResult {
+    build_result_timed(
+        graph,
+        config,
+        dep_count,
+        components,
+        files_analyzed,
+        dependencies,
+        &[],
+        Path::new("."),
+        &mut PhaseTimer::start(),
+    )
+}
+
+/// [`build_result`] for the project at `project_root`, also running `rules` and
+/// charging detection and scoring time to `timer`.
+#[allow(clippy::too_many_arguments)]
+pub fn build_result_timed(
+    graph: &DependencyGraph,
+    config: &Config,
+    dep_count: usize,
+    components: &[Component],
+    files_analyzed: usize,
+    dependencies: &[Dependency],
+    rules: &[Box<dyn Rule>],
+    project_root: &Path,
+    timer: &mut PhaseTimer,
 ) -> AnalysisResult {
     let architecture_score = calculate_score(graph, config, components, dependencies);
-    let violations = detect_violations(graph, config);
+    timer.lap(Phase::Scoring);
+    let violations = detect_violations_with_rules(graph, config, rules, project_root);
     let metrics = compute_metrics(graph, components, &violations);
+    timer.lap(Phase::Detection);
     let package_metrics = compute_package_metrics(components, dependencies);
     let pattern_detection = detect_patterns(components, dependencies);
+    timer.lap(Phase::Scoring);
 
     let score = if pattern_detection.top_confidence >= 0.5 {
         Some(architecture_score)
     } else {
         None
     };
+    let debt = ArchitectureDebt::estimate(&violations, &config.debt);
 
     AnalysisResult {
         score,
@@ -993,9 +3355,102 @@ pub fn build_result(
         metrics: Some(metrics),
         package_metrics,
         pattern_detection: Some(pattern_detection),
+        violations_total: None,
+        truncated_violations: Vec::new(),
+        config: None,
+        debt,
+        generated_files: Vec::new(),
+        generated_exempt: false,
+        project_root: Some(project_root.to_path_buf()),
+    }
+}
+
+/// Snake-case name of a component kind, as used in reports.
+pub(crate) fn kind_name(kind: &ComponentKind) -> &'static str {
+    match kind {
+        ComponentKind::Port(_) => "port",
+        ComponentKind::Adapter(_) => "adapter",
+        ComponentKind::Entity(_) => "entity",
+        ComponentKind::ValueObject(_) => "value_object",
+        ComponentKind::UseCase => "use_case",
+        ComponentKind::Repository => "repository",
+        ComponentKind::Service => "service",
+        ComponentKind::DomainEvent(_) => "domain_event",
     }
 }
 
+/// Snake-case name of a violation kind, as used in `violations_by_kind`.
+pub(crate) fn violation_kind_name(kind: &ViolationKind) -> &'static str {
+    match kind {
+        ViolationKind::LayerBoundary { .. } => "layer_boundary",
+        ViolationKind::CircularDependency { .. } => "circular_dependency",
+        ViolationKind::DisallowedExternalDependency { .. } => "external_dependency",
+        ViolationKind::IntraLayerCycle { .. } => "intra_layer_cycle",
+        ViolationKind::HighCoupling { .. } => "high_coupling",
+        ViolationKind::ArchitectureDrift { .. } => "architecture_drift",
+        ViolationKind::ExcessiveFanOut { .. } => "excessive_fan_out",
+        ViolationKind::MissingPort { .. } => "missing_port",
+        ViolationKind::CustomRule { .. } => "custom_rule",
+        ViolationKind::DomainInfrastructureLeak { .. } => "domain_infrastructure_leak",
+        ViolationKind::InitFunctionCoupling { .. } => "init_coupling",
+        ViolationKind::ConstructorReturnsConcrete { .. } => "constructor_concrete",
+        ViolationKind::PortWithoutImplementation { .. } => "missing_implementation",
+        ViolationKind::FatRepository { .. } => "fat_repository",
+        ViolationKind::DuplicatePort { .. } => "duplicate_port",
+        ViolationKind::FatInterface { .. } => "fat_interface",
+        ViolationKind::BehaviorHeavyPort { .. } => "behavior_heavy_port",
+        ViolationKind::ContextMissingPort { .. } => "context_missing_port",
+        ViolationKind::FactoryReturnsConcrete { .. } => "factory_concrete",
+        ViolationKind::AdapterMisplaced { .. } => "adapter_misplaced",
+        ViolationKind::AdapterToAdapterCoupling { .. } => "adapter_coupling",
+        ViolationKind::PortAdapterCycle { .. } => "port_adapter_cycle",
+        ViolationKind::ModuleDirectionViolation { .. } => "module_direction",
+        ViolationKind::PassThroughService { .. } => "pass_through_service",
+        ViolationKind::MixedArchitectureModes { .. } => "mixed_architecture_modes",
+        ViolationKind::LayerSkip { .. } => "layer_skip",
+        ViolationKind::LowClassificationCoverage { .. } => "classification_coverage",
+        ViolationKind::LowInterfaceCoverage { .. } => "interface_coverage",
+        ViolationKind::TestDoubleInProduction { .. } => "test_double",
+        ViolationKind::CrossAggregateFieldAccess { .. } => "cross_aggregate_access",
+        ViolationKind::AnemicDomainModel { .. } => "anemic_model",
+    }
+}
+
+/// Every name `violation_kind_name` can return.
+pub(crate) const VIOLATION_KIND_NAMES: &[&str] = &[
+    "layer_boundary",
+    "circular_dependency",
+    "external_dependency",
+    "intra_layer_cycle",
+    "high_coupling",
+    "architecture_drift",
+    "excessive_fan_out",
+    "missing_port",
+    "custom_rule",
+    "domain_infrastructure_leak",
+    "init_coupling",
+    "constructor_concrete",
+    "missing_implementation",
+    "fat_repository",
+    "duplicate_port",
+    "fat_interface",
+    "behavior_heavy_port",
+    "context_missing_port",
+    "factory_concrete",
+    "adapter_misplaced",
+    "adapter_coupling",
+    "port_adapter_cycle",
+    "module_direction",
+    "pass_through_service",
+    "mixed_architecture_modes",
+    "layer_skip",
+    "classification_coverage",
+    "interface_coverage",
+    "test_double",
+    "cross_aggregate_access",
+    "anemic_model",
+];
+
 fn compute_metrics(
     graph: &DependencyGraph,
     components: &[Component],
@@ -1004,17 +3459,9 @@ fn compute_metrics(
     // Components by kind
     let mut components_by_kind: HashMap<String, usize> = HashMap::new();
     for comp in components {
-        let kind_name = match &comp.kind {
-            ComponentKind::Port(_) => "port",
-            ComponentKind::Adapter(_) => "adapter",
-            ComponentKind::Entity(_) => "entity",
-            ComponentKind::ValueObject => "value_object",
-            ComponentKind::UseCase => "use_case",
-            ComponentKind::Repository => "repository",
-            ComponentKind::Service => "service",
-            ComponentKind::DomainEvent(_) => "domain_event",
-        };
-        *components_by_kind.entry(kind_name.to_string()).or_insert(0) += 1;
+        *components_by_kind
+            .entry(kind_name(&comp.kind).to_string())
+            .or_insert(0) += 1;
     }
 
     // Components by layer
@@ -1023,27 +3470,13 @@ fn compute_metrics(
     // Violations by kind
     let mut violations_by_kind: HashMap<String, usize> = HashMap::new();
     for v in violations {
-        let kind_name = match &v.kind {
-            ViolationKind::LayerBoundary { .. } => "layer_boundary",
-            ViolationKind::CircularDependency { .. } => "circular_dependency",
-            ViolationKind::MissingPort { .. } => "missing_port",
-            ViolationKind::CustomRule { .. } => "custom_rule",
-            ViolationKind::DomainInfrastructureLeak { .. } => "domain_infrastructure_leak",
-            ViolationKind::InitFunctionCoupling { .. } => "init_coupling",
-            ViolationKind::ConstructorReturnsConcrete { .. } => "constructor_concrete",
-            ViolationKind::PortWithoutImplementation { .. } => "missing_implementation",
-        };
+        let kind_name = violation_kind_name(&v.kind);
         *violations_by_kind.entry(kind_name.to_string()).or_insert(0) += 1;
     }
 
     // Dependency depth
     let max_depth = graph.max_dependency_depth();
-    let node_count = graph.node_count();
-    let avg_depth = if node_count > 0 {
-        max_depth as f64 / node_count as f64
-    } else {
-        0.0
-    };
+    let avg_depth = graph.avg_dependency_depth();
 
     // Layer coupling
     let layer_coupling = graph.layer_coupling_matrix();
@@ -1061,7 +3494,32 @@ fn compute_metrics(
         },
         layer_coupling,
         classification_coverage: Some(classification_coverage),
+        worst_layer_pair: worst_layer_pair(graph),
+        layer_metrics: graph.layer_metrics(),
+    }
+}
+
+/// The layer pair contributing the most violating edges to dependency compliance.
+/// Ties go to the pair whose source, then target, is innermost.
+fn worst_layer_pair(graph: &DependencyGraph) -> Option<WorstLayerPair> {
+    let mut counts: HashMap<(ArchLayer, ArchLayer), usize> = HashMap::new();
+    for (src, tgt, edge) in compliance_edges(graph) {
+        if let (Some(from), Some(to)) = (src.layer, tgt.layer) {
+            if from.violates_dependency_on(&to) {
+                *counts.entry((from, to)).or_insert(0) += edge.weight();
+            }
+        }
     }
+    counts
+        .into_iter()
+        .max_by_key(|((from, to), count)| {
+            (
+                *count,
+                std::cmp::Reverse(from.depth()),
+                std::cmp::Reverse(to.depth()),
+            )
+        })
+        .map(|((from, to), count)| WorstLayerPair { from, to, count })
 }
 
 fn compute_classification_coverage(graph: &DependencyGraph) -> ClassificationCoverage {
@@ -1092,7 +3550,7 @@ fn compute_classification_coverage(graph: &DependencyGraph) -> ClassificationCov
         } else {
             unclassified += 1;
             // Extract parent directory from component ID
-            let id = &node.id.0;
+            let id = node.id.untagged();
             if let Some(dir) = id.rsplit_once("::").map(|(pkg, _)| pkg.to_string()) {
                 if !unclassified_dirs.contains(&dir) {
                     unclassified_dirs.push(dir);
@@ -1138,7 +3596,8 @@ fn compute_package_metrics(
 
     // Step 1: identify source package paths from real components.
     // Package path is everything before "::" in ComponentId.0.
-    let mut pkg_full_paths: HashSet<String> = HashSet::new();
+    // Ordered, so a short-name collision resolves the same way on every run.
+    let mut pkg_full_paths: BTreeSet<String> = BTreeSet::new();
     for comp in components {
         let full_pkg = pkg_from_id(&comp.id.0);
         if !full_pkg.is_empty() {
@@ -1147,7 +3606,7 @@ fn compute_package_metrics(
     }
 
     // Build a map: short name (last path segment) → full package path.
-    // If two packages share a short name we keep the first and skip the rest.
+    // If two packages share a short name we keep the first by path and skip the rest.
     let mut short_to_full: HashMap<String, String> = HashMap::new();
     for full in &pkg_full_paths {
         let short = last_segment(full).to_string();
@@ -1229,24 +3688,12 @@ fn compute_package_metrics(
             };
             let d = (a + i - 1.0).abs();
 
-            let zone = if d > 0.5 {
-                if a < 0.5 && i < 0.5 {
-                    Some("pain".to_string())
-                } else if a > 0.5 && i > 0.5 {
-                    Some("uselessness".to_string())
-                } else {
-                    None
-                }
-            } else {
-                None
-            };
-
             PackageMetric {
                 package: short.clone(),
                 abstractness: round2(a),
                 instability: round2(i),
                 distance: round2(d),
-                zone,
+                zone: main_sequence_zone(a, i, d),
             }
         })
         .collect();
@@ -1255,9 +3702,183 @@ fn compute_package_metrics(
     result
 }
 
-/// Extract the package portion of a ComponentId string ("pkg::name" → "pkg").
+/// Zone for a point far from the main sequence (D > 0.5): "pain" when concrete and
+/// stable, "uselessness" when abstract and unstable.
+pub(crate) fn main_sequence_zone(a: f64, i: f64, d: f64) -> Option<String> {
+    if d <= 0.5 {
+        None
+    } else if a < 0.5 && i < 0.5 {
+        Some("pain".to_string())
+    } else if a > 0.5 && i > 0.5 {
+        Some("uselessness".to_string())
+    } else {
+        None
+    }
+}
+
+/// Per-component Afferent/Efferent coupling, Instability and Distance, most distant
+/// from the main sequence first (ties: most depended-upon first).
+///
+/// Dependencies are recorded per file, so an import is attributed to the components
+/// declared in the importing file, and resolved to the components it names: a class
+/// (Java), a module file (TypeScript) or, failing that, every component of the
+/// imported package (Go). Dependencies within a package are ignored, as are
+/// components with no coupling at all.
+pub fn compute_component_metrics(
+    components: &[Component],
+    dependencies: &[Dependency],
+) -> Vec<ComponentMetric> {
+    let mut by_file: HashMap<&std::path::Path, Vec<usize>> = HashMap::new();
+    for (idx, comp) in components.iter().enumerate() {
+        by_file
+            .entry(comp.location.file.as_path())
+            .or_default()
+            .push(idx);
+    }
+
+    let resolver = TargetResolver::new(components);
+    let mut afferent: Vec<BTreeSet<String>> = vec![BTreeSet::new(); components.len()];
+    let mut efferent: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); components.len()];
+    for dep in dependencies {
+        let from_pkg = pkg_from_id(&dep.from.0);
+        let sources: Vec<usize> = by_file
+            .get(dep.location.file.as_path())
+            .cloned()
+            .unwrap_or_default();
+        for target in resolver.resolve(dep) {
+            if pkg_from_id(&components[target].id.0) == from_pkg {
+                continue;
+            }
+            if sources.is_empty() {
+                afferent[target].insert(dep.location.file.to_string_lossy().into_owned());
+            }
+            for &source in &sources {
+                afferent[target].insert(components[source].id.0.clone());
+                efferent[source].insert(target);
+            }
+        }
+    }
+
+    let mut result: Vec<ComponentMetric> = components
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !afferent[*idx].is_empty() || !efferent[*idx].is_empty())
+        .map(|(idx, comp)| {
+            let ca = afferent[idx].len();
+            let ce = efferent[idx].len();
+            let a = if matches!(comp.kind, ComponentKind::Port(_)) {
+                1.0
+            } else {
+                0.0
+            };
+            let i = ce as f64 / (ca + ce) as f64;
+            let d = (a + i - 1.0).abs();
+            ComponentMetric {
+                id: comp.id.0.clone(),
+                name: comp.name.clone(),
+                kind: kind_name(&comp.kind).to_string(),
+                layer: comp.layer,
+                location: comp.location.clone(),
+                afferent_coupling: ca,
+                efferent_coupling: ce,
+                abstractness: a,
+                instability: round2(i),
+                distance: round2(d),
+                zone: main_sequence_zone(a, i, d),
+            }
+        })
+        .collect();
+
+    result.sort_by(|a, b| {
+        b.distance
+            .total_cmp(&a.distance)
+            .then_with(|| b.afferent_coupling.cmp(&a.afferent_coupling))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    result
+}
+
+/// Resolves dependency targets to the components they refer to.
+struct TargetResolver<'a> {
+    components: &'a [Component],
+    by_id: HashMap<&'a str, Vec<usize>>,
+    /// Component name and file stem → components.
+    by_name: HashMap<String, Vec<usize>>,
+    /// Last package path segment → components.
+    by_package: HashMap<&'a str, Vec<usize>>,
+}
+
+impl<'a> TargetResolver<'a> {
+    fn new(components: &'a [Component]) -> Self {
+        let mut resolver = Self {
+            components,
+            by_id: HashMap::new(),
+            by_name: HashMap::new(),
+            by_package: HashMap::new(),
+        };
+        for (idx, comp) in components.iter().enumerate() {
+            resolver.by_id.entry(&comp.id.0).or_default().push(idx);
+            resolver
+                .by_name
+                .entry(comp.name.clone())
+                .or_default()
+                .push(idx);
+            if let Some(stem) = comp.location.file.file_stem() {
+                let stem = stem.to_string_lossy().into_owned();
+                if stem != comp.name {
+                    resolver.by_name.entry(stem).or_default().push(idx);
+                }
+            }
+            resolver
+                .by_package
+                .entry(last_segment(pkg_from_id(&comp.id.0)))
+                .or_default()
+                .push(idx);
+        }
+        resolver
+    }
+
+    /// Components `dep` refers to, as indices into `components`.
+    fn resolve(&self, dep: &Dependency) -> Vec<usize> {
+        if let Some(exact) = self.by_id.get(dep.to.0.as_str()) {
+            return exact.clone();
+        }
+
+        // Java names classes by dotted path; relative TypeScript imports start with `./`/`../`.
+        let raw = pkg_from_id(&dep.to.0);
+        let target = if raw.contains('/') {
+            raw.to_string()
+        } else {
+            raw.replace('.', "/")
+        };
+        let target = target
+            .split('/')
+            .filter(|s| !s.is_empty() && *s != "." && *s != "..")
+            .collect::<Vec<_>>()
+            .join("/");
+        let Some((parent, last)) = target.rsplit_once('/') else {
+            return Vec::new();
+        };
+
+        let in_package = |candidates: Option<&Vec<usize>>, package: &str| -> Vec<usize> {
+            candidates
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(|&i| pkg_import_match(pkg_from_id(&self.components[i].id.0), package))
+                .collect()
+        };
+        let named = in_package(self.by_name.get(last), parent);
+        if !named.is_empty() {
+            return named;
+        }
+        in_package(self.by_package.get(last), &target)
+    }
+}
+
+/// Extract the package portion of a ComponentId string ("lang:pkg::name" → "pkg").
 fn pkg_from_id(id: &str) -> &str {
-    id.split("::").next().unwrap_or("")
+    split_language_tag(id).1.split("::").next().unwrap_or("")
 }
 
 /// Extract the last path segment from a path-like string.
@@ -1266,7 +3887,7 @@ fn last_segment(path: &str) -> &str {
 }
 
 /// Round a float to 2 decimal places.
-fn round2(v: f64) -> f64 {
+pub(crate) fn round2(v: f64) -> f64 {
     (v * 100.0).round() / 100.0
 }
 
@@ -1296,7 +3917,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -1314,6 +3937,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_repeated_dependency_reported_at_each_location() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("domain", "User", Some(ArchLayer::Domain)));
+        graph.add_component(&make_component(
+            "infra",
+            "Store",
+            Some(ArchLayer::Infrastructure),
+        ));
+        let mut dep = make_dep("domain", "infra");
+        dep.location.file = PathBuf::from("a.go");
+        graph.add_dependency(&dep);
+        dep.location.file = PathBuf::from("b.go");
+        graph.add_dependency(&dep);
+
+        let files: Vec<_> = detect_violations(&graph, &Config::default())
+            .into_iter()
+            .filter(|v| matches!(v.kind, ViolationKind::LayerBoundary { .. }))
+            .map(|v| v.location.file)
+            .collect();
+        assert_eq!(files, [PathBuf::from("a.go"), PathBuf::from("b.go")]);
+    }
+
+    #[test]
+    fn test_package_metrics_short_name_collision_is_deterministic() {
+        // Two packages named "user": the first by path (application) wins every time.
+        let service = make_component(
+            "internal/application/user::Service",
+            "Service",
+            Some(ArchLayer::Application),
+        );
+        let mut port = make_component(
+            "internal/domain/user::UserRepository",
+            "UserRepository",
+            Some(ArchLayer::Domain),
+        );
+        port.kind = ComponentKind::Port(PortInfo {
+            name: "UserRepository".to_string(),
+            methods: vec![],
+        });
+        let components = vec![port, service];
+
+        for _ in 0..20 {
+            let metrics = compute_package_metrics(&components, &[]);
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(metrics[0].package, "user");
+            assert_eq!(metrics[0].abstractness, 0.0);
+        }
+    }
+
     #[test]
     fn test_perfect_score_no_violations() {
         let mut graph = DependencyGraph::new();
@@ -1392,6 +4065,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_empty_graph_neutral_policy() {
+        let graph = DependencyGraph::new();
+        let mut config = Config::default();
+        config.scoring.empty_metric_policy = EmptyMetricPolicy::Neutral;
+        let score = calculate_score(&graph, &config, &[], &[]);
+        assert_eq!(score.structural_presence, 50.0);
+        assert_eq!(score.interface_coverage, 50.0);
+        // 50% presence gating 50% correctness
+        assert!((score.overall - 25.0).abs() < 0.01, "got {}", score.overall);
+        assert!(score.not_applicable.is_empty());
+    }
+
+    #[test]
+    fn test_empty_graph_na_policy() {
+        let graph = DependencyGraph::new();
+        let mut config = Config::default();
+        config.scoring.empty_metric_policy = EmptyMetricPolicy::Na;
+        let score = calculate_score(&graph, &config, &[], &[]);
+        assert_eq!(score.overall, 0.0);
+        assert_eq!(
+            score.not_applicable,
+            [
+                "structural_presence",
+                "layer_conformance",
+                "dependency_compliance",
+                "interface_coverage"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_na_policy_excludes_only_unmeasured_metrics() {
+        let mut graph = DependencyGraph::new();
+        let domain = make_component("domain", "Order", Some(ArchLayer::Domain));
+        let app = make_component("app", "Checkout", Some(ArchLayer::Application));
+        graph.add_component(&domain);
+        graph.add_component(&app);
+        graph.add_dependency(&make_dep("app", "domain"));
+
+        let mut config = Config::default();
+        config.scoring.empty_metric_policy = EmptyMetricPolicy::Na;
+        let score = calculate_score(&graph, &config, &[domain, app], &[]);
+        // No infrastructure adapters: interface coverage has nothing to measure.
+        assert_eq!(score.not_applicable, ["interface_coverage"]);
+        assert!(!score.is_applicable("interface_coverage"));
+        assert_eq!(score.dependency_compliance, 100.0);
+    }
+
+    #[test]
+    fn test_noncompliant_edges_list_what_compliance_counts() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("domain", "Entity", Some(ArchLayer::Domain)));
+        graph.add_component(&make_component(
+            "infra",
+            "Repo",
+            Some(ArchLayer::Infrastructure),
+        ));
+        graph.add_component(&make_component("misc", "Helper", None));
+        graph.add_dependency(&make_dep("infra", "domain"));
+        graph.add_dependency(&make_dep("domain", "infra"));
+        graph.add_dependency(&make_dep("domain", "infra"));
+        graph.add_dependency(&make_dep("infra", "misc"));
+
+        let edges = noncompliant_edges(&graph);
+        let summary: Vec<_> = edges
+            .iter()
+            .map(|e| (e.from.0.as_str(), e.to.0.as_str(), e.weight))
+            .collect();
+        assert_eq!(summary, [("domain", "infra", 2), ("infra", "misc", 1)]);
+        assert_eq!(calculate_dependency_compliance(&graph), Some(25.0));
+    }
+
+    /// Flags every component whose name ends in "Manager".
+    struct NoManagers;
+
+    impl Rule for NoManagers {
+        fn evaluate(&self, graph: &DependencyGraph, _config: &Config) -> Vec<Violation> {
+            graph
+                .nodes()
+                .into_iter()
+                .filter(|n| n.name.ends_with("Manager"))
+                .map(|n| Violation {
+                    kind: ViolationKind::CustomRule {
+                        rule_name: "no-managers".to_string(),
+                    },
+                    severity: Severity::Warning,
+                    location: n.location.clone(),
+                    message: format!("'{}' is a manager", n.name),
+                    suggestion: None,
+                    fix: None,
+                    doc_url: None,
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_registered_rule_runs_after_builtins() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("domain", "Entity", Some(ArchLayer::Domain)));
+        graph.add_component(&make_component(
+            "infra",
+            "ConnectionManager",
+            Some(ArchLayer::Infrastructure),
+        ));
+        graph.add_dependency(&make_dep("domain", "infra"));
+        let rules: Vec<Box<dyn Rule>> = vec![Box::new(NoManagers)];
+
+        let violations =
+            detect_violations_with_rules(&graph, &Config::default(), &rules, Path::new("."));
+        let kinds: Vec<_> = violations
+            .iter()
+            .map(|v| v.kind.rule_id().to_string())
+            .collect();
+        assert_eq!(kinds, ["L001", "C-no-managers"]);
+        assert_eq!(violations[1].message, "'ConnectionManager' is a manager");
+
+        let config =
+            Config::parse("[[rules.ignore]]\nrule = \"C-no-managers\"\npaths = [\"**\"]\n")
+                .unwrap();
+        let violations = detect_violations_with_rules(&graph, &config, &rules, Path::new("."));
+        assert_eq!(violations.len(), 1, "ignores apply to registered rules");
+    }
+
     #[test]
     fn test_build_result() {
         let graph = DependencyGraph::new();
@@ -1405,6 +4203,115 @@ mod tests {
         assert!(result.pattern_detection.is_some());
     }
 
+    #[test]
+    fn test_truncate_violations_keeps_most_severe() {
+        let graph = DependencyGraph::new();
+        let mut result = build_result(&graph, &Config::default(), 0, &[], 0, &[]);
+        let violation = |severity: Severity, file: &str, line: usize| Violation {
+            kind: ViolationKind::CircularDependency {
+                cycle: vec![],
+                layers: vec![],
+                cross_layer: false,
+            },
+            severity,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line,
+                column: 1,
+            },
+            message: String::new(),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        };
+        result.violations = vec![
+            violation(Severity::Info, "a.go", 1),
+            violation(Severity::Error, "b.go", 9),
+            violation(Severity::Warning, "a.go", 2),
+            violation(Severity::Error, "b.go", 3),
+        ];
+
+        result.truncate_violations(2);
+        assert_eq!(result.violations.len(), 2);
+        assert_eq!(result.violations_total, Some(4));
+        assert_eq!(result.omitted_violations(), 2);
+        assert!(result
+            .violations
+            .iter()
+            .all(|v| v.severity == Severity::Error));
+        assert_eq!(result.violations[0].location.line, 3);
+
+        assert_eq!(result.violations_at_or_above(Severity::Error).count(), 2);
+        assert_eq!(result.violations_at_or_above(Severity::Info).count(), 4);
+
+        result.truncate_violations(0);
+        assert!(result.violations.is_empty());
+        assert_eq!(result.violations_at_or_above(Severity::Error).count(), 2);
+
+        result.truncate_violations(10);
+        assert_eq!(result.violations_total, Some(4));
+    }
+
+    #[test]
+    fn test_violation_filters() {
+        let graph = DependencyGraph::new();
+        let mut result = build_result(&graph, &Config::default(), 0, &[], 0, &[]);
+        let violation = |kind: ViolationKind, severity: Severity, file: &str| Violation {
+            kind,
+            severity,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line: 1,
+                column: 1,
+            },
+            message: String::new(),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        };
+        let cycle = || ViolationKind::CircularDependency {
+            cycle: vec![],
+            layers: vec![],
+            cross_layer: false,
+        };
+        let missing_port = || ViolationKind::MissingPort {
+            adapter_name: "PgRepo".to_string(),
+        };
+        result.violations = vec![
+            violation(cycle(), Severity::Error, "./a.go"),
+            violation(missing_port(), Severity::Warning, "a.go"),
+            violation(missing_port(), Severity::Info, "b.go"),
+            violation(cycle(), Severity::Warning, "b.go"),
+        ];
+
+        let files = |violations: Vec<&Violation>| -> Vec<String> {
+            violations
+                .iter()
+                .map(|v| v.location.file.display().to_string())
+                .collect()
+        };
+        assert_eq!(
+            files(result.violations_by_kind("missing_port").collect()),
+            ["a.go", "b.go"]
+        );
+        assert_eq!(result.violations_by_kind("layer_boundary").count(), 0);
+
+        assert_eq!(result.violations_at_or_above(Severity::Error).count(), 1);
+        assert_eq!(
+            files(result.violations_at_or_above(Severity::Warning).collect()),
+            ["./a.go", "a.go", "b.go"]
+        );
+        assert_eq!(result.violations_at_or_above(Severity::Info).count(), 4);
+
+        assert_eq!(
+            result.violations_in_file(Path::new("a.go")).count(),
+            2,
+            "./a.go and a.go are the same file"
+        );
+        assert_eq!(result.violations_in_file(Path::new("./b.go")).count(), 2);
+        assert_eq!(result.violations_in_file(Path::new("c.go")).count(), 0);
+    }
+
     fn make_cross_cutting_component(id: &str, name: &str, layer: Option<ArchLayer>) -> Component {
         Component {
             id: ComponentId(id.to_string()),
@@ -1423,7 +4330,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: true,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -1484,7 +4393,7 @@ mod tests {
 
         let compliance = calculate_dependency_compliance(&graph);
         assert_eq!(
-            compliance, 100.0,
+            compliance, None,
             "cross-cutting edges should be excluded from dependency compliance"
         );
     }
@@ -1538,7 +4447,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: mode,
+            methods: Vec::new(),
         }
     }
 
@@ -1595,7 +4506,7 @@ mod tests {
 
         let compliance = calculate_dependency_compliance(&graph);
         assert_eq!(
-            compliance, 100.0,
+            compliance, None,
             "service-oriented edges should be excluded from dependency compliance"
         );
     }
@@ -1702,6 +4613,425 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fat_repository_flags_method_count() {
+        let mut graph = DependencyGraph::new();
+        let mut repo = make_component(
+            "infra::UserRepo",
+            "UserRepo",
+            Some(ArchLayer::Infrastructure),
+        );
+        repo.kind = ComponentKind::Repository;
+        repo.methods = ["Save", "FindByID", "FindByEmail", "Delete"]
+            .iter()
+            .map(|name| crate::types::MethodInfo {
+                name: name.to_string(),
+                parameters: "()".to_string(),
+                return_type: String::new(),
+                parameter_types: Vec::new(),
+                visibility: crate::types::Visibility::Public,
+                interface_kind: crate::types::InterfaceMethodKind::Abstract,
+                annotations: Vec::new(),
+            })
+            .collect();
+        graph.add_component(&repo);
+
+        let mut config = Config::default();
+        let is_fat = |config: &Config| {
+            detect_violations(&graph, config)
+                .iter()
+                .any(|v| matches!(v.kind, ViolationKind::FatRepository { .. }))
+        };
+        assert!(!is_fat(&config), "rule is opt-in");
+
+        config.rules.detect_fat_repositories = true;
+        assert!(!is_fat(&config), "CRUD-only repository under the limit");
+
+        config.rules.max_repository_methods = 3;
+        assert!(is_fat(&config), "4 methods exceeds max of 3");
+    }
+
+    #[test]
+    fn test_fat_repository_accepts_camel_case_crud_methods() {
+        let mut graph = DependencyGraph::new();
+        let mut repo = make_component(
+            "infra::UserRepo",
+            "UserRepo",
+            Some(ArchLayer::Infrastructure),
+        );
+        repo.kind = ComponentKind::Repository;
+        repo.methods = ["save", "findById", "recalculateLoyalty"]
+            .iter()
+            .map(|name| crate::types::MethodInfo {
+                name: name.to_string(),
+                parameters: "()".to_string(),
+                return_type: String::new(),
+                parameter_types: Vec::new(),
+                visibility: crate::types::Visibility::Public,
+                interface_kind: crate::types::InterfaceMethodKind::Abstract,
+                annotations: Vec::new(),
+            })
+            .collect();
+        graph.add_component(&repo);
+
+        let mut config = Config::default();
+        config.rules.detect_fat_repositories = true;
+        let non_crud: Vec<String> = detect_violations(&graph, &config)
+            .into_iter()
+            .find_map(|v| match v.kind {
+                ViolationKind::FatRepository {
+                    non_crud_methods, ..
+                } => Some(non_crud_methods),
+                _ => None,
+            })
+            .expect("business method should be flagged");
+        assert_eq!(non_crud, ["recalculateLoyalty"]);
+    }
+
+    #[test]
+    fn test_fat_interface_respects_max_methods() {
+        let mut graph = DependencyGraph::new();
+        let mut port = make_component("domain::Store", "Store", Some(ArchLayer::Domain));
+        port.kind = ComponentKind::Port(crate::types::PortInfo {
+            name: "Store".to_string(),
+            methods: ["Save", "Delete", "Find"]
+                .iter()
+                .map(|name| crate::types::MethodInfo {
+                    name: name.to_string(),
+                    parameters: "()".to_string(),
+                    return_type: String::new(),
+                    parameter_types: Vec::new(),
+                    visibility: crate::types::Visibility::Public,
+                    interface_kind: crate::types::InterfaceMethodKind::Abstract,
+                    annotations: Vec::new(),
+                })
+                .collect(),
+        });
+        graph.add_component(&port);
+
+        let mut config = Config::default();
+        let is_fat = |config: &Config| {
+            detect_violations(&graph, config)
+                .iter()
+                .any(|v| matches!(v.kind, ViolationKind::FatInterface { .. }))
+        };
+        config.rules.max_interface_methods = 2;
+        assert!(!is_fat(&config), "rule is opt-in");
+
+        config.rules.detect_fat_interfaces = true;
+        assert!(is_fat(&config), "3 methods exceeds max of 2");
+
+        config.rules.max_interface_methods = 3;
+        assert!(!is_fat(&config), "3 methods is within max of 3");
+    }
+
+    #[test]
+    fn test_names_infra_technology() {
+        let keywords = Config::default().rules.infra_keywords;
+        assert!(names_infra_technology("MongoUserRepository", &keywords));
+        assert!(names_infra_technology("UserDBStore", &keywords));
+        assert!(names_infra_technology("postgres_order_repo", &keywords));
+        assert!(!names_infra_technology("FeedbackRepository", &keywords));
+        assert!(!names_infra_technology("UserRepository", &keywords));
+    }
+
+    #[test]
+    fn test_infra_keywords_replace_defaults() {
+        let import_leaks = |import_path: &str, config: &Config| {
+            let mut graph = DependencyGraph::new();
+            graph.add_component(&make_component("domain", "Order", Some(ArchLayer::Domain)));
+            graph.add_component(&make_component(
+                "infra",
+                "Publisher",
+                Some(ArchLayer::Infrastructure),
+            ));
+            let mut dep = make_dep("domain", "infra");
+            dep.import_path = Some(import_path.to_string());
+            graph.add_dependency(&dep);
+            detect_violations(&graph, config)
+                .iter()
+                .filter(|v| {
+                    matches!(&v.kind, ViolationKind::DomainInfrastructureLeak { detail }
+                        if detail.contains("imports infrastructure path"))
+                })
+                .count()
+        };
+
+        let default = Config::default();
+        assert_eq!(import_leaks("github.com/lib/postgres", &default), 1);
+        assert_eq!(import_leaks("com/example/kafka/producer", &default), 0);
+
+        let mut config = Config::default();
+        config.rules.infra_keywords = vec!["Kafka".to_string()];
+        assert_eq!(import_leaks("com/example/kafka/producer", &config), 1);
+        assert_eq!(import_leaks("github.com/lib/postgres", &config), 0);
+    }
+
+    #[test]
+    fn test_empty_infra_keywords_fall_back_to_defaults() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component("domain", "Order", Some(ArchLayer::Domain)));
+        graph.add_component(&make_component(
+            "infra",
+            "Store",
+            Some(ArchLayer::Infrastructure),
+        ));
+        let mut dep = make_dep("domain", "infra");
+        dep.import_path = Some("github.com/lib/postgres".to_string());
+        graph.add_dependency(&dep);
+
+        for keywords in [vec![], vec![String::new()]] {
+            let mut config = Config::default();
+            config.rules.infra_keywords = keywords;
+            assert!(
+                detect_violations(&graph, &config).iter().any(|v| matches!(
+                    &v.kind,
+                    ViolationKind::DomainInfrastructureLeak { detail }
+                        if detail.contains("imports infrastructure path")
+                )),
+                "{:?} should not disable L005",
+                config.rules.infra_keywords
+            );
+        }
+    }
+
+    #[test]
+    fn test_bounded_context() {
+        let context = |p: &str| bounded_context(std::path::Path::new(p));
+        assert_eq!(
+            context("services/billing/domain/invoice.go").as_deref(),
+            Some("services/billing")
+        );
+        assert_eq!(
+            context("services/billing/infrastructure/pg/store.go").as_deref(),
+            Some("services/billing")
+        );
+        assert_eq!(context("domain/user.go").as_deref(), Some(""));
+        assert_eq!(context("pkg/util/strings.go"), None);
+    }
+
+    #[test]
+    fn test_similar_port_names() {
+        assert!(similar_port_names("UserRepository", "UserRepository"));
+        assert!(similar_port_names("UserRepository", "IUserRepository"));
+        assert!(similar_port_names("Notifier", "NotifierPort"));
+        assert!(!similar_port_names("UserRepository", "InvoiceRepository"));
+        assert!(!similar_port_names("Identity", "dentity"));
+    }
+
+    fn module_direction_violations(graph: &DependencyGraph) -> Vec<Violation> {
+        let mut config = Config::default();
+        config.rules.detect_module_direction = true;
+        detect_violations(graph, &config)
+            .into_iter()
+            .filter(|v| matches!(v.kind, ViolationKind::ModuleDirectionViolation { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn test_module_direction_fires_on_net_domain_to_infra_flow() {
+        let mut graph = DependencyGraph::new();
+        for (id, name, layer) in [
+            ("domain/user::User", "User", ArchLayer::Domain),
+            ("domain/user::Email", "Email", ArchLayer::Domain),
+            ("infra/db::Store", "Store", ArchLayer::Infrastructure),
+        ] {
+            graph.add_component(&make_component(id, name, Some(layer)));
+        }
+        // Two domain → infra edges outweigh one infra → domain edge.
+        graph.add_dependency(&make_dep("domain/user::User", "infra/db::Store"));
+        graph.add_dependency(&make_dep("domain/user::Email", "infra/db::Store"));
+        graph.add_dependency(&make_dep("infra/db::Store", "domain/user::User"));
+
+        let violations = module_direction_violations(&graph);
+        assert_eq!(violations.len(), 1);
+        match &violations[0].kind {
+            ViolationKind::ModuleDirectionViolation {
+                module,
+                dependent_modules,
+                inbound_from_domain,
+                outbound_to_domain,
+            } => {
+                assert_eq!(module, "infra/db");
+                assert_eq!(dependent_modules, &vec!["domain/user".to_string()]);
+                assert_eq!(*inbound_from_domain, 2);
+                assert_eq!(*outbound_to_domain, 1);
+            }
+            other => panic!("unexpected kind {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_module_direction_quiet_when_infra_points_inward() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component(
+            "domain/user::User",
+            "User",
+            Some(ArchLayer::Domain),
+        ));
+        graph.add_component(&make_component(
+            "infra/db::Store",
+            "Store",
+            Some(ArchLayer::Infrastructure),
+        ));
+        graph.add_dependency(&make_dep("infra/db::Store", "domain/user::User"));
+
+        assert!(module_direction_violations(&graph).is_empty());
+        // And the rule is opt-in.
+        let mut reversed = DependencyGraph::new();
+        reversed.add_component(&make_component(
+            "domain/user::User",
+            "User",
+            Some(ArchLayer::Domain),
+        ));
+        reversed.add_component(&make_component(
+            "infra/db::Store",
+            "Store",
+            Some(ArchLayer::Infrastructure),
+        ));
+        reversed.add_dependency(&make_dep("domain/user::User", "infra/db::Store"));
+        assert!(!detect_violations(&reversed, &Config::default())
+            .iter()
+            .any(|v| matches!(v.kind, ViolationKind::ModuleDirectionViolation { .. })));
+    }
+
+    fn methods(names: &[&str]) -> Vec<crate::types::MethodInfo> {
+        names
+            .iter()
+            .map(|name| crate::types::MethodInfo {
+                name: name.to_string(),
+                parameters: "()".to_string(),
+                return_type: String::new(),
+                parameter_types: Vec::new(),
+                visibility: crate::types::Visibility::Public,
+                interface_kind: crate::types::InterfaceMethodKind::Abstract,
+                annotations: Vec::new(),
+            })
+            .collect()
+    }
+
+    fn pass_through_graph(service_methods: &[&str]) -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        let mut service = make_component(
+            "app/orders::OrderService",
+            "OrderService",
+            Some(ArchLayer::Application),
+        );
+        service.kind = ComponentKind::UseCase;
+        service.methods = methods(service_methods);
+        graph.add_component(&service);
+
+        let mut repo = make_component(
+            "domain/order::OrderRepository",
+            "OrderRepository",
+            Some(ArchLayer::Domain),
+        );
+        repo.kind = ComponentKind::Port(crate::types::PortInfo {
+            name: "OrderRepository".to_string(),
+            methods: methods(&["Save", "FindByID"]),
+        });
+        graph.add_component(&repo);
+        graph.add_dependency(&make_dep("app/orders::<file>", "domain/order::<package>"));
+        graph
+    }
+
+    fn is_pass_through(graph: &DependencyGraph) -> bool {
+        let mut config = Config::default();
+        config.rules.detect_pass_through_services = true;
+        detect_violations(graph, &config)
+            .iter()
+            .any(|v| matches!(v.kind, ViolationKind::PassThroughService { .. }))
+    }
+
+    #[test]
+    fn test_pass_through_service_fires_on_single_repository_dependency() {
+        let graph = pass_through_graph(&["Save", "FindByID"]);
+        assert!(is_pass_through(&graph));
+        assert!(
+            !detect_violations(&graph, &Config::default())
+                .iter()
+                .any(|v| matches!(v.kind, ViolationKind::PassThroughService { .. })),
+            "rule is opt-in"
+        );
+    }
+
+    #[test]
+    fn test_pass_through_service_quiet_when_wider_than_repository() {
+        let graph = pass_through_graph(&["Save", "FindByID", "Cancel"]);
+        assert!(!is_pass_through(&graph));
+    }
+
+    fn entity_graph(kind: ComponentKind, mode: ArchitectureMode) -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        let mut order = make_component("domain/order::Order", "Order", Some(ArchLayer::Domain));
+        order.kind = kind;
+        order.architecture_mode = mode;
+        graph.add_component(&order);
+        graph
+    }
+
+    fn order_entity(method_names: &[&str]) -> ComponentKind {
+        ComponentKind::Entity(EntityInfo {
+            name: "Order".to_string(),
+            fields: vec![crate::types::FieldInfo {
+                name: "Status".to_string(),
+                type_name: "string".to_string(),
+                visibility: crate::types::Visibility::Public,
+            }],
+            methods: methods(method_names),
+            is_active_record: false,
+            is_anemic_domain_model: method_names.is_empty(),
+        })
+    }
+
+    fn anemic_violations(graph: &DependencyGraph) -> Vec<Violation> {
+        let mut config = Config::default();
+        config.rules.detect_anemic_model = true;
+        detect_violations(graph, &config)
+            .into_iter()
+            .filter(|v| matches!(v.kind, ViolationKind::AnemicDomainModel { .. }))
+            .collect()
+    }
+
+    #[test]
+    fn test_anemic_domain_model_fires_on_entity_without_methods() {
+        let graph = entity_graph(order_entity(&[]), ArchitectureMode::Ddd);
+        let violations = anemic_violations(&graph);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].kind.rule_id().as_str(), "L012");
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert!(violations[0].message.contains("'Order' has 1 field(s)"));
+        assert!(
+            !detect_violations(&graph, &Config::default())
+                .iter()
+                .any(|v| matches!(v.kind, ViolationKind::AnemicDomainModel { .. })),
+            "rule is opt-in"
+        );
+    }
+
+    #[test]
+    fn test_anemic_domain_model_quiet_for_entity_with_methods() {
+        let graph = entity_graph(order_entity(&["Cancel"]), ArchitectureMode::Ddd);
+        assert!(anemic_violations(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_anemic_domain_model_skips_active_record_and_value_objects() {
+        let graph = entity_graph(order_entity(&[]), ArchitectureMode::ActiveRecord);
+        assert!(anemic_violations(&graph).is_empty());
+
+        let value_object = ComponentKind::ValueObject(crate::types::ValueObjectInfo {
+            name: "Order".to_string(),
+            fields: vec![crate::types::FieldInfo {
+                name: "Status".to_string(),
+                type_name: "string".to_string(),
+                visibility: crate::types::Visibility::Public,
+            }],
+        });
+        let graph = entity_graph(value_object, ArchitectureMode::Ddd);
+        assert!(anemic_violations(&graph).is_empty());
+    }
+
     fn make_external_component(id: &str, name: &str, layer: Option<ArchLayer>) -> Component {
         Component {
             id: ComponentId(id.to_string()),
@@ -1720,7 +5050,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -1736,7 +5068,7 @@ mod tests {
 
         let compliance = calculate_dependency_compliance(&graph);
         assert_eq!(
-            compliance, 100.0,
+            compliance, None,
             "external edges should be excluded from dependency compliance"
         );
     }
@@ -1753,7 +5085,7 @@ mod tests {
 
         let compliance = calculate_dependency_compliance(&graph);
         assert_eq!(
-            compliance, 100.0,
+            compliance, None,
             "external edges should be excluded from dependency compliance"
         );
     }
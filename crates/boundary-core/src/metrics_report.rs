@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 use crate::types::ArchLayer;
 
@@ -18,13 +18,51 @@ pub struct ClassificationCoverage {
 /// Detailed metrics beyond scores.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricsReport {
+    #[serde(serialize_with = "sorted_map")]
     pub components_by_kind: HashMap<String, usize>,
+    #[serde(serialize_with = "sorted_map")]
     pub components_by_layer: HashMap<String, usize>,
+    #[serde(serialize_with = "sorted_map")]
     pub violations_by_kind: HashMap<String, usize>,
     pub dependency_depth: DependencyDepthMetrics,
     pub layer_coupling: LayerCouplingMatrix,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub classification_coverage: Option<ClassificationCoverage>,
+    /// The layer pair with the most dependencies against the layer direction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worst_layer_pair: Option<WorstLayerPair>,
+    /// R.C. Martin coupling metrics per layer, innermost first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layer_metrics: Vec<LayerMetric>,
+}
+
+/// Dependencies from one layer to another that flow against the layer direction,
+/// each occurrence counted as in dependency compliance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorstLayerPair {
+    pub from: ArchLayer,
+    pub to: ArchLayer,
+    pub count: usize,
+}
+
+/// R.C. Martin coupling metrics for one layer, treating the layer as a package.
+/// Couplings count components, not edges.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerMetric {
+    pub layer: ArchLayer,
+    /// Afferent coupling Ca: components in other layers that depend on this layer.
+    pub afferent_coupling: usize,
+    /// Efferent coupling Ce: components in this layer that depend on other layers.
+    pub efferent_coupling: usize,
+    /// Abstractness A = ports / components, rounded to 2 decimal places.
+    pub abstractness: f64,
+    /// Instability I = Ce / (Ca + Ce), rounded to 2 decimal places; 0.0 without coupling.
+    pub instability: f64,
+    /// Distance from main sequence D = |A + I - 1|, rounded to 2 decimal places.
+    pub distance: f64,
+    /// "pain" or "uselessness" when far from the main sequence, as for packages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
 }
 
 /// Dependency depth metrics.
@@ -37,9 +75,27 @@ pub struct DependencyDepthMetrics {
 /// Layer-to-layer coupling matrix: counts of edges between each pair of layers.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LayerCouplingMatrix {
+    #[serde(serialize_with = "sorted_matrix")]
     pub matrix: HashMap<String, HashMap<String, usize>>,
 }
 
+/// Serialize map keys in sorted order so reports are byte-for-byte reproducible.
+fn sorted_map<S: Serializer>(map: &HashMap<String, usize>, s: S) -> Result<S::Ok, S::Error> {
+    s.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+fn sorted_matrix<S: Serializer>(
+    matrix: &HashMap<String, HashMap<String, usize>>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    s.collect_map(
+        matrix
+            .iter()
+            .map(|(from, row)| (from, row.iter().collect::<BTreeMap<_, _>>()))
+            .collect::<BTreeMap<_, _>>(),
+    )
+}
+
 impl LayerCouplingMatrix {
     pub fn new() -> Self {
         let layers = [
@@ -59,10 +115,11 @@ impl LayerCouplingMatrix {
         Self { matrix }
     }
 
-    pub fn increment(&mut self, from: &ArchLayer, to: &ArchLayer) {
+    /// Add `weight` dependencies from layer `from` to layer `to`.
+    pub fn add(&mut self, from: &ArchLayer, to: &ArchLayer, weight: usize) {
         if let Some(row) = self.matrix.get_mut(&from.to_string()) {
             if let Some(count) = row.get_mut(&to.to_string()) {
-                *count += 1;
+                *count += weight;
             }
         }
     }
@@ -79,13 +136,13 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_coupling_matrix_increment() {
+    fn test_coupling_matrix_add() {
         let mut matrix = LayerCouplingMatrix::new();
-        matrix.increment(&ArchLayer::Domain, &ArchLayer::Infrastructure);
-        matrix.increment(&ArchLayer::Domain, &ArchLayer::Infrastructure);
+        matrix.add(&ArchLayer::Domain, &ArchLayer::Infrastructure, 1);
+        matrix.add(&ArchLayer::Domain, &ArchLayer::Infrastructure, 3);
         assert_eq!(
-            matrix.matrix["domain"]["infrastructure"], 2,
-            "should count two edges"
+            matrix.matrix["domain"]["infrastructure"], 4,
+            "should sum edge weights"
         );
     }
 }
@@ -105,7 +105,11 @@ pub fn detect_patterns(components: &[Component], dependencies: &[Dependency]) ->
 // ─── Signal extraction ────────────────────────────────────────────────────────
 
 fn pkg_from_id(id: &str) -> &str {
-    id.split("::").next().unwrap_or("")
+    crate::types::split_language_tag(id)
+        .1
+        .split("::")
+        .next()
+        .unwrap_or("")
 }
 
 /// Check whether any path segment (split on `/`, `.`, or `:`) equals `layer`.
@@ -348,7 +352,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -370,7 +376,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -1,20 +1,25 @@
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use rayon::prelude::*;
 use walkdir::WalkDir;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::analyzer::LanguageAnalyzer;
 use crate::cache::{AnalysisCache, CachedFileResult};
-use crate::config::Config;
+use crate::classification::ComponentClassifier;
+use crate::config::{Config, ProjectConfig};
+use crate::generated::is_generated_source;
 use crate::graph::DependencyGraph;
-use crate::layer::LayerClassifier;
+use crate::layer::{build_globset, resolve_relative_import, LayerClassifier};
 use crate::metrics;
+use crate::rule::Rule;
+use crate::timing::PhaseTimer;
 use crate::types::{
-    AdapterConfidence, AdapterInfo, ArchLayer, ArchitectureMode, Component, ComponentKind,
-    Dependency, DependencyKind,
+    AdapterConfidence, AdapterInfo, ArchLayer, ArchitectureMode, Component, ComponentId,
+    ComponentKind, Dependency, DependencyKind, FactoryInfo, FieldAccess, ImplMethods,
+    SourceLocation,
 };
 
 /// Full analysis output including the graph for diagram generation.
@@ -39,6 +44,11 @@ type ClassifiedDependency = (
 struct FileResult {
     components: Vec<(Component, Option<ArchLayer>)>,
     dependencies: Vec<ClassifiedDependency>,
+    /// The file carries a generated-code marker.
+    is_generated: bool,
+    factories: Vec<FactoryInfo>,
+    field_accesses: Vec<FieldAccess>,
+    impl_methods: Vec<ImplMethods>,
 }
 
 /// Reclassify infrastructure-layer handler/controller structs as `Adapter`.
@@ -72,21 +82,392 @@ pub fn reclassify_infra_handlers(comp: &mut Component) {
     }
 }
 
+/// Mark code matched by `[project].treat_as_external` as external.
+///
+/// A node matches when its source file, or for synthetic nodes its ID read as a path
+/// (`pkg::<file>` as `pkg/<file>`), matches one of `patterns` relative to
+/// `project_root`. Synthetic nodes of a package whose components all matched are
+/// marked too. Marked components, and the dependencies they originate, are removed
+/// from `components` and `dependencies` so scoring treats them like real external
+/// packages; the graph keeps them, so dependencies on them stay visible.
+pub fn apply_treat_as_external(
+    graph: &mut DependencyGraph,
+    components: &mut Vec<Component>,
+    dependencies: &mut Vec<Dependency>,
+    patterns: &[String],
+    project_root: &Path,
+) {
+    if patterns.is_empty() {
+        return;
+    }
+    let globs = build_globset(patterns);
+    let normalize = |p: &Path| -> String {
+        p.strip_prefix(project_root)
+            .unwrap_or(p)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+    let package_key =
+        |id: &ComponentId| (id.language().map(str::to_string), id.package().to_string());
+
+    let mut marked: HashSet<ComponentId> = HashSet::new();
+    let mut partly_internal: HashSet<(Option<String>, String)> = HashSet::new();
+    let mut fully_external: HashSet<(Option<String>, String)> = HashSet::new();
+    for node in graph.nodes() {
+        if node.is_external || node.kind.is_none() {
+            continue;
+        }
+        if globs.is_match(normalize(&node.location.file)) {
+            marked.insert(node.id.clone());
+            fully_external.insert(package_key(&node.id));
+        } else {
+            partly_internal.insert(package_key(&node.id));
+        }
+    }
+    for node in graph.nodes() {
+        if node.is_external || node.kind.is_some() {
+            continue;
+        }
+        let key = package_key(&node.id);
+        if globs.is_match(node.id.untagged().replace("::", "/"))
+            || (fully_external.contains(&key) && !partly_internal.contains(&key))
+        {
+            marked.insert(node.id.clone());
+        }
+    }
+
+    for id in &marked {
+        graph.mark_external(id);
+    }
+    components.retain(|c| !marked.contains(&c.id));
+    dependencies.retain(|d| !marked.contains(&d.from));
+}
+
+/// Merge one file's extraction into `graph`. A dependency target with no component
+/// yet is marked cross-cutting, as external code is; the mark goes if the component is
+/// merged later. Embeddings of types from other packages are set aside in `embedded`,
+/// and methods declared apart from their type in `impl_methods`, until every
+/// component is known.
+fn merge_file(
+    graph: &mut DependencyGraph,
+    fr: FileResult,
+    components: &mut Vec<Component>,
+    dependencies: &mut Vec<Dependency>,
+    embedded: &mut Vec<ClassifiedDependency>,
+    impl_methods: &mut Vec<ImplMethods>,
+) {
+    for (comp, _) in fr.components {
+        graph.add_component(&comp);
+        components.push(comp);
+    }
+    for factory in &fr.factories {
+        graph.add_factory(factory);
+    }
+    for access in &fr.field_accesses {
+        graph.add_field_access(access);
+    }
+    impl_methods.extend(fr.impl_methods);
+    for classified in fr.dependencies {
+        if EmbeddingTargets::applies_to(&classified.0) {
+            embedded.push(classified);
+        } else {
+            merge_dependency(graph, &classified);
+            dependencies.push(classified.0);
+        }
+    }
+}
+
+/// Merge the embeddings [`merge_file`] set aside, pointed at their components.
+fn merge_embeddings(
+    graph: &mut DependencyGraph,
+    embedded: Vec<ClassifiedDependency>,
+    targets: &EmbeddingTargets,
+    dependencies: &mut Vec<Dependency>,
+) {
+    for mut classified in embedded {
+        targets.resolve(&mut classified.0);
+        merge_dependency(graph, &classified);
+        dependencies.push(classified.0);
+    }
+}
+
+/// Attach the methods [`merge_file`] set aside to their type's component: the one with
+/// the type's ID, else the nearest of that name in an enclosing module, else the only
+/// one of that name.
+pub fn merge_impl_methods(
+    graph: &mut DependencyGraph,
+    components: &mut [Component],
+    impl_methods: Vec<ImplMethods>,
+) {
+    for im in impl_methods {
+        let Some(i) = impl_target(components, &im.type_id) else {
+            continue;
+        };
+        let component = &mut components[i];
+        component.methods.extend(im.methods.iter().cloned());
+        if let ComponentKind::Entity(info) = &mut component.kind {
+            info.methods.extend(im.methods);
+            info.is_anemic_domain_model = false;
+        }
+        graph.update_methods(component);
+    }
+}
+
+fn impl_target(components: &[Component], type_id: &ComponentId) -> Option<usize> {
+    if let Some(i) = components.iter().position(|c| c.id == *type_id) {
+        return Some(i);
+    }
+    let untagged = type_id.untagged();
+    let name = untagged.rsplit("::").next().unwrap_or(untagged);
+    let named: Vec<usize> = (0..components.len())
+        .filter(|&i| {
+            let c = &components[i];
+            c.name == name
+                && c.id.language() == type_id.language()
+                && !matches!(c.kind, ComponentKind::Port(_))
+        })
+        .collect();
+    let module = type_id.package();
+    let enclosing = named
+        .iter()
+        .copied()
+        .filter(|&i| module.starts_with(&format!("{}/", components[i].id.package())))
+        .max_by_key(|&i| components[i].id.package().len());
+    match (enclosing, named.as_slice()) {
+        (Some(i), _) => Some(i),
+        (None, [i]) => Some(*i),
+        _ => None,
+    }
+}
+
+fn merge_dependency(
+    graph: &mut DependencyGraph,
+    (dep, from_layer, to_layer, is_cc, arch_mode, to_is_cc): &ClassifiedDependency,
+) {
+    graph.ensure_node_with_mode(&dep.from, *from_layer, *is_cc, *arch_mode);
+    let target_is_external = !graph.has_component(&dep.to);
+    graph.ensure_node(&dep.to, *to_layer, *to_is_cc || target_is_external);
+    graph.add_dependency(dep);
+}
+
+/// Targets for embeddings of types from another package (Go's `postgres.Store`).
+///
+/// The analyzer only knows the embedded type's import path, which names no
+/// component. The target is the component of that name whose source directory,
+/// relative to the project root, the import path ends with: the same suffix match
+/// that tells project imports from external packages.
+pub struct EmbeddingTargets {
+    by_name: HashMap<TypeName, Vec<(String, ComponentId)>>,
+}
+
+/// A type name with its component's language tag.
+type TypeName = (Option<String>, String);
+
+impl EmbeddingTargets {
+    pub fn new(components: &[Component], project_root: &Path) -> Self {
+        let mut by_name: HashMap<_, Vec<_>> = HashMap::new();
+        for c in components {
+            let file = c
+                .location
+                .file
+                .strip_prefix(project_root)
+                .unwrap_or(&c.location.file);
+            let Some(dir) = file
+                .parent()
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+            else {
+                continue;
+            };
+            if dir.is_empty() {
+                continue;
+            }
+            let key = (c.id.language().map(str::to_string), c.name.clone());
+            by_name.entry(key).or_default().push((dir, c.id.clone()));
+        }
+        Self { by_name }
+    }
+
+    /// Whether `dep` embeds a type from an imported package, so needs resolving.
+    pub fn applies_to(dep: &Dependency) -> bool {
+        dep.kind == DependencyKind::Embedding && dep.import_path.is_some()
+    }
+
+    /// Point `dep`, if it embeds a type from an imported package, at that type's
+    /// component. Types from outside the project keep their import-path target.
+    pub fn resolve(&self, dep: &mut Dependency) {
+        let Some(import_path) = dep.import_path.as_deref().filter(|_| Self::applies_to(dep)) else {
+            return;
+        };
+        let (_, name) = dep.to.untagged().rsplit_once("::").unwrap_or(("", ""));
+        let key = (dep.to.language().map(str::to_string), name.to_string());
+        let target = self
+            .by_name
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .filter(|(dir, _)| {
+                import_path == dir
+                    || import_path
+                        .strip_suffix(dir.as_str())
+                        .is_some_and(|rest| rest.ends_with('/'))
+            })
+            .max_by_key(|(dir, _)| dir.len());
+        if let Some((_, id)) = target {
+            dep.to = id.clone();
+        }
+    }
+}
+
+/// Parse and extract `files` in parallel, handing each result to `consume` with the
+/// index of its file, in file order.
+///
+/// Without `max_in_flight_files`, every result is collected before `consume` sees any.
+/// With it, results stream to `consume` as they complete: workers take files in order
+/// and stop taking more while that many are parsed but not yet consumed, so a caller
+/// that merges each result and drops the file's source holds at most that many in
+/// memory. A result that completes ahead of an earlier file waits for it, which keeps
+/// the merge independent of thread timing. `threads` caps the worker pool.
+pub fn process_files<T, P, C>(
+    files: &[PathBuf],
+    project: &ProjectConfig,
+    process: P,
+    mut consume: C,
+) -> Result<()>
+where
+    T: Send,
+    P: Fn(&Path) -> Option<T> + Sync,
+    C: FnMut(usize, T),
+{
+    let pool = match project.threads {
+        Some(threads) => Some(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .context("failed to start the analysis thread pool")?,
+        ),
+        None => None,
+    };
+    let pool = pool.as_ref();
+    let process = &process;
+
+    match project.max_in_flight_files {
+        None => {
+            let results: Vec<(usize, T)> = in_pool(pool, || {
+                files
+                    .par_iter()
+                    .enumerate()
+                    .filter_map(|(i, file)| process(file).map(|r| (i, r)))
+                    .collect()
+            });
+            for (i, result) in results {
+                consume(i, result);
+            }
+        }
+        Some(cap) => {
+            let cap = cap.max(1);
+            let window = InFlight::default();
+            let (tx, rx) = std::sync::mpsc::sync_channel(cap);
+            std::thread::scope(|scope| {
+                let window = &window;
+                scope.spawn(move || {
+                    in_pool(pool, || {
+                        rayon::scope(|s| {
+                            for _ in 0..rayon::current_num_threads() {
+                                let tx = tx.clone();
+                                s.spawn(move |_| {
+                                    while let Some(i) = window.claim(files.len(), cap) {
+                                        // The receiver outlives the workers, so this cannot fail.
+                                        let _ = tx.send((i, process(&files[i])));
+                                    }
+                                });
+                            }
+                        });
+                    });
+                });
+
+                let mut ahead = BTreeMap::new();
+                let mut next = 0;
+                for (i, result) in rx {
+                    ahead.insert(i, result);
+                    while let Some(result) = ahead.remove(&next) {
+                        if let Some(result) = result {
+                            consume(next, result);
+                        }
+                        next += 1;
+                        window.consumed(next);
+                    }
+                }
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Files taken by workers and results consumed so far, bounding the files in flight
+/// for [`process_files`].
+#[derive(Default)]
+struct InFlight {
+    state: std::sync::Mutex<(usize, usize)>,
+    changed: std::sync::Condvar,
+}
+
+impl InFlight {
+    /// Take the next file, waiting while `cap` files are taken but not consumed.
+    /// `None` once all `len` files are taken.
+    fn claim(&self, len: usize, cap: usize) -> Option<usize> {
+        let mut state = self.state.lock().unwrap();
+        while state.0 < len && state.0 - state.1 >= cap {
+            state = self.changed.wait(state).unwrap();
+        }
+        let next = state.0;
+        (next < len).then(|| {
+            state.0 += 1;
+            next
+        })
+    }
+
+    /// Record that the results of the first `count` files were consumed.
+    fn consumed(&self, count: usize) {
+        self.state.lock().unwrap().1 = count;
+        self.changed.notify_all();
+    }
+}
+
+/// Run `work` in `pool`, or in the global rayon pool when there is none.
+fn in_pool<R: Send>(pool: Option<&rayon::ThreadPool>, work: impl FnOnce() -> R + Send) -> R {
+    match pool {
+        Some(pool) => pool.install(work),
+        None => work(),
+    }
+}
+
 /// Reusable analysis pipeline that can be shared between CLI and LSP.
 pub struct AnalysisPipeline {
     analyzers: Vec<Box<dyn LanguageAnalyzer>>,
     config: Config,
     classifier: LayerClassifier,
+    component_classifier: ComponentClassifier,
+    rules: Vec<Box<dyn Rule>>,
 }
 
 impl AnalysisPipeline {
-    pub fn new(analyzers: Vec<Box<dyn LanguageAnalyzer>>, config: Config) -> Self {
+    /// Fails when a `[[classification]]` rule's pattern does not compile.
+    pub fn new(analyzers: Vec<Box<dyn LanguageAnalyzer>>, config: Config) -> Result<Self> {
         let classifier = LayerClassifier::new(&config.layers);
-        Self {
+        let component_classifier = ComponentClassifier::new(&config.classification)?;
+        Ok(Self {
             analyzers,
             config,
             classifier,
-        }
+            component_classifier,
+            rules: Vec::new(),
+        })
+    }
+
+    /// Register a rule to run after the built-in ones on every analysis.
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
     }
 
     /// Run a full analysis on the given project path.
@@ -103,10 +484,23 @@ impl AnalysisPipeline {
     /// `module_path` is the directory to analyze.
     /// `project_root` is the project root for layer classification patterns.
     pub fn analyze_module(&self, module_path: &Path, project_root: &Path) -> Result<FullAnalysis> {
+        self.analyze_scoped(module_path, project_root, &self.config, &self.classifier)
+    }
+
+    /// Module-scoped analysis with `config` and its layer `classifier` in place of the
+    /// pipeline's own, for zones that carry their own layers and scoring.
+    fn analyze_scoped(
+        &self,
+        module_path: &Path,
+        project_root: &Path,
+        config: &Config,
+        classifier: &LayerClassifier,
+    ) -> Result<FullAnalysis> {
         let mut graph = DependencyGraph::new();
         let mut total_deps = 0usize;
         let mut total_files = 0usize;
         let mut all_components = Vec::new();
+        let mut generated_files = Vec::new();
         let mut all_dependencies = Vec::new();
 
         for analyzer in &self.analyzers {
@@ -136,128 +530,142 @@ impl AnalysisPipeline {
                 continue;
             }
 
-            let classifier = &self.classifier;
+            let component_classifier = &self.component_classifier;
 
-            let file_results: Vec<FileResult> = source_files
-                .par_iter()
-                .filter_map(|file_path| {
-                    let content = match std::fs::read_to_string(file_path) {
-                        Ok(c) => c,
-                        Err(e) => {
-                            eprintln!("Warning: failed to read {}: {e}", file_path.display());
-                            return None;
-                        }
-                    };
-
-                    // Use project_root for relative path computation so layer patterns match
-                    let rel_path = file_path
-                        .strip_prefix(project_root)
-                        .unwrap_or(file_path)
-                        .to_string_lossy()
-                        .to_string();
-
-                    let parsed = match analyzer.parse_file(file_path, &content) {
-                        Ok(p) => p,
-                        Err(e) => {
-                            eprintln!("Warning: failed to parse {}: {e}", file_path.display());
-                            return None;
+            let process_file = |file_path: &Path| {
+                let content = match std::fs::read_to_string(file_path) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Warning: failed to read {}: {e}", file_path.display());
+                        return None;
+                    }
+                };
+
+                // Use project_root for relative path computation so layer patterns match
+                let rel_path = file_path
+                    .strip_prefix(project_root)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
+
+                let parsed = match analyzer.parse_file(file_path, &content) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        eprintln!("Warning: failed to parse {}: {e}", file_path.display());
+                        return None;
+                    }
+                };
+
+                let mut components_raw = analyzer.extract_components(&parsed);
+                let file_layer = classifier.classify_file(&rel_path, &content);
+                let is_cross_cutting = classifier.is_cross_cutting(&rel_path);
+                let is_generated = is_generated_source(&content);
+                let arch_mode = classifier.architecture_mode(&rel_path);
+
+                let components: Vec<_> = components_raw
+                    .drain(..)
+                    .map(|mut comp| {
+                        component_classifier.apply(&mut comp, &rel_path);
+                        if comp.layer.is_none() {
+                            comp.layer = file_layer;
                         }
-                    };
-
-                    let mut components_raw = analyzer.extract_components(&parsed);
-                    let file_layer = classifier.classify(&rel_path);
-                    let is_cross_cutting = classifier.is_cross_cutting(&rel_path);
-                    let arch_mode = classifier.architecture_mode(&rel_path);
-
-                    let components: Vec<_> = components_raw
-                        .drain(..)
-                        .map(|mut comp| {
-                            if comp.layer.is_none() {
-                                comp.layer = file_layer;
-                            }
-                            comp.is_cross_cutting = is_cross_cutting;
-                            comp.architecture_mode = arch_mode;
-                            reclassify_infra_handlers(&mut comp);
-                            let layer = comp.layer;
-                            (comp, layer)
-                        })
-                        .collect();
-
-                    let deps = analyzer.extract_dependencies(&parsed);
-                    let dependencies: Vec<_> = deps
-                        .into_iter()
-                        .filter(|dep| {
-                            // MethodCall (init function) deps use local aliases, not module paths;
-                            // never treat them as stdlib. Only filter Import-kind deps.
-                            matches!(dep.kind, DependencyKind::MethodCall)
-                                || !dep
-                                    .import_path
-                                    .as_deref()
-                                    .is_some_and(|p| analyzer.is_stdlib_import(p))
-                        })
-                        .map(|dep| {
-                            let to_layer = dep
-                                .import_path
-                                .as_deref()
-                                .and_then(|p| classifier.classify_import(p));
-                            let to_is_cross_cutting = dep
-                                .import_path
-                                .as_deref()
-                                .is_some_and(|p| classifier.is_cross_cutting(p));
-                            let from_layer = classifier.classify(&rel_path);
-                            (
-                                dep,
-                                from_layer,
-                                to_layer,
-                                is_cross_cutting,
-                                arch_mode,
-                                to_is_cross_cutting,
-                            )
-                        })
-                        .collect();
-
-                    Some(FileResult {
-                        components,
-                        dependencies,
+                        comp.is_cross_cutting = is_cross_cutting;
+                        comp.is_generated = is_generated;
+                        comp.architecture_mode = arch_mode;
+                        reclassify_infra_handlers(&mut comp);
+                        let layer = comp.layer;
+                        (comp, layer)
+                    })
+                    .collect();
+
+                let deps = analyzer.extract_dependencies(&parsed);
+                let dependencies: Vec<_> = deps
+                    .into_iter()
+                    .filter(|dep| {
+                        // MethodCall (init function) deps use local aliases, not module paths;
+                        // never treat them as stdlib. Only filter Import-kind deps.
+                        matches!(dep.kind, DependencyKind::MethodCall)
+                            || !dep.import_path.as_deref().is_some_and(|p| {
+                                analyzer.is_stdlib_import(p) && !config.rules.is_infra_import(p)
+                            })
+                    })
+                    .map(|dep| {
+                        let import_path = dep
+                            .import_path
+                            .as_deref()
+                            .map(|p| resolve_relative_import(&rel_path, p));
+                        let to_layer = import_path
+                            .as_deref()
+                            .and_then(|p| classifier.classify_import(p));
+                        let to_is_cross_cutting = import_path
+                            .as_deref()
+                            .is_some_and(|p| classifier.is_cross_cutting(p));
+                        let from_layer = classifier.classify_file(&rel_path, &content);
+                        (
+                            dep,
+                            from_layer,
+                            to_layer,
+                            is_cross_cutting,
+                            arch_mode,
+                            to_is_cross_cutting,
+                        )
                     })
+                    .collect();
+                let factories = analyzer.extract_factories(&parsed);
+                let field_accesses = analyzer.extract_field_accesses(&parsed);
+                let impl_methods = analyzer.extract_impl_methods(&parsed);
+
+                Some(FileResult {
+                    components,
+                    dependencies,
+                    is_generated,
+                    factories,
+                    field_accesses,
+                    impl_methods,
                 })
-                .collect();
-
-            total_files += file_results.len();
-
-            // First pass: add all source file components
-            for fr in &file_results {
-                for (comp, _) in &fr.components {
-                    graph.add_component(comp);
-                    all_components.push(comp.clone());
-                }
-            }
-
-            // Collect known source component IDs for external dependency detection
-            let source_ids: std::collections::HashSet<_> =
-                all_components.iter().map(|c| &c.id).collect();
-
-            // Second pass: add dependencies, marking external targets as cross-cutting
-            for fr in file_results {
-                for (dep, from_layer, to_layer, is_cc, arch_mode, to_is_cc) in &fr.dependencies {
-                    graph.ensure_node_with_mode(&dep.from, *from_layer, *is_cc, *arch_mode);
-                    let target_is_external = !source_ids.contains(&dep.to);
-                    graph.ensure_node(&dep.to, *to_layer, *to_is_cc || target_is_external);
-                    graph.add_dependency(dep);
-                    all_dependencies.push(dep.clone());
+            };
+            let mut embedded = Vec::new();
+            let mut impl_methods = Vec::new();
+            process_files(&source_files, &config.project, process_file, |i, fr| {
+                if fr.is_generated {
+                    generated_files.push(source_files[i].clone());
                 }
+                total_files += 1;
                 total_deps += fr.dependencies.len();
-            }
+                merge_file(
+                    &mut graph,
+                    fr,
+                    &mut all_components,
+                    &mut all_dependencies,
+                    &mut embedded,
+                    &mut impl_methods,
+                );
+            })?;
+            let embeddings = EmbeddingTargets::new(&all_components, module_path);
+            merge_embeddings(&mut graph, embedded, &embeddings, &mut all_dependencies);
+            merge_impl_methods(&mut graph, &mut all_components, impl_methods);
         }
 
-        let result = metrics::build_result(
+        apply_treat_as_external(
+            &mut graph,
+            &mut all_components,
+            &mut all_dependencies,
+            &config.project.treat_as_external,
+            project_root,
+        );
+
+        let mut result = metrics::build_result_timed(
             &graph,
-            &self.config,
+            config,
             total_deps,
             &all_components,
             total_files,
             &all_dependencies,
+            &self.rules,
+            project_root,
+            &mut PhaseTimer::start(),
         );
+        result.generated_files = generated_files;
         Ok(FullAnalysis {
             result,
             graph,
@@ -271,13 +679,17 @@ impl AnalysisPipeline {
         let mut total_deps = 0usize;
         let mut total_files = 0usize;
         let mut all_components = Vec::new();
+        let mut generated_files = Vec::new();
         let mut all_dependencies = Vec::new();
 
-        let mut cache = if incremental {
+        // Workers read the loaded cache while results are merged into a fresh one, so
+        // files that no longer exist drop out of the saved cache.
+        let previous = if incremental {
             AnalysisCache::load(project_path).unwrap_or_default()
         } else {
             AnalysisCache::new()
         };
+        let mut cache = AnalysisCache::new();
 
         for analyzer in &self.analyzers {
             let extensions: Vec<&str> = analyzer.file_extensions().to_vec();
@@ -307,205 +719,219 @@ impl AnalysisPipeline {
             }
 
             let classifier = &self.classifier;
-
-            let file_results: Vec<(String, FileResult, String)> = source_files
-                .par_iter()
-                .filter_map(|file_path| {
-                    let content = match std::fs::read_to_string(file_path) {
-                        Ok(c) => c,
-                        Err(e) => {
-                            eprintln!("Warning: failed to read {}: {e}", file_path.display());
-                            return None;
-                        }
-                    };
-
-                    let rel_path = file_path
-                        .strip_prefix(project_path)
-                        .unwrap_or(file_path)
-                        .to_string_lossy()
-                        .to_string();
-
-                    let is_cross_cutting = classifier.is_cross_cutting(&rel_path);
-                    let arch_mode = classifier.architecture_mode(&rel_path);
-
-                    if incremental {
-                        if let Some(cached) = cache.get(&rel_path, &content) {
-                            let file_layer = classifier.classify(&rel_path);
-                            let components: Vec<_> = cached
-                                .components
-                                .iter()
-                                .map(|comp| {
-                                    let mut comp = comp.clone();
-                                    if comp.layer.is_none() {
-                                        comp.layer = file_layer;
-                                    }
-                                    comp.is_cross_cutting = is_cross_cutting;
-                                    comp.architecture_mode = arch_mode;
-                                    reclassify_infra_handlers(&mut comp);
-                                    let layer = comp.layer;
-                                    (comp, layer)
-                                })
-                                .collect();
-
-                            let dependencies: Vec<_> = cached
-                                .dependencies
-                                .iter()
-                                .filter(|dep| {
-                                    matches!(dep.kind, DependencyKind::MethodCall)
-                                        || !dep
-                                            .import_path
-                                            .as_deref()
-                                            .is_some_and(|p| analyzer.is_stdlib_import(p))
-                                })
-                                .map(|dep| {
-                                    let to_layer = dep
-                                        .import_path
-                                        .as_deref()
-                                        .and_then(|p| classifier.classify_import(p));
-                                    let to_is_cross_cutting = dep
-                                        .import_path
-                                        .as_deref()
-                                        .is_some_and(|p| classifier.is_cross_cutting(p));
-                                    let from_layer = classifier.classify(&rel_path);
-                                    (
-                                        dep.clone(),
-                                        from_layer,
-                                        to_layer,
-                                        is_cross_cutting,
-                                        arch_mode,
-                                        to_is_cross_cutting,
-                                    )
-                                })
-                                .collect();
-
-                            return Some((
-                                rel_path,
-                                FileResult {
-                                    components,
-                                    dependencies,
-                                },
-                                content,
-                            ));
-                        }
+            let component_classifier = &self.component_classifier;
+
+            let process_file = |file_path: &Path| {
+                let content = match std::fs::read_to_string(file_path) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Warning: failed to read {}: {e}", file_path.display());
+                        return None;
                     }
+                };
 
-                    let parsed = match analyzer.parse_file(file_path, &content) {
-                        Ok(p) => p,
-                        Err(e) => {
-                            eprintln!("Warning: failed to parse {}: {e}", file_path.display());
-                            return None;
-                        }
-                    };
+                let rel_path = file_path
+                    .strip_prefix(project_path)
+                    .unwrap_or(file_path)
+                    .to_string_lossy()
+                    .to_string();
 
-                    let mut components_raw = analyzer.extract_components(&parsed);
-                    let file_layer = classifier.classify(&rel_path);
+                let is_cross_cutting = classifier.is_cross_cutting(&rel_path);
+                let is_generated = is_generated_source(&content);
+                let arch_mode = classifier.architecture_mode(&rel_path);
 
-                    let components: Vec<_> = components_raw
-                        .drain(..)
-                        .map(|mut comp| {
-                            if comp.layer.is_none() {
-                                comp.layer = file_layer;
-                            }
-                            comp.is_cross_cutting = is_cross_cutting;
-                            comp.architecture_mode = arch_mode;
-                            reclassify_infra_handlers(&mut comp);
-                            let layer = comp.layer;
-                            (comp, layer)
-                        })
-                        .collect();
-
-                    let deps = analyzer.extract_dependencies(&parsed);
-                    let dependencies: Vec<_> = deps
-                        .into_iter()
-                        .filter(|dep| {
-                            matches!(dep.kind, DependencyKind::MethodCall)
-                                || !dep
+                if incremental {
+                    if let Some(cached) = previous.get(&rel_path, &content) {
+                        let file_layer = classifier.classify_file(&rel_path, &content);
+                        let components: Vec<_> = cached
+                            .components
+                            .iter()
+                            .map(|comp| {
+                                let mut comp = comp.clone();
+                                component_classifier.apply(&mut comp, &rel_path);
+                                if comp.layer.is_none() {
+                                    comp.layer = file_layer;
+                                }
+                                comp.is_cross_cutting = is_cross_cutting;
+                                comp.is_generated = is_generated;
+                                comp.architecture_mode = arch_mode;
+                                reclassify_infra_handlers(&mut comp);
+                                let layer = comp.layer;
+                                (comp, layer)
+                            })
+                            .collect();
+
+                        let dependencies: Vec<_> = cached
+                            .dependencies
+                            .iter()
+                            .filter(|dep| {
+                                matches!(dep.kind, DependencyKind::MethodCall)
+                                    || !dep.import_path.as_deref().is_some_and(|p| {
+                                        analyzer.is_stdlib_import(p)
+                                            && !self.config.rules.is_infra_import(p)
+                                    })
+                            })
+                            .map(|dep| {
+                                let import_path = dep
                                     .import_path
                                     .as_deref()
-                                    .is_some_and(|p| analyzer.is_stdlib_import(p))
-                        })
-                        .map(|dep| {
-                            let to_layer = dep
-                                .import_path
-                                .as_deref()
-                                .and_then(|p| classifier.classify_import(p));
-                            let to_is_cross_cutting = dep
-                                .import_path
-                                .as_deref()
-                                .is_some_and(|p| classifier.is_cross_cutting(p));
-                            let from_layer = classifier.classify(&rel_path);
-                            (
-                                dep,
-                                from_layer,
-                                to_layer,
-                                is_cross_cutting,
-                                arch_mode,
-                                to_is_cross_cutting,
-                            )
-                        })
-                        .collect();
-
-                    Some((
-                        rel_path,
-                        FileResult {
-                            components,
-                            dependencies,
-                        },
-                        content,
-                    ))
-                })
-                .collect();
-
-            let current_files: Vec<String> =
-                file_results.iter().map(|(p, _, _)| p.clone()).collect();
-            total_files += file_results.len();
-
-            // First pass: add all source file components and update cache
-            for (rel_path, fr, content) in &file_results {
-                if incremental {
-                    let cached_components: Vec<_> =
-                        fr.components.iter().map(|(comp, _)| comp.clone()).collect();
-                    let cached_deps: Vec<_> = fr
-                        .dependencies
-                        .iter()
-                        .map(|(dep, _, _, _, _, _)| dep.clone())
-                        .collect();
-                    cache.insert(
-                        rel_path.clone(),
-                        content,
-                        CachedFileResult {
-                            hash: String::new(),
-                            components: cached_components,
-                            dependencies: cached_deps,
-                        },
-                    );
+                                    .map(|p| resolve_relative_import(&rel_path, p));
+                                let to_layer = import_path
+                                    .as_deref()
+                                    .and_then(|p| classifier.classify_import(p));
+                                let to_is_cross_cutting = import_path
+                                    .as_deref()
+                                    .is_some_and(|p| classifier.is_cross_cutting(p));
+                                let from_layer = classifier.classify_file(&rel_path, &content);
+                                (
+                                    dep.clone(),
+                                    from_layer,
+                                    to_layer,
+                                    is_cross_cutting,
+                                    arch_mode,
+                                    to_is_cross_cutting,
+                                )
+                            })
+                            .collect();
+
+                        return Some((
+                            rel_path,
+                            FileResult {
+                                components,
+                                dependencies,
+                                is_generated,
+                                factories: cached.factories.clone(),
+                                field_accesses: cached.field_accesses.clone(),
+                                impl_methods: cached.impl_methods.clone(),
+                            },
+                            content,
+                        ));
+                    }
                 }
 
-                for (comp, _) in &fr.components {
-                    graph.add_component(comp);
-                    all_components.push(comp.clone());
-                }
-            }
+                let parsed = match analyzer.parse_file(file_path, &content) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        eprintln!("Warning: failed to parse {}: {e}", file_path.display());
+                        return None;
+                    }
+                };
 
-            // Collect known source component IDs for external dependency detection
-            let source_ids: std::collections::HashSet<_> =
-                all_components.iter().map(|c| &c.id).collect();
-
-            // Second pass: add dependencies, marking external targets as cross-cutting
-            for (_rel_path, fr, _content) in file_results {
-                for (dep, from_layer, to_layer, is_cc, arch_mode, to_is_cc) in &fr.dependencies {
-                    graph.ensure_node_with_mode(&dep.from, *from_layer, *is_cc, *arch_mode);
-                    let target_is_external = !source_ids.contains(&dep.to);
-                    graph.ensure_node(&dep.to, *to_layer, *to_is_cc || target_is_external);
-                    graph.add_dependency(dep);
-                    all_dependencies.push(dep.clone());
-                }
-                total_deps += fr.dependencies.len();
-            }
+                let mut components_raw = analyzer.extract_components(&parsed);
+                let file_layer = classifier.classify_file(&rel_path, &content);
 
-            if incremental {
-                cache.prune(&current_files);
-            }
+                let components: Vec<_> = components_raw
+                    .drain(..)
+                    .map(|mut comp| {
+                        component_classifier.apply(&mut comp, &rel_path);
+                        if comp.layer.is_none() {
+                            comp.layer = file_layer;
+                        }
+                        comp.is_cross_cutting = is_cross_cutting;
+                        comp.is_generated = is_generated;
+                        comp.architecture_mode = arch_mode;
+                        reclassify_infra_handlers(&mut comp);
+                        let layer = comp.layer;
+                        (comp, layer)
+                    })
+                    .collect();
+
+                let deps = analyzer.extract_dependencies(&parsed);
+                let dependencies: Vec<_> = deps
+                    .into_iter()
+                    .filter(|dep| {
+                        matches!(dep.kind, DependencyKind::MethodCall)
+                            || !dep.import_path.as_deref().is_some_and(|p| {
+                                analyzer.is_stdlib_import(p)
+                                    && !self.config.rules.is_infra_import(p)
+                            })
+                    })
+                    .map(|dep| {
+                        let import_path = dep
+                            .import_path
+                            .as_deref()
+                            .map(|p| resolve_relative_import(&rel_path, p));
+                        let to_layer = import_path
+                            .as_deref()
+                            .and_then(|p| classifier.classify_import(p));
+                        let to_is_cross_cutting = import_path
+                            .as_deref()
+                            .is_some_and(|p| classifier.is_cross_cutting(p));
+                        let from_layer = classifier.classify_file(&rel_path, &content);
+                        (
+                            dep,
+                            from_layer,
+                            to_layer,
+                            is_cross_cutting,
+                            arch_mode,
+                            to_is_cross_cutting,
+                        )
+                    })
+                    .collect();
+                let factories = analyzer.extract_factories(&parsed);
+                let field_accesses = analyzer.extract_field_accesses(&parsed);
+                let impl_methods = analyzer.extract_impl_methods(&parsed);
+
+                Some((
+                    rel_path,
+                    FileResult {
+                        components,
+                        dependencies,
+                        is_generated,
+                        factories,
+                        field_accesses,
+                        impl_methods,
+                    },
+                    content,
+                ))
+            };
+            // Update the cache and merge as results arrive so each file's source can be
+            // dropped.
+            let mut embedded = Vec::new();
+            let mut impl_methods = Vec::new();
+            process_files(
+                &source_files,
+                &self.config.project,
+                process_file,
+                |i, (rel_path, fr, content)| {
+                    if incremental {
+                        let cached_components: Vec<_> =
+                            fr.components.iter().map(|(comp, _)| comp.clone()).collect();
+                        let cached_deps: Vec<_> = fr
+                            .dependencies
+                            .iter()
+                            .map(|(dep, _, _, _, _, _)| dep.clone())
+                            .collect();
+                        cache.insert(
+                            rel_path,
+                            &content,
+                            CachedFileResult::new(
+                                cached_components,
+                                cached_deps,
+                                fr.factories.clone(),
+                                fr.field_accesses.clone(),
+                                fr.impl_methods.clone(),
+                            ),
+                        );
+                    }
+                    if fr.is_generated {
+                        generated_files.push(source_files[i].clone());
+                    }
+                    total_files += 1;
+                    total_deps += fr.dependencies.len();
+                    merge_file(
+                        &mut graph,
+                        fr,
+                        &mut all_components,
+                        &mut all_dependencies,
+                        &mut embedded,
+                        &mut impl_methods,
+                    );
+                },
+            )?;
+            let embeddings = EmbeddingTargets::new(&all_components, project_path);
+            merge_embeddings(&mut graph, embedded, &embeddings, &mut all_dependencies);
+            merge_impl_methods(&mut graph, &mut all_components, impl_methods);
         }
 
         if incremental {
@@ -514,14 +940,26 @@ impl AnalysisPipeline {
             }
         }
 
-        let result = metrics::build_result(
+        apply_treat_as_external(
+            &mut graph,
+            &mut all_components,
+            &mut all_dependencies,
+            &self.config.project.treat_as_external,
+            project_path,
+        );
+
+        let mut result = metrics::build_result_timed(
             &graph,
             &self.config,
             total_deps,
             &all_components,
             total_files,
             &all_dependencies,
+            &self.rules,
+            project_path,
+            &mut PhaseTimer::start(),
         );
+        result.generated_files = generated_files;
         Ok(FullAnalysis {
             result,
             graph,
@@ -536,9 +974,10 @@ impl AnalysisPipeline {
     }
 
     /// Run per-service analysis for monorepo support.
-    /// Discovers services matching the pattern, analyzes each independently,
-    /// and returns aggregate results.
+    /// Discovers services by `service_marker` or the services pattern, analyzes
+    /// each independently, and returns aggregate results.
     pub fn analyze_per_service(&self, project_path: &Path) -> Result<metrics::MultiServiceResult> {
+        let marker = self.config.project.service_marker.as_deref();
         let pattern = self
             .config
             .project
@@ -546,40 +985,44 @@ impl AnalysisPipeline {
             .as_deref()
             .unwrap_or("services/*");
 
-        let service_dirs = discover_services(project_path, pattern);
+        let service_dirs = match marker {
+            Some(marker) => discover_services_by_marker(project_path, marker),
+            None => discover_services(project_path, pattern),
+        };
 
         if service_dirs.is_empty() {
-            anyhow::bail!(
-                "no services found matching pattern '{}' in '{}'",
-                pattern,
-                project_path.display()
-            );
+            match marker {
+                Some(marker) => anyhow::bail!(
+                    "no directories containing service marker '{}' in '{}'",
+                    marker,
+                    project_path.display()
+                ),
+                None => anyhow::bail!(
+                    "no services found matching pattern '{}' in '{}'",
+                    pattern,
+                    project_path.display()
+                ),
+            }
         }
 
         let mut service_results = Vec::new();
-        let mut import_paths_by_service: HashMap<String, Vec<String>> = HashMap::new();
-
         for service_dir in &service_dirs {
-            let service_name = service_dir
-                .file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| service_dir.to_string_lossy().to_string());
+            // Marker-discovered services sit at any depth, so name them by their
+            // relative path to keep e.g. `billing/api` and `orders/api` apart.
+            let relative = service_dir
+                .strip_prefix(project_path)
+                .ok()
+                .filter(|_| marker.is_some());
+            let service_name = match relative {
+                Some(rel) => rel.to_string_lossy().replace('\\', "/"),
+                None => service_dir
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| service_dir.to_string_lossy().to_string()),
+            };
 
             match self.analyze_module(service_dir, project_path) {
-                Ok(analysis) => {
-                    // Collect import paths for shared module detection
-                    let imports: Vec<String> = analysis
-                        .dependencies
-                        .iter()
-                        .filter_map(|d| d.import_path.clone())
-                        .collect();
-                    import_paths_by_service.insert(service_name.clone(), imports);
-
-                    service_results.push(metrics::ServiceAnalysisResult {
-                        service_name,
-                        result: analysis.result,
-                    });
-                }
+                Ok(analysis) => service_results.push((service_name, analysis)),
                 Err(e) => {
                     eprintln!(
                         "Warning: failed to analyze service '{}': {e}",
@@ -589,16 +1032,106 @@ impl AnalysisPipeline {
             }
         }
 
-        // Detect shared modules (import paths used by 2+ services)
-        let shared_modules = detect_shared_modules(&import_paths_by_service);
+        Ok(combine_units(service_results))
+    }
+
+    /// Analyze each `[[zones]]` entry with its own layers and scoring, then aggregate
+    /// them the same way as services. Files outside every zone are not analyzed.
+    pub fn analyze_per_zone(&self, project_path: &Path) -> Result<metrics::MultiServiceResult> {
+        if self.config.zones.is_empty() {
+            anyhow::bail!("no zones configured; add [[zones]] entries to .boundary.toml");
+        }
+
+        let mut zone_results = Vec::new();
+        for zone in &self.config.zones {
+            let zone_dir = project_path.join(&zone.path);
+            if !zone_dir.is_dir() {
+                anyhow::bail!(
+                    "zone '{}' path '{}' is not a directory",
+                    zone.name,
+                    zone_dir.display()
+                );
+            }
+            let config = self.config.for_zone(zone);
+            let classifier = LayerClassifier::new(&config.layers);
+            let analysis = self
+                .analyze_scoped(&zone_dir, project_path, &config, &classifier)
+                .with_context(|| format!("failed to analyze zone '{}'", zone.name))?;
+            zone_results.push((zone.name.clone(), analysis));
+        }
+
+        Ok(combine_units(zone_results))
+    }
+
+    /// Score each top-level directory of `project_path` as its own unit, for
+    /// per-directory trend tracking. Directories without analyzable source are skipped.
+    pub fn analyze_per_directory(
+        &self,
+        project_path: &Path,
+    ) -> Result<metrics::MultiServiceResult> {
+        let directories = discover_source_directories(project_path);
+
+        let mut dir_results = Vec::new();
+        for dir in &directories {
+            let name = dir
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| dir.to_string_lossy().to_string());
+            let analysis = self
+                .analyze_module(dir, project_path)
+                .with_context(|| format!("failed to analyze directory '{}'", dir.display()))?;
+            if analysis.components.is_empty() && analysis.dependencies.is_empty() {
+                continue;
+            }
+            dir_results.push((name, analysis));
+        }
 
-        let aggregate = metrics::aggregate_results(&service_results);
+        if dir_results.is_empty() {
+            anyhow::bail!(
+                "no top-level directories with source files in '{}'",
+                project_path.display()
+            );
+        }
 
-        Ok(metrics::MultiServiceResult {
-            services: service_results,
-            aggregate,
-            shared_modules,
-        })
+        Ok(combine_units(dir_results))
+    }
+}
+
+/// Combine per-service or per-zone analyses into one result with an aggregate score
+/// and the modules they share.
+fn combine_units(units: Vec<(String, FullAnalysis)>) -> metrics::MultiServiceResult {
+    let mut import_paths_by_service: HashMap<String, Vec<(String, SourceLocation)>> =
+        HashMap::new();
+    let mut services = Vec::new();
+    for (service_name, analysis) in units {
+        // Collect import paths and sites for shared module detection
+        let imports: Vec<(String, SourceLocation)> = analysis
+            .dependencies
+            .iter()
+            .filter_map(|d| Some((d.import_path.clone()?, d.location.clone())))
+            .collect();
+        import_paths_by_service.insert(service_name.clone(), imports);
+
+        services.push(metrics::ServiceAnalysisResult {
+            service_name,
+            result: analysis.result,
+        });
+    }
+
+    // Detect shared modules (import paths used by 2+ services)
+    let shared_module_graph = detect_shared_modules(&import_paths_by_service);
+    let shared_modules = shared_module_graph
+        .iter()
+        .map(metrics::SharedModuleUsage::summary)
+        .collect();
+
+    let aggregate = metrics::aggregate_results(&services);
+
+    metrics::MultiServiceResult {
+        services,
+        aggregate,
+        shared_modules,
+        shared_module_graph,
     }
 }
 
@@ -614,51 +1147,119 @@ pub fn discover_services(project_path: &Path, pattern: &str) -> Vec<PathBuf> {
     dirs
 }
 
-/// Detect shared modules from import paths used by multiple services.
+/// Directories never searched for service markers or scored per directory:
+/// dependency and build output trees are full of `package.json` and `go.mod`
+/// files that are not services.
+const MARKER_SKIP_DIRS: &[&str] = &["node_modules", "vendor", "target", "build", "dist"];
+
+/// Discover service directories as the directories below `project_path` that
+/// contain a `marker` file, at any depth. The project root itself is never a
+/// service, and a service directory is not searched for nested services.
+pub fn discover_services_by_marker(project_path: &Path, marker: &str) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let mut walker = walkdir::WalkDir::new(project_path)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy();
+        if name.starts_with('.') || MARKER_SKIP_DIRS.contains(&name.as_ref()) {
+            walker.skip_current_dir();
+            continue;
+        }
+        if entry.path().join(marker).is_file() {
+            dirs.push(entry.into_path());
+            walker.skip_current_dir();
+        }
+    }
+    dirs
+}
+
+/// Discover the immediate subdirectories of `project_path` to score with
+/// `--by-directory`, skipping hidden, dependency and build output directories.
+pub fn discover_source_directories(project_path: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(project_path) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().into_owned();
+            !name.starts_with('.') && !MARKER_SKIP_DIRS.contains(&name.as_str())
+        })
+        .map(|e| e.path())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// Detect shared modules from import paths used by multiple services, keeping
+/// each service's import sites.
 fn detect_shared_modules(
-    import_paths_by_service: &HashMap<String, Vec<String>>,
-) -> Vec<metrics::SharedModule> {
-    let mut path_to_services: HashMap<String, Vec<String>> = HashMap::new();
+    import_paths_by_service: &HashMap<String, Vec<(String, SourceLocation)>>,
+) -> Vec<metrics::SharedModuleUsage> {
+    // Ordered by path, then service, so the output is stable.
+    let mut path_to_services: BTreeMap<&str, BTreeMap<&str, Vec<SourceLocation>>> = BTreeMap::new();
 
     for (service, imports) in import_paths_by_service {
-        for import in imports {
+        for (import, location) in imports {
             path_to_services
-                .entry(import.clone())
+                .entry(import)
+                .or_default()
+                .entry(service)
                 .or_default()
-                .push(service.clone());
+                .push(location.clone());
         }
     }
 
-    let mut shared: Vec<_> = path_to_services
+    path_to_services
         .into_iter()
         .filter(|(_, services)| services.len() > 1)
-        .map(|(path, mut used_by)| {
-            used_by.sort();
-            used_by.dedup();
-            metrics::SharedModule { path, used_by }
+        .map(|(path, services)| metrics::SharedModuleUsage {
+            path: path.to_string(),
+            importers: services
+                .into_iter()
+                .map(|(service, mut locations)| {
+                    locations.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+                    metrics::SharedModuleImporter {
+                        service: service.to_string(),
+                        locations,
+                    }
+                })
+                .collect(),
         })
-        .collect();
-
-    shared.sort_by(|a, b| a.path.cmp(&b.path));
-    shared
+        .collect()
 }
 
-/// Walk up from `start` looking for `.boundary.toml` or `.git` to find the project root.
+/// Files that mark a project root, in order of precedence.
+///
+/// `.boundary-root` is an empty marker for pinning the root explicitly, e.g. above
+/// nested git submodules.
+const ROOT_MARKERS: &[&str] = &[".boundary-root", ".boundary.toml", ".git"];
+
+/// Walk up from `start` to find the project root: the nearest ancestor holding a
+/// `.boundary-root` marker, else the nearest with `.boundary.toml`, else the nearest
+/// with `.git`.
 pub fn find_project_root(start: &Path) -> Option<PathBuf> {
-    let mut current = if start.is_file() {
-        start.parent()?.to_path_buf()
+    let start = if start.is_file() {
+        start.parent()?
     } else {
-        start.to_path_buf()
+        start
     };
 
-    loop {
-        if current.join(".boundary.toml").exists() || current.join(".git").exists() {
-            return Some(current);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
+    ROOT_MARKERS.iter().find_map(|marker| {
+        start
+            .ancestors()
+            .find(|dir| dir.join(marker).exists())
+            .map(Path::to_path_buf)
+    })
 }
 
 #[cfg(test)]
@@ -683,6 +1284,75 @@ mod tests {
         assert!(names.contains(&"billing"));
     }
 
+    /// Collect what `process_files` hands over, by index.
+    fn collect_processed(files: &[PathBuf], project: &ProjectConfig) -> Vec<Option<String>> {
+        let mut slots = vec![None; files.len()];
+        process_files(
+            files,
+            project,
+            |file| {
+                let name = file.to_string_lossy().into_owned();
+                (!name.contains("skip")).then(|| name.to_uppercase())
+            },
+            |i, result| slots[i] = Some(result),
+        )
+        .unwrap();
+        slots
+    }
+
+    #[test]
+    fn test_process_files_streaming_matches_batch() {
+        let files: Vec<PathBuf> = (0..200)
+            .map(|i| {
+                PathBuf::from(if i % 7 == 0 {
+                    format!("skip{i}.go")
+                } else {
+                    format!("f{i}.go")
+                })
+            })
+            .collect();
+        let batch = collect_processed(&files, &ProjectConfig::default());
+        let streaming = collect_processed(
+            &files,
+            &ProjectConfig {
+                threads: Some(3),
+                max_in_flight_files: Some(1),
+                ..ProjectConfig::default()
+            },
+        );
+        assert_eq!(batch, streaming);
+        assert_eq!(batch[1].as_deref(), Some("F1.GO"));
+        assert_eq!(batch[7], None);
+    }
+
+    #[test]
+    fn test_process_files_streams_in_order_within_cap() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let files: Vec<PathBuf> = (0..100)
+            .map(|i| PathBuf::from(format!("f{i}.go")))
+            .collect();
+        let started = AtomicUsize::new(0);
+        let mut consumed = 0;
+        let mut order = Vec::new();
+        process_files(
+            &files,
+            &ProjectConfig {
+                threads: Some(4),
+                max_in_flight_files: Some(3),
+                ..ProjectConfig::default()
+            },
+            |_| Some(started.fetch_add(1, Ordering::SeqCst)),
+            |i, _| {
+                assert!(started.load(Ordering::SeqCst) - consumed <= 3);
+                consumed += 1;
+                order.push(i);
+            },
+        )
+        .unwrap();
+        assert_eq!(order, (0..100).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_discover_services_no_matches() {
         let tmp = tempfile::tempdir().unwrap();
@@ -690,22 +1360,234 @@ mod tests {
         assert!(dirs.is_empty());
     }
 
+    #[test]
+    fn test_discover_services_by_marker_at_any_depth() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        for dir in [
+            "auth",
+            "platform/billing/api",
+            "platform/billing/api/internal/tools",
+            "web/node_modules/left-pad",
+            "docs",
+        ] {
+            std::fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        for dir in [
+            "",
+            "auth",
+            "platform/billing/api",
+            "platform/billing/api/internal/tools",
+            "web/node_modules/left-pad",
+        ] {
+            std::fs::write(base.join(dir).join("go.mod"), "module x\n").unwrap();
+        }
+
+        let dirs = discover_services_by_marker(base, "go.mod");
+        let relative: Vec<_> = dirs
+            .iter()
+            .map(|d| {
+                d.strip_prefix(base)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        assert_eq!(relative, ["auth", "platform/billing/api"]);
+    }
+
+    #[test]
+    fn test_discover_source_directories_skips_hidden_and_build_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        for dir in ["pkg/util", "internal", ".git", "node_modules/x", "target"] {
+            std::fs::create_dir_all(base.join(dir)).unwrap();
+        }
+        std::fs::write(base.join("main.go"), "package main\n").unwrap();
+
+        let dirs = discover_source_directories(base);
+        let names: Vec<_> = dirs
+            .iter()
+            .map(|d| d.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["internal", "pkg"]);
+    }
+
+    #[test]
+    fn test_find_project_root_prefers_root_marker() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        let sub = base.join("vendor/lib");
+        std::fs::create_dir_all(sub.join("src/deep")).unwrap();
+        std::fs::create_dir_all(base.join(".git")).unwrap();
+        std::fs::write(sub.join(".git"), "gitdir: ../../.git/modules/lib").unwrap();
+        std::fs::write(base.join(".boundary.toml"), "").unwrap();
+
+        // .boundary.toml beats a nearer .git
+        assert_eq!(
+            find_project_root(&sub.join("src/deep")).as_deref(),
+            Some(base)
+        );
+
+        // .boundary-root beats both
+        std::fs::write(sub.join("src/.boundary-root"), "").unwrap();
+        assert_eq!(
+            find_project_root(&sub.join("src/deep")),
+            Some(sub.join("src"))
+        );
+    }
+
+    #[test]
+    fn test_find_project_root_falls_back_to_git() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path();
+        std::fs::create_dir_all(base.join(".git")).unwrap();
+        std::fs::create_dir_all(base.join("cmd/app")).unwrap();
+
+        assert_eq!(
+            find_project_root(&base.join("cmd/app")).as_deref(),
+            Some(base)
+        );
+    }
+
     #[test]
     fn test_detect_shared_modules() {
+        let import = |path: &str, file: &str, line| {
+            let location = SourceLocation {
+                file: PathBuf::from(file),
+                line,
+                column: 1,
+            };
+            (path.to_string(), location)
+        };
         let mut import_map = HashMap::new();
         import_map.insert(
             "auth".to_string(),
-            vec!["pkg/logger".to_string(), "pkg/db".to_string()],
+            vec![
+                import("pkg/logger", "auth/server.go", 4),
+                import("pkg/db", "auth/server.go", 5),
+                import("pkg/logger", "auth/handler.go", 3),
+            ],
         );
         import_map.insert(
             "billing".to_string(),
-            vec!["pkg/logger".to_string(), "pkg/events".to_string()],
+            vec![
+                import("pkg/logger", "billing/main.go", 6),
+                import("pkg/events", "billing/main.go", 7),
+            ],
         );
 
         let shared = detect_shared_modules(&import_map);
         assert_eq!(shared.len(), 1);
         assert_eq!(shared[0].path, "pkg/logger");
-        assert!(shared[0].used_by.contains(&"auth".to_string()));
-        assert!(shared[0].used_by.contains(&"billing".to_string()));
+        assert_eq!(shared[0].summary().used_by, ["auth", "billing"]);
+        let auth = &shared[0].importers[0];
+        let sites: Vec<_> = auth
+            .locations
+            .iter()
+            .map(|l| (l.file.to_str().unwrap(), l.line))
+            .collect();
+        assert_eq!(sites, [("auth/handler.go", 3), ("auth/server.go", 4)]);
+    }
+
+    #[test]
+    fn test_impl_methods_attach_to_type_declared_elsewhere() {
+        let entity = |package: &str| Component {
+            id: ComponentId::with_language("rust", package, "User"),
+            name: "User".to_string(),
+            kind: ComponentKind::Entity(crate::types::EntityInfo {
+                name: "User".to_string(),
+                fields: Vec::new(),
+                methods: Vec::new(),
+                is_active_record: false,
+                is_anemic_domain_model: true,
+            }),
+            layer: Some(ArchLayer::Domain),
+            location: SourceLocation::default(),
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
+        };
+        let rename = crate::types::MethodInfo {
+            name: "rename".to_string(),
+            parameters: "(&mut self, name: String)".to_string(),
+            return_type: String::new(),
+            parameter_types: Vec::new(),
+            visibility: crate::types::Visibility::Public,
+            interface_kind: crate::types::InterfaceMethodKind::Default,
+            annotations: Vec::new(),
+        };
+        let mut components = vec![entity("src/domain"), entity("src/billing")];
+        let mut graph = DependencyGraph::new();
+        for c in &components {
+            graph.add_component(c);
+        }
+
+        // `impl User` in `src/domain/user/behavior.rs` belongs to the `User` of the
+        // enclosing `src/domain` module, not the one in `src/billing`.
+        merge_impl_methods(
+            &mut graph,
+            &mut components,
+            vec![ImplMethods {
+                type_id: ComponentId::with_language("rust", "src/domain/user", "User"),
+                methods: vec![rename],
+            }],
+        );
+
+        let ComponentKind::Entity(info) = &components[0].kind else {
+            unreachable!()
+        };
+        assert_eq!(info.methods.len(), 1);
+        assert!(!info.is_anemic_domain_model);
+        assert!(components[1].methods.is_empty());
+        let node = graph
+            .nodes()
+            .into_iter()
+            .find(|n| n.id == components[0].id)
+            .unwrap();
+        assert!(matches!(&node.kind, Some(ComponentKind::Entity(info)) if info.methods.len() == 1));
+    }
+
+    #[test]
+    fn test_embedding_targets_resolve_to_project_components() {
+        let store = Component {
+            id: ComponentId::with_language("go", "app/internal/postgres", "Store"),
+            name: "Store".to_string(),
+            kind: ComponentKind::Service,
+            layer: Some(ArchLayer::Infrastructure),
+            location: SourceLocation {
+                file: PathBuf::from("app/internal/postgres/store.go"),
+                line: 3,
+                column: 1,
+            },
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
+        };
+        let targets = EmbeddingTargets::new(std::slice::from_ref(&store), Path::new("app"));
+        let embedding = |import_path: &str, name: &str| Dependency {
+            from: ComponentId::with_language("go", "app/internal/domain", "Admin"),
+            to: ComponentId::with_language("go", import_path, name),
+            kind: DependencyKind::Embedding,
+            location: SourceLocation::default(),
+            import_path: Some(import_path.to_string()),
+        };
+
+        let mut dep = embedding("github.com/acme/app/internal/postgres", "Store");
+        targets.resolve(&mut dep);
+        assert_eq!(dep.to, store.id);
+
+        for (import_path, name) in [
+            ("github.com/acme/app/internal/postgres", "Pool"),
+            ("github.com/acme/app/xinternal/postgres", "Store"),
+            ("github.com/lib/pq", "Store"),
+        ] {
+            let mut dep = embedding(import_path, name);
+            let unresolved = dep.to.clone();
+            targets.resolve(&mut dep);
+            assert_eq!(dep.to, unresolved);
+        }
     }
 }
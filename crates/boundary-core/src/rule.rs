@@ -0,0 +1,18 @@
+//! Extension point for rules implemented in Rust.
+//!
+//! Every built-in detector is a [`Rule`]; library users register their own with
+//! [`AnalysisPipeline::with_rule`](crate::pipeline::AnalysisPipeline::with_rule) and
+//! they run after the built-ins. Path-scoped severity overrides, `[[rules.ignore]]`
+//! entries and `doc_base_url` apply to their violations as well.
+
+use crate::config::Config;
+use crate::graph::DependencyGraph;
+use crate::types::Violation;
+
+/// A check over the dependency graph.
+pub trait Rule: Send + Sync {
+    /// Violations found in `graph`. Rules pick their own severity, usually with
+    /// [`RulesConfig::resolve_severity`](crate::config::RulesConfig::resolve_severity)
+    /// so `[rules.severities]` can adjust it.
+    fn evaluate(&self, graph: &DependencyGraph, config: &Config) -> Vec<Violation>;
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use globset::{Glob, GlobMatcher};
+
+use crate::config::{configured_severity, RuleOverrideConfig};
+use crate::types::{path_relative_to_root, Severity, ViolationKind};
+
+/// Resolves rule severities for a file path from `[[rules.overrides]]` scopes.
+pub struct RuleScoper {
+    scopes: Vec<(GlobMatcher, HashMap<String, Severity>)>,
+}
+
+impl RuleScoper {
+    pub fn new(overrides: &[RuleOverrideConfig]) -> Self {
+        let scopes = overrides
+            .iter()
+            .filter_map(|o| match Glob::new(&o.scope) {
+                Ok(glob) => Some((glob.compile_matcher(), o.severities.clone())),
+                Err(e) => {
+                    eprintln!(
+                        "Warning: invalid glob pattern '{}' in rules.overrides: {e}",
+                        o.scope
+                    );
+                    None
+                }
+            })
+            .collect();
+        Self { scopes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scopes.is_empty()
+    }
+
+    /// Severity configured for `kind` at `file_path`, if a matching scope sets one.
+    ///
+    /// The first scope whose glob matches wins. Scopes are written relative to the
+    /// project root, so they are matched against the path relative to `project_root`
+    /// only: `legacy/**` matches `/repo/legacy/a.go` under `/repo`, but not
+    /// `/repo/vendor/x/legacy/a.go`.
+    pub fn resolve(
+        &self,
+        kind: &ViolationKind,
+        file_path: &str,
+        project_root: &Path,
+    ) -> Option<Severity> {
+        let relative = path_relative_to_root(file_path, project_root);
+        let (_, severities) = self
+            .scopes
+            .iter()
+            .find(|(matcher, _)| matcher.is_match(&relative))?;
+        configured_severity(severities, kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ArchLayer;
+
+    fn legacy_scope() -> RuleScoper {
+        let mut severities = HashMap::new();
+        severities.insert("layer_boundary".to_string(), Severity::Warning);
+        RuleScoper::new(&[RuleOverrideConfig {
+            scope: "legacy/**".to_string(),
+            severities,
+        }])
+    }
+
+    fn domain_to_infra() -> ViolationKind {
+        ViolationKind::LayerBoundary {
+            from_layer: ArchLayer::Domain,
+            to_layer: ArchLayer::Infrastructure,
+        }
+    }
+
+    #[test]
+    fn test_scope_matches_paths_relative_to_root() {
+        let scoper = legacy_scope();
+        let kind = domain_to_infra();
+        let here = Path::new(".");
+        assert_eq!(
+            scoper.resolve(&kind, "legacy/domain/user.go", here),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            scoper.resolve(&kind, "./legacy/domain/user.go", here),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            scoper.resolve(
+                &kind,
+                "/work/repo/legacy/domain/user.go",
+                Path::new("/work/repo")
+            ),
+            Some(Severity::Warning)
+        );
+        assert_eq!(scoper.resolve(&kind, "domain/user.go", here), None);
+    }
+
+    #[test]
+    fn test_scope_is_anchored_at_root() {
+        let scoper = legacy_scope();
+        let kind = domain_to_infra();
+        assert_eq!(
+            scoper.resolve(&kind, "vendor/x/legacy/domain/user.go", Path::new(".")),
+            None
+        );
+        assert_eq!(
+            scoper.resolve(
+                &kind,
+                "/work/repo/vendor/legacy/user.go",
+                Path::new("/work/repo")
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_scope_without_matching_key_falls_through() {
+        let scoper = legacy_scope();
+        let kind = ViolationKind::MissingPort {
+            adapter_name: "Repo".to_string(),
+        };
+        assert_eq!(
+            scoper.resolve(&kind, "legacy/infra/repo.go", Path::new(".")),
+            None
+        );
+    }
+}
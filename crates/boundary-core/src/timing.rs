@@ -0,0 +1,181 @@
+use std::time::{Duration, Instant};
+
+/// Analysis phases reported by `boundary bench`, in pipeline order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Analyzer setup, cache loading and walking the tree for source files.
+    Discovery,
+    /// Reading and parsing source files.
+    Parse,
+    /// Extracting and classifying components and dependencies.
+    Extraction,
+    /// Building the dependency graph and resolving external nodes.
+    GraphBuild,
+    /// Violation detection and metrics.
+    Detection,
+    /// Architecture score, package metrics and pattern detection.
+    Scoring,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 6] = [
+        Phase::Discovery,
+        Phase::Parse,
+        Phase::Extraction,
+        Phase::GraphBuild,
+        Phase::Detection,
+        Phase::Scoring,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Discovery => "discovery",
+            Phase::Parse => "parse",
+            Phase::Extraction => "extraction",
+            Phase::GraphBuild => "graph_build",
+            Phase::Detection => "detection",
+            Phase::Scoring => "scoring",
+        }
+    }
+}
+
+/// Wall-clock time spent in each phase of an analysis run.
+#[derive(Debug, Clone, Default)]
+pub struct PhaseTimings {
+    phases: [Duration; Phase::ALL.len()],
+    /// Wall-clock time of the whole run, measured independently of the phases.
+    pub total: Duration,
+}
+
+impl PhaseTimings {
+    pub fn get(&self, phase: Phase) -> Duration {
+        self.phases[phase as usize]
+    }
+
+    pub fn add(&mut self, phase: Phase, duration: Duration) {
+        self.phases[phase as usize] += duration;
+    }
+
+    /// Sum of all phase durations. Matches `total` up to timer overhead.
+    pub fn phase_sum(&self) -> Duration {
+        self.phases.iter().sum()
+    }
+
+    /// Per-phase median across runs; `total` is the median of the run totals.
+    pub fn median(runs: &[PhaseTimings]) -> PhaseTimings {
+        let mut median = PhaseTimings::default();
+        for phase in Phase::ALL {
+            median.phases[phase as usize] =
+                median_duration(runs.iter().map(|r| r.get(phase)).collect());
+        }
+        median.total = median_duration(runs.iter().map(|r| r.total).collect());
+        median
+    }
+}
+
+fn median_duration(mut values: Vec<Duration>) -> Duration {
+    if values.is_empty() {
+        return Duration::ZERO;
+    }
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2
+    } else {
+        values[mid]
+    }
+}
+
+/// Lap timer for an analysis run.
+///
+/// Each lap charges the time since the previous lap to a phase, so consecutive laps
+/// cover the run without gaps and the phases add up to the total.
+pub struct PhaseTimer {
+    start: Instant,
+    last: Instant,
+    timings: PhaseTimings,
+}
+
+impl PhaseTimer {
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Self {
+            start: now,
+            last: now,
+            timings: PhaseTimings::default(),
+        }
+    }
+
+    /// Charge the time since the previous lap to `phase`.
+    pub fn lap(&mut self, phase: Phase) {
+        let elapsed = self.take_lap();
+        self.timings.add(phase, elapsed);
+    }
+
+    /// Charge the time since the previous lap to two phases that ran interleaved
+    /// (e.g. parse and extraction on worker threads), split in proportion to the
+    /// CPU time measured for each.
+    pub fn lap_split(&mut self, (a, a_cpu): (Phase, Duration), (b, b_cpu): (Phase, Duration)) {
+        let elapsed = self.take_lap();
+        let cpu = a_cpu + b_cpu;
+        let a_share = if cpu.is_zero() {
+            elapsed / 2
+        } else {
+            elapsed.mul_f64(a_cpu.as_secs_f64() / cpu.as_secs_f64())
+        };
+        self.timings.add(a, a_share);
+        self.timings.add(b, elapsed.saturating_sub(a_share));
+    }
+
+    /// Stop the timer and record the total.
+    pub fn finish(mut self) -> PhaseTimings {
+        self.timings.total = self.start.elapsed();
+        self.timings
+    }
+
+    fn take_lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now - self.last;
+        self.last = now;
+        elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_laps_sum_to_total() {
+        let mut timer = PhaseTimer::start();
+        std::thread::sleep(Duration::from_millis(2));
+        timer.lap(Phase::Discovery);
+        std::thread::sleep(Duration::from_millis(2));
+        timer.lap_split(
+            (Phase::Parse, Duration::from_millis(3)),
+            (Phase::Extraction, Duration::from_millis(1)),
+        );
+        timer.lap(Phase::Scoring);
+        let timings = timer.finish();
+
+        assert!(timings.get(Phase::Parse) > timings.get(Phase::Extraction));
+        assert!(timings.phase_sum() <= timings.total);
+        assert!(timings.total - timings.phase_sum() < Duration::from_millis(1));
+    }
+
+    #[test]
+    fn test_median_per_phase() {
+        let run = |ms: u64| {
+            let mut t = PhaseTimings::default();
+            t.add(Phase::Parse, Duration::from_millis(ms));
+            t.total = Duration::from_millis(ms);
+            t
+        };
+        let median = PhaseTimings::median(&[run(5), run(1), run(3)]);
+        assert_eq!(median.get(Phase::Parse), Duration::from_millis(3));
+        assert_eq!(median.total, Duration::from_millis(3));
+
+        let median = PhaseTimings::median(&[run(2), run(4)]);
+        assert_eq!(median.get(Phase::Parse), Duration::from_millis(3));
+    }
+}
@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Architecture mode for a module or component.
 /// Controls which violations are enforced.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+)]
 #[serde(rename_all = "kebab-case")]
 pub enum ArchitectureMode {
     #[default]
@@ -13,6 +15,16 @@ pub enum ArchitectureMode {
     ServiceOriented,
 }
 
+impl fmt::Display for ArchitectureMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchitectureMode::Ddd => write!(f, "ddd"),
+            ArchitectureMode::ActiveRecord => write!(f, "active-record"),
+            ArchitectureMode::ServiceOriented => write!(f, "service-oriented"),
+        }
+    }
+}
+
 /// Unique identifier for a component: "package::Name"
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ComponentId(pub String);
@@ -21,11 +33,51 @@ impl ComponentId {
     pub fn new(package: &str, name: &str) -> Self {
         Self(format!("{package}::{name}"))
     }
+
+    /// Build a language-scoped ID (`lang:package::name`) so that components with the
+    /// same path and name in different languages never collide.
+    pub fn with_language(language: &str, package: &str, name: &str) -> Self {
+        Self(format!("{language}:{package}::{name}"))
+    }
+
+    /// Language tag of a scoped ID, if present.
+    pub fn language(&self) -> Option<&str> {
+        split_language_tag(&self.0).0
+    }
+
+    /// The ID without its language tag (`package::name`).
+    pub fn untagged(&self) -> &str {
+        split_language_tag(&self.0).1
+    }
+
+    /// Package portion of the ID (everything before the first `::`).
+    pub fn package(&self) -> &str {
+        let id = self.untagged();
+        id.split("::").next().unwrap_or(id)
+    }
+}
+
+/// Split `lang:package::name` into its language tag and the remainder.
+///
+/// A tag is a non-empty run of lowercase ASCII letters/digits directly followed by a
+/// single `:`, so untagged IDs like `pkg::Name` are returned unchanged.
+pub fn split_language_tag(id: &str) -> (Option<&str>, &str) {
+    if let Some((tag, rest)) = id.split_once(':') {
+        let is_tag = !tag.is_empty()
+            && tag
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+            && !rest.starts_with(':');
+        if is_tag {
+            return (Some(tag), rest);
+        }
+    }
+    (None, id)
 }
 
 impl fmt::Display for ComponentId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.untagged())
     }
 }
 
@@ -82,11 +134,28 @@ impl fmt::Display for ArchLayer {
     }
 }
 
+impl std::str::FromStr for ArchLayer {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "domain" => Ok(ArchLayer::Domain),
+            "application" => Ok(ArchLayer::Application),
+            "infrastructure" => Ok(ArchLayer::Infrastructure),
+            "presentation" => Ok(ArchLayer::Presentation),
+            _ => Err(anyhow::anyhow!("unknown layer: {s}")),
+        }
+    }
+}
+
 /// Information about a struct/class field
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldInfo {
     pub name: String,
     pub type_name: String,
+    /// Whether the field is accessible from outside its type's module/package.
+    #[serde(default)]
+    pub visibility: Visibility,
 }
 
 /// Information about a method
@@ -95,6 +164,82 @@ pub struct MethodInfo {
     pub name: String,
     pub parameters: String,
     pub return_type: String,
+    /// Declared type of each parameter, where the analyzer extracts them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameter_types: Vec<String>,
+    /// Whether the method is callable from outside its type's module/package.
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// For interface methods, whether it is contract only or carries a body.
+    #[serde(default, skip_serializing_if = "InterfaceMethodKind::is_abstract")]
+    pub interface_kind: InterfaceMethodKind,
+    /// Annotation names on the method, without `@` or package qualifier
+    /// (e.g. `Transactional`), where the analyzer extracts them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<String>,
+}
+
+impl MethodInfo {
+    pub fn is_public(&self) -> bool {
+        self.visibility == Visibility::Public
+    }
+
+    /// Whether an interface method carries behavior (a Java `default` or `static` method).
+    pub fn has_body(&self) -> bool {
+        !self.interface_kind.is_abstract()
+    }
+}
+
+/// How an interface method is declared.
+///
+/// Only the Java analyzer tells them apart; other analyzers report every
+/// interface method as abstract.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InterfaceMethodKind {
+    #[default]
+    Abstract,
+    Default,
+    Static,
+}
+
+impl InterfaceMethodKind {
+    pub fn is_abstract(&self) -> bool {
+        *self == InterfaceMethodKind::Abstract
+    }
+}
+
+/// Visibility of an extracted method or field.
+///
+/// Go exports by capitalization, Rust by `pub`, Java/TypeScript/C++ by access
+/// modifiers. Interface and trait methods are public unless marked otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Private,
+}
+
+/// CRUD/persistence method name patterns.
+/// Used to detect Active Record structs and to tell plain persistence methods
+/// apart from business logic on repositories.
+pub const ACTIVE_RECORD_METHODS: &[&str] = &[
+    "Load", "Save", "Update", "Delete", "Insert", "Create", "FindByID", "FindBy", "Get", "GetAll",
+    "List", "Upsert", "Remove", "Persist", "Fetch",
+];
+
+/// Whether a method name is a CRUD/persistence operation (e.g. `Save`, `FindByEmail`).
+/// Names are compared as spelled, so unexported Go methods such as `save` do not match.
+pub fn is_crud_method_name(name: &str) -> bool {
+    ACTIVE_RECORD_METHODS.iter().any(|ar| name.starts_with(ar))
+}
+
+/// Information about a value object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueObjectInfo {
+    pub name: String,
+    pub fields: Vec<FieldInfo>,
 }
 
 /// Information about a domain event
@@ -110,7 +255,7 @@ pub enum ComponentKind {
     Port(PortInfo),
     Adapter(AdapterInfo),
     Entity(EntityInfo),
-    ValueObject,
+    ValueObject(ValueObjectInfo),
     UseCase,
     Repository,
     Service,
@@ -174,17 +319,25 @@ pub struct Component {
     pub location: SourceLocation,
     #[serde(default)]
     pub is_cross_cutting: bool,
+    /// Declared in a file marked as generated code (`DO NOT EDIT`, `@generated`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_generated: bool,
     #[serde(default)]
     pub architecture_mode: ArchitectureMode,
+    /// Methods declared on the component, where the analyzer extracts them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub methods: Vec<MethodInfo>,
 }
 
 /// Kind of dependency relationship
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DependencyKind {
     Import,
     MethodCall,
     TypeReference,
     Inheritance,
+    /// A type embedded in a struct (Go composition: `type Admin struct { User }`).
+    Embedding,
 }
 
 /// A dependency between components or files
@@ -197,6 +350,40 @@ pub struct Dependency {
     pub import_path: Option<String>,
 }
 
+/// A factory function (`NewX`, `createX`) and the type it returns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactoryInfo {
+    pub name: String,
+    /// Returned type name, without pointer or package qualifier.
+    pub return_type: String,
+    /// Package qualifier of the returned type; empty for a type of the same package.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub return_package: String,
+    pub location: SourceLocation,
+}
+
+/// A field read or written through a selector (`u.Name`) on a variable whose declared
+/// type is known, such as a function parameter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldAccess {
+    pub field: String,
+    /// Declared type of the variable, without pointer or package qualifier.
+    pub receiver_type: String,
+    /// Package qualifier of the receiver type; empty for a type of the same package.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub receiver_package: String,
+    pub location: SourceLocation,
+}
+
+/// Methods declared apart from their type, such as a Rust `impl` block in another
+/// file than the struct. Attached to the type's component once every file is merged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImplMethods {
+    /// The type as named where the methods are declared.
+    pub type_id: ComponentId,
+    pub methods: Vec<MethodInfo>,
+}
+
 /// Structured identifier for a violation rule.
 ///
 /// Format: `{prefix}{number}` (e.g., L001, PA001, D001) or `C-{name}` for custom rules.
@@ -268,7 +455,8 @@ impl std::str::FromStr for Severity {
     }
 }
 
-const DOCS_BASE_URL: &str = "https://rebelopsio.github.io/boundary/features/rules.html";
+/// Default page for violation documentation links, overridable with `[rules].doc_base_url`.
+pub const DOCS_BASE_URL: &str = "https://rebelopsio.github.io/boundary/features/rules.html";
 
 /// Kind of architectural violation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -279,6 +467,35 @@ pub enum ViolationKind {
     },
     CircularDependency {
         cycle: Vec<ComponentId>,
+        /// Layers of the cycle's members, innermost first.
+        #[serde(default)]
+        layers: Vec<ArchLayer>,
+        /// Whether the cycle spans layers, so one of its edges points outward.
+        #[serde(default)]
+        cross_layer: bool,
+    },
+    DisallowedExternalDependency {
+        layer: ArchLayer,
+        import_path: String,
+    },
+    IntraLayerCycle {
+        layer: ArchLayer,
+        packages: Vec<String>,
+    },
+    HighCoupling {
+        layer: ArchLayer,
+        component: String,
+        fan_out: usize,
+    },
+    ExcessiveFanOut {
+        component: String,
+        fan_out: usize,
+        /// Components and packages depended on, sorted.
+        targets: Vec<String>,
+    },
+    ArchitectureDrift {
+        subject: String,
+        detail: String,
     },
     MissingPort {
         adapter_name: String,
@@ -302,9 +519,180 @@ pub enum ViolationKind {
     PortWithoutImplementation {
         port_name: String,
     },
+    FatRepository {
+        repository_name: String,
+        method_count: usize,
+        non_crud_methods: Vec<String>,
+    },
+    DuplicatePort {
+        port_name: String,
+        duplicates: Vec<String>,
+    },
+    ModuleDirectionViolation {
+        module: String,
+        dependent_modules: Vec<String>,
+        inbound_from_domain: usize,
+        outbound_to_domain: usize,
+    },
+    PassThroughService {
+        service_name: String,
+        repository_name: String,
+        method_count: usize,
+    },
+    MixedArchitectureModes {
+        context: String,
+        modes: Vec<ArchitectureMode>,
+    },
+    FatInterface {
+        port: String,
+        method_count: usize,
+    },
+    AdapterMisplaced {
+        adapter: String,
+        layer: ArchLayer,
+    },
+    AdapterToAdapterCoupling {
+        from_adapter: String,
+        to_adapter: String,
+    },
+    PortAdapterCycle {
+        port: String,
+        adapter: String,
+    },
+    BehaviorHeavyPort {
+        port: String,
+        /// The port's `default` and `static` methods.
+        methods: Vec<String>,
+    },
+    FactoryReturnsConcrete {
+        factory: String,
+        concrete_type: String,
+        /// The port the factory should return instead.
+        port: String,
+    },
+    ContextMissingPort {
+        context: String,
+        /// Adapters and repositories of the context, none of them behind a port.
+        adapters: Vec<String>,
+    },
+    LowClassificationCoverage {
+        /// Percentage of components assigned a layer or marked cross-cutting.
+        coverage: f64,
+        minimum: f64,
+        unclassified: usize,
+    },
+    LowInterfaceCoverage {
+        /// The interface coverage sub-score.
+        coverage: f64,
+        minimum: f64,
+        ports: usize,
+        adapters: usize,
+    },
+    LayerSkip {
+        from: ArchLayer,
+        to: ArchLayer,
+        /// Layers between `from` and `to` that the dependency jumps over.
+        skipped: Vec<ArchLayer>,
+    },
+    TestDoubleInProduction {
+        /// Package of the production code depending on the test double.
+        component: String,
+        test_double: String,
+    },
+    CrossAggregateFieldAccess {
+        entity: String,
+        field: String,
+        /// Package of the code reading the field.
+        accessor: String,
+    },
+    AnemicDomainModel {
+        entity_name: String,
+    },
 }
 
 impl ViolationKind {
+    /// The fields that identify what this violation is about, for
+    /// [`Violation::fingerprint`]. Counts are left out since they change as code grows.
+    fn structural_key(&self) -> String {
+        let sorted = |items: &[String]| {
+            let mut items = items.to_vec();
+            items.sort();
+            items.join(",")
+        };
+        match self {
+            ViolationKind::LayerBoundary {
+                from_layer,
+                to_layer,
+            } => format!("{from_layer}->{to_layer}"),
+            ViolationKind::CircularDependency { cycle, .. } => {
+                let ids: Vec<String> = cycle.iter().map(|id| id.0.clone()).collect();
+                sorted(&ids)
+            }
+            ViolationKind::DisallowedExternalDependency { layer, import_path } => {
+                format!("{layer}:{import_path}")
+            }
+            ViolationKind::IntraLayerCycle { layer, packages } => {
+                format!("{layer}:{}", sorted(packages))
+            }
+            ViolationKind::HighCoupling {
+                layer, component, ..
+            } => format!("{layer}:{component}"),
+            ViolationKind::ExcessiveFanOut { component, .. } => component.clone(),
+            ViolationKind::ArchitectureDrift { subject, .. } => subject.clone(),
+            ViolationKind::MissingPort { adapter_name } => adapter_name.clone(),
+            ViolationKind::CustomRule { rule_name } => rule_name.clone(),
+            ViolationKind::DomainInfrastructureLeak { detail } => detail.clone(),
+            ViolationKind::InitFunctionCoupling {
+                init_file,
+                called_package,
+                ..
+            } => format!("{init_file}:{called_package}"),
+            ViolationKind::ConstructorReturnsConcrete {
+                adapter_name,
+                concrete_type,
+            } => format!("{adapter_name}:{concrete_type}"),
+            ViolationKind::PortWithoutImplementation { port_name } => port_name.clone(),
+            ViolationKind::FatRepository {
+                repository_name, ..
+            } => repository_name.clone(),
+            ViolationKind::DuplicatePort { port_name, .. } => port_name.clone(),
+            ViolationKind::ModuleDirectionViolation { module, .. } => module.clone(),
+            ViolationKind::PassThroughService {
+                service_name,
+                repository_name,
+                ..
+            } => format!("{service_name}:{repository_name}"),
+            ViolationKind::MixedArchitectureModes { context, .. } => context.clone(),
+            ViolationKind::FatInterface { port, .. } => port.clone(),
+            ViolationKind::AdapterMisplaced { adapter, .. } => adapter.clone(),
+            ViolationKind::AdapterToAdapterCoupling {
+                from_adapter,
+                to_adapter,
+            } => format!("{from_adapter}->{to_adapter}"),
+            ViolationKind::PortAdapterCycle { port, adapter } => format!("{port}:{adapter}"),
+            ViolationKind::BehaviorHeavyPort { port, .. } => port.clone(),
+            ViolationKind::FactoryReturnsConcrete {
+                factory,
+                concrete_type,
+                ..
+            } => format!("{factory}:{concrete_type}"),
+            ViolationKind::ContextMissingPort { context, .. } => context.clone(),
+            ViolationKind::LowClassificationCoverage { .. } => String::new(),
+            ViolationKind::LowInterfaceCoverage { .. } => String::new(),
+            ViolationKind::LayerSkip { from, to, .. } => format!("{from}->{to}"),
+            ViolationKind::TestDoubleInProduction {
+                component,
+                test_double,
+            } => format!("{component}->{test_double}"),
+            ViolationKind::CrossAggregateFieldAccess {
+                entity,
+                field,
+                accessor,
+            } => format!("{accessor}->{entity}.{field}"),
+            ViolationKind::AnemicDomainModel { entity_name } => entity_name.clone(),
+        }
+    }
+
     /// Returns the structured rule ID for this violation kind.
     pub fn rule_id(&self) -> RuleId {
         match self {
@@ -319,10 +707,33 @@ impl ViolationKind {
             },
             ViolationKind::InitFunctionCoupling { .. } => RuleId::layer(4),
             ViolationKind::DomainInfrastructureLeak { .. } => RuleId::layer(5),
+            ViolationKind::ModuleDirectionViolation { .. } => RuleId::layer(6),
+            ViolationKind::PassThroughService { .. } => RuleId::layer(7),
+            ViolationKind::MixedArchitectureModes { .. } => RuleId::layer(8),
+            ViolationKind::LayerSkip { .. } => RuleId::layer(9),
+            ViolationKind::LowClassificationCoverage { .. } => RuleId::layer(10),
+            ViolationKind::TestDoubleInProduction { .. } => RuleId::layer(11),
+            ViolationKind::AnemicDomainModel { .. } => RuleId::layer(12),
             ViolationKind::CircularDependency { .. } => RuleId::dependency(1),
+            ViolationKind::DisallowedExternalDependency { .. } => RuleId::dependency(2),
+            ViolationKind::IntraLayerCycle { .. } => RuleId::dependency(3),
+            ViolationKind::HighCoupling { .. } => RuleId::dependency(4),
+            ViolationKind::ArchitectureDrift { .. } => RuleId::dependency(5),
+            ViolationKind::ExcessiveFanOut { .. } => RuleId::dependency(6),
+            ViolationKind::CrossAggregateFieldAccess { .. } => RuleId::dependency(7),
             ViolationKind::MissingPort { .. } => RuleId::port_adapter(1),
             ViolationKind::ConstructorReturnsConcrete { .. } => RuleId::port_adapter(3),
             ViolationKind::PortWithoutImplementation { .. } => RuleId::port_adapter(2),
+            ViolationKind::FatRepository { .. } => RuleId::port_adapter(4),
+            ViolationKind::DuplicatePort { .. } => RuleId::port_adapter(5),
+            ViolationKind::FatInterface { .. } => RuleId::port_adapter(6),
+            ViolationKind::AdapterMisplaced { .. } => RuleId::port_adapter(7),
+            ViolationKind::AdapterToAdapterCoupling { .. } => RuleId::port_adapter(8),
+            ViolationKind::PortAdapterCycle { .. } => RuleId::port_adapter(9),
+            ViolationKind::BehaviorHeavyPort { .. } => RuleId::port_adapter(10),
+            ViolationKind::ContextMissingPort { .. } => RuleId::port_adapter(11),
+            ViolationKind::FactoryReturnsConcrete { .. } => RuleId::port_adapter(12),
+            ViolationKind::LowInterfaceCoverage { .. } => RuleId::port_adapter(13),
             ViolationKind::CustomRule { rule_name } => RuleId::custom(rule_name),
         }
     }
@@ -343,21 +754,51 @@ impl ViolationKind {
             },
             ViolationKind::InitFunctionCoupling { .. } => "init-function-coupling",
             ViolationKind::DomainInfrastructureLeak { .. } => "domain-uses-infrastructure-type",
+            ViolationKind::ModuleDirectionViolation { .. } => {
+                "infrastructure-module-points-outward"
+            }
+            ViolationKind::PassThroughService { .. } => "pass-through-service",
+            ViolationKind::MixedArchitectureModes { .. } => "mixed-architecture-modes",
+            ViolationKind::LayerSkip { .. } => "layer-skip",
+            ViolationKind::LowClassificationCoverage { .. } => "low-classification-coverage",
+            ViolationKind::TestDoubleInProduction { .. } => "test-double-in-production",
+            ViolationKind::AnemicDomainModel { .. } => "anemic-domain-model",
             ViolationKind::CircularDependency { .. } => "circular-dependency",
+            ViolationKind::DisallowedExternalDependency { .. } => "disallowed-external-dependency",
+            ViolationKind::IntraLayerCycle { .. } => "intra-layer-cycle",
+            ViolationKind::HighCoupling { .. } => "high-coupling",
+            ViolationKind::ArchitectureDrift { .. } => "architecture-drift",
+            ViolationKind::ExcessiveFanOut { .. } => "excessive-fan-out",
+            ViolationKind::CrossAggregateFieldAccess { .. } => "cross-aggregate-field-access",
             ViolationKind::MissingPort { .. } => "missing-port-interface",
             ViolationKind::ConstructorReturnsConcrete { .. } => "constructor-returns-concrete-type",
             ViolationKind::PortWithoutImplementation { .. } => "port-without-implementation",
+            ViolationKind::FatRepository { .. } => "fat-repository",
+            ViolationKind::DuplicatePort { .. } => "duplicate-port",
+            ViolationKind::FatInterface { .. } => "fat-interface",
+            ViolationKind::AdapterMisplaced { .. } => "adapter-misplaced",
+            ViolationKind::AdapterToAdapterCoupling { .. } => "adapter-to-adapter-coupling",
+            ViolationKind::PortAdapterCycle { .. } => "port-adapter-cycle",
+            ViolationKind::BehaviorHeavyPort { .. } => "behavior-heavy-port",
+            ViolationKind::ContextMissingPort { .. } => "context-missing-port",
+            ViolationKind::FactoryReturnsConcrete { .. } => "factory-returns-concrete-type",
+            ViolationKind::LowInterfaceCoverage { .. } => "low-interface-coverage",
             ViolationKind::CustomRule { rule_name } => rule_name,
         }
     }
 
     /// Returns a documentation URL for this violation kind, or `None` for custom rules.
     pub fn doc_url(&self) -> Option<String> {
+        self.doc_url_with_base(DOCS_BASE_URL)
+    }
+
+    /// Like [`doc_url`](Self::doc_url), anchored into the page at `base` instead.
+    pub fn doc_url_with_base(&self, base: &str) -> Option<String> {
         match self {
             ViolationKind::CustomRule { .. } => None,
             _ => {
                 let id = self.rule_id().to_string().to_lowercase();
-                Some(format!("{DOCS_BASE_URL}#{id}"))
+                Some(format!("{}#{id}", base.trim_end_matches('#')))
             }
         }
     }
@@ -371,6 +812,123 @@ pub struct Violation {
     pub location: SourceLocation,
     pub message: String,
     pub suggestion: Option<String>,
+    /// Machine-applicable edit that resolves the violation, when one can be derived.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Fix>,
+    /// Documentation link resolved against `[rules].doc_base_url` at detection time.
+    /// Reports emit it through [`Violation::help_url`], so it is not serialized here.
+    #[serde(default, skip_serializing)]
+    pub doc_url: Option<String>,
+}
+
+impl Violation {
+    /// Documentation link for this violation: the one resolved at detection time,
+    /// else the default for its kind.
+    pub fn help_url(&self) -> Option<String> {
+        self.doc_url.clone().or_else(|| self.kind.doc_url())
+    }
+
+    /// Stable identifier for matching this violation across runs, shared by every
+    /// exporter. It hashes the rule, the file, the message with numbers masked and the
+    /// kind's identifying fields, but not the line, so edits that only move the
+    /// violation keep its fingerprint. Paths are taken relative to the project
+    /// `root`, so the fingerprint is the same whichever directory boundary was run from.
+    pub fn fingerprint(&self, root: &Path) -> String {
+        let parts = [
+            self.kind.rule_id().to_string(),
+            relative_to_root(&self.location.file.to_string_lossy(), root),
+            normalize_message(&relative_to_root(&self.message, root)),
+            relative_to_root(&self.kind.structural_key(), root),
+        ];
+        crate::cache::compute_hash(&parts.join("\0"))
+    }
+
+    /// The violation's file relative to the project `root`, with `/` separators: the
+    /// path exporters report, whichever directory boundary was run on.
+    pub fn relative_file(&self, root: &Path) -> String {
+        path_relative_to_root(&self.location.file.to_string_lossy(), root)
+    }
+}
+
+/// `text` with the project `root` removed from the paths and component IDs in it, so
+/// `go:app/internal/user::User` reads `go:internal/user::User` for the root `app`.
+/// Only tokens that start with the root (or `./`) change; an import path such as
+/// `github.com/acme/app/internal` is left alone.
+pub fn relative_to_root(text: &str, root: &Path) -> String {
+    let is_delimiter = |c: char| c.is_whitespace() || "'\"`()[]{},;".contains(c);
+    let mut out = String::with_capacity(text.len());
+    for piece in text.split_inclusive(is_delimiter) {
+        let (token, delimiter) = match piece.char_indices().last() {
+            Some((i, c)) if is_delimiter(c) => piece.split_at(i),
+            _ => (piece, ""),
+        };
+        // Component IDs carry a language tag: `go:<path>::<name>`.
+        match token.split_once(':') {
+            Some((tag, path))
+                if !tag.is_empty()
+                    && tag.chars().all(|c| c.is_ascii_alphanumeric())
+                    && !path.starts_with(':') =>
+            {
+                out.push_str(tag);
+                out.push(':');
+                out.push_str(&path_relative_to_root(path, root));
+            }
+            _ => out.push_str(&path_relative_to_root(token, root)),
+        }
+        out.push_str(delimiter);
+    }
+    out
+}
+
+/// `path`, with `/` separators, relative to the project `root` it was found under.
+/// A path outside `root` is returned as is, less a leading `./`.
+pub fn path_relative_to_root(path: &str, root: &Path) -> String {
+    let root = root.to_string_lossy().replace('\\', "/");
+    let root = root.trim_start_matches("./").trim_end_matches('/');
+    let path = path.replace('\\', "/");
+    let path = path.strip_prefix("./").unwrap_or(&path);
+    match root {
+        "" | "." => path,
+        _ => path
+            .strip_prefix(root)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .unwrap_or(path),
+    }
+    .to_string()
+}
+
+/// Collapse whitespace and mask digit runs, so counts and percentages that drift
+/// between runs don't change a fingerprint.
+fn normalize_message(message: &str) -> String {
+    let mut out = String::with_capacity(message.len());
+    let mut in_digits = false;
+    for word in message.split_whitespace() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        for c in word.chars() {
+            if c.is_ascii_digit() {
+                if !in_digits {
+                    out.push('#');
+                }
+                in_digits = true;
+            } else {
+                out.push(c);
+                in_digits = false;
+            }
+        }
+        in_digits = false;
+    }
+    out
+}
+
+/// A concrete text insertion that tooling can apply to resolve a violation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fix {
+    pub description: String,
+    /// Insertion point; `line` and `column` are 1-based like `SourceLocation`.
+    pub location: SourceLocation,
+    pub insert_text: String,
 }
 
 #[cfg(test)]
@@ -430,6 +988,24 @@ mod tests {
         assert_eq!(id.to_string(), "pkg::Name");
     }
 
+    #[test]
+    fn test_component_id_with_language() {
+        let id = ComponentId::with_language("go", "internal/domain", "User");
+        assert_eq!(id.0, "go:internal/domain::User");
+        assert_eq!(id.language(), Some("go"));
+        assert_eq!(id.untagged(), "internal/domain::User");
+        assert_eq!(id.package(), "internal/domain");
+        assert_eq!(id.to_string(), "internal/domain::User");
+
+        let untagged = ComponentId::new("internal/domain", "User");
+        assert_eq!(untagged.language(), None);
+        assert_eq!(untagged.package(), "internal/domain");
+        assert_ne!(
+            id,
+            ComponentId::with_language("typescript", "internal/domain", "User")
+        );
+    }
+
     #[test]
     fn test_rule_id_display() {
         assert_eq!(RuleId::layer(1).to_string(), "L001");
@@ -485,7 +1061,12 @@ mod tests {
             RuleId::layer(5)
         );
         assert_eq!(
-            ViolationKind::CircularDependency { cycle: vec![] }.rule_id(),
+            ViolationKind::CircularDependency {
+                cycle: vec![],
+                layers: vec![],
+                cross_layer: false,
+            }
+            .rule_id(),
             RuleId::dependency(1)
         );
         assert_eq!(
@@ -541,7 +1122,12 @@ mod tests {
             "application-bypasses-ports"
         );
         assert_eq!(
-            ViolationKind::CircularDependency { cycle: vec![] }.name(),
+            ViolationKind::CircularDependency {
+                cycle: vec![],
+                layers: vec![],
+                cross_layer: false,
+            }
+            .name(),
             "circular-dependency"
         );
         assert_eq!(
@@ -659,4 +1245,98 @@ mod tests {
             "L099"
         );
     }
+
+    fn fat_repository(file: &str, line: usize, method_count: usize) -> Violation {
+        Violation {
+            kind: ViolationKind::FatRepository {
+                repository_name: "UserRepository".to_string(),
+                method_count,
+                non_crud_methods: vec!["Promote".to_string()],
+            },
+            severity: Severity::Warning,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line,
+                column: 1,
+            },
+            message: format!("Repository 'UserRepository' has {method_count} methods"),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_and_counts() {
+        let base =
+            fat_repository("internal/domain/user/repo.go", 4, 12).fingerprint(Path::new("."));
+        assert_eq!(base.len(), 64);
+        assert_eq!(
+            base,
+            fat_repository("./internal/domain/user/repo.go", 9, 13).fingerprint(Path::new("."))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_depends_on_file_and_kind() {
+        let base = fat_repository("internal/domain/user/repo.go", 4, 12);
+        let moved = fat_repository("internal/domain/order/repo.go", 4, 12);
+        assert_ne!(
+            base.fingerprint(Path::new(".")),
+            moved.fingerprint(Path::new("."))
+        );
+
+        let mut renamed = base.clone();
+        renamed.kind = ViolationKind::FatRepository {
+            repository_name: "AccountRepository".to_string(),
+            method_count: 12,
+            non_crud_methods: Vec::new(),
+        };
+        assert_ne!(
+            base.fingerprint(Path::new(".")),
+            renamed.fingerprint(Path::new("."))
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_strips_root() {
+        let leak = |root: &str| Violation {
+            kind: ViolationKind::DomainInfrastructureLeak {
+                detail: format!("{root}internal/domain/user::<file>"),
+            },
+            message: format!(
+                "Domain component '{root}internal/domain/user::<file>' depends on infrastructure"
+            ),
+            ..fat_repository(&format!("{root}internal/domain/user/repo.go"), 4, 12)
+        };
+        let local = leak("").fingerprint(Path::new("."));
+        assert_eq!(leak("./").fingerprint(Path::new(".")), local);
+        assert_eq!(leak("./app/").fingerprint(Path::new("./app")), local);
+        assert_eq!(
+            leak("/work/app/").fingerprint(Path::new("/work/app/")),
+            local
+        );
+        assert_ne!(leak("app/").fingerprint(Path::new(".")), local);
+    }
+
+    #[test]
+    fn test_relative_to_root_only_strips_leading_paths() {
+        let root = Path::new("p");
+        assert_eq!(
+            relative_to_root(
+                "component 'go:p/internal/user::User' imports github.com/acme/p/internal (p/x.go)",
+                root
+            ),
+            "component 'go:internal/user::User' imports github.com/acme/p/internal (x.go)"
+        );
+        assert_eq!(
+            relative_to_root("'./internal/user::<file>' uses ./db", Path::new(".")),
+            "'internal/user::<file>' uses db"
+        );
+        assert_eq!(
+            relative_to_root("proj/internal/user::<file>", Path::new("proj")),
+            "internal/user::<file>"
+        );
+        assert_eq!(relative_to_root("import: p/x", root), "import: x");
+    }
 }
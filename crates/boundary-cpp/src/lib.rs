@@ -0,0 +1,564 @@
+use std::path::{Component as PathComponent, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, StreamingIterator};
+
+use boundary_core::analyzer::{grammar_self_test, Extracted, LanguageAnalyzer, ParsedFile};
+use boundary_core::types::*;
+
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "cpp";
+
+/// Known-good source checked by [`CppAnalyzer::new`] against the compiled queries.
+const SELF_TEST_SNIPPET: &str = r#"
+#include "money/amount.h"
+
+class OrderRepository {
+public:
+    virtual ~OrderRepository() = default;
+    virtual void save(const Order& order) = 0;
+};
+
+class Order {
+public:
+    void pay();
+private:
+    int total;
+};
+"#;
+
+/// C/C++ language analyzer using tree-sitter.
+///
+/// Headers act as interfaces: a class whose methods are all pure virtual
+/// (`= 0`) is treated as a port.
+pub struct CppAnalyzer {
+    language: Language,
+    class_query: Query,
+    include_query: Query,
+}
+
+impl CppAnalyzer {
+    pub fn new() -> Result<Self> {
+        let language: Language = tree_sitter_cpp::LANGUAGE.into();
+
+        let class_query = Query::new(
+            &language,
+            r#"
+            [
+              (class_specifier
+                name: (type_identifier) @name
+                body: (field_declaration_list) @body)
+              (struct_specifier
+                name: (type_identifier) @name
+                body: (field_declaration_list) @body)
+            ]
+            "#,
+        )
+        .context("failed to compile class query")?;
+
+        let include_query = Query::new(
+            &language,
+            r#"
+            (preproc_include
+              path: [
+                (string_literal (string_content) @local)
+                (system_lib_string) @system
+              ])
+            "#,
+        )
+        .context("failed to compile include query")?;
+
+        let analyzer = Self {
+            language,
+            class_query,
+            include_query,
+        };
+        grammar_self_test(
+            &analyzer,
+            "C++",
+            "order/order.h",
+            SELF_TEST_SNIPPET,
+            &[
+                Extracted::Interfaces,
+                Extracted::InterfaceMethods,
+                Extracted::Types,
+                Extracted::Imports,
+            ],
+        )?;
+        Ok(analyzer)
+    }
+}
+
+impl LanguageAnalyzer for CppAnalyzer {
+    fn language(&self) -> &'static str {
+        LANGUAGE
+    }
+
+    fn file_extensions(&self) -> &[&str] {
+        &["cpp", "cc", "hpp", "h"]
+    }
+
+    fn parse_file(&self, path: &Path, content: &str) -> Result<ParsedFile> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&self.language)
+            .context("failed to set C++ language")?;
+        let tree = parser
+            .parse(content, None)
+            .context("failed to parse C++ file")?;
+        Ok(ParsedFile {
+            path: path.to_path_buf(),
+            tree,
+            content: content.to_string(),
+        })
+    }
+
+    fn extract_components(&self, parsed: &ParsedFile) -> Vec<Component> {
+        let mut components = Vec::new();
+        let package_path = derive_package_path(&parsed.path);
+
+        let mut cursor = QueryCursor::new();
+        let name_idx = self
+            .class_query
+            .capture_names()
+            .iter()
+            .position(|n| *n == "name");
+        let body_idx = self
+            .class_query
+            .capture_names()
+            .iter()
+            .position(|n| *n == "body");
+
+        let mut matches = cursor.matches(
+            &self.class_query,
+            parsed.tree.root_node(),
+            parsed.content.as_bytes(),
+        );
+
+        while let Some(m) = matches.next() {
+            let mut name_node = None;
+            let mut body_node = None;
+            for capture in m.captures {
+                if Some(capture.index as usize) == name_idx {
+                    name_node = Some(capture.node);
+                } else if Some(capture.index as usize) == body_idx {
+                    body_node = Some(capture.node);
+                }
+            }
+            let (Some(name_node), Some(body)) = (name_node, body_node) else {
+                continue;
+            };
+
+            let name = node_text(name_node, &parsed.content);
+            let members = extract_members(body, &parsed.content);
+            let bases = body
+                .parent()
+                .map(|class| extract_base_classes(class, &parsed.content))
+                .unwrap_or_default();
+
+            let is_port =
+                !members.methods.is_empty() && members.methods.iter().all(|(_, pure)| *pure);
+            let methods: Vec<MethodInfo> = members.methods.into_iter().map(|(m, _)| m).collect();
+
+            let kind = if is_port {
+                ComponentKind::Port(PortInfo {
+                    name: name.clone(),
+                    methods: methods.clone(),
+                })
+            } else {
+                classify_class_kind(&name, &bases, members.fields, methods.clone())
+            };
+
+            components.push(Component {
+                id: ComponentId::with_language(LANGUAGE, &package_path, &name),
+                name,
+                kind,
+                layer: None,
+                location: SourceLocation {
+                    file: parsed.path.clone(),
+                    line: name_node.start_position().row + 1,
+                    column: name_node.start_position().column + 1,
+                },
+                is_cross_cutting: false,
+                is_generated: false,
+                architecture_mode: ArchitectureMode::default(),
+                methods,
+            });
+        }
+
+        components
+    }
+
+    fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
+        let mut deps = Vec::new();
+        let package_path = derive_package_path(&parsed.path);
+        let from_id = ComponentId::with_language(LANGUAGE, &package_path, "<file>");
+        let base_dir = parsed.path.parent().unwrap_or(Path::new(""));
+
+        let mut cursor = QueryCursor::new();
+        let local_idx = self
+            .include_query
+            .capture_names()
+            .iter()
+            .position(|n| *n == "local");
+
+        let mut matches = cursor.matches(
+            &self.include_query,
+            parsed.tree.root_node(),
+            parsed.content.as_bytes(),
+        );
+
+        while let Some(m) = matches.next() {
+            for capture in m.captures {
+                // `<system>` headers are the standard library or third-party SDKs.
+                if Some(capture.index as usize) != local_idx {
+                    continue;
+                }
+                let node = capture.node;
+                let header = node_text(node, &parsed.content);
+                let resolved = normalize_path(&base_dir.join(&header));
+                let import_path = resolved.to_string_lossy().replace('\\', "/");
+
+                deps.push(Dependency {
+                    from: from_id.clone(),
+                    to: ComponentId::with_language(
+                        LANGUAGE,
+                        &derive_package_path(&resolved),
+                        "<file>",
+                    ),
+                    kind: DependencyKind::Import,
+                    location: SourceLocation {
+                        file: parsed.path.clone(),
+                        line: node.start_position().row + 1,
+                        column: node.start_position().column + 1,
+                    },
+                    import_path: Some(import_path),
+                });
+            }
+        }
+
+        deps
+    }
+}
+
+/// Methods (with their pure-virtual flag) and data members of a class body.
+#[derive(Default)]
+struct Members {
+    methods: Vec<(MethodInfo, bool)>,
+    fields: Vec<FieldInfo>,
+}
+
+fn extract_members(body: Node, source: &str) -> Members {
+    let mut members = Members::default();
+    let mut walker = body.walk();
+    // Class members are private until an access specifier says otherwise; struct members public.
+    let mut visibility = match body.parent().map(|p| p.kind()) {
+        Some("class_specifier") => Visibility::Private,
+        _ => Visibility::Public,
+    };
+
+    for child in body.named_children(&mut walker) {
+        let is_definition = match child.kind() {
+            "access_specifier" => {
+                visibility = if node_text(child, source) == "public" {
+                    Visibility::Public
+                } else {
+                    Visibility::Private
+                };
+                continue;
+            }
+            "field_declaration" => false,
+            "function_definition" => true,
+            _ => continue,
+        };
+        let Some(declarator) = child.child_by_field_name("declarator") else {
+            continue;
+        };
+        let type_name = child
+            .child_by_field_name("type")
+            .map(|t| node_text(t, source))
+            .unwrap_or_default();
+
+        match find_function_declarator(declarator) {
+            Some(func) => {
+                let Some(func_name) = func.child_by_field_name("declarator") else {
+                    continue;
+                };
+                // Constructors/destructors and operators are not part of a port's surface.
+                if func_name.kind() != "field_identifier" {
+                    continue;
+                }
+                let pure = !is_definition
+                    && child
+                        .child_by_field_name("default_value")
+                        .is_some_and(|v| node_text(v, source) == "0");
+                let parameters = func
+                    .child_by_field_name("parameters")
+                    .map(|p| node_text(p, source))
+                    .unwrap_or_default();
+                members.methods.push((
+                    MethodInfo {
+                        name: node_text(func_name, source),
+                        parameters,
+                        return_type: type_name,
+                        parameter_types: Vec::new(),
+                        visibility,
+                        interface_kind: InterfaceMethodKind::Abstract,
+                        annotations: Vec::new(),
+                    },
+                    pure,
+                ));
+            }
+            None if !is_definition => {
+                members.fields.push(FieldInfo {
+                    name: declarator_name(declarator, source),
+                    type_name,
+                    visibility,
+                });
+            }
+            None => {}
+        }
+    }
+
+    members
+}
+
+/// Unwrap pointer/reference declarators down to a function declarator, if any.
+fn find_function_declarator(node: Node) -> Option<Node> {
+    match node.kind() {
+        "function_declarator" => Some(node),
+        "pointer_declarator" | "reference_declarator" => {
+            let mut walker = node.walk();
+            let inner = node
+                .named_children(&mut walker)
+                .find(|c| c.kind().ends_with("declarator"));
+            inner.and_then(find_function_declarator)
+        }
+        _ => None,
+    }
+}
+
+/// Name of a data member, stripping pointer/reference/array declarators.
+fn declarator_name(node: Node, source: &str) -> String {
+    if node.kind() == "field_identifier" || node.kind() == "identifier" {
+        return node_text(node, source);
+    }
+    let mut walker = node.walk();
+    let inner = node
+        .named_children(&mut walker)
+        .find(|c| c.kind().ends_with("declarator") || c.kind().ends_with("identifier"));
+    match inner {
+        Some(inner) => declarator_name(inner, source),
+        None => node_text(node, source),
+    }
+}
+
+/// Base classes listed in a class's `base_class_clause`.
+fn extract_base_classes(class: Node, source: &str) -> Vec<String> {
+    let mut walker = class.walk();
+    let Some(clause) = class
+        .named_children(&mut walker)
+        .find(|c| c.kind() == "base_class_clause")
+    else {
+        return Vec::new();
+    };
+    let mut clause_walker = clause.walk();
+    clause
+        .named_children(&mut clause_walker)
+        .filter(|c| c.kind() != "access_specifier")
+        .map(|c| node_text(c, source))
+        .collect()
+}
+
+/// Classify a concrete class by its name suffix and base classes.
+fn classify_class_kind(
+    name: &str,
+    bases: &[String],
+    fields: Vec<FieldInfo>,
+    methods: Vec<MethodInfo>,
+) -> ComponentKind {
+    let lower = name.to_lowercase();
+    if lower.ends_with("repository") || lower.ends_with("repo") {
+        ComponentKind::Repository
+    } else if lower.ends_with("service") || lower.ends_with("svc") {
+        ComponentKind::Service
+    } else if lower.ends_with("usecase") || lower.ends_with("interactor") {
+        ComponentKind::UseCase
+    } else if lower.ends_with("handler")
+        || lower.ends_with("controller")
+        || lower.ends_with("adapter")
+        || !bases.is_empty()
+    {
+        ComponentKind::Adapter(AdapterInfo {
+            name: name.to_string(),
+            implements: bases.to_vec(),
+            confidence: AdapterConfidence::default(),
+            returns_concrete: None,
+        })
+    } else {
+        let is_anemic_domain_model = methods.is_empty();
+        ComponentKind::Entity(EntityInfo {
+            name: name.to_string(),
+            fields,
+            methods,
+            is_active_record: false,
+            is_anemic_domain_model,
+        })
+    }
+}
+
+/// Lexically resolve `.` and `..` segments without touching the filesystem.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for part in path.components() {
+        match part {
+            PathComponent::CurDir => {}
+            PathComponent::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+/// Extract text from a tree-sitter node.
+fn node_text(node: Node, source: &str) -> String {
+    source[node.byte_range()].to_string()
+}
+
+/// Derive a package path from a file path.
+fn derive_package_path(path: &Path) -> String {
+    path.parent()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grammar_self_test_passes() {
+        // `new()` runs the self-test; it must pass against the bundled grammar.
+        CppAnalyzer::new().expect("C++ grammar self-test should pass");
+    }
+
+    #[test]
+    fn test_abstract_base_class_is_port() {
+        let analyzer = CppAnalyzer::new().unwrap();
+        let content = r#"
+#pragma once
+#include "domain/user.h"
+
+class UserRepository {
+public:
+    virtual ~UserRepository() = default;
+    virtual void save(const User& user) = 0;
+    virtual User* findById(int id) const = 0;
+};
+"#;
+        let path = PathBuf::from("src/domain/user_repository.hpp");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let repo = components
+            .iter()
+            .find(|c| c.name == "UserRepository")
+            .expect("should find UserRepository");
+        let ComponentKind::Port(ref info) = repo.kind else {
+            panic!("expected Port, got {:?}", repo.kind);
+        };
+        let names: Vec<&str> = info.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["save", "findById"]);
+        assert_eq!(info.methods[0].parameters, "(const User& user)");
+    }
+
+    #[test]
+    fn test_concrete_class_is_not_port() {
+        let analyzer = CppAnalyzer::new().unwrap();
+        let content = r#"
+class PostgresUserRepository : public UserRepository {
+public:
+    void save(const User& user) override;
+    User* findById(int id) const override { return nullptr; }
+private:
+    Connection* conn_;
+};
+
+struct Money {
+    long cents;
+    Money add(const Money& other) const;
+};
+"#;
+        let path = PathBuf::from("src/infrastructure/postgres_user_repository.hpp");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let repo = components
+            .iter()
+            .find(|c| c.name == "PostgresUserRepository")
+            .unwrap();
+        assert!(matches!(repo.kind, ComponentKind::Repository));
+        assert_eq!(repo.methods.len(), 2);
+
+        let money = components.iter().find(|c| c.name == "Money").unwrap();
+        let ComponentKind::Entity(ref info) = money.kind else {
+            panic!("expected Entity, got {:?}", money.kind);
+        };
+        assert_eq!(info.fields[0].name, "cents");
+        assert_eq!(info.methods[0].name, "add");
+    }
+
+    #[test]
+    fn test_class_with_base_is_adapter() {
+        let analyzer = CppAnalyzer::new().unwrap();
+        let content = r#"
+class SmtpMailer : public Mailer {
+public:
+    void send(const std::string& to) override;
+};
+"#;
+        let path = PathBuf::from("src/infrastructure/smtp_mailer.hpp");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let ComponentKind::Adapter(ref info) = components[0].kind else {
+            panic!("expected Adapter, got {:?}", components[0].kind);
+        };
+        assert_eq!(info.implements, vec!["Mailer".to_string()]);
+    }
+
+    #[test]
+    fn test_local_include_is_dependency() {
+        let analyzer = CppAnalyzer::new().unwrap();
+        let content = r#"
+#include <vector>
+#include <string>
+#include "../domain/user_repository.hpp"
+
+void run();
+"#;
+        let path = PathBuf::from("src/application/register_user.cpp");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let deps = analyzer.extract_dependencies(&parsed);
+
+        assert_eq!(deps.len(), 1, "system headers should be skipped");
+        let dep = &deps[0];
+        assert_eq!(
+            dep.import_path.as_deref(),
+            Some("src/domain/user_repository.hpp")
+        );
+        assert_eq!(
+            dep.from,
+            ComponentId::with_language(LANGUAGE, "src/application", "<file>")
+        );
+        assert_eq!(
+            dep.to,
+            ComponentId::with_language(LANGUAGE, "src/domain", "<file>")
+        );
+        assert_eq!(dep.location.line, 4);
+    }
+}
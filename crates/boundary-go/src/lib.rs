@@ -4,9 +4,29 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use tree_sitter::{Language, Parser, Query, QueryCursor, StreamingIterator};
 
-use boundary_core::analyzer::{LanguageAnalyzer, ParsedFile};
+use boundary_core::analyzer::{grammar_self_test, Extracted, LanguageAnalyzer, ParsedFile};
 use boundary_core::types::*;
 
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "go";
+
+/// Known-good source checked by [`GoAnalyzer::new`] against the compiled queries.
+const SELF_TEST_SNIPPET: &str = r#"package order
+
+import "example.com/shop/money"
+
+type Order struct {
+	ID    string
+	Total money.Amount
+}
+
+func (o *Order) Pay() {}
+
+type OrderRepository interface {
+	Save(o *Order) error
+}
+"#;
+
 /// Extracted constructor signature for a `New*()` function.
 ///
 /// Only `return_type` is consumed during classification. The remaining fields
@@ -23,13 +43,6 @@ struct ConstructorSignature {
     return_type: String,
 }
 
-/// Active Record method name patterns.
-/// If a struct has 2+ methods matching these names, it's treated as Active Record.
-const ACTIVE_RECORD_METHODS: &[&str] = &[
-    "Load", "Save", "Update", "Delete", "Insert", "Create", "FindByID", "FindBy", "Get", "GetAll",
-    "List", "Upsert", "Remove", "Persist", "Fetch",
-];
-
 /// Go language analyzer using tree-sitter.
 pub struct GoAnalyzer {
     language: Language,
@@ -39,6 +52,7 @@ pub struct GoAnalyzer {
     method_query: Query,
     init_query: Query,
     constructor_query: Query,
+    factory_query: Query,
 }
 
 impl GoAnalyzer {
@@ -154,7 +168,18 @@ impl GoAnalyzer {
         )
         .context("failed to compile constructor query")?;
 
-        Ok(Self {
+        // Any declared result; `extract_factories` unwraps pointers and result lists.
+        let factory_query = Query::new(
+            &language,
+            r#"
+            (function_declaration
+              name: (identifier) @factory_name
+              result: (_) @result)
+            "#,
+        )
+        .context("failed to compile factory query")?;
+
+        let analyzer = Self {
             language,
             interface_query,
             struct_query,
@@ -162,13 +187,28 @@ impl GoAnalyzer {
             method_query,
             init_query,
             constructor_query,
-        })
+            factory_query,
+        };
+        grammar_self_test(
+            &analyzer,
+            "Go",
+            "order/order.go",
+            SELF_TEST_SNIPPET,
+            &[
+                Extracted::Interfaces,
+                Extracted::InterfaceMethods,
+                Extracted::Types,
+                Extracted::Methods,
+                Extracted::Imports,
+            ],
+        )?;
+        Ok(analyzer)
     }
 }
 
 impl LanguageAnalyzer for GoAnalyzer {
     fn language(&self) -> &'static str {
-        "go"
+        LANGUAGE
     }
 
     fn file_extensions(&self) -> &[&str] {
@@ -226,7 +266,7 @@ impl LanguageAnalyzer for GoAnalyzer {
     fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
         let mut deps = Vec::new();
         let pkg = derive_package_path(&parsed.path);
-        let from_id = ComponentId::new(&pkg, "<file>");
+        let from_id = ComponentId::with_language(LANGUAGE, &pkg, "<file>");
 
         let mut cursor = QueryCursor::new();
         let path_idx = self
@@ -249,7 +289,7 @@ impl LanguageAnalyzer for GoAnalyzer {
                     let raw = node_text(node, &parsed.content);
                     // Strip quotes from import path
                     let import_path = raw.trim_matches('"').to_string();
-                    let to_id = ComponentId::new(&import_path, "<package>");
+                    let to_id = ComponentId::with_language(LANGUAGE, &import_path, "<package>");
 
                     deps.push(Dependency {
                         from: from_id.clone(),
@@ -270,8 +310,151 @@ impl LanguageAnalyzer for GoAnalyzer {
         let init_deps = extract_init_dependencies(&self.init_query, parsed, &pkg);
         deps.extend(init_deps);
 
+        deps.extend(extract_embedding_dependencies(
+            &self.struct_query,
+            parsed,
+            &pkg,
+        ));
+
         deps
     }
+
+    fn extract_factories(&self, parsed: &ParsedFile) -> Vec<FactoryInfo> {
+        let mut factories = Vec::new();
+        let capture_names = self.factory_query.capture_names();
+        let name_idx = capture_names.iter().position(|n| *n == "factory_name");
+        let result_idx = capture_names.iter().position(|n| *n == "result");
+
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(
+            &self.factory_query,
+            parsed.tree.root_node(),
+            parsed.content.as_bytes(),
+        );
+        while let Some(m) = matches.next() {
+            let mut name = String::new();
+            let mut result = None;
+            for capture in m.captures {
+                let idx = Some(capture.index as usize);
+                if idx == name_idx {
+                    name = node_text(capture.node, &parsed.content);
+                } else if idx == result_idx {
+                    result = Some(capture.node);
+                }
+            }
+
+            if !name.starts_with("New") || name.len() <= 3 {
+                continue;
+            }
+            let Some((return_package, return_type)) =
+                result.and_then(|r| factory_return_type(r, &parsed.content))
+            else {
+                continue;
+            };
+            let pos = m.captures[0].node.start_position();
+            factories.push(FactoryInfo {
+                name,
+                return_type,
+                return_package,
+                location: SourceLocation {
+                    file: parsed.path.clone(),
+                    line: pos.row + 1,
+                    column: pos.column + 1,
+                },
+            });
+        }
+        factories
+    }
+
+    fn extract_field_accesses(&self, parsed: &ParsedFile) -> Vec<FieldAccess> {
+        let mut accesses = Vec::new();
+        let root = parsed.tree.root_node();
+        let mut cursor = root.walk();
+        for decl in root.named_children(&mut cursor) {
+            if !matches!(decl.kind(), "function_declaration" | "method_declaration") {
+                continue;
+            }
+            let Some(body) = decl.child_by_field_name("body") else {
+                continue;
+            };
+            // Variables of a known named type: receiver and parameters, then `var`
+            // declarations as the body is walked.
+            let mut typed = HashMap::new();
+            for field in ["receiver", "parameters"] {
+                if let Some(params) = decl.child_by_field_name(field) {
+                    let mut params_cursor = params.walk();
+                    for param in params.named_children(&mut params_cursor) {
+                        add_typed_names(param, &parsed.content, &mut typed);
+                    }
+                }
+            }
+            walk_for_field_accesses(body, parsed, &mut typed, &mut accesses);
+        }
+        accesses
+    }
+}
+
+/// Record the names declared by a `parameter_declaration` or `var_spec` whose type
+/// is a named type.
+fn add_typed_names(
+    decl: tree_sitter::Node,
+    source: &str,
+    typed: &mut HashMap<String, (String, String)>,
+) {
+    let Some(type_node) = decl.child_by_field_name("type") else {
+        return;
+    };
+    let Some(named) = named_type(type_node, source) else {
+        return;
+    };
+    let mut cursor = decl.walk();
+    for name in decl.children_by_field_name("name", &mut cursor) {
+        typed.insert(node_text(name, source), named.clone());
+    }
+}
+
+/// Recursively collect selector expressions (`u.Name`) on typed variables that are
+/// not method calls.
+fn walk_for_field_accesses(
+    node: tree_sitter::Node,
+    parsed: &ParsedFile,
+    typed: &mut HashMap<String, (String, String)>,
+    accesses: &mut Vec<FieldAccess>,
+) {
+    let source = &parsed.content;
+    match node.kind() {
+        "var_spec" => add_typed_names(node, source, typed),
+        "selector_expression" => {
+            let is_call = node.parent().is_some_and(|p| {
+                p.kind() == "call_expression" && p.child_by_field_name("function") == Some(node)
+            });
+            let operand = node.child_by_field_name("operand");
+            let field = node.child_by_field_name("field");
+            if let (false, Some(operand), Some(field)) = (is_call, operand, field) {
+                if let Some((receiver_package, receiver_type)) = (operand.kind() == "identifier")
+                    .then(|| typed.get(&node_text(operand, source)))
+                    .flatten()
+                {
+                    let pos = field.start_position();
+                    accesses.push(FieldAccess {
+                        field: node_text(field, source),
+                        receiver_type: receiver_type.clone(),
+                        receiver_package: receiver_package.clone(),
+                        location: SourceLocation {
+                            file: parsed.path.clone(),
+                            line: pos.row + 1,
+                            column: pos.column + 1,
+                        },
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        walk_for_field_accesses(child, parsed, typed, accesses);
+    }
 }
 
 fn extract_interfaces(
@@ -307,6 +490,7 @@ fn extract_interfaces(
         // Collect method data from captures
         let mut current_method_name = String::new();
         let mut current_params = String::new();
+        let mut current_param_types = Vec::new();
         let mut current_return = String::new();
 
         for capture in m.captures {
@@ -318,9 +502,13 @@ fn extract_interfaces(
                 // Save previous method if any
                 if !current_method_name.is_empty() {
                     methods.push(MethodInfo {
+                        visibility: go_visibility(&current_method_name),
+                        interface_kind: InterfaceMethodKind::Abstract,
+                        annotations: Vec::new(),
                         name: current_method_name.clone(),
                         parameters: current_params.clone(),
                         return_type: current_return.clone(),
+                        parameter_types: std::mem::take(&mut current_param_types),
                     });
                 }
                 current_method_name = node_text(capture.node, &parsed.content);
@@ -328,6 +516,7 @@ fn extract_interfaces(
                 current_return = String::new();
             } else if Some(capture.index as usize) == params_idx {
                 current_params = node_text(capture.node, &parsed.content);
+                current_param_types = parameter_types(capture.node, &parsed.content);
             } else if Some(capture.index as usize) == return_type_idx {
                 current_return = node_text(capture.node, &parsed.content);
             }
@@ -336,9 +525,13 @@ fn extract_interfaces(
         // Save last method
         if !current_method_name.is_empty() {
             methods.push(MethodInfo {
+                visibility: go_visibility(&current_method_name),
+                interface_kind: InterfaceMethodKind::Abstract,
+                annotations: Vec::new(),
                 name: current_method_name,
                 parameters: current_params,
                 return_type: current_return,
+                parameter_types: current_param_types,
             });
         }
 
@@ -353,7 +546,7 @@ fn extract_interfaces(
         }
 
         components.push(Component {
-            id: ComponentId::new(pkg, &name),
+            id: ComponentId::with_language(LANGUAGE, pkg, &name),
             name: name.clone(),
             kind: ComponentKind::Port(PortInfo { name, methods }),
             layer: None,
@@ -363,7 +556,9 @@ fn extract_interfaces(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
@@ -397,6 +592,7 @@ fn extract_structs(
         let mut fields = Vec::new();
         let mut start_row = 0;
         let mut start_col = 0;
+        let mut type_spec = None;
 
         let mut current_field_name = String::new();
 
@@ -405,12 +601,14 @@ fn extract_structs(
                 name = node_text(capture.node, &parsed.content);
                 start_row = capture.node.start_position().row;
                 start_col = capture.node.start_position().column;
+                type_spec = capture.node.parent();
             } else if Some(capture.index as usize) == field_name_idx {
                 current_field_name = node_text(capture.node, &parsed.content);
             } else if Some(capture.index as usize) == field_type_idx {
                 let type_name = node_text(capture.node, &parsed.content);
                 if !current_field_name.is_empty() {
                     fields.push(FieldInfo {
+                        visibility: go_visibility(&current_field_name),
                         name: current_field_name.clone(),
                         type_name,
                     });
@@ -422,12 +620,20 @@ fn extract_structs(
         if name.is_empty() {
             continue;
         }
+        // An embedded field is named after its type.
+        for embedded in type_spec.map_or_else(Vec::new, |s| embedded_types(s, &parsed.content)) {
+            fields.push(FieldInfo {
+                visibility: go_visibility(&embedded.name),
+                name: embedded.name,
+                type_name: embedded.declared,
+            });
+        }
 
         let kind =
             classify_struct_kind(&name, &fields, &parsed.path.to_string_lossy(), constructors);
 
         components.push(Component {
-            id: ComponentId::new(pkg, &name),
+            id: ComponentId::with_language(LANGUAGE, pkg, &name),
             name: name.clone(),
             kind,
             layer: None,
@@ -437,11 +643,149 @@ fn extract_structs(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
 
+/// A type embedded in a struct: a field declaration without a name
+/// (`User`, `*log.Logger`).
+struct EmbeddedType {
+    /// Package qualifier; empty for a type of the same package.
+    package: String,
+    name: String,
+    /// The declared type, including any `*`.
+    declared: String,
+    position: tree_sitter::Point,
+}
+
+/// Types embedded in the struct declared by `type_spec`. Embedded generic
+/// instantiations are skipped.
+fn embedded_types(type_spec: tree_sitter::Node, source: &str) -> Vec<EmbeddedType> {
+    let Some(struct_type) = type_spec
+        .child_by_field_name("type")
+        .filter(|t| t.kind() == "struct_type")
+    else {
+        return Vec::new();
+    };
+    let mut cursor = struct_type.walk();
+    let lists: Vec<_> = struct_type.named_children(&mut cursor).collect();
+    let mut embedded = Vec::new();
+    for list in lists
+        .into_iter()
+        .filter(|n| n.kind() == "field_declaration_list")
+    {
+        let mut cursor = list.walk();
+        let decls: Vec<_> = list.named_children(&mut cursor).collect();
+        for decl in decls {
+            if decl.kind() != "field_declaration" || decl.child_by_field_name("name").is_some() {
+                continue;
+            }
+            let Some(type_node) = decl.child_by_field_name("type") else {
+                continue;
+            };
+            let Some((package, name)) = named_type(type_node, source) else {
+                continue;
+            };
+            embedded.push(EmbeddedType {
+                package,
+                name,
+                declared: source[decl.start_byte()..type_node.end_byte()].to_string(),
+                position: type_node.start_position(),
+            });
+        }
+    }
+    embedded
+}
+
+/// Embedding dependencies from each struct to the types it embeds. Qualified
+/// types are resolved through the file's imports; unresolved qualifiers are skipped.
+fn extract_embedding_dependencies(
+    struct_query: &Query,
+    parsed: &ParsedFile,
+    pkg: &str,
+) -> Vec<Dependency> {
+    let source = &parsed.content;
+    let name_idx = struct_query
+        .capture_names()
+        .iter()
+        .position(|n| *n == "name")
+        .unwrap_or(0);
+    let imports = import_aliases(parsed.tree.root_node(), source);
+
+    let mut deps = Vec::new();
+    let mut cursor = QueryCursor::new();
+    let mut matches = cursor.matches(struct_query, parsed.tree.root_node(), source.as_bytes());
+    while let Some(m) = matches.next() {
+        let Some(name_node) = m
+            .captures
+            .iter()
+            .find(|c| c.index as usize == name_idx)
+            .map(|c| c.node)
+        else {
+            continue;
+        };
+        let Some(type_spec) = name_node.parent() else {
+            continue;
+        };
+        let from = ComponentId::with_language(LANGUAGE, pkg, &node_text(name_node, source));
+        for embedded in embedded_types(type_spec, source) {
+            let (to_pkg, import_path) = if embedded.package.is_empty() {
+                (pkg.to_string(), None)
+            } else if let Some(path) = imports.get(&embedded.package) {
+                (path.clone(), Some(path.clone()))
+            } else {
+                continue;
+            };
+            deps.push(Dependency {
+                from: from.clone(),
+                to: ComponentId::with_language(LANGUAGE, &to_pkg, &embedded.name),
+                kind: DependencyKind::Embedding,
+                location: SourceLocation {
+                    file: parsed.path.clone(),
+                    line: embedded.position.row + 1,
+                    column: embedded.position.column + 1,
+                },
+                import_path,
+            });
+        }
+    }
+    deps
+}
+
+/// Package names in scope mapped to their import paths: the explicit alias, or
+/// the last path segment.
+fn import_aliases(root: tree_sitter::Node, source: &str) -> HashMap<String, String> {
+    let mut aliases = HashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "import_spec" {
+            let Some(path) = node.child_by_field_name("path") else {
+                continue;
+            };
+            let path = node_text(path, source).trim_matches('"').to_string();
+            let alias = match node.child_by_field_name("name") {
+                Some(name) if name.kind() == "package_identifier" => node_text(name, source),
+                Some(_) => continue,
+                None => path.rsplit('/').next().unwrap_or(&path).to_string(),
+            };
+            aliases.insert(alias, path);
+            continue;
+        }
+        // Imports come before any declaration; no need to descend into them.
+        if matches!(
+            node.kind(),
+            "source_file" | "import_declaration" | "import_spec_list"
+        ) {
+            let mut cursor = node.walk();
+            stack.extend(node.named_children(&mut cursor));
+        }
+    }
+    aliases
+}
+
 /// Extract methods from method declarations and group by receiver type.
 fn extract_methods(query: &Query, parsed: &ParsedFile) -> HashMap<String, Vec<MethodInfo>> {
     let mut methods: HashMap<String, Vec<MethodInfo>> = HashMap::new();
@@ -467,6 +811,7 @@ fn extract_methods(query: &Query, parsed: &ParsedFile) -> HashMap<String, Vec<Me
         let mut receiver = String::new();
         let mut method_name = String::new();
         let mut params = String::new();
+        let mut param_types = Vec::new();
         let mut return_type = String::new();
 
         for capture in m.captures {
@@ -476,6 +821,7 @@ fn extract_methods(query: &Query, parsed: &ParsedFile) -> HashMap<String, Vec<Me
                 method_name = node_text(capture.node, &parsed.content);
             } else if Some(capture.index as usize) == params_idx {
                 params = node_text(capture.node, &parsed.content);
+                param_types = parameter_types(capture.node, &parsed.content);
             } else if Some(capture.index as usize) == return_type_idx {
                 return_type = node_text(capture.node, &parsed.content);
             }
@@ -483,9 +829,13 @@ fn extract_methods(query: &Query, parsed: &ParsedFile) -> HashMap<String, Vec<Me
 
         if !receiver.is_empty() && !method_name.is_empty() {
             methods.entry(receiver).or_default().push(MethodInfo {
+                visibility: go_visibility(&method_name),
+                interface_kind: InterfaceMethodKind::Abstract,
+                annotations: Vec::new(),
                 name: method_name,
                 parameters: params,
                 return_type,
+                parameter_types: param_types,
             });
         }
     }
@@ -493,6 +843,15 @@ fn extract_methods(query: &Query, parsed: &ParsedFile) -> HashMap<String, Vec<Me
     methods
 }
 
+/// Go exports identifiers that start with an upper-case letter.
+fn go_visibility(name: &str) -> Visibility {
+    if name.starts_with(char::is_uppercase) {
+        Visibility::Public
+    } else {
+        Visibility::Private
+    }
+}
+
 /// Associate extracted methods with their receiver struct components.
 ///
 /// After associating methods, entities with no methods are flagged as
@@ -502,6 +861,7 @@ fn extract_methods(query: &Query, parsed: &ParsedFile) -> HashMap<String, Vec<Me
 fn associate_methods(components: &mut [Component], methods: &HashMap<String, Vec<MethodInfo>>) {
     for component in components.iter_mut() {
         if let Some(struct_methods) = methods.get(&component.name) {
+            component.methods = struct_methods.clone();
             match &mut component.kind {
                 ComponentKind::Entity(info) => {
                     info.methods = struct_methods.clone();
@@ -529,11 +889,7 @@ fn associate_methods(components: &mut [Component], methods: &HashMap<String, Vec
 fn is_active_record(methods: &[MethodInfo]) -> bool {
     methods
         .iter()
-        .filter(|m| {
-            ACTIVE_RECORD_METHODS
-                .iter()
-                .any(|ar| m.name == *ar || m.name.starts_with(ar))
-        })
+        .filter(|m| is_crud_method_name(&m.name))
         .count()
         >= 2
 }
@@ -548,6 +904,36 @@ fn to_pascal_case(name: &str) -> String {
     }
 }
 
+/// The `(package, type)` a factory returns: the first result of a result list, with
+/// any pointer removed. The package is empty for a type of the same package.
+fn factory_return_type(result: tree_sitter::Node, source: &str) -> Option<(String, String)> {
+    let mut node = result;
+    if node.kind() == "parameter_list" {
+        let mut cursor = node.walk();
+        let first = node
+            .named_children(&mut cursor)
+            .find(|c| c.kind() == "parameter_declaration")?;
+        node = first.child_by_field_name("type")?;
+    }
+    named_type(node, source)
+}
+
+/// The `(package, type)` of a named type or a pointer to one. The package is empty
+/// for a type of the same package.
+fn named_type(mut node: tree_sitter::Node, source: &str) -> Option<(String, String)> {
+    if node.kind() == "pointer_type" {
+        node = node.named_child(0)?;
+    }
+    match node.kind() {
+        "type_identifier" => Some((String::new(), node_text(node, source))),
+        "qualified_type" => Some((
+            node_text(node.child_by_field_name("package")?, source),
+            node_text(node.child_by_field_name("name")?, source),
+        )),
+        _ => None,
+    }
+}
+
 /// Infer the struct name from a constructor function name.
 ///
 /// `"NewStripePaymentProcessor"` → `"stripePaymentProcessor"`
@@ -777,7 +1163,10 @@ fn classify_struct_kind(
         })
     {
         // Value object heuristic: has fields but no identity field.
-        ComponentKind::ValueObject
+        ComponentKind::ValueObject(ValueObjectInfo {
+            name: name.to_string(),
+            fields: fields.to_vec(),
+        })
     } else {
         // is_anemic_domain_model is set to false here and updated after method
         // association in associate_methods, where method counts are available.
@@ -830,7 +1219,7 @@ fn extract_init_dependencies(query: &Query, parsed: &ParsedFile, pkg: &str) -> V
             continue;
         };
 
-        let from_id = ComponentId::new(pkg, "<init>");
+        let from_id = ComponentId::with_language(LANGUAGE, pkg, "<init>");
 
         // Walk the body tree for call_expression nodes with selector_expression
         let mut tree_cursor = body.walk();
@@ -863,7 +1252,7 @@ fn walk_for_calls(
                 if func_node.kind() == "selector_expression" {
                     if let Some(operand) = func_node.child_by_field_name("operand") {
                         let called_pkg = node_text(operand, source);
-                        let to_id = ComponentId::new(&called_pkg, "<package>");
+                        let to_id = ComponentId::with_language(LANGUAGE, &called_pkg, "<package>");
                         deps.push(Dependency {
                             from: from_id.clone(),
                             to: to_id,
@@ -892,6 +1281,31 @@ fn walk_for_calls(
     }
 }
 
+/// Declared type of each parameter in a `parameter_list`, repeated for grouped
+/// names (`a, b int` yields `int` twice).
+fn parameter_types(params: tree_sitter::Node, source: &str) -> Vec<String> {
+    let mut types = Vec::new();
+    let mut cursor = params.walk();
+    for decl in params.named_children(&mut cursor) {
+        if !matches!(
+            decl.kind(),
+            "parameter_declaration" | "variadic_parameter_declaration"
+        ) {
+            continue;
+        }
+        let Some(type_node) = decl.child_by_field_name("type") else {
+            continue;
+        };
+        let names = decl
+            .children_by_field_name("name", &mut decl.walk())
+            .count()
+            .max(1);
+        let type_name = node_text(type_node, source);
+        types.extend(std::iter::repeat_n(type_name, names));
+    }
+    types
+}
+
 /// Extract text from a tree-sitter node.
 fn node_text(node: tree_sitter::Node, source: &str) -> String {
     source[node.byte_range()].to_string()
@@ -910,6 +1324,30 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_grammar_self_test_passes() {
+        // `new()` runs the self-test; it must pass against the bundled grammar.
+        GoAnalyzer::new().expect("Go grammar self-test should pass");
+    }
+
+    #[test]
+    fn test_grammar_self_test_reports_missing_captures() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let err = grammar_self_test(
+            &analyzer,
+            "Go",
+            "order/order.go",
+            "package order\n\ntype Order struct {\n\tID string\n}\n",
+            &[Extracted::Types, Extracted::Interfaces],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Go grammar produced 0 interfaces for a known-good snippet; \
+             grammar version may be incompatible"
+        );
+    }
+
     #[test]
     fn test_parse_simple_go_file() {
         let analyzer = GoAnalyzer::new().unwrap();
@@ -993,6 +1431,82 @@ func main() {
         assert!(paths.contains(&"github.com/example/app/internal/infrastructure/postgres"));
     }
 
+    #[test]
+    fn test_embedded_structs_are_embedding_dependencies() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let content = r#"
+package user
+
+import (
+    applog "github.com/example/app/internal/infrastructure/log"
+    "github.com/example/app/internal/domain/audit"
+)
+
+type User struct {
+    ID string
+}
+
+type Admin struct {
+    User
+    *applog.Logger
+    audit.Trail
+    Level int
+}
+"#;
+        let path = PathBuf::from("internal/domain/user/admin.go");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+
+        let deps = analyzer.extract_dependencies(&parsed);
+        let embedded: Vec<(String, Option<&str>, usize)> = deps
+            .iter()
+            .filter(|d| d.kind == DependencyKind::Embedding)
+            .map(|d| {
+                assert_eq!(d.from.untagged(), "internal/domain/user::Admin");
+                (
+                    d.to.untagged().to_string(),
+                    d.import_path.as_deref(),
+                    d.location.line,
+                )
+            })
+            .collect();
+        assert_eq!(
+            embedded,
+            [
+                ("internal/domain/user::User".to_string(), None, 14),
+                (
+                    "github.com/example/app/internal/infrastructure/log::Logger".to_string(),
+                    Some("github.com/example/app/internal/infrastructure/log"),
+                    15
+                ),
+                (
+                    "github.com/example/app/internal/domain/audit::Trail".to_string(),
+                    Some("github.com/example/app/internal/domain/audit"),
+                    16
+                ),
+            ]
+        );
+
+        let components = analyzer.extract_components(&parsed);
+        let admin = components.iter().find(|c| c.name == "Admin").unwrap();
+        let ComponentKind::ValueObject(info) = &admin.kind else {
+            panic!("Admin should be a value object, got {:?}", admin.kind);
+        };
+        let fields: Vec<(&str, &str)> = info
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.type_name.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("Level", "int"),
+                ("User", "User"),
+                ("Logger", "*applog.Logger"),
+                ("Trail", "audit.Trail"),
+            ]
+        );
+    }
+
     #[test]
     fn test_domain_event_detection() {
         let analyzer = GoAnalyzer::new().unwrap();
@@ -1122,11 +1636,41 @@ type Money struct {
         let vo = components.iter().find(|c| c.name == "Money");
         assert!(vo.is_some(), "should find Money");
         assert!(
-            matches!(vo.unwrap().kind, ComponentKind::ValueObject),
+            matches!(vo.unwrap().kind, ComponentKind::ValueObject(_)),
             "should be classified as ValueObject (no ID field)"
         );
     }
 
+    #[test]
+    fn test_method_visibility_follows_capitalization() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let content = r#"
+package postgres
+
+type UserRepository struct{}
+
+func (r *UserRepository) Save() error { return nil }
+
+func (r *UserRepository) scanRow() error { return nil }
+"#;
+        let path = PathBuf::from("internal/infrastructure/postgres/user_repository.go");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let repo = components
+            .iter()
+            .find(|c| c.name == "UserRepository")
+            .expect("should find UserRepository");
+        let visibility = |name: &str| {
+            repo.methods
+                .iter()
+                .find(|m| m.name == name)
+                .map(|m| m.visibility)
+        };
+        assert_eq!(visibility("Save"), Some(Visibility::Public));
+        assert_eq!(visibility("scanRow"), Some(Visibility::Private));
+    }
+
     #[test]
     fn test_method_extraction() {
         let analyzer = GoAnalyzer::new().unwrap();
@@ -1168,6 +1712,49 @@ func (u *User) Validate() error {
         }
     }
 
+    #[test]
+    fn test_parameter_types_extracted() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let content = r#"
+package order
+
+type OrderRepository interface {
+    Save(ctx context.Context, tx *sql.Tx, order *Order) error
+    Tag(ids ...string)
+}
+
+type Order struct {
+    ID string
+}
+
+func (o *Order) Move(from, to string) error {
+    return nil
+}
+"#;
+        let path = PathBuf::from("internal/domain/order/order.go");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let Some(ComponentKind::Port(port)) = components
+            .iter()
+            .find(|c| c.name == "OrderRepository")
+            .map(|c| &c.kind)
+        else {
+            panic!("expected OrderRepository port");
+        };
+        let save = port.methods.iter().find(|m| m.name == "Save").unwrap();
+        assert_eq!(
+            save.parameter_types,
+            ["context.Context", "*sql.Tx", "*Order"]
+        );
+        let tag = port.methods.iter().find(|m| m.name == "Tag").unwrap();
+        assert_eq!(tag.parameter_types, ["string"]);
+
+        let order = components.iter().find(|c| c.name == "Order").unwrap();
+        let mv = order.methods.iter().find(|m| m.name == "Move").unwrap();
+        assert_eq!(mv.parameter_types, ["string", "string"]);
+    }
+
     #[test]
     fn test_active_record_detection() {
         let analyzer = GoAnalyzer::new().unwrap();
@@ -1207,6 +1794,44 @@ func (u *User) FindByID(id string) (*User, error) {
         }
     }
 
+    #[test]
+    fn test_unexported_crud_methods_are_not_active_record() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let content = r#"
+package domain
+
+type Session struct {
+    ID string
+}
+
+func (s *Session) save() error {
+    return nil
+}
+
+func (s *Session) delete() error {
+    return nil
+}
+
+func (s *Session) listen() error {
+    return nil
+}
+"#;
+        let path = PathBuf::from("domain/session.go");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let entity = components.iter().find(|c| c.name == "Session");
+        assert!(entity.is_some(), "should find Session");
+        if let ComponentKind::Entity(ref info) = entity.unwrap().kind {
+            assert!(
+                !info.is_active_record,
+                "unexported save/delete/listen should not make Session an active record"
+            );
+        } else {
+            panic!("expected Entity kind");
+        }
+    }
+
     #[test]
     fn test_not_active_record_with_few_crud_methods() {
         let analyzer = GoAnalyzer::new().unwrap();
@@ -1575,6 +2200,76 @@ func NewCycleInfrastructureProvider() domain.InfrastructureProvider {
         );
     }
 
+    #[test]
+    fn test_extract_factories() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let content = r#"
+package wiring
+
+func NewUserRepo(dsn string) *PostgresUserRepository { return nil }
+
+func NewUserStore(dsn string) (*postgres.PostgresUserRepository, error) { return nil, nil }
+
+func NewUserPort() user.UserRepository { return nil }
+
+func New() *Thing { return nil }
+
+func BuildRepo() *PostgresUserRepository { return nil }
+
+func NewNothing() {}
+"#;
+        let path = PathBuf::from("internal/application/wiring/wiring.go");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let factories: Vec<_> = analyzer
+            .extract_factories(&parsed)
+            .into_iter()
+            .map(|f| (f.name, f.return_package, f.return_type))
+            .collect();
+
+        let expected = [
+            ("NewUserRepo", "", "PostgresUserRepository"),
+            ("NewUserStore", "postgres", "PostgresUserRepository"),
+            ("NewUserPort", "user", "UserRepository"),
+        ]
+        .map(|(n, p, t)| (n.to_string(), p.to_string(), t.to_string()));
+        assert_eq!(factories, expected);
+    }
+
+    #[test]
+    fn test_extract_field_accesses() {
+        let analyzer = GoAnalyzer::new().unwrap();
+        let content = r#"
+package greeting
+
+func Greet(u *user.User, n int) string {
+	var o order.Order
+	_ = o.Total
+	_ = n
+	return u.Name + u.DisplayName() + u.Address.City
+}
+
+func (s *Service) Run() {
+	s.repo.Save()
+}
+"#;
+        let path = PathBuf::from("internal/application/greeting/service.go");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let accesses: Vec<_> = analyzer
+            .extract_field_accesses(&parsed)
+            .into_iter()
+            .map(|a| (a.receiver_package, a.receiver_type, a.field))
+            .collect();
+
+        let expected = [
+            ("order", "Order", "Total"),
+            ("user", "User", "Name"),
+            ("user", "User", "Address"),
+            ("", "Service", "repo"),
+        ]
+        .map(|(p, t, f)| (p.to_string(), t.to_string(), f.to_string()));
+        assert_eq!(accesses, expected);
+    }
+
     #[test]
     fn test_constructor_with_multi_return_populates_implements() {
         let analyzer = GoAnalyzer::new().unwrap();
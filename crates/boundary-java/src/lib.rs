@@ -3,16 +3,45 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use tree_sitter::{Language, Parser, Query, QueryCursor, StreamingIterator};
 
-use boundary_core::analyzer::{LanguageAnalyzer, ParsedFile};
+use boundary_core::analyzer::{grammar_self_test, Extracted, LanguageAnalyzer, ParsedFile};
 use boundary_core::types::*;
 
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "java";
+
+/// Known-good source checked by [`JavaAnalyzer::new`] against the compiled queries.
+const SELF_TEST_SNIPPET: &str = r#"
+package com.example.order;
+
+import com.example.money.Amount;
+
+public interface OrderRepository {
+    void save(Order order);
+}
+
+public class Order {
+    private String id;
+    private Amount total;
+
+    public Amount total() {
+        return total;
+    }
+}
+
+public record OrderPlaced(String orderId) {}
+"#;
+
 /// Java language analyzer using tree-sitter.
 pub struct JavaAnalyzer {
     language: Language,
     interface_query: Query,
     class_query: Query,
+    method_query: Query,
+    field_query: Query,
+    record_query: Query,
     import_query: Query,
     annotation_query: Query,
+    layer_annotation_query: Query,
 }
 
 impl JavaAnalyzer {
@@ -44,6 +73,41 @@ impl JavaAnalyzer {
         )
         .context("failed to compile class query")?;
 
+        // Methods declared directly in a class body, with their annotations
+        let method_query = Query::new(
+            &language,
+            r#"
+            (class_declaration
+              name: (identifier) @class_name
+              body: (class_body
+                (method_declaration) @method))
+            "#,
+        )
+        .context("failed to compile method query")?;
+
+        // Fields declared directly in a class body: `private String a, b;`
+        let field_query = Query::new(
+            &language,
+            r#"
+            (class_declaration
+              name: (identifier) @class_name
+              body: (class_body
+                (field_declaration) @field))
+            "#,
+        )
+        .context("failed to compile field query")?;
+
+        // Java 16+ records: `record Money(BigDecimal amount, String currency) {}`
+        let record_query = Query::new(
+            &language,
+            r#"
+            (record_declaration
+              name: (identifier) @name
+              parameters: (formal_parameters) @params)
+            "#,
+        )
+        .context("failed to compile record query")?;
+
         let import_query = Query::new(
             &language,
             r#"
@@ -66,19 +130,59 @@ impl JavaAnalyzer {
         )
         .context("failed to compile annotation query")?;
 
-        Ok(Self {
+        // `@BoundaryLayer("domain")` pins a type's layer
+        let layer_annotation_query = Query::new(
+            &language,
+            r#"
+            [
+              (class_declaration
+                (modifiers
+                  (annotation
+                    name: (identifier) @annotation
+                    arguments: (annotation_argument_list (string_literal) @layer)))
+                name: (identifier) @class_name)
+              (interface_declaration
+                (modifiers
+                  (annotation
+                    name: (identifier) @annotation
+                    arguments: (annotation_argument_list (string_literal) @layer)))
+                name: (identifier) @class_name)
+            ]
+            "#,
+        )
+        .context("failed to compile layer annotation query")?;
+
+        let analyzer = Self {
             language,
             interface_query,
             class_query,
+            method_query,
+            field_query,
+            record_query,
             import_query,
             annotation_query,
-        })
+            layer_annotation_query,
+        };
+        grammar_self_test(
+            &analyzer,
+            "Java",
+            "com/example/order/Order.java",
+            SELF_TEST_SNIPPET,
+            &[
+                Extracted::Interfaces,
+                Extracted::InterfaceMethods,
+                Extracted::Types,
+                Extracted::Methods,
+                Extracted::Imports,
+            ],
+        )?;
+        Ok(analyzer)
     }
 }
 
 impl LanguageAnalyzer for JavaAnalyzer {
     fn language(&self) -> &'static str {
-        "java"
+        LANGUAGE
     }
 
     fn file_extensions(&self) -> &[&str] {
@@ -112,8 +216,13 @@ impl LanguageAnalyzer for JavaAnalyzer {
             &mut components,
         );
 
-        // Extract classes
+        // Extract classes and their methods
         extract_classes(&self.class_query, parsed, &package_path, &mut components);
+        extract_class_methods(&self.method_query, parsed, &mut components);
+        extract_class_fields(&self.field_query, parsed, &mut components);
+
+        // Extract records (value objects and events)
+        extract_records(&self.record_query, parsed, &package_path, &mut components);
 
         // Enrich with annotation info
         enrich_with_annotations(
@@ -122,6 +231,12 @@ impl LanguageAnalyzer for JavaAnalyzer {
             &package_path,
             &mut components,
         );
+        apply_layer_annotations(
+            &self.layer_annotation_query,
+            parsed,
+            &package_path,
+            &mut components,
+        );
 
         components
     }
@@ -129,7 +244,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
     fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
         let mut deps = Vec::new();
         let package_path = derive_package_path(&parsed.path);
-        let from_id = ComponentId::new(&package_path, "<file>");
+        let from_id = ComponentId::with_language(LANGUAGE, &package_path, "<file>");
 
         let mut cursor = QueryCursor::new();
         let path_idx = self
@@ -156,7 +271,7 @@ impl LanguageAnalyzer for JavaAnalyzer {
                         continue;
                     }
 
-                    let to_id = ComponentId::new(&import_path, "<class>");
+                    let to_id = ComponentId::with_language(LANGUAGE, &import_path, "<class>");
 
                     deps.push(Dependency {
                         from: from_id.clone(),
@@ -209,6 +324,14 @@ fn extract_interfaces(
                     name: node_text(capture.node, &parsed.content),
                     parameters: String::new(),
                     return_type: String::new(),
+                    parameter_types: Vec::new(),
+                    visibility: java_visibility(capture.node, &parsed.content),
+                    interface_kind: java_interface_kind(capture.node, &parsed.content),
+                    annotations: capture
+                        .node
+                        .parent()
+                        .map(|m| method_annotations(m, &parsed.content))
+                        .unwrap_or_default(),
                 });
             }
         }
@@ -218,7 +341,7 @@ fn extract_interfaces(
         }
 
         components.push(Component {
-            id: ComponentId::new(package_path, &name),
+            id: ComponentId::with_language(LANGUAGE, package_path, &name),
             name: name.clone(),
             kind: ComponentKind::Port(PortInfo { name, methods }),
             layer: None,
@@ -228,7 +351,9 @@ fn extract_interfaces(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
@@ -275,7 +400,7 @@ fn extract_classes(
         let kind = classify_class_kind(&name, &implements);
 
         components.push(Component {
-            id: ComponentId::new(package_path, &name),
+            id: ComponentId::with_language(LANGUAGE, package_path, &name),
             name: name.clone(),
             kind,
             layer: None,
@@ -285,11 +410,278 @@ fn extract_classes(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
 
+/// Attach the methods declared in each class body to the class component, and to
+/// its entity info for entities.
+fn extract_class_methods(query: &Query, parsed: &ParsedFile, components: &mut [Component]) {
+    let mut cursor = QueryCursor::new();
+    let names = query.capture_names();
+    let idx = |name: &str| names.iter().position(|n| *n == name);
+    let (class_idx, method_idx) = (idx("class_name"), idx("method"));
+
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut class_name = String::new();
+        let mut method = None;
+        for capture in m.captures {
+            if Some(capture.index as usize) == class_idx {
+                class_name = node_text(capture.node, &parsed.content);
+            } else if Some(capture.index as usize) == method_idx {
+                method = Some(capture.node);
+            }
+        }
+        let Some(method) = method else {
+            continue;
+        };
+        let Some(name) = method.child_by_field_name("name") else {
+            continue;
+        };
+        let Some(comp) = components
+            .iter_mut()
+            .find(|c| c.name == class_name && !matches!(c.kind, ComponentKind::Port(_)))
+        else {
+            continue;
+        };
+
+        let parameters = method.child_by_field_name("parameters");
+        let parameter_types = parameters
+            .map(|params| {
+                let mut cursor = params.walk();
+                params
+                    .named_children(&mut cursor)
+                    .filter_map(|p| p.child_by_field_name("type"))
+                    .map(|t| node_text(t, &parsed.content))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let method_info = MethodInfo {
+            name: node_text(name, &parsed.content),
+            parameters: parameters
+                .map(|p| node_text(p, &parsed.content))
+                .unwrap_or_default(),
+            return_type: method
+                .child_by_field_name("type")
+                .map(|t| node_text(t, &parsed.content))
+                .unwrap_or_default(),
+            parameter_types,
+            // Package-private methods are not callable from other packages.
+            visibility: if has_modifier(name, &parsed.content, "public")
+                || has_modifier(name, &parsed.content, "protected")
+            {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            },
+            interface_kind: InterfaceMethodKind::Abstract,
+            annotations: method_annotations(method, &parsed.content),
+        };
+        if let ComponentKind::Entity(info) = &mut comp.kind {
+            info.methods.push(method_info.clone());
+        }
+        comp.methods.push(method_info);
+    }
+}
+
+/// Attach the fields declared in each entity class body to its `EntityInfo`,
+/// one field per declarator.
+fn extract_class_fields(query: &Query, parsed: &ParsedFile, components: &mut [Component]) {
+    let mut cursor = QueryCursor::new();
+    let names = query.capture_names();
+    let idx = |name: &str| names.iter().position(|n| *n == name);
+    let (class_idx, field_idx) = (idx("class_name"), idx("field"));
+
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut class_name = String::new();
+        let mut field = None;
+        for capture in m.captures {
+            if Some(capture.index as usize) == class_idx {
+                class_name = node_text(capture.node, &parsed.content);
+            } else if Some(capture.index as usize) == field_idx {
+                field = Some(capture.node);
+            }
+        }
+        let Some(field) = field else {
+            continue;
+        };
+        let Some(type_node) = field.child_by_field_name("type") else {
+            continue;
+        };
+        let Some(info) = components.iter_mut().find_map(|c| match &mut c.kind {
+            ComponentKind::Entity(info) if c.name == class_name => Some(info),
+            _ => None,
+        }) else {
+            continue;
+        };
+
+        let type_name = node_text(type_node, &parsed.content);
+        // Only `public` fields are public; package-private is the default.
+        let mut cursor = field.walk();
+        let is_public = field
+            .children(&mut cursor)
+            .filter(|c| c.kind() == "modifiers")
+            .any(|m| m.children(&mut m.walk()).any(|c| c.kind() == "public"));
+        let visibility = if is_public {
+            Visibility::Public
+        } else {
+            Visibility::Private
+        };
+        let mut cursor = field.walk();
+        for declarator in field.children_by_field_name("declarator", &mut cursor) {
+            let Some(name) = declarator.child_by_field_name("name") else {
+                continue;
+            };
+            info.fields.push(FieldInfo {
+                name: node_text(name, &parsed.content),
+                type_name: type_name.clone(),
+                visibility,
+            });
+        }
+    }
+}
+
+/// Names of the annotations on a method declaration, without `@` or package
+/// qualifier: `@javax.transaction.Transactional` gives `Transactional`.
+fn method_annotations(method: tree_sitter::Node, source: &str) -> Vec<String> {
+    let mut cursor = method.walk();
+    let Some(modifiers) = method
+        .children(&mut cursor)
+        .find(|c| c.kind() == "modifiers")
+    else {
+        return Vec::new();
+    };
+    let mut cursor = modifiers.walk();
+    let annotations = modifiers
+        .named_children(&mut cursor)
+        .filter(|m| matches!(m.kind(), "marker_annotation" | "annotation"))
+        .filter_map(|m| m.child_by_field_name("name"))
+        .map(|n| {
+            let name = node_text(n, source);
+            name.rsplit('.').next().unwrap_or_default().to_string()
+        })
+        .collect();
+    annotations
+}
+
+fn extract_records(
+    query: &Query,
+    parsed: &ParsedFile,
+    package_path: &str,
+    components: &mut Vec<Component>,
+) {
+    let mut cursor = QueryCursor::new();
+    let names = query.capture_names();
+    let idx = |name: &str| names.iter().position(|n| *n == name);
+    let (name_idx, params_idx) = (idx("name"), idx("params"));
+
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut name_node = None;
+        let mut fields = Vec::new();
+        for capture in m.captures {
+            if Some(capture.index as usize) == name_idx {
+                name_node = Some(capture.node);
+            } else if Some(capture.index as usize) == params_idx {
+                fields = record_fields(capture.node, &parsed.content);
+            }
+        }
+        let Some(name_node) = name_node else {
+            continue;
+        };
+        let name = node_text(name_node, &parsed.content);
+
+        // Records are immutable, so they are value objects unless named as events.
+        let kind = if name.ends_with("Event") {
+            ComponentKind::DomainEvent(EventInfo {
+                name: name.clone(),
+                fields,
+            })
+        } else {
+            ComponentKind::ValueObject(ValueObjectInfo {
+                name: name.clone(),
+                fields,
+            })
+        };
+
+        components.push(Component {
+            id: ComponentId::with_language(LANGUAGE, package_path, &name),
+            name,
+            kind,
+            layer: None,
+            location: SourceLocation {
+                file: parsed.path.clone(),
+                line: name_node.start_position().row + 1,
+                column: name_node.start_position().column + 1,
+            },
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
+        });
+    }
+}
+
+/// The components of a record header, as fields.
+fn record_fields(params: tree_sitter::Node, source: &str) -> Vec<FieldInfo> {
+    let mut cursor = params.walk();
+    params
+        .named_children(&mut cursor)
+        .filter(|p| p.kind() == "formal_parameter")
+        .filter_map(|p| {
+            // Record components are exposed through public accessors.
+            Some(FieldInfo {
+                name: node_text(p.child_by_field_name("name")?, source),
+                type_name: node_text(p.child_by_field_name("type")?, source),
+                visibility: Visibility::Public,
+            })
+        })
+        .collect()
+}
+
+/// Interface methods are implicitly public; only Java 9+ `private` helpers are not.
+fn java_visibility(method_name: tree_sitter::Node, source: &str) -> Visibility {
+    if has_modifier(method_name, source, "private") {
+        Visibility::Private
+    } else {
+        Visibility::Public
+    }
+}
+
+/// Whether an interface method is abstract or carries a body as a `default` or
+/// `static` method.
+fn java_interface_kind(method_name: tree_sitter::Node, source: &str) -> InterfaceMethodKind {
+    if has_modifier(method_name, source, "default") {
+        InterfaceMethodKind::Default
+    } else if has_modifier(method_name, source, "static") {
+        InterfaceMethodKind::Static
+    } else {
+        InterfaceMethodKind::Abstract
+    }
+}
+
+/// Whether the method declaring `method_name` lists `modifier` among its modifiers.
+fn has_modifier(method_name: tree_sitter::Node, source: &str, modifier: &str) -> bool {
+    let Some(method) = method_name.parent() else {
+        return false;
+    };
+    let mut cursor = method.walk();
+    let found = method
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "modifiers")
+        .any(|m| {
+            node_text(m, source)
+                .split_whitespace()
+                .any(|w| w == modifier)
+        });
+    found
+}
+
 /// Enrich class components with annotation-based classification.
 fn enrich_with_annotations(
     query: &Query,
@@ -326,7 +718,7 @@ fn enrich_with_annotations(
             continue;
         }
 
-        let id = ComponentId::new(package_path, &class_name);
+        let id = ComponentId::with_language(LANGUAGE, package_path, &class_name);
         if let Some(comp) = components.iter_mut().find(|c| c.id == id) {
             match annotation.as_str() {
                 "Repository" => {
@@ -349,6 +741,48 @@ fn enrich_with_annotations(
     }
 }
 
+/// Set the layer of types annotated `@BoundaryLayer("<layer>")`.
+fn apply_layer_annotations(
+    query: &Query,
+    parsed: &ParsedFile,
+    package_path: &str,
+    components: &mut [Component],
+) {
+    let mut cursor = QueryCursor::new();
+    let names = query.capture_names();
+    let idx = |name: &str| names.iter().position(|n| *n == name);
+    let (annotation_idx, layer_idx, class_name_idx) =
+        (idx("annotation"), idx("layer"), idx("class_name"));
+
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut annotation = String::new();
+        let mut layer = String::new();
+        let mut class_name = String::new();
+        for capture in m.captures {
+            let text = node_text(capture.node, &parsed.content);
+            if Some(capture.index as usize) == annotation_idx {
+                annotation = text;
+            } else if Some(capture.index as usize) == layer_idx {
+                layer = text.trim_matches('"').to_string();
+            } else if Some(capture.index as usize) == class_name_idx {
+                class_name = text;
+            }
+        }
+        if annotation != "BoundaryLayer" {
+            continue;
+        }
+        let Ok(layer) = layer.parse::<ArchLayer>() else {
+            continue;
+        };
+
+        let id = ComponentId::with_language(LANGUAGE, package_path, &class_name);
+        if let Some(comp) = components.iter_mut().find(|c| c.id == id) {
+            comp.layer = Some(layer);
+        }
+    }
+}
+
 /// Classify a class by its name suffix heuristic and implements clause.
 fn classify_class_kind(name: &str, implements: &[String]) -> ComponentKind {
     let lower = name.to_lowercase();
@@ -400,6 +834,12 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_grammar_self_test_passes() {
+        // `new()` runs the self-test; it must pass against the bundled grammar.
+        JavaAnalyzer::new().expect("Java grammar self-test should pass");
+    }
+
     #[test]
     fn test_parse_java_interface() {
         let analyzer = JavaAnalyzer::new().unwrap();
@@ -425,6 +865,88 @@ public interface UserRepository {
         }
     }
 
+    #[test]
+    fn test_interface_default_and_static_methods() {
+        let analyzer = JavaAnalyzer::new().unwrap();
+        let content = r#"
+package com.example.domain.pricing;
+
+public interface PriceCalculator {
+    long basePrice(String sku);
+
+    default long withTax(String sku) {
+        return basePrice(sku) * 120 / 100;
+    }
+
+    static PriceCalculator flat(long price) {
+        return sku -> price;
+    }
+}
+"#;
+        let path = PathBuf::from("src/main/java/com/example/domain/pricing/PriceCalculator.java");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let Some(ComponentKind::Port(info)) = components
+            .iter()
+            .find(|c| c.name == "PriceCalculator")
+            .map(|c| &c.kind)
+        else {
+            panic!("should find PriceCalculator port");
+        };
+        let kind = |name: &str| {
+            info.methods
+                .iter()
+                .find(|m| m.name == name)
+                .map(|m| m.interface_kind)
+        };
+        assert_eq!(kind("basePrice"), Some(InterfaceMethodKind::Abstract));
+        assert_eq!(kind("withTax"), Some(InterfaceMethodKind::Default));
+        assert_eq!(kind("flat"), Some(InterfaceMethodKind::Static));
+    }
+
+    #[test]
+    fn test_class_method_annotations() {
+        let analyzer = JavaAnalyzer::new().unwrap();
+        let content = r#"
+package com.example.domain.order;
+
+import org.springframework.transaction.annotation.Transactional;
+
+public class OrderService {
+    @Transactional(readOnly = true)
+    public Order find(String id) {
+        return null;
+    }
+
+    @java.lang.Override
+    public String toString() {
+        return "OrderService";
+    }
+
+    void plain() {}
+}
+"#;
+        let path = PathBuf::from("src/main/java/com/example/domain/order/OrderService.java");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let service = components
+            .iter()
+            .find(|c| c.name == "OrderService")
+            .expect("should find OrderService");
+        let annotations = |name: &str| {
+            service
+                .methods
+                .iter()
+                .find(|m| m.name == name)
+                .map(|m| m.annotations.clone())
+        };
+        assert_eq!(annotations("find"), Some(vec!["Transactional".to_string()]));
+        assert_eq!(annotations("toString"), Some(vec!["Override".to_string()]));
+        assert_eq!(annotations("plain"), Some(Vec::new()));
+    }
+
     #[test]
     fn test_parse_java_class_with_implements() {
         let analyzer = JavaAnalyzer::new().unwrap();
@@ -537,6 +1059,30 @@ public class UserController {
         );
     }
 
+    #[test]
+    fn test_boundary_layer_annotation() {
+        let analyzer = JavaAnalyzer::new().unwrap();
+        let content = r#"
+package com.example.common;
+
+@BoundaryLayer("domain")
+public class Money {}
+
+@BoundaryLayer("application")
+public interface Clock {}
+
+public class Helper {}
+"#;
+        let path = PathBuf::from("src/main/java/com/example/common/Money.java");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let layer_of = |name: &str| components.iter().find(|c| c.name == name).unwrap().layer;
+        assert_eq!(layer_of("Money"), Some(ArchLayer::Domain));
+        assert_eq!(layer_of("Clock"), Some(ArchLayer::Application));
+        assert_eq!(layer_of("Helper"), None);
+    }
+
     #[test]
     fn test_entity_class() {
         let analyzer = JavaAnalyzer::new().unwrap();
@@ -555,6 +1101,124 @@ public class User {
 
         let user = components.iter().find(|c| c.name == "User");
         assert!(user.is_some(), "should find User");
-        assert!(matches!(user.unwrap().kind, ComponentKind::Entity(_)));
+        let ComponentKind::Entity(info) = &user.unwrap().kind else {
+            panic!("User should be an entity");
+        };
+        let fields: Vec<(&str, &str)> = info
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.type_name.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            [("id", "String"), ("name", "String"), ("email", "String")]
+        );
+    }
+
+    #[test]
+    fn test_entity_fields_one_per_declarator() {
+        let analyzer = JavaAnalyzer::new().unwrap();
+        let content = r#"
+package com.example.domain.order;
+
+public class Order {
+    private String a, b;
+    private List<LineItem> items = new ArrayList<>();
+
+    public void place() {
+        int local = 0;
+    }
+}
+"#;
+        let path = PathBuf::from("src/main/java/com/example/domain/order/Order.java");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let order = components.iter().find(|c| c.name == "Order").unwrap();
+        let ComponentKind::Entity(info) = &order.kind else {
+            panic!("Order should be an entity");
+        };
+        let fields: Vec<(&str, &str)> = info
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.type_name.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("a", "String"),
+                ("b", "String"),
+                ("items", "List<LineItem>")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entity_methods_recorded_on_entity_info() {
+        let analyzer = JavaAnalyzer::new().unwrap();
+        let content = r#"
+package com.example.domain.order;
+
+public class Order {
+    private String status;
+
+    public void cancel() {
+        this.status = "cancelled";
+    }
+}
+"#;
+        let path = PathBuf::from("src/main/java/com/example/domain/order/Order.java");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let order = components.iter().find(|c| c.name == "Order").unwrap();
+        let ComponentKind::Entity(info) = &order.kind else {
+            panic!("Order should be an entity");
+        };
+        let methods: Vec<&str> = info.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(methods, ["cancel"]);
+        assert_eq!(order.methods.len(), 1);
+    }
+
+    #[test]
+    fn test_record_is_value_object() {
+        let analyzer = JavaAnalyzer::new().unwrap();
+        let content = r#"
+package com.example.domain.billing;
+
+import java.math.BigDecimal;
+
+public record Money(BigDecimal amount, String currency) {}
+
+public record InvoicePaidEvent(String invoiceId, Money total) {}
+"#;
+        let path = PathBuf::from("src/main/java/com/example/domain/billing/Money.java");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let money = components
+            .iter()
+            .find(|c| c.name == "Money")
+            .expect("should find Money");
+        let ComponentKind::ValueObject(info) = &money.kind else {
+            panic!("Money should be a value object, got {:?}", money.kind);
+        };
+        let fields: Vec<(&str, &str)> = info
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.type_name.as_str()))
+            .collect();
+        assert_eq!(fields, [("amount", "BigDecimal"), ("currency", "String")]);
+        assert_eq!(money.location.line, 6);
+
+        let event = components
+            .iter()
+            .find(|c| c.name == "InvoicePaidEvent")
+            .expect("should find InvoicePaidEvent");
+        assert!(
+            matches!(&event.kind, ComponentKind::DomainEvent(e) if e.fields.len() == 2),
+            "got {:?}",
+            event.kind
+        );
     }
 }
@@ -48,6 +48,11 @@ pub fn create_analyzers(
                     boundary_java::JavaAnalyzer::new().context("failed to init Java analyzer")?,
                 ));
             }
+            "cpp" | "c++" | "c" => {
+                analyzers.push(Box::new(
+                    boundary_cpp::CppAnalyzer::new().context("failed to init C/C++ analyzer")?,
+                ));
+            }
             _ => {}
         }
     }
@@ -64,6 +69,7 @@ fn auto_detect_languages(project_path: &Path) -> Vec<String> {
     let mut has_rust = false;
     let mut has_ts = false;
     let mut has_java = false;
+    let mut has_cpp = false;
 
     for entry in WalkDir::new(project_path)
         .into_iter()
@@ -76,6 +82,7 @@ fn auto_detect_languages(project_path: &Path) -> Vec<String> {
                 Some("rs") => has_rust = true,
                 Some("ts" | "tsx") => has_ts = true,
                 Some("java") => has_java = true,
+                Some("cpp" | "cc" | "hpp" | "h") => has_cpp = true,
                 _ => {}
             }
         }
@@ -94,6 +101,9 @@ fn auto_detect_languages(project_path: &Path) -> Vec<String> {
     if has_java {
         languages.push("java".to_string());
     }
+    if has_cpp {
+        languages.push("cpp".to_string());
+    }
     if languages.is_empty() {
         languages.push("go".to_string());
     }
@@ -31,9 +31,10 @@ impl BoundaryLanguageServer {
 
     async fn initialize_pipeline(&self, root: PathBuf) {
         let config = Config::load_or_default(&root);
-        match create_analyzers(&root, &config) {
-            Ok(analyzers) => {
-                let pipeline = AnalysisPipeline::new(analyzers, config);
+        match create_analyzers(&root, &config)
+            .and_then(|analyzers| AnalysisPipeline::new(analyzers, config))
+        {
+            Ok(pipeline) => {
                 *self.pipeline.lock().await = Some(pipeline);
                 *self.project_root.lock().await = Some(root);
                 self.client
@@ -44,7 +45,7 @@ impl BoundaryLanguageServer {
                 self.client
                     .log_message(
                         MessageType::ERROR,
-                        format!("Boundary LSP: failed to initialize analyzers: {e}"),
+                        format!("Boundary LSP: failed to initialize pipeline: {e:#}"),
                     )
                     .await;
             }
@@ -100,6 +101,18 @@ impl BoundaryLanguageServer {
                     to_layer,
                 } => format!("layer-boundary: {from_layer} -> {to_layer}"),
                 ViolationKind::CircularDependency { .. } => "circular-dependency".to_string(),
+                ViolationKind::DisallowedExternalDependency { import_path, .. } => {
+                    format!("disallowed-external-dependency: {import_path}")
+                }
+                ViolationKind::IntraLayerCycle { layer, .. } => {
+                    format!("intra-layer-cycle: {layer}")
+                }
+                ViolationKind::HighCoupling { component, .. } => {
+                    format!("high-coupling: {component}")
+                }
+                ViolationKind::ArchitectureDrift { subject, .. } => {
+                    format!("architecture-drift: {subject}")
+                }
                 ViolationKind::MissingPort { adapter_name } => {
                     format!("missing-port: {adapter_name}")
                 }
@@ -123,6 +136,65 @@ impl BoundaryLanguageServer {
                 ViolationKind::PortWithoutImplementation { port_name } => {
                     format!("port-without-impl: {port_name}")
                 }
+                ViolationKind::FatRepository {
+                    repository_name, ..
+                } => {
+                    format!("fat-repository: {repository_name}")
+                }
+                ViolationKind::DuplicatePort { port_name, .. } => {
+                    format!("duplicate-port: {port_name}")
+                }
+                ViolationKind::FatInterface { port, .. } => {
+                    format!("fat-interface: {port}")
+                }
+                ViolationKind::BehaviorHeavyPort { port, .. } => {
+                    format!("behavior-heavy-port: {port}")
+                }
+                ViolationKind::ContextMissingPort { context, .. } => {
+                    format!("context-missing-port: {context}")
+                }
+                ViolationKind::ExcessiveFanOut { component, .. } => {
+                    format!("excessive-fan-out: {component}")
+                }
+                ViolationKind::FactoryReturnsConcrete { factory, .. } => {
+                    format!("factory-returns-concrete-type: {factory}")
+                }
+                ViolationKind::AdapterMisplaced { adapter, .. } => {
+                    format!("adapter-misplaced: {adapter}")
+                }
+                ViolationKind::AdapterToAdapterCoupling { from_adapter, .. } => {
+                    format!("adapter-coupling: {from_adapter}")
+                }
+                ViolationKind::PortAdapterCycle { port, .. } => {
+                    format!("port-adapter-cycle: {port}")
+                }
+                ViolationKind::ModuleDirectionViolation { module, .. } => {
+                    format!("module-direction: {module}")
+                }
+                ViolationKind::PassThroughService { service_name, .. } => {
+                    format!("pass-through-service: {service_name}")
+                }
+                ViolationKind::MixedArchitectureModes { context, .. } => {
+                    format!("mixed-architecture-modes: {context}")
+                }
+                ViolationKind::LayerSkip { from, to, .. } => {
+                    format!("layer-skip: {from} -> {to}")
+                }
+                ViolationKind::LowClassificationCoverage { coverage, .. } => {
+                    format!("low-classification-coverage: {coverage:.1}%")
+                }
+                ViolationKind::LowInterfaceCoverage { coverage, .. } => {
+                    format!("low-interface-coverage: {coverage:.1}%")
+                }
+                ViolationKind::TestDoubleInProduction { test_double, .. } => {
+                    format!("test-double-in-production: {test_double}")
+                }
+                ViolationKind::CrossAggregateFieldAccess { entity, field, .. } => {
+                    format!("cross-aggregate-field-access: {entity}.{field}")
+                }
+                ViolationKind::AnemicDomainModel { entity_name } => {
+                    format!("anemic-domain-model: {entity_name}")
+                }
             };
 
             let diagnostic = Diagnostic {
@@ -242,15 +314,13 @@ impl LanguageServer for BoundaryLanguageServer {
                 let rel_str = rel_path.to_string_lossy();
 
                 // Check if this node's ID contains the file's directory
-                if node.id.0.contains(&*rel_str)
-                    || rel_str.contains(node.id.0.split("::").next().unwrap_or_default())
-                {
+                if node.id.0.contains(&*rel_str) || rel_str.contains(node.id.package()) {
                     let layer_info = match node.layer {
                         Some(layer) => format!("**Layer:** {layer}"),
                         None => "**Layer:** unclassified".to_string(),
                     };
 
-                    let content = format!("**{}** ({})\n\n{}", node.name, node.id.0, layer_info);
+                    let content = format!("**{}** ({})\n\n{}", node.name, node.id, layer_info);
 
                     return Ok(Some(Hover {
                         contents: HoverContents::Markup(MarkupContent {
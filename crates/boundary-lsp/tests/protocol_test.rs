@@ -30,14 +30,9 @@ impl LspReader {
         let (tx, rx) = mpsc::channel();
         std::thread::spawn(move || {
             let mut reader = BufReader::new(stdout);
-            loop {
-                match Self::read_one(&mut reader) {
-                    Some(msg) => {
-                        if tx.send(msg).is_err() {
-                            break;
-                        }
-                    }
-                    None => break,
+            while let Some(msg) = Self::read_one(&mut reader) {
+                if tx.send(msg).is_err() {
+                    break;
                 }
             }
         });
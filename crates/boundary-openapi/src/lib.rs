@@ -0,0 +1,458 @@
+use std::path::{Component as PathComponent, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde_yaml::Value;
+use tree_sitter::{Language, Parser};
+
+use boundary_core::analyzer::{LanguageAnalyzer, ParsedFile};
+use boundary_core::types::*;
+
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "openapi";
+
+const HTTP_METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// OpenAPI (and Swagger 2) contract analyzer.
+///
+/// Unlike the other analyzers this one does not use tree-sitter: documents are read
+/// with `serde_yaml`, which also accepts JSON. Each path operation becomes a
+/// presentation-layer adapter, each schema a presentation-layer value object, and
+/// every `$ref` to a schema a dependency. YAML and JSON files that are not OpenAPI
+/// documents yield nothing.
+pub struct OpenApiAnalyzer {
+    placeholder: Language,
+}
+
+impl OpenApiAnalyzer {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            placeholder: tree_sitter_json::LANGUAGE.into(),
+        })
+    }
+}
+
+impl LanguageAnalyzer for OpenApiAnalyzer {
+    fn language(&self) -> &'static str {
+        LANGUAGE
+    }
+
+    fn file_extensions(&self) -> &[&str] {
+        &["yaml", "yml", "json"]
+    }
+
+    fn parse_file(&self, path: &Path, content: &str) -> Result<ParsedFile> {
+        // The document is read in the extract methods; `ParsedFile` only needs a tree,
+        // so it gets an empty one.
+        let mut parser = Parser::new();
+        parser
+            .set_language(&self.placeholder)
+            .context("failed to set placeholder language")?;
+        let tree = parser
+            .parse("", None)
+            .context("failed to create placeholder tree")?;
+        Ok(ParsedFile {
+            path: path.to_path_buf(),
+            tree,
+            content: content.to_string(),
+        })
+    }
+
+    fn extract_components(&self, parsed: &ParsedFile) -> Vec<Component> {
+        let Some(spec) = load_spec(&parsed.content) else {
+            return Vec::new();
+        };
+        let package_path = derive_package_path(&parsed.path);
+        let mut components = Vec::new();
+
+        for op in operations(&spec, &parsed.content) {
+            components.push(Component {
+                id: ComponentId::with_language(LANGUAGE, &package_path, &op.name),
+                name: op.name.clone(),
+                kind: ComponentKind::Adapter(AdapterInfo {
+                    name: op.name,
+                    implements: Vec::new(),
+                    confidence: AdapterConfidence::High,
+                    returns_concrete: None,
+                }),
+                layer: Some(ArchLayer::Presentation),
+                location: location(&parsed.path, op.line),
+                is_cross_cutting: false,
+                is_generated: false,
+                architecture_mode: ArchitectureMode::default(),
+                methods: Vec::new(),
+            });
+        }
+
+        for schema in schemas(&spec, &parsed.content) {
+            components.push(Component {
+                id: ComponentId::with_language(LANGUAGE, &package_path, &schema.name),
+                name: schema.name.clone(),
+                kind: ComponentKind::ValueObject(ValueObjectInfo {
+                    name: schema.name,
+                    fields: Vec::new(),
+                }),
+                layer: Some(ArchLayer::Presentation),
+                location: location(&parsed.path, schema.line),
+                is_cross_cutting: false,
+                is_generated: false,
+                architecture_mode: ArchitectureMode::default(),
+                methods: Vec::new(),
+            });
+        }
+
+        components
+    }
+
+    fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
+        let Some(spec) = load_spec(&parsed.content) else {
+            return Vec::new();
+        };
+        let package_path = derive_package_path(&parsed.path);
+        let mut deps = Vec::new();
+
+        let sources = operations(&spec, &parsed.content)
+            .into_iter()
+            .chain(schemas(&spec, &parsed.content));
+        for source in sources {
+            let from = ComponentId::with_language(LANGUAGE, &package_path, &source.name);
+            let mut refs = Vec::new();
+            collect_refs(source.node, &mut refs);
+            for reference in refs {
+                let Some((file, schema)) = split_schema_ref(reference) else {
+                    continue;
+                };
+                let (target_package, import_path) = if file.is_empty() {
+                    (package_path.clone(), None)
+                } else {
+                    let target = normalize(&parsed.path.with_file_name(file));
+                    (derive_package_path(&target), Some(file.to_string()))
+                };
+                deps.push(Dependency {
+                    from: from.clone(),
+                    to: ComponentId::with_language(LANGUAGE, &target_package, schema),
+                    kind: DependencyKind::TypeReference,
+                    location: location(
+                        &parsed.path,
+                        line_containing(&parsed.content, reference, source.line),
+                    ),
+                    import_path,
+                });
+            }
+        }
+
+        deps
+    }
+}
+
+/// A path operation or schema found in a document, with the YAML node it came from.
+struct SpecItem<'a> {
+    name: String,
+    line: usize,
+    node: &'a Value,
+}
+
+/// Parse `content` if it is an OpenAPI 3 or Swagger 2 document.
+fn load_spec(content: &str) -> Option<Value> {
+    let spec: Value = serde_yaml::from_str(content).ok()?;
+    (spec.get("openapi").is_some() || spec.get("swagger").is_some()).then_some(spec)
+}
+
+/// Operations under `paths`, named by `operationId` or by method and path
+/// (e.g. `GET /users/{id}`).
+fn operations<'a>(spec: &'a Value, content: &str) -> Vec<SpecItem<'a>> {
+    let Some(paths) = spec.get("paths").and_then(Value::as_mapping) else {
+        return Vec::new();
+    };
+    let mut ops = Vec::new();
+    for (path, item) in paths {
+        let Some(path) = path.as_str() else {
+            continue;
+        };
+        let path_line = key_line(content, path, 1);
+        for method in HTTP_METHODS {
+            let Some(op) = item.get(method).filter(|op| op.is_mapping()) else {
+                continue;
+            };
+            let name = op
+                .get("operationId")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{} {path}", method.to_uppercase()));
+            ops.push(SpecItem {
+                name,
+                line: key_line(content, method, path_line),
+                node: op,
+            });
+        }
+    }
+    ops
+}
+
+/// Schemas under `components.schemas` (OpenAPI 3) or `definitions` (Swagger 2).
+fn schemas<'a>(spec: &'a Value, content: &str) -> Vec<SpecItem<'a>> {
+    let (section, mapping) = match spec
+        .get("components")
+        .and_then(|c| c.get("schemas"))
+        .and_then(Value::as_mapping)
+    {
+        Some(mapping) => ("schemas", mapping),
+        None => match spec.get("definitions").and_then(Value::as_mapping) {
+            Some(mapping) => ("definitions", mapping),
+            None => return Vec::new(),
+        },
+    };
+    let section_line = key_line(content, section, 1);
+    mapping
+        .iter()
+        .filter_map(|(name, node)| {
+            let name = name.as_str()?;
+            Some(SpecItem {
+                name: name.to_string(),
+                line: key_line(content, name, section_line),
+                node,
+            })
+        })
+        .collect()
+}
+
+fn collect_refs<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::Mapping(map) => {
+            for (key, child) in map {
+                match (key.as_str(), child.as_str()) {
+                    (Some("$ref"), Some(reference)) => out.push(reference),
+                    _ => collect_refs(child, out),
+                }
+            }
+        }
+        Value::Sequence(items) => {
+            for item in items {
+                collect_refs(item, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Split a schema reference into its file part (empty for local references) and
+/// schema name: `common.yaml#/components/schemas/Error` → `("common.yaml", "Error")`.
+/// References to anything other than a schema return `None`.
+fn split_schema_ref(reference: &str) -> Option<(&str, &str)> {
+    let (file, pointer) = reference.split_once('#')?;
+    let name = pointer
+        .strip_prefix("/components/schemas/")
+        .or_else(|| pointer.strip_prefix("/definitions/"))?;
+    (!name.is_empty() && !name.contains('/')).then_some((file, name))
+}
+
+/// Line (1-based) of the first mapping key `key` at or after line `from`, in either
+/// YAML or JSON syntax. Falls back to `from` when the key is not found.
+fn key_line(content: &str, key: &str, from: usize) -> usize {
+    let candidates = [
+        format!("{key}:"),
+        format!("\"{key}\":"),
+        format!("'{key}':"),
+        format!("\"{key}\" :"),
+    ];
+    content
+        .lines()
+        .enumerate()
+        .skip(from.saturating_sub(1))
+        .find(|(_, line)| {
+            let trimmed = line.trim_start();
+            candidates.iter().any(|c| trimmed.starts_with(c.as_str()))
+        })
+        .map_or(from, |(i, _)| i + 1)
+}
+
+fn line_containing(content: &str, needle: &str, from: usize) -> usize {
+    content
+        .lines()
+        .enumerate()
+        .skip(from.saturating_sub(1))
+        .find(|(_, line)| line.contains(needle))
+        .map_or(from, |(i, _)| i + 1)
+}
+
+fn location(path: &Path, line: usize) -> SourceLocation {
+    SourceLocation {
+        file: path.to_path_buf(),
+        line,
+        column: 1,
+    }
+}
+
+/// Resolve `.` and `..` segments so references to sibling files map to the same
+/// package path as the files themselves.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for part in path.components() {
+        match part {
+            PathComponent::CurDir => {}
+            PathComponent::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn derive_package_path(path: &Path) -> String {
+    path.parent()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"openapi: 3.0.3
+info:
+  title: Users
+  version: "1.0"
+paths:
+  /users:
+    get:
+      operationId: listUsers
+      responses:
+        200:
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: "#/components/schemas/User"
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/NewUser"
+      responses:
+        "201":
+          description: created
+        default:
+          $ref: "#/components/responses/Error"
+  /users/{id}:
+    parameters:
+      - name: id
+        in: path
+    delete:
+      responses:
+        "204":
+          description: deleted
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        address:
+          $ref: "./common.yaml#/components/schemas/Address"
+    NewUser:
+      type: object
+"##;
+
+    fn parse(path: &str, content: &str) -> (OpenApiAnalyzer, ParsedFile) {
+        let analyzer = OpenApiAnalyzer::new().unwrap();
+        let parsed = analyzer.parse_file(&PathBuf::from(path), content).unwrap();
+        (analyzer, parsed)
+    }
+
+    #[test]
+    fn test_extracts_operations_as_presentation_components() {
+        let (analyzer, parsed) = parse("api/openapi.yaml", SPEC);
+        let components = analyzer.extract_components(&parsed);
+
+        let ops: Vec<(&str, usize)> = components
+            .iter()
+            .filter(|c| matches!(c.kind, ComponentKind::Adapter(_)))
+            .map(|c| (c.name.as_str(), c.location.line))
+            .collect();
+        assert_eq!(
+            ops,
+            [
+                ("listUsers", 7),
+                ("POST /users", 17),
+                ("DELETE /users/{id}", 32)
+            ]
+        );
+        assert!(components
+            .iter()
+            .all(|c| c.layer == Some(ArchLayer::Presentation)));
+        assert_eq!(
+            components[0].id,
+            ComponentId::with_language("openapi", "api", "listUsers")
+        );
+
+        let schemas: Vec<&str> = components
+            .iter()
+            .filter(|c| matches!(c.kind, ComponentKind::ValueObject(_)))
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(schemas, ["User", "NewUser"]);
+    }
+
+    #[test]
+    fn test_schema_refs_become_dependencies() {
+        let (analyzer, parsed) = parse("api/v1/openapi.yaml", SPEC);
+        let deps = analyzer.extract_dependencies(&parsed);
+
+        let edges: Vec<(String, String)> = deps
+            .iter()
+            .map(|d| (d.from.to_string(), d.to.to_string()))
+            .collect();
+        assert_eq!(
+            edges,
+            [
+                ("api/v1::listUsers".to_string(), "api/v1::User".to_string()),
+                (
+                    "api/v1::POST /users".to_string(),
+                    "api/v1::NewUser".to_string()
+                ),
+                ("api/v1::User".to_string(), "api/v1::Address".to_string()),
+            ],
+            "response refs are not schemas and are skipped"
+        );
+        assert_eq!(deps[0].location.line, 16);
+        assert_eq!(deps[2].import_path.as_deref(), Some("./common.yaml"));
+    }
+
+    #[test]
+    fn test_reads_json_swagger_documents() {
+        let content = r##"{
+  "swagger": "2.0",
+  "paths": {
+    "/orders": {
+      "get": {
+        "operationId": "listOrders",
+        "responses": { "200": { "schema": { "$ref": "#/definitions/Order" } } }
+      }
+    }
+  },
+  "definitions": { "Order": { "type": "object" } }
+}"##;
+        let (analyzer, parsed) = parse("api/swagger.json", content);
+        let components = analyzer.extract_components(&parsed);
+        let names: Vec<&str> = components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["listOrders", "Order"]);
+        assert_eq!(components[0].location.line, 5);
+
+        let deps = analyzer.extract_dependencies(&parsed);
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].to.to_string(), "api::Order");
+    }
+
+    #[test]
+    fn test_ignores_other_yaml_and_json() {
+        let (analyzer, parsed) = parse("package.json", r#"{ "name": "web", "version": "1.0.0" }"#);
+        assert!(analyzer.extract_components(&parsed).is_empty());
+
+        let (analyzer, parsed) = parse(".github/ci.yml", "on: [push]\njobs: {}\n");
+        assert!(analyzer.extract_components(&parsed).is_empty());
+        assert!(analyzer.extract_dependencies(&parsed).is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+use serde::Serialize;
+
+use boundary_core::metrics::ArchitectureScore;
+
+/// Label on the left half of the badge.
+const LABEL: &str = "architecture";
+
+/// Approximate width of a character in the 11px Verdana the badge uses.
+const CHAR_WIDTH: usize = 7;
+
+/// Horizontal padding on each side of a badge half.
+const PADDING: usize = 6;
+
+/// Letter grade for an overall score. The grade boundaries fall on the text report's
+/// color thresholds, so A and B are green, C and D yellow and F red.
+pub fn grade(overall: f64) -> char {
+    match overall {
+        s if s >= 90.0 => 'A',
+        s if s >= 80.0 => 'B',
+        s if s >= 65.0 => 'C',
+        s if s >= 50.0 => 'D',
+        _ => 'F',
+    }
+}
+
+/// Badge color for a score: green from 80, yellow from 50, red below, as in the text
+/// report. A result without a score (no layers detected) is gray.
+fn color(score: Option<&ArchitectureScore>) -> (&'static str, &'static str) {
+    match score.map(|s| s.overall) {
+        Some(s) if s >= 80.0 => ("green", "#4c1"),
+        Some(s) if s >= 50.0 => ("yellow", "#dfb317"),
+        Some(_) => ("red", "#e05d44"),
+        None => ("lightgrey", "#9f9f9f"),
+    }
+}
+
+/// Right-hand text of the badge, e.g. `85% (B)`.
+fn message(score: Option<&ArchitectureScore>) -> String {
+    match score {
+        Some(s) => format!("{}% ({})", s.overall.round() as i64, grade(s.overall)),
+        None => "n/a".to_string(),
+    }
+}
+
+/// A shields.io endpoint payload (<https://shields.io/badges/endpoint-badge>).
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ShieldsEndpoint {
+    schema_version: u32,
+    label: &'static str,
+    message: String,
+    color: &'static str,
+}
+
+/// Format the score as a shields.io endpoint JSON payload.
+pub fn format_shields_endpoint(score: Option<&ArchitectureScore>, compact: bool) -> String {
+    let payload = ShieldsEndpoint {
+        schema_version: 1,
+        label: LABEL,
+        message: message(score),
+        color: color(score).0,
+    };
+    if compact {
+        serde_json::to_string(&payload).unwrap_or_default()
+    } else {
+        serde_json::to_string_pretty(&payload).unwrap_or_default()
+    }
+}
+
+/// Format the score as a self-contained flat SVG badge.
+pub fn format_svg(score: Option<&ArchitectureScore>) -> String {
+    let message = message(score);
+    let fill = color(score).1;
+    let label_width = LABEL.len() * CHAR_WIDTH + 2 * PADDING;
+    let message_width = message.len() * CHAR_WIDTH + 2 * PADDING;
+    let width = label_width + message_width;
+    let label_x = label_width / 2;
+    let message_x = label_width + message_width / 2;
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{LABEL}: {message}">
+  <title>{LABEL}: {message}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r"><rect width="{width}" height="20" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="20" fill="#555"/>
+    <rect x="{label_width}" width="{message_width}" height="20" fill="{fill}"/>
+    <rect width="{width}" height="20" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="15" fill="#010101" fill-opacity=".3">{LABEL}</text>
+    <text x="{label_x}" y="14">{LABEL}</text>
+    <text x="{message_x}" y="15" fill="#010101" fill-opacity=".3">{message}</text>
+    <text x="{message_x}" y="14">{message}</text>
+  </g>
+</svg>"##
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(overall: f64) -> ArchitectureScore {
+        ArchitectureScore {
+            overall,
+            structural_presence: overall,
+            layer_conformance: overall,
+            dependency_compliance: overall,
+            interface_coverage: overall,
+            not_applicable: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_shields_endpoint_for_passing_score() {
+        let payload: serde_json::Value =
+            serde_json::from_str(&format_shields_endpoint(Some(&score(92.4)), true)).unwrap();
+        assert_eq!(payload["schemaVersion"], 1);
+        assert_eq!(payload["label"], "architecture");
+        assert_eq!(payload["message"], "92% (A)");
+        assert_eq!(payload["color"], "green");
+    }
+
+    #[test]
+    fn test_badge_colors_follow_text_thresholds() {
+        let color_of = |overall| color(Some(&score(overall))).0;
+        assert_eq!(color_of(80.0), "green");
+        assert_eq!(color_of(79.9), "yellow");
+        assert_eq!(color_of(50.0), "yellow");
+        assert_eq!(color_of(49.9), "red");
+        assert_eq!(color(None).0, "lightgrey");
+    }
+
+    #[test]
+    fn test_svg_badge_shows_score_and_grade() {
+        let svg = format_svg(Some(&score(67.0)));
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains(">67% (C)</text>"));
+        assert!(svg.contains("fill=\"#dfb317\""));
+    }
+}
@@ -0,0 +1,232 @@
+use serde_json::{json, Value};
+
+use boundary_core::graph::{DependencyGraph, GraphNode};
+use boundary_core::types::ComponentKind;
+
+/// Export the dependency graph as Cytoscape.js elements JSON.
+///
+/// Produces `{ "elements": { "nodes": [...], "edges": [...] } }`. Every node carries
+/// its layer, kind and flags in `data`; `classes` holds space-separated style hooks
+/// (`domain`, `port`, `external`, `cross-cutting`, `violating`, ...).
+pub fn export(graph: &DependencyGraph) -> String {
+    let edges_with_nodes = graph.edges_with_nodes();
+
+    let violating_ids: Vec<&str> = edges_with_nodes
+        .iter()
+        .filter(|(src, tgt, _)| is_violation(src, tgt))
+        .map(|(src, _, _)| src.id.0.as_str())
+        .collect();
+
+    let nodes: Vec<Value> = graph
+        .nodes()
+        .into_iter()
+        .map(|node| {
+            let kind = kind_name(node.kind.as_ref());
+            let violating = violating_ids.contains(&node.id.0.as_str());
+
+            let mut classes = Vec::new();
+            if let Some(layer) = node.layer {
+                classes.push(layer.to_string());
+            }
+            if let Some(kind) = kind {
+                classes.push(kind.replace('_', "-"));
+            }
+            if node.is_external {
+                classes.push("external".to_string());
+            }
+            if node.is_cross_cutting {
+                classes.push("cross-cutting".to_string());
+            }
+            if violating {
+                classes.push("violating".to_string());
+            }
+
+            json!({
+                "data": {
+                    "id": node.id.0,
+                    "label": node.name,
+                    "layer": node.layer.map(|l| l.to_string()),
+                    "kind": kind,
+                    "language": node.id.language(),
+                    "file": node.location.file.to_string_lossy(),
+                    "line": node.location.line,
+                    "external": node.is_external,
+                    "cross_cutting": node.is_cross_cutting,
+                    "violating": violating,
+                },
+                "classes": classes.join(" "),
+            })
+        })
+        .collect();
+
+    let edges: Vec<Value> = edges_with_nodes
+        .iter()
+        .enumerate()
+        .map(|(i, (src, tgt, edge))| {
+            let violating = is_violation(src, tgt);
+            json!({
+                "data": {
+                    "id": format!("e{i}"),
+                    "source": src.id.0,
+                    "target": tgt.id.0,
+                    "kind": edge.kind,
+                    "import_path": edge.import_path,
+                    "weight": edge.weight(),
+                    "violating": violating,
+                },
+                "classes": if violating { "violating" } else { "" },
+            })
+        })
+        .collect();
+
+    let doc = json!({
+        "elements": {
+            "nodes": nodes,
+            "edges": edges,
+        }
+    });
+    serde_json::to_string_pretty(&doc).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// An edge is violating when it points from an inner layer to an outer one.
+/// Cross-cutting components are exempt, matching the layer-boundary rule.
+fn is_violation(src: &GraphNode, tgt: &GraphNode) -> bool {
+    if src.is_cross_cutting || tgt.is_cross_cutting {
+        return false;
+    }
+    match (src.layer, tgt.layer) {
+        (Some(from_layer), Some(to_layer)) => from_layer.violates_dependency_on(&to_layer),
+        _ => false,
+    }
+}
+
+fn kind_name(kind: Option<&ComponentKind>) -> Option<&'static str> {
+    kind.map(|k| match k {
+        ComponentKind::Port(_) => "port",
+        ComponentKind::Adapter(_) => "adapter",
+        ComponentKind::Entity(_) => "entity",
+        ComponentKind::ValueObject(_) => "value_object",
+        ComponentKind::UseCase => "use_case",
+        ComponentKind::Repository => "repository",
+        ComponentKind::Service => "service",
+        ComponentKind::DomainEvent(_) => "domain_event",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boundary_core::types::*;
+    use std::path::PathBuf;
+
+    fn make_component(id: &str, name: &str, layer: Option<ArchLayer>) -> Component {
+        Component {
+            id: ComponentId(id.to_string()),
+            name: name.to_string(),
+            kind: ComponentKind::Entity(EntityInfo {
+                name: name.to_string(),
+                fields: vec![],
+                methods: vec![],
+                is_active_record: false,
+                is_anemic_domain_model: false,
+            }),
+            layer,
+            location: SourceLocation {
+                file: PathBuf::from("test.go"),
+                line: 1,
+                column: 1,
+            },
+            is_cross_cutting: false,
+            is_generated: false,
+            architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
+        }
+    }
+
+    fn make_dep(from: &str, to: &str) -> Dependency {
+        Dependency {
+            from: ComponentId(from.to_string()),
+            to: ComponentId(to.to_string()),
+            kind: DependencyKind::Import,
+            location: SourceLocation {
+                file: PathBuf::from("test.go"),
+                line: 1,
+                column: 1,
+            },
+            import_path: None,
+        }
+    }
+
+    #[test]
+    fn test_export_nodes_and_violating_edges() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component(
+            "domain::User",
+            "User",
+            Some(ArchLayer::Domain),
+        ));
+        graph.add_component(&make_component(
+            "infra::Repo",
+            "Repo",
+            Some(ArchLayer::Infrastructure),
+        ));
+        graph.add_component(&make_component(
+            "app::Service",
+            "Service",
+            Some(ArchLayer::Application),
+        ));
+        graph.add_dependency(&make_dep("domain::User", "infra::Repo"));
+        graph.add_dependency(&make_dep("app::Service", "domain::User"));
+
+        let parsed: Value = serde_json::from_str(&export(&graph)).unwrap();
+        let nodes = parsed["elements"]["nodes"].as_array().unwrap();
+        assert_eq!(nodes.len(), graph.node_count());
+
+        let edges = parsed["elements"]["edges"].as_array().unwrap();
+        let bad = edges
+            .iter()
+            .find(|e| e["data"]["source"] == "domain::User")
+            .unwrap();
+        assert_eq!(bad["classes"], "violating");
+        assert_eq!(bad["data"]["violating"], true);
+
+        let ok = edges
+            .iter()
+            .find(|e| e["data"]["source"] == "app::Service")
+            .unwrap();
+        assert_eq!(ok["classes"], "");
+
+        let user = nodes
+            .iter()
+            .find(|n| n["data"]["id"] == "domain::User")
+            .unwrap();
+        assert_eq!(user["data"]["layer"], "domain");
+        assert_eq!(user["classes"], "domain entity violating");
+    }
+
+    #[test]
+    fn test_external_node_class() {
+        let mut graph = DependencyGraph::new();
+        graph.add_component(&make_component(
+            "domain::User",
+            "User",
+            Some(ArchLayer::Domain),
+        ));
+        let ext = ComponentId("github.com/lib/pq::<package>".to_string());
+        graph.ensure_node(&ext, None, true);
+        graph.mark_external(&ext);
+        graph.add_dependency(&make_dep("domain::User", &ext.0));
+
+        let parsed: Value = serde_json::from_str(&export(&graph)).unwrap();
+        let node = parsed["elements"]["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|n| n["data"]["id"] == ext.0)
+            .unwrap()
+            .clone();
+        let classes = node["classes"].as_str().unwrap();
+        assert!(classes.contains("external"), "classes: {classes}");
+        assert!(classes.contains("cross-cutting"), "classes: {classes}");
+    }
+}
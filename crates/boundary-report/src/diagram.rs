@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
-use boundary_core::graph::DependencyGraph;
+use boundary_core::graph::{DependencyGraph, GraphNode};
 use boundary_core::types::ArchLayer;
 
 /// Generate a Mermaid flowchart showing layers as subgraphs with components inside.
-pub fn generate_layer_diagram(graph: &DependencyGraph) -> String {
+///
+/// With `include_external`, external packages are drawn in a dashed gray `External`
+/// subgraph, with dashed edges from the components that import them.
+pub fn generate_layer_diagram(graph: &DependencyGraph, include_external: bool) -> String {
     let mut out = String::new();
     out.push_str("flowchart TB\n");
 
@@ -60,6 +63,24 @@ pub fn generate_layer_diagram(graph: &DependencyGraph) -> String {
         out.push_str("  end\n");
     }
 
+    let external_deps = if include_external {
+        external_dependencies(graph)
+    } else {
+        Vec::new()
+    };
+    let externals = distinct_externals(&external_deps);
+    if !externals.is_empty() {
+        out.push_str("  subgraph External\n");
+        for node in &externals {
+            let id = sanitize_mermaid_id(&node.id.0);
+            out.push_str(&format!(
+                "    {id}[\"{}\"]:::external\n",
+                external_label(node)
+            ));
+        }
+        out.push_str("  end\n");
+    }
+
     // Render edges — skip edges involving synthetic nodes
     for (src, tgt, edge) in graph.edges_with_nodes() {
         if src.kind.is_none() || tgt.kind.is_none() {
@@ -101,17 +122,32 @@ pub fn generate_layer_diagram(graph: &DependencyGraph) -> String {
         }
     }
 
+    for (src, ext) in &external_deps {
+        let from = sanitize_mermaid_id(&src.id.0);
+        let to = sanitize_mermaid_id(&ext.id.0);
+        out.push_str(&format!("  {from} -.-> {to}\n"));
+    }
+
     // Style violation edges in red
     out.push_str("\n  style Domain fill:#e8f5e9\n");
     out.push_str("  style Application fill:#e3f2fd\n");
     out.push_str("  style Infrastructure fill:#fff3e0\n");
     out.push_str("  style Presentation fill:#fce4ec\n");
+    if !externals.is_empty() {
+        out.push_str("  style External fill:#fafafa,stroke:#9e9e9e,stroke-dasharray:5 5\n");
+        out.push_str(
+            "  classDef external fill:#eeeeee,stroke:#9e9e9e,stroke-dasharray:5 5,color:#616161\n",
+        );
+    }
 
     out
 }
 
 /// Generate a simplified Mermaid flowchart showing layer-to-layer edges with counts.
-pub fn generate_dependency_flow(graph: &DependencyGraph) -> String {
+///
+/// With `include_external`, a dashed `external` node counts the external packages
+/// imported, with an edge from each layer that imports them.
+pub fn generate_dependency_flow(graph: &DependencyGraph, include_external: bool) -> String {
     let mut out = String::new();
     out.push_str("flowchart LR\n");
 
@@ -164,13 +200,94 @@ pub fn generate_dependency_flow(graph: &DependencyGraph) -> String {
         }
     }
 
+    if include_external {
+        let external_deps = external_dependencies(graph);
+        let externals = distinct_externals(&external_deps);
+        if !externals.is_empty() {
+            out.push_str(&format!("  external[\"external ({})\"]\n", externals.len()));
+            for (from, total) in external_counts_by_layer(&external_deps) {
+                let from_id = sanitize_mermaid_id(&from);
+                out.push_str(&format!("  {from_id} -.->|\"{total} deps\"| external\n"));
+            }
+            out.push_str("  style external fill:#eeeeee,stroke:#9e9e9e,stroke-dasharray:5 5\n");
+        }
+    }
+
     out
 }
 
+/// Dependencies on external packages as (importing component, external node) pairs,
+/// sorted and deduplicated. Dependencies recorded at import level (e.g. from a Go
+/// `<file>` node) are attributed to every component of the importing package.
+pub(crate) fn external_dependencies(graph: &DependencyGraph) -> Vec<(&GraphNode, &GraphNode)> {
+    let components: Vec<&GraphNode> = graph
+        .nodes()
+        .into_iter()
+        .filter(|n| n.kind.is_some() && !n.is_external)
+        .collect();
+
+    let mut deps = Vec::new();
+    for (src, tgt, _) in graph.edges_with_nodes() {
+        if !tgt.is_external || src.is_external {
+            continue;
+        }
+        if src.kind.is_some() {
+            deps.push((src, tgt));
+            continue;
+        }
+        deps.extend(
+            components
+                .iter()
+                .filter(|c| {
+                    c.id.package() == src.id.package() && c.id.language() == src.id.language()
+                })
+                .map(|c| (*c, tgt)),
+        );
+    }
+    deps.sort_by(|a, b| (&a.0.id.0, &a.1.id.0).cmp(&(&b.0.id.0, &b.1.id.0)));
+    deps.dedup_by(|a, b| a.0.id == b.0.id && a.1.id == b.1.id);
+    deps
+}
+
+/// The external nodes of `deps`, sorted by ID.
+pub(crate) fn distinct_externals<'a>(
+    deps: &[(&'a GraphNode, &'a GraphNode)],
+) -> Vec<&'a GraphNode> {
+    let mut externals: Vec<&GraphNode> = deps.iter().map(|(_, ext)| *ext).collect();
+    externals.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+    externals.dedup_by(|a, b| a.id == b.id);
+    externals
+}
+
+/// Number of external dependencies per importing layer (`unclassified` for none).
+pub(crate) fn external_counts_by_layer(deps: &[(&GraphNode, &GraphNode)]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for (src, _) in deps {
+        let layer = src
+            .layer
+            .map_or_else(|| "unclassified".to_string(), |l| l.to_string());
+        match counts.iter_mut().find(|(l, _)| *l == layer) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((layer, 1)),
+        }
+    }
+    counts.sort();
+    counts
+}
+
+/// Display name of an external node: its import path without the `::<package>` suffix.
+pub(crate) fn external_label(node: &GraphNode) -> &str {
+    let id = node.id.untagged();
+    match id.rsplit_once("::") {
+        Some((path, name)) if name.starts_with('<') => path,
+        _ => id,
+    }
+}
+
 /// Sanitize a string to be a valid Mermaid node ID.
 fn sanitize_mermaid_id(s: &str) -> String {
     s.replace("::", "_")
-        .replace(['/', '.', '-', ' '], "_")
+        .replace(['/', '.', '-', ' ', ':'], "_")
         .replace(['<', '>'], "")
 }
 
@@ -199,7 +316,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -226,7 +345,7 @@ mod tests {
         graph.add_component(&c2);
         graph.add_dependency(&make_dep("infra::Repo", "domain::User"));
 
-        let diagram = generate_layer_diagram(&graph);
+        let diagram = generate_layer_diagram(&graph, false);
         assert!(diagram.contains("flowchart TB"));
         assert!(diagram.contains("subgraph Domain"));
         assert!(diagram.contains("subgraph Infrastructure"));
@@ -241,7 +360,7 @@ mod tests {
         graph.add_component(&c2);
         graph.add_dependency(&make_dep("infra::Repo", "domain::User"));
 
-        let diagram = generate_dependency_flow(&graph);
+        let diagram = generate_dependency_flow(&graph, false);
         assert!(diagram.contains("flowchart LR"));
         assert!(diagram.contains("deps"));
     }
@@ -256,7 +375,30 @@ mod tests {
         // Domain -> Infrastructure = violation
         graph.add_dependency(&make_dep("domain::User", "infra::Repo"));
 
-        let diagram = generate_layer_diagram(&graph);
+        let diagram = generate_layer_diagram(&graph, false);
         assert!(diagram.contains("violation"));
     }
+
+    #[test]
+    fn test_external_nodes_only_with_flag() {
+        let mut graph = DependencyGraph::new();
+        let c1 = make_component("go:infra::Repo", "Repo", Some(ArchLayer::Infrastructure));
+        graph.add_component(&c1);
+        // Imports are recorded from the file node; the external target has no kind.
+        graph.add_dependency(&make_dep(
+            "go:infra::<file>",
+            "go:github.com/lib/pq::<package>",
+        ));
+        graph.mark_external(&ComponentId("go:github.com/lib/pq::<package>".to_string()));
+
+        let without = generate_layer_diagram(&graph, false);
+        assert!(!without.contains("github.com/lib/pq"));
+
+        let with = generate_layer_diagram(&graph, true);
+        assert!(with.contains("subgraph External"));
+        assert!(with.contains("\"github.com/lib/pq\""));
+
+        assert!(!generate_dependency_flow(&graph, false).contains("external"));
+        assert!(generate_dependency_flow(&graph, true).contains("external[\"external (1)\"]"));
+    }
 }
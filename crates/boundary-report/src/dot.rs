@@ -3,8 +3,15 @@ use std::collections::HashMap;
 use boundary_core::graph::DependencyGraph;
 use boundary_core::types::ArchLayer;
 
+use crate::diagram::{
+    distinct_externals, external_counts_by_layer, external_dependencies, external_label,
+};
+
 /// Generate a GraphViz DOT diagram showing layers as subgraphs with components inside.
-pub fn generate_layer_diagram(graph: &DependencyGraph) -> String {
+///
+/// With `include_external`, external packages are drawn in a dashed gray cluster, with
+/// dashed edges from the components that import them.
+pub fn generate_layer_diagram(graph: &DependencyGraph, include_external: bool) -> String {
     let mut out = String::new();
     out.push_str("digraph architecture {\n");
     out.push_str("  rankdir=TB;\n");
@@ -75,6 +82,25 @@ pub fn generate_layer_diagram(graph: &DependencyGraph) -> String {
         out.push_str("  }\n\n");
     }
 
+    let external_deps = if include_external {
+        external_dependencies(graph)
+    } else {
+        Vec::new()
+    };
+    let externals = distinct_externals(&external_deps);
+    if !externals.is_empty() {
+        out.push_str("  subgraph cluster_external {\n");
+        out.push_str("    label=\"External\";\n");
+        out.push_str("    style=dashed;\n");
+        out.push_str("    color=gray;\n");
+        out.push_str("    node [style=dashed, color=gray, fontcolor=gray40];\n");
+        for node in &externals {
+            let id = sanitize_dot_id(&node.id.0);
+            out.push_str(&format!("    {id} [label=\"{}\"];\n", external_label(node)));
+        }
+        out.push_str("  }\n\n");
+    }
+
     // Render edges — skip edges involving synthetic nodes
     for (src, tgt, edge) in graph.edges_with_nodes() {
         if src.kind.is_none() || tgt.kind.is_none() {
@@ -118,12 +144,21 @@ pub fn generate_layer_diagram(graph: &DependencyGraph) -> String {
         }
     }
 
+    for (src, ext) in &external_deps {
+        let from = sanitize_dot_id(&src.id.0);
+        let to = sanitize_dot_id(&ext.id.0);
+        out.push_str(&format!("  {from} -> {to} [style=dashed, color=gray];\n"));
+    }
+
     out.push_str("}\n");
     out
 }
 
 /// Generate a simplified DOT diagram showing layer-to-layer edges with counts.
-pub fn generate_dependency_flow(graph: &DependencyGraph) -> String {
+///
+/// With `include_external`, a dashed `external` node counts the external packages
+/// imported, with an edge from each layer that imports them.
+pub fn generate_dependency_flow(graph: &DependencyGraph, include_external: bool) -> String {
     let mut out = String::new();
     out.push_str("digraph dependency_flow {\n");
     out.push_str("  rankdir=LR;\n");
@@ -191,6 +226,22 @@ pub fn generate_dependency_flow(graph: &DependencyGraph) -> String {
         }
     }
 
+    if include_external {
+        let external_deps = external_dependencies(graph);
+        let externals = distinct_externals(&external_deps);
+        if !externals.is_empty() {
+            out.push_str(&format!(
+                "  external [label=\"external ({})\", style=dashed, color=gray, fontcolor=gray40];\n",
+                externals.len()
+            ));
+            for (from, total) in external_counts_by_layer(&external_deps) {
+                out.push_str(&format!(
+                    "  {from} -> external [style=dashed, color=gray, label=\"{total} deps\"];\n"
+                ));
+            }
+        }
+    }
+
     out.push_str("}\n");
     out
 }
@@ -240,7 +291,9 @@ mod tests {
                 column: 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::Ddd,
+            methods: Vec::new(),
         }
     }
 
@@ -267,7 +320,7 @@ mod tests {
         graph.add_component(&c2);
         graph.add_dependency(&make_dep("infra::Repo", "domain::User"));
 
-        let diagram = generate_layer_diagram(&graph);
+        let diagram = generate_layer_diagram(&graph, false);
         assert!(diagram.contains("digraph architecture"));
         assert!(diagram.contains("cluster_domain"));
         assert!(diagram.contains("cluster_infrastructure"));
@@ -283,7 +336,7 @@ mod tests {
         graph.add_component(&c2);
         graph.add_dependency(&make_dep("infra::Repo", "domain::User"));
 
-        let diagram = generate_dependency_flow(&graph);
+        let diagram = generate_dependency_flow(&graph, false);
         assert!(diagram.contains("digraph dependency_flow"));
         assert!(diagram.contains("deps"));
     }
@@ -297,8 +350,31 @@ mod tests {
         graph.add_component(&c2);
         graph.add_dependency(&make_dep("domain::User", "infra::Repo"));
 
-        let diagram = generate_layer_diagram(&graph);
+        let diagram = generate_layer_diagram(&graph, false);
         assert!(diagram.contains("color=red"));
         assert!(diagram.contains("violation"));
     }
+
+    #[test]
+    fn test_external_nodes_only_with_flag() {
+        let mut graph = DependencyGraph::new();
+        let c1 = make_component("go:infra::Repo", "Repo", Some(ArchLayer::Infrastructure));
+        graph.add_component(&c1);
+        // Imports are recorded from the file node; the external target has no kind.
+        graph.add_dependency(&make_dep(
+            "go:infra::<file>",
+            "go:github.com/lib/pq::<package>",
+        ));
+        graph.mark_external(&ComponentId("go:github.com/lib/pq::<package>".to_string()));
+
+        let without = generate_layer_diagram(&graph, false);
+        assert!(!without.contains("github.com/lib/pq"));
+
+        let with = generate_layer_diagram(&graph, true);
+        assert!(with.contains("cluster_external"));
+        assert!(with.contains("\"github.com/lib/pq\""));
+
+        assert!(!generate_dependency_flow(&graph, false).contains("external"));
+        assert!(generate_dependency_flow(&graph, true).contains("external [label=\"external (1)\""));
+    }
 }
@@ -1,5 +1,5 @@
-use boundary_core::forensics::ForensicsAnalysis;
-use boundary_core::types::{ComponentKind, ViolationKind};
+use boundary_core::forensics::{ForensicsAnalysis, ScoreBreakdown};
+use boundary_core::types::{ArchLayer, ComponentKind, ViolationKind};
 
 /// Format a forensics analysis as a Markdown report.
 pub fn format_forensics_report(analysis: &ForensicsAnalysis) -> String {
@@ -247,6 +247,8 @@ pub fn format_forensics_report(analysis: &ForensicsAnalysis) -> String {
         out.push_str("Score not available (insufficient architectural signals detected).\n\n");
     }
 
+    format_score_breakdown(&mut out, &analysis.score_breakdown);
+
     // Violations
     out.push_str("### Violations\n\n");
     if analysis.violations.is_empty() {
@@ -259,6 +261,20 @@ pub fn format_forensics_report(analysis: &ForensicsAnalysis) -> String {
                     to_layer,
                 } => format!("{from_layer} -> {to_layer}"),
                 ViolationKind::CircularDependency { .. } => "circular dependency".to_string(),
+                ViolationKind::DisallowedExternalDependency { import_path, .. } => {
+                    format!("disallowed external dependency: {import_path}")
+                }
+                ViolationKind::IntraLayerCycle { layer, .. } => {
+                    format!("{layer} intra-layer cycle")
+                }
+                ViolationKind::HighCoupling {
+                    component, fan_out, ..
+                } => {
+                    format!("high coupling: {component} ({fan_out} dependencies)")
+                }
+                ViolationKind::ArchitectureDrift { subject, .. } => {
+                    format!("architecture drift: {subject}")
+                }
                 ViolationKind::MissingPort { adapter_name } => {
                     format!("missing port for {adapter_name}")
                 }
@@ -282,6 +298,68 @@ pub fn format_forensics_report(analysis: &ForensicsAnalysis) -> String {
                 ViolationKind::PortWithoutImplementation { port_name } => {
                     format!("unimplemented port: {port_name}")
                 }
+                ViolationKind::FatRepository {
+                    repository_name, ..
+                } => {
+                    format!("fat repository: {repository_name}")
+                }
+                ViolationKind::DuplicatePort { port_name, .. } => {
+                    format!("duplicate port: {port_name}")
+                }
+                ViolationKind::FatInterface { port, .. } => {
+                    format!("fat interface: {port}")
+                }
+                ViolationKind::BehaviorHeavyPort { port, .. } => {
+                    format!("behavior-heavy port: {port}")
+                }
+                ViolationKind::ContextMissingPort { context, .. } => {
+                    format!("context missing port: {context}")
+                }
+                ViolationKind::ExcessiveFanOut { component, .. } => {
+                    format!("excessive fan-out: {component}")
+                }
+                ViolationKind::FactoryReturnsConcrete { factory, .. } => {
+                    format!("factory returns concrete: {factory}")
+                }
+                ViolationKind::AdapterMisplaced { adapter, .. } => {
+                    format!("misplaced adapter: {adapter}")
+                }
+                ViolationKind::AdapterToAdapterCoupling {
+                    from_adapter,
+                    to_adapter,
+                } => {
+                    format!("adapter coupling: {from_adapter} -> {to_adapter}")
+                }
+                ViolationKind::PortAdapterCycle { port, adapter } => {
+                    format!("port-adapter cycle: {port} <-> {adapter}")
+                }
+                ViolationKind::ModuleDirectionViolation { module, .. } => {
+                    format!("module direction: {module}")
+                }
+                ViolationKind::PassThroughService { service_name, .. } => {
+                    format!("pass-through service: {service_name}")
+                }
+                ViolationKind::MixedArchitectureModes { context, .. } => {
+                    format!("mixed architecture modes: {context}")
+                }
+                ViolationKind::LayerSkip { from, to, .. } => {
+                    format!("layer skip: {from} -> {to}")
+                }
+                ViolationKind::LowClassificationCoverage { coverage, .. } => {
+                    format!("classification coverage: {coverage:.1}%")
+                }
+                ViolationKind::LowInterfaceCoverage { coverage, .. } => {
+                    format!("interface coverage: {coverage:.1}%")
+                }
+                ViolationKind::TestDoubleInProduction { test_double, .. } => {
+                    format!("test double in production: {test_double}")
+                }
+                ViolationKind::CrossAggregateFieldAccess { entity, field, .. } => {
+                    format!("cross-aggregate field access: {entity}.{field}")
+                }
+                ViolationKind::AnemicDomainModel { entity_name } => {
+                    format!("anemic domain model: {entity_name}")
+                }
             };
             out.push_str(&format!(
                 "- **{}** [{}] {}: {}\n",
@@ -307,3 +385,57 @@ pub fn format_forensics_report(analysis: &ForensicsAnalysis) -> String {
 
     out
 }
+
+/// Render what lowers the score: misdirected dependencies, unclassified components
+/// and the imports behind most violations.
+fn format_score_breakdown(out: &mut String, breakdown: &ScoreBreakdown) {
+    out.push_str("### Score Breakdown\n\n");
+
+    out.push_str("#### Dependency Compliance\n\n");
+    if breakdown.noncompliant_edges.is_empty() {
+        out.push_str("Every dependency flows in a valid direction.\n\n");
+    } else {
+        out.push_str("| From | To | Import | Location |\n");
+        out.push_str("|------|----|--------|----------|\n");
+        let layer = |l: Option<ArchLayer>| l.map_or("unclassified".to_string(), |l| l.to_string());
+        for edge in &breakdown.noncompliant_edges {
+            out.push_str(&format!(
+                "| {} ({}) | {} ({}) | {} | {} |\n",
+                edge.from,
+                layer(edge.from_layer),
+                edge.to,
+                layer(edge.to_layer),
+                edge.import_path.as_deref().unwrap_or("-"),
+                edge.location
+            ));
+        }
+        out.push('\n');
+    }
+
+    out.push_str("#### Structural Presence\n\n");
+    if breakdown.unclassified_components.is_empty() {
+        out.push_str("Every component is assigned a layer.\n\n");
+    } else {
+        out.push_str("Components without a layer:\n\n");
+        for comp in &breakdown.unclassified_components {
+            out.push_str(&format!(
+                "- `{}` ({})\n",
+                comp.name,
+                comp.location.file.display()
+            ));
+        }
+        out.push('\n');
+    }
+
+    out.push_str("#### Top Violating Imports\n\n");
+    if breakdown.violating_imports.is_empty() {
+        out.push_str("No violations point at an import.\n\n");
+    } else {
+        out.push_str("| Import | Violations |\n");
+        out.push_str("|--------|------------|\n");
+        for (import, count) in &breakdown.violating_imports {
+            out.push_str(&format!("| `{import}` | {count} |\n"));
+        }
+        out.push('\n');
+    }
+}
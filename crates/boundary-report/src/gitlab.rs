@@ -0,0 +1,137 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use boundary_core::metrics::{AnalysisResult, MultiServiceResult};
+use boundary_core::types::{Severity, Violation};
+
+/// One entry of a GitLab Code Quality report, the Code Climate issue subset GitLab reads
+/// (<https://docs.gitlab.com/ee/ci/testing/code_quality.html#code-quality-report-format>).
+#[derive(Serialize)]
+struct CodeQualityIssue {
+    description: String,
+    check_name: String,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLocation {
+    path: String,
+    lines: CodeQualityLines,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLines {
+    begin: usize,
+}
+
+/// GitLab severity for a violation. Errors fail `boundary check`, so they show as critical.
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "critical",
+        Severity::Warning => "major",
+        Severity::Info => "info",
+    }
+}
+
+/// The issue for `v`, found in the project at `root`.
+fn issue(v: &Violation, root: &Path) -> CodeQualityIssue {
+    CodeQualityIssue {
+        description: v.message.clone(),
+        check_name: v.kind.rule_id().to_string(),
+        fingerprint: v.fingerprint(root),
+        severity: severity(v.severity),
+        location: CodeQualityLocation {
+            lines: CodeQualityLines {
+                begin: v.location.line.max(1),
+            },
+            path: v.relative_file(root),
+        },
+    }
+}
+
+fn to_json(issues: &[CodeQualityIssue], compact: bool) -> String {
+    if compact {
+        serde_json::to_string(issues).unwrap_or_default()
+    } else {
+        serde_json::to_string_pretty(issues).unwrap_or_default()
+    }
+}
+
+/// Format the violations of an analysis as a GitLab Code Quality report.
+pub fn format_report(result: &AnalysisResult, compact: bool) -> String {
+    let issues: Vec<_> = result
+        .violations
+        .iter()
+        .map(|v| issue(v, result.root()))
+        .collect();
+    to_json(&issues, compact)
+}
+
+/// Format the violations of every service as one GitLab Code Quality report.
+pub fn format_multi_service_report(multi: &MultiServiceResult, compact: bool) -> String {
+    let issues: Vec<_> = multi
+        .services
+        .iter()
+        .flat_map(|s| {
+            s.result
+                .violations
+                .iter()
+                .map(|v| issue(v, s.result.root()))
+        })
+        .collect();
+    to_json(&issues, compact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boundary_core::types::{ArchLayer, SourceLocation, ViolationKind};
+    use std::path::PathBuf;
+
+    fn violation(line: usize) -> Violation {
+        Violation {
+            kind: ViolationKind::LayerBoundary {
+                from_layer: ArchLayer::Domain,
+                to_layer: ArchLayer::Infrastructure,
+            },
+            severity: Severity::Error,
+            location: SourceLocation {
+                file: PathBuf::from("internal/domain/user.go"),
+                line,
+                column: 1,
+            },
+            message: "domain layer depends on infrastructure layer".to_string(),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_issue_fields() {
+        let issue = serde_json::to_value(issue(&violation(7), Path::new("."))).unwrap();
+        assert_eq!(issue["check_name"], "L001");
+        assert_eq!(issue["severity"], "critical");
+        assert_eq!(issue["location"]["path"], "internal/domain/user.go");
+        assert_eq!(issue["location"]["lines"]["begin"], 7);
+    }
+
+    #[test]
+    fn test_path_is_relative_to_project_root() {
+        let mut v = violation(7);
+        v.location.file = PathBuf::from("/builds/acme/app/internal/domain/user.go");
+        let issue = serde_json::to_value(issue(&v, Path::new("/builds/acme/app"))).unwrap();
+        assert_eq!(issue["location"]["path"], "internal/domain/user.go");
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line() {
+        let a = issue(&violation(7), Path::new("."));
+        let b = issue(&violation(12), Path::new("."));
+        assert_eq!(a.fingerprint, b.fingerprint);
+        assert_eq!(a.fingerprint.len(), 64);
+    }
+}
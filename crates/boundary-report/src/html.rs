@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+
+use boundary_core::metrics::AnalysisResult;
+use boundary_core::types::{Severity, Violation};
+
+/// Inline stylesheet, so the report works offline as a single file.
+const STYLE: &str = "\
+body{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',Helvetica,Arial,sans-serif;\
+margin:2rem auto;max-width:960px;padding:0 1rem;color:#1f2328;line-height:1.5}\
+h1{border-bottom:1px solid #d0d7de;padding-bottom:.3rem}\
+h2{margin-top:2rem;border-bottom:1px solid #d0d7de;padding-bottom:.2rem}\
+table{border-collapse:collapse;width:100%;margin:.5rem 0 1rem}\
+th,td{border:1px solid #d0d7de;padding:.35rem .6rem;text-align:left;vertical-align:top}\
+th{background:#f6f8fa}\
+td.num{text-align:right;font-variant-numeric:tabular-nums}\
+a{color:#0969da;text-decoration:none}a:hover{text-decoration:underline}\
+code{font-family:ui-monospace,SFMono-Regular,Menlo,monospace;font-size:.9em}\
+.notice{background:#fff8c5;border:1px solid #d4a72c;border-radius:6px;padding:.75rem 1rem}\
+.overall{font-size:2rem;font-weight:600}\
+.good{color:#1a7f37}.fair{color:#9a6700}.poor{color:#cf222e}\
+.sev{font-weight:600;font-size:.85em}\
+.sev-error{color:#cf222e}.sev-warning{color:#9a6700}.sev-info{color:#0969da}\
+.passed{color:#1a7f37;font-weight:600}.failed{color:#cf222e;font-weight:600}\
+.suggestion{color:#59636e;font-size:.9em}";
+
+/// Format a full analysis report as a standalone HTML document.
+pub fn format_report(result: &AnalysisResult) -> String {
+    document(&report_body(result))
+}
+
+/// Format check results as a standalone HTML document. Returns (output, passed).
+pub fn format_check(result: &AnalysisResult, fail_on: Severity) -> (String, bool) {
+    let failing = result.violations_at_or_above(fail_on).count();
+    let passed = failing == 0;
+
+    let mut body = report_body(result);
+    body.push_str("<h2>Result</h2>\n");
+    if passed {
+        body.push_str("<p class=\"passed\">CHECK PASSED</p>\n");
+    } else {
+        body.push_str(&format!(
+            "<p class=\"failed\">CHECK FAILED: {failing} violation(s) at severity {fail_on} or above</p>\n"
+        ));
+    }
+
+    (document(&body), passed)
+}
+
+fn document(body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>Boundary - Architecture Analysis</title>\n<style>{STYLE}</style>\n\
+         </head>\n<body>\n<h1>Boundary - Architecture Analysis</h1>\n{body}</body>\n</html>\n"
+    )
+}
+
+fn report_body(result: &AnalysisResult) -> String {
+    let mut out = String::new();
+
+    let no_layers = result
+        .score
+        .as_ref()
+        .map(|s| s.structural_presence == 0.0)
+        .unwrap_or(false);
+    if result.files_analyzed == 0 {
+        out.push_str(&notice(
+            "No supported source files found",
+            "The target directory contains no files that boundary can analyze. \
+             Ensure the directory contains Go, Rust, TypeScript, or Java source files.",
+        ));
+    } else if result.component_count == 0 {
+        out.push_str(&notice(
+            "No components were detected",
+            "Source files were found but no exported types could be extracted. \
+             Ensure types are exported (e.g. capitalized names in Go).",
+        ));
+    } else if no_layers {
+        out.push_str(&notice(
+            "No architectural layers detected",
+            "Components were found but none match a known DDD layer pattern. \
+             Add layer path patterns to .boundary.toml to classify them.",
+        ));
+    } else if let Some(score) = &result.score {
+        out.push_str(&format_score_section(score));
+        if let Some(pd) = &result.pattern_detection {
+            out.push_str(&format!(
+                "<p>Pattern: {} ({:.0}% confidence)</p>\n",
+                escape(&pd.top_pattern),
+                pd.top_confidence * 100.0
+            ));
+        }
+    }
+
+    out.push_str(&format!(
+        "<h2>Summary</h2>\n<p>{} components, {} dependencies</p>\n",
+        result.component_count, result.dependency_count
+    ));
+
+    if let Some(metrics) = &result.metrics {
+        if !metrics.components_by_layer.is_empty() {
+            out.push_str("<h2>Components by Layer</h2>\n<table>\n");
+            out.push_str("<tr><th>Layer</th><th>Components</th></tr>\n");
+            let mut layers: Vec<_> = metrics.components_by_layer.iter().collect();
+            layers.sort_by_key(|(k, _)| (*k).clone());
+            for (layer, count) in layers {
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td class=\"num\">{count}</td></tr>\n",
+                    escape(layer)
+                ));
+            }
+            out.push_str("</table>\n");
+        }
+    }
+
+    // Only claim "no violations" when we actually checked (layers were detected)
+    if result.violations.is_empty() && !(no_layers && result.component_count > 0) {
+        out.push_str("<h2>Violations</h2>\n<p class=\"passed\">No violations found!</p>\n");
+    } else if !result.violations.is_empty() {
+        out.push_str(&format_violations_section(result));
+    }
+
+    out
+}
+
+fn notice(title: &str, detail: &str) -> String {
+    format!("<div class=\"notice\"><strong>{title}</strong><br>{detail}</div>\n")
+}
+
+fn format_score_section(score: &boundary_core::metrics::ArchitectureScore) -> String {
+    let grade = if score.overall >= 80.0 {
+        "good"
+    } else if score.overall >= 50.0 {
+        "fair"
+    } else {
+        "poor"
+    };
+    let mut out = format!(
+        "<h2>Score</h2>\n<p class=\"overall {grade}\">{}%</p>\n<table>\n\
+         <tr><th>Metric</th><th>Score</th></tr>\n",
+        score.overall.round() as i64
+    );
+    for (metric, label, value) in [
+        (
+            "structural_presence",
+            "Structural Presence",
+            score.structural_presence,
+        ),
+        (
+            "layer_conformance",
+            "Layer Conformance",
+            score.layer_conformance,
+        ),
+        (
+            "dependency_compliance",
+            "Dependency Compliance",
+            score.dependency_compliance,
+        ),
+        (
+            "interface_coverage",
+            "Interface Coverage",
+            score.interface_coverage,
+        ),
+    ] {
+        let value = if score.is_applicable(metric) {
+            format!("{}%", value.round() as i64)
+        } else {
+            "n/a".to_string()
+        };
+        out.push_str(&format!(
+            "<tr><td>{label}</td><td class=\"num\">{value}</td></tr>\n"
+        ));
+    }
+    out.push_str("</table>\n");
+    out
+}
+
+/// One table per violation kind, each row linking to the violation's file and line.
+fn format_violations_section(result: &AnalysisResult) -> String {
+    let mut out = format!(
+        "<h2>Violations ({} found)</h2>\n",
+        result.violations_total.unwrap_or(result.violations.len())
+    );
+
+    let mut by_kind: BTreeMap<(String, &str), Vec<&Violation>> = BTreeMap::new();
+    for v in &result.violations {
+        by_kind
+            .entry((v.kind.rule_id().to_string(), v.kind.name()))
+            .or_default()
+            .push(v);
+    }
+    for ((rule_id, name), violations) in &by_kind {
+        out.push_str(&format!(
+            "<h3>{rule_id} {} ({})</h3>\n<table>\n\
+             <tr><th>Severity</th><th>Location</th><th>Message</th></tr>\n",
+            escape(name),
+            violations.len()
+        ));
+        for v in violations {
+            let (class, label) = match v.severity {
+                Severity::Error => ("sev-error", "ERROR"),
+                Severity::Warning => ("sev-warning", "WARN"),
+                Severity::Info => ("sev-info", "INFO"),
+            };
+            let file = v.location.file.to_string_lossy();
+            out.push_str(&format!(
+                "<tr><td><span class=\"sev {class}\">{label}</span></td>\
+                 <td><a href=\"{}#L{line}\"><code>{}:{line}</code></a></td><td>{}",
+                escape(&file),
+                escape(&file),
+                escape(&v.message),
+                line = v.location.line,
+            ));
+            if let Some(suggestion) = &v.suggestion {
+                out.push_str(&format!(
+                    "<div class=\"suggestion\">Suggestion: {}</div>",
+                    escape(suggestion)
+                ));
+            }
+            if let Some(url) = v.help_url() {
+                out.push_str(&format!(" <a href=\"{}\">docs</a>", escape(&url)));
+            }
+            out.push_str("</td></tr>\n");
+        }
+        out.push_str("</table>\n");
+    }
+
+    let omitted = result.omitted_violations();
+    if omitted > 0 {
+        out.push_str(&format!("<p>… and {omitted} more</p>\n"));
+    }
+    out
+}
+
+/// Escape text for use in HTML element content and quoted attribute values.
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boundary_core::metrics::ArchitectureScore;
+    use boundary_core::types::{ArchLayer, SourceLocation, ViolationKind};
+    use std::path::PathBuf;
+
+    fn result(violations: Vec<Violation>) -> AnalysisResult {
+        AnalysisResult {
+            score: Some(ArchitectureScore {
+                overall: 85.0,
+                structural_presence: 100.0,
+                layer_conformance: 90.0,
+                dependency_compliance: 80.0,
+                interface_coverage: 85.0,
+                not_applicable: Vec::new(),
+            }),
+            violations,
+            component_count: 3,
+            dependency_count: 2,
+            files_analyzed: 3,
+            metrics: None,
+            package_metrics: vec![],
+            pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
+        }
+    }
+
+    fn layer_violation(file: &str, line: usize, import: &str) -> Violation {
+        Violation {
+            kind: ViolationKind::LayerBoundary {
+                from_layer: ArchLayer::Domain,
+                to_layer: ArchLayer::Infrastructure,
+            },
+            severity: Severity::Error,
+            location: SourceLocation {
+                file: PathBuf::from(file),
+                line,
+                column: 1,
+            },
+            message: format!("domain layer depends on infrastructure layer (import: {import})"),
+            suggestion: None,
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    #[test]
+    fn test_format_report_is_standalone_document() {
+        let report = format_report(&result(vec![]));
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("<style>"));
+        assert!(!report.contains("<link"), "CSS must be inline");
+        assert!(!report.contains("<script src"));
+        assert!(report.contains("85%"));
+        assert!(report.contains("No violations found!"));
+    }
+
+    #[test]
+    fn test_violations_grouped_and_linked() {
+        let report = format_report(&result(vec![
+            layer_violation("internal/domain/a.go", 4, "<infra>"),
+            layer_violation("internal/domain/b.go", 9, "db"),
+        ]));
+        assert_eq!(report.matches("<h3>L001").count(), 1);
+        assert!(report.contains("<h3>L001 domain-depends-on-infrastructure (2)</h3>"));
+        assert!(report.contains(
+            "<a href=\"internal/domain/a.go#L4\"><code>internal/domain/a.go:4</code></a>"
+        ));
+        assert!(report.contains("(import: &lt;infra&gt;)"));
+    }
+
+    #[test]
+    fn test_empty_states() {
+        let mut empty = result(vec![]);
+        empty.files_analyzed = 0;
+        empty.component_count = 0;
+        assert!(format_report(&empty).contains("No supported source files found"));
+
+        let mut no_layers = result(vec![]);
+        no_layers.score.as_mut().unwrap().structural_presence = 0.0;
+        let report = format_report(&no_layers);
+        assert!(report.contains("No architectural layers detected"));
+        assert!(!report.contains("No violations found!"));
+    }
+
+    #[test]
+    fn test_format_check_failed() {
+        let (report, passed) = format_check(
+            &result(vec![layer_violation("a.go", 1, "db")]),
+            Severity::Error,
+        );
+        assert!(!passed);
+        assert!(report.contains("CHECK FAILED: 1 violation(s)"));
+        assert!(report.trim_end().ends_with("</html>"));
+    }
+}
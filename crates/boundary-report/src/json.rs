@@ -1,8 +1,17 @@
-use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
+use anyhow::{bail, Context};
+use serde::{Serialize, Serializer};
+
+use boundary_core::config::Config;
 use boundary_core::metrics::AnalysisResult;
 use boundary_core::types::{Severity, Violation};
 
+/// Version of the JSON report layout. Bump when a change stops older reports from
+/// being read back by [`read_report`].
+pub const SCHEMA_VERSION: u32 = 1;
+
 /// A violation with rule ID and name added for JSON output.
 #[derive(Serialize)]
 struct ViolationOutput<'a> {
@@ -10,27 +19,43 @@ struct ViolationOutput<'a> {
     rule_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     doc_url: Option<String>,
+    fingerprint: String,
     #[serde(flatten)]
     violation: &'a Violation,
 }
 
 impl<'a> ViolationOutput<'a> {
-    fn from(v: &'a Violation) -> Self {
+    /// The output for `v`, found in the project at `root`.
+    fn from(v: &'a Violation, root: &Path) -> Self {
         Self {
             rule: v.kind.rule_id().to_string(),
             rule_name: v.kind.name().to_string(),
-            doc_url: v.kind.doc_url(),
+            doc_url: v.help_url(),
+            fingerprint: v.fingerprint(root),
             violation: v,
         }
     }
 }
 
+/// Violations serialized one at a time, so a streaming writer never holds the
+/// enriched list in memory. The path is the project root they were found under.
+struct ViolationsOutput<'a>(&'a [Violation], &'a Path);
+
+impl Serialize for ViolationsOutput<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|v| ViolationOutput::from(v, self.1)))
+    }
+}
+
 /// Wrapper for the full analysis result that enriches violations with rule metadata.
 #[derive(Serialize)]
 struct AnalysisOutput<'a> {
+    /// Set on top-level reports only, not on results nested in a multi-service report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema_version: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     score: &'a Option<boundary_core::metrics::ArchitectureScore>,
-    violations: Vec<ViolationOutput<'a>>,
+    violations: ViolationsOutput<'a>,
     component_count: usize,
     dependency_count: usize,
     files_analyzed: usize,
@@ -40,23 +65,39 @@ struct AnalysisOutput<'a> {
     package_metrics: &'a Vec<boundary_core::metrics::PackageMetric>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pattern_detection: &'a Option<boundary_core::pattern_detection::PatternDetection>,
+    /// Set when `violations` was capped; `total` is the count before truncation.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: &'a Option<Config>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debt: &'a Option<boundary_core::metrics::ArchitectureDebt>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    generated_files: &'a Vec<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project_root: &'a Option<PathBuf>,
 }
 
 impl<'a> AnalysisOutput<'a> {
     fn from(result: &'a AnalysisResult) -> Self {
         Self {
+            schema_version: Some(SCHEMA_VERSION),
             score: &result.score,
-            violations: result
-                .violations
-                .iter()
-                .map(ViolationOutput::from)
-                .collect(),
+            violations: ViolationsOutput(&result.violations, result.root()),
             component_count: result.component_count,
             dependency_count: result.dependency_count,
             files_analyzed: result.files_analyzed,
             metrics: &result.metrics,
             package_metrics: &result.package_metrics,
             pattern_detection: &result.pattern_detection,
+            truncated: result.violations_total.is_some(),
+            total: result.violations_total,
+            config: &result.config,
+            debt: &result.debt,
+            generated_files: &result.generated_files,
+            project_root: &result.project_root,
         }
     }
 }
@@ -71,13 +112,39 @@ pub fn format_report(result: &AnalysisResult, compact: bool) -> String {
     }
 }
 
+/// Stream a full analysis report as JSON to `writer`.
+///
+/// Writes the header fields, then each violation, then the footer, without
+/// building the document in memory. Output is identical to [`format_report`].
+pub fn write_report<W: Write>(
+    result: &AnalysisResult,
+    compact: bool,
+    writer: W,
+) -> std::io::Result<()> {
+    write_json(&AnalysisOutput::from(result), compact, writer)
+}
+
+fn write_json<T: Serialize, W: Write>(value: &T, compact: bool, writer: W) -> std::io::Result<()> {
+    if compact {
+        serde_json::to_writer(writer, value)?;
+    } else {
+        serde_json::to_writer_pretty(writer, value)?;
+    }
+    Ok(())
+}
+
 /// Wrapper for multi-service output that enriches violations with rule metadata.
 #[derive(Serialize)]
 struct MultiServiceOutput<'a> {
+    schema_version: u32,
     services: Vec<ServiceOutput<'a>>,
     aggregate: AnalysisOutput<'a>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     shared_modules: &'a Vec<boundary_core::metrics::SharedModule>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    shared_module_graph: &'a Vec<boundary_core::metrics::SharedModuleUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: &'a Option<Config>,
 }
 
 #[derive(Serialize)]
@@ -86,23 +153,39 @@ struct ServiceOutput<'a> {
     result: AnalysisOutput<'a>,
 }
 
+impl<'a> MultiServiceOutput<'a> {
+    fn from(multi: &'a boundary_core::metrics::MultiServiceResult) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            services: multi
+                .services
+                .iter()
+                .map(|s| ServiceOutput {
+                    service_name: &s.service_name,
+                    result: AnalysisOutput {
+                        schema_version: None,
+                        ..AnalysisOutput::from(&s.result)
+                    },
+                })
+                .collect(),
+            aggregate: AnalysisOutput {
+                schema_version: None,
+                config: &None,
+                ..AnalysisOutput::from(&multi.aggregate)
+            },
+            shared_modules: &multi.shared_modules,
+            shared_module_graph: &multi.shared_module_graph,
+            config: &multi.aggregate.config,
+        }
+    }
+}
+
 /// Format a multi-service analysis report as JSON.
 pub fn format_multi_service_report(
     multi: &boundary_core::metrics::MultiServiceResult,
     compact: bool,
 ) -> String {
-    let output = MultiServiceOutput {
-        services: multi
-            .services
-            .iter()
-            .map(|s| ServiceOutput {
-                service_name: &s.service_name,
-                result: AnalysisOutput::from(&s.result),
-            })
-            .collect(),
-        aggregate: AnalysisOutput::from(&multi.aggregate),
-        shared_modules: &multi.shared_modules,
-    };
+    let output = MultiServiceOutput::from(multi);
     if compact {
         serde_json::to_string(&output).expect("MultiServiceOutput should be serializable")
     } else {
@@ -110,6 +193,45 @@ pub fn format_multi_service_report(
     }
 }
 
+/// Stream a multi-service analysis report as JSON to `writer`.
+/// Output is identical to [`format_multi_service_report`].
+pub fn write_multi_service_report<W: Write>(
+    multi: &boundary_core::metrics::MultiServiceResult,
+    compact: bool,
+    writer: W,
+) -> std::io::Result<()> {
+    write_json(&MultiServiceOutput::from(multi), compact, writer)
+}
+
+/// Read a single-project report written by [`format_report`] (or `check`) back
+/// into an [`AnalysisResult`].
+///
+/// Reports without a `schema_version` predate versioning and share version 1's layout.
+pub fn read_report(content: &str) -> anyhow::Result<AnalysisResult> {
+    let mut value: serde_json::Value = serde_json::from_str(content).context("invalid JSON")?;
+    if value.get("services").is_some() {
+        bail!("multi-service reports cannot be merged; merge single-project reports instead");
+    }
+    if let Some(version) = value.get("schema_version") {
+        if version.as_u64() != Some(u64::from(SCHEMA_VERSION)) {
+            bail!(
+                "report schema version {version} is not supported by this version of \
+                 boundary (expected {SCHEMA_VERSION}); regenerate the report"
+            );
+        }
+    }
+
+    // Output names the pre-truncation count `total`.
+    let total = value.get("total").and_then(|t| t.as_u64());
+    if let Some(obj) = value.as_object_mut() {
+        obj.remove("total");
+    }
+    let mut result: AnalysisResult =
+        serde_json::from_value(value).context("not a boundary analysis report")?;
+    result.violations_total = total.map(|t| t as usize);
+    Ok(result)
+}
+
 /// Wrapper for check output that adds pass/fail metadata.
 #[derive(Serialize)]
 struct CheckOutput<'a> {
@@ -125,16 +247,49 @@ struct CheckStatus {
     failing_violation_count: usize,
 }
 
+/// Minimal check output for CI gating (`check --summary-only`).
+#[derive(Serialize)]
+struct CheckSummary {
+    passed: bool,
+    overall: Option<f64>,
+    error_count: usize,
+    warning_count: usize,
+    info_count: usize,
+    failing_count: usize,
+}
+
 /// Format a check result as JSON. Returns (json_string, passed).
-pub fn format_check(result: &AnalysisResult, fail_on: Severity, compact: bool) -> (String, bool) {
-    let failing_count = result
-        .violations
-        .iter()
-        .filter(|v| v.severity >= fail_on)
-        .count();
+///
+/// With `summary_only`, emits just pass/fail, the overall score and violation counts
+/// instead of the full result.
+pub fn format_check(
+    result: &AnalysisResult,
+    fail_on: Severity,
+    compact: bool,
+    summary_only: bool,
+) -> (String, bool) {
+    let failing_count = result.violations_at_or_above(fail_on).count();
 
     let passed = failing_count == 0;
 
+    if summary_only {
+        let count = |severity: Severity| {
+            result
+                .violations_at_or_above(severity)
+                .filter(|v| v.severity == severity)
+                .count()
+        };
+        let summary = CheckSummary {
+            passed,
+            overall: result.score.as_ref().map(|s| s.overall),
+            error_count: count(Severity::Error),
+            warning_count: count(Severity::Warning),
+            info_count: count(Severity::Info),
+            failing_count,
+        };
+        return (to_json(&summary, compact), passed);
+    }
+
     let output = CheckOutput {
         result: AnalysisOutput::from(result),
         check: CheckStatus {
@@ -144,13 +299,52 @@ pub fn format_check(result: &AnalysisResult, fail_on: Severity, compact: bool) -
         },
     };
 
-    let json = if compact {
-        serde_json::to_string(&output).expect("CheckOutput should be serializable")
-    } else {
-        serde_json::to_string_pretty(&output).expect("CheckOutput should be serializable")
-    };
+    (to_json(&output, compact), passed)
+}
+
+/// Format a side-by-side comparison of two trees as JSON.
+pub fn format_comparison(comparison: &boundary_core::compare::Comparison, compact: bool) -> String {
+    to_json(comparison, compact)
+}
+
+/// Format R.C. Martin package metrics as JSON (`boundary metrics`).
+pub fn format_package_metrics(
+    packages: &[boundary_core::metrics::PackageMetric],
+    compact: bool,
+) -> String {
+    to_json(&serde_json::json!({ "packages": packages }), compact)
+}
 
-    (json, passed)
+/// Format per-component coupling metrics as JSON (`boundary metrics --per-component`).
+pub fn format_component_metrics(
+    components: &[boundary_core::metrics::ComponentMetric],
+    compact: bool,
+) -> String {
+    to_json(&serde_json::json!({ "components": components }), compact)
+}
+
+/// Format change hotspots as JSON (`boundary hotspots`).
+pub fn format_hotspots(hotspots: &[boundary_core::churn::Hotspot], compact: bool) -> String {
+    to_json(&serde_json::json!({ "hotspots": hotspots }), compact)
+}
+
+/// Format violation explanations (`boundary explain`) as JSON.
+pub fn format_explanations(
+    explanations: &[boundary_core::explain::Explanation],
+    compact: bool,
+) -> String {
+    to_json(
+        &serde_json::json!({ "explanations": explanations }),
+        compact,
+    )
+}
+
+fn to_json<T: Serialize>(value: &T, compact: bool) -> String {
+    if compact {
+        serde_json::to_string(value).expect("check output should be serializable")
+    } else {
+        serde_json::to_string_pretty(value).expect("check output should be serializable")
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +369,8 @@ mod tests {
                 },
                 message: "Domain depends on infrastructure".to_string(),
                 suggestion: Some("Use a port interface".to_string()),
+                fix: None,
+                doc_url: None,
             }]
         } else {
             vec![]
@@ -187,6 +383,7 @@ mod tests {
                 layer_conformance: 80.0,
                 dependency_compliance: 70.0,
                 interface_coverage: 75.0,
+                not_applicable: Vec::new(),
             }),
             violations,
             component_count: 5,
@@ -195,6 +392,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -209,6 +413,42 @@ mod tests {
         assert_eq!(parsed["dependency_count"], 3);
     }
 
+    #[test]
+    fn test_read_report_round_trips() {
+        let result = sample_result(true);
+        let read = read_report(&format_report(&result, false)).unwrap();
+        assert_eq!(read.component_count, 5);
+        assert_eq!(read.violations.len(), 1);
+        assert_eq!(read.violations[0].kind.rule_id().as_str(), "L001");
+        assert!((read.score.unwrap().overall - 75.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_read_report_keeps_fingerprints() {
+        let mut result = sample_result(true);
+        result.project_root = Some(PathBuf::from("/builds/acme/app"));
+        result.violations[0].location.file = PathBuf::from("/builds/acme/app/domain/user.go");
+        let read = read_report(&format_report(&result, false)).unwrap();
+        assert_eq!(read.project_root, result.project_root);
+        assert_eq!(
+            read.violations[0].fingerprint(read.root()),
+            result.violations[0].fingerprint(result.root())
+        );
+        assert_eq!(
+            read.violations[0].fingerprint(read.root()),
+            sample_result(true).violations[0].fingerprint(Path::new("."))
+        );
+    }
+
+    #[test]
+    fn test_read_report_rejects_other_schema_version() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(&format_report(&sample_result(false), false)).unwrap();
+        value["schema_version"] = serde_json::json!(SCHEMA_VERSION + 1);
+        let err = read_report(&value.to_string()).unwrap_err();
+        assert!(err.to_string().contains("schema version"), "{err}");
+    }
+
     #[test]
     fn test_format_report_compact_is_single_line() {
         let result = sample_result(false);
@@ -224,10 +464,23 @@ mod tests {
         assert!(json.contains('\n'), "pretty JSON should be multiline");
     }
 
+    #[test]
+    fn test_write_report_matches_format_report() {
+        let result = sample_result(true);
+        for compact in [false, true] {
+            let mut streamed = Vec::new();
+            write_report(&result, compact, &mut streamed).unwrap();
+            assert_eq!(
+                String::from_utf8(streamed).unwrap(),
+                format_report(&result, compact)
+            );
+        }
+    }
+
     #[test]
     fn test_format_check_passed() {
         let result = sample_result(false);
-        let (json, passed) = format_check(&result, Severity::Error, false);
+        let (json, passed) = format_check(&result, Severity::Error, false, false);
         assert!(passed);
         let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
         assert_eq!(parsed["check"]["passed"], true);
@@ -238,7 +491,7 @@ mod tests {
     #[test]
     fn test_format_check_failed() {
         let result = sample_result(true);
-        let (json, passed) = format_check(&result, Severity::Error, false);
+        let (json, passed) = format_check(&result, Severity::Error, false, false);
         assert!(!passed);
         let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
         assert_eq!(parsed["check"]["passed"], false);
@@ -248,11 +501,39 @@ mod tests {
     #[test]
     fn test_format_check_compact() {
         let result = sample_result(true);
-        let (json, _) = format_check(&result, Severity::Error, true);
+        let (json, _) = format_check(&result, Severity::Error, true, false);
         assert!(!json.contains('\n'), "compact JSON should be single line");
         let _: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
     }
 
+    #[test]
+    fn test_format_check_summary_only() {
+        let result = sample_result(true);
+        let (json, passed) = format_check(&result, Severity::Error, false, true);
+        assert!(!passed);
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
+        assert_eq!(parsed["error_count"], 1);
+        assert_eq!(parsed["warning_count"], 0);
+        assert_eq!(parsed["failing_count"], 1);
+        assert!(parsed.get("violations").is_none());
+    }
+
+    #[test]
+    fn test_format_check_counts_truncated_violations() {
+        let mut result = sample_result(true);
+        result.truncate_violations(0);
+        let (json, passed) = format_check(&result, Severity::Error, false, false);
+        assert!(!passed);
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
+        assert_eq!(parsed["check"]["failing_violation_count"], 1);
+
+        let (json, passed) = format_check(&result, Severity::Error, false, true);
+        assert!(!passed);
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
+        assert_eq!(parsed["error_count"], 1);
+        assert_eq!(parsed["failing_count"], 1);
+    }
+
     #[test]
     fn test_violation_doc_url_in_json() {
         let result = sample_result(true);
@@ -273,7 +554,7 @@ mod tests {
     #[test]
     fn test_check_flattened_fields() {
         let result = sample_result(true);
-        let (json, _) = format_check(&result, Severity::Error, false);
+        let (json, _) = format_check(&result, Severity::Error, false, false);
         let parsed: serde_json::Value = serde_json::from_str(&json).expect("should be valid JSON");
         // Flattened AnalysisResult fields should be at top level
         assert!(parsed.get("score").is_some());
@@ -1,6 +1,11 @@
+pub mod badge;
+pub mod cytoscape;
 pub mod diagram;
 pub mod dot;
 pub mod forensics;
+pub mod gitlab;
+pub mod html;
 pub mod json;
 pub mod markdown;
+pub mod sarif;
 pub mod text;
@@ -1,6 +1,55 @@
+use boundary_core::compare::{Change, Comparison};
 use boundary_core::metrics::AnalysisResult;
 use boundary_core::types::Severity;
 
+/// Format a side-by-side comparison of two trees as Markdown.
+pub fn format_comparison(comparison: &Comparison) -> String {
+    let mut out = String::new();
+
+    out.push_str("# Boundary - Comparison\n\n");
+    out.push_str(&format!("- **A:** `{}`\n", comparison.left));
+    out.push_str(&format!("- **B:** `{}`\n\n", comparison.right));
+
+    let change = |change: Change, delta: String| match change {
+        Change::Improved => format!("{delta} improved"),
+        Change::Regressed => format!("{delta} regressed"),
+        Change::Unchanged => "unchanged".to_string(),
+    };
+    let points = |v: Option<f64>| match v {
+        Some(v) => format!("{v:.1}"),
+        None => "n/a".to_string(),
+    };
+
+    out.push_str("## Scores\n\n");
+    out.push_str("| Metric | A | B | Change |\n");
+    out.push_str("|--------|---|---|--------|\n");
+    for score in &comparison.scores {
+        let delta = score.delta.map_or_else(String::new, |d| format!("{d:+.1}"));
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            score.metric,
+            points(score.left),
+            points(score.right),
+            change(score.change, delta)
+        ));
+    }
+
+    out.push_str("\n## Violations\n\n");
+    out.push_str("| Rule | A | B | Change |\n");
+    out.push_str("|------|---|---|--------|\n");
+    for count in std::iter::once(&comparison.violations).chain(&comparison.rules) {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            count.rule,
+            count.left,
+            count.right,
+            change(count.change, format!("{:+}", count.delta))
+        ));
+    }
+
+    out
+}
+
 /// Format a full analysis report as Markdown.
 pub fn format_report(result: &AnalysisResult) -> String {
     let mut out = String::new();
@@ -12,22 +61,29 @@ pub fn format_report(result: &AnalysisResult) -> String {
         out.push_str("## Scores\n\n");
         out.push_str("| Metric | Score |\n");
         out.push_str("|--------|-------|\n");
+        let points = |metric: &str, value: f64| {
+            if score.is_applicable(metric) {
+                format!("{value:.1}/100")
+            } else {
+                "n/a".to_string()
+            }
+        };
         out.push_str(&format!("| **Overall** | **{:.1}/100** |\n", score.overall));
         out.push_str(&format!(
-            "| Structural Presence | {:.1}/100 |\n",
-            score.structural_presence
+            "| Structural Presence | {} |\n",
+            points("structural_presence", score.structural_presence)
         ));
         out.push_str(&format!(
-            "| Layer Conformance | {:.1}/100 |\n",
-            score.layer_conformance
+            "| Layer Conformance | {} |\n",
+            points("layer_conformance", score.layer_conformance)
         ));
         out.push_str(&format!(
-            "| Dependency Compliance | {:.1}/100 |\n",
-            score.dependency_compliance
+            "| Dependency Compliance | {} |\n",
+            points("dependency_compliance", score.dependency_compliance)
         ));
         out.push_str(&format!(
-            "| Interface Coverage | {:.1}/100 |\n",
-            score.interface_coverage
+            "| Interface Coverage | {} |\n",
+            points("interface_coverage", score.interface_coverage)
         ));
     }
 
@@ -132,7 +188,7 @@ pub fn format_report(result: &AnalysisResult) -> String {
     } else {
         out.push_str(&format!(
             "\n## Violations ({} found)\n\n",
-            result.violations.len()
+            result.violations_total.unwrap_or(result.violations.len())
         ));
         out.push_str("| Rule | Severity | Name | Location | Message |\n");
         out.push_str("|------|----------|------|----------|--------|\n");
@@ -153,6 +209,11 @@ pub fn format_report(result: &AnalysisResult) -> String {
                 v.message
             ));
         }
+
+        let omitted = result.omitted_violations();
+        if omitted > 0 {
+            out.push_str(&format!("\n… and {omitted} more\n"));
+        }
     }
 
     out.push('\n');
@@ -260,11 +321,7 @@ pub fn format_multi_service_report(multi: &boundary_core::metrics::MultiServiceR
 
 /// Format a check result as Markdown. Returns (markdown, passed).
 pub fn format_check(result: &AnalysisResult, fail_on: Severity) -> (String, bool) {
-    let failing_violations: Vec<_> = result
-        .violations
-        .iter()
-        .filter(|v| v.severity >= fail_on)
-        .collect();
+    let failing_violations: Vec<_> = result.violations_at_or_above(fail_on).collect();
 
     let passed = failing_violations.is_empty();
 
@@ -297,6 +354,7 @@ mod tests {
                 layer_conformance: 90.0,
                 dependency_compliance: 80.0,
                 interface_coverage: 85.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 3,
@@ -305,6 +363,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         };
         let report = format_report(&result);
         assert!(report.contains("85.0/100"));
@@ -320,6 +385,7 @@ mod tests {
                 layer_conformance: 100.0,
                 dependency_compliance: 100.0,
                 interface_coverage: 100.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 0,
@@ -328,6 +394,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         };
         let (report, passed) = format_check(&result, Severity::Error);
         assert!(passed);
@@ -0,0 +1,302 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use boundary_core::metrics::{AnalysisResult, MultiServiceResult};
+use boundary_core::types::{Severity, Violation};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// A SARIF 2.1.0 log with a single run, the subset GitHub code scanning reads
+/// (<https://docs.github.com/en/code-security/code-scanning/integrating-with-code-scanning/sarif-support-for-code-scanning>).
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run; 1],
+}
+
+#[derive(Serialize)]
+struct Run {
+    tool: Tool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: String,
+    name: String,
+    short_description: Message,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: [Location; 1],
+    partial_fingerprints: PartialFingerprints,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+}
+
+#[derive(Serialize)]
+struct PartialFingerprints {
+    #[serde(rename = "boundaryFingerprint/v1")]
+    boundary: String,
+}
+
+/// SARIF level for a violation.
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// Build the run, registering one rule per rule ID in order of first appearance.
+/// A rule's help is the first suggestion given for it. Each violation comes with the
+/// root of the project it was found in.
+fn run<'a>(violations: impl Iterator<Item = (&'a Violation, &'a Path)>) -> Run {
+    let mut rules: Vec<Rule> = Vec::new();
+    let mut results = Vec::new();
+    for (v, root) in violations {
+        let rule_id = v.kind.rule_id().to_string();
+        let rule_index = match rules.iter().position(|r| r.id == rule_id) {
+            Some(index) => index,
+            None => {
+                rules.push(Rule {
+                    id: rule_id.clone(),
+                    name: v.kind.name().to_string(),
+                    short_description: Message {
+                        text: v.kind.name().to_string(),
+                    },
+                    help: None,
+                    help_uri: v.help_url(),
+                });
+                rules.len() - 1
+            }
+        };
+        let rule = &mut rules[rule_index];
+        if rule.help.is_none() {
+            rule.help = v.suggestion.clone().map(|text| Message { text });
+        }
+
+        results.push(SarifResult {
+            rule_id,
+            rule_index,
+            level: level(v.severity),
+            message: Message {
+                text: v.message.clone(),
+            },
+            locations: [Location {
+                physical_location: PhysicalLocation {
+                    artifact_location: ArtifactLocation {
+                        uri: v.relative_file(root),
+                    },
+                    region: Region {
+                        start_line: v.location.line.max(1),
+                        start_column: v.location.column.max(1),
+                    },
+                },
+            }],
+            partial_fingerprints: PartialFingerprints {
+                boundary: v.fingerprint(root),
+            },
+        });
+    }
+
+    Run {
+        tool: Tool {
+            driver: Driver {
+                name: "boundary",
+                version: env!("CARGO_PKG_VERSION"),
+                information_uri: "https://github.com/rebelopsio/boundary",
+                rules,
+            },
+        },
+        results,
+    }
+}
+
+fn to_json(run: Run) -> String {
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: [run],
+    };
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
+/// Format the violations of an analysis as a SARIF 2.1.0 log.
+pub fn format_report(result: &AnalysisResult) -> String {
+    to_json(run(result.violations.iter().map(|v| (v, result.root()))))
+}
+
+/// Format the violations of every service as one SARIF 2.1.0 log.
+pub fn format_multi_service_report(multi: &MultiServiceResult) -> String {
+    to_json(run(multi.services.iter().flat_map(|s| {
+        s.result.violations.iter().map(|v| (v, s.result.root()))
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boundary_core::types::{ArchLayer, SourceLocation, ViolationKind};
+    use std::path::PathBuf;
+
+    fn violation(kind: ViolationKind, severity: Severity, suggestion: Option<&str>) -> Violation {
+        Violation {
+            kind,
+            severity,
+            location: SourceLocation {
+                file: PathBuf::from("./internal/domain/user.go"),
+                line: 7,
+                column: 2,
+            },
+            message: "domain layer depends on infrastructure layer".to_string(),
+            suggestion: suggestion.map(str::to_string),
+            fix: None,
+            doc_url: None,
+        }
+    }
+
+    fn layer_boundary() -> ViolationKind {
+        ViolationKind::LayerBoundary {
+            from_layer: ArchLayer::Domain,
+            to_layer: ArchLayer::Infrastructure,
+        }
+    }
+
+    fn report(violations: &[Violation]) -> serde_json::Value {
+        report_in(violations, Path::new("."))
+    }
+
+    fn report_in(violations: &[Violation], root: &Path) -> serde_json::Value {
+        serde_json::from_str(&to_json(run(violations.iter().map(|v| (v, root))))).unwrap()
+    }
+
+    #[test]
+    fn test_result_fields() {
+        let sarif = report(&[violation(layer_boundary(), Severity::Error, None)]);
+        assert_eq!(sarif["version"], "2.1.0");
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "L001");
+        assert_eq!(result["ruleIndex"], 0);
+        assert_eq!(result["level"], "error");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            "internal/domain/user.go"
+        );
+        assert_eq!(location["region"]["startLine"], 7);
+        assert_eq!(location["region"]["startColumn"], 2);
+        assert_eq!(
+            result["partialFingerprints"]["boundaryFingerprint/v1"]
+                .as_str()
+                .unwrap()
+                .len(),
+            64
+        );
+    }
+
+    #[test]
+    fn test_uri_is_relative_to_project_root() {
+        let mut v = violation(layer_boundary(), Severity::Error, None);
+        v.location.file = PathBuf::from("/home/runner/work/app/internal/domain/user.go");
+        let sarif = report_in(&[v], Path::new("/home/runner/work/app"));
+        assert_eq!(
+            sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]
+                ["uri"],
+            "internal/domain/user.go"
+        );
+    }
+
+    #[test]
+    fn test_one_rule_per_kind_with_suggestion_as_help() {
+        let cycle = ViolationKind::CircularDependency {
+            cycle: vec![],
+            layers: vec![],
+            cross_layer: false,
+        };
+        let sarif = report(&[
+            violation(layer_boundary(), Severity::Error, None),
+            violation(cycle, Severity::Info, None),
+            violation(
+                layer_boundary(),
+                Severity::Warning,
+                Some("Depend on a port"),
+            ),
+        ]);
+        let rules = sarif["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap();
+        let ids: Vec<&str> = rules.iter().map(|r| r["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, ["L001", "D001"]);
+        assert_eq!(rules[0]["help"]["text"], "Depend on a port");
+        assert!(rules[1].get("help").is_none());
+        assert!(rules[0]["helpUri"]
+            .as_str()
+            .unwrap()
+            .starts_with("https://"));
+
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results[1]["ruleIndex"], 1);
+        assert_eq!(results[1]["level"], "note");
+        assert_eq!(results[2]["ruleIndex"], 0);
+        assert_eq!(results[2]["level"], "warning");
+    }
+}
@@ -1,5 +1,6 @@
 use colored::Colorize;
 
+use boundary_core::compare::{Change, Comparison};
 use boundary_core::metrics::AnalysisResult;
 use boundary_core::types::Severity;
 
@@ -66,6 +67,10 @@ pub fn format_report(result: &AnalysisResult) -> String {
         result.dependency_count,
     ));
 
+    if let Some(debt) = &result.debt {
+        out.push_str(&format_debt_section(debt));
+    }
+
     // Metrics
     if let Some(ref metrics) = result.metrics {
         out.push_str(&format!("\n{}\n{}\n", "Metrics".bold(), "-".repeat(40)));
@@ -93,6 +98,37 @@ pub fn format_report(result: &AnalysisResult) -> String {
             "  Dependency depth: max={}, avg={:.1}\n",
             metrics.dependency_depth.max_depth, metrics.dependency_depth.avg_depth
         ));
+        if let Some(pair) = &metrics.worst_layer_pair {
+            out.push_str(&format!(
+                "  Most violating direction: {} → {} ({} edge{})\n",
+                pair.from,
+                pair.to,
+                pair.count,
+                if pair.count == 1 { "" } else { "s" }
+            ));
+        }
+
+        if !metrics.layer_metrics.is_empty() {
+            out.push_str(&format!(
+                "  Layer metrics:\n    {:<16} {:>4} {:>4} {:>5} {:>5} {:>5}\n",
+                "Layer", "Ca", "Ce", "A", "I", "D"
+            ));
+            for m in &metrics.layer_metrics {
+                let zone = m
+                    .zone
+                    .as_deref()
+                    .map_or_else(String::new, |z| format!("  zone: {z}"));
+                out.push_str(&format!(
+                    "    {:<16} {:>4} {:>4} {:>5.2} {:>5.2} {:>5.2}{zone}\n",
+                    capitalize(&m.layer.to_string()),
+                    m.afferent_coupling,
+                    m.efferent_coupling,
+                    m.abstractness,
+                    m.instability,
+                    m.distance
+                ));
+            }
+        }
 
         if let Some(ref coverage) = metrics.classification_coverage {
             out.push_str(&format!("\n{}\n", "Classification Coverage".bold()));
@@ -169,7 +205,7 @@ pub fn format_report(result: &AnalysisResult) -> String {
         out.push_str(&format!(
             "\n{} ({} found)\n{}\n",
             "Violations".red().bold(),
-            result.violations.len(),
+            result.violations_total.unwrap_or(result.violations.len()),
             "-".repeat(40),
         ));
 
@@ -191,10 +227,15 @@ pub fn format_report(result: &AnalysisResult) -> String {
             if let Some(ref suggestion) = v.suggestion {
                 out.push_str(&format!("    {}: {}\n", "Suggestion".cyan(), suggestion));
             }
-            if let Some(url) = v.kind.doc_url() {
-                out.push_str(&format!("    {}: {}\n", "Docs".cyan(), url));
+            if let Some(url) = v.help_url() {
+                out.push_str(&format!("    {}\n", format!("Docs: {url}").dimmed()));
             }
         }
+
+        let omitted = result.omitted_violations();
+        if omitted > 0 {
+            out.push_str(&format!("\n  … and {omitted} more\n"));
+        }
     }
 
     out.push('\n');
@@ -214,27 +255,49 @@ fn format_score_section(score: &boundary_core::metrics::ArchitectureScore) -> St
         overall_str.red()
     };
 
+    let pct = |metric: &str, value: f64| {
+        if score.is_applicable(metric) {
+            format!("{}%", value.round() as i64)
+        } else {
+            "n/a".to_string()
+        }
+    };
     out.push_str(&format!("{}: {}\n", "Overall Score".bold(), overall_color));
     out.push_str(&format!(
-        "  Structural Presence: {}%\n",
-        score.structural_presence.round() as i64
+        "  Structural Presence: {}\n",
+        pct("structural_presence", score.structural_presence)
     ));
     out.push_str(&format!(
-        "  Layer Conformance: {}%\n",
-        score.layer_conformance.round() as i64
+        "  Layer Conformance: {}\n",
+        pct("layer_conformance", score.layer_conformance)
     ));
     out.push_str(&format!(
-        "  Dependency Compliance: {}%\n",
-        score.dependency_compliance.round() as i64
+        "  Dependency Compliance: {}\n",
+        pct("dependency_compliance", score.dependency_compliance)
     ));
     out.push_str(&format!(
-        "  Interface Coverage: {}%\n",
-        score.interface_coverage.round() as i64
+        "  Interface Coverage: {}\n",
+        pct("interface_coverage", score.interface_coverage)
     ));
 
     out
 }
 
+fn format_debt_section(debt: &boundary_core::metrics::ArchitectureDebt) -> String {
+    let mut out = format!(
+        "\n{}: {:.1} hours (estimated)\n",
+        "Architecture Debt".bold(),
+        debt.total_hours
+    );
+    for entry in &debt.by_rule {
+        out.push_str(&format!(
+            "  {:<6} {:>4} × {:.1}h = {:.1}h\n",
+            entry.rule, entry.count, entry.hours_each, entry.hours
+        ));
+    }
+    out
+}
+
 fn capitalize(s: &str) -> String {
     let mut chars = s.chars();
     match chars.next() {
@@ -243,6 +306,209 @@ fn capitalize(s: &str) -> String {
     }
 }
 
+/// Format R.C. Martin package metrics for terminal output (`boundary metrics`).
+pub fn format_package_metrics(packages: &[boundary_core::metrics::PackageMetric]) -> String {
+    let mut out = format!("\n{}\n", "Package Metrics".bold());
+    if packages.is_empty() {
+        out.push_str("  No packages to report.\n");
+        return out;
+    }
+    out.push_str(&format!(
+        "  {:<32} {:>5} {:>5} {:>5}  Zone\n",
+        "Package", "A", "I", "D"
+    ));
+    out.push_str(&format!("  {}\n", "-".repeat(60)));
+    for pm in packages {
+        out.push_str(&format!(
+            "  {:<32} {:>5.2} {:>5.2} {:>5.2}  {}\n",
+            pm.package,
+            pm.abstractness,
+            pm.instability,
+            pm.distance,
+            pm.zone.as_deref().unwrap_or("")
+        ));
+    }
+    out
+}
+
+/// Format change hotspots for terminal output, highest score first.
+pub fn format_hotspots(hotspots: &[boundary_core::churn::Hotspot]) -> String {
+    let mut out = format!("\n{}\n", "Hotspots".bold());
+    if hotspots.is_empty() {
+        out.push_str("  No files with violations.\n");
+        return out;
+    }
+    out.push_str(&format!(
+        "  {:>6} {:>8} {:>11}  File\n",
+        "Score", "Commits", "Violations"
+    ));
+    out.push_str(&format!("  {}\n", "-".repeat(60)));
+    for h in hotspots {
+        out.push_str(&format!(
+            "  {:>6} {:>8} {:>11}  {}\n",
+            h.score,
+            h.commits,
+            h.violations,
+            h.file.display()
+        ));
+    }
+    out
+}
+
+/// Format violation explanations (`boundary explain`) for terminal output.
+pub fn format_explanations(explanations: &[boundary_core::explain::Explanation]) -> String {
+    let mut out = String::new();
+    for e in explanations {
+        let severity_str = match e.severity {
+            Severity::Error => "ERROR".red().bold().to_string(),
+            Severity::Warning => "WARN".yellow().bold().to_string(),
+            Severity::Info => "INFO".blue().bold().to_string(),
+        };
+        out.push_str(&format!(
+            "\n{} {} [{severity_str}] {}\n",
+            e.rule.bold(),
+            e.name,
+            e.location
+        ));
+        out.push_str(&format!("  {}\n", format!("id: {}", e.id).dimmed()));
+        out.push_str(&format!("\n  {}\n", e.message));
+
+        if !e.layers.is_empty() {
+            let layers: Vec<String> = e.layers.iter().map(ToString::to_string).collect();
+            out.push_str(&format!("\n  {}: {}\n", "Layers".bold(), layers.join(", ")));
+        }
+        if !e.chain.is_empty() {
+            let title = if e.is_cycle {
+                format!("Cycle ({} hops)", e.chain.len())
+            } else {
+                "Dependency chain".to_string()
+            };
+            out.push_str(&format!("\n  {}:\n", title.bold()));
+            let layer = |l: Option<boundary_core::types::ArchLayer>| {
+                l.map(|l| format!(" ({l})")).unwrap_or_default()
+            };
+            for (i, hop) in e.chain.iter().enumerate() {
+                out.push_str(&format!(
+                    "    {}. {}{} -> {}{}\n",
+                    i + 1,
+                    hop.from,
+                    layer(hop.from_layer),
+                    hop.to,
+                    layer(hop.to_layer)
+                ));
+                let import = hop
+                    .import_path
+                    .as_deref()
+                    .map(|p| format!(" (import: {p})"))
+                    .unwrap_or_default();
+                out.push_str(&format!(
+                    "       {}\n",
+                    format!("at {}{import}", hop.location).dimmed()
+                ));
+            }
+        }
+
+        if let Some(suggestion) = &e.suggestion {
+            out.push_str(&format!("\n  {}: {}\n", "Suggestion".cyan(), suggestion));
+        }
+        if let Some(url) = &e.doc_url {
+            out.push_str(&format!("  {}\n", format!("Docs: {url}").dimmed()));
+        }
+    }
+    out
+}
+
+/// Format per-component coupling metrics for terminal output, in report order
+/// (most distant from the main sequence first).
+pub fn format_component_metrics(components: &[boundary_core::metrics::ComponentMetric]) -> String {
+    let mut out = format!("\n{}\n", "Component Metrics".bold());
+    if components.is_empty() {
+        out.push_str("  No coupled components to report.\n");
+        return out;
+    }
+    out.push_str(&format!(
+        "  {:<32} {:<14} {:>4} {:>4} {:>5} {:>5}  Zone\n",
+        "Component", "Kind", "Ca", "Ce", "I", "D"
+    ));
+    out.push_str(&format!("  {}\n", "-".repeat(76)));
+    for cm in components {
+        out.push_str(&format!(
+            "  {:<32} {:<14} {:>4} {:>4} {:>5.2} {:>5.2}  {}\n",
+            cm.name,
+            cm.kind,
+            cm.afferent_coupling,
+            cm.efferent_coupling,
+            cm.instability,
+            cm.distance,
+            cm.zone.as_deref().unwrap_or("")
+        ));
+    }
+    out
+}
+
+/// Format a side-by-side comparison of two trees for terminal output.
+pub fn format_comparison(comparison: &Comparison) -> String {
+    let mut out = String::new();
+
+    out.push_str(&format!("\n{}\n", "Boundary - Comparison".bold()));
+    out.push_str(&format!("{}\n\n", "=".repeat(40)));
+    out.push_str(&format!("  A: {}\n", comparison.left));
+    out.push_str(&format!("  B: {}\n\n", comparison.right));
+
+    let change = |change: Change, delta: String| match change {
+        Change::Improved => format!("{delta} improved").green().to_string(),
+        Change::Regressed => format!("{delta} regressed").red().to_string(),
+        Change::Unchanged => "unchanged".dimmed().to_string(),
+    };
+
+    out.push_str(&format!("{}\n", "Scores".bold()));
+    out.push_str(&format!(
+        "  {:<24} {:>8} {:>8}  Change\n",
+        "Metric", "A", "B"
+    ));
+    out.push_str(&format!("  {}\n", "-".repeat(56)));
+    let pct = |v: Option<f64>| match v {
+        Some(v) => format!("{v:.1}"),
+        None => "n/a".to_string(),
+    };
+    for score in &comparison.scores {
+        let label = score
+            .metric
+            .split('_')
+            .map(capitalize)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let delta = score.delta.map_or_else(String::new, |d| format!("{d:+.1}"));
+        out.push_str(&format!(
+            "  {:<24} {:>8} {:>8}  {}\n",
+            label,
+            pct(score.left),
+            pct(score.right),
+            change(score.change, delta)
+        ));
+    }
+
+    out.push_str(&format!("\n{}\n", "Violations".bold()));
+    out.push_str(&format!("  {:<24} {:>8} {:>8}  Change\n", "Rule", "A", "B"));
+    out.push_str(&format!("  {}\n", "-".repeat(56)));
+    for count in std::iter::once(&comparison.violations).chain(&comparison.rules) {
+        let label = if count.rule == "total" {
+            "Total".to_string()
+        } else {
+            count.rule.clone()
+        };
+        out.push_str(&format!(
+            "  {:<24} {:>8} {:>8}  {}\n",
+            label,
+            count.left,
+            count.right,
+            change(count.change, format!("{:+}", count.delta))
+        ));
+    }
+
+    out
+}
+
 /// Format a multi-service analysis report for terminal output.
 pub fn format_multi_service_report(multi: &boundary_core::metrics::MultiServiceResult) -> String {
     let mut out = String::new();
@@ -284,6 +550,9 @@ pub fn format_multi_service_report(multi: &boundary_core::metrics::MultiServiceR
     if let Some(agg_score) = &multi.aggregate.score {
         out.push_str(&format_score_section(agg_score));
     }
+    if let Some(debt) = &multi.aggregate.debt {
+        out.push_str(&format_debt_section(debt));
+    }
 
     // Shared modules
     if !multi.shared_modules.is_empty() {
@@ -341,6 +610,28 @@ pub fn format_multi_service_report(multi: &boundary_core::metrics::MultiServiceR
     out
 }
 
+/// Format a check result for CI use. Returns (text, passed).
+pub fn format_check(result: &AnalysisResult, fail_on: Severity) -> (String, bool) {
+    let failing_violations: Vec<_> = result.violations_at_or_above(fail_on).collect();
+
+    let passed = failing_violations.is_empty();
+
+    let mut out = format_report(result);
+
+    if passed {
+        out.push_str(&format!("{}\n", "CHECK PASSED".green().bold()));
+    } else {
+        out.push_str(&format!(
+            "{}: {} violation(s) at severity {} or above\n",
+            "CHECK FAILED".red().bold(),
+            failing_violations.len(),
+            fail_on,
+        ));
+    }
+
+    (out, passed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -354,6 +645,7 @@ mod tests {
                 layer_conformance: 100.0,
                 dependency_compliance: 100.0,
                 interface_coverage: 100.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 2,
@@ -362,6 +654,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -373,6 +672,7 @@ mod tests {
                 layer_conformance: 100.0,
                 dependency_compliance: 100.0,
                 interface_coverage: 100.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 0,
@@ -381,6 +681,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -392,6 +699,7 @@ mod tests {
                 layer_conformance: 100.0,
                 dependency_compliance: 100.0,
                 interface_coverage: 100.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 0,
@@ -400,6 +708,13 @@ mod tests {
             metrics: None,
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -421,6 +736,7 @@ mod tests {
                 layer_conformance: 100.0,
                 dependency_compliance: 100.0,
                 interface_coverage: 100.0,
+                not_applicable: Vec::new(),
             }),
             violations: vec![],
             component_count: 4,
@@ -445,9 +761,18 @@ mod tests {
                     coverage_percentage: 100.0,
                     unclassified_paths: vec![],
                 }),
+                worst_layer_pair: None,
+                layer_metrics: vec![],
             }),
             package_metrics: vec![],
             pattern_detection: None,
+            violations_total: None,
+            truncated_violations: Vec::new(),
+            config: None,
+            debt: None,
+            generated_files: Vec::new(),
+            generated_exempt: false,
+            project_root: None,
         }
     }
 
@@ -529,29 +854,3 @@ mod tests {
         );
     }
 }
-
-/// Format a check result for CI use. Returns (text, passed).
-pub fn format_check(result: &AnalysisResult, fail_on: Severity) -> (String, bool) {
-    let failing_violations: Vec<_> = result
-        .violations
-        .iter()
-        .filter(|v| v.severity >= fail_on)
-        .collect();
-
-    let passed = failing_violations.is_empty();
-
-    let mut out = format_report(result);
-
-    if passed {
-        out.push_str(&format!("{}\n", "CHECK PASSED".green().bold()));
-    } else {
-        out.push_str(&format!(
-            "{}: {} violation(s) at severity {} or above\n",
-            "CHECK FAILED".red().bold(),
-            failing_violations.len(),
-            fail_on,
-        ));
-    }
-
-    (out, passed)
-}
@@ -1,17 +1,40 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use anyhow::{Context, Result};
 use tree_sitter::{Language, Parser, Query, QueryCursor, StreamingIterator};
 
-use boundary_core::analyzer::{LanguageAnalyzer, ParsedFile};
+use boundary_core::analyzer::{grammar_self_test, Extracted, LanguageAnalyzer, ParsedFile};
 use boundary_core::types::*;
 
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "rust";
+
+/// Known-good source checked by [`RustAnalyzer::new`] against the compiled queries.
+const SELF_TEST_SNIPPET: &str = r#"
+use crate::money::Amount;
+
+pub struct Order {
+    pub id: String,
+    pub total: Amount,
+}
+
+impl Order {
+    pub fn pay(&mut self) {}
+}
+
+pub trait OrderRepository {
+    fn save(&self, order: &Order) -> Result<(), String>;
+}
+"#;
+
 /// Rust language analyzer using tree-sitter.
 pub struct RustAnalyzer {
     language: Language,
     trait_query: Query,
     struct_query: Query,
     impl_query: Query,
+    method_query: Query,
     use_query: Query,
 }
 
@@ -54,6 +77,22 @@ impl RustAnalyzer {
         )
         .context("failed to compile impl query")?;
 
+        // Methods of inherent impls only; trait impls implement a port instead.
+        let method_query = Query::new(
+            &language,
+            r#"
+            (impl_item
+              !trait
+              type: [
+                (type_identifier) @type_name
+                (generic_type type: (type_identifier) @type_name)
+              ]
+              body: (declaration_list
+                (function_item) @method))
+            "#,
+        )
+        .context("failed to compile method query")?;
+
         let use_query = Query::new(
             &language,
             r#"
@@ -63,19 +102,34 @@ impl RustAnalyzer {
         )
         .context("failed to compile use query")?;
 
-        Ok(Self {
+        let analyzer = Self {
             language,
             trait_query,
             struct_query,
             impl_query,
+            method_query,
             use_query,
-        })
+        };
+        grammar_self_test(
+            &analyzer,
+            "Rust",
+            "src/order.rs",
+            SELF_TEST_SNIPPET,
+            &[
+                Extracted::Interfaces,
+                Extracted::InterfaceMethods,
+                Extracted::Types,
+                Extracted::Methods,
+                Extracted::Imports,
+            ],
+        )?;
+        Ok(analyzer)
     }
 }
 
 impl LanguageAnalyzer for RustAnalyzer {
     fn language(&self) -> &'static str {
-        "rust"
+        LANGUAGE
     }
 
     fn file_extensions(&self) -> &[&str] {
@@ -110,13 +164,31 @@ impl LanguageAnalyzer for RustAnalyzer {
         // Enrich structs with impl info (adapter classification)
         enrich_with_impls(&self.impl_query, parsed, &module_path, &mut components);
 
+        // Attach inherent methods to their structs
+        let methods = extract_impl_methods(&self.method_query, parsed, &module_path);
+        associate_methods(&mut components, &methods);
+
         components
     }
 
+    fn extract_impl_methods(&self, parsed: &ParsedFile) -> Vec<ImplMethods> {
+        let module_path = derive_module_path(&parsed.path);
+        let mut declared = Vec::new();
+        extract_structs(&self.struct_query, parsed, &module_path, &mut declared);
+
+        let mut impl_methods: Vec<ImplMethods> =
+            extract_impl_methods(&self.method_query, parsed, &module_path)
+                .into_iter()
+                .filter(|(id, _)| !declared.iter().any(|c| c.id == *id))
+                .map(|(type_id, methods)| ImplMethods { type_id, methods })
+                .collect();
+        impl_methods.sort_by(|a, b| a.type_id.0.cmp(&b.type_id.0));
+        impl_methods
+    }
+
     fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
         let mut deps = Vec::new();
         let module_path = derive_module_path(&parsed.path);
-        let from_id = ComponentId::new(&module_path, "<file>");
 
         let mut cursor = QueryCursor::new();
         let path_idx = self
@@ -143,10 +215,11 @@ impl LanguageAnalyzer for RustAnalyzer {
                         continue;
                     }
 
-                    let to_id = ComponentId::new(&use_path, "<module>");
+                    let to_id = ComponentId::with_language(LANGUAGE, &use_path, "<module>");
+                    let from_module = item_module_path(node, &parsed.content, &module_path);
 
                     deps.push(Dependency {
-                        from: from_id.clone(),
+                        from: ComponentId::with_language(LANGUAGE, &from_module, "<file>"),
                         to: to_id,
                         kind: DependencyKind::Import,
                         location: SourceLocation {
@@ -182,6 +255,7 @@ fn extract_traits(
 
     while let Some(m) = matches.next() {
         let mut name = String::new();
+        let mut item_module = String::new();
         let mut methods = Vec::new();
         let mut start_row = 0;
         let mut start_col = 0;
@@ -189,13 +263,19 @@ fn extract_traits(
         for capture in m.captures {
             if capture.index as usize == name_idx {
                 name = node_text(capture.node, &parsed.content);
+                item_module = item_module_path(capture.node, &parsed.content, module_path);
                 start_row = capture.node.start_position().row;
                 start_col = capture.node.start_position().column;
             } else if Some(capture.index as usize) == method_idx {
+                // Trait methods share the trait's visibility.
                 methods.push(MethodInfo {
                     name: node_text(capture.node, &parsed.content),
                     parameters: String::new(),
                     return_type: String::new(),
+                    parameter_types: Vec::new(),
+                    visibility: Visibility::Public,
+                    interface_kind: InterfaceMethodKind::Abstract,
+                    annotations: Vec::new(),
                 });
             }
         }
@@ -205,7 +285,7 @@ fn extract_traits(
         }
 
         components.push(Component {
-            id: ComponentId::new(module_path, &name),
+            id: ComponentId::with_language(LANGUAGE, &item_module, &name),
             name: name.clone(),
             kind: ComponentKind::Port(PortInfo { name, methods }),
             layer: None,
@@ -215,7 +295,9 @@ fn extract_traits(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
@@ -242,6 +324,7 @@ fn extract_structs(
 
     while let Some(m) = matches.next() {
         let mut name = String::new();
+        let mut item_module = String::new();
         let mut fields = Vec::new();
         let mut start_row = 0;
         let mut start_col = 0;
@@ -251,6 +334,7 @@ fn extract_structs(
         for capture in m.captures {
             if capture.index as usize == name_idx {
                 name = node_text(capture.node, &parsed.content);
+                item_module = item_module_path(capture.node, &parsed.content, module_path);
                 start_row = capture.node.start_position().row;
                 start_col = capture.node.start_position().column;
             } else if Some(capture.index as usize) == field_idx {
@@ -258,9 +342,16 @@ fn extract_structs(
             } else if Some(capture.index as usize) == field_type_idx {
                 let type_name = node_text(capture.node, &parsed.content);
                 if !current_field_name.is_empty() {
+                    let visibility = match capture.node.parent() {
+                        Some(declaration) if is_pub(declaration, &parsed.content) => {
+                            Visibility::Public
+                        }
+                        _ => Visibility::Private,
+                    };
                     fields.push(FieldInfo {
                         name: current_field_name.clone(),
                         type_name,
+                        visibility,
                     });
                     current_field_name = String::new();
                 }
@@ -274,7 +365,7 @@ fn extract_structs(
         let kind = classify_struct_kind(&name, &fields);
 
         components.push(Component {
-            id: ComponentId::new(module_path, &name),
+            id: ComponentId::with_language(LANGUAGE, &item_module, &name),
             name: name.clone(),
             kind,
             layer: None,
@@ -284,7 +375,9 @@ fn extract_structs(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
@@ -312,6 +405,7 @@ fn enrich_with_impls(
     while let Some(m) = matches.next() {
         let mut trait_name: Option<String> = None;
         let mut type_name = String::new();
+        let mut item_module = String::new();
 
         for capture in m.captures {
             if Some(capture.index as usize) == trait_name_idx {
@@ -319,6 +413,7 @@ fn enrich_with_impls(
             }
             if capture.index as usize == type_name_idx {
                 type_name = node_text(capture.node, &parsed.content);
+                item_module = item_module_path(capture.node, &parsed.content, module_path);
             }
         }
 
@@ -328,7 +423,7 @@ fn enrich_with_impls(
 
         // If this impl has a trait, mark the struct as an Adapter
         if let Some(ref trait_name) = trait_name {
-            let id = ComponentId::new(module_path, &type_name);
+            let id = ComponentId::with_language(LANGUAGE, &item_module, &type_name);
             if let Some(comp) = components.iter_mut().find(|c| c.id == id) {
                 match &mut comp.kind {
                     ComponentKind::Adapter(info) => {
@@ -350,6 +445,131 @@ fn enrich_with_impls(
     }
 }
 
+/// Collect the methods of inherent `impl` blocks, keyed by the ID of the type they
+/// belong to. Only impls in this file are seen; those of types declared elsewhere
+/// reach their component through `LanguageAnalyzer::extract_impl_methods`.
+fn extract_impl_methods(
+    query: &Query,
+    parsed: &ParsedFile,
+    module_path: &str,
+) -> HashMap<ComponentId, Vec<MethodInfo>> {
+    let mut methods: HashMap<ComponentId, Vec<MethodInfo>> = HashMap::new();
+    let mut cursor = QueryCursor::new();
+    let type_name_idx = query.capture_names().iter().position(|n| *n == "type_name");
+    let method_idx = query.capture_names().iter().position(|n| *n == "method");
+
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+
+    while let Some(m) = matches.next() {
+        let mut id = None;
+        let mut method = None;
+
+        for capture in m.captures {
+            if Some(capture.index as usize) == type_name_idx {
+                let type_name = node_text(capture.node, &parsed.content);
+                let item_module = item_module_path(capture.node, &parsed.content, module_path);
+                id = Some(ComponentId::with_language(
+                    LANGUAGE,
+                    &item_module,
+                    &type_name,
+                ));
+            } else if Some(capture.index as usize) == method_idx {
+                method = method_info(capture.node, &parsed.content);
+            }
+        }
+
+        if let (Some(id), Some(method)) = (id, method) {
+            methods.entry(id).or_default().push(method);
+        }
+    }
+
+    methods
+}
+
+/// Build a `MethodInfo` from a `function_item`. Only `pub` methods are public.
+fn method_info(function: tree_sitter::Node, source: &str) -> Option<MethodInfo> {
+    let name = function.child_by_field_name("name")?;
+    let field_text = |field: &str| {
+        function
+            .child_by_field_name(field)
+            .map(|n| node_text(n, source))
+            .unwrap_or_default()
+    };
+    Some(MethodInfo {
+        name: node_text(name, source),
+        parameters: field_text("parameters"),
+        return_type: field_text("return_type"),
+        parameter_types: Vec::new(),
+        visibility: if is_pub(function, source) {
+            Visibility::Public
+        } else {
+            Visibility::Private
+        },
+        interface_kind: InterfaceMethodKind::Default,
+        annotations: Vec::new(),
+    })
+}
+
+/// Whether an item or field is declared plain `pub`; `pub(crate)` and other
+/// restricted visibilities are not public.
+fn is_pub(node: tree_sitter::Node, source: &str) -> bool {
+    let mut walker = node.walk();
+    let found = node
+        .children(&mut walker)
+        .any(|c| c.kind() == "visibility_modifier" && node_text(c, source) == "pub");
+    found
+}
+
+/// Associate inherent methods with their struct components.
+///
+/// After associating methods, entities with no methods are flagged as
+/// `is_anemic_domain_model`; methods come from a separate query, so this cannot
+/// happen in `classify_struct_kind`.
+fn associate_methods(
+    components: &mut [Component],
+    methods: &HashMap<ComponentId, Vec<MethodInfo>>,
+) {
+    for component in components.iter_mut() {
+        if matches!(component.kind, ComponentKind::Port(_)) {
+            continue;
+        }
+        if let Some(struct_methods) = methods.get(&component.id) {
+            component.methods = struct_methods.clone();
+            if let ComponentKind::Entity(info) = &mut component.kind {
+                info.methods = struct_methods.clone();
+                info.is_active_record = is_active_record(&info.methods);
+            }
+        }
+
+        if let ComponentKind::Entity(info) = &mut component.kind {
+            info.is_anemic_domain_model = info.methods.is_empty();
+        }
+    }
+}
+
+/// Check if a struct's methods indicate an Active Record pattern.
+/// Returns true if 2+ methods match known CRUD/persistence method names.
+fn is_active_record(methods: &[MethodInfo]) -> bool {
+    methods
+        .iter()
+        .filter(|m| is_crud_method_name(&to_pascal_case(&m.name)))
+        .count()
+        >= 2
+}
+
+/// PascalCase form of a snake_case name, so `find_by_id` compares like Go's `FindByID`.
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
 /// Classify a struct by its name suffix heuristic.
 fn classify_struct_kind(name: &str, fields: &[FieldInfo]) -> ComponentKind {
     let lower = name.to_lowercase();
@@ -377,7 +597,10 @@ fn classify_struct_kind(name: &str, fields: &[FieldInfo]) -> ComponentKind {
             fl == "id" || fl == "uuid"
         })
     {
-        ComponentKind::ValueObject
+        ComponentKind::ValueObject(ValueObjectInfo {
+            name: name.to_string(),
+            fields: fields.to_vec(),
+        })
     } else {
         ComponentKind::Entity(EntityInfo {
             name: name.to_string(),
@@ -406,11 +629,39 @@ fn derive_module_path(path: &Path) -> String {
     }
 }
 
+/// Module path of an item: the file's module path extended by the inline
+/// `mod name { ... }` blocks enclosing `node`, outermost first. A struct inside
+/// `mod billing { }` in a file under `src/domain` is in `src/domain/billing`.
+fn item_module_path(node: tree_sitter::Node, source: &str, file_module: &str) -> String {
+    let mut inline_mods = Vec::new();
+    let mut current = node.parent();
+    while let Some(ancestor) = current {
+        if ancestor.kind() == "mod_item" {
+            if let Some(name) = ancestor.child_by_field_name("name") {
+                inline_mods.push(node_text(name, source));
+            }
+        }
+        current = ancestor.parent();
+    }
+    inline_mods.reverse();
+    std::iter::once(file_module.to_string())
+        .filter(|m| !m.is_empty())
+        .chain(inline_mods)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_grammar_self_test_passes() {
+        // `new()` runs the self-test; it must pass against the bundled grammar.
+        RustAnalyzer::new().expect("Rust grammar self-test should pass");
+    }
+
     #[test]
     fn test_parse_simple_rust_file() {
         let analyzer = RustAnalyzer::new().unwrap();
@@ -542,5 +793,224 @@ impl UserRepository for PostgresRepo {
             }
             other => panic!("expected Adapter, got {:?}", other),
         }
+        assert!(
+            repo.unwrap().methods.is_empty(),
+            "trait impl methods are not inherent methods"
+        );
+    }
+
+    #[test]
+    fn test_inherent_methods_make_active_record() {
+        let analyzer = RustAnalyzer::new().unwrap();
+        let content = r#"
+pub struct User {
+    pub id: String,
+    pub name: String,
+}
+
+impl User {
+    pub fn save(&self, db: &Db) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn delete(&self, db: &Db) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn find_by_id(db: &Db, id: &str) -> Option<User> {
+        None
+    }
+
+    fn validate(&self) -> bool {
+        true
+    }
+}
+"#;
+        let path = PathBuf::from("src/models/user.rs");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let user = components
+            .iter()
+            .find(|c| c.name == "User")
+            .expect("should find User");
+        let names: Vec<&str> = user.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["save", "delete", "find_by_id", "validate"]);
+        let find = &user.methods[2];
+        assert_eq!(find.parameters, "(db: &Db, id: &str)");
+        assert_eq!(find.return_type, "Option<User>");
+        assert_eq!(user.methods[3].visibility, Visibility::Private);
+
+        match &user.kind {
+            ComponentKind::Entity(info) => {
+                assert_eq!(info.methods.len(), 4);
+                assert!(
+                    info.is_active_record,
+                    "User with save, delete, find_by_id should be active record"
+                );
+                assert!(!info.is_anemic_domain_model);
+            }
+            other => panic!("expected Entity, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_not_active_record_with_few_crud_methods() {
+        let analyzer = RustAnalyzer::new().unwrap();
+        let content = r#"
+pub struct Order {
+    pub id: String,
+}
+
+impl Order {
+    pub fn save(&self) {}
+    pub fn cancel(&mut self) {}
+}
+
+pub struct Invoice {
+    pub id: String,
+}
+"#;
+        let path = PathBuf::from("src/domain/order.rs");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let entity_info =
+            |name: &str| match &components.iter().find(|c| c.name == name).unwrap().kind {
+                ComponentKind::Entity(info) => info.clone(),
+                other => panic!("expected Entity, got {:?}", other),
+            };
+        let order = entity_info("Order");
+        assert!(!order.is_active_record, "one CRUD method is not enough");
+        assert!(!order.is_anemic_domain_model);
+        assert!(entity_info("Invoice").is_anemic_domain_model);
+    }
+
+    #[test]
+    fn test_generic_impl_methods_and_restricted_visibility() {
+        let analyzer = RustAnalyzer::new().unwrap();
+        let content = r#"
+pub struct Page<T> {
+    pub items: Vec<T>,
+}
+
+impl<T> Page<T> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub(crate) fn clear(&mut self) {}
+}
+"#;
+        let path = PathBuf::from("src/domain/page.rs");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let page = components
+            .iter()
+            .find(|c| c.name == "Page")
+            .expect("should find Page");
+        let methods: Vec<_> = page
+            .methods
+            .iter()
+            .map(|m| (m.name.as_str(), m.visibility))
+            .collect();
+        assert_eq!(
+            methods,
+            [("len", Visibility::Public), ("clear", Visibility::Private)]
+        );
+        assert!(analyzer.extract_impl_methods(&parsed).is_empty());
+    }
+
+    #[test]
+    fn test_impl_methods_of_type_declared_in_another_file() {
+        let analyzer = RustAnalyzer::new().unwrap();
+        let content = r#"
+use super::User;
+
+impl User {
+    pub fn rename(&mut self, name: String) {
+        self.name = name;
+    }
+}
+"#;
+        let path = PathBuf::from("src/domain/user/behavior.rs");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        assert!(analyzer.extract_components(&parsed).is_empty());
+
+        let impl_methods = analyzer.extract_impl_methods(&parsed);
+        let [im] = impl_methods.as_slice() else {
+            panic!("expected one impl, got {impl_methods:?}");
+        };
+        assert_eq!(
+            im.type_id,
+            ComponentId::with_language(LANGUAGE, "src/domain/user", "User")
+        );
+        assert_eq!(im.methods[0].name, "rename");
+    }
+
+    #[test]
+    fn test_inline_mod_extends_module_path() {
+        let analyzer = RustAnalyzer::new().unwrap();
+        let content = r#"
+pub struct Ledger {
+    pub id: String,
+}
+
+mod billing {
+    use crate::infrastructure::stripe::Client;
+
+    pub trait InvoiceRepository {
+        fn save(&self, invoice: &Invoice);
+    }
+
+    pub struct Invoice {
+        pub id: String,
+    }
+
+    pub struct PgInvoices {
+        pool: Pool,
+    }
+
+    impl InvoiceRepository for PgInvoices {
+        fn save(&self, invoice: &Invoice) {}
+    }
+
+    mod tax {
+        pub struct Rate {
+            pub percent: u8,
+        }
+    }
+}
+"#;
+        let path = PathBuf::from("src/domain/mod.rs");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+        let id_of = |name: &str| {
+            components
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap_or_else(|| panic!("should find {name}"))
+                .id
+                .0
+                .clone()
+        };
+
+        assert_eq!(id_of("Ledger"), "rust:src/domain::Ledger");
+        assert_eq!(id_of("Invoice"), "rust:src/domain/billing::Invoice");
+        assert_eq!(
+            id_of("InvoiceRepository"),
+            "rust:src/domain/billing::InvoiceRepository"
+        );
+        assert_eq!(id_of("Rate"), "rust:src/domain/billing/tax::Rate");
+        let adapter = components.iter().find(|c| c.name == "PgInvoices").unwrap();
+        assert!(
+            matches!(adapter.kind, ComponentKind::Adapter(_)),
+            "impl inside the same inline mod should enrich the struct"
+        );
+
+        let deps = analyzer.extract_dependencies(&parsed);
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].from.0, "rust:src/domain/billing::<file>");
     }
 }
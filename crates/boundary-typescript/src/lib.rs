@@ -1,16 +1,36 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Component as PathComponent, Path, PathBuf};
 
 use anyhow::{Context, Result};
 use tree_sitter::{Language, Parser, Query, QueryCursor, StreamingIterator};
 
-use boundary_core::analyzer::{LanguageAnalyzer, ParsedFile};
+use boundary_core::analyzer::{grammar_self_test, Extracted, LanguageAnalyzer, ParsedFile};
 use boundary_core::types::*;
 
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "typescript";
+
+/// Known-good source checked by [`TypeScriptAnalyzer::new`] against the compiled
+/// queries of both dialects.
+const SELF_TEST_SNIPPET: &str = r#"
+import { Amount } from "../money/amount";
+
+export interface OrderRepository {
+  save(order: Order): Promise<void>;
+}
+
+export class Order {
+  constructor(private id: string, private total: Amount) {}
+}
+"#;
+
 /// Holds queries compiled for a specific TypeScript dialect.
 struct QuerySet {
     interface_query: Query,
     type_alias_query: Query,
     class_query: Query,
+    decorator_query: Query,
+    factory_query: Query,
     import_query: Query,
 }
 
@@ -35,6 +55,42 @@ const CLASS_QUERY_SRC: &str = r#"
   body: (class_body))
 "#;
 
+/// Class decorators, either on the class itself or on its `export` statement
+/// (`@Controller() export class Users {}` attaches to the export).
+const DECORATOR_QUERY_SRC: &str = r#"
+[
+  (class_declaration
+    decorator: (decorator) @decorator
+    name: (type_identifier) @class_name)
+  (export_statement
+    decorator: (decorator) @decorator
+    declaration: (class_declaration
+      name: (type_identifier) @class_name))
+]
+"#;
+
+/// Exported functions, declared or assigned as arrow functions; `extract_factories`
+/// keeps those named like factories (`createUserService`).
+const FACTORY_QUERY_SRC: &str = r#"
+[
+  (export_statement
+    declaration: (function_declaration
+      name: (identifier) @name
+      parameters: (formal_parameters) @params
+      return_type: (type_annotation)? @return_type))
+  (export_statement
+    declaration: (lexical_declaration
+      (variable_declarator
+        name: (identifier) @name
+        value: (arrow_function
+          parameters: (formal_parameters) @params
+          return_type: (type_annotation)? @return_type))))
+]
+"#;
+
+/// Name prefixes that mark a function as a factory.
+const FACTORY_PREFIXES: &[&str] = &["create", "make", "build", "new"];
+
 const IMPORT_QUERY_SRC: &str = r#"
 (import_statement
   source: (string) @path)
@@ -48,6 +104,10 @@ fn compile_queries(language: &Language) -> Result<QuerySet> {
             .context("failed to compile type alias query")?,
         class_query: Query::new(language, CLASS_QUERY_SRC)
             .context("failed to compile class query")?,
+        decorator_query: Query::new(language, DECORATOR_QUERY_SRC)
+            .context("failed to compile decorator query")?,
+        factory_query: Query::new(language, FACTORY_QUERY_SRC)
+            .context("failed to compile factory query")?,
         import_query: Query::new(language, IMPORT_QUERY_SRC)
             .context("failed to compile import query")?,
     })
@@ -69,12 +129,33 @@ impl TypeScriptAnalyzer {
         let ts_queries = compile_queries(&ts_language)?;
         let tsx_queries = compile_queries(&tsx_language)?;
 
-        Ok(Self {
+        let analyzer = Self {
             ts_language,
             tsx_language,
             ts_queries,
             tsx_queries,
-        })
+        };
+        let expected = [
+            Extracted::Interfaces,
+            Extracted::InterfaceMethods,
+            Extracted::Types,
+            Extracted::Imports,
+        ];
+        grammar_self_test(
+            &analyzer,
+            "TypeScript",
+            "src/order.ts",
+            SELF_TEST_SNIPPET,
+            &expected,
+        )?;
+        grammar_self_test(
+            &analyzer,
+            "TSX",
+            "src/order.tsx",
+            SELF_TEST_SNIPPET,
+            &expected,
+        )?;
+        Ok(analyzer)
     }
 
     fn language_for_file(&self, path: &Path) -> &Language {
@@ -94,7 +175,7 @@ impl TypeScriptAnalyzer {
 
 impl LanguageAnalyzer for TypeScriptAnalyzer {
     fn language(&self) -> &'static str {
-        "typescript"
+        LANGUAGE
     }
 
     fn file_extensions(&self) -> &[&str] {
@@ -140,6 +221,25 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
             &mut components,
         );
         extract_classes(&queries.class_query, parsed, &module_path, &mut components);
+        enrich_with_decorators(
+            &queries.decorator_query,
+            parsed,
+            &module_path,
+            &mut components,
+        );
+        for factory in extract_factories(&queries.factory_query, parsed) {
+            components.push(Component {
+                id: ComponentId::with_language(LANGUAGE, &module_path, &factory.name),
+                kind: classify_factory_kind(&factory),
+                name: factory.name,
+                layer: None,
+                location: factory.location,
+                is_cross_cutting: false,
+                is_generated: false,
+                architecture_mode: ArchitectureMode::default(),
+                methods: Vec::new(),
+            });
+        }
 
         components
     }
@@ -147,7 +247,7 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
     fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
         let mut deps = Vec::new();
         let module_path = derive_module_path(&parsed.path);
-        let from_id = ComponentId::new(&module_path, "<file>");
+        let from_id = ComponentId::with_language(LANGUAGE, &module_path, "<file>");
 
         let queries = self.queries_for_file(&parsed.path);
         let mut cursor = QueryCursor::new();
@@ -171,7 +271,13 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
                     let raw = node_text(node, &parsed.content);
                     // Strip quotes (single or double)
                     let import_path = raw.trim_matches('"').trim_matches('\'').to_string();
-                    let to_id = ComponentId::new(&import_path, "<module>");
+                    // Relative specifiers name the importing file's neighbours; resolve
+                    // them so every importer of a module shares one target node.
+                    let to_id = ComponentId::with_language(
+                        LANGUAGE,
+                        &resolve_module(&parsed.path, &import_path),
+                        "<module>",
+                    );
 
                     deps.push(Dependency {
                         from: from_id.clone(),
@@ -188,10 +294,200 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
             }
         }
 
+        // Typed parameters of factory functions are their injected dependencies.
+        let imports = imported_names(parsed);
+        for factory in extract_factories(&queries.factory_query, parsed) {
+            let from = ComponentId::with_language(LANGUAGE, &module_path, &factory.name);
+            for (type_name, location) in factory.parameter_types {
+                let (target_module, target_name, import_path) = match imports.get(&type_name) {
+                    Some((source, imported)) => (
+                        resolve_module(&parsed.path, source),
+                        imported.clone(),
+                        Some(source.clone()),
+                    ),
+                    None => (module_path.clone(), type_name, None),
+                };
+                deps.push(Dependency {
+                    from: from.clone(),
+                    to: ComponentId::with_language(LANGUAGE, &target_module, &target_name),
+                    kind: DependencyKind::TypeReference,
+                    location,
+                    import_path,
+                });
+            }
+        }
+
         deps
     }
 }
 
+/// An exported factory function (`export function createUserService(repo: UserRepository)`).
+struct Factory {
+    name: String,
+    location: SourceLocation,
+    /// Named types of the parameters, with their locations.
+    parameter_types: Vec<(String, SourceLocation)>,
+    return_type: Option<String>,
+}
+
+fn extract_factories(query: &Query, parsed: &ParsedFile) -> Vec<Factory> {
+    let mut cursor = QueryCursor::new();
+    let name_idx = query.capture_names().iter().position(|n| *n == "name");
+    let params_idx = query.capture_names().iter().position(|n| *n == "params");
+    let return_idx = query
+        .capture_names()
+        .iter()
+        .position(|n| *n == "return_type");
+
+    let location = |node: tree_sitter::Node| SourceLocation {
+        file: parsed.path.clone(),
+        line: node.start_position().row + 1,
+        column: node.start_position().column + 1,
+    };
+
+    let mut factories = Vec::new();
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+    while let Some(m) = matches.next() {
+        let mut factory = Factory {
+            name: String::new(),
+            location: location(parsed.tree.root_node()),
+            parameter_types: Vec::new(),
+            return_type: None,
+        };
+        for capture in m.captures {
+            let idx = Some(capture.index as usize);
+            if idx == name_idx {
+                factory.name = node_text(capture.node, &parsed.content);
+                factory.location = location(capture.node);
+            } else if idx == params_idx {
+                let mut walker = capture.node.walk();
+                for param in capture.node.named_children(&mut walker) {
+                    let type_name = param
+                        .child_by_field_name("type")
+                        .and_then(|t| named_type(t, &parsed.content));
+                    if let Some(type_name) = type_name {
+                        factory.parameter_types.push((type_name, location(param)));
+                    }
+                }
+            } else if idx == return_idx {
+                factory.return_type = named_type(capture.node, &parsed.content);
+            }
+        }
+        if is_factory_name(&factory.name) {
+            factories.push(factory);
+        }
+    }
+    factories
+}
+
+/// `createUserService` → true; `create`, `created` and `render` → false.
+fn is_factory_name(name: &str) -> bool {
+    FACTORY_PREFIXES.iter().any(|prefix| {
+        name.strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+    })
+}
+
+/// The type a `type_annotation` names, if it is a plain or generic type reference
+/// (`UserRepository`, `Repository<User>`); primitives, unions and literals yield `None`.
+fn named_type(annotation: tree_sitter::Node, source: &str) -> Option<String> {
+    let ty = if annotation.kind() == "type_annotation" {
+        annotation.named_child(0)?
+    } else {
+        annotation
+    };
+    match ty.kind() {
+        "type_identifier" => Some(node_text(ty, source)),
+        "generic_type" => ty
+            .child_by_field_name("name")
+            .filter(|n| n.kind() == "type_identifier")
+            .map(|n| node_text(n, source)),
+        _ => None,
+    }
+}
+
+/// Classify a factory by the name it builds (`createUserRepository` → `UserRepository`).
+/// A declared return type counts as the port it implements; factories of anything
+/// else are treated as services.
+fn classify_factory_kind(factory: &Factory) -> ComponentKind {
+    let built = FACTORY_PREFIXES
+        .iter()
+        .find_map(|prefix| factory.name.strip_prefix(prefix))
+        .unwrap_or(&factory.name);
+    let implements: Vec<String> = factory.return_type.iter().cloned().collect();
+    match classify_class_kind(built, &implements) {
+        ComponentKind::Adapter(info) => ComponentKind::Adapter(AdapterInfo {
+            name: factory.name.clone(),
+            ..info
+        }),
+        ComponentKind::Entity(_) => ComponentKind::Service,
+        kind => kind,
+    }
+}
+
+/// Map each name bound by an import to its source module and exported name:
+/// `import { UserRepository as Repo } from './repo'` → `Repo` → (`./repo`, `UserRepository`).
+fn imported_names(parsed: &ParsedFile) -> HashMap<String, (String, String)> {
+    let mut names = HashMap::new();
+    let root = parsed.tree.root_node();
+    let mut walker = root.walk();
+    for statement in root.named_children(&mut walker) {
+        if statement.kind() != "import_statement" {
+            continue;
+        }
+        let Some(source) = statement.child_by_field_name("source") else {
+            continue;
+        };
+        let source = node_text(source, &parsed.content)
+            .trim_matches(|c| c == '"' || c == '\'')
+            .to_string();
+        let mut stack = vec![statement];
+        while let Some(node) = stack.pop() {
+            let binding = match node.kind() {
+                "import_specifier" => node.child_by_field_name("name").map(|name| {
+                    let local = node.child_by_field_name("alias").unwrap_or(name);
+                    (local, name)
+                }),
+                // Default import: `import Pool from 'pg'`
+                "identifier" if node.parent().is_some_and(|p| p.kind() == "import_clause") => {
+                    Some((node, node))
+                }
+                _ => None,
+            };
+            if let Some((local, imported)) = binding {
+                names.insert(
+                    node_text(local, &parsed.content),
+                    (source.clone(), node_text(imported, &parsed.content)),
+                );
+                continue;
+            }
+            let mut children = node.walk();
+            stack.extend(node.named_children(&mut children));
+        }
+    }
+    names
+}
+
+/// Module path of an import: relative imports resolve against the importing file to
+/// the directory holding the target (`../domain/user/user-repository` from
+/// `src/app/service.ts` → `src/domain/user`); package imports keep their name.
+fn resolve_module(file: &Path, import: &str) -> String {
+    if !import.starts_with('.') {
+        return import.to_string();
+    }
+    let mut resolved = PathBuf::new();
+    for part in file.with_file_name(import).components() {
+        match part {
+            PathComponent::CurDir => {}
+            PathComponent::ParentDir => {
+                resolved.pop();
+            }
+            other => resolved.push(other),
+        }
+    }
+    derive_module_path(&resolved)
+}
+
 fn extract_interfaces(
     query: &Query,
     parsed: &ParsedFile,
@@ -228,10 +524,26 @@ fn extract_interfaces(
                         let child = child_cursor.node();
                         if child.kind() == "method_signature" {
                             if let Some(name_node) = child.child_by_field_name("name") {
+                                let parameters = child
+                                    .child_by_field_name("parameters")
+                                    .map(|n| node_text(n, &parsed.content))
+                                    .unwrap_or_default();
+                                // The annotation's type, without the leading `:`; empty
+                                // when the signature has none.
+                                let return_type = child
+                                    .child_by_field_name("return_type")
+                                    .and_then(|n| n.named_child(0))
+                                    .map(|n| node_text(n, &parsed.content))
+                                    .unwrap_or_default();
+                                // Interface members are public.
                                 methods.push(MethodInfo {
                                     name: node_text(name_node, &parsed.content),
-                                    parameters: String::new(),
-                                    return_type: String::new(),
+                                    parameters,
+                                    return_type,
+                                    parameter_types: Vec::new(),
+                                    visibility: Visibility::Public,
+                                    interface_kind: InterfaceMethodKind::Abstract,
+                                    annotations: Vec::new(),
                                 });
                             }
                         }
@@ -248,7 +560,7 @@ fn extract_interfaces(
         }
 
         components.push(Component {
-            id: ComponentId::new(module_path, &name),
+            id: ComponentId::with_language(LANGUAGE, module_path, &name),
             name: name.clone(),
             kind: ComponentKind::Port(PortInfo { name, methods }),
             layer: None,
@@ -258,7 +570,9 @@ fn extract_interfaces(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
@@ -287,7 +601,7 @@ fn extract_type_aliases(
                 }
 
                 components.push(Component {
-                    id: ComponentId::new(module_path, &name),
+                    id: ComponentId::with_language(LANGUAGE, module_path, &name),
                     name: name.clone(),
                     kind: ComponentKind::Port(PortInfo {
                         name,
@@ -300,7 +614,9 @@ fn extract_type_aliases(
                         column: capture.node.start_position().column + 1,
                     },
                     is_cross_cutting: false,
+                    is_generated: false,
                     architecture_mode: ArchitectureMode::default(),
+                    methods: Vec::new(),
                 });
             }
         }
@@ -349,7 +665,7 @@ fn extract_classes(
         let kind = classify_class_kind(&name, &implements);
 
         components.push(Component {
-            id: ComponentId::new(module_path, &name),
+            id: ComponentId::with_language(LANGUAGE, module_path, &name),
             name: name.clone(),
             kind,
             layer: None,
@@ -359,11 +675,92 @@ fn extract_classes(
                 column: start_col + 1,
             },
             is_cross_cutting: false,
+            is_generated: false,
             architecture_mode: ArchitectureMode::default(),
+            methods: Vec::new(),
         });
     }
 }
 
+/// Enrich class components with decorator-based classification (NestJS).
+///
+/// `@Controller()` and `@Repository()` are authoritative. `@Injectable()` only marks
+/// a DI provider, so it promotes a class to a service only when nothing more specific
+/// (name suffix, implemented port, another decorator) classified it already.
+fn enrich_with_decorators(
+    query: &Query,
+    parsed: &ParsedFile,
+    module_path: &str,
+    components: &mut [Component],
+) {
+    let mut cursor = QueryCursor::new();
+    let decorator_idx = query.capture_names().iter().position(|n| *n == "decorator");
+    let class_name_idx = query
+        .capture_names()
+        .iter()
+        .position(|n| *n == "class_name");
+
+    let mut matches = cursor.matches(query, parsed.tree.root_node(), parsed.content.as_bytes());
+
+    while let Some(m) = matches.next() {
+        let mut decorator = String::new();
+        let mut class_name = String::new();
+
+        for capture in m.captures {
+            if Some(capture.index as usize) == decorator_idx {
+                decorator = decorator_name(capture.node, &parsed.content);
+            }
+            if Some(capture.index as usize) == class_name_idx {
+                class_name = node_text(capture.node, &parsed.content);
+            }
+        }
+
+        if class_name.is_empty() || decorator.is_empty() {
+            continue;
+        }
+
+        let id = ComponentId::with_language(LANGUAGE, module_path, &class_name);
+        if let Some(comp) = components.iter_mut().find(|c| c.id == id) {
+            match decorator.as_str() {
+                "Controller" => {
+                    let implements = match &comp.kind {
+                        ComponentKind::Adapter(info) => info.implements.clone(),
+                        _ => Vec::new(),
+                    };
+                    comp.kind = ComponentKind::Adapter(AdapterInfo {
+                        name: class_name,
+                        implements,
+                        confidence: AdapterConfidence::default(),
+                        returns_concrete: None,
+                    });
+                }
+                "Repository" => {
+                    comp.kind = ComponentKind::Repository;
+                }
+                "Injectable" if matches!(comp.kind, ComponentKind::Entity(_)) => {
+                    comp.kind = ComponentKind::Service;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Name of the function a decorator invokes: `@Controller('users')` → `Controller`,
+/// `@nest.Injectable()` → `Injectable`.
+fn decorator_name(decorator: tree_sitter::Node, source: &str) -> String {
+    let Some(expr) = decorator.named_child(0) else {
+        return String::new();
+    };
+    let callee = if expr.kind() == "call_expression" {
+        expr.child_by_field_name("function").unwrap_or(expr)
+    } else {
+        expr
+    };
+    let text = node_text(callee, source);
+    text.rsplit('.').next().unwrap_or_default().to_string()
+}
+
 /// Classify a class by its name suffix heuristic and implements clause.
 fn classify_class_kind(name: &str, implements: &[String]) -> ComponentKind {
     let lower = name.to_lowercase();
@@ -415,6 +812,12 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_grammar_self_test_passes() {
+        // `new()` runs the self-test; it must pass against the bundled grammar.
+        TypeScriptAnalyzer::new().expect("TypeScript and TSX grammar self-test should pass");
+    }
+
     #[test]
     fn test_parse_typescript_interface() {
         let analyzer = TypeScriptAnalyzer::new().unwrap();
@@ -450,6 +853,42 @@ export interface User {
         }
     }
 
+    #[test]
+    fn test_interface_method_signatures() {
+        let analyzer = TypeScriptAnalyzer::new().unwrap();
+        let content = r#"
+export interface UserRepository {
+    save(user: User): Promise<void>;
+    findById(id: string): Promise<User | null>;
+    clear();
+}
+"#;
+        let path = PathBuf::from("src/domain/user/user.ts");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let repo = components
+            .iter()
+            .find(|c| c.name == "UserRepository")
+            .expect("should find UserRepository interface");
+        let ComponentKind::Port(ref info) = repo.kind else {
+            panic!("expected Port, got {:?}", repo.kind);
+        };
+
+        let save = info.methods.iter().find(|m| m.name == "save").unwrap();
+        assert!(save.parameters.contains("user: User"), "{save:?}");
+        assert!(save.return_type.contains("Promise<void>"), "{save:?}");
+
+        let find = info.methods.iter().find(|m| m.name == "findById").unwrap();
+        assert_eq!(find.parameters, "(id: string)");
+        assert_eq!(find.return_type, "Promise<User | null>");
+
+        // No annotation: the method is still extracted, with an empty return type.
+        let clear = info.methods.iter().find(|m| m.name == "clear").unwrap();
+        assert_eq!(clear.parameters, "()");
+        assert_eq!(clear.return_type, "");
+    }
+
     #[test]
     fn test_extract_class_with_implements() {
         let analyzer = TypeScriptAnalyzer::new().unwrap();
@@ -484,6 +923,46 @@ export class PostgresUserRepository implements UserRepository {
         }
     }
 
+    #[test]
+    fn test_nestjs_decorators_classify_classes() {
+        let analyzer = TypeScriptAnalyzer::new().unwrap();
+        let content = r#"
+import { Controller, Get, Injectable } from '@nestjs/common';
+
+@Controller('users')
+export class Users {
+    @Get()
+    findAll() {}
+}
+
+@Injectable()
+export class Billing {}
+
+@Injectable()
+export class UsersRepository {}
+
+@Injectable()
+class Mailer implements Notifier {}
+"#;
+        let path = PathBuf::from("src/users/users.ts");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+        let kind_of = |name: &str| &components.iter().find(|c| c.name == name).unwrap().kind;
+
+        assert!(
+            matches!(kind_of("Users"), ComponentKind::Adapter(_)),
+            "@Controller() class should be an adapter, got {:?}",
+            kind_of("Users")
+        );
+        assert!(matches!(kind_of("Billing"), ComponentKind::Service));
+        // @Injectable() does not override more specific classifications.
+        assert!(matches!(
+            kind_of("UsersRepository"),
+            ComponentKind::Repository
+        ));
+        assert!(matches!(kind_of("Mailer"), ComponentKind::Adapter(_)));
+    }
+
     #[test]
     fn test_extract_imports() {
         let analyzer = TypeScriptAnalyzer::new().unwrap();
@@ -562,6 +1041,102 @@ export class CreateUserUseCase {
         assert!(matches!(uc.unwrap().kind, ComponentKind::UseCase));
     }
 
+    #[test]
+    fn test_factory_functions_are_components() {
+        let analyzer = TypeScriptAnalyzer::new().unwrap();
+        let content = r#"
+import { UserRepository } from '../../domain/user/user-repository';
+import type { Clock as SystemClock } from '../../domain/clock';
+import { Pool } from 'pg';
+
+export function createUserRepository(pool: Pool, cache: UserRepository): UserRepository {
+    return { save: async () => {}, findById: async () => null };
+}
+
+export const makeUserService = (repo: UserRepository, clock: SystemClock, retries: number) => ({
+    register: async () => {},
+});
+
+export function formatUser(user: User): string {
+    return user.name;
+}
+"#;
+        let path = PathBuf::from("src/infrastructure/postgres/user-repo.ts");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+        let kind_of = |name: &str| {
+            &components
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap_or_else(|| panic!("{name} should be a component"))
+                .kind
+        };
+
+        assert!(matches!(
+            kind_of("createUserRepository"),
+            ComponentKind::Repository
+        ));
+        assert!(matches!(kind_of("makeUserService"), ComponentKind::Service));
+        assert!(
+            !components.iter().any(|c| c.name == "formatUser"),
+            "only factory-named functions are components"
+        );
+
+        let deps = analyzer.extract_dependencies(&parsed);
+        let injected: Vec<(&str, &str, Option<&str>)> = deps
+            .iter()
+            .filter(|d| matches!(d.kind, DependencyKind::TypeReference))
+            .map(|d| (d.from.untagged(), d.to.untagged(), d.import_path.as_deref()))
+            .collect();
+        let repo_source = Some("../../domain/user/user-repository");
+        assert_eq!(
+            injected,
+            [
+                (
+                    "src/infrastructure/postgres::createUserRepository",
+                    "pg::Pool",
+                    Some("pg")
+                ),
+                (
+                    "src/infrastructure/postgres::createUserRepository",
+                    "src/domain/user::UserRepository",
+                    repo_source
+                ),
+                (
+                    "src/infrastructure/postgres::makeUserService",
+                    "src/domain/user::UserRepository",
+                    repo_source
+                ),
+                (
+                    "src/infrastructure/postgres::makeUserService",
+                    "src/domain::Clock",
+                    Some("../../domain/clock")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_factory_return_type_is_implemented_port() {
+        let analyzer = TypeScriptAnalyzer::new().unwrap();
+        let content = r#"
+export function createMailer(apiKey: string): Notifier {
+    return { send: async () => {} };
+}
+"#;
+        let path = PathBuf::from("src/infrastructure/mail/mailer.ts");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        match &components[0].kind {
+            ComponentKind::Adapter(info) => {
+                assert_eq!(info.name, "createMailer");
+                assert_eq!(info.implements, ["Notifier"]);
+            }
+            other => panic!("expected adapter, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_type_alias_port() {
         let analyzer = TypeScriptAnalyzer::new().unwrap();
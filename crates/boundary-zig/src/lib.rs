@@ -0,0 +1,551 @@
+use std::path::{Component as PathComponent, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tree_sitter::{Language, Node, Parser, Query, QueryCursor, StreamingIterator};
+
+use boundary_core::analyzer::{grammar_self_test, Extracted, LanguageAnalyzer, ParsedFile};
+use boundary_core::types::*;
+
+/// Language tag used in component IDs.
+const LANGUAGE: &str = "zig";
+
+/// Modules provided by the compiler rather than the project.
+const COMPILER_MODULES: &[&str] = &["std", "builtin", "root"];
+
+/// Known-good source checked by [`ZigAnalyzer::new`] against the compiled queries.
+const SELF_TEST_SNIPPET: &str = r#"
+const std = @import("std");
+const money = @import("money.zig");
+
+pub const OrderRepository = struct {
+    ptr: *anyopaque,
+    saveFn: *const fn (ptr: *anyopaque, order: Order) anyerror!void,
+};
+
+pub const Order = struct {
+    total: u64,
+
+    pub fn pay(self: *Order) void {
+        self.total = 0;
+    }
+};
+"#;
+
+/// Zig language analyzer using tree-sitter.
+///
+/// Zig has no interfaces; the idiom is a struct of function pointers, optionally next
+/// to a type-erased `*anyopaque` context. Such vtable-style structs, and structs named
+/// `...Interface`, are treated as ports.
+pub struct ZigAnalyzer {
+    language: Language,
+    struct_query: Query,
+    import_query: Query,
+}
+
+impl ZigAnalyzer {
+    pub fn new() -> Result<Self> {
+        let language: Language = tree_sitter_zig::LANGUAGE.into();
+
+        let struct_query = Query::new(
+            &language,
+            r#"
+            (variable_declaration
+              (identifier) @name
+              (struct_declaration) @body)
+            "#,
+        )
+        .context("failed to compile struct query")?;
+
+        let import_query = Query::new(
+            &language,
+            r#"
+            (builtin_function
+              (builtin_identifier) @builtin
+              (arguments (string (string_content) @path))
+              (#eq? @builtin "@import"))
+            "#,
+        )
+        .context("failed to compile import query")?;
+
+        let analyzer = Self {
+            language,
+            struct_query,
+            import_query,
+        };
+        grammar_self_test(
+            &analyzer,
+            "Zig",
+            "order/order.zig",
+            SELF_TEST_SNIPPET,
+            &[
+                Extracted::Interfaces,
+                Extracted::InterfaceMethods,
+                Extracted::Types,
+                Extracted::Methods,
+                Extracted::Imports,
+            ],
+        )?;
+        Ok(analyzer)
+    }
+}
+
+impl LanguageAnalyzer for ZigAnalyzer {
+    fn language(&self) -> &'static str {
+        LANGUAGE
+    }
+
+    fn file_extensions(&self) -> &[&str] {
+        &["zig"]
+    }
+
+    fn parse_file(&self, path: &Path, content: &str) -> Result<ParsedFile> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&self.language)
+            .context("failed to set Zig language")?;
+        let tree = parser
+            .parse(content, None)
+            .context("failed to parse Zig file")?;
+        Ok(ParsedFile {
+            path: path.to_path_buf(),
+            tree,
+            content: content.to_string(),
+        })
+    }
+
+    fn extract_components(&self, parsed: &ParsedFile) -> Vec<Component> {
+        let mut components = Vec::new();
+        let package_path = derive_package_path(&parsed.path);
+
+        let mut cursor = QueryCursor::new();
+        let name_idx = self
+            .struct_query
+            .capture_names()
+            .iter()
+            .position(|n| *n == "name");
+        let body_idx = self
+            .struct_query
+            .capture_names()
+            .iter()
+            .position(|n| *n == "body");
+
+        let mut matches = cursor.matches(
+            &self.struct_query,
+            parsed.tree.root_node(),
+            parsed.content.as_bytes(),
+        );
+
+        while let Some(m) = matches.next() {
+            let mut name_node = None;
+            let mut body_node = None;
+            for capture in m.captures {
+                if Some(capture.index as usize) == name_idx {
+                    name_node = Some(capture.node);
+                } else if Some(capture.index as usize) == body_idx {
+                    body_node = Some(capture.node);
+                }
+            }
+            let (Some(name_node), Some(body)) = (name_node, body_node) else {
+                continue;
+            };
+
+            let name = node_text(name_node, &parsed.content);
+            let members = extract_members(body, &parsed.content);
+
+            // Context pointers don't count against a vtable; any other data field does.
+            let is_vtable = !members.fn_pointers.is_empty() && members.fields.is_empty();
+            let kind = if is_vtable || name.ends_with("Interface") {
+                let mut methods = members.fn_pointers;
+                methods.extend(members.methods.iter().cloned());
+                ComponentKind::Port(PortInfo {
+                    name: name.clone(),
+                    methods,
+                })
+            } else {
+                classify_struct_kind(&name, members.fields, members.methods.clone())
+            };
+
+            components.push(Component {
+                id: ComponentId::with_language(LANGUAGE, &package_path, &name),
+                name,
+                kind,
+                layer: None,
+                location: SourceLocation {
+                    file: parsed.path.clone(),
+                    line: name_node.start_position().row + 1,
+                    column: name_node.start_position().column + 1,
+                },
+                is_cross_cutting: false,
+                is_generated: false,
+                architecture_mode: ArchitectureMode::default(),
+                methods: members.methods,
+            });
+        }
+
+        components
+    }
+
+    fn extract_dependencies(&self, parsed: &ParsedFile) -> Vec<Dependency> {
+        let mut deps = Vec::new();
+        let package_path = derive_package_path(&parsed.path);
+        let from_id = ComponentId::with_language(LANGUAGE, &package_path, "<file>");
+        let base_dir = parsed.path.parent().unwrap_or(Path::new(""));
+
+        let mut cursor = QueryCursor::new();
+        let path_idx = self
+            .import_query
+            .capture_names()
+            .iter()
+            .position(|n| *n == "path");
+
+        let mut matches = cursor.matches(
+            &self.import_query,
+            parsed.tree.root_node(),
+            parsed.content.as_bytes(),
+        );
+
+        while let Some(m) = matches.next() {
+            for capture in m.captures {
+                if Some(capture.index as usize) != path_idx {
+                    continue;
+                }
+                let node = capture.node;
+                let target = node_text(node, &parsed.content);
+                if COMPILER_MODULES.contains(&target.as_str()) {
+                    continue;
+                }
+
+                // A `.zig` path names a file relative to the importer; anything else is a
+                // module registered by the build, such as a package dependency.
+                let (import_path, to_package) = if target.ends_with(".zig") {
+                    let resolved = normalize_path(&base_dir.join(&target));
+                    (
+                        resolved.to_string_lossy().replace('\\', "/"),
+                        derive_package_path(&resolved),
+                    )
+                } else {
+                    (target.clone(), target)
+                };
+
+                deps.push(Dependency {
+                    from: from_id.clone(),
+                    to: ComponentId::with_language(LANGUAGE, &to_package, "<file>"),
+                    kind: DependencyKind::Import,
+                    location: SourceLocation {
+                        file: parsed.path.clone(),
+                        line: node.start_position().row + 1,
+                        column: node.start_position().column + 1,
+                    },
+                    import_path: Some(import_path),
+                });
+            }
+        }
+
+        deps
+    }
+}
+
+/// Fields and functions of a struct body. Function-pointer fields are kept apart as
+/// the methods a vtable-style port declares.
+#[derive(Default)]
+struct Members {
+    fields: Vec<FieldInfo>,
+    fn_pointers: Vec<MethodInfo>,
+    methods: Vec<MethodInfo>,
+}
+
+fn extract_members(body: Node, source: &str) -> Members {
+    let mut members = Members::default();
+    let mut walker = body.walk();
+
+    for child in body.named_children(&mut walker) {
+        match child.kind() {
+            "container_field" => {
+                let (Some(name), Some(ty)) = (
+                    child.child_by_field_name("name"),
+                    child.child_by_field_name("type"),
+                ) else {
+                    continue;
+                };
+                let name = node_text(name, source);
+                if let Some(signature) = find_fn_pointer(ty) {
+                    members.fn_pointers.push(MethodInfo {
+                        name,
+                        parameters: child_text(signature, "parameters", source),
+                        return_type: field_text(signature, "type", source),
+                        parameter_types: Vec::new(),
+                        visibility: Visibility::Public,
+                        interface_kind: InterfaceMethodKind::Abstract,
+                        annotations: Vec::new(),
+                    });
+                } else if !is_context_pointer(&node_text(ty, source)) {
+                    // Zig container fields are always accessible.
+                    members.fields.push(FieldInfo {
+                        name,
+                        type_name: node_text(ty, source),
+                        visibility: Visibility::Public,
+                    });
+                }
+            }
+            "function_declaration" => {
+                let Some(name) = child.child_by_field_name("name") else {
+                    continue;
+                };
+                let mut fn_walker = child.walk();
+                let is_pub = child.children(&mut fn_walker).any(|c| c.kind() == "pub");
+                members.methods.push(MethodInfo {
+                    name: node_text(name, source),
+                    parameters: child_text(child, "parameters", source),
+                    return_type: field_text(child, "type", source),
+                    parameter_types: Vec::new(),
+                    visibility: if is_pub {
+                        Visibility::Public
+                    } else {
+                        Visibility::Private
+                    },
+                    interface_kind: InterfaceMethodKind::Default,
+                    annotations: Vec::new(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    members
+}
+
+/// The signature of a function pointer (`*const fn (...) T`) within a field type.
+fn find_fn_pointer(ty: Node) -> Option<Node> {
+    let mut walker = ty.walk();
+    let children: Vec<Node> = ty.named_children(&mut walker).collect();
+    if ty.kind() == "pointer_type" {
+        if let Some(signature) = children.iter().find(|c| c.kind() == "function_signature") {
+            return Some(*signature);
+        }
+    }
+    children.into_iter().find_map(find_fn_pointer)
+}
+
+/// A type-erased pointer to the implementation, as passed to vtable functions.
+fn is_context_pointer(type_name: &str) -> bool {
+    matches!(type_name, "*anyopaque" | "*const anyopaque")
+}
+
+/// Classify a non-port struct by its name suffix.
+fn classify_struct_kind(
+    name: &str,
+    fields: Vec<FieldInfo>,
+    methods: Vec<MethodInfo>,
+) -> ComponentKind {
+    let lower = name.to_lowercase();
+    if lower.ends_with("repository") || lower.ends_with("repo") {
+        ComponentKind::Repository
+    } else if lower.ends_with("service") || lower.ends_with("svc") {
+        ComponentKind::Service
+    } else if lower.ends_with("usecase") || lower.ends_with("interactor") {
+        ComponentKind::UseCase
+    } else if lower.ends_with("handler")
+        || lower.ends_with("controller")
+        || lower.ends_with("adapter")
+    {
+        ComponentKind::Adapter(AdapterInfo {
+            name: name.to_string(),
+            implements: Vec::new(),
+            confidence: AdapterConfidence::default(),
+            returns_concrete: None,
+        })
+    } else {
+        let is_anemic_domain_model = methods.is_empty();
+        ComponentKind::Entity(EntityInfo {
+            name: name.to_string(),
+            fields,
+            methods,
+            is_active_record: false,
+            is_anemic_domain_model,
+        })
+    }
+}
+
+/// Text of the first named child of `kind`, or empty.
+fn child_text(node: Node, kind: &str, source: &str) -> String {
+    let mut walker = node.walk();
+    let child = node.named_children(&mut walker).find(|c| c.kind() == kind);
+    child.map(|c| node_text(c, source)).unwrap_or_default()
+}
+
+/// Text of the child in `field`, or empty.
+fn field_text(node: Node, field: &str, source: &str) -> String {
+    node.child_by_field_name(field)
+        .map(|n| node_text(n, source))
+        .unwrap_or_default()
+}
+
+/// Lexically resolve `.` and `..` segments without touching the filesystem.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for part in path.components() {
+        match part {
+            PathComponent::CurDir => {}
+            PathComponent::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+/// Extract text from a tree-sitter node.
+fn node_text(node: Node, source: &str) -> String {
+    source[node.byte_range()].to_string()
+}
+
+/// Derive a package path from a file path.
+fn derive_package_path(path: &Path) -> String {
+    path.parent()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grammar_self_test_passes() {
+        // `new()` runs the self-test; it must pass against the bundled grammar.
+        ZigAnalyzer::new().expect("Zig grammar self-test should pass");
+    }
+
+    #[test]
+    fn test_vtable_struct_is_port() {
+        let analyzer = ZigAnalyzer::new().unwrap();
+        let content = r#"
+const user = @import("user.zig");
+
+pub const UserRepository = struct {
+    ptr: *anyopaque,
+    saveFn: *const fn (ptr: *anyopaque, u: user.User) anyerror!void,
+    findFn: *const fn (ptr: *anyopaque, id: []const u8) anyerror!user.User,
+
+    pub fn save(self: UserRepository, u: user.User) anyerror!void {
+        return self.saveFn(self.ptr, u);
+    }
+};
+
+pub const NotifierInterface = struct {
+    pub fn notify(self: NotifierInterface, msg: []const u8) void {
+        _ = self;
+        _ = msg;
+    }
+};
+"#;
+        let path = PathBuf::from("src/domain/user_repository.zig");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let repo = components
+            .iter()
+            .find(|c| c.name == "UserRepository")
+            .expect("should find UserRepository");
+        let ComponentKind::Port(ref info) = repo.kind else {
+            panic!("expected Port, got {:?}", repo.kind);
+        };
+        let names: Vec<&str> = info.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["saveFn", "findFn", "save"]);
+        assert_eq!(
+            info.methods[0].parameters,
+            "(ptr: *anyopaque, u: user.User)"
+        );
+
+        let notifier = components
+            .iter()
+            .find(|c| c.name == "NotifierInterface")
+            .unwrap();
+        assert!(
+            matches!(notifier.kind, ComponentKind::Port(_)),
+            "`Interface` naming makes a port, got {:?}",
+            notifier.kind
+        );
+    }
+
+    #[test]
+    fn test_data_struct_is_not_port() {
+        let analyzer = ZigAnalyzer::new().unwrap();
+        let content = r#"
+pub const PostgresUserRepository = struct {
+    conn: *Connection,
+    onError: *const fn (err: anyerror) void,
+
+    pub fn save(self: *PostgresUserRepository, u: User) !void {
+        _ = self;
+        _ = u;
+    }
+};
+
+pub const Money = struct {
+    cents: i64,
+
+    fn add(self: Money, other: Money) Money {
+        return .{ .cents = self.cents + other.cents };
+    }
+};
+"#;
+        let path = PathBuf::from("src/infrastructure/postgres.zig");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let components = analyzer.extract_components(&parsed);
+
+        let repo = components
+            .iter()
+            .find(|c| c.name == "PostgresUserRepository")
+            .unwrap();
+        assert!(matches!(repo.kind, ComponentKind::Repository));
+        assert_eq!(repo.methods[0].name, "save");
+
+        let money = components.iter().find(|c| c.name == "Money").unwrap();
+        let ComponentKind::Entity(ref info) = money.kind else {
+            panic!("expected Entity, got {:?}", money.kind);
+        };
+        assert_eq!(info.fields[0].name, "cents");
+        assert_eq!(info.fields[0].type_name, "i64");
+        assert_eq!(info.methods[0].visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn test_import_is_dependency() {
+        let analyzer = ZigAnalyzer::new().unwrap();
+        let content = r#"
+const std = @import("std");
+const builtin = @import("builtin");
+const repo = @import("../domain/user_repository.zig");
+const httpz = @import("httpz");
+
+pub fn run() void {}
+"#;
+        let path = PathBuf::from("src/application/register_user.zig");
+        let parsed = analyzer.parse_file(&path, content).unwrap();
+        let deps = analyzer.extract_dependencies(&parsed);
+
+        let imports: Vec<_> = deps
+            .iter()
+            .map(|d| d.import_path.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            imports,
+            ["src/domain/user_repository.zig", "httpz"],
+            "std and builtin should be skipped"
+        );
+        let dep = &deps[0];
+        assert_eq!(
+            dep.from,
+            ComponentId::with_language(LANGUAGE, "src/application", "<file>")
+        );
+        assert_eq!(
+            dep.to,
+            ComponentId::with_language(LANGUAGE, "src/domain", "<file>")
+        );
+        assert_eq!(dep.location.line, 4);
+    }
+}
@@ -0,0 +1,165 @@
+//! `boundary doctor`: the environment and configuration checks behind most empty or
+//! surprising results, in one report.
+
+use std::path::Path;
+use std::process;
+
+use anyhow::Result;
+
+use boundary_core::config::Config;
+use boundary_core::layer::invalid_layer_patterns;
+
+use crate::{
+    auto_detect_languages, find_source_files, new_analyzer, resolve_project_root, run_analysis,
+    validate_path, LayerBuckets, LAYERS,
+};
+
+/// Languages with a tree-sitter grammar bundled in every build.
+const GRAMMAR_LANGUAGES: [&str; 5] = ["go", "rust", "typescript", "java", "cpp"];
+
+/// Print the doctor report for `path` and exit with code 1 when it found problems.
+pub fn cmd_doctor(path: &Path, config_path: Option<&Path>) -> Result<()> {
+    validate_path(path)?;
+    let project_root = resolve_project_root(path, config_path);
+    let mut problems: Vec<String> = Vec::new();
+
+    println!("Boundary doctor: {}", path.display());
+    println!();
+    // An empty root means the working directory.
+    let canonical_root = Path::new(".")
+        .join(&project_root)
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.clone());
+    println!("Project root: {}", canonical_root.display());
+
+    // Config discovery and validation
+    let config_file = config_path
+        .map(Path::to_path_buf)
+        .or_else(|| Config::find(&canonical_root));
+    let config = match &config_file {
+        Some(file) => match Config::load(file) {
+            Ok(config) => {
+                println!("Config: {} (valid)", file.display());
+                config
+            }
+            Err(e) => {
+                println!("Config: {} (invalid, using defaults)", file.display());
+                problems.push(format!("{e:#}"));
+                Config::default()
+            }
+        },
+        None => {
+            println!("Config: none found, using defaults (run `boundary init` to create one)");
+            Config::default()
+        }
+    };
+    for (key, pattern, error) in invalid_layer_patterns(&config.layers) {
+        problems.push(format!(
+            "[layers].{key} pattern \"{pattern}\" is not a valid glob ({error}) and is ignored"
+        ));
+    }
+
+    // Grammar self-tests
+    println!();
+    println!("Grammars:");
+    let mut grammars_ok = true;
+    for lang in GRAMMAR_LANGUAGES {
+        match new_analyzer(lang) {
+            Some(Ok(_)) => println!("  {lang}: ok"),
+            Some(Err(e)) => {
+                grammars_ok = false;
+                println!("  {lang}: FAILED");
+                problems.push(format!("{lang} grammar self-test failed: {e:#}"));
+            }
+            None => {}
+        }
+    }
+
+    // Languages and files per language
+    let (languages, source) = if config.project.languages.is_empty() {
+        (auto_detect_languages(path), "auto-detected")
+    } else {
+        (config.project.languages.clone(), "from config")
+    };
+    println!();
+    println!("Languages ({source}):");
+    let mut analyzers = Vec::new();
+    let mut total_files = 0;
+    for lang in &languages {
+        match new_analyzer(lang) {
+            Some(Ok(analyzer)) => {
+                let files = find_source_files(path, analyzer.as_ref()).len();
+                println!("  {lang}: {files} file(s)");
+                if files == 0 {
+                    problems.push(format!(
+                        "no {lang} source files found; check the path or [project].languages"
+                    ));
+                }
+                total_files += files;
+                analyzers.push(analyzer);
+            }
+            // The grammar failure is already reported above.
+            Some(Err(_)) => println!("  {lang}: unavailable"),
+            None => {
+                println!("  {lang}: unsupported");
+                problems.push(format!(
+                    "[project].languages lists unsupported language \"{lang}\""
+                ));
+            }
+        }
+    }
+
+    // Files per layer
+    let buckets = LayerBuckets::collect(path, &project_root, &config, &analyzers);
+    println!();
+    println!("Layer classification (by path):");
+    for layer in LAYERS {
+        println!("  {layer}: {} file(s)", buckets.files(layer).len());
+    }
+    println!("  cross-cutting: {} file(s)", buckets.cross_cutting);
+    println!("  unmatched: {} file(s)", buckets.unmatched.len());
+
+    // Components, classified by path, annotation or classification rule
+    println!();
+    if total_files > 0 && grammars_ok {
+        let analysis = run_analysis(path, &project_root, &config, None, false, &[])?;
+        let Some(coverage) = analysis
+            .result
+            .metrics
+            .and_then(|m| m.classification_coverage)
+        else {
+            anyhow::bail!("analysis produced no classification coverage");
+        };
+        println!(
+            "Components: {} extracted, {} classified, {} cross-cutting, {} unclassified",
+            coverage.total_components,
+            coverage.classified,
+            coverage.cross_cutting,
+            coverage.unclassified
+        );
+        if coverage.total_components == 0 {
+            problems.push(format!(
+                "no components extracted from {total_files} source file(s)"
+            ));
+        } else if coverage.classified == 0 && coverage.unclassified > 0 {
+            problems.push(format!(
+                "all {} components unclassified — your layer globs match nothing; \
+                 run `boundary init --detect` to suggest globs for this tree",
+                coverage.unclassified
+            ));
+        }
+    } else {
+        println!("Components: not extracted");
+    }
+
+    println!();
+    if problems.is_empty() {
+        println!("No problems found.");
+        return Ok(());
+    }
+    println!("{} problem(s) found:", problems.len());
+    for problem in &problems {
+        println!("  - {problem}");
+    }
+    process::exit(1);
+}
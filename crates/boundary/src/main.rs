@@ -1,5 +1,8 @@
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
@@ -7,24 +10,58 @@ use rayon::prelude::*;
 use walkdir::WalkDir;
 
 use boundary_core::analyzer::LanguageAnalyzer;
+use boundary_core::baseline::{Baseline, BASELINE_FILE_NAME};
+use boundary_core::churn::{rank_hotspots, FileChurn};
+use boundary_core::classification::ComponentClassifier;
 use boundary_core::config::Config;
+use boundary_core::evolution::{self, ClassificationChange, ComponentClassification};
+use boundary_core::explain;
+use boundary_core::fitness;
+use boundary_core::generated::exempt_generated_violations;
+use boundary_core::grace::{apply_grace_period, FileAges};
 use boundary_core::graph::DependencyGraph;
 use boundary_core::layer::LayerClassifier;
+use boundary_core::lock::{ArchitectureLock, LOCK_FILE_NAME};
 use boundary_core::metrics;
 use boundary_core::pipeline::{self, reclassify_infra_handlers, AnalysisPipeline};
-use boundary_core::types::{DependencyKind, Severity};
+use boundary_core::rule::Rule;
+use boundary_core::timing::{Phase, PhaseTimer, PhaseTimings};
+use boundary_core::types::{ArchLayer, DependencyKind, Severity};
 
+use boundary_cpp::CppAnalyzer;
 use boundary_go::GoAnalyzer;
 use boundary_java::JavaAnalyzer;
-use boundary_report::{json, text};
+use boundary_report::{badge, gitlab, html, json, sarif, text};
 use boundary_rust::RustAnalyzer;
 use boundary_typescript::TypeScriptAnalyzer;
 
+mod doctor;
+mod remote;
+mod webhook;
+use remote::RemoteArgs;
+use webhook::WebhookArgs;
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 enum OutputFormat {
     Text,
     Json,
     Markdown,
+    /// Score badge for READMEs (`analyze` only; see `--badge-style`)
+    Badge,
+    /// GitLab Code Quality report (`analyze`, `check` and `merge` only)
+    Gitlab,
+    /// Self-contained HTML page (`analyze` and `check` only, single project)
+    Html,
+    /// SARIF 2.1.0 log for GitHub code scanning (`analyze` and `check` only)
+    Sarif,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum BadgeStyle {
+    /// Self-contained SVG image
+    Svg,
+    /// shields.io endpoint JSON (`{schemaVersion, label, message, color}`)
+    Shields,
 }
 
 #[derive(Parser)]
@@ -40,7 +77,7 @@ struct Cli {
 enum Commands {
     /// Analyze a codebase and print a full architecture report
     Analyze {
-        /// Path to the project root
+        /// Path to the project root, or a git URL to clone and analyze
         path: PathBuf,
         /// Config file path (defaults to .boundary.toml in project root)
         #[arg(short, long)]
@@ -60,16 +97,41 @@ enum Commands {
         /// Analyze each service independently (monorepo support)
         #[arg(long)]
         per_service: bool,
+        /// Score each `[[zones]]` entry with its own layers and scoring, plus an aggregate
+        #[arg(long, conflicts_with = "per_service")]
+        per_zone: bool,
+        /// Score each top-level directory on its own, plus an aggregate
+        #[arg(long, conflicts_with_all = ["per_service", "per_zone"])]
+        by_directory: bool,
         /// Output only the architecture score (one line)
         #[arg(long)]
         score_only: bool,
         /// Ignore specific rule IDs (comma-separated, e.g. PA001,L005)
         #[arg(long, value_delimiter = ',')]
         ignore: Option<Vec<String>>,
+        /// Report only the N most severe violations
+        #[arg(long, value_name = "N")]
+        max_violations: Option<usize>,
+        /// Write output to file instead of stdout (JSON is streamed)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Include the effective configuration in JSON output under `config`
+        #[arg(long)]
+        include_config: bool,
+        /// Print which files each layer pattern matched to stderr
+        #[arg(long)]
+        debug_classification: bool,
+        /// Badge flavor for --format badge
+        #[arg(long, value_enum, default_value_t = BadgeStyle::Svg)]
+        badge_style: BadgeStyle,
+        #[command(flatten)]
+        remote: RemoteArgs,
+        #[command(flatten)]
+        webhook: WebhookArgs,
     },
     /// Analyze and exit with code 0 (pass) or 1 (fail)
     Check {
-        /// Path to the project root
+        /// Path to the project root, or a git URL to clone and check
         path: PathBuf,
         /// Minimum severity to cause failure
         #[arg(long, default_value = "error")]
@@ -92,23 +154,179 @@ enum Commands {
         /// Fail if architecture score regresses from last snapshot
         #[arg(long)]
         no_regression: bool,
+        /// Print components whose layer or kind changed since the last snapshot
+        #[arg(long, alias = "components-changed")]
+        show_classification_changes: bool,
         /// Use incremental analysis (cache unchanged files)
         #[arg(long)]
         incremental: bool,
         /// Analyze each service independently (monorepo support)
         #[arg(long)]
         per_service: bool,
+        /// Score each `[[zones]]` entry with its own layers and scoring, plus an aggregate
+        #[arg(long, conflicts_with = "per_service")]
+        per_zone: bool,
         /// Ignore specific rule IDs (comma-separated, e.g. PA001,L005)
         #[arg(long, value_delimiter = ',')]
         ignore: Option<Vec<String>>,
+        /// Report only the N most severe violations
+        #[arg(long, value_name = "N")]
+        max_violations: Option<usize>,
+        /// Include the effective configuration in JSON output under `config`
+        #[arg(long)]
+        include_config: bool,
+        /// With --format json, print only pass/fail, the overall score and violation counts
+        #[arg(long)]
+        summary_only: bool,
+        /// Report drift from an approved architecture lock file (see `boundary lock`)
+        #[arg(long, value_name = "FILE")]
+        lock: Option<PathBuf>,
+        /// Downgrade violations in files first committed less than N days ago to warnings
+        #[arg(long, value_name = "N")]
+        grace_days: Option<u64>,
+        /// Ignore known violations recorded in a baseline file (see `boundary baseline`)
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+        /// Rewrite the --baseline file with the current violations before checking
+        #[arg(long, requires = "baseline")]
+        update_baseline: bool,
+        #[command(flatten)]
+        remote: RemoteArgs,
+        #[command(flatten)]
+        webhook: WebhookArgs,
     },
     /// Create a default .boundary.toml configuration file
     Init {
         /// Overwrite existing config
         #[arg(long)]
         force: bool,
+        /// Infer layer globs and languages from the directory tree at PATH and
+        /// write PATH/.boundary.toml
+        #[arg(long, value_name = "PATH")]
+        detect: Option<PathBuf>,
+    },
+    /// Print the complete default configuration with every available key
+    Config,
+    /// Combine saved JSON reports into one multi-service report
+    Merge {
+        /// JSON reports written by `analyze --format json` or `check --format json`
+        #[arg(required = true)]
+        reports: Vec<PathBuf>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+        format: OutputFormat,
+        /// Compact output (single-line JSON, no colors for text)
+        #[arg(long)]
+        compact: bool,
+        /// Write output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Analyze two trees and compare their scores and violation counts side by side
+    Compare {
+        /// Baseline tree (A)
+        left: PathBuf,
+        /// Tree compared against the baseline (B)
+        right: PathBuf,
+        /// Config file path, used for both trees (defaults to each tree's .boundary.toml)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Compact output (single-line JSON, no colors for text)
+        #[arg(long)]
+        compact: bool,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Write output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// List extracted components and dependencies, without scoring or rule checks
+    Extract {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Output format (only json is supported)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+        format: OutputFormat,
+        /// Compact output (single-line JSON)
+        #[arg(long)]
+        compact: bool,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Write output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Run the analysis repeatedly and report median time per phase
+    Bench {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Number of analysis runs
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+    },
+    /// Capture the current components and cross-layer edges as an approved architecture
+    Lock {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Write the lock to PATH/boundary.lock instead of printing it
+        #[arg(long)]
+        write: bool,
+    },
+    /// Record the current violations in a baseline file for `check --baseline`
+    Baseline {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Write the baseline to FILE instead of PATH/.boundary-baseline.json
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Apply machine-applicable fixes (e.g. port interface stubs for PA001) to source files
+    Fix {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Only apply fixes for this rule, by ID or name (e.g. PA001 or missing-port-interface)
+        #[arg(long)]
+        kind: Option<String>,
+        /// Print the changes as a diff without writing any files
+        #[arg(long)]
+        dry_run: bool,
     },
-    /// Generate an architecture diagram (Mermaid or DOT format)
+    /// Generate an architecture diagram (Mermaid, DOT or Cytoscape.js format)
     Diagram {
         /// Path to the project root
         path: PathBuf,
@@ -121,6 +339,89 @@ enum Commands {
         /// Languages to analyze (auto-detect if not specified)
         #[arg(long, value_delimiter = ',')]
         languages: Option<Vec<String>>,
+        /// Also draw external (third-party) packages and the dependencies on them
+        #[arg(long)]
+        include_external: bool,
+    },
+    /// Report R.C. Martin coupling metrics per package, or per component
+    Metrics {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Report each component, most distant from the main sequence first
+        #[arg(long)]
+        per_component: bool,
+        /// Output format (text or json)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Compact output (single-line JSON)
+        #[arg(long)]
+        compact: bool,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Write output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Rank files by git churn times violation count
+    Hotspots {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Output format (text or json)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Compact output (single-line JSON)
+        #[arg(long)]
+        compact: bool,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Report only the N highest-ranked files
+        #[arg(long, value_name = "N")]
+        top: Option<usize>,
+        /// Write output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Explain a violation: the dependencies behind it, the layers involved and how to fix it
+    Explain {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+        /// Violation fingerprint, or a unique prefix of one (the `fingerprint` in JSON output)
+        #[arg(long, required_unless_present = "kind")]
+        id: Option<String>,
+        /// Explain every violation of a rule, by ID (e.g. L001) or name (e.g. circular-dependency)
+        #[arg(long)]
+        kind: Option<String>,
+        /// Output format (text or json)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+        /// Compact output (single-line JSON)
+        #[arg(long)]
+        compact: bool,
+        /// Languages to analyze (auto-detect if not specified)
+        #[arg(long, value_delimiter = ',')]
+        languages: Option<Vec<String>>,
+        /// Write output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Check the environment and configuration: languages, config, grammars and layer globs
+    Doctor {
+        /// Path to the project root
+        path: PathBuf,
+        /// Config file path
+        #[arg(short, long)]
+        config: Option<PathBuf>,
     },
     /// Generate a detailed forensics report for a module
     Forensics {
@@ -147,6 +448,7 @@ enum DiagramType {
     Dependencies,
     Dot,
     DotDependencies,
+    Cytoscape,
 }
 
 fn main() {
@@ -161,19 +463,42 @@ fn main() {
             languages,
             incremental,
             per_service,
+            per_zone,
+            by_directory,
             score_only,
             ignore,
-        } => cmd_analyze(
-            &path,
-            config.as_deref(),
-            format,
-            compact,
-            languages.as_deref(),
-            incremental,
-            per_service,
-            score_only,
-            ignore.as_deref(),
-        ),
+            max_violations,
+            output,
+            include_config,
+            debug_classification,
+            badge_style,
+            remote,
+            webhook,
+        } => {
+            let config = remote::keep_local(&path, config);
+            let output = remote::keep_local(&path, output);
+            remote.run(&path, |path| {
+                cmd_analyze(
+                    path,
+                    config.as_deref(),
+                    format,
+                    compact,
+                    languages.as_deref(),
+                    incremental,
+                    per_service,
+                    per_zone,
+                    by_directory,
+                    score_only,
+                    ignore.as_deref(),
+                    max_violations,
+                    output.as_deref(),
+                    include_config,
+                    debug_classification,
+                    badge_style,
+                    &webhook,
+                )
+            })
+        }
         Commands::Check {
             path,
             fail_on,
@@ -183,247 +508,1397 @@ fn main() {
             languages,
             track,
             no_regression,
+            show_classification_changes,
             incremental,
             per_service,
+            per_zone,
             ignore,
-        } => cmd_check(
+            max_violations,
+            include_config,
+            summary_only,
+            lock,
+            grace_days,
+            baseline,
+            update_baseline,
+            remote,
+            webhook,
+        } => {
+            let config = remote::keep_local(&path, config);
+            let lock = remote::keep_local(&path, lock);
+            let baseline = remote::keep_local(&path, baseline);
+            let result = remote.run(&path, |path| {
+                cmd_check(
+                    path,
+                    &fail_on,
+                    config.as_deref(),
+                    format,
+                    compact,
+                    languages.as_deref(),
+                    track,
+                    no_regression,
+                    show_classification_changes,
+                    incremental,
+                    per_service,
+                    per_zone,
+                    ignore.as_deref(),
+                    max_violations,
+                    include_config,
+                    summary_only,
+                    lock.as_deref(),
+                    grace_days,
+                    baseline.as_deref(),
+                    update_baseline,
+                    &webhook,
+                )
+            });
+            match result {
+                // Exit here rather than in `cmd_check`, once a remote clone is removed.
+                Ok(false) => process::exit(1),
+                result => result.map(|_| ()),
+            }
+        }
+        Commands::Init { force, detect } => cmd_init(force, detect.as_deref()),
+        Commands::Config => {
+            print!("{}", Config::reference_toml());
+            Ok(())
+        }
+        Commands::Merge {
+            reports,
+            format,
+            compact,
+            output,
+        } => cmd_merge(&reports, format, compact, output.as_deref()),
+        Commands::Compare {
+            left,
+            right,
+            config,
+            format,
+            compact,
+            languages,
+            output,
+        } => cmd_compare(
+            &left,
+            &right,
+            config.as_deref(),
+            format,
+            compact,
+            languages.as_deref(),
+            output.as_deref(),
+        ),
+        Commands::Extract {
+            path,
+            config,
+            format,
+            compact,
+            languages,
+            output,
+        } => cmd_extract(
             &path,
-            &fail_on,
             config.as_deref(),
             format,
             compact,
             languages.as_deref(),
-            track,
-            no_regression,
-            incremental,
-            per_service,
-            ignore.as_deref(),
+            output.as_deref(),
+        ),
+        Commands::Bench {
+            path,
+            config,
+            runs,
+            format,
+            languages,
+        } => cmd_bench(&path, config.as_deref(), runs, format, languages.as_deref()),
+        Commands::Lock {
+            path,
+            config,
+            languages,
+            write,
+        } => cmd_lock(&path, config.as_deref(), languages.as_deref(), write),
+        Commands::Baseline {
+            path,
+            config,
+            languages,
+            output,
+        } => cmd_baseline(
+            &path,
+            config.as_deref(),
+            languages.as_deref(),
+            output.as_deref(),
+        ),
+        Commands::Fix {
+            path,
+            config,
+            languages,
+            kind,
+            dry_run,
+        } => cmd_fix(
+            &path,
+            config.as_deref(),
+            languages.as_deref(),
+            kind.as_deref(),
+            dry_run,
         ),
-        Commands::Init { force } => cmd_init(force),
         Commands::Diagram {
             path,
             config,
             diagram_type,
             languages,
-        } => cmd_diagram(&path, config.as_deref(), diagram_type, languages.as_deref()),
-        Commands::Forensics {
+            include_external,
+        } => cmd_diagram(
+            &path,
+            config.as_deref(),
+            diagram_type,
+            languages.as_deref(),
+            include_external,
+        ),
+        Commands::Metrics {
+            path,
+            config,
+            per_component,
+            format,
+            compact,
+            languages,
+            output,
+        } => cmd_metrics(
+            &path,
+            config.as_deref(),
+            per_component,
+            format,
+            compact,
+            languages.as_deref(),
+            output.as_deref(),
+        ),
+        Commands::Hotspots {
             path,
-            project_root,
             config,
+            format,
+            compact,
             languages,
+            top,
             output,
-        } => cmd_forensics(
+        } => cmd_hotspots(
             &path,
-            project_root.as_deref(),
             config.as_deref(),
+            format,
+            compact,
             languages.as_deref(),
+            top,
             output.as_deref(),
         ),
-    };
-
-    if let Err(e) = result {
-        eprintln!("Error: {e:#}");
-        process::exit(2);
-    }
-}
-
-fn validate_path(path: &Path) -> Result<()> {
-    if !path.exists() {
-        anyhow::bail!("path '{}' does not exist", path.display());
-    }
-    if !path.is_dir() {
-        anyhow::bail!("path '{}' is not a directory", path.display());
+        Commands::Explain {
+            path,
+            config,
+            id,
+            kind,
+            format,
+            compact,
+            languages,
+            output,
+        } => cmd_explain(
+            &path,
+            config.as_deref(),
+            id.as_deref(),
+            kind.as_deref(),
+            format,
+            compact,
+            languages.as_deref(),
+            output.as_deref(),
+        ),
+        Commands::Forensics {
+            path,
+            project_root,
+            config,
+            languages,
+            output,
+        } => cmd_forensics(
+            &path,
+            project_root.as_deref(),
+            config.as_deref(),
+            languages.as_deref(),
+            output.as_deref(),
+        ),
+        Commands::Doctor { path, config } => doctor::cmd_doctor(&path, config.as_deref()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {e:#}");
+        process::exit(2);
+    }
+}
+
+fn validate_path(path: &Path) -> Result<()> {
+    if !path.exists() {
+        anyhow::bail!("path '{}' does not exist", path.display());
+    }
+    if !path.is_dir() {
+        anyhow::bail!("path '{}' is not a directory", path.display());
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_analyze(
+    path: &Path,
+    config_path: Option<&Path>,
+    format: OutputFormat,
+    compact: bool,
+    languages: Option<&[String]>,
+    incremental: bool,
+    per_service: bool,
+    per_zone: bool,
+    by_directory: bool,
+    score_only: bool,
+    ignore: Option<&[String]>,
+    max_violations: Option<usize>,
+    output_path: Option<&Path>,
+    include_config: bool,
+    debug_classification: bool,
+    badge_style: BadgeStyle,
+    webhook: &WebhookArgs,
+) -> Result<()> {
+    validate_path(path)?;
+    let webhook = webhook.resolve()?;
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+
+    if debug_classification {
+        print_classification_debug(path, &project_root, &config, languages)?;
+    }
+
+    if per_service || per_zone || by_directory {
+        reject_html(format, "multi-service reports")?;
+        let analyzers = create_analyzers(path, &config, languages)?;
+        let effective_config = include_config.then(|| config.clone());
+        let pipeline = AnalysisPipeline::new(analyzers, config)?;
+        let mut multi = if per_zone {
+            pipeline.analyze_per_zone(path)?
+        } else if by_directory {
+            pipeline.analyze_per_directory(path)?
+        } else {
+            pipeline.analyze_per_service(path)?
+        };
+        multi.aggregate.config = effective_config;
+        apply_max_violations_multi(&mut multi, max_violations);
+        if let Some(hook) = &webhook {
+            hook.deliver(&json::format_multi_service_report(&multi, true))?;
+        }
+
+        if score_only {
+            for svc in &multi.services {
+                print_score_only(&svc.service_name, svc.result.score.as_ref(), format);
+            }
+            return Ok(());
+        }
+
+        if let (OutputFormat::Json, Some(out_path)) = (format, output_path) {
+            return stream_to_file(out_path, |w| {
+                json::write_multi_service_report(&multi, compact, w)
+            });
+        }
+
+        let report = match format {
+            OutputFormat::Text => text::format_multi_service_report(&multi),
+            OutputFormat::Json => json::format_multi_service_report(&multi, compact),
+            OutputFormat::Markdown => {
+                boundary_report::markdown::format_multi_service_report(&multi)
+            }
+            OutputFormat::Badge => {
+                format_badge(multi.aggregate.score.as_ref(), badge_style, compact)
+            }
+            OutputFormat::Gitlab => gitlab::format_multi_service_report(&multi, compact),
+            OutputFormat::Html => unreachable!("rejected by reject_html"),
+            OutputFormat::Sarif => sarif::format_multi_service_report(&multi),
+        };
+        return emit_report(&report, output_path);
+    }
+
+    let mut analysis = run_analysis(path, &project_root, &config, languages, incremental, &[])?;
+    filter_ignored_violations(&mut analysis.result, ignore);
+    if let Some(max) = max_violations {
+        analysis.result.truncate_violations(max);
+    }
+    if include_config {
+        analysis.result.config = Some(config);
+    }
+    if let Some(hook) = &webhook {
+        hook.deliver(&json::format_report(&analysis.result, true))?;
+    }
+
+    if score_only {
+        let module_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.to_string_lossy().into_owned());
+        print_score_only(&module_name, analysis.result.score.as_ref(), format);
+        return Ok(());
+    }
+
+    if let (OutputFormat::Json, Some(out_path)) = (format, output_path) {
+        return stream_to_file(out_path, |w| {
+            json::write_report(&analysis.result, compact, w)
+        });
+    }
+
+    let report = match format {
+        OutputFormat::Text => text::format_report(&analysis.result),
+        OutputFormat::Json => json::format_report(&analysis.result, compact),
+        OutputFormat::Markdown => boundary_report::markdown::format_report(&analysis.result),
+        OutputFormat::Badge => format_badge(analysis.result.score.as_ref(), badge_style, compact),
+        OutputFormat::Gitlab => gitlab::format_report(&analysis.result, compact),
+        OutputFormat::Html => html::format_report(&analysis.result),
+        OutputFormat::Sarif => sarif::format_report(&analysis.result),
+    };
+    emit_report(&report, output_path)
+}
+
+/// Fail for `--format badge` outside `analyze`, the only command that renders badges.
+fn reject_badge(format: OutputFormat, command: &str) -> Result<()> {
+    if matches!(format, OutputFormat::Badge) {
+        anyhow::bail!("--format badge is only supported by analyze, not {command}");
+    }
+    Ok(())
+}
+
+/// Fail for `--format gitlab` in commands that report no violations.
+fn reject_gitlab(format: OutputFormat, command: &str) -> Result<()> {
+    if matches!(format, OutputFormat::Gitlab) {
+        anyhow::bail!(
+            "--format gitlab is only supported by analyze, check and merge, not {command}"
+        );
+    }
+    Ok(())
+}
+
+/// Fail for `--format sarif` in commands other than `analyze` and `check`.
+fn reject_sarif(format: OutputFormat, command: &str) -> Result<()> {
+    if matches!(format, OutputFormat::Sarif) {
+        anyhow::bail!("--format sarif is only supported by analyze and check, not {command}");
+    }
+    Ok(())
+}
+
+/// Fail for `--format html` outside single-project `analyze` and `check`.
+fn reject_html(format: OutputFormat, command: &str) -> Result<()> {
+    if matches!(format, OutputFormat::Html) {
+        anyhow::bail!("--format html is only supported by analyze and check, not {command}");
+    }
+    Ok(())
+}
+
+fn format_badge(
+    score: Option<&metrics::ArchitectureScore>,
+    style: BadgeStyle,
+    compact: bool,
+) -> String {
+    match style {
+        BadgeStyle::Svg => badge::format_svg(score),
+        BadgeStyle::Shields => badge::format_shields_endpoint(score, compact),
+    }
+}
+
+/// Number of matched paths listed per layer by `--debug-classification`.
+const DEBUG_CLASSIFICATION_SAMPLE: usize = 10;
+
+/// Architectural layers, innermost first.
+const LAYERS: [ArchLayer; 4] = [
+    ArchLayer::Domain,
+    ArchLayer::Application,
+    ArchLayer::Infrastructure,
+    ArchLayer::Presentation,
+];
+
+/// Discovered source files bucketed by layer.
+#[derive(Default)]
+struct LayerBuckets {
+    by_layer: HashMap<ArchLayer, Vec<String>>,
+    cross_cutting: usize,
+    unmatched: Vec<String>,
+}
+
+impl LayerBuckets {
+    /// Classify every source file the analyzers handle as analysis does: by a
+    /// `boundary:layer` annotation in the file, else by its path relative to
+    /// `project_root`. Files within each bucket are sorted.
+    fn collect(
+        project_path: &Path,
+        project_root: &Path,
+        config: &Config,
+        analyzers: &[Box<dyn LanguageAnalyzer>],
+    ) -> Self {
+        let classifier = LayerClassifier::new(&config.layers);
+        let mut buckets = Self::default();
+        for analyzer in analyzers {
+            for file_path in find_source_files(project_path, analyzer.as_ref()) {
+                let rel_path = file_path
+                    .strip_prefix(project_root)
+                    .unwrap_or(&file_path)
+                    .to_string_lossy()
+                    .to_string();
+                let content = std::fs::read_to_string(&file_path).unwrap_or_default();
+                if classifier.is_cross_cutting(&rel_path) {
+                    buckets.cross_cutting += 1;
+                } else if let Some(layer) = classifier.classify_file(&rel_path, &content) {
+                    buckets.by_layer.entry(layer).or_default().push(rel_path);
+                } else {
+                    buckets.unmatched.push(rel_path);
+                }
+            }
+        }
+        buckets.by_layer.values_mut().for_each(|files| files.sort());
+        buckets.unmatched.sort();
+        buckets
+    }
+
+    /// Files classified into `layer`.
+    fn files(&self, layer: ArchLayer) -> &[String] {
+        self.by_layer.get(&layer).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Bucket every discovered source file by its layer and print the buckets to stderr,
+/// so users can check their layer globs.
+fn print_classification_debug(
+    project_path: &Path,
+    project_root: &Path,
+    config: &Config,
+    languages: Option<&[String]>,
+) -> Result<()> {
+    let analyzers = create_analyzers(project_path, config, languages)?;
+    let buckets = LayerBuckets::collect(project_path, project_root, config, &analyzers);
+
+    eprintln!("Layer classification:");
+    for layer in LAYERS {
+        let files = buckets.files(layer);
+        eprintln!("  {layer}: {} file(s)", files.len());
+        for file in files.iter().take(DEBUG_CLASSIFICATION_SAMPLE) {
+            eprintln!("    {file}");
+        }
+        if files.len() > DEBUG_CLASSIFICATION_SAMPLE {
+            eprintln!(
+                "    … and {} more",
+                files.len() - DEBUG_CLASSIFICATION_SAMPLE
+            );
+        }
+    }
+    eprintln!("  cross-cutting: {} file(s)", buckets.cross_cutting);
+    eprintln!("  unmatched: {} file(s)", buckets.unmatched.len());
+    for file in &buckets.unmatched {
+        eprintln!("    {file}");
+    }
+    Ok(())
+}
+
+/// Print a report, or write it to `output_path` with the same trailing newline.
+fn emit_report(report: &str, output_path: Option<&Path>) -> Result<()> {
+    match output_path {
+        Some(out_path) => std::fs::write(out_path, format!("{report}\n"))
+            .with_context(|| format!("failed to write output to {}", out_path.display())),
+        None => {
+            println!("{report}");
+            Ok(())
+        }
+    }
+}
+
+/// Stream a report into `out_path` through a buffered writer, so large JSON
+/// results are never held in memory as a single string.
+fn stream_to_file(
+    out_path: &Path,
+    write: impl FnOnce(&mut std::io::BufWriter<std::fs::File>) -> std::io::Result<()>,
+) -> Result<()> {
+    use std::io::Write;
+
+    let file = std::fs::File::create(out_path)
+        .with_context(|| format!("failed to create {}", out_path.display()))?;
+    let mut writer = std::io::BufWriter::new(file);
+    write(&mut writer)
+        .and_then(|()| writeln!(writer))
+        .and_then(|()| writer.flush())
+        .with_context(|| format!("failed to write output to {}", out_path.display()))
+}
+
+fn print_score_only(
+    module: &str,
+    score: Option<&metrics::ArchitectureScore>,
+    format: OutputFormat,
+) {
+    let overall = score.map(|s| s.overall).unwrap_or(0.0);
+    let presence = score.map(|s| s.structural_presence).unwrap_or(0.0);
+    let conformance = score.map(|s| s.layer_conformance).unwrap_or(0.0);
+    let compliance = score.map(|s| s.dependency_compliance).unwrap_or(0.0);
+    let iface = score.map(|s| s.interface_coverage).unwrap_or(0.0);
+    match format {
+        OutputFormat::Json => {
+            println!(
+                "{{\"module\":\"{module}\",\"overall\":{overall:.1},\"structural_presence\":{presence:.1},\"layer_conformance\":{conformance:.1},\"dependency_compliance\":{compliance:.1},\"interface_coverage\":{iface:.1}}}"
+            );
+        }
+        OutputFormat::Text
+        | OutputFormat::Markdown
+        | OutputFormat::Badge
+        | OutputFormat::Gitlab
+        | OutputFormat::Html
+        | OutputFormat::Sarif => {
+            println!(
+                "{module}: {overall:.1}/100 (Presence: {presence:.1}, Conformance: {conformance:.1}, Compliance: {compliance:.1}, Interfaces: {iface:.1})"
+            );
+        }
+    }
+}
+
+/// Print classification changes since the last snapshot to stderr, keeping stdout
+/// for the report.
+fn print_classification_changes(changes: Option<&[ClassificationChange]>) {
+    let Some(changes) = changes else {
+        eprintln!(
+            "No snapshot with component classifications to compare against; \
+             run `boundary check --track` to record one."
+        );
+        return;
+    };
+    if changes.is_empty() {
+        eprintln!("No classification changes since the last snapshot.");
+        return;
+    }
+    let describe = |c: &ComponentClassification| match c.layer {
+        Some(layer) => format!("{layer} {}", c.kind),
+        None => format!("unclassified {}", c.kind),
+    };
+    eprintln!("Classification changes since the last snapshot:");
+    for change in changes {
+        match change {
+            ClassificationChange::Added(c) => eprintln!("  + {} ({})", c.id, describe(c)),
+            ClassificationChange::Removed(c) => eprintln!("  - {} ({})", c.id, describe(c)),
+            ClassificationChange::Changed { previous, current } => eprintln!(
+                "  ~ {}: {} -> {}",
+                current.id,
+                describe(previous),
+                describe(current)
+            ),
+        }
+    }
+}
+
+/// Run `boundary check`, returning whether it passed.
+#[allow(clippy::too_many_arguments)]
+fn cmd_check(
+    path: &Path,
+    fail_on_str: &str,
+    config_path: Option<&Path>,
+    format: OutputFormat,
+    compact: bool,
+    languages: Option<&[String]>,
+    track: bool,
+    no_regression: bool,
+    show_classification_changes: bool,
+    incremental: bool,
+    per_service: bool,
+    per_zone: bool,
+    ignore: Option<&[String]>,
+    max_violations: Option<usize>,
+    include_config: bool,
+    summary_only: bool,
+    lock_path: Option<&Path>,
+    grace_days: Option<u64>,
+    baseline_path: Option<&Path>,
+    update_baseline: bool,
+    webhook: &WebhookArgs,
+) -> Result<bool> {
+    validate_path(path)?;
+    reject_badge(format, "check")?;
+    let webhook = webhook.resolve()?;
+    if summary_only && !matches!(format, OutputFormat::Json) {
+        anyhow::bail!("--summary-only requires --format json");
+    }
+    if summary_only && (per_service || per_zone) {
+        anyhow::bail!("--summary-only cannot be combined with --per-service or --per-zone");
+    }
+    if lock_path.is_some() && (per_service || per_zone) {
+        anyhow::bail!("--lock cannot be combined with --per-service or --per-zone");
+    }
+    if baseline_path.is_some() && (per_service || per_zone) {
+        anyhow::bail!("--baseline cannot be combined with --per-service or --per-zone");
+    }
+    if show_classification_changes && (per_service || per_zone) {
+        anyhow::bail!(
+            "--show-classification-changes cannot be combined with --per-service or --per-zone"
+        );
+    }
+    let lock = lock_path.map(ArchitectureLock::load).transpose()?;
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+    let fail_on: Severity = fail_on_str.parse()?;
+
+    if per_service || per_zone {
+        reject_html(format, "multi-service reports")?;
+        let analyzers = create_analyzers(path, &config, languages)?;
+        let effective_config = include_config.then(|| config.clone());
+        let fail_on_generated = config.rules.fail_on_generated;
+        let assertions = config.fitness.clone();
+        let pipeline = AnalysisPipeline::new(analyzers, config)?;
+        let mut multi = if per_zone {
+            pipeline.analyze_per_zone(path)?
+        } else {
+            pipeline.analyze_per_service(path)?
+        };
+        multi.aggregate.config = effective_config;
+        if let Some(days) = grace_days {
+            let ages = FileAges::from_git(path);
+            for svc in &mut multi.services {
+                apply_grace_period(&mut svc.result.violations, days, |f| ages.age_days(f));
+            }
+            apply_grace_period(&mut multi.aggregate.violations, days, |f| ages.age_days(f));
+        }
+        if !fail_on_generated {
+            for svc in &mut multi.services {
+                exempt_generated_violations(&mut svc.result);
+            }
+            exempt_generated_violations(&mut multi.aggregate);
+        }
+
+        // Decide pass/fail on the full violation lists, before any truncation.
+        let has_failures = multi
+            .services
+            .iter()
+            .any(|s| s.result.violations_at_or_above(fail_on).next().is_some());
+        let fitness_failures = fitness::evaluate_all(&assertions, &multi.aggregate)?;
+        apply_max_violations_multi(&mut multi, max_violations);
+        if let Some(hook) = &webhook {
+            hook.deliver(&json::format_multi_service_report(&multi, true))?;
+        }
+
+        let report = match format {
+            OutputFormat::Text => text::format_multi_service_report(&multi),
+            OutputFormat::Json => json::format_multi_service_report(&multi, compact),
+            OutputFormat::Markdown => {
+                boundary_report::markdown::format_multi_service_report(&multi)
+            }
+            OutputFormat::Badge => unreachable!("rejected by reject_badge"),
+            OutputFormat::Gitlab => gitlab::format_multi_service_report(&multi, compact),
+            OutputFormat::Html => unreachable!("rejected by reject_html"),
+            OutputFormat::Sarif => sarif::format_multi_service_report(&multi),
+        };
+        println!("{report}");
+        report_fitness_failures(&fitness_failures);
+        return Ok(!has_failures && fitness_failures.is_empty());
+    }
+
+    let rules: Vec<Box<dyn Rule>> = lock
+        .map(|lock| Box::new(lock.into_rule(path)) as Box<dyn Rule>)
+        .into_iter()
+        .collect();
+    let mut analysis = run_analysis(path, &project_root, &config, languages, incremental, &rules)?;
+    filter_ignored_violations(&mut analysis.result, ignore);
+    if let Some(days) = grace_days {
+        let ages = FileAges::from_git(path);
+        apply_grace_period(&mut analysis.result.violations, days, |f| ages.age_days(f));
+    }
+    if !config.rules.fail_on_generated {
+        exempt_generated_violations(&mut analysis.result);
+    }
+    if let Some(baseline_path) = baseline_path {
+        let baseline = if update_baseline {
+            let baseline = Baseline::from_violations(&analysis.result.violations, path);
+            baseline.save(baseline_path)?;
+            eprintln!(
+                "Updated baseline {} ({} violation(s))",
+                baseline_path.display(),
+                baseline.violations.len()
+            );
+            baseline
+        } else {
+            Baseline::load(baseline_path)?
+        };
+        let suppressed = baseline.suppress(&mut analysis.result.violations, path);
+        if suppressed > 0 {
+            eprintln!("{suppressed} known violation(s) suppressed by the baseline");
+        }
+    }
+    let fitness_failures = fitness::evaluate_all(&config.fitness, &analysis.result)?;
+
+    // Evolution tracking. Classification changes are read before this run's snapshot
+    // is appended, so they compare against the previous run.
+    let classifications = ComponentClassification::from_graph(&analysis.graph, path);
+    if show_classification_changes {
+        let changes = evolution::classification_diff(path, &classifications)?;
+        print_classification_changes(changes.as_deref());
+    }
+    if track {
+        evolution::save_snapshot(path, &analysis.result, &classifications)?;
+    }
+    if include_config {
+        analysis.result.config = Some(config);
+    }
+    if let Some(hook) = &webhook {
+        let (body, _) = json::format_check(&analysis.result, fail_on, true, summary_only);
+        hook.deliver(&body)?;
+    }
+    // Regressions are judged on the full violation list, before truncation.
+    let regression = if no_regression {
+        evolution::check_regression(path, &analysis.result)?
+    } else {
+        None
+    };
+
+    // Truncation only affects what is reported; pass/fail uses every violation.
+    let has_failures = analysis
+        .result
+        .violations_at_or_above(fail_on)
+        .next()
+        .is_some();
+    if let Some(max) = max_violations {
+        analysis.result.truncate_violations(max);
+    }
+
+    let report = match format {
+        OutputFormat::Text => text::format_check(&analysis.result, fail_on).0,
+        OutputFormat::Json => {
+            json::format_check(&analysis.result, fail_on, compact, summary_only).0
+        }
+        OutputFormat::Markdown => {
+            boundary_report::markdown::format_check(&analysis.result, fail_on).0
+        }
+        OutputFormat::Badge => unreachable!("rejected by reject_badge"),
+        OutputFormat::Gitlab => gitlab::format_report(&analysis.result, compact),
+        OutputFormat::Html => html::format_check(&analysis.result, fail_on).0,
+        OutputFormat::Sarif => sarif::format_report(&analysis.result),
+    };
+    println!("{report}");
+    if let Some(trend) = regression {
+        eprintln!("Architecture regression detected!");
+        eprintln!(
+            "  Score: {:.1} -> {:.1} ({:+.1})",
+            trend.previous_score, trend.current_score, trend.score_delta
+        );
+        eprintln!(
+            "  Violations: {} -> {} ({:+})",
+            trend.previous_violations, trend.current_violations, trend.violation_delta
+        );
+        for rt in &trend.rule_trends {
+            if rt.delta != 0 {
+                eprintln!(
+                    "    {}: {} -> {} ({:+})",
+                    rt.rule_id, rt.previous_count, rt.current_count, rt.delta
+                );
+            }
+        }
+        return Ok(false);
+    }
+    report_fitness_failures(&fitness_failures);
+    Ok(!has_failures && fitness_failures.is_empty())
+}
+
+/// Print each failed `[fitness]` assertion with the values of the metrics it uses.
+fn report_fitness_failures(failures: &[fitness::FitnessFailure]) {
+    for failure in failures {
+        eprintln!(
+            "Fitness function '{}' failed: {}",
+            failure.name, failure.expression
+        );
+        let values: Vec<String> = failure
+            .values
+            .iter()
+            .map(|(metric, value)| match value.fract() {
+                0.0 => format!("{metric} = {value}"),
+                _ => format!("{metric} = {value:.1}"),
+            })
+            .collect();
+        if !values.is_empty() {
+            eprintln!("  {}", values.join(", "));
+        }
+    }
+}
+
+fn cmd_lock(
+    path: &Path,
+    config_path: Option<&Path>,
+    languages: Option<&[String]>,
+    write: bool,
+) -> Result<()> {
+    validate_path(path)?;
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
+    let lock = ArchitectureLock::from_graph(&analysis.graph, path).to_toml()?;
+
+    if !write {
+        print!("{lock}");
+        return Ok(());
+    }
+    let target = path.join(LOCK_FILE_NAME);
+    std::fs::write(&target, lock)
+        .with_context(|| format!("failed to write {}", target.display()))?;
+    println!("Wrote {}", target.display());
+    Ok(())
+}
+
+fn cmd_baseline(
+    path: &Path,
+    config_path: Option<&Path>,
+    languages: Option<&[String]>,
+    output_path: Option<&Path>,
+) -> Result<()> {
+    validate_path(path)?;
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
+    let baseline = Baseline::from_violations(&analysis.result.violations, path);
+
+    let target = output_path.map_or_else(|| path.join(BASELINE_FILE_NAME), Path::to_path_buf);
+    baseline.save(&target)?;
+    println!(
+        "Wrote {} violation(s) to {}",
+        baseline.violations.len(),
+        target.display()
+    );
+    Ok(())
+}
+
+fn cmd_fix(
+    path: &Path,
+    config_path: Option<&Path>,
+    languages: Option<&[String]>,
+    kind: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    validate_path(path)?;
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
+
+    let mut by_file: BTreeMap<PathBuf, Vec<&boundary_core::types::Fix>> = BTreeMap::new();
+    for violation in &analysis.result.violations {
+        let Some(fix) = &violation.fix else { continue };
+        if let Some(kind) = kind {
+            let rule = violation.kind.rule_id().to_string();
+            if !rule.eq_ignore_ascii_case(kind) && violation.kind.name() != kind {
+                continue;
+            }
+        }
+        by_file
+            .entry(fix.location.file.clone())
+            .or_default()
+            .push(fix);
+    }
+
+    let mut applied = 0;
+    let mut files = 0;
+    for (file, fixes) in &by_file {
+        let source = std::fs::read_to_string(file)
+            .with_context(|| format!("failed to read {}", file.display()))?;
+        let fixes = boundary_core::fix::applicable_fixes(&source, fixes);
+        if fixes.is_empty() {
+            continue;
+        }
+        if dry_run {
+            print!(
+                "{}",
+                boundary_core::fix::insertion_diff(&file.to_string_lossy(), &fixes)
+            );
+        } else {
+            std::fs::write(file, boundary_core::fix::apply_fixes(&source, &fixes))
+                .with_context(|| format!("failed to write {}", file.display()))?;
+            for fix in &fixes {
+                println!(
+                    "{}:{}: {}",
+                    file.display(),
+                    fix.location.line,
+                    fix.description
+                );
+            }
+        }
+        applied += fixes.len();
+        files += 1;
+    }
+
+    if applied == 0 {
+        println!("No machine-applicable fixes found.");
+    } else if dry_run {
+        eprintln!("{applied} fix(es) for {files} file(s); no files were changed (--dry-run).");
+    } else {
+        println!("Applied {applied} fix(es) to {files} file(s).");
+    }
+    Ok(())
+}
+
+fn cmd_init(force: bool, detect: Option<&Path>) -> Result<()> {
+    let target = detect.map_or_else(
+        || PathBuf::from(".boundary.toml"),
+        |path| path.join(".boundary.toml"),
+    );
+    if target.exists() && !force {
+        anyhow::bail!(
+            "{} already exists. Use --force to overwrite.",
+            target.display()
+        );
+    }
+    let Some(project_path) = detect else {
+        std::fs::write(&target, Config::default_toml())?;
+        println!("Created .boundary.toml with default configuration.");
+        return Ok(());
+    };
+
+    if !project_path.is_dir() {
+        anyhow::bail!("{} is not a directory", project_path.display());
+    }
+    let languages = auto_detect_languages(project_path);
+    let layers = detect_layer_globs(project_path);
+    std::fs::write(&target, detected_config_toml(&languages, &layers))?;
+
+    println!("Created {} from the detected structure.", target.display());
+    if languages.is_empty() {
+        println!("  languages: none detected");
+    } else {
+        println!("  languages: {}", languages.join(", "));
+    }
+    for (layer, globs) in &layers {
+        println!("  {layer}: {}", globs.join(", "));
+    }
+    if layers.is_empty() {
+        println!("  no layer directories found; default layer patterns apply");
+    }
+    Ok(())
+}
+
+/// Directory names that name a layer but are not covered by the default layer globs.
+const LAYER_DIR_ALIASES: &[(&str, ArchLayer)] = &[
+    ("app", ArchLayer::Application),
+    ("infra", ArchLayer::Infrastructure),
+];
+
+/// Walk `project_path` for directories whose names match layer keywords and return a
+/// glob per directory, grouped by layer in layer order. Once a directory is matched its
+/// subdirectories are not considered, so `internal/domain/user` stays under
+/// `internal/domain/**`.
+fn detect_layer_globs(project_path: &Path) -> Vec<(ArchLayer, Vec<String>)> {
+    let classifier = LayerClassifier::new(&Config::default().layers);
+    let mut detected: Vec<(ArchLayer, String)> = Vec::new();
+
+    let mut walker = WalkDir::new(project_path)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else { continue };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        if name.starts_with('.') || matches!(name.as_str(), "vendor" | "node_modules" | "target") {
+            walker.skip_current_dir();
+            continue;
+        }
+        let layer = classifier.classify_import(&name).or_else(|| {
+            LAYER_DIR_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == name)
+                .map(|(_, layer)| *layer)
+        });
+        if let Some(layer) = layer {
+            let rel = entry
+                .path()
+                .strip_prefix(project_path)
+                .unwrap_or(entry.path())
+                .to_string_lossy()
+                .replace('\\', "/");
+            detected.push((layer, format!("{rel}/**")));
+            walker.skip_current_dir();
+        }
+    }
+
+    LAYERS
+        .into_iter()
+        .filter_map(|layer| {
+            let globs: Vec<String> = detected
+                .iter()
+                .filter(|(l, _)| *l == layer)
+                .map(|(_, glob)| glob.clone())
+                .collect();
+            (!globs.is_empty()).then_some((layer, globs))
+        })
+        .collect()
+}
+
+/// Render the config written by `init --detect`. Layers that were not detected are
+/// left out so they keep the default patterns.
+fn detected_config_toml(languages: &[String], layers: &[(ArchLayer, Vec<String>)]) -> String {
+    let array = |items: &[String]| toml::Value::from(items.to_vec()).to_string();
+    let mut out = String::from(
+        "# Boundary - Architecture Analysis Configuration\n\
+         # Generated by `boundary init --detect`; review the layer globs below.\n\n\
+         [project]\n",
+    );
+    out.push_str(&format!("languages = {}\n\n[layers]\n", array(languages)));
+    out.push_str("# Glob patterns to classify files into architectural layers\n");
+    for (layer, globs) in layers {
+        out.push_str(&format!("{layer} = {}\n", array(globs)));
+    }
+    out
+}
+
+fn cmd_merge(
+    reports: &[PathBuf],
+    format: OutputFormat,
+    compact: bool,
+    output_path: Option<&Path>,
+) -> Result<()> {
+    reject_badge(format, "merge")?;
+    reject_html(format, "merge")?;
+    reject_sarif(format, "merge")?;
+    let mut services = Vec::with_capacity(reports.len());
+    for report in reports {
+        let content = std::fs::read_to_string(report)
+            .with_context(|| format!("failed to read {}", report.display()))?;
+        let result = json::read_report(&content)
+            .with_context(|| format!("failed to load report {}", report.display()))?;
+        services.push(metrics::ServiceAnalysisResult {
+            service_name: merged_service_name(report, reports),
+            result,
+        });
+    }
+
+    let multi = metrics::MultiServiceResult {
+        aggregate: metrics::aggregate_results(&services),
+        services,
+        shared_modules: Vec::new(),
+        shared_module_graph: Vec::new(),
+    };
+    let report = match format {
+        OutputFormat::Text => text::format_multi_service_report(&multi),
+        OutputFormat::Json => json::format_multi_service_report(&multi, compact),
+        OutputFormat::Markdown => boundary_report::markdown::format_multi_service_report(&multi),
+        OutputFormat::Badge => unreachable!("rejected by reject_badge"),
+        OutputFormat::Gitlab => gitlab::format_multi_service_report(&multi, compact),
+        OutputFormat::Html => unreachable!("rejected by reject_html"),
+        OutputFormat::Sarif => unreachable!("rejected by reject_sarif"),
+    };
+    emit_report(&report, output_path)
+}
+
+fn cmd_compare(
+    left: &Path,
+    right: &Path,
+    config_path: Option<&Path>,
+    format: OutputFormat,
+    compact: bool,
+    languages: Option<&[String]>,
+    output_path: Option<&Path>,
+) -> Result<()> {
+    reject_badge(format, "compare")?;
+    reject_gitlab(format, "compare")?;
+    reject_html(format, "compare")?;
+    reject_sarif(format, "compare")?;
+    let analyze = |path: &Path| -> Result<metrics::AnalysisResult> {
+        validate_path(path)?;
+        let project_root = resolve_project_root(path, config_path);
+        let config = load_config(&project_root, config_path)?;
+        Ok(run_analysis(path, &project_root, &config, languages, false, &[])?.result)
+    };
+    let left_result = analyze(left)?;
+    let right_result = analyze(right)?;
+
+    let comparison = boundary_core::compare::compare_results(
+        &left.display().to_string(),
+        &left_result,
+        &right.display().to_string(),
+        &right_result,
+    );
+    let report = match format {
+        OutputFormat::Text => text::format_comparison(&comparison),
+        OutputFormat::Json => json::format_comparison(&comparison, compact),
+        OutputFormat::Markdown => boundary_report::markdown::format_comparison(&comparison),
+        OutputFormat::Badge => unreachable!("rejected by reject_badge"),
+        OutputFormat::Gitlab => unreachable!("rejected by reject_gitlab"),
+        OutputFormat::Html => unreachable!("rejected by reject_html"),
+        OutputFormat::Sarif => unreachable!("rejected by reject_sarif"),
+    };
+    emit_report(&report, output_path)
+}
+
+/// Name a merged report after its file stem, or its full path when stems collide.
+fn merged_service_name(report: &Path, all: &[PathBuf]) -> String {
+    let stem = |p: &Path| p.file_stem().map(|s| s.to_string_lossy().into_owned());
+    match stem(report) {
+        Some(name)
+            if all
+                .iter()
+                .filter(|p| stem(p).as_ref() == Some(&name))
+                .count()
+                == 1 =>
+        {
+            name
+        }
+        _ => report.display().to_string(),
+    }
+}
+
+fn cmd_extract(
+    path: &Path,
+    config_path: Option<&Path>,
+    format: OutputFormat,
+    compact: bool,
+    languages: Option<&[String]>,
+    output_path: Option<&Path>,
+) -> Result<()> {
+    validate_path(path)?;
+    if !matches!(format, OutputFormat::Json) {
+        anyhow::bail!("extract only supports --format json");
+    }
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+    let analyzers = create_analyzers(path, &config, languages)?;
+
+    let mut files = 0;
+    let mut components = Vec::new();
+    let mut dependencies = Vec::new();
+    for analyzer in &analyzers {
+        let source_files = find_source_files(path, analyzer.as_ref());
+        files += source_files.len();
+        let extracted: Vec<_> = source_files
+            .par_iter()
+            .filter_map(|file_path| {
+                let content = match std::fs::read_to_string(file_path) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Warning: failed to read {}: {e}", file_path.display());
+                        return None;
+                    }
+                };
+                let parsed = match analyzer.parse_file(file_path, &content) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        eprintln!("Warning: failed to parse {}: {e}", file_path.display());
+                        return None;
+                    }
+                };
+                let deps: Vec<_> = analyzer
+                    .extract_dependencies(&parsed)
+                    .into_iter()
+                    .filter(|dep| {
+                        matches!(dep.kind, DependencyKind::MethodCall)
+                            || !dep.import_path.as_deref().is_some_and(|p| {
+                                analyzer.is_stdlib_import(p) && !config.rules.is_infra_import(p)
+                            })
+                    })
+                    .collect();
+                Some((analyzer.extract_components(&parsed), deps))
+            })
+            .collect();
+        for (comps, deps) in extracted {
+            components.extend(comps);
+            dependencies.extend(deps);
+        }
     }
-    Ok(())
+    components.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+    dependencies.sort_by(|a, b| (&a.from.0, &a.to.0).cmp(&(&b.from.0, &b.to.0)));
+
+    let report = serde_json::json!({
+        "files_analyzed": files,
+        "components": components,
+        "dependencies": dependencies,
+    });
+    let report = if compact {
+        serde_json::to_string(&report)?
+    } else {
+        serde_json::to_string_pretty(&report)?
+    };
+    emit_report(&report, output_path)
 }
 
-#[allow(clippy::too_many_arguments)]
-fn cmd_analyze(
+fn cmd_metrics(
     path: &Path,
     config_path: Option<&Path>,
+    per_component: bool,
     format: OutputFormat,
     compact: bool,
     languages: Option<&[String]>,
-    incremental: bool,
-    per_service: bool,
-    score_only: bool,
-    ignore: Option<&[String]>,
+    output_path: Option<&Path>,
 ) -> Result<()> {
     validate_path(path)?;
+    if matches!(
+        format,
+        OutputFormat::Markdown
+            | OutputFormat::Badge
+            | OutputFormat::Gitlab
+            | OutputFormat::Html
+            | OutputFormat::Sarif
+    ) {
+        anyhow::bail!("metrics supports --format text or json");
+    }
     let project_root = resolve_project_root(path, config_path);
     let config = load_config(&project_root, config_path)?;
-
-    if per_service {
-        let analyzers = create_analyzers(path, &config, languages)?;
-        let pipeline = AnalysisPipeline::new(analyzers, config);
-        let multi = pipeline.analyze_per_service(path)?;
-
-        if score_only {
-            for svc in &multi.services {
-                print_score_only(&svc.service_name, svc.result.score.as_ref(), format);
-            }
-            return Ok(());
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
+
+    let report = if per_component {
+        let components =
+            metrics::compute_component_metrics(&analysis.components, &analysis.dependencies);
+        match format {
+            OutputFormat::Json => json::format_component_metrics(&components, compact),
+            _ => text::format_component_metrics(&components),
+        }
+    } else {
+        let packages = &analysis.result.package_metrics;
+        match format {
+            OutputFormat::Json => json::format_package_metrics(packages, compact),
+            _ => text::format_package_metrics(packages),
         }
+    };
+    emit_report(&report, output_path)
+}
 
-        let report = match format {
-            OutputFormat::Text => text::format_multi_service_report(&multi),
-            OutputFormat::Json => json::format_multi_service_report(&multi, compact),
-            OutputFormat::Markdown => {
-                boundary_report::markdown::format_multi_service_report(&multi)
-            }
-        };
-        println!("{report}");
-        return Ok(());
+fn cmd_hotspots(
+    path: &Path,
+    config_path: Option<&Path>,
+    format: OutputFormat,
+    compact: bool,
+    languages: Option<&[String]>,
+    top: Option<usize>,
+    output_path: Option<&Path>,
+) -> Result<()> {
+    validate_path(path)?;
+    if matches!(
+        format,
+        OutputFormat::Markdown
+            | OutputFormat::Badge
+            | OutputFormat::Gitlab
+            | OutputFormat::Html
+            | OutputFormat::Sarif
+    ) {
+        anyhow::bail!("hotspots supports --format text or json");
     }
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
 
-    let mut analysis = run_analysis(path, &project_root, &config, languages, incremental)?;
-    filter_ignored_violations(&mut analysis.result, ignore);
-
-    if score_only {
-        let module_name = path
-            .file_name()
-            .map(|n| n.to_string_lossy().into_owned())
-            .unwrap_or_else(|| path.to_string_lossy().into_owned());
-        print_score_only(&module_name, analysis.result.score.as_ref(), format);
-        return Ok(());
+    let churn = FileChurn::from_git(path);
+    let mut hotspots = rank_hotspots(&analysis.result.violations, |file| churn.commits(file));
+    if let Some(top) = top {
+        hotspots.truncate(top);
     }
-
     let report = match format {
-        OutputFormat::Text => text::format_report(&analysis.result),
-        OutputFormat::Json => json::format_report(&analysis.result, compact),
-        OutputFormat::Markdown => boundary_report::markdown::format_report(&analysis.result),
+        OutputFormat::Json => json::format_hotspots(&hotspots, compact),
+        _ => text::format_hotspots(&hotspots),
     };
-    println!("{report}");
-    Ok(())
-}
-
-fn print_score_only(
-    module: &str,
-    score: Option<&metrics::ArchitectureScore>,
-    format: OutputFormat,
-) {
-    let overall = score.map(|s| s.overall).unwrap_or(0.0);
-    let presence = score.map(|s| s.structural_presence).unwrap_or(0.0);
-    let conformance = score.map(|s| s.layer_conformance).unwrap_or(0.0);
-    let compliance = score.map(|s| s.dependency_compliance).unwrap_or(0.0);
-    let iface = score.map(|s| s.interface_coverage).unwrap_or(0.0);
-    match format {
-        OutputFormat::Json => {
-            println!(
-                "{{\"module\":\"{module}\",\"overall\":{overall:.1},\"structural_presence\":{presence:.1},\"layer_conformance\":{conformance:.1},\"dependency_compliance\":{compliance:.1},\"interface_coverage\":{iface:.1}}}"
-            );
-        }
-        OutputFormat::Text | OutputFormat::Markdown => {
-            println!(
-                "{module}: {overall:.1}/100 (Presence: {presence:.1}, Conformance: {conformance:.1}, Compliance: {compliance:.1}, Interfaces: {iface:.1})"
-            );
-        }
-    }
+    emit_report(&report, output_path)
 }
 
 #[allow(clippy::too_many_arguments)]
-fn cmd_check(
+fn cmd_explain(
     path: &Path,
-    fail_on_str: &str,
     config_path: Option<&Path>,
+    id: Option<&str>,
+    kind: Option<&str>,
     format: OutputFormat,
     compact: bool,
     languages: Option<&[String]>,
-    track: bool,
-    no_regression: bool,
-    incremental: bool,
-    per_service: bool,
-    ignore: Option<&[String]>,
+    output_path: Option<&Path>,
 ) -> Result<()> {
     validate_path(path)?;
+    if !matches!(format, OutputFormat::Text | OutputFormat::Json) {
+        anyhow::bail!("explain supports --format text or json");
+    }
     let project_root = resolve_project_root(path, config_path);
     let config = load_config(&project_root, config_path)?;
-    let fail_on: Severity = fail_on_str.parse()?;
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
 
-    if per_service {
-        let analyzers = create_analyzers(path, &config, languages)?;
-        let pipeline = AnalysisPipeline::new(analyzers, config);
-        let multi = pipeline.analyze_per_service(path)?;
+    let selected = explain::select(&analysis.result, id, kind);
+    let selector = [("--id", id), ("--kind", kind)]
+        .into_iter()
+        .filter_map(|(flag, value)| value.map(|v| format!("{flag} {v}")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if selected.is_empty() {
+        anyhow::bail!("no violation matches {selector}");
+    }
+    if id.is_some() && selected.len() > 1 {
+        anyhow::bail!(
+            "{selector} matches {} violations; give more of the fingerprint",
+            selected.len()
+        );
+    }
 
-        let report = match format {
-            OutputFormat::Text => text::format_multi_service_report(&multi),
-            OutputFormat::Json => json::format_multi_service_report(&multi, compact),
-            OutputFormat::Markdown => {
-                boundary_report::markdown::format_multi_service_report(&multi)
-            }
-        };
-        println!("{report}");
+    let explanations: Vec<_> = selected
+        .into_iter()
+        .map(|v| explain::explain(v, analysis.result.root(), &analysis.graph))
+        .collect();
+    let report = match format {
+        OutputFormat::Json => json::format_explanations(&explanations, compact),
+        _ => text::format_explanations(&explanations),
+    };
+    emit_report(&report, output_path)
+}
 
-        // Check if any service has failing violations
-        let has_failures = multi
-            .services
-            .iter()
-            .any(|s| s.result.violations.iter().any(|v| v.severity >= fail_on));
-        if has_failures {
-            process::exit(1);
-        }
-        return Ok(());
+fn cmd_bench(
+    path: &Path,
+    config_path: Option<&Path>,
+    runs: usize,
+    format: OutputFormat,
+    languages: Option<&[String]>,
+) -> Result<()> {
+    validate_path(path)?;
+    reject_badge(format, "bench")?;
+    reject_gitlab(format, "bench")?;
+    reject_html(format, "bench")?;
+    reject_sarif(format, "bench")?;
+    if runs == 0 {
+        anyhow::bail!("--runs must be at least 1");
     }
+    let project_root = resolve_project_root(path, config_path);
+    let config = load_config(&project_root, config_path)?;
 
-    let mut analysis = run_analysis(path, &project_root, &config, languages, incremental)?;
-    filter_ignored_violations(&mut analysis.result, ignore);
+    let mut timings = Vec::with_capacity(runs);
+    let mut files = 0;
+    for _ in 0..runs {
+        let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
+        files = analysis.result.files_analyzed;
+        timings.push(analysis.timings);
+    }
+    let median = PhaseTimings::median(&timings);
+    let total_secs = median.total.as_secs_f64();
+    let files_per_sec = if total_secs > 0.0 {
+        files as f64 / total_secs
+    } else {
+        0.0
+    };
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
 
-    // Evolution tracking
-    if track {
-        boundary_core::evolution::save_snapshot(path, &analysis.result)?;
-    }
-    if no_regression {
-        if let Some(trend) = boundary_core::evolution::check_regression(path, &analysis.result)? {
-            let (report, _) = match format {
-                OutputFormat::Text => text::format_check(&analysis.result, fail_on),
-                OutputFormat::Json => json::format_check(&analysis.result, fail_on, compact),
-                OutputFormat::Markdown => {
-                    boundary_report::markdown::format_check(&analysis.result, fail_on)
-                }
-            };
-            println!("{report}");
-            eprintln!("Architecture regression detected!");
-            eprintln!(
-                "  Score: {:.1} -> {:.1} ({:+.1})",
-                trend.previous_score, trend.current_score, trend.score_delta
-            );
-            eprintln!(
-                "  Violations: {} -> {} ({:+})",
-                trend.previous_violations, trend.current_violations, trend.violation_delta
+    match format {
+        OutputFormat::Json => {
+            let phases: Vec<serde_json::Value> = Phase::ALL
+                .iter()
+                .map(|&phase| {
+                    serde_json::json!({
+                        "phase": phase.name(),
+                        "median_ms": ms(median.get(phase)),
+                    })
+                })
+                .collect();
+            let report = serde_json::json!({
+                "runs": runs,
+                "files": files,
+                "phases": phases,
+                "total_ms": ms(median.total),
+                "files_per_sec": files_per_sec,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Text
+        | OutputFormat::Markdown
+        | OutputFormat::Badge
+        | OutputFormat::Gitlab
+        | OutputFormat::Html
+        | OutputFormat::Sarif => {
+            println!(
+                "Benchmark: {} ({runs} runs, {files} files)\n",
+                path.display()
             );
-            for rt in &trend.rule_trends {
-                if rt.delta != 0 {
-                    eprintln!(
-                        "    {}: {} -> {} ({:+})",
-                        rt.rule_id, rt.previous_count, rt.current_count, rt.delta
-                    );
-                }
+            println!("{:<12} {:>12}", "Phase", "Median (ms)");
+            for phase in Phase::ALL {
+                println!("{:<12} {:>12.2}", phase.name(), ms(median.get(phase)));
             }
-            process::exit(1);
-        }
-    }
-
-    let (report, passed) = match format {
-        OutputFormat::Text => text::format_check(&analysis.result, fail_on),
-        OutputFormat::Json => json::format_check(&analysis.result, fail_on, compact),
-        OutputFormat::Markdown => {
-            boundary_report::markdown::format_check(&analysis.result, fail_on)
+            println!("{:<12} {:>12.2}", "total", ms(median.total));
+            println!("\nThroughput: {files_per_sec:.1} files/sec");
         }
-    };
-    println!("{report}");
-    if !passed {
-        process::exit(1);
-    }
-    Ok(())
-}
-
-fn cmd_init(force: bool) -> Result<()> {
-    let target = PathBuf::from(".boundary.toml");
-    if target.exists() && !force {
-        anyhow::bail!(".boundary.toml already exists. Use --force to overwrite.");
     }
-    std::fs::write(&target, Config::default_toml())?;
-    println!("Created .boundary.toml with default configuration.");
     Ok(())
 }
 
@@ -432,21 +1907,27 @@ fn cmd_diagram(
     config_path: Option<&Path>,
     diagram_type: DiagramType,
     languages: Option<&[String]>,
+    include_external: bool,
 ) -> Result<()> {
     validate_path(path)?;
     let project_root = resolve_project_root(path, config_path);
     let config = load_config(&project_root, config_path)?;
-    let analysis = run_analysis(path, &project_root, &config, languages, false)?;
+    let analysis = run_analysis(path, &project_root, &config, languages, false, &[])?;
 
+    let graph = &analysis.graph;
     let diagram = match diagram_type {
-        DiagramType::Layers => boundary_report::diagram::generate_layer_diagram(&analysis.graph),
+        DiagramType::Layers => {
+            boundary_report::diagram::generate_layer_diagram(graph, include_external)
+        }
         DiagramType::Dependencies => {
-            boundary_report::diagram::generate_dependency_flow(&analysis.graph)
+            boundary_report::diagram::generate_dependency_flow(graph, include_external)
         }
-        DiagramType::Dot => boundary_report::dot::generate_layer_diagram(&analysis.graph),
+        DiagramType::Dot => boundary_report::dot::generate_layer_diagram(graph, include_external),
         DiagramType::DotDependencies => {
-            boundary_report::dot::generate_dependency_flow(&analysis.graph)
+            boundary_report::dot::generate_dependency_flow(graph, include_external)
         }
+        // Cytoscape always exports external nodes, tagged with the `external` class.
+        DiagramType::Cytoscape => boundary_report::cytoscape::export(&analysis.graph),
     };
     println!("{diagram}");
     Ok(())
@@ -477,7 +1958,7 @@ fn cmd_forensics(
 
     let config = load_config(&project_root, config_path)?;
     let analyzers = create_analyzers(&project_root, &config, languages)?;
-    let pipeline = AnalysisPipeline::new(analyzers, config);
+    let pipeline = AnalysisPipeline::new(analyzers, config)?;
 
     let full_analysis = pipeline.analyze_module(&module_path, &project_root)?;
     let forensics =
@@ -501,6 +1982,22 @@ fn filter_ignored_violations(result: &mut metrics::AnalysisResult, ignore: Optio
         result
             .violations
             .retain(|v| !rules.iter().any(|r| r == v.kind.rule_id().as_str()));
+        if let Some(debt) = &mut result.debt {
+            debt.remove_rules(rules);
+        }
+    }
+}
+
+/// Cap each service's violation list (and the aggregate) at `max` entries.
+fn apply_max_violations_multi(
+    multi: &mut metrics::MultiServiceResult,
+    max_violations: Option<usize>,
+) {
+    if let Some(max) = max_violations {
+        for svc in &mut multi.services {
+            svc.result.truncate_violations(max);
+        }
+        multi.aggregate.truncate_violations(max);
     }
 }
 
@@ -514,7 +2011,7 @@ fn load_config(project_path: &Path, config_path: Option<&Path>) -> Result<Config
 /// Resolve the project root directory for path normalization.
 ///
 /// When `--config` is explicit, derives root from the config file's parent.
-/// Otherwise walks ancestors looking for `.boundary.toml` or `.git`.
+/// Otherwise walks ancestors looking for `.boundary-root`, `.boundary.toml` or `.git`.
 /// Falls back to `analysis_path` if nothing found.
 fn resolve_project_root(analysis_path: &Path, config_path: Option<&Path>) -> PathBuf {
     if let Some(cp) = config_path {
@@ -531,6 +2028,9 @@ fn resolve_project_root(analysis_path: &Path, config_path: Option<&Path>) -> Pat
 pub struct FullAnalysis {
     pub result: metrics::AnalysisResult,
     pub graph: DependencyGraph,
+    pub components: Vec<boundary_core::types::Component>,
+    pub dependencies: Vec<boundary_core::types::Dependency>,
+    pub timings: PhaseTimings,
 }
 
 /// A dependency with its resolved layer info and architecture context.
@@ -543,6 +2043,17 @@ type ClassifiedDependency = (
     bool, // to_is_cross_cutting
 );
 
+/// Add a dependency and its end nodes to `graph`. A target merged before its component
+/// is replaced when the component arrives.
+fn add_classified_dependency(
+    graph: &mut DependencyGraph,
+    (dep, from_layer, to_layer, is_cc, arch_mode, to_is_cc): &ClassifiedDependency,
+) {
+    graph.ensure_node_with_mode(&dep.from, *from_layer, *is_cc, *arch_mode);
+    graph.ensure_node(&dep.to, *to_layer, *to_is_cc);
+    graph.add_dependency(dep);
+}
+
 /// Extracted per-file data before merging into the graph.
 struct FileResult {
     components: Vec<(
@@ -550,6 +2061,11 @@ struct FileResult {
         Option<boundary_core::types::ArchLayer>,
     )>,
     dependencies: Vec<ClassifiedDependency>,
+    /// The file carries a generated-code marker.
+    is_generated: bool,
+    factories: Vec<boundary_core::types::FactoryInfo>,
+    field_accesses: Vec<boundary_core::types::FieldAccess>,
+    impl_methods: Vec<boundary_core::types::ImplMethods>,
 }
 
 /// Create analyzers based on languages config or auto-detection.
@@ -570,29 +2086,24 @@ fn create_analyzers(
     let mut analyzers: Vec<Box<dyn LanguageAnalyzer>> = Vec::new();
 
     for lang in &languages {
-        match lang.as_str() {
-            "go" => {
-                analyzers.push(Box::new(
-                    GoAnalyzer::new().context("failed to init Go analyzer")?,
-                ));
-            }
-            "rust" => {
-                analyzers.push(Box::new(
-                    RustAnalyzer::new().context("failed to init Rust analyzer")?,
-                ));
-            }
-            "typescript" | "ts" => {
-                analyzers.push(Box::new(
-                    TypeScriptAnalyzer::new().context("failed to init TypeScript analyzer")?,
-                ));
+        match new_analyzer(lang) {
+            Some(analyzer) => analyzers.push(analyzer?),
+            #[cfg(not(feature = "openapi"))]
+            None if lang == "openapi" => {
+                eprintln!(
+                    "Warning: OpenAPI support requires building boundary with the `openapi` \
+                     feature, skipping"
+                );
             }
-            "java" => {
-                analyzers.push(Box::new(
-                    JavaAnalyzer::new().context("failed to init Java analyzer")?,
-                ));
+            #[cfg(not(feature = "zig"))]
+            None if lang == "zig" => {
+                eprintln!(
+                    "Warning: Zig support requires building boundary with the `zig` feature, \
+                     skipping"
+                );
             }
-            other => {
-                eprintln!("Warning: unsupported language '{other}', skipping");
+            None => {
+                eprintln!("Warning: unsupported language '{lang}', skipping");
             }
         }
     }
@@ -604,12 +2115,46 @@ fn create_analyzers(
     Ok(analyzers)
 }
 
+/// Initialize the analyzer for a language name (running its grammar self-test), or
+/// `None` when the language is not supported by this build.
+fn new_analyzer(lang: &str) -> Option<Result<Box<dyn LanguageAnalyzer>>> {
+    let analyzer: Result<Box<dyn LanguageAnalyzer>> = match lang {
+        "go" => GoAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init Go analyzer"),
+        "rust" => RustAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init Rust analyzer"),
+        "typescript" | "ts" => TypeScriptAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init TypeScript analyzer"),
+        "java" => JavaAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init Java analyzer"),
+        "cpp" | "c++" | "c" => CppAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init C/C++ analyzer"),
+        #[cfg(feature = "openapi")]
+        "openapi" => boundary_openapi::OpenApiAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init OpenAPI analyzer"),
+        #[cfg(feature = "zig")]
+        "zig" => boundary_zig::ZigAnalyzer::new()
+            .map(|a| Box::new(a) as _)
+            .context("failed to init Zig analyzer"),
+        _ => return None,
+    };
+    Some(analyzer)
+}
+
 /// Auto-detect languages by scanning for file extensions.
 fn auto_detect_languages(project_path: &Path) -> Vec<String> {
     let mut has_go = false;
     let mut has_rust = false;
     let mut has_ts = false;
     let mut has_java = false;
+    let mut has_cpp = false;
+    let mut has_zig = false;
 
     for entry in WalkDir::new(project_path)
         .into_iter()
@@ -620,17 +2165,23 @@ fn auto_detect_languages(project_path: &Path) -> Vec<String> {
             match ext.to_str() {
                 Some("go") => has_go = true,
                 Some("rs") => has_rust = true,
-                Some("ts" | "tsx") => {
-                    // Skip .d.ts files
-                    if !entry.path().to_string_lossy().ends_with(".d.ts") {
-                        has_ts = true;
-                    }
+                // Skip .d.ts files
+                Some("ts" | "tsx") if !entry.path().to_string_lossy().ends_with(".d.ts") => {
+                    has_ts = true;
                 }
                 Some("java") => has_java = true,
+                Some("cpp" | "cc" | "hpp" | "h") => has_cpp = true,
+                Some("zig") => has_zig = true,
                 _ => {}
             }
         }
-        if has_go && has_rust && has_ts && has_java {
+        if has_go
+            && has_rust
+            && has_ts
+            && has_java
+            && has_cpp
+            && (has_zig || !cfg!(feature = "zig"))
+        {
             break;
         }
     }
@@ -648,6 +2199,13 @@ fn auto_detect_languages(project_path: &Path) -> Vec<String> {
     if has_java {
         languages.push("java".to_string());
     }
+    if has_cpp {
+        languages.push("cpp".to_string());
+    }
+    // Only reported when this build can analyze it.
+    if has_zig && cfg!(feature = "zig") {
+        languages.push("zig".to_string());
+    }
     if languages.is_empty() {
         // Fallback to Go for backward compat
         languages.push("go".to_string());
@@ -655,257 +2213,314 @@ fn auto_detect_languages(project_path: &Path) -> Vec<String> {
     languages
 }
 
+/// Source files under `project_path` that `analyzer` handles, minus vendored code,
+/// build output, Go tests and TypeScript declaration files.
+fn find_source_files(project_path: &Path, analyzer: &dyn LanguageAnalyzer) -> Vec<PathBuf> {
+    let extensions = analyzer.file_extensions();
+    WalkDir::new(project_path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            let p = e.path();
+            let matches_ext = p
+                .extension()
+                .is_some_and(|ext| extensions.iter().any(|e| ext == *e));
+            if !matches_ext {
+                return false;
+            }
+            let path_str = p.to_string_lossy();
+            // Common exclusions
+            !path_str.contains("vendor/")
+                && !path_str.contains("/target/")
+                && !path_str.ends_with("_test.go")
+                && !path_str.ends_with(".d.ts")
+        })
+        .map(|e| e.into_path())
+        .collect()
+}
+
 fn run_analysis(
     project_path: &Path,
     project_root: &Path,
     config: &Config,
     language_override: Option<&[String]>,
     incremental: bool,
+    rules: &[Box<dyn Rule>],
 ) -> Result<FullAnalysis> {
+    let mut timer = PhaseTimer::start();
     let analyzers = create_analyzers(project_path, config, language_override)?;
     let classifier = LayerClassifier::new(&config.layers);
+    let component_classifier = ComponentClassifier::new(&config.classification)?;
     let mut graph = DependencyGraph::new();
     let mut total_deps = 0usize;
     let mut total_files = 0usize;
     let mut all_components = Vec::new();
+    let mut generated_files = Vec::new();
     let mut all_dependencies: Vec<boundary_core::types::Dependency> = Vec::new();
 
-    // Load cache if incremental
-    let mut cache = if incremental {
+    // Workers read the loaded cache while results are merged into a fresh one, so
+    // files that no longer exist drop out of the saved cache.
+    let previous = if incremental {
         boundary_core::cache::AnalysisCache::load(project_path).unwrap_or_default()
     } else {
         boundary_core::cache::AnalysisCache::new()
     };
+    let mut cache = boundary_core::cache::AnalysisCache::new();
 
     for analyzer in &analyzers {
-        let extensions: Vec<&str> = analyzer.file_extensions().to_vec();
-
-        // Walk directory and find matching files
-        let source_files: Vec<PathBuf> = WalkDir::new(project_path)
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                let p = e.path();
-                let matches_ext = p
-                    .extension()
-                    .is_some_and(|ext| extensions.iter().any(|e| ext == *e));
-                if !matches_ext {
-                    return false;
-                }
-                let path_str = p.to_string_lossy();
-                // Common exclusions
-                !path_str.contains("vendor/")
-                    && !path_str.contains("/target/")
-                    && !path_str.ends_with("_test.go")
-                    && !path_str.ends_with(".d.ts")
-            })
-            .map(|e| e.into_path())
-            .collect();
+        let source_files = find_source_files(project_path, analyzer.as_ref());
+        timer.lap(Phase::Discovery);
 
         if source_files.is_empty() {
             continue;
         }
         total_files += source_files.len();
 
-        // Parse and extract in parallel
-        let file_results: Vec<(String, FileResult, String)> = source_files
-            .par_iter()
-            .filter_map(|file_path| {
-                let content = match std::fs::read_to_string(file_path) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        eprintln!("Warning: failed to read {}: {e}", file_path.display());
-                        return None;
-                    }
-                };
-
-                let rel_path = file_path
-                    .strip_prefix(project_root)
-                    .unwrap_or(file_path)
-                    .to_string_lossy()
-                    .to_string();
-
-                let is_cross_cutting = classifier.is_cross_cutting(&rel_path);
-                let arch_mode = classifier.architecture_mode(&rel_path);
-
-                // Check cache for incremental analysis
-                if incremental {
-                    if let Some(cached) = cache.get(&rel_path, &content) {
-                        let file_layer = classifier.classify(&rel_path);
-                        let components: Vec<_> = cached
-                            .components
-                            .iter()
-                            .map(|comp| {
-                                let mut comp = comp.clone();
-                                if comp.layer.is_none() {
-                                    comp.layer = file_layer;
-                                }
-                                comp.is_cross_cutting = is_cross_cutting;
-                                comp.architecture_mode = arch_mode;
-                                reclassify_infra_handlers(&mut comp);
-                                let layer = comp.layer;
-                                (comp, layer)
-                            })
-                            .collect();
-
-                        let dependencies: Vec<_> = cached
-                            .dependencies
-                            .iter()
-                            .filter(|dep| {
-                                matches!(dep.kind, DependencyKind::MethodCall)
-                                    || !dep
-                                        .import_path
-                                        .as_deref()
-                                        .is_some_and(|p| analyzer.is_stdlib_import(p))
-                            })
-                            .map(|dep| {
-                                let to_layer = dep
-                                    .import_path
-                                    .as_deref()
-                                    .and_then(|p| classifier.classify_import(p));
-                                let to_is_cross_cutting = dep
-                                    .import_path
-                                    .as_deref()
-                                    .is_some_and(|p| classifier.is_cross_cutting_import(p));
-                                let from_layer = classifier.classify(&rel_path);
-                                (
-                                    dep.clone(),
-                                    from_layer,
-                                    to_layer,
-                                    is_cross_cutting,
-                                    arch_mode,
-                                    to_is_cross_cutting,
-                                )
-                            })
-                            .collect();
-
-                        return Some((
-                            rel_path,
-                            FileResult {
-                                components,
-                                dependencies,
-                            },
-                            content,
-                        ));
-                    }
+        // Parse and extract in parallel, accumulating per-phase CPU time
+        let parse_nanos = AtomicU64::new(0);
+        let extract_nanos = AtomicU64::new(0);
+        let process_file = |file_path: &Path| {
+            let started = Instant::now();
+            let content = match std::fs::read_to_string(file_path) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("Warning: failed to read {}: {e}", file_path.display());
+                    return None;
                 }
+            };
 
-                let parsed = match analyzer.parse_file(file_path, &content) {
-                    Ok(p) => p,
-                    Err(e) => {
-                        eprintln!("Warning: failed to parse {}: {e}", file_path.display());
-                        return None;
-                    }
-                };
-
-                // Extract and classify components
-                let mut components_raw = analyzer.extract_components(&parsed);
-                let file_layer = classifier.classify(&rel_path);
-
-                let components: Vec<_> = components_raw
-                    .drain(..)
-                    .map(|mut comp| {
-                        if comp.layer.is_none() {
-                            comp.layer = file_layer;
-                        }
-                        comp.is_cross_cutting = is_cross_cutting;
-                        comp.architecture_mode = arch_mode;
-                        reclassify_infra_handlers(&mut comp);
-                        let layer = comp.layer;
-                        (comp, layer)
-                    })
-                    .collect();
-
-                // Extract dependencies with layer info
-                let deps = analyzer.extract_dependencies(&parsed);
-                let dependencies: Vec<_> = deps
-                    .into_iter()
-                    .filter(|dep| {
-                        // MethodCall (init function) deps use local aliases, not module paths;
-                        // never treat them as stdlib. Only filter Import-kind deps.
-                        matches!(dep.kind, DependencyKind::MethodCall)
-                            || !dep
-                                .import_path
-                                .as_deref()
-                                .is_some_and(|p| analyzer.is_stdlib_import(p))
-                    })
-                    .map(|dep| {
-                        let to_layer = dep
-                            .import_path
-                            .as_deref()
-                            .and_then(|p| classifier.classify_import(p));
-                        let to_is_cross_cutting = dep
-                            .import_path
-                            .as_deref()
-                            .is_some_and(|p| classifier.is_cross_cutting_import(p));
-                        let from_layer = classifier.classify(&rel_path);
-                        (
-                            dep,
-                            from_layer,
-                            to_layer,
-                            is_cross_cutting,
-                            arch_mode,
-                            to_is_cross_cutting,
-                        )
-                    })
-                    .collect();
-
-                Some((
-                    rel_path,
-                    FileResult {
-                        components,
-                        dependencies,
-                    },
-                    content,
-                ))
-            })
-            .collect();
+            let rel_path = file_path
+                .strip_prefix(project_root)
+                .unwrap_or(file_path)
+                .to_string_lossy()
+                .to_string();
 
-        // Collect rel_paths for pruning
-        let current_files: Vec<String> = file_results.iter().map(|(p, _, _)| p.clone()).collect();
+            let is_cross_cutting = classifier.is_cross_cutting(&rel_path);
+            let is_generated = boundary_core::generated::is_generated_source(&content);
+            let arch_mode = classifier.architecture_mode(&rel_path);
 
-        // First pass: add all source file components and update cache
-        for (rel_path, fr, content) in &file_results {
+            // Check cache for incremental analysis
             if incremental {
-                let cached_components: Vec<_> =
-                    fr.components.iter().map(|(comp, _)| comp.clone()).collect();
-                let cached_deps: Vec<_> = fr
-                    .dependencies
-                    .iter()
-                    .map(|(dep, _, _, _, _, _)| dep.clone())
-                    .collect();
-                cache.insert(
-                    rel_path.clone(),
-                    content,
-                    boundary_core::cache::CachedFileResult {
-                        hash: String::new(),
-                        components: cached_components,
-                        dependencies: cached_deps,
-                    },
-                );
-            }
-
-            for (comp, _) in &fr.components {
-                graph.add_component(comp);
-                all_components.push(comp.clone());
-            }
-        }
-
-        // Second pass: add dependencies
-        for (_rel_path, fr, _content) in file_results {
-            for (dep, from_layer, to_layer, is_cc, arch_mode, to_is_cc) in &fr.dependencies {
-                graph.ensure_node_with_mode(&dep.from, *from_layer, *is_cc, *arch_mode);
-                graph.ensure_node(&dep.to, *to_layer, *to_is_cc);
-                graph.add_dependency(dep);
-                all_dependencies.push(dep.clone());
+                if let Some(cached) = previous.get(&rel_path, &content) {
+                    let file_layer = classifier.classify_file(&rel_path, &content);
+                    let components: Vec<_> = cached
+                        .components
+                        .iter()
+                        .map(|comp| {
+                            let mut comp = comp.clone();
+                            component_classifier.apply(&mut comp, &rel_path);
+                            if comp.layer.is_none() {
+                                comp.layer = file_layer;
+                            }
+                            comp.is_cross_cutting = is_cross_cutting;
+                            comp.is_generated = is_generated;
+                            comp.architecture_mode = arch_mode;
+                            reclassify_infra_handlers(&mut comp);
+                            let layer = comp.layer;
+                            (comp, layer)
+                        })
+                        .collect();
+
+                    let dependencies: Vec<_> = cached
+                        .dependencies
+                        .iter()
+                        .filter(|dep| {
+                            matches!(dep.kind, DependencyKind::MethodCall)
+                                || !dep.import_path.as_deref().is_some_and(|p| {
+                                    analyzer.is_stdlib_import(p) && !config.rules.is_infra_import(p)
+                                })
+                        })
+                        .map(|dep| {
+                            let import_path = dep.import_path.as_deref().map(|p| {
+                                boundary_core::layer::resolve_relative_import(&rel_path, p)
+                            });
+                            let to_layer = import_path
+                                .as_deref()
+                                .and_then(|p| classifier.classify_import(p));
+                            let to_is_cross_cutting = import_path
+                                .as_deref()
+                                .is_some_and(|p| classifier.is_cross_cutting_import(p));
+                            let from_layer = classifier.classify_file(&rel_path, &content);
+                            (
+                                dep.clone(),
+                                from_layer,
+                                to_layer,
+                                is_cross_cutting,
+                                arch_mode,
+                                to_is_cross_cutting,
+                            )
+                        })
+                        .collect();
+
+                    extract_nanos.fetch_add(elapsed_nanos(started), Ordering::Relaxed);
+                    return Some((
+                        rel_path,
+                        FileResult {
+                            components,
+                            dependencies,
+                            is_generated,
+                            factories: cached.factories.clone(),
+                            field_accesses: cached.field_accesses.clone(),
+                            impl_methods: cached.impl_methods.clone(),
+                        },
+                        content,
+                    ));
+                }
             }
-            total_deps += fr.dependencies.len();
-        }
 
-        // Prune deleted files from cache
-        if incremental {
-            cache.prune(&current_files);
+            let parsed = match analyzer.parse_file(file_path, &content) {
+                Ok(p) => p,
+                Err(e) => {
+                    eprintln!("Warning: failed to parse {}: {e}", file_path.display());
+                    return None;
+                }
+            };
+            parse_nanos.fetch_add(elapsed_nanos(started), Ordering::Relaxed);
+            let extract_started = Instant::now();
+
+            // Extract and classify components
+            let mut components_raw = analyzer.extract_components(&parsed);
+            let file_layer = classifier.classify_file(&rel_path, &content);
+
+            let components: Vec<_> = components_raw
+                .drain(..)
+                .map(|mut comp| {
+                    component_classifier.apply(&mut comp, &rel_path);
+                    if comp.layer.is_none() {
+                        comp.layer = file_layer;
+                    }
+                    comp.is_cross_cutting = is_cross_cutting;
+                    comp.is_generated = is_generated;
+                    comp.architecture_mode = arch_mode;
+                    reclassify_infra_handlers(&mut comp);
+                    let layer = comp.layer;
+                    (comp, layer)
+                })
+                .collect();
+
+            // Extract dependencies with layer info
+            let deps = analyzer.extract_dependencies(&parsed);
+            let dependencies: Vec<_> = deps
+                .into_iter()
+                .filter(|dep| {
+                    // MethodCall (init function) deps use local aliases, not module paths;
+                    // never treat them as stdlib. Only filter Import-kind deps.
+                    matches!(dep.kind, DependencyKind::MethodCall)
+                        || !dep.import_path.as_deref().is_some_and(|p| {
+                            analyzer.is_stdlib_import(p) && !config.rules.is_infra_import(p)
+                        })
+                })
+                .map(|dep| {
+                    let import_path = dep
+                        .import_path
+                        .as_deref()
+                        .map(|p| boundary_core::layer::resolve_relative_import(&rel_path, p));
+                    let to_layer = import_path
+                        .as_deref()
+                        .and_then(|p| classifier.classify_import(p));
+                    let to_is_cross_cutting = import_path
+                        .as_deref()
+                        .is_some_and(|p| classifier.is_cross_cutting_import(p));
+                    let from_layer = classifier.classify_file(&rel_path, &content);
+                    (
+                        dep,
+                        from_layer,
+                        to_layer,
+                        is_cross_cutting,
+                        arch_mode,
+                        to_is_cross_cutting,
+                    )
+                })
+                .collect();
+            let factories = analyzer.extract_factories(&parsed);
+            let field_accesses = analyzer.extract_field_accesses(&parsed);
+            let impl_methods = analyzer.extract_impl_methods(&parsed);
+
+            extract_nanos.fetch_add(elapsed_nanos(extract_started), Ordering::Relaxed);
+            Some((
+                rel_path,
+                FileResult {
+                    components,
+                    dependencies,
+                    is_generated,
+                    factories,
+                    field_accesses,
+                    impl_methods,
+                },
+                content,
+            ))
+        };
+        // Update the cache and merge as results arrive so each file's source can be
+        // dropped. Embeddings of types from other packages, and methods declared apart
+        // from their type, wait for every component, which they are resolved against.
+        let mut embedded = Vec::new();
+        let mut impl_methods = Vec::new();
+        pipeline::process_files(
+            &source_files,
+            &config.project,
+            process_file,
+            |i, (rel_path, fr, content)| {
+                if incremental {
+                    let cached_components: Vec<_> =
+                        fr.components.iter().map(|(comp, _)| comp.clone()).collect();
+                    let cached_deps: Vec<_> = fr
+                        .dependencies
+                        .iter()
+                        .map(|(dep, _, _, _, _, _)| dep.clone())
+                        .collect();
+                    cache.insert(
+                        rel_path,
+                        &content,
+                        boundary_core::cache::CachedFileResult::new(
+                            cached_components,
+                            cached_deps,
+                            fr.factories.clone(),
+                            fr.field_accesses.clone(),
+                            fr.impl_methods.clone(),
+                        ),
+                    );
+                }
+                if fr.is_generated {
+                    generated_files.push(source_files[i].clone());
+                }
+                for (comp, _) in fr.components {
+                    graph.add_component(&comp);
+                    all_components.push(comp);
+                }
+                for factory in &fr.factories {
+                    graph.add_factory(factory);
+                }
+                for access in &fr.field_accesses {
+                    graph.add_field_access(access);
+                }
+                impl_methods.extend(fr.impl_methods);
+                total_deps += fr.dependencies.len();
+                for classified in fr.dependencies {
+                    if pipeline::EmbeddingTargets::applies_to(&classified.0) {
+                        embedded.push(classified);
+                    } else {
+                        add_classified_dependency(&mut graph, &classified);
+                        all_dependencies.push(classified.0);
+                    }
+                }
+            },
+        )?;
+        timer.lap_split(
+            (Phase::Parse, Duration::from_nanos(parse_nanos.into_inner())),
+            (
+                Phase::Extraction,
+                Duration::from_nanos(extract_nanos.into_inner()),
+            ),
+        );
+
+        let embeddings = pipeline::EmbeddingTargets::new(&all_components, project_path);
+        for mut classified in embedded {
+            embeddings.resolve(&mut classified.0);
+            add_classified_dependency(&mut graph, &classified);
+            all_dependencies.push(classified.0);
         }
+        pipeline::merge_impl_methods(&mut graph, &mut all_components, impl_methods);
+        timer.lap(Phase::GraphBuild);
     }
 
     // Save cache if incremental
@@ -939,9 +2554,9 @@ fn run_analysis(
             if source_ids.contains(&n.id) {
                 return false;
             }
-            // Extract the path portion before "::" (component IDs use path::name format)
-            let id = n.id.0.replace('\\', "/");
-            let path_part = id.split("::").next().unwrap_or(&id);
+            // Extract the path portion (component IDs use lang:path::name format)
+            let path = n.id.package().replace('\\', "/");
+            let path_part = path.as_str();
             // Relative imports (starting with . or ..) are always internal
             if path_part.starts_with('.') {
                 return false;
@@ -985,14 +2600,36 @@ fn run_analysis(
     for id in &external_ids {
         graph.mark_external(id);
     }
+    pipeline::apply_treat_as_external(
+        &mut graph,
+        &mut all_components,
+        &mut all_dependencies,
+        &config.project.treat_as_external,
+        project_root,
+    );
+    timer.lap(Phase::GraphBuild);
 
-    let result = metrics::build_result(
+    let mut result = metrics::build_result_timed(
         &graph,
         config,
         total_deps,
         &all_components,
         total_files,
         &all_dependencies,
+        rules,
+        project_path,
+        &mut timer,
     );
-    Ok(FullAnalysis { result, graph })
+    result.generated_files = generated_files;
+    Ok(FullAnalysis {
+        result,
+        graph,
+        components: all_components,
+        dependencies: all_dependencies,
+        timings: timer.finish(),
+    })
+}
+
+fn elapsed_nanos(since: Instant) -> u64 {
+    u64::try_from(since.elapsed().as_nanos()).unwrap_or(u64::MAX)
 }
@@ -0,0 +1,96 @@
+//! Analyze a remote git repository given as the `analyze`/`check` path.
+//!
+//! The repository is shallow-cloned with the `git` CLI into a temporary directory that
+//! is removed afterwards. Cloning needs the `remote` feature; without it a URL path
+//! fails before analysis.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use clap::Args;
+
+/// Remote repository options shared by `analyze` and `check`.
+#[derive(Debug, Clone, Default, Args)]
+pub struct RemoteArgs {
+    /// Branch or tag to clone when PATH is a git URL (requires the `remote` feature)
+    #[arg(long = "ref", value_name = "REF")]
+    pub git_ref: Option<String>,
+}
+
+/// The path as a git URL, if it is one: `https://`, `http://`, `ssh://`, `git://` and
+/// `file://` URLs, and scp-like `git@host:org/repo` addresses.
+pub fn git_url(path: &Path) -> Option<&str> {
+    let s = path.to_str()?;
+    let is_url = ["https://", "http://", "ssh://", "git://", "file://"]
+        .iter()
+        .any(|scheme| s.starts_with(scheme))
+        || (s.starts_with("git@") && s.contains(':'));
+    is_url.then_some(s)
+}
+
+/// Make a relative path argument absolute when `path` is a git URL, since the
+/// analysis then runs from inside the clone.
+pub fn keep_local(path: &Path, arg: Option<PathBuf>) -> Option<PathBuf> {
+    match arg {
+        Some(p) if git_url(path).is_some() => Some(std::path::absolute(&p).unwrap_or(p)),
+        other => other,
+    }
+}
+
+impl RemoteArgs {
+    /// Run `f` on the project at `path`. A git URL is cloned first and `f` runs from
+    /// the clone's root on `.`, so reported paths are relative to the repository.
+    pub fn run<T>(&self, path: &Path, f: impl FnOnce(&Path) -> Result<T>) -> Result<T> {
+        match git_url(path) {
+            Some(url) => self.run_remote(url, f),
+            None if self.git_ref.is_some() => bail!("--ref requires PATH to be a git URL"),
+            None => f(path),
+        }
+    }
+
+    #[cfg(feature = "remote")]
+    fn run_remote<T>(&self, url: &str, f: impl FnOnce(&Path) -> Result<T>) -> Result<T> {
+        use anyhow::Context;
+
+        let checkout = clone(url, self.git_ref.as_deref())?;
+        let previous = std::env::current_dir()?;
+        std::env::set_current_dir(checkout.path())
+            .context("failed to enter the cloned repository")?;
+        let result = f(Path::new("."));
+        // Leave the clone before it is removed.
+        std::env::set_current_dir(previous)?;
+        result
+    }
+
+    #[cfg(not(feature = "remote"))]
+    fn run_remote<T>(&self, _url: &str, _f: impl FnOnce(&Path) -> Result<T>) -> Result<T> {
+        bail!("analyzing a git URL requires building boundary with the `remote` feature")
+    }
+}
+
+/// Shallow-clone `url` at `git_ref` (or the default branch) into a temporary directory.
+#[cfg(feature = "remote")]
+fn clone(url: &str, git_ref: Option<&str>) -> Result<tempfile::TempDir> {
+    use anyhow::Context;
+
+    let dir = tempfile::Builder::new()
+        .prefix("boundary-remote-")
+        .tempdir()
+        .context("failed to create a directory for the clone")?;
+    let mut cmd = std::process::Command::new("git");
+    cmd.args(["clone", "--quiet", "--depth", "1"]);
+    if let Some(git_ref) = git_ref {
+        cmd.args(["--branch", git_ref]);
+    }
+    cmd.arg("--").arg(url).arg(dir.path());
+    // Fail instead of waiting for credentials nobody will type.
+    cmd.env("GIT_TERMINAL_PROMPT", "0");
+    let output = cmd
+        .output()
+        .context("failed to run `git clone`; is git installed?")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("failed to clone '{url}': {}", stderr.trim());
+    }
+    Ok(dir)
+}
@@ -0,0 +1,97 @@
+//! POST analysis results to an HTTP endpoint (`--webhook`).
+//!
+//! Delivery needs the `http` feature; without it `--webhook` fails before analysis.
+
+use anyhow::{bail, Result};
+use clap::Args;
+
+/// Webhook options shared by `analyze` and `check`.
+#[derive(Debug, Clone, Default, Args)]
+pub struct WebhookArgs {
+    /// POST the JSON result to this URL (requires the `http` feature)
+    #[arg(long, value_name = "URL")]
+    pub webhook: Option<String>,
+    /// Extra request header for --webhook, as "Name: value" (repeatable)
+    #[arg(long, value_name = "HEADER", requires = "webhook")]
+    pub webhook_header: Vec<String>,
+    /// Fail when the webhook cannot be delivered instead of warning
+    #[arg(long, requires = "webhook")]
+    pub webhook_required: bool,
+}
+
+/// A validated webhook target.
+#[derive(Debug)]
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+pub struct Webhook {
+    url: String,
+    headers: Vec<(String, String)>,
+    required: bool,
+}
+
+impl WebhookArgs {
+    /// Validate the options, so a bad header fails before the analysis runs.
+    pub fn resolve(&self) -> Result<Option<Webhook>> {
+        let Some(url) = &self.webhook else {
+            return Ok(None);
+        };
+        let headers = self
+            .webhook_header
+            .iter()
+            .map(|h| parse_header(h))
+            .collect::<Result<_>>()?;
+        if !cfg!(feature = "http") {
+            bail!("--webhook requires building boundary with the `http` feature");
+        }
+        Ok(Some(Webhook {
+            url: url.clone(),
+            headers,
+            required: self.webhook_required,
+        }))
+    }
+}
+
+fn parse_header(header: &str) -> Result<(String, String)> {
+    match header.split_once(':') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => bail!("invalid --webhook-header '{header}': expected \"Name: value\""),
+    }
+}
+
+impl Webhook {
+    /// POST `body` as JSON. A failed delivery is a warning unless `--webhook-required`.
+    pub fn deliver(&self, body: &str) -> Result<()> {
+        match self.post(body) {
+            Ok(()) => Ok(()),
+            Err(e) if self.required => Err(e),
+            Err(e) => {
+                eprintln!("Warning: {e:#}");
+                Ok(())
+            }
+        }
+    }
+
+    #[cfg(feature = "http")]
+    fn post(&self, body: &str) -> Result<()> {
+        use anyhow::Context;
+
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(std::time::Duration::from_secs(30)))
+            .build()
+            .into();
+        let mut request = agent.post(&self.url).content_type("application/json");
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        request
+            .send(body)
+            .with_context(|| format!("failed to deliver results to webhook {}", self.url))?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "http"))]
+    fn post(&self, _body: &str) -> Result<()> {
+        bail!("--webhook requires building boundary with the `http` feature")
+    }
+}
@@ -0,0 +1,108 @@
+/// Acceptance tests for PA008 (adapter-to-adapter-coupling).
+///
+/// The rule is opt-in via `detect_adapter_coupling`; the `adapter-coupling` fixture
+/// enables it. `PostgresRepo` holds a `*redis.RedisCache` directly, while
+/// `CachedCartRepository` depends only on the domain `CartRepository` port.
+/// `ArchiveRepository` shares `PostgresRepo`'s package without importing Redis.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn adapter_coupling_violations(config: Option<&std::path::Path>) -> Vec<serde_json::Value> {
+    let mut cmd = boundary_cmd();
+    cmd.args(["analyze", &fixture("adapter-coupling"), "--format", "json"]);
+    if let Some(config) = config {
+        cmd.arg("--config").arg(config);
+    }
+    let output = cmd.output().expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA008"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// An adapter holding another adapter fires
+// ----------------------------------------------------------------------------
+#[test]
+fn repo_depending_on_cache_adapter_fires() {
+    let violations = adapter_coupling_violations(None);
+    assert_eq!(violations.len(), 1, "got: {violations:?}");
+
+    let v = &violations[0];
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(
+        v["kind"]["AdapterToAdapterCoupling"]["from_adapter"],
+        "PostgresRepo"
+    );
+    assert_eq!(
+        v["kind"]["AdapterToAdapterCoupling"]["to_adapter"],
+        "RedisCache"
+    );
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("postgres/repo.go"),
+        "got: {v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// An adapter depending only on a domain port passes
+// ----------------------------------------------------------------------------
+#[test]
+fn adapter_depending_on_port_passes() {
+    let violations = adapter_coupling_violations(None);
+    assert!(
+        violations.iter().all(
+            |v| v["kind"]["AdapterToAdapterCoupling"]["from_adapter"] != "CachedCartRepository"
+        ),
+        "got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Only the adapter whose file imports the other adapter fires, not its package
+// neighbours
+// ----------------------------------------------------------------------------
+#[test]
+fn sibling_adapter_in_same_package_passes() {
+    let violations = adapter_coupling_violations(None);
+    assert!(
+        violations
+            .iter()
+            .all(|v| v["kind"]["AdapterToAdapterCoupling"]["from_adapter"] != "ArchiveRepository"),
+        "got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn adapter_coupling_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = adapter_coupling_violations(Some(&config));
+    assert!(
+        violations.is_empty(),
+        "PA008 should be opt-in: {violations:?}"
+    );
+}
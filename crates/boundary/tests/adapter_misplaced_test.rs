@@ -0,0 +1,70 @@
+/// Acceptance tests for PA007 (adapter-misplaced).
+///
+/// The `adapter-misplaced` fixture holds a `MongoUserRepository` in both the
+/// application and the infrastructure layer.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn misplaced_adapter_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA007"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A Mongo repository in application/ fires PA007
+// ----------------------------------------------------------------------------
+#[test]
+fn repository_in_application_layer_fires() {
+    let violations = misplaced_adapter_violations("adapter-misplaced");
+    let v = violations
+        .iter()
+        .find(|v| {
+            v["location"]["file"]
+                .as_str()
+                .is_some_and(|f| f.contains("application/"))
+        })
+        .expect("application MongoUserRepository should fire PA007");
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(
+        v["kind"]["AdapterMisplaced"]["adapter"],
+        "MongoUserRepository"
+    );
+    assert_eq!(v["kind"]["AdapterMisplaced"]["layer"], "Application");
+}
+
+// ----------------------------------------------------------------------------
+// The same repository in infrastructure/ passes
+// ----------------------------------------------------------------------------
+#[test]
+fn repository_in_infrastructure_layer_passes() {
+    let violations = misplaced_adapter_violations("adapter-misplaced");
+    assert_eq!(violations.len(), 1, "got: {violations:?}");
+    assert!(
+        !violations.iter().any(|v| v["location"]["file"]
+            .as_str()
+            .is_some_and(|f| f.contains("infrastructure/"))),
+        "infrastructure MongoUserRepository should not fire, got: {violations:?}"
+    );
+}
@@ -0,0 +1,72 @@
+/// Acceptance tests for `analyze --format badge`: an SVG badge or a shields.io endpoint
+/// payload showing the overall score and grade.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn badge(fixture_name: &str, extra_args: &[&str]) -> String {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "badge"])
+        .args(extra_args)
+        .output()
+        .expect("failed to run boundary analyze");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+// ---------------------------------------------------------------------------
+// shields.io endpoint
+// ---------------------------------------------------------------------------
+
+/// A passing score is green and carries the score and grade.
+#[test]
+fn shields_payload_is_green_for_passing_score() {
+    let stdout = badge("fat-interface", &["--badge-style", "shields"]);
+    let payload: serde_json::Value =
+        serde_json::from_str(stdout.trim()).expect("badge should be JSON");
+
+    assert_eq!(payload["schemaVersion"], 1);
+    assert_eq!(payload["label"], "architecture");
+    assert_eq!(payload["color"], "green");
+    let message = payload["message"].as_str().unwrap();
+    assert!(message.ends_with("% (A)"), "message: {message}");
+}
+
+// ---------------------------------------------------------------------------
+// SVG
+// ---------------------------------------------------------------------------
+
+/// SVG is the default badge style.
+#[test]
+fn svg_badge_is_the_default() {
+    let stdout = badge("fat-interface", &[]);
+    assert!(stdout.starts_with("<svg "), "stdout: {stdout}");
+    assert!(stdout.contains("architecture"), "stdout: {stdout}");
+    assert!(stdout.trim_end().ends_with("</svg>"), "stdout: {stdout}");
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Only `analyze` renders badges.
+#[test]
+fn check_rejects_badge_format() {
+    let output = boundary_cmd()
+        .args(["check", &fixture("fat-interface"), "--format", "badge"])
+        .output()
+        .expect("failed to run boundary");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--format badge is only supported by analyze"),
+        "stderr: {stderr}"
+    );
+}
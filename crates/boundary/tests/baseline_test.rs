@@ -0,0 +1,181 @@
+/// Acceptance tests for `boundary baseline` and `check --baseline`.
+///
+/// The Go fixture fails `check` on its domain → infrastructure import. Once that
+/// violation is baselined, `check` should only fail on violations added later.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+fn write_baseline(dir: &Path) {
+    let output = boundary_cmd()
+        .arg("baseline")
+        .arg(dir)
+        .output()
+        .expect("failed to run boundary baseline");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Wrote "));
+}
+
+fn check_with_baseline(dir: &Path, extra: &[&str]) -> (bool, String) {
+    let output = boundary_cmd()
+        .arg("check")
+        .arg(dir)
+        .arg("--baseline")
+        .arg(dir.join(".boundary-baseline.json"))
+        .args(["--format", "json", "--compact"])
+        .args(extra)
+        .output()
+        .expect("failed to run boundary check");
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+// ----------------------------------------------------------------------------
+// Baselined violations no longer fail check, even after their line moves
+// ----------------------------------------------------------------------------
+#[test]
+fn baseline_suppresses_known_violations_across_line_drift() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    write_baseline(dir.path());
+
+    let baseline = std::fs::read_to_string(dir.path().join(".boundary-baseline.json")).unwrap();
+    assert!(baseline.contains("bad_dependency.go"), "{baseline}");
+
+    let (passed, _) = check_with_baseline(dir.path(), &[]);
+    assert!(passed, "baselined violations should not fail check");
+
+    let file = dir.path().join("internal/domain/user/bad_dependency.go");
+    let content = std::fs::read_to_string(&file).unwrap();
+    std::fs::write(&file, format!("// moved down\n\n{content}")).unwrap();
+    let (passed, _) = check_with_baseline(dir.path(), &[]);
+    assert!(passed, "line drift should not invalidate the baseline");
+}
+
+// ----------------------------------------------------------------------------
+// A baseline written from one directory holds when checking from another
+// ----------------------------------------------------------------------------
+#[test]
+fn baseline_holds_across_working_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let fixture = copy_fixture_to_tempdir("sample-go-project");
+    let project = dir.path().join("p");
+    std::fs::rename(fixture.path(), &project).unwrap();
+
+    let output = boundary_cmd()
+        .current_dir(dir.path())
+        .args(["baseline", "p"])
+        .output()
+        .expect("failed to run boundary baseline");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let check = |cwd: &Path, path: &str| {
+        boundary_cmd()
+            .current_dir(cwd)
+            .args(["check", path, "--baseline"])
+            .arg(project.join(".boundary-baseline.json"))
+            .output()
+            .expect("failed to run boundary check")
+    };
+    let output = check(&project, ".");
+    assert!(
+        output.status.success(),
+        "baseline written as `p` should hold for `.`: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let output = check(dir.path(), "./p");
+    assert!(
+        output.status.success(),
+        "baseline written as `p` should hold for `./p`: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+// ----------------------------------------------------------------------------
+// New violations still fail, and --update-baseline accepts them
+// ----------------------------------------------------------------------------
+#[test]
+fn baseline_reports_new_violations_until_updated() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    write_baseline(dir.path());
+
+    std::fs::write(
+        dir.path().join("internal/domain/user/new_dependency.go"),
+        "package user\n\nimport (\n\t\"github.com/example/app/internal/infrastructure/postgres\"\n)\n\n\
+         func NewFunction() {\n\t_ = postgres.NewPostgresUserRepository(\"new\")\n}\n",
+    )
+    .unwrap();
+    let (passed, stdout) = check_with_baseline(dir.path(), &[]);
+    assert!(!passed, "a new violation should fail check");
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let files: Vec<&str> = report["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["location"]["file"].as_str().unwrap())
+        .collect();
+    assert!(!files.is_empty());
+    assert!(
+        files.iter().all(|f| f.ends_with("new_dependency.go")),
+        "only the new violation should be reported: {files:?}"
+    );
+
+    let (passed, _) = check_with_baseline(dir.path(), &["--update-baseline"]);
+    assert!(
+        passed,
+        "--update-baseline should accept the current violations"
+    );
+    let (passed, _) = check_with_baseline(dir.path(), &[]);
+    assert!(passed);
+}
+
+// ----------------------------------------------------------------------------
+// A missing baseline file is an error rather than an empty baseline
+// ----------------------------------------------------------------------------
+#[test]
+fn missing_baseline_is_an_error() {
+    let output = boundary_cmd()
+        .args([
+            "check",
+            &fixture("sample-go-project"),
+            "--baseline",
+            "/nonexistent/.boundary-baseline.json",
+        ])
+        .output()
+        .expect("failed to run boundary check");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("failed to read baseline"), "{stderr}");
+}
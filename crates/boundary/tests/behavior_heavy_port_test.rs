@@ -0,0 +1,89 @@
+/// Acceptance tests for PA010: ports carrying default-method logic.
+///
+/// The check is opt-in via `detect_behavior_heavy_ports`; the `behavior-heavy-port`
+/// fixture enables it and declares a Java `DiscountPolicy` port with two `default`
+/// methods next to a pure-abstract `PriceRepository`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn behavior_heavy_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("behavior-heavy-port");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA010"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A port with two default methods fires; a pure-abstract port does not
+// ----------------------------------------------------------------------------
+#[test]
+fn port_with_two_default_methods_is_behavior_heavy() {
+    let violations = behavior_heavy_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one PA010 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["rule_name"], "behavior-heavy-port");
+    let kind = &v["kind"]["BehaviorHeavyPort"];
+    assert_eq!(kind["port"], "DiscountPolicy");
+    assert_eq!(kind["methods"], serde_json::json!(["apply", "applyAll"]));
+}
+
+// ----------------------------------------------------------------------------
+// The threshold is configurable
+// ----------------------------------------------------------------------------
+#[test]
+fn default_methods_within_threshold_pass() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("lenient.toml");
+    std::fs::write(
+        &config,
+        "[rules]\ndetect_behavior_heavy_ports = true\nmax_port_default_methods = 2\n",
+    )
+    .unwrap();
+
+    let violations = behavior_heavy_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "two default methods are within a max of 2, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn behavior_heavy_ports_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = behavior_heavy_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "behavior-heavy port check should be opt-in, got: {violations:?}"
+    );
+}
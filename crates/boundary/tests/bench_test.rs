@@ -0,0 +1,83 @@
+/// Acceptance tests for `boundary bench`.
+///
+/// Verifies the per-phase timing report. The full benchmark run is ignored by
+/// default; run it with `cargo test --test bench_test -- --ignored`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn bench_json(fixture_name: &str, extra: &[&str]) -> serde_json::Value {
+    let path = fixture(fixture_name);
+    let mut args = vec!["bench", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary bench");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "bench failed: {stderr}");
+    serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("invalid JSON: {e}\noutput: {stdout}"))
+}
+
+// ----------------------------------------------------------------------------
+// A single run's phase timings add up to its total
+// ----------------------------------------------------------------------------
+#[test]
+fn phase_timings_sum_to_total() {
+    let json = bench_json("sample-go-project", &["--runs", "1"]);
+
+    let phases = json["phases"].as_array().expect("phases array");
+    let names: Vec<&str> = phases.iter().filter_map(|p| p["phase"].as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "discovery",
+            "parse",
+            "extraction",
+            "graph_build",
+            "detection",
+            "scoring"
+        ]
+    );
+
+    let sum: f64 = phases
+        .iter()
+        .map(|p| p["median_ms"].as_f64().expect("median_ms"))
+        .sum();
+    let total = json["total_ms"].as_f64().expect("total_ms");
+    assert!(
+        sum <= total + 1e-6 && total - sum < 1.0,
+        "phases sum to {sum}ms but total is {total}ms"
+    );
+}
+
+#[test]
+fn bench_rejects_zero_runs() {
+    let output = boundary_cmd()
+        .args(["bench", &fixture("sample-go-project"), "--runs", "0"])
+        .output()
+        .expect("failed to run boundary bench");
+    assert!(!output.status.success());
+}
+
+// ----------------------------------------------------------------------------
+// Full benchmark: several runs with throughput
+// ----------------------------------------------------------------------------
+#[test]
+#[ignore = "benchmark; run explicitly with --ignored"]
+fn bench_reports_throughput() {
+    let json = bench_json("sample-go-project", &[]);
+
+    assert_eq!(json["runs"], 5);
+    assert!(json["files"].as_u64().unwrap() > 0, "{json}");
+    assert!(json["files_per_sec"].as_f64().unwrap() > 0.0, "{json}");
+}
@@ -0,0 +1,73 @@
+/// Acceptance tests for `boundary analyze --by-directory`.
+///
+/// The `by-directory` fixture has two top-level directories: `billing` pairs a domain
+/// port with an infrastructure adapter, while `legacy` has a domain entity holding a
+/// concrete database handle and no ports.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn analyze_by_directory(format: &str) -> String {
+    let path = fixture("by-directory");
+    let output = boundary_cmd()
+        .args(["analyze", &path, "--by-directory", "--format", format])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "boundary failed: stderr={stderr}");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+// ----------------------------------------------------------------------------
+// Each top-level directory gets its own score
+// ----------------------------------------------------------------------------
+#[test]
+fn directories_are_scored_separately() {
+    let stdout = analyze_by_directory("json");
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    let services = parsed["services"]
+        .as_array()
+        .expect("should have a services array");
+
+    let names: Vec<_> = services
+        .iter()
+        .map(|s| s["service_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["billing", "legacy"]);
+
+    let overall = |i: usize| services[i]["result"]["score"]["overall"].as_f64().unwrap();
+    assert!(
+        overall(0) > overall(1),
+        "billing ({}) should outscore legacy ({})",
+        overall(0),
+        overall(1)
+    );
+    assert!(parsed["aggregate"]["score"]["overall"].is_number());
+}
+
+#[test]
+fn text_output_lists_each_directory() {
+    let stdout = analyze_by_directory("text");
+    assert!(stdout.contains("billing"), "output: {stdout}");
+    assert!(stdout.contains("legacy"), "output: {stdout}");
+}
+
+// ----------------------------------------------------------------------------
+// --by-directory cannot be combined with --per-service
+// ----------------------------------------------------------------------------
+#[test]
+fn by_directory_conflicts_with_per_service() {
+    let path = fixture("by-directory");
+    let output = boundary_cmd()
+        .args(["analyze", &path, "--by-directory", "--per-service"])
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(!output.status.success());
+}
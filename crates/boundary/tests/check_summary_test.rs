@@ -0,0 +1,101 @@
+/// Acceptance tests for `boundary check --format json --summary-only`.
+///
+/// The summary is a small object for CI gating; the exit code still reflects
+/// pass/fail.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn check_json(fixture_name: &str, extra: &[&str]) -> (serde_json::Value, Option<i32>) {
+    let path = fixture(fixture_name);
+    let mut args = vec!["check", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary check");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("invalid JSON: {e}\noutput: {stdout}"));
+    (json, output.status.code())
+}
+
+// ----------------------------------------------------------------------------
+// The summary has exactly the documented keys and matches the full result
+// ----------------------------------------------------------------------------
+#[test]
+fn summary_has_documented_keys_and_values() {
+    let (summary, code) = check_json("sample-go-project", &["--summary-only"]);
+    let (full, _) = check_json("sample-go-project", &[]);
+
+    let mut keys: Vec<&str> = summary
+        .as_object()
+        .expect("summary should be an object")
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort_unstable();
+    assert_eq!(
+        keys,
+        [
+            "error_count",
+            "failing_count",
+            "info_count",
+            "overall",
+            "passed",
+            "warning_count"
+        ]
+    );
+
+    let count = |severity: &str| {
+        full["violations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|v| v["severity"] == severity)
+            .count()
+    };
+    assert_eq!(summary["passed"], full["check"]["passed"]);
+    assert_eq!(summary["overall"], full["score"]["overall"]);
+    assert_eq!(summary["error_count"], count("error"));
+    assert_eq!(summary["warning_count"], count("warning"));
+    assert_eq!(summary["info_count"], count("info"));
+    assert_eq!(
+        summary["failing_count"],
+        full["check"]["failing_violation_count"]
+    );
+
+    assert_eq!(summary["passed"], false, "the Go fixture has L001 errors");
+    assert_eq!(code, Some(1), "exit code should still signal failure");
+}
+
+// ----------------------------------------------------------------------------
+// Counts are not affected by --max-violations
+// ----------------------------------------------------------------------------
+#[test]
+fn summary_counts_every_violation() {
+    let (summary, _) = check_json("sample-go-project", &["--summary-only"]);
+    let (truncated, _) = check_json(
+        "sample-go-project",
+        &["--summary-only", "--max-violations", "1"],
+    );
+    assert_eq!(summary, truncated);
+}
+
+#[test]
+fn summary_only_requires_json() {
+    let output = boundary_cmd()
+        .args(["check", &fixture("sample-go-project"), "--summary-only"])
+        .output()
+        .expect("failed to run boundary check");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("--format json"), "stderr: {stderr}");
+}
@@ -0,0 +1,98 @@
+/// Acceptance tests for L010: classification coverage below `min_classification_coverage`.
+///
+/// The `classification-coverage` fixture classifies two of its four components (the
+/// `domain` package) and leaves the two in `worker` unclassified, for 50% coverage
+/// against a configured minimum of 90%.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn coverage_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("classification-coverage");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L010"))
+        .cloned()
+        .collect()
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+// ----------------------------------------------------------------------------
+// 50% coverage fails a 90% minimum
+// ----------------------------------------------------------------------------
+#[test]
+fn coverage_below_minimum_is_flagged() {
+    let violations = coverage_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one L010 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert_eq!(v["rule_name"], "low-classification-coverage");
+    let kind = &v["kind"]["LowClassificationCoverage"];
+    assert_eq!(kind["coverage"], 50.0);
+    assert_eq!(kind["minimum"], 90.0);
+    assert_eq!(kind["unclassified"], 2);
+}
+
+#[test]
+fn check_fails_below_minimum() {
+    let output = boundary_cmd()
+        .args(["check", &fixture("classification-coverage")])
+        .output()
+        .expect("failed to run boundary check");
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "check should fail: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Coverage at the minimum passes; the check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn coverage_at_minimum_passes() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "[rules]\nmin_classification_coverage = 50.0\n");
+
+    let violations = coverage_violations(&["--config", &config]);
+    assert!(violations.is_empty(), "got: {violations:?}");
+}
+
+#[test]
+fn classification_coverage_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "");
+
+    let violations = coverage_violations(&["--config", &config]);
+    assert!(
+        violations.is_empty(),
+        "classification coverage check should be opt-in, got: {violations:?}"
+    );
+}
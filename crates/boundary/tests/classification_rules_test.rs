@@ -0,0 +1,110 @@
+/// Acceptance tests for `[[classification.rules]]`.
+///
+/// The `classification-rules` fixture holds a `PaymentGateway` struct that the Go
+/// analyzer classifies as a value object; its config maps `.*Gateway$` to Adapter.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn components_by_kind(extra: &[&str]) -> serde_json::Value {
+    let path = fixture("classification-rules");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(
+        output.status.success(),
+        "analyze failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    parsed["metrics"]["components_by_kind"].clone()
+}
+
+// ----------------------------------------------------------------------------
+// A name rule reclassifies PaymentGateway as an adapter
+// ----------------------------------------------------------------------------
+#[test]
+fn gateway_rule_reclassifies_to_adapter() {
+    let kinds = components_by_kind(&[]);
+    assert_eq!(kinds["adapter"], 1, "kinds: {kinds}");
+    assert!(kinds.get("value_object").is_none(), "kinds: {kinds}");
+}
+
+#[test]
+fn without_rules_default_classification_applies() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let kinds = components_by_kind(&["--config", config.to_str().unwrap()]);
+    assert_eq!(kinds["value_object"], 1, "kinds: {kinds}");
+}
+
+// ----------------------------------------------------------------------------
+// Invalid patterns fail the run
+// ----------------------------------------------------------------------------
+#[test]
+fn invalid_rule_pattern_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("bad.toml");
+    std::fs::write(
+        &config,
+        "[[classification.rules]]\nname = \"(\"\nkind = \"adapter\"\n",
+    )
+    .unwrap();
+
+    let output = boundary_cmd()
+        .args(["analyze", &fixture("classification-rules"), "--config"])
+        .arg(&config)
+        .output()
+        .expect("failed to run boundary analyze");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("invalid name pattern in classification rule 1"),
+        "stderr: {stderr}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Multi-service analysis fails on invalid patterns too, instead of dropping the rules
+// ----------------------------------------------------------------------------
+#[test]
+fn invalid_rule_pattern_is_an_error_per_service() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("bad.toml");
+    std::fs::write(
+        &config,
+        "[[classification.rules]]\nname = \"(\"\nkind = \"adapter\"\n",
+    )
+    .unwrap();
+
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("classification-rules"),
+            "--per-service",
+            "--config",
+        ])
+        .arg(&config)
+        .output()
+        .expect("failed to run boundary analyze");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("invalid name pattern in classification rule 1"),
+        "stderr: {stderr}"
+    );
+}
@@ -0,0 +1,133 @@
+/// Acceptance tests for `boundary compare`.
+///
+/// The Rust fixture's domain imports its Postgres repository (L005). Comparing it
+/// against a copy with that import removed should report the fix as an improvement.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+/// A copy of the Rust fixture whose domain no longer imports infrastructure.
+fn fixed_rust_fixture() -> tempfile::TempDir {
+    let dir = copy_fixture_to_tempdir("sample-rust-project");
+    let domain = dir.path().join("src/domain/user/mod.rs");
+    let content = std::fs::read_to_string(&domain).unwrap();
+    let fixed = content.replace(
+        "use crate::infrastructure::postgres::PostgresUserRepository;\n",
+        "",
+    );
+    assert_ne!(
+        content, fixed,
+        "fixture should contain the infrastructure import"
+    );
+    std::fs::write(&domain, fixed).unwrap();
+    dir
+}
+
+fn run_compare(left: &str, right: &str, format: &str) -> String {
+    let output = boundary_cmd()
+        .args(["compare", left, right, "--format", format])
+        .output()
+        .expect("failed to run boundary compare");
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    assert!(
+        output.status.success(),
+        "boundary compare failed: stdout={stdout}, stderr={}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    stdout
+}
+
+// ----------------------------------------------------------------------------
+// Fixing the violation shows up as an improvement in JSON
+// ----------------------------------------------------------------------------
+#[test]
+fn fixed_violation_is_reported_as_improvement() {
+    let fixed = fixed_rust_fixture();
+    let stdout = run_compare(
+        &fixture("sample-rust-project"),
+        fixed.path().to_str().unwrap(),
+        "json",
+    );
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    assert_eq!(parsed["violations"]["left"], 1, "{parsed}");
+    assert_eq!(parsed["violations"]["right"], 0, "{parsed}");
+    assert_eq!(parsed["violations"]["change"], "improved");
+
+    let l005 = parsed["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["rule"] == "L005")
+        .expect("L005 should be compared");
+    assert_eq!(l005["delta"], -1);
+    assert_eq!(l005["change"], "improved");
+
+    let overall = parsed["scores"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["metric"] == "overall")
+        .unwrap();
+    assert_ne!(overall["change"], "regressed", "{overall}");
+}
+
+// ----------------------------------------------------------------------------
+// Comparing a tree with itself reports nothing changed
+// ----------------------------------------------------------------------------
+#[test]
+fn identical_trees_are_unchanged() {
+    let path = fixture("sample-rust-project");
+    let stdout = run_compare(&path, &path, "json");
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let changes = parsed["scores"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .chain(parsed["rules"].as_array().unwrap())
+        .chain(std::iter::once(&parsed["violations"]))
+        .map(|c| c["change"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert!(changes.iter().all(|c| *c == "unchanged"), "{parsed}");
+}
+
+// ----------------------------------------------------------------------------
+// The text report shows both trees side by side
+// ----------------------------------------------------------------------------
+#[test]
+fn text_report_lists_both_trees() {
+    let fixed = fixed_rust_fixture();
+    let stdout = run_compare(
+        &fixture("sample-rust-project"),
+        fixed.path().to_str().unwrap(),
+        "text",
+    );
+    assert!(stdout.contains("Boundary - Comparison"), "{stdout}");
+    assert!(stdout.contains("sample-rust-project"), "{stdout}");
+    assert!(stdout.contains("L005"), "{stdout}");
+    assert!(stdout.contains("-1 improved"), "{stdout}");
+}
@@ -0,0 +1,121 @@
+/// Acceptance tests for `boundary metrics --per-component`.
+///
+/// The `component-instability` fixture is a Java project where the concrete `Money`
+/// class is imported from four other packages, next to an `OrderRepository` port and
+/// a few leaf classes.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn component_metrics(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args([
+            "metrics",
+            &fixture(fixture_name),
+            "--per-component",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary metrics");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    parsed["components"]
+        .as_array()
+        .expect("should have a components array")
+        .clone()
+}
+
+fn find<'a>(metrics: &'a [serde_json::Value], name: &str) -> &'a serde_json::Value {
+    metrics
+        .iter()
+        .find(|m| m["name"] == name)
+        .unwrap_or_else(|| panic!("{name} missing from {metrics:?}"))
+}
+
+// ---------------------------------------------------------------------------
+// Ordering
+// ---------------------------------------------------------------------------
+
+/// A concrete class many packages depend on is stable but not abstract, so it
+/// leads the list in the zone of pain.
+#[test]
+fn depended_upon_concrete_class_is_listed_first() {
+    let metrics = component_metrics("component-instability");
+    let first = &metrics[0];
+    assert_eq!(first["name"], "Money", "metrics: {metrics:?}");
+    assert_eq!(first["kind"], "entity");
+    assert_eq!(first["afferent_coupling"], 4);
+    assert_eq!(first["efferent_coupling"], 0);
+    assert_eq!(first["instability"].as_f64(), Some(0.0));
+    assert_eq!(first["distance"].as_f64(), Some(1.0));
+    assert_eq!(first["zone"], "pain");
+}
+
+/// The list is sorted by distance from the main sequence.
+#[test]
+fn components_are_sorted_by_distance() {
+    let metrics = component_metrics("component-instability");
+    let distances: Vec<f64> = metrics
+        .iter()
+        .map(|m| m["distance"].as_f64().unwrap())
+        .collect();
+    assert!(
+        distances.windows(2).all(|w| w[0] >= w[1]),
+        "distances should be descending: {distances:?}"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Coupling
+// ---------------------------------------------------------------------------
+
+/// A port that is depended upon is stable and abstract: on the main sequence.
+#[test]
+fn depended_upon_port_is_on_main_sequence() {
+    let metrics = component_metrics("component-instability");
+    let port = find(&metrics, "OrderRepository");
+    assert_eq!(port["abstractness"].as_f64(), Some(1.0));
+    assert_eq!(port["afferent_coupling"], 2);
+    assert_eq!(port["distance"].as_f64(), Some(0.0));
+    assert!(port.get("zone").is_none(), "port: {port}");
+}
+
+/// Efferent coupling counts the components a class's file imports from other packages.
+#[test]
+fn efferent_coupling_counts_imported_components() {
+    let metrics = component_metrics("component-instability");
+    let service = find(&metrics, "OrderService");
+    assert_eq!(service["efferent_coupling"], 2);
+    assert_eq!(service["afferent_coupling"], 1);
+    assert_eq!(service["instability"].as_f64(), Some(0.67));
+}
+
+// ---------------------------------------------------------------------------
+// Package metrics
+// ---------------------------------------------------------------------------
+
+/// Without `--per-component`, the command reports package metrics.
+#[test]
+fn metrics_without_per_component_reports_packages() {
+    let output = boundary_cmd()
+        .args(["metrics", &fixture("rcm-zone-of-pain"), "--format", "json"])
+        .output()
+        .expect("failed to run boundary metrics");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    assert!(
+        parsed["packages"].as_array().is_some_and(|p| !p.is_empty()),
+        "output: {parsed}"
+    );
+}
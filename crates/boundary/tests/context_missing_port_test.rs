@@ -0,0 +1,80 @@
+/// Acceptance tests for PA011: bounded contexts without a single port.
+///
+/// The check is opt-in via `detect_context_missing_ports`; the `context-missing-port`
+/// fixture enables it. `services/billing` declares an `InvoiceRepository` port with a
+/// Postgres adapter, while `services/shipping` has only concrete classes: a Postgres
+/// repository and a carrier client.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn context_missing_port_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("context-missing-port");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA011"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A context of concrete classes fires; a context with a port does not
+// ----------------------------------------------------------------------------
+#[test]
+fn context_without_port_is_flagged() {
+    let violations = context_missing_port_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one PA011 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["rule_name"], "context-missing-port");
+    let kind = &v["kind"]["ContextMissingPort"];
+    assert!(
+        kind["context"]
+            .as_str()
+            .unwrap()
+            .ends_with("services/shipping"),
+        "context: {}",
+        kind["context"]
+    );
+    assert_eq!(
+        kind["adapters"],
+        serde_json::json!(["CarrierClient", "PostgresShipmentRepository"])
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn context_missing_ports_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = context_missing_port_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "context missing port check should be opt-in, got: {violations:?}"
+    );
+}
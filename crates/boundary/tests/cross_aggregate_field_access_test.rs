@@ -0,0 +1,91 @@
+/// Acceptance tests for D007: domain entity fields accessed from another package.
+///
+/// In the `cross-aggregate-field-access` fixture, the application `greeting` package
+/// reads `u.Name` on a `*user.User` in `Greet` and calls `u.DisplayName()` in `Farewell`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+fn field_access_violations(config: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("cross-aggregate-field-access"),
+            "--config",
+            config,
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("D007"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Reading an entity field from another package is flagged; method calls are not
+// ----------------------------------------------------------------------------
+#[test]
+fn field_read_from_other_package_is_flagged() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(
+        &dir,
+        "[rules]\ndetect_cross_aggregate_field_access = true\n",
+    );
+
+    let violations = field_access_violations(&config);
+    let [v] = violations.as_slice() else {
+        panic!("expected one D007 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["rule_name"], "cross-aggregate-field-access");
+    let kind = &v["kind"]["CrossAggregateFieldAccess"];
+    assert_eq!(kind["entity"], "User");
+    assert_eq!(kind["field"], "Name");
+    assert!(
+        kind["accessor"]
+            .as_str()
+            .unwrap()
+            .ends_with("application/greeting"),
+        "should name the accessing package, got: {kind}"
+    );
+    assert_eq!(v["location"]["line"], 6, "should point at u.Name, got: {v}");
+}
+
+// ----------------------------------------------------------------------------
+// Off by default
+// ----------------------------------------------------------------------------
+#[test]
+fn disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "");
+
+    let violations = field_access_violations(&config);
+    assert!(
+        violations.is_empty(),
+        "D007 should be opt-in, got: {violations:?}"
+    );
+}
@@ -0,0 +1,116 @@
+/// Acceptance tests for package cycles that cross layers (D001 with `cross_layer`).
+///
+/// The check is opt-in via `detect_cross_layer_cycles`; the `cross-layer-cycle`
+/// fixture enables it together with D003 and holds a domain package and an
+/// infrastructure package that import each other, plus two domain packages that
+/// import each other.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn cycle_violations(fixture_name: &str, extra_args: &[&str]) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .args(extra_args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| matches!(v["rule"].as_str(), Some("D001" | "D003")))
+        .cloned()
+        .collect()
+}
+
+fn names(values: &serde_json::Value) -> Vec<String> {
+    values
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|p| p.as_str().map(String::from))
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A domain <-> infrastructure cycle is flagged as cross-layer
+// ----------------------------------------------------------------------------
+#[test]
+fn domain_infrastructure_cycle_is_cross_layer() {
+    let violations = cycle_violations("cross-layer-cycle", &[]);
+    let cross: Vec<_> = violations
+        .iter()
+        .filter(|v| v["kind"]["CircularDependency"]["cross_layer"] == true)
+        .collect();
+    let [v] = cross.as_slice() else {
+        panic!("expected one cross-layer D001 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["rule"], "D001");
+    assert_eq!(v["severity"], "error");
+    let kind = &v["kind"]["CircularDependency"];
+    assert_eq!(names(&kind["layers"]), ["Domain", "Infrastructure"]);
+    let cycle = names(&kind["cycle"]);
+    assert_eq!(cycle.len(), 2, "cycle: {cycle:?}");
+    assert!(cycle[0].ends_with("domain/order"), "{cycle:?}");
+    assert!(cycle[1].ends_with("infrastructure/postgres"), "{cycle:?}");
+    assert!(
+        v["message"]
+            .as_str()
+            .unwrap()
+            .contains("across layers (domain, infrastructure)"),
+        "message: {}",
+        v["message"]
+    );
+}
+
+// ----------------------------------------------------------------------------
+// An intra-domain cycle stays a D003 warning, not a cross-layer D001
+// ----------------------------------------------------------------------------
+#[test]
+fn intra_domain_cycle_is_reported_separately() {
+    let violations = cycle_violations("cross-layer-cycle", &[]);
+    let intra: Vec<_> = violations.iter().filter(|v| v["rule"] == "D003").collect();
+    let [v] = intra.as_slice() else {
+        panic!("expected one D003 violation, got: {violations:?}");
+    };
+    assert_eq!(v["severity"], "warning");
+    let packages = names(&v["kind"]["IntraLayerCycle"]["packages"]);
+    assert!(packages[0].ends_with("domain/catalog"), "{packages:?}");
+    assert!(packages[1].ends_with("domain/pricing"), "{packages:?}");
+
+    assert!(
+        !violations.iter().any(|v| v["rule"] == "D001"
+            && names(&v["kind"]["CircularDependency"]["cycle"])
+                .iter()
+                .any(|p| p.ends_with("domain/catalog"))),
+        "the intra-domain cycle should not be a D001: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Package-level cross-layer cycles are disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn cross_layer_cycles_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = cycle_violations("cross-layer-cycle", &["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "package cycle checks should be opt-in, got: {violations:?}"
+    );
+}
@@ -0,0 +1,126 @@
+/// Acceptance tests for the `[debt]` architecture debt estimate.
+///
+/// `[debt]` maps rule IDs or category names to remediation hours; the report sums
+/// them over the violations found.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Analyze `fixture_name` with `config` as the only configuration.
+fn analyze_with_config(fixture_name: &str, config: &str, extra_args: &[&str]) -> serde_json::Value {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("boundary.toml");
+    std::fs::write(&config_path, config).unwrap();
+
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .args(["--config", config_path.to_str().unwrap()])
+        .args(extra_args)
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(&stdout).expect("output should be valid JSON")
+}
+
+fn count_rule(result: &serde_json::Value, rule: &str) -> usize {
+    result["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"] == rule)
+        .count()
+}
+
+const DEBT_CONFIG: &str = r#"
+[debt]
+L001 = 4.0
+layer_boundary = 100.0
+domain_infra_leak = 1.5
+"#;
+
+// ---------------------------------------------------------------------------
+// Totals
+// ---------------------------------------------------------------------------
+
+/// Each rule costs its violation count times the configured hours; a rule ID
+/// takes precedence over its category name.
+#[test]
+fn debt_totals_match_counts_times_hours() {
+    let result = analyze_with_config("sample-go-project", DEBT_CONFIG, &[]);
+    let l001 = count_rule(&result, "L001");
+    let l005 = count_rule(&result, "L005");
+    assert!(
+        l001 > 0 && l005 > 0,
+        "fixture should have L001 and L005: {result}"
+    );
+
+    let debt = &result["debt"];
+    let entry = |rule: &str| {
+        debt["by_rule"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|e| e["rule"] == rule)
+            .unwrap_or_else(|| panic!("no debt entry for {rule}: {debt}"))
+            .clone()
+    };
+    assert_eq!(entry("L001")["count"], l001);
+    assert_eq!(entry("L001")["hours"].as_f64(), Some(l001 as f64 * 4.0));
+    assert_eq!(entry("L005")["count"], l005);
+    assert_eq!(entry("L005")["hours"].as_f64(), Some(l005 as f64 * 1.5));
+    assert_eq!(
+        debt["total_hours"].as_f64(),
+        Some(l001 as f64 * 4.0 + l005 as f64 * 1.5)
+    );
+}
+
+/// Ignored rules drop out of the estimate along with their violations.
+#[test]
+fn ignored_rules_do_not_add_debt() {
+    let result = analyze_with_config("sample-go-project", DEBT_CONFIG, &["--ignore", "L001"]);
+    let l005 = count_rule(&result, "L005");
+    let debt = &result["debt"];
+    assert!(
+        !debt["by_rule"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|e| e["rule"] == "L001"),
+        "debt: {debt}"
+    );
+    assert_eq!(debt["total_hours"].as_f64(), Some(l005 as f64 * 1.5));
+}
+
+/// The text report shows the total.
+#[test]
+fn text_report_shows_debt() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("boundary.toml");
+    std::fs::write(&config_path, DEBT_CONFIG).unwrap();
+    let output = boundary_cmd()
+        .args(["analyze", &fixture("sample-go-project")])
+        .args(["--config", config_path.to_str().unwrap()])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Architecture Debt: 7.0 hours"),
+        "stdout: {stdout}"
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Disabled by default
+// ---------------------------------------------------------------------------
+
+#[test]
+fn no_debt_without_config() {
+    let result = analyze_with_config("sample-go-project", "", &[]);
+    assert!(result.get("debt").is_none(), "result: {result}");
+}
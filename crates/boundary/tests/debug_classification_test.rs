@@ -0,0 +1,90 @@
+/// Acceptance tests for `analyze --debug-classification`.
+///
+/// The flag prints, per layer, which discovered files the layer patterns matched,
+/// plus files no pattern matched, to stderr. In-source `boundary:layer` annotations
+/// take precedence over the path.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+// ----------------------------------------------------------------------------
+// Domain files of the Rust fixture are listed under the domain layer
+// ----------------------------------------------------------------------------
+#[test]
+fn debug_output_lists_domain_files() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("sample-rust-project"),
+            "--format",
+            "json",
+            "--debug-classification",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let domain = stderr
+        .split("  domain: 1 file(s)\n")
+        .nth(1)
+        .unwrap_or_else(|| panic!("should count one domain file, got:\n{stderr}"));
+    assert!(
+        domain
+            .lines()
+            .next()
+            .is_some_and(|line| line.trim().ends_with("src/domain/user/mod.rs")),
+        "got:\n{stderr}"
+    );
+    assert!(stderr.contains("  unmatched: 0 file(s)"), "got:\n{stderr}");
+
+    // stdout stays a clean JSON report
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str::<serde_json::Value>(&stdout).expect("stdout should be valid JSON");
+}
+
+// ----------------------------------------------------------------------------
+// A layer annotation counts even where no layer pattern matches the path
+// ----------------------------------------------------------------------------
+#[test]
+fn debug_output_honors_layer_annotation() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("layer-annotation"),
+            "--format",
+            "json",
+            "--debug-classification",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let domain = stderr
+        .split("  domain: 1 file(s)\n")
+        .nth(1)
+        .unwrap_or_else(|| panic!("should count one domain file, got:\n{stderr}"));
+    assert!(
+        domain
+            .lines()
+            .next()
+            .is_some_and(|line| line.trim().ends_with("internal/shared/money.go")),
+        "got:\n{stderr}"
+    );
+    let unmatched = stderr
+        .split("  unmatched: ")
+        .nth(1)
+        .unwrap_or_else(|| panic!("should list unmatched files, got:\n{stderr}"));
+    assert!(
+        unmatched.contains("internal/common/clock.go"),
+        "got:\n{stderr}"
+    );
+    assert!(!unmatched.contains("money.go"), "got:\n{stderr}");
+}
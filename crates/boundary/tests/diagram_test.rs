@@ -135,3 +135,63 @@ fn mermaid_layer_diagram_succeeds_on_project_with_violations() {
         "diagram should not contain synthetic <file> nodes: {output}"
     );
 }
+
+// ----------------------------------------------------------------------------
+// Scenario: Cytoscape.js export marks the violating edge
+// Given a project with a known layer boundary violation (domain importing infra)
+// When I run "boundary diagram . --diagram-type cytoscape"
+// Then the output is Cytoscape.js elements JSON with a "violating" edge
+// ----------------------------------------------------------------------------
+#[test]
+fn cytoscape_export_marks_violating_edges() {
+    let output = run_diagram("domain-imports-infra", "cytoscape");
+    let parsed: serde_json::Value =
+        serde_json::from_str(&output).expect("cytoscape output should be valid JSON");
+
+    let nodes = parsed["elements"]["nodes"]
+        .as_array()
+        .expect("elements.nodes should be an array");
+    assert!(!nodes.is_empty(), "expected nodes: {output}");
+
+    let edges = parsed["elements"]["edges"]
+        .as_array()
+        .expect("elements.edges should be an array");
+    assert!(
+        edges.iter().any(|e| e["classes"] == "violating"),
+        "domain -> infrastructure edge should carry the violating class: {output}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Scenario: External dependencies are drawn only when requested
+// Given a Go project that imports "github.com/gin-gonic/gin"
+// When I run "boundary diagram . --diagram-type layers [--include-external]"
+// Then the gin node appears only with --include-external
+// ----------------------------------------------------------------------------
+#[test]
+fn external_nodes_only_with_include_external() {
+    let without = run_diagram("external-policy", "layers");
+    assert!(
+        !without.contains("github.com/gin-gonic/gin"),
+        "external nodes should be omitted by default: {without}"
+    );
+
+    for diagram_type in ["layers", "dot"] {
+        let output = boundary_cmd()
+            .args([
+                "diagram",
+                &fixture("external-policy"),
+                "--diagram-type",
+                diagram_type,
+                "--include-external",
+            ])
+            .output()
+            .expect("failed to run boundary diagram");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success());
+        assert!(
+            stdout.contains("\"github.com/gin-gonic/gin\""),
+            "{diagram_type} diagram should contain the external gin node: {stdout}"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+/// Acceptance tests for violation documentation links.
+///
+/// Every built-in violation links to its rule's anchor; `[rules].doc_base_url`
+/// moves the links to another page.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn layer_boundary_doc_url(config: Option<&std::path::Path>) -> String {
+    let mut cmd = boundary_cmd();
+    cmd.args(["analyze", &fixture("sample-go-project"), "--format", "json"]);
+    if let Some(config) = config {
+        cmd.arg("--config").arg(config);
+    }
+    let output = cmd.output().expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    let violation = parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .find(|v| v["rule"] == "L001")
+        .cloned()
+        .expect("sample-go-project should have an L001 violation");
+    violation["doc_url"]
+        .as_str()
+        .expect("L001 should have a doc_url")
+        .to_string()
+}
+
+// ----------------------------------------------------------------------------
+// Layer-boundary violations link to their rule
+// ----------------------------------------------------------------------------
+#[test]
+fn layer_boundary_doc_url_ends_in_rule_id() {
+    let url = layer_boundary_doc_url(None);
+    assert!(
+        url.starts_with("https://rebelopsio.github.io/boundary/features/rules.html"),
+        "default links should point at the rule catalog: {url}"
+    );
+    assert!(url.ends_with("#l001"), "url: {url}");
+}
+
+// ----------------------------------------------------------------------------
+// doc_base_url points links at internal docs
+// ----------------------------------------------------------------------------
+#[test]
+fn doc_base_url_overrides_link_page() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(
+        &config,
+        "[rules]\ndoc_base_url = \"https://wiki.example.com/architecture/rules\"\n",
+    )
+    .unwrap();
+
+    let url = layer_boundary_doc_url(Some(&config));
+    assert_eq!(url, "https://wiki.example.com/architecture/rules#l001");
+}
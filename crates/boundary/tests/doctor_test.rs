@@ -0,0 +1,102 @@
+/// Acceptance tests for `boundary doctor`: one report of languages, config, grammars
+/// and layer classification, exiting 1 when it finds a problem.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Run doctor on `fixture_name`, with `config` as the config file when given.
+fn doctor(fixture_name: &str, config: Option<&str>) -> (Option<i32>, String) {
+    let dir = tempfile::tempdir().unwrap();
+    let mut cmd = boundary_cmd();
+    cmd.args(["doctor", &fixture(fixture_name)]);
+    if let Some(config) = config {
+        let config_path = dir.path().join(".boundary.toml");
+        std::fs::write(&config_path, config).unwrap();
+        cmd.arg("--config").arg(&config_path);
+    }
+    let output = cmd.output().expect("failed to run boundary doctor");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.is_empty(), "stderr: {stderr}");
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    (output.status.code(), stdout)
+}
+
+// ---------------------------------------------------------------------------
+// Healthy project
+// ---------------------------------------------------------------------------
+
+/// A project whose globs classify everything passes with per-language and per-layer counts.
+#[test]
+fn healthy_project_reports_no_problems() {
+    let (code, stdout) = doctor("sample-go-project", Some(""));
+
+    assert_eq!(code, Some(0), "stdout: {stdout}");
+    assert!(stdout.contains("(valid)"), "stdout: {stdout}");
+    assert!(stdout.contains("  go: ok"), "stdout: {stdout}");
+    assert!(stdout.contains("  go: 4 file(s)"), "stdout: {stdout}");
+    assert!(stdout.contains("  domain: 2 file(s)"), "stdout: {stdout}");
+    assert!(stdout.contains("No problems found."), "stdout: {stdout}");
+}
+
+// ---------------------------------------------------------------------------
+// Misconfiguration
+// ---------------------------------------------------------------------------
+
+/// Globs that match no path leave every component unclassified.
+#[test]
+fn reports_zero_classification_when_globs_match_nothing() {
+    let config = r#"
+[layers]
+domain = ["nowhere/domain/**"]
+application = ["nowhere/application/**"]
+infrastructure = ["nowhere/infrastructure/**"]
+presentation = ["nowhere/presentation/**"]
+"#;
+    let (code, stdout) = doctor("sample-go-project", Some(config));
+
+    assert_eq!(code, Some(1), "stdout: {stdout}");
+    assert!(
+        stdout.contains("  unmatched: 4 file(s)"),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.contains("all 4 components unclassified — your layer globs match nothing"),
+        "stdout: {stdout}"
+    );
+}
+
+/// A glob that fails to compile is named, since the classifier skips it silently.
+#[test]
+fn reports_invalid_layer_glob() {
+    let (code, stdout) = doctor(
+        "sample-go-project",
+        Some("[layers]\ninfrastructure = [\"**/infrastructure/{postgres\"]\n"),
+    );
+
+    assert_eq!(code, Some(1), "stdout: {stdout}");
+    assert!(
+        stdout.contains(
+            r#"[layers].infrastructure pattern "**/infrastructure/{postgres" is not a valid glob"#
+        ),
+        "stdout: {stdout}"
+    );
+}
+
+/// An unparsable config is reported rather than silently replaced by defaults.
+#[test]
+fn reports_invalid_config() {
+    let (code, stdout) = doctor("sample-go-project", Some("[layers\n"));
+
+    assert_eq!(code, Some(1), "stdout: {stdout}");
+    assert!(
+        stdout.contains("(invalid, using defaults)"),
+        "stdout: {stdout}"
+    );
+    assert!(stdout.contains("failed to parse"), "stdout: {stdout}");
+}
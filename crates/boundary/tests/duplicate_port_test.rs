@@ -0,0 +1,91 @@
+/// Acceptance tests for PA005 (duplicate-port).
+///
+/// The rule is opt-in via `detect_duplicate_ports`; the `duplicate-ports`
+/// fixture enables it in its `.boundary.toml`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn duplicate_port_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA005"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Two UserRepository ports with the same methods in different packages fire
+// ----------------------------------------------------------------------------
+#[test]
+fn same_port_in_two_packages_fires() {
+    let violations = duplicate_port_violations("duplicate-ports");
+    assert_eq!(
+        violations.len(),
+        1,
+        "expected one PA005, got: {violations:?}"
+    );
+
+    let v = &violations[0];
+    assert_eq!(v["kind"]["DuplicatePort"]["port_name"], "UserRepository");
+    assert_eq!(v["severity"], "warning");
+
+    let duplicates = v["kind"]["DuplicatePort"]["duplicates"]
+        .as_array()
+        .expect("duplicates array");
+    assert_eq!(duplicates.len(), 1);
+    let reported = v["location"]["file"].as_str().unwrap();
+    let duplicate = duplicates[0].as_str().unwrap();
+    let mut dirs = [reported, duplicate].map(|p| {
+        if p.contains("/account/") {
+            "account"
+        } else {
+            "user"
+        }
+    });
+    dirs.sort();
+    assert_eq!(dirs, ["account", "user"], "got: {v}");
+}
+
+// ----------------------------------------------------------------------------
+// Ports with the same methods but unrelated names are not duplicates
+// ----------------------------------------------------------------------------
+#[test]
+fn same_methods_different_name_does_not_fire() {
+    let violations = duplicate_port_violations("duplicate-ports");
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v["kind"]["DuplicatePort"]["port_name"] == "InvoiceRepository"),
+        "InvoiceRepository is a distinct port, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn duplicate_port_disabled_by_default() {
+    let violations = duplicate_port_violations("pa002-port-without-impl");
+    assert!(
+        violations.is_empty(),
+        "PA005 should be opt-in, got: {violations:?}"
+    );
+}
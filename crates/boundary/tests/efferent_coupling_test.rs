@@ -0,0 +1,108 @@
+/// Acceptance tests for D006: components depending on too many others.
+///
+/// The `efferent-coupling` fixture sets `max_efferent_coupling = 5` and has an
+/// application `checkout` package importing six domain modules.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn fan_out_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("efferent-coupling");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("D006"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A service depending on six modules fires at a cap of five
+// ----------------------------------------------------------------------------
+#[test]
+fn service_above_cap_is_flagged() {
+    let violations = fan_out_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one D006 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["rule_name"], "excessive-fan-out");
+    let kind = &v["kind"]["ExcessiveFanOut"];
+    assert!(
+        kind["component"]
+            .as_str()
+            .unwrap()
+            .ends_with("application/checkout"),
+        "component: {}",
+        kind["component"]
+    );
+    assert_eq!(kind["fan_out"], 6);
+    let targets: Vec<&str> = kind["targets"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t.as_str().unwrap().rsplit('/').next().unwrap())
+        .collect();
+    assert_eq!(
+        targets,
+        [
+            "cart",
+            "customer",
+            "inventory",
+            "payment",
+            "pricing",
+            "shipping"
+        ]
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Dependencies up to the cap pass
+// ----------------------------------------------------------------------------
+#[test]
+fn service_at_cap_passes() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("six.toml");
+    std::fs::write(&config, "[rules]\nmax_efferent_coupling = 6\n").unwrap();
+
+    let violations = fan_out_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "six dependencies are within a max of 6, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless a cap is configured
+// ----------------------------------------------------------------------------
+#[test]
+fn fan_out_cap_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = fan_out_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "fan-out cap should be opt-in, got: {violations:?}"
+    );
+}
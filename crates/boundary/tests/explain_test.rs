@@ -0,0 +1,106 @@
+/// Acceptance tests for `boundary explain`: a narrative for one violation, with the
+/// dependency chain behind it.
+use std::process::{Command, Output};
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn explain(fixture_name: &str, args: &[&str]) -> Output {
+    boundary_cmd()
+        .arg("explain")
+        .arg(fixture(fixture_name))
+        .args(args)
+        .output()
+        .expect("failed to run boundary explain")
+}
+
+fn explain_json(fixture_name: &str, args: &[&str]) -> serde_json::Value {
+    let output = explain(fixture_name, &[args, &["--format", "json"]].concat());
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("output should be valid JSON")
+}
+
+// ----------------------------------------------------------------------------
+// A layer violation names its layers, the dependency and the fix
+// ----------------------------------------------------------------------------
+#[test]
+fn explain_layer_violation_shows_chain_and_suggestion() {
+    let output = explain("sample-go-project", &["--kind", "L001"]);
+    assert!(output.status.success());
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        text.contains("L001 domain-depends-on-infrastructure"),
+        "{text}"
+    );
+    assert!(text.contains("Layers: domain, infrastructure"), "{text}");
+    assert!(text.contains("Dependency chain:"), "{text}");
+    assert!(text.contains("bad_dependency.go:4"), "{text}");
+    assert!(text.contains("Suggestion:"), "{text}");
+}
+
+// ----------------------------------------------------------------------------
+// A cycle is printed hop by hop, each at its file and line
+// ----------------------------------------------------------------------------
+#[test]
+fn explain_cycle_lists_every_hop() {
+    let report = explain_json("cross-layer-cycle", &["--kind", "circular-dependency"]);
+    let explanation = &report["explanations"][0];
+    assert_eq!(explanation["rule"], "D001");
+    assert_eq!(explanation["is_cycle"], true);
+
+    let chain = explanation["chain"].as_array().unwrap();
+    assert_eq!(chain.len(), 2, "{explanation}");
+    let files: Vec<&str> = chain
+        .iter()
+        .map(|hop| hop["location"]["file"].as_str().unwrap())
+        .collect();
+    assert!(files[0].ends_with("domain/order/order.go"), "{files:?}");
+    assert!(
+        files[1].ends_with("infrastructure/postgres/store.go"),
+        "{files:?}"
+    );
+    assert!(chain
+        .iter()
+        .all(|hop| hop["location"]["line"].as_u64() > Some(0)));
+}
+
+// ----------------------------------------------------------------------------
+// --id selects a single violation by fingerprint prefix
+// ----------------------------------------------------------------------------
+#[test]
+fn explain_by_id_prefix() {
+    let check = boundary_cmd()
+        .args(["check", &fixture("sample-go-project"), "--format", "json"])
+        .output()
+        .expect("failed to run boundary check");
+    let result: serde_json::Value = serde_json::from_slice(&check.stdout).unwrap();
+    let fingerprint = result["violations"][0]["fingerprint"].as_str().unwrap();
+
+    let report = explain_json("sample-go-project", &["--id", &fingerprint[..12]]);
+    let explanations = report["explanations"].as_array().unwrap();
+    assert_eq!(explanations.len(), 1);
+    assert_eq!(explanations[0]["id"], fingerprint);
+}
+
+// ----------------------------------------------------------------------------
+// A selector matching nothing is an error
+// ----------------------------------------------------------------------------
+#[test]
+fn explain_without_match_fails() {
+    let output = explain("sample-go-project", &["--kind", "PA999"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no violation matches --kind PA999"),
+        "{stderr}"
+    );
+}
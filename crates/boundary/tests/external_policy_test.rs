@@ -0,0 +1,66 @@
+/// Acceptance tests for D002 (disallowed-external-dependency).
+///
+/// The `external-policy` fixture forbids every external import in the domain
+/// layer; both its domain and infrastructure code import gin.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn external_policy_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("D002"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Domain importing gin fires under a deny-all domain policy
+// ----------------------------------------------------------------------------
+#[test]
+fn domain_importing_gin_fires() {
+    let violations = external_policy_violations("external-policy");
+    let [v] = violations.as_slice() else {
+        panic!("expected one D002 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert_eq!(
+        v["kind"]["DisallowedExternalDependency"]["import_path"],
+        "github.com/gin-gonic/gin"
+    );
+    assert_eq!(v["kind"]["DisallowedExternalDependency"]["layer"], "Domain");
+    assert!(v["location"]["file"]
+        .as_str()
+        .unwrap()
+        .contains("domain/order/order.go"));
+}
+
+// ----------------------------------------------------------------------------
+// Layers without a policy are unrestricted
+// ----------------------------------------------------------------------------
+#[test]
+fn no_policy_means_no_violations() {
+    let violations = external_policy_violations("sample-go-project");
+    assert!(
+        violations.is_empty(),
+        "D002 requires a policy, got: {violations:?}"
+    );
+}
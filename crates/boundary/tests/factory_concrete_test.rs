@@ -0,0 +1,86 @@
+/// Acceptance tests for PA012: factories returning a concrete adapter where a port
+/// exists.
+///
+/// The check is opt-in via `detect_factory_concrete_returns`; the `factory-concrete`
+/// fixture enables it. The domain declares a `UserRepository` port implemented by
+/// `PostgresUserRepository`. `NewUserRepo` and the application-layer `NewPostgresStore`
+/// return the adapter; `NewUserStore` returns the port.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn factory_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("factory-concrete");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA012"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Factories returning the adapter fire; the one returning the port passes
+// ----------------------------------------------------------------------------
+#[test]
+fn factory_returning_concrete_adapter_is_flagged() {
+    let violations = factory_violations(&[]);
+    let mut factories: Vec<&str> = violations
+        .iter()
+        .map(|v| {
+            v["kind"]["FactoryReturnsConcrete"]["factory"]
+                .as_str()
+                .unwrap()
+        })
+        .collect();
+    factories.sort_unstable();
+    assert_eq!(
+        factories,
+        ["NewPostgresStore", "NewUserRepo"],
+        "violations: {violations:?}"
+    );
+
+    let v = violations
+        .iter()
+        .find(|v| v["kind"]["FactoryReturnsConcrete"]["factory"] == "NewUserRepo")
+        .unwrap();
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["rule_name"], "factory-returns-concrete-type");
+    let kind = &v["kind"]["FactoryReturnsConcrete"];
+    assert_eq!(kind["concrete_type"], "PostgresUserRepository");
+    assert_eq!(kind["port"], "UserRepository");
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn factory_concrete_returns_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = factory_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "factory concrete return check should be opt-in, got: {violations:?}"
+    );
+}
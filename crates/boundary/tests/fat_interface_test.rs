@@ -0,0 +1,85 @@
+/// Acceptance tests for PA006 (fat-interface).
+///
+/// The rule is opt-in via `detect_fat_interfaces`; the `fat-interface` fixture
+/// enables it and holds a 10-method and a 3-method port in both Go and Java.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn fat_interface_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA006"))
+        .cloned()
+        .collect()
+}
+
+fn flagged(violations: &[serde_json::Value], port: &str) -> Option<serde_json::Value> {
+    violations
+        .iter()
+        .find(|v| v["kind"]["FatInterface"]["port"] == port)
+        .cloned()
+}
+
+// ----------------------------------------------------------------------------
+// 10-method ports fire PA006
+// ----------------------------------------------------------------------------
+#[test]
+fn go_ten_method_interface_fires() {
+    let violations = fat_interface_violations("fat-interface");
+    let v = flagged(&violations, "CatalogStore").expect("CatalogStore should fire PA006");
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["kind"]["FatInterface"]["method_count"], 10);
+}
+
+#[test]
+fn java_ten_method_interface_fires() {
+    let violations = fat_interface_violations("fat-interface");
+    let v = flagged(&violations, "CatalogGateway").expect("CatalogGateway should fire PA006");
+
+    assert_eq!(v["kind"]["FatInterface"]["method_count"], 10);
+}
+
+// ----------------------------------------------------------------------------
+// 3-method ports pass
+// ----------------------------------------------------------------------------
+#[test]
+fn three_method_interfaces_do_not_fire() {
+    let violations = fat_interface_violations("fat-interface");
+    for port in ["ProductReader", "PriceLookup"] {
+        assert!(
+            flagged(&violations, port).is_none(),
+            "{port} has 3 methods, got: {violations:?}"
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn fat_interface_disabled_by_default() {
+    let violations = fat_interface_violations("sample-java-project");
+    assert!(
+        violations.is_empty(),
+        "PA006 should be opt-in, got: {violations:?}"
+    );
+}
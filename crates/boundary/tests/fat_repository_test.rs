@@ -0,0 +1,97 @@
+/// Acceptance tests for PA004 (fat-repository).
+///
+/// The rule is opt-in via `detect_fat_repositories`; the `fat-repository`
+/// fixture enables it in its `.boundary.toml`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn fat_repository_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA004"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A Go repository with a business method fires PA004
+// ----------------------------------------------------------------------------
+#[test]
+fn go_repository_with_business_method_fires() {
+    let violations = fat_repository_violations("fat-repository");
+    let v = violations
+        .iter()
+        .find(|v| v["kind"]["FatRepository"]["repository_name"] == "OrderRepository")
+        .expect("OrderRepository should have a PA004 violation");
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(
+        v["kind"]["FatRepository"]["non_crud_methods"],
+        serde_json::json!(["CalculateDiscount"])
+    );
+    assert!(
+        v["message"].as_str().unwrap().contains("CalculateDiscount"),
+        "message should name the offending method, got: {v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// CRUD-only repositories are not flagged
+// ----------------------------------------------------------------------------
+#[test]
+fn crud_only_repository_does_not_fire() {
+    let violations = fat_repository_violations("fat-repository");
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v["kind"]["FatRepository"]["repository_name"] == "CustomerRepository"),
+        "CustomerRepository only has CRUD methods, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Unexported Go methods don't count toward the method total
+// ----------------------------------------------------------------------------
+#[test]
+fn unexported_methods_do_not_count() {
+    // InvoiceRepository has 5 exported CRUD methods (the configured max) plus
+    // two unexported, non-CRUD helpers.
+    let violations = fat_repository_violations("fat-repository");
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v["kind"]["FatRepository"]["repository_name"] == "InvoiceRepository"),
+        "unexported helpers should not make InvoiceRepository fat, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn fat_repository_disabled_by_default() {
+    // missing-port-fix has a repository with an unexported helper but no config.
+    let violations = fat_repository_violations("missing-port-fix");
+    assert!(
+        violations.is_empty(),
+        "PA004 should be opt-in, got: {violations:?}"
+    );
+}
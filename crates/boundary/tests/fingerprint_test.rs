@@ -0,0 +1,154 @@
+/// Acceptance tests for violation fingerprints: the `fingerprint` on every JSON
+/// violation, shared with the GitLab report, that identifies a violation across runs.
+///
+/// The `sample-go-project` fixture is copied to a temp dir so tests can edit it.
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+/// Copy a fixture to a fresh temp directory so tests can edit files without
+/// touching the checked-in fixture tree.
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(format!(
+        "{}/tests/fixtures/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    ));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+/// Fingerprints of the violations found in `dir`, keyed by rule and message.
+fn fingerprints(dir: &Path) -> BTreeMap<(String, String), String> {
+    let output = boundary_cmd()
+        .args(["analyze", dir.to_str().unwrap(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+    fingerprints_in(&output.stdout)
+}
+
+/// Fingerprints from `analyze <path>` run in `cwd`, sorted.
+fn fingerprints_from(cwd: &Path, path: &str) -> Vec<String> {
+    let output = boundary_cmd()
+        .current_dir(cwd)
+        .args(["analyze", path, "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+    let mut fingerprints: Vec<String> = fingerprints_in(&output.stdout).into_values().collect();
+    fingerprints.sort();
+    fingerprints
+}
+
+fn fingerprints_in(stdout: &[u8]) -> BTreeMap<(String, String), String> {
+    let stdout = String::from_utf8_lossy(stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .map(|v| {
+            let key = (
+                v["rule"].as_str().unwrap().to_string(),
+                v["message"].as_str().unwrap().to_string(),
+            );
+            let fingerprint = v["fingerprint"]
+                .as_str()
+                .expect("every violation should carry a fingerprint")
+                .to_string();
+            (key, fingerprint)
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Fingerprints are deterministic
+// ----------------------------------------------------------------------------
+#[test]
+fn two_runs_produce_identical_fingerprints() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    let first = fingerprints(dir.path());
+    let second = fingerprints(dir.path());
+
+    assert!(!first.is_empty(), "fixture should have violations");
+    assert_eq!(first, second);
+}
+
+// ----------------------------------------------------------------------------
+// Fingerprints do not depend on the path the project was given as
+// ----------------------------------------------------------------------------
+#[test]
+fn fingerprints_ignore_invocation_path() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    let project = dir.path().join("p");
+    std::fs::create_dir(&project).unwrap();
+    for entry in std::fs::read_dir(dir.path()).unwrap() {
+        let entry = entry.unwrap();
+        if entry.path() != project {
+            std::fs::rename(entry.path(), project.join(entry.file_name())).unwrap();
+        }
+    }
+
+    let inside = fingerprints_from(&project, ".");
+    assert!(!inside.is_empty(), "fixture should have violations");
+    assert_eq!(fingerprints_from(dir.path(), "p"), inside);
+    assert_eq!(fingerprints_from(dir.path(), "./p"), inside);
+    assert_eq!(
+        fingerprints_from(dir.path(), project.to_str().unwrap()),
+        inside
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Unrelated edits keep fingerprints stable
+// ----------------------------------------------------------------------------
+#[test]
+fn unrelated_edit_keeps_fingerprint() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    let before = fingerprints(dir.path());
+
+    // Edit another file, and shift the violating import down a few lines.
+    let entity = dir.path().join("internal/domain/user/entity.go");
+    let content = std::fs::read_to_string(&entity).unwrap();
+    std::fs::write(&entity, format!("{content}\n// Audit fields follow.\n")).unwrap();
+    let bad = dir.path().join("internal/domain/user/bad_dependency.go");
+    let content = std::fs::read_to_string(&bad).unwrap();
+    std::fs::write(
+        &bad,
+        format!("// Legacy code.\n// Scheduled for removal.\n{content}"),
+    )
+    .unwrap();
+
+    let after = fingerprints(dir.path());
+    let l001 = |map: &BTreeMap<(String, String), String>| {
+        map.iter()
+            .find(|((rule, _), _)| rule == "L001")
+            .map(|(_, fp)| fp.clone())
+            .expect("expected an L001 violation")
+    };
+    assert_eq!(l001(&before), l001(&after));
+}
+
+#[test]
+fn fingerprints_are_distinct_per_violation() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    let fingerprints = fingerprints(dir.path());
+    let mut unique: Vec<_> = fingerprints.values().collect();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), fingerprints.len());
+}
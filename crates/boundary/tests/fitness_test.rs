@@ -0,0 +1,101 @@
+/// Acceptance tests for `[fitness]` assertions evaluated by `boundary check`.
+///
+/// The `interface-coverage-threshold` fixture passes `check` on its own: it has no
+/// errors, two PA003 warnings, 60% interface coverage and no circular dependencies.
+use std::process::{Command, Output};
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+fn check_with(fitness: &str) -> Output {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, &format!("[fitness]\n{fitness}"));
+    boundary_cmd()
+        .args([
+            "check",
+            &fixture("interface-coverage-threshold"),
+            "--config",
+            &config,
+        ])
+        .output()
+        .expect("failed to run boundary check")
+}
+
+// ----------------------------------------------------------------------------
+// Passing assertions leave check passing
+// ----------------------------------------------------------------------------
+#[test]
+fn passing_assertions_pass_check() {
+    let output = check_with(
+        "no_cycles = \"circular_dependency_count == 0\"\n\
+         coverage = \"interface_coverage >= 50 AND constructor_concrete_count <= 2\"\n",
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(output.status.code(), Some(0), "stderr: {stderr}");
+    assert!(!stderr.contains("Fitness function"), "got: {stderr}");
+}
+
+// ----------------------------------------------------------------------------
+// A failing assertion fails check and is named
+// ----------------------------------------------------------------------------
+#[test]
+fn failing_assertion_fails_check() {
+    let output = check_with(
+        "no_cycles = \"circular_dependency_count == 0\"\n\
+         ports = \"interface_coverage >= 80 OR warning_count == 0\"\n",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(
+            "Fitness function 'ports' failed: interface_coverage >= 80 OR warning_count == 0"
+        ),
+        "should name the failed assertion, got: {stderr}"
+    );
+    assert!(
+        stderr.contains("interface_coverage = 60, warning_count = 2"),
+        "should show the metric values, got: {stderr}"
+    );
+    assert!(
+        !stderr.contains("'no_cycles'"),
+        "passing assertions are not reported, got: {stderr}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Malformed assertions and unknown metrics are configuration errors
+// ----------------------------------------------------------------------------
+#[test]
+fn unknown_metric_is_an_error() {
+    let output = check_with("typo = \"overal >= 70\"\n");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("assertion 'typo'"), "got: {stderr}");
+    assert!(stderr.contains("unknown metric 'overal'"), "got: {stderr}");
+}
+
+#[test]
+fn malformed_assertion_is_an_error() {
+    let output = check_with("broken = \"overall >=\"\n");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("invalid [fitness] assertion 'broken'"),
+        "got: {stderr}"
+    );
+}
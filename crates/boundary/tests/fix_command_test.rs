@@ -0,0 +1,136 @@
+/// Acceptance tests for `boundary fix`.
+///
+/// Uses the `missing-port-fix` fixture, where `UserRepository` has exported
+/// methods to build a port from and `AuditRepository` has none.
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+fn run_fix(root: &Path, extra: &[&str]) -> Output {
+    let output = boundary_cmd()
+        .arg("fix")
+        .arg(root)
+        .args(extra)
+        .output()
+        .expect("failed to run boundary fix");
+    assert!(
+        output.status.success(),
+        "fix failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+const REPOSITORY: &str = "internal/infrastructure/postgres/user_repository.go";
+
+// ----------------------------------------------------------------------------
+// --dry-run prints an insertion diff and leaves files untouched
+// ----------------------------------------------------------------------------
+#[test]
+fn dry_run_prints_insertion_diff_without_writing() {
+    let dir = copy_fixture_to_tempdir("missing-port-fix");
+    let file = dir.path().join(REPOSITORY);
+    let original = std::fs::read_to_string(&file).unwrap();
+
+    let output = run_fix(dir.path(), &["--dry-run"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("+++ b/"), "expected a diff, got: {stdout}");
+    assert!(stdout.contains("user_repository.go"));
+    assert!(
+        stdout.contains("@@ -9,0 +10,"),
+        "stub should be inserted above line 10, got: {stdout}"
+    );
+    assert!(stdout.contains("+type UserRepositoryPort interface {"));
+    assert!(
+        stdout
+            .lines()
+            .all(|l| !l.starts_with('-') || l.starts_with("---")),
+        "fixes must only insert lines, got: {stdout}"
+    );
+    assert!(!stdout.contains("AuditRepositoryPort"));
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), original);
+}
+
+// ----------------------------------------------------------------------------
+// Applying inserts the stub above the adapter, keeps existing code, and is idempotent
+// ----------------------------------------------------------------------------
+#[test]
+fn fix_inserts_port_stub_once() {
+    let dir = copy_fixture_to_tempdir("missing-port-fix");
+    let file = dir.path().join(REPOSITORY);
+    let original = std::fs::read_to_string(&file).unwrap();
+
+    let output = run_fix(dir.path(), &[]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Applied 1 fix(es) to 1 file(s)."),
+        "got: {stdout}"
+    );
+
+    let fixed = std::fs::read_to_string(&file).unwrap();
+    let start = fixed
+        .find("type UserRepositoryPort interface {")
+        .expect("stub should be written");
+    let end = start + fixed[start..].find("}\n\n").unwrap() + 3;
+    assert!(end <= fixed.find("type UserRepository struct").unwrap());
+    let mut without_stub = fixed.clone();
+    without_stub.replace_range(start..end, "");
+    assert_eq!(without_stub, original, "existing code must be kept as is");
+
+    let output = run_fix(dir.path(), &[]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("No machine-applicable fixes found."),
+        "got: {stdout}"
+    );
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), fixed);
+}
+
+// ----------------------------------------------------------------------------
+// --kind limits fixes to one rule
+// ----------------------------------------------------------------------------
+#[test]
+fn kind_filters_fixes() {
+    let dir = copy_fixture_to_tempdir("missing-port-fix");
+
+    let output = run_fix(dir.path(), &["--dry-run", "--kind", "L001"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("No machine-applicable fixes found."),
+        "got: {stdout}"
+    );
+
+    let output = run_fix(
+        dir.path(),
+        &["--dry-run", "--kind", "missing-port-interface"],
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("+type UserRepositoryPort interface {"),
+        "got: {stdout}"
+    );
+}
@@ -0,0 +1,78 @@
+/// Acceptance tests for machine-applicable fixes in JSON output.
+///
+/// Verifies that PA001 violations carry a `fix` with a generated port
+/// interface when the adapter's methods are known, and omit it otherwise.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn missing_port_violations() -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture("missing-port-fix"), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA001"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// PA001 fix contains a port interface built from the adapter's methods
+// ----------------------------------------------------------------------------
+#[test]
+fn missing_port_fix_includes_adapter_methods() {
+    let violations = missing_port_violations();
+    let v = violations
+        .iter()
+        .find(|v| v["kind"]["MissingPort"]["adapter_name"] == "UserRepository")
+        .expect("UserRepository should have a PA001 violation");
+
+    let fix = &v["fix"];
+    assert!(fix.is_object(), "PA001 should include a fix, got: {v}");
+    assert!(
+        fix["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("user_repository.go"),
+        "fix should target the adapter's file, got: {fix}"
+    );
+    assert_eq!(fix["location"]["line"], 10);
+
+    let text = fix["insert_text"].as_str().expect("insert_text string");
+    assert!(text.contains("type UserRepositoryPort interface"));
+    assert!(text.contains("Save(ctx context.Context, u *user.User) error"));
+    assert!(text.contains("FindByID(ctx context.Context, id string)"));
+    assert!(
+        !text.contains("scanRow"),
+        "unexported methods should not be part of the port: {text}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// No fix when the adapter has no methods to derive a port from
+// ----------------------------------------------------------------------------
+#[test]
+fn missing_port_without_methods_has_no_fix() {
+    let violations = missing_port_violations();
+    let v = violations
+        .iter()
+        .find(|v| v["kind"]["MissingPort"]["adapter_name"] == "AuditRepository")
+        .expect("AuditRepository should have a PA001 violation");
+
+    assert!(v.get("fix").is_none(), "expected no fix, got: {v}");
+}
@@ -0,0 +1,20 @@
+package cart
+
+import "context"
+
+// Cart is a shopping cart.
+type Cart struct {
+	ID    string
+	Items []string
+}
+
+// AddItem puts an item in the cart.
+func (c *Cart) AddItem(sku string) {
+	c.Items = append(c.Items, sku)
+}
+
+// CartRepository is the persistence port for carts.
+type CartRepository interface {
+	Save(ctx context.Context, c *Cart) error
+	FindByID(ctx context.Context, id string) (*Cart, error)
+}
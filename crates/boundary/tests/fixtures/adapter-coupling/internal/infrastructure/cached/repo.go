@@ -0,0 +1,24 @@
+package cached
+
+import (
+	"context"
+
+	"example.com/shop/internal/domain/cart"
+)
+
+// CachedCartRepository decorates any CartRepository through the domain port.
+type CachedCartRepository struct {
+	inner cart.CartRepository
+}
+
+func NewCachedCartRepository(inner cart.CartRepository) cart.CartRepository {
+	return &CachedCartRepository{inner: inner}
+}
+
+func (r *CachedCartRepository) Save(ctx context.Context, c *cart.Cart) error {
+	return r.inner.Save(ctx, c)
+}
+
+func (r *CachedCartRepository) FindByID(ctx context.Context, id string) (*cart.Cart, error) {
+	return r.inner.FindByID(ctx, id)
+}
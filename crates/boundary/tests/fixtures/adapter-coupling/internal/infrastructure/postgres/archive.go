@@ -0,0 +1,22 @@
+package postgres
+
+import (
+	"context"
+
+	"example.com/shop/internal/domain/cart"
+)
+
+// ArchiveRepository shares the postgres package with PostgresRepo but never touches Redis.
+type ArchiveRepository struct{}
+
+func NewArchiveRepository() cart.CartRepository {
+	return &ArchiveRepository{}
+}
+
+func (r *ArchiveRepository) Save(ctx context.Context, c *cart.Cart) error {
+	return nil
+}
+
+func (r *ArchiveRepository) FindByID(ctx context.Context, id string) (*cart.Cart, error) {
+	return nil, nil
+}
@@ -0,0 +1,28 @@
+package postgres
+
+import (
+	"context"
+
+	"example.com/shop/internal/domain/cart"
+	"example.com/shop/internal/infrastructure/redis"
+)
+
+// PostgresRepo stores carts in Postgres and reaches straight into the Redis adapter.
+type PostgresRepo struct {
+	cache *redis.RedisCache
+}
+
+func NewPostgresRepo(cache *redis.RedisCache) cart.CartRepository {
+	return &PostgresRepo{cache: cache}
+}
+
+func (r *PostgresRepo) Save(ctx context.Context, c *cart.Cart) error {
+	return nil
+}
+
+func (r *PostgresRepo) FindByID(ctx context.Context, id string) (*cart.Cart, error) {
+	if _, ok := r.cache.Get(ctx, id); ok {
+		return &cart.Cart{ID: id}, nil
+	}
+	return nil, nil
+}
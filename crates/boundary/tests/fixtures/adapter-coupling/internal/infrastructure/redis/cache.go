@@ -0,0 +1,18 @@
+package redis
+
+import "context"
+
+// RedisCache caches serialized values in Redis.
+type RedisCache struct {
+	addr string
+}
+
+func NewRedisCache(addr string) *RedisCache {
+	return &RedisCache{addr: addr}
+}
+
+func (c *RedisCache) Get(ctx context.Context, key string) ([]byte, bool) {
+	return nil, false
+}
+
+func (c *RedisCache) Set(ctx context.Context, key string, value []byte) {}
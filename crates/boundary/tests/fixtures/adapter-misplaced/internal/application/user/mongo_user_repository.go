@@ -0,0 +1,20 @@
+package user
+
+import (
+	"context"
+
+	"github.com/example/app/internal/domain/user"
+)
+
+// MongoUserRepository is a concrete Mongo implementation that belongs in infrastructure.
+type MongoUserRepository struct {
+	collection string
+}
+
+func (r *MongoUserRepository) Save(ctx context.Context, u *user.User) error {
+	return nil
+}
+
+func (r *MongoUserRepository) FindByID(ctx context.Context, id string) (*user.User, error) {
+	return nil, nil
+}
@@ -0,0 +1,14 @@
+package user
+
+import "context"
+
+type User struct {
+	ID    string
+	Email string
+}
+
+// UserRepository is the persistence port for users.
+type UserRepository interface {
+	Save(ctx context.Context, u *User) error
+	FindByID(ctx context.Context, id string) (*User, error)
+}
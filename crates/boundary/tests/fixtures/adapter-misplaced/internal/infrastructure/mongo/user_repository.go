@@ -0,0 +1,23 @@
+package mongo
+
+import (
+	"context"
+
+	"github.com/example/app/internal/domain/user"
+)
+
+type MongoUserRepository struct {
+	collection string
+}
+
+func NewMongoUserRepository(collection string) user.UserRepository {
+	return &MongoUserRepository{collection: collection}
+}
+
+func (r *MongoUserRepository) Save(ctx context.Context, u *user.User) error {
+	return nil
+}
+
+func (r *MongoUserRepository) FindByID(ctx context.Context, id string) (*user.User, error) {
+	return nil, nil
+}
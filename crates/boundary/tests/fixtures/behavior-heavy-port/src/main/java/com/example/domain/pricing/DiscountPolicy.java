@@ -0,0 +1,20 @@
+package com.example.domain.pricing;
+
+import java.math.BigDecimal;
+
+/** A "port" that mostly implements the pricing rules itself. */
+public interface DiscountPolicy {
+    BigDecimal rateFor(String customerId);
+
+    default BigDecimal apply(String customerId, BigDecimal amount) {
+        return amount.subtract(amount.multiply(rateFor(customerId)));
+    }
+
+    default BigDecimal applyAll(String customerId, BigDecimal... amounts) {
+        BigDecimal total = BigDecimal.ZERO;
+        for (BigDecimal amount : amounts) {
+            total = total.add(apply(customerId, amount));
+        }
+        return total;
+    }
+}
@@ -0,0 +1,10 @@
+package com.example.domain.pricing;
+
+import java.math.BigDecimal;
+
+/** A pure contract. */
+public interface PriceRepository {
+    BigDecimal findPrice(String sku);
+
+    void savePrice(String sku, BigDecimal price);
+}
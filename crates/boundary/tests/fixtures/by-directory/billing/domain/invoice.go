@@ -0,0 +1,12 @@
+package domain
+
+// Invoice is a domain entity.
+type Invoice struct {
+	ID     string
+	Amount int64
+}
+
+// InvoiceRepository is the port for invoice persistence.
+type InvoiceRepository interface {
+	Save(invoice *Invoice) error
+}
@@ -0,0 +1,14 @@
+package infrastructure
+
+import "example.com/app/billing/domain"
+
+// PostgresInvoiceRepository implements domain.InvoiceRepository.
+type PostgresInvoiceRepository struct{}
+
+func NewPostgresInvoiceRepository() domain.InvoiceRepository {
+	return &PostgresInvoiceRepository{}
+}
+
+func (r *PostgresInvoiceRepository) Save(invoice *domain.Invoice) error {
+	return nil
+}
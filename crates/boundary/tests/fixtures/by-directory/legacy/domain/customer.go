@@ -0,0 +1,9 @@
+package domain
+
+import "example.com/app/legacy/infrastructure"
+
+// Customer reaches straight into the database layer.
+type Customer struct {
+	ID string
+	DB *infrastructure.Database
+}
@@ -0,0 +1,4 @@
+package infrastructure
+
+// Database is a concrete database handle.
+type Database struct{}
@@ -0,0 +1,11 @@
+package domain
+
+// Order is a domain entity.
+type Order struct {
+	ID string
+}
+
+// OrderRepository is the port for order persistence.
+type OrderRepository interface {
+	Save(order *Order) error
+}
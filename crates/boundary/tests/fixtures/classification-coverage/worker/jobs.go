@@ -0,0 +1,11 @@
+package worker
+
+// Job is queued background work.
+type Job struct {
+	Name string
+}
+
+// Scheduler runs jobs on an interval.
+type Scheduler struct {
+	Jobs []Job
+}
@@ -0,0 +1,10 @@
+package payments
+
+// PaymentGateway talks to the card processor.
+type PaymentGateway struct {
+	apiKey string
+}
+
+func (g *PaymentGateway) Charge(amount int) error {
+	return nil
+}
@@ -0,0 +1,16 @@
+package com.example.application;
+
+import com.example.domain.order.Money;
+import com.example.domain.order.OrderRepository;
+
+public class OrderService {
+    private final OrderRepository orders;
+
+    public OrderService(OrderRepository orders) {
+        this.orders = orders;
+    }
+
+    public void place(String orderId, long cents) {
+        orders.save(orderId, new Money(cents));
+    }
+}
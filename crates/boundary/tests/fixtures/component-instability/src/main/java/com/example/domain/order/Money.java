@@ -0,0 +1,13 @@
+package com.example.domain.order;
+
+public class Money {
+    private final long cents;
+
+    public Money(long cents) {
+        this.cents = cents;
+    }
+
+    public Money add(Money other) {
+        return new Money(cents + other.cents);
+    }
+}
@@ -0,0 +1,5 @@
+package com.example.domain.order;
+
+public interface OrderRepository {
+    void save(String orderId, Money total);
+}
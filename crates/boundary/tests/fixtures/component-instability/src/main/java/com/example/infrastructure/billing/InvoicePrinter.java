@@ -0,0 +1,9 @@
+package com.example.infrastructure.billing;
+
+import com.example.domain.order.Money;
+
+public class InvoicePrinter {
+    public String print(Money total) {
+        return total.toString();
+    }
+}
@@ -0,0 +1,9 @@
+package com.example.infrastructure.jpa;
+
+import com.example.domain.order.Money;
+import com.example.domain.order.OrderRepository;
+
+public class JpaOrderRepository implements OrderRepository {
+    public void save(String orderId, Money total) {
+    }
+}
@@ -0,0 +1,16 @@
+package com.example.presentation;
+
+import com.example.application.OrderService;
+import com.example.domain.order.Money;
+
+public class OrderController {
+    private final OrderService service;
+
+    public OrderController(OrderService service) {
+        this.service = service;
+    }
+
+    public Money preview(long cents) {
+        return new Money(cents);
+    }
+}
@@ -0,0 +1,15 @@
+package infrastructure
+
+import "github.com/example/app/services/billing/domain"
+
+type PostgresInvoiceRepository struct {
+	DSN string
+}
+
+func NewPostgresInvoiceRepository(dsn string) domain.InvoiceRepository {
+	return &PostgresInvoiceRepository{DSN: dsn}
+}
+
+func (r *PostgresInvoiceRepository) Save(invoice *domain.Invoice) error {
+	return nil
+}
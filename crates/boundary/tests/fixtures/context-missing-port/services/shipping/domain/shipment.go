@@ -0,0 +1,6 @@
+package domain
+
+type Shipment struct {
+	ID      string
+	Carrier string
+}
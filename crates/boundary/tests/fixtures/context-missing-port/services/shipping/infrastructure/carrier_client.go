@@ -0,0 +1,13 @@
+package infrastructure
+
+type CarrierClient struct {
+	BaseURL string
+}
+
+func NewCarrierClient(baseURL string) *CarrierClient {
+	return &CarrierClient{BaseURL: baseURL}
+}
+
+func (c *CarrierClient) Dispatch(shipmentID string) error {
+	return nil
+}
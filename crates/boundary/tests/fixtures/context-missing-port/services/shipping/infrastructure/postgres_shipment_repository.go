@@ -0,0 +1,15 @@
+package infrastructure
+
+import "github.com/example/app/services/shipping/domain"
+
+type PostgresShipmentRepository struct {
+	DSN string
+}
+
+func NewPostgresShipmentRepository(dsn string) *PostgresShipmentRepository {
+	return &PostgresShipmentRepository{DSN: dsn}
+}
+
+func (r *PostgresShipmentRepository) Save(shipment *domain.Shipment) error {
+	return nil
+}
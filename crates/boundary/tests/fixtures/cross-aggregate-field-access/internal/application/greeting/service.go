@@ -0,0 +1,11 @@
+package greeting
+
+import "github.com/example/app/internal/domain/user"
+
+func Greet(u *user.User) string {
+	return "Hello " + u.Name
+}
+
+func Farewell(u *user.User) string {
+	return "Goodbye " + u.DisplayName()
+}
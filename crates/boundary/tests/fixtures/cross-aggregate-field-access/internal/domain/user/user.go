@@ -0,0 +1,10 @@
+package user
+
+type User struct {
+	ID   string
+	Name string
+}
+
+func (u *User) DisplayName() string {
+	return u.Name
+}
@@ -0,0 +1,8 @@
+package catalog
+
+import "github.com/example/app/internal/domain/pricing"
+
+type Product struct {
+	SKU   string
+	Price pricing.Price
+}
@@ -0,0 +1,9 @@
+package order
+
+import "github.com/example/app/internal/infrastructure/postgres"
+
+// Order persists itself through postgres, which imports order back.
+type Order struct {
+	ID    string
+	store *postgres.Store
+}
@@ -0,0 +1,8 @@
+package pricing
+
+import "github.com/example/app/internal/domain/catalog"
+
+type Price struct {
+	Amount  int64
+	Product *catalog.Product
+}
@@ -0,0 +1,11 @@
+package postgres
+
+import "github.com/example/app/internal/domain/order"
+
+type Store struct {
+	DSN string
+}
+
+func (s *Store) Save(o *order.Order) error {
+	return nil
+}
@@ -0,0 +1,12 @@
+package account
+
+type Account struct {
+	ID     string
+	UserID string
+}
+
+// UserRepository was copied from the user package.
+type UserRepository interface {
+	Save(u *Account) error
+	FindByID(id string) (*Account, error)
+}
@@ -0,0 +1,11 @@
+package billing
+
+type Invoice struct {
+	ID string
+}
+
+// InvoiceRepository shares method names with UserRepository but is a different port.
+type InvoiceRepository interface {
+	Save(i *Invoice) error
+	FindByID(id string) (*Invoice, error)
+}
@@ -0,0 +1,20 @@
+package checkout
+
+import (
+	"github.com/example/shop/domain/cart"
+	"github.com/example/shop/domain/customer"
+	"github.com/example/shop/domain/inventory"
+	"github.com/example/shop/domain/payment"
+	"github.com/example/shop/domain/pricing"
+	"github.com/example/shop/domain/shipping"
+)
+
+// CheckoutService coordinates six domain modules.
+type CheckoutService struct {
+	Cart      *cart.Cart
+	Customer  *customer.Customer
+	Inventory *inventory.Inventory
+	Payment   *payment.Payment
+	Pricing   *pricing.Pricing
+	Shipping  *shipping.Shipping
+}
@@ -0,0 +1,5 @@
+package cart
+
+type Cart struct {
+	ID string
+}
@@ -0,0 +1,5 @@
+package customer
+
+type Customer struct {
+	ID string
+}
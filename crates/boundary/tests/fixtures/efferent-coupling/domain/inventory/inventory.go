@@ -0,0 +1,5 @@
+package inventory
+
+type Inventory struct {
+	ID string
+}
@@ -0,0 +1,5 @@
+package payment
+
+type Payment struct {
+	ID string
+}
@@ -0,0 +1,5 @@
+package pricing
+
+type Pricing struct {
+	ID string
+}
@@ -0,0 +1,5 @@
+package shipping
+
+type Shipping struct {
+	ID string
+}
@@ -0,0 +1,13 @@
+package order
+
+import "github.com/gin-gonic/gin"
+
+type Order struct {
+	ID    string
+	Total int
+}
+
+// Bind reads an order from an HTTP request, tying the domain to gin.
+func (o *Order) Bind(c *gin.Context) error {
+	return c.BindJSON(o)
+}
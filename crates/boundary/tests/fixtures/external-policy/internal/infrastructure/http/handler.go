@@ -0,0 +1,13 @@
+package http
+
+import (
+	"github.com/example/app/internal/domain/order"
+	"github.com/gin-gonic/gin"
+)
+
+type OrderHandler struct{}
+
+func (h *OrderHandler) Create(c *gin.Context) {
+	var o order.Order
+	_ = o.Bind(c)
+}
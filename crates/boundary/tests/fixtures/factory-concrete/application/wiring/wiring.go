@@ -0,0 +1,16 @@
+package wiring
+
+import (
+	"github.com/example/app/domain/user"
+	"github.com/example/app/infrastructure/postgres"
+)
+
+// NewUserStore returns the port, so callers never see the adapter.
+func NewUserStore(dsn string) user.UserRepository {
+	return postgres.NewUserRepo(dsn)
+}
+
+// NewPostgresStore leaks the adapter across the layer boundary.
+func NewPostgresStore(dsn string) (*postgres.PostgresUserRepository, error) {
+	return postgres.NewUserRepo(dsn), nil
+}
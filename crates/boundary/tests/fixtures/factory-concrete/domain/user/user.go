@@ -0,0 +1,11 @@
+package user
+
+type User struct {
+	ID    string
+	Email string
+}
+
+type UserRepository interface {
+	Save(u *User) error
+	FindByID(id string) (*User, error)
+}
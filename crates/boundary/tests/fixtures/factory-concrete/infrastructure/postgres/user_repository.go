@@ -0,0 +1,20 @@
+package postgres
+
+import "github.com/example/app/domain/user"
+
+type PostgresUserRepository struct {
+	DSN string
+}
+
+// NewUserRepo hands out the adapter itself instead of the UserRepository port.
+func NewUserRepo(dsn string) *PostgresUserRepository {
+	return &PostgresUserRepository{DSN: dsn}
+}
+
+func (r *PostgresUserRepository) Save(u *user.User) error {
+	return nil
+}
+
+func (r *PostgresUserRepository) FindByID(id string) (*user.User, error) {
+	return nil, nil
+}
@@ -0,0 +1,29 @@
+package catalog
+
+import "context"
+
+type Product struct {
+	ID    string
+	Price int
+}
+
+// CatalogStore mixes reads, writes, pricing and reporting.
+type CatalogStore interface {
+	Save(ctx context.Context, p *Product) error
+	Delete(ctx context.Context, id string) error
+	FindByID(ctx context.Context, id string) (*Product, error)
+	FindAll(ctx context.Context) ([]*Product, error)
+	FindByCategory(ctx context.Context, category string) ([]*Product, error)
+	UpdatePrice(ctx context.Context, id string, price int) error
+	ApplyDiscount(ctx context.Context, id string, percent int) error
+	CountByCategory(ctx context.Context, category string) (int, error)
+	ExportCSV(ctx context.Context) ([]byte, error)
+	Reindex(ctx context.Context) error
+}
+
+// ProductReader is narrow and focused.
+type ProductReader interface {
+	FindByID(ctx context.Context, id string) (*Product, error)
+	FindAll(ctx context.Context) ([]*Product, error)
+	FindByCategory(ctx context.Context, category string) ([]*Product, error)
+}
@@ -0,0 +1,16 @@
+package com.example.domain.catalog;
+
+import java.util.List;
+
+public interface CatalogGateway {
+    void save(Product product);
+    void delete(String id);
+    Product findById(String id);
+    List<Product> findAll();
+    List<Product> findByCategory(String category);
+    void updatePrice(String id, int price);
+    void applyDiscount(String id, int percent);
+    int countByCategory(String category);
+    byte[] exportCsv();
+    void reindex();
+}
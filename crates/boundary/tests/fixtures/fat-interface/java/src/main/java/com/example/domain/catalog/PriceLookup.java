@@ -0,0 +1,7 @@
+package com.example.domain.catalog;
+
+public interface PriceLookup {
+    int priceOf(String id);
+    int discountedPriceOf(String id, int percent);
+    String currency();
+}
@@ -0,0 +1,14 @@
+package com.example.domain.catalog;
+
+public class Product {
+    private String id;
+    private int price;
+
+    public String getId() {
+        return id;
+    }
+
+    public int getPrice() {
+        return price;
+    }
+}
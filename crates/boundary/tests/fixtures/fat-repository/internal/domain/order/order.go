@@ -0,0 +1,10 @@
+package order
+
+type Order struct {
+	ID    string
+	Total int
+}
+
+func (o *Order) AddItem(price int) {
+	o.Total += price
+}
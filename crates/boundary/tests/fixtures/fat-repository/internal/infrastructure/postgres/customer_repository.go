@@ -0,0 +1,23 @@
+package postgres
+
+import (
+	"context"
+	"database/sql"
+)
+
+// CustomerRepository only performs persistence operations.
+type CustomerRepository struct {
+	db *sql.DB
+}
+
+func (r *CustomerRepository) Save(ctx context.Context, name string) error {
+	return nil
+}
+
+func (r *CustomerRepository) FindByEmail(ctx context.Context, email string) (string, error) {
+	return "", nil
+}
+
+func (r *CustomerRepository) Delete(ctx context.Context, id string) error {
+	return nil
+}
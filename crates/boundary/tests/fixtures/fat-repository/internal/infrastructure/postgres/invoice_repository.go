@@ -0,0 +1,44 @@
+package postgres
+
+import (
+	"context"
+	"database/sql"
+)
+
+// InvoiceRepository exposes only CRUD methods; its unexported helpers are not
+// part of the repository's surface.
+type InvoiceRepository struct {
+	db *sql.DB
+}
+
+func (r *InvoiceRepository) Save(ctx context.Context, id string) error {
+	return r.exec(ctx, buildInsert(id))
+}
+
+func (r *InvoiceRepository) FindByID(ctx context.Context, id string) (string, error) {
+	return r.scanRow(ctx, id)
+}
+
+func (r *InvoiceRepository) List(ctx context.Context) ([]string, error) {
+	return nil, nil
+}
+
+func (r *InvoiceRepository) Update(ctx context.Context, id string) error {
+	return nil
+}
+
+func (r *InvoiceRepository) Delete(ctx context.Context, id string) error {
+	return nil
+}
+
+func (r *InvoiceRepository) exec(ctx context.Context, query string) error {
+	return nil
+}
+
+func (r *InvoiceRepository) scanRow(ctx context.Context, id string) (string, error) {
+	return "", nil
+}
+
+func buildInsert(id string) string {
+	return "INSERT INTO invoices VALUES ($1)"
+}
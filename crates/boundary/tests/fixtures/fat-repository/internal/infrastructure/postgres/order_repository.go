@@ -0,0 +1,25 @@
+package postgres
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/example/app/internal/domain/order"
+)
+
+// OrderRepository mixes persistence with a pricing rule.
+type OrderRepository struct {
+	db *sql.DB
+}
+
+func (r *OrderRepository) Save(ctx context.Context, o *order.Order) error {
+	return nil
+}
+
+func (r *OrderRepository) FindByID(ctx context.Context, id string) (*order.Order, error) {
+	return nil, nil
+}
+
+func (r *OrderRepository) CalculateDiscount(o *order.Order) int {
+	return o.Total / 10
+}
@@ -0,0 +1,7 @@
+package order
+
+// Order is an aggregate root.
+type Order struct {
+	ID    string
+	Total int64
+}
@@ -0,0 +1,17 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// source: order.proto
+
+package order
+
+import (
+	"github.com/example/shop/internal/infrastructure/grpc"
+)
+
+// OrderServiceClient is generated from the OrderService definition.
+type OrderServiceClient struct {
+	conn *grpc.ClientConn
+}
+
+func NewOrderServiceClient(conn *grpc.ClientConn) *OrderServiceClient {
+	return &OrderServiceClient{conn: conn}
+}
@@ -0,0 +1,6 @@
+package grpc
+
+// ClientConn is a connection to a remote service.
+type ClientConn struct {
+	Target string
+}
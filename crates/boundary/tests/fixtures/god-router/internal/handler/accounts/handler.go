@@ -0,0 +1,7 @@
+package accounts
+
+// Handler serves accounts endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
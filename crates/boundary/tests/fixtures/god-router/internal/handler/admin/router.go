@@ -0,0 +1,21 @@
+package admin
+
+import (
+	"example.com/shop/internal/handler/accounts"
+	"example.com/shop/internal/handler/coupons"
+	"example.com/shop/internal/handler/returns"
+)
+
+// Router wires the back-office handlers.
+type Router struct {
+	Accounts *accounts.Handler
+	Coupons  *coupons.Handler
+	Returns  *returns.Handler
+}
+
+// Mount registers the admin routes.
+func (r *Router) Mount() {
+	r.Accounts.Register()
+	r.Coupons.Register()
+	r.Returns.Register()
+}
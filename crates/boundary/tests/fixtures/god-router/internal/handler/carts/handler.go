@@ -0,0 +1,7 @@
+package carts
+
+// Handler serves carts endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package coupons
+
+// Handler serves coupons endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package invoices
+
+// Handler serves invoices endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package orders
+
+// Handler serves orders endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
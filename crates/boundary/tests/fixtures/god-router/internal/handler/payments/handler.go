@@ -0,0 +1,7 @@
+package payments
+
+// Handler serves payments endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package products
+
+// Handler serves products endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package returns
+
+// Handler serves returns endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package reviews
+
+// Handler serves reviews endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
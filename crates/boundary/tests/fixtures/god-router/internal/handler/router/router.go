@@ -0,0 +1,48 @@
+package router
+
+import (
+	"example.com/shop/internal/handler/users"
+	"example.com/shop/internal/handler/orders"
+	"example.com/shop/internal/handler/carts"
+	"example.com/shop/internal/handler/payments"
+	"example.com/shop/internal/handler/invoices"
+	"example.com/shop/internal/handler/shipments"
+	"example.com/shop/internal/handler/products"
+	"example.com/shop/internal/handler/reviews"
+	"example.com/shop/internal/handler/coupons"
+	"example.com/shop/internal/handler/wishlists"
+	"example.com/shop/internal/handler/returns"
+	"example.com/shop/internal/handler/accounts"
+)
+
+// Router wires every HTTP handler.
+type Router struct {
+	Users *users.Handler
+	Orders *orders.Handler
+	Carts *carts.Handler
+	Payments *payments.Handler
+	Invoices *invoices.Handler
+	Shipments *shipments.Handler
+	Products *products.Handler
+	Reviews *reviews.Handler
+	Coupons *coupons.Handler
+	Wishlists *wishlists.Handler
+	Returns *returns.Handler
+	Accounts *accounts.Handler
+}
+
+// Mount registers all routes.
+func (r *Router) Mount() {
+	r.Users.Register()
+	r.Orders.Register()
+	r.Carts.Register()
+	r.Payments.Register()
+	r.Invoices.Register()
+	r.Shipments.Register()
+	r.Products.Register()
+	r.Reviews.Register()
+	r.Coupons.Register()
+	r.Wishlists.Register()
+	r.Returns.Register()
+	r.Accounts.Register()
+}
@@ -0,0 +1,7 @@
+package shipments
+
+// Handler serves shipments endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
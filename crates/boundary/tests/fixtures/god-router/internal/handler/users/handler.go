@@ -0,0 +1,7 @@
+package users
+
+// Handler serves users endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
@@ -0,0 +1,7 @@
+package wishlists
+
+// Handler serves wishlists endpoints.
+type Handler struct{}
+
+// Register mounts the routes.
+func (h *Handler) Register() {}
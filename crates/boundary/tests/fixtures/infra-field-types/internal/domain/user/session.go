@@ -0,0 +1,9 @@
+package user
+
+import "database/sql"
+
+// Session keeps its database handle unexported, so it is not exposed.
+type Session struct {
+	ID string
+	db *sql.DB
+}
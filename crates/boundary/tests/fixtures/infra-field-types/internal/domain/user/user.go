@@ -0,0 +1,17 @@
+package user
+
+import "database/sql"
+
+// User carries a database handle, leaking infrastructure into the domain.
+type User struct {
+	ID    string
+	Email string
+	DB    *sql.DB
+}
+
+// Account holds only primitive fields.
+type Account struct {
+	ID      string
+	Balance int64
+	Active  bool
+}
@@ -0,0 +1,17 @@
+package order
+
+import (
+	"context"
+	"database/sql"
+)
+
+type Order struct {
+	ID    string
+	Total int
+}
+
+// OrderRepository leaks the SQL transaction into the domain contract.
+type OrderRepository interface {
+	Save(tx *sql.Tx, order *Order) error
+	FindByID(ctx context.Context, id string) (*Order, error)
+}
@@ -0,0 +1,25 @@
+package postgres
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/example/app/internal/domain/order"
+)
+
+type OrderRepository struct {
+	db *sql.DB
+}
+
+func NewOrderRepository(db *sql.DB) order.OrderRepository {
+	return &OrderRepository{db: db}
+}
+
+func (r *OrderRepository) Save(tx *sql.Tx, o *order.Order) error {
+	_, err := tx.Exec("INSERT INTO orders (id, total) VALUES ($1, $2)", o.ID, o.Total)
+	return err
+}
+
+func (r *OrderRepository) FindByID(ctx context.Context, id string) (*order.Order, error) {
+	return nil, nil
+}
@@ -0,0 +1,26 @@
+package domain
+
+// UserRepository is port #1.
+type UserRepository interface {
+	FindByID(id string) (*User, error)
+}
+
+// OrderRepository is port #2.
+type OrderRepository interface {
+	FindByID(id string) (*Order, error)
+}
+
+// PaymentGateway is port #3.
+type PaymentGateway interface {
+	Charge(orderID string, cents int64) error
+}
+
+// User is a domain entity.
+type User struct {
+	ID string
+}
+
+// Order is a domain entity.
+type Order struct {
+	ID string
+}
@@ -0,0 +1,23 @@
+package infrastructure
+
+import (
+	"github.com/example/interface-coverage-threshold/domain"
+)
+
+// PostgresUserRepository implements domain.UserRepository.
+type PostgresUserRepository struct {
+	db interface{}
+}
+
+func (r *PostgresUserRepository) FindByID(id string) (*domain.User, error) {
+	return nil, nil
+}
+
+// PostgresOrderRepository implements domain.OrderRepository.
+type PostgresOrderRepository struct {
+	db interface{}
+}
+
+func (r *PostgresOrderRepository) FindByID(id string) (*domain.Order, error) {
+	return nil, nil
+}
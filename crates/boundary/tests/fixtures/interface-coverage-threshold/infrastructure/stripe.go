@@ -0,0 +1,18 @@
+package infrastructure
+
+import (
+	"github.com/example/interface-coverage-threshold/domain"
+)
+
+// StripePaymentGateway implements domain.PaymentGateway.
+type StripePaymentGateway struct {
+	apiKey string
+}
+
+func NewStripePaymentGateway(apiKey string) domain.PaymentGateway {
+	return &StripePaymentGateway{apiKey: apiKey}
+}
+
+func (g *StripePaymentGateway) Charge(orderID string, cents int64) error {
+	return nil
+}
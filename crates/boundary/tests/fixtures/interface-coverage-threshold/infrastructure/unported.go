@@ -0,0 +1,27 @@
+package infrastructure
+
+// SmtpMailer has no port yet.
+type SmtpMailer struct {
+	host string
+}
+
+func NewSmtpMailer(host string) *SmtpMailer {
+	return &SmtpMailer{host: host}
+}
+
+func (m *SmtpMailer) Send(to, body string) error {
+	return nil
+}
+
+// RedisCache has no port yet.
+type RedisCache struct {
+	addr string
+}
+
+func NewRedisCache(addr string) *RedisCache {
+	return &RedisCache{addr: addr}
+}
+
+func (c *RedisCache) Get(key string) (string, error) {
+	return "", nil
+}
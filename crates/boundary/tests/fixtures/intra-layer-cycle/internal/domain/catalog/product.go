@@ -0,0 +1,9 @@
+package catalog
+
+import "github.com/example/app/internal/domain/order"
+
+// Product depends on order without order depending back, so it is not in a cycle.
+type Product struct {
+	SKU       string
+	LastOrder *order.Order
+}
@@ -0,0 +1,8 @@
+package customer
+
+import "github.com/example/app/internal/domain/order"
+
+type Customer struct {
+	ID     string
+	Orders []order.Order
+}
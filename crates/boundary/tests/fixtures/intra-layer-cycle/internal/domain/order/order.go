@@ -0,0 +1,8 @@
+package order
+
+import "github.com/example/app/internal/domain/customer"
+
+type Order struct {
+	ID       string
+	Customer customer.Customer
+}
@@ -0,0 +1,8 @@
+package common
+
+import "example.com/ledger/internal/infrastructure/postgres"
+
+// Clock has no layer annotation, so its path leaves it unclassified.
+type Clock struct {
+	DB *postgres.DB
+}
@@ -0,0 +1,15 @@
+// boundary:layer domain
+package shared
+
+import "example.com/ledger/internal/infrastructure/postgres"
+
+// Money is a domain value kept in a shared package.
+type Money struct {
+	Cents    int64
+	Currency string
+}
+
+// Persist reaches straight into the database layer.
+func (m Money) Persist(db *postgres.DB) error {
+	return db.Exec("INSERT INTO money VALUES (?)", m.Cents)
+}
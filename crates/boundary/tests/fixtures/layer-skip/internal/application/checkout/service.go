@@ -0,0 +1,9 @@
+package checkout
+
+import "github.com/example/shop/internal/domain/order"
+
+type CheckoutService struct{}
+
+func (s *CheckoutService) Place(o *order.Order) string {
+	return o.Label()
+}
@@ -0,0 +1,13 @@
+package order
+
+import "github.com/example/shop/internal/presentation/http"
+
+// Order formats itself with a presentation helper, skipping the application layer.
+type Order struct {
+	ID    string
+	Total int
+}
+
+func (o *Order) Label() string {
+	return http.FormatPrice(o.Total)
+}
@@ -0,0 +1,15 @@
+package http
+
+import (
+	"fmt"
+
+	"github.com/example/shop/internal/application/checkout"
+)
+
+type CheckoutHandler struct {
+	service *checkout.CheckoutService
+}
+
+func FormatPrice(cents int) string {
+	return fmt.Sprintf("$%d.%02d", cents/100, cents%100)
+}
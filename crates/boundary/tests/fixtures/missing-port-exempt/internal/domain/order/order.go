@@ -0,0 +1,10 @@
+package order
+
+type Order struct {
+	ID    string
+	Total int64
+}
+
+func (o *Order) Pay() {
+	o.Total = 0
+}
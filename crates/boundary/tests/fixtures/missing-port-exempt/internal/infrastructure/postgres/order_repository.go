@@ -0,0 +1,15 @@
+package postgres
+
+import (
+	"database/sql"
+
+	"github.com/example/app/internal/domain/order"
+)
+
+type OrderRepository struct {
+	db *sql.DB
+}
+
+func (r *OrderRepository) Save(o *order.Order) error {
+	return nil
+}
@@ -0,0 +1,26 @@
+package postgres
+
+import (
+	"context"
+	"database/sql"
+
+	"github.com/example/app/internal/domain/user"
+)
+
+type UserRepository struct {
+	db *sql.DB
+}
+
+func (r *UserRepository) Save(ctx context.Context, u *user.User) error {
+	return nil
+}
+
+func (r *UserRepository) FindByID(ctx context.Context, id string) (*user.User, error) {
+	return nil, nil
+}
+
+func (r *UserRepository) scanRow(row *sql.Row) (*user.User, error) {
+	return nil, nil
+}
+
+type AuditRepository struct{}
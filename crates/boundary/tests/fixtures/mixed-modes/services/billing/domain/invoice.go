@@ -0,0 +1,10 @@
+package domain
+
+type Invoice struct {
+	ID     string
+	Amount int
+}
+
+type InvoiceRepository interface {
+	Save(invoice *Invoice) error
+}
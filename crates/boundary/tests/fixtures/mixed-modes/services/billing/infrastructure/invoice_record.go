@@ -0,0 +1,10 @@
+package infrastructure
+
+type InvoiceRecord struct {
+	ID     string
+	Amount int
+}
+
+func (r *InvoiceRecord) Save() error {
+	return nil
+}
@@ -0,0 +1,10 @@
+package domain
+
+type Shipment struct {
+	ID      string
+	Carrier string
+}
+
+func (s *Shipment) Save() error {
+	return nil
+}
@@ -0,0 +1,5 @@
+package infrastructure
+
+type CarrierClient struct {
+	BaseURL string
+}
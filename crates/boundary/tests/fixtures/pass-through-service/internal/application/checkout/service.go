@@ -0,0 +1,22 @@
+package checkout
+
+import (
+	"context"
+
+	"github.com/example/app/internal/domain/order"
+	"github.com/example/app/internal/domain/payment"
+)
+
+// CheckoutService coordinates orders and payments.
+type CheckoutService struct {
+	orders   order.OrderRepository
+	payments payment.PaymentGateway
+}
+
+func (s *CheckoutService) Checkout(ctx context.Context, id string) error {
+	o, err := s.orders.FindByID(ctx, id)
+	if err != nil {
+		return err
+	}
+	return s.payments.Charge(ctx, o.ID, o.Total)
+}
@@ -0,0 +1,20 @@
+package orders
+
+import (
+	"context"
+
+	"github.com/example/app/internal/domain/order"
+)
+
+// OrderService only forwards to the repository.
+type OrderService struct {
+	repo order.OrderRepository
+}
+
+func (s *OrderService) Save(ctx context.Context, o *order.Order) error {
+	return s.repo.Save(ctx, o)
+}
+
+func (s *OrderService) FindByID(ctx context.Context, id string) (*order.Order, error) {
+	return s.repo.FindByID(ctx, id)
+}
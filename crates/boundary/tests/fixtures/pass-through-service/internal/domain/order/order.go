@@ -0,0 +1,18 @@
+package order
+
+import "context"
+
+type Order struct {
+	ID    string
+	Total int
+}
+
+func (o *Order) AddItem(price int) {
+	o.Total += price
+}
+
+// OrderRepository is the persistence port for orders.
+type OrderRepository interface {
+	Save(ctx context.Context, o *Order) error
+	FindByID(ctx context.Context, id string) (*Order, error)
+}
@@ -0,0 +1,8 @@
+package payment
+
+import "context"
+
+// PaymentGateway is the port for charging customers.
+type PaymentGateway interface {
+	Charge(ctx context.Context, orderID string, amount int) error
+}
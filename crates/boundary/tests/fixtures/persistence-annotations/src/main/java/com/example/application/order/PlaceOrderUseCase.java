@@ -0,0 +1,19 @@
+package com.example.application.order;
+
+import com.example.domain.order.Order;
+import com.example.domain.order.OrderService;
+import org.springframework.transaction.annotation.Transactional;
+
+public class PlaceOrderUseCase {
+    private final OrderService service;
+
+    public PlaceOrderUseCase(OrderService service) {
+        this.service = service;
+    }
+
+    // Transactions belong here, in the application layer.
+    @Transactional
+    public void execute(Order order) {
+        service.place(order);
+    }
+}
@@ -0,0 +1,10 @@
+package com.example.domain.order;
+
+public class Order {
+    private String id;
+    private boolean confirmed;
+
+    public void confirm() {
+        this.confirmed = true;
+    }
+}
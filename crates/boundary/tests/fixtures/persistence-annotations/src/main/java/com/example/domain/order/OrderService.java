@@ -0,0 +1,22 @@
+package com.example.domain.order;
+
+import org.springframework.transaction.annotation.Transactional;
+
+public class OrderService {
+    private final OrderRepository orders;
+
+    public OrderService(OrderRepository orders) {
+        this.orders = orders;
+    }
+
+    @Transactional
+    public void place(Order order) {
+        order.confirm();
+        orders.save(order);
+    }
+
+    @Override
+    public String toString() {
+        return "OrderService";
+    }
+}
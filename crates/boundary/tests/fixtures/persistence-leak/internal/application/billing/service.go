@@ -0,0 +1,14 @@
+package billing
+
+import "database/sql"
+
+// InvoiceService queries the database itself instead of going through a port.
+type InvoiceService struct {
+	db *sql.DB
+}
+
+func (s *InvoiceService) Outstanding() (int, error) {
+	var n int
+	err := s.db.QueryRow("SELECT count(*) FROM invoices WHERE paid = false").Scan(&n)
+	return n, err
+}
@@ -0,0 +1,12 @@
+package postgres
+
+import "database/sql"
+
+type InvoiceStore struct {
+	db *sql.DB
+}
+
+func (s *InvoiceStore) MarkPaid(id string) error {
+	_, err := s.db.Exec("UPDATE invoices SET paid = true WHERE id = $1", id)
+	return err
+}
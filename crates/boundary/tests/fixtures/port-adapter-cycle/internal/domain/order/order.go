@@ -0,0 +1,14 @@
+package order
+
+import "context"
+
+// Order is a placed order.
+type Order struct {
+	ID     string
+	UserID string
+}
+
+// OrderRepository is the persistence port for orders.
+type OrderRepository interface {
+	Save(ctx context.Context, o *Order) error
+}
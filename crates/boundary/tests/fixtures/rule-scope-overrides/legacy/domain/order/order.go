@@ -0,0 +1,10 @@
+package order
+
+import (
+	_ "github.com/example/app/legacy/infrastructure/mysql"
+)
+
+type Order struct {
+	ID    string
+	Total int
+}
@@ -0,0 +1,3 @@
+package mysql
+
+type OrderRepository struct{}
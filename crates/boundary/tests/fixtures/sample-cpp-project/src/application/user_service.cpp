@@ -0,0 +1,12 @@
+#include <stdexcept>
+
+#include "../domain/user/user_repository.hpp"
+
+class UserService {
+public:
+    explicit UserService(UserRepository* repo) : repo_(repo) {}
+    void rename(const std::string& id, const std::string& name);
+
+private:
+    UserRepository* repo_;
+};
@@ -0,0 +1,12 @@
+#pragma once
+
+// Violation: domain includes an infrastructure header.
+#include "../../infrastructure/postgres/postgres_user_repository.hpp"
+
+class UserAuditor {
+public:
+    void audit(const User& user);
+
+private:
+    PostgresUserRepository* repo_;
+};
@@ -0,0 +1,12 @@
+#pragma once
+
+#include <string>
+
+class User {
+public:
+    void rename(const std::string& name);
+
+private:
+    std::string id_;
+    std::string name_;
+};
@@ -0,0 +1,10 @@
+#pragma once
+
+#include "user.hpp"
+
+class UserRepository {
+public:
+    virtual ~UserRepository() = default;
+    virtual void save(const User& user) = 0;
+    virtual User* findById(const std::string& id) = 0;
+};
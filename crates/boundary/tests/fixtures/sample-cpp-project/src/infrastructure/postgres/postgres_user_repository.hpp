@@ -0,0 +1,14 @@
+#pragma once
+
+#include <memory>
+
+#include "../../domain/user/user_repository.hpp"
+
+class PostgresUserRepository : public UserRepository {
+public:
+    void save(const User& user) override;
+    User* findById(const std::string& id) override;
+
+private:
+    void* conn_;
+};
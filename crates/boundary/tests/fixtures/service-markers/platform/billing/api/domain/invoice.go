@@ -0,0 +1,10 @@
+package domain
+
+type Invoice struct {
+	ID    string
+	Total int64
+}
+
+type InvoiceRepository interface {
+	Save(invoice *Invoice) error
+}
@@ -0,0 +1,9 @@
+package infrastructure
+
+import "example.com/billing/api/domain"
+
+type InvoiceStore struct{}
+
+func (s *InvoiceStore) Save(invoice *domain.Invoice) error {
+	return nil
+}
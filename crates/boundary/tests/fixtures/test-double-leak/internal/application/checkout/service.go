@@ -0,0 +1,18 @@
+package checkout
+
+import (
+	"github.com/example/shop/internal/domain/payment"
+	"github.com/example/shop/internal/mocks"
+)
+
+type CheckoutService struct {
+	gateway payment.PaymentGateway
+}
+
+func NewCheckoutService() *CheckoutService {
+	return &CheckoutService{gateway: &mocks.MockPaymentGateway{}}
+}
+
+func (s *CheckoutService) Checkout(orderID string, cents int64) error {
+	return s.gateway.Charge(orderID, cents)
+}
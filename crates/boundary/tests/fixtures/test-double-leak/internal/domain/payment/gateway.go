@@ -0,0 +1,5 @@
+package payment
+
+type PaymentGateway interface {
+	Charge(orderID string, cents int64) error
+}
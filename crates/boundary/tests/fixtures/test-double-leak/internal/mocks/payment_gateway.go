@@ -0,0 +1,10 @@
+package mocks
+
+type MockPaymentGateway struct {
+	Charged []string
+}
+
+func (m *MockPaymentGateway) Charge(orderID string, cents int64) error {
+	m.Charged = append(m.Charged, orderID)
+	return nil
+}
@@ -0,0 +1,21 @@
+package application
+
+import (
+	"context"
+
+	"example.com/orders/internal/domain/order"
+	"example.com/orders/internal/generated/paymentsapi"
+)
+
+// CheckoutService places orders and charges them through the generated payments client.
+type CheckoutService struct {
+	orders   order.OrderRepository
+	payments *paymentsapi.Client
+}
+
+func (s *CheckoutService) Checkout(ctx context.Context, o *order.Order) error {
+	if _, err := s.payments.Charge(ctx, &paymentsapi.ChargeRequest{Amount: o.Total}); err != nil {
+		return err
+	}
+	return s.orders.Save(ctx, o)
+}
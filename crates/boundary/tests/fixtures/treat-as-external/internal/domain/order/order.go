@@ -0,0 +1,17 @@
+package order
+
+import "context"
+
+// Order is a placed order.
+type Order struct {
+	ID    string
+	Total int
+}
+
+// Pay marks the order as paid.
+func (o *Order) Pay() {}
+
+// OrderRepository is the persistence port for orders.
+type OrderRepository interface {
+	Save(ctx context.Context, o *Order) error
+}
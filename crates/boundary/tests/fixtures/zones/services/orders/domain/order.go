@@ -0,0 +1,13 @@
+package domain
+
+import "github.com/example/zones/services/orders/infrastructure/db"
+
+// Order reaches into infrastructure, which DDD forbids.
+type Order struct {
+	ID   string
+	conn db.Connection
+}
+
+func (o *Order) Save() error {
+	return o.conn.Exec("INSERT INTO orders ...")
+}
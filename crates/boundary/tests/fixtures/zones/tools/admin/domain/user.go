@@ -0,0 +1,14 @@
+package domain
+
+import "github.com/example/zones/tools/admin/infrastructure/db"
+
+// User is an Active Record model: it knows how to persist itself.
+type User struct {
+	ID    string
+	Email string
+	conn  db.Connection
+}
+
+func (u *User) Save() error {
+	return u.conn.Exec("INSERT INTO users ...")
+}
@@ -0,0 +1,69 @@
+/// Acceptance tests for `boundary forensics`.
+///
+/// The domain module of `sample-rust-project` imports the postgres repository from
+/// infrastructure.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn forensics(module: &str, project: &str) -> String {
+    let output = boundary_cmd()
+        .args([
+            "forensics",
+            &format!("{}/{module}", fixture(project)),
+            "--project-root",
+            &fixture(project),
+        ])
+        .output()
+        .expect("failed to run boundary forensics");
+
+    assert!(
+        output.status.success(),
+        "forensics failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// The report text from `heading` up to the next heading of the same or a higher level.
+fn section<'a>(report: &'a str, heading: &str) -> &'a str {
+    let start = report
+        .find(heading)
+        .unwrap_or_else(|| panic!("report should have '{heading}':\n{report}"));
+    let level = heading.split(' ').next().unwrap();
+    let body = &report[start + heading.len()..];
+    let end = body
+        .match_indices("\n#")
+        .find(|(i, _)| {
+            let hashes = body[i + 1..].split(' ').next().unwrap();
+            hashes.len() <= level.len()
+        })
+        .map_or(body.len(), |(i, _)| i);
+    &body[..end]
+}
+
+// ----------------------------------------------------------------------------
+// The score breakdown names the import behind the domain module's violations
+// ----------------------------------------------------------------------------
+#[test]
+fn breakdown_names_offending_postgres_import() {
+    let report = forensics("src/domain", "sample-rust-project");
+
+    let breakdown = section(&report, "### Score Breakdown");
+    let imports = section(breakdown, "#### Top Violating Imports");
+    assert!(
+        imports.contains("`crate::infrastructure::postgres::PostgresUserRepository` | 1"),
+        "should list the postgres import, got:\n{breakdown}"
+    );
+    assert!(
+        section(breakdown, "#### Structural Presence")
+            .contains("Every component is assigned a layer"),
+        "all domain components are classified, got:\n{breakdown}"
+    );
+}
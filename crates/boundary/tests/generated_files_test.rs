@@ -0,0 +1,79 @@
+/// Acceptance tests for generated-file detection.
+///
+/// The `generated-files` fixture has a domain `order.pb.go` starting with Go's
+/// `// Code generated ... DO NOT EDIT.` marker that imports infrastructure, next to a
+/// hand-written `order.go` without violations.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Run `check --format json` on the fixture, returning the exit code and the report.
+fn check(extra: &[&str]) -> (Option<i32>, serde_json::Value) {
+    let path = fixture("generated-files");
+    let output = boundary_cmd()
+        .args(["check", &path, "--format", "json"])
+        .args(extra)
+        .output()
+        .expect("failed to run boundary check");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report = serde_json::from_str(&stdout).expect("output should be valid JSON");
+    (output.status.code(), report)
+}
+
+fn l001_severities(report: &serde_json::Value) -> Vec<String> {
+    report["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"] == "L001")
+        .map(|v| v["severity"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A DO NOT EDIT file's violations are reported but do not fail the check
+// ----------------------------------------------------------------------------
+#[test]
+fn generated_file_violations_do_not_fail_check() {
+    let (code, report) = check(&[]);
+
+    assert_eq!(code, Some(0), "check should pass: {report}");
+    assert_eq!(l001_severities(&report), ["info"]);
+    assert_eq!(report["check"]["failing_violation_count"], 0);
+    let generated = report["generated_files"].as_array().unwrap();
+    assert_eq!(generated.len(), 1, "generated_files: {generated:?}");
+    assert!(generated[0].as_str().unwrap().ends_with("order.pb.go"));
+}
+
+// ----------------------------------------------------------------------------
+// fail_on_generated keeps the configured severity
+// ----------------------------------------------------------------------------
+#[test]
+fn fail_on_generated_restores_failure() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".boundary.toml");
+    std::fs::write(&config, "[rules]\nfail_on_generated = true\n").unwrap();
+
+    let (code, report) = check(&["--config", config.to_str().unwrap()]);
+
+    assert_eq!(code, Some(1), "check should fail: {report}");
+    assert_eq!(l001_severities(&report), ["error"]);
+}
+
+// ----------------------------------------------------------------------------
+// Generated-file violations stay out of the failing set even at --fail-on info
+// ----------------------------------------------------------------------------
+#[test]
+fn generated_file_violations_do_not_fail_check_at_info() {
+    let (code, report) = check(&["--fail-on", "info"]);
+
+    assert_eq!(code, Some(0), "check should pass: {report}");
+    assert_eq!(l001_severities(&report), ["info"]);
+    assert_eq!(report["check"]["failing_violation_count"], 0);
+}
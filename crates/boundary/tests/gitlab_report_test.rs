@@ -0,0 +1,97 @@
+/// Acceptance tests for `--format gitlab`: a GitLab Code Quality report, one entry per
+/// violation with a description, rule, fingerprint, severity and location.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn gitlab_report(command: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args([command, &fixture("sample-go-project"), "--format", "gitlab"])
+        .output()
+        .expect("failed to run boundary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(stdout.trim()).expect("report should be valid JSON");
+    parsed
+        .as_array()
+        .expect("report should be a JSON array")
+        .clone()
+}
+
+// ---------------------------------------------------------------------------
+// Report shape
+// ---------------------------------------------------------------------------
+
+/// The Go fixture's domain -> infrastructure import is reported with every field
+/// GitLab requires.
+#[test]
+fn layer_violation_has_required_fields() {
+    let issues = gitlab_report("analyze");
+    let issue = issues
+        .iter()
+        .find(|i| i["check_name"] == "L001")
+        .unwrap_or_else(|| panic!("expected an L001 issue, got: {issues:?}"));
+
+    assert!(issue["description"]
+        .as_str()
+        .unwrap()
+        .contains("domain layer depends on infrastructure layer"));
+    assert_eq!(issue["severity"], "critical");
+    assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 64);
+    assert!(
+        issue["location"]["path"]
+            .as_str()
+            .unwrap()
+            .ends_with("internal/domain/user/bad_dependency.go"),
+        "path: {}",
+        issue["location"]["path"]
+    );
+    assert!(issue["location"]["lines"]["begin"].as_u64().unwrap() >= 1);
+}
+
+/// Every issue has a distinct fingerprint, so GitLab does not merge them.
+#[test]
+fn fingerprints_are_unique() {
+    let issues = gitlab_report("analyze");
+    let mut fingerprints: Vec<_> = issues
+        .iter()
+        .map(|i| i["fingerprint"].as_str().unwrap())
+        .collect();
+    let total = fingerprints.len();
+    fingerprints.sort_unstable();
+    fingerprints.dedup();
+    assert_eq!(fingerprints.len(), total);
+}
+
+/// `check` emits the same report, and still fails on errors.
+#[test]
+fn check_emits_gitlab_report() {
+    let output = boundary_cmd()
+        .args(["check", &fixture("sample-go-project"), "--format", "gitlab"])
+        .output()
+        .expect("failed to run boundary check");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(stdout.trim()).expect("report should be valid JSON");
+    assert!(parsed.as_array().is_some_and(|a| !a.is_empty()));
+}
+
+/// Commands without violations reject the format.
+#[test]
+fn compare_rejects_gitlab_format() {
+    let path = fixture("sample-go-project");
+    let output = boundary_cmd()
+        .args(["compare", &path, &path, "--format", "gitlab"])
+        .output()
+        .expect("failed to run boundary compare");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--format gitlab"), "stderr: {stderr}");
+}
@@ -0,0 +1,58 @@
+/// Acceptance tests for Go struct embedding.
+///
+/// A domain struct embedding an infrastructure type from another package needs
+/// that package's import, so the layer boundary is reported once, at the import.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn write(dir: &Path, rel: &str, content: &str) {
+    let path = dir.join(rel);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+// ----------------------------------------------------------------------------
+// A qualified embedding does not repeat its import's L001
+// ----------------------------------------------------------------------------
+#[test]
+fn qualified_embedding_reports_layer_boundary_once() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "go.mod", "module github.com/example/app\n");
+    write(
+        dir.path(),
+        "internal/infrastructure/postgres/store.go",
+        "package postgres\n\ntype Store struct {\n\tDSN string\n}\n",
+    );
+    write(
+        dir.path(),
+        "internal/domain/user/admin.go",
+        "package user\n\nimport \"github.com/example/app/internal/infrastructure/postgres\"\n\n\
+         type Admin struct {\n\tpostgres.Store\n\tLevel int\n}\n",
+    );
+
+    let output = boundary_cmd()
+        .args(["analyze", dir.path().to_str().unwrap(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    let lines: Vec<u64> = parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"] == "L001")
+        .filter(|v| {
+            v["location"]["file"]
+                .as_str()
+                .is_some_and(|f| f.ends_with("admin.go"))
+        })
+        .map(|v| v["location"]["line"].as_u64().unwrap())
+        .collect();
+    assert_eq!(lines, [3], "expected one L001 at the import: {stdout}");
+}
@@ -0,0 +1,91 @@
+/// Acceptance tests for `check --grace-days`.
+///
+/// Verifies that violations in newly added files stop failing the check, and that
+/// a shallow clone, where every file looks new, leaves severities alone.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .expect("failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// A git repository holding `name`'s files, with a second commit on top so that a
+/// depth-1 clone of it is shallow.
+fn fixture_repo(name: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.unwrap();
+        let dest = dir.path().join(entry.path().strip_prefix(&src).unwrap());
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    git(dir.path(), &["init", "--quiet"]);
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "--quiet", "-m", "init"]);
+    std::fs::write(dir.path().join("README.md"), "fixture\n").unwrap();
+    git(dir.path(), &["add", "-A"]);
+    git(dir.path(), &["commit", "--quiet", "-m", "readme"]);
+    dir
+}
+
+fn check_with_grace(path: &Path) -> std::process::Output {
+    boundary_cmd()
+        .args(["check", path.to_str().unwrap(), "--grace-days", "14"])
+        .output()
+        .expect("failed to run boundary check")
+}
+
+// ----------------------------------------------------------------------------
+// Errors in files added today are downgraded, so the check passes
+// ----------------------------------------------------------------------------
+#[test]
+fn new_files_are_within_grace_period() {
+    let repo = fixture_repo("domain-imports-infra");
+    let output = check_with_grace(repo.path());
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+// ----------------------------------------------------------------------------
+// A shallow clone has no usable history: warn and keep every severity
+// ----------------------------------------------------------------------------
+#[test]
+fn shallow_clone_leaves_severities_alone() {
+    let repo = fixture_repo("domain-imports-infra");
+    let parent = tempfile::tempdir().unwrap();
+    let url = format!("file://{}", repo.path().display());
+    git(
+        parent.path(),
+        &["clone", "--quiet", "--depth", "1", &url, "clone"],
+    );
+
+    let output = check_with_grace(&parent.path().join("clone"));
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("shallow clone"),
+        "should warn about the shallow clone, got: {stderr}"
+    );
+}
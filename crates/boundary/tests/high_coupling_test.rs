@@ -0,0 +1,109 @@
+/// Acceptance tests for D004 (high-coupling).
+///
+/// The rule is opt-in via `detect_high_coupling`; the `god-router` fixture enables it
+/// with a threshold of 10 and holds a router wiring 12 handler packages and an admin
+/// router wiring 3.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn high_coupling_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("D004"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A router wiring 12 handlers fires at a threshold of 10
+// ----------------------------------------------------------------------------
+#[test]
+fn router_with_twelve_handlers_fires() {
+    let violations = high_coupling_violations("god-router");
+    assert_eq!(violations.len(), 1, "got: {violations:?}");
+
+    let v = &violations[0];
+    assert_eq!(v["severity"], "info");
+    assert_eq!(v["kind"]["HighCoupling"]["layer"], "Presentation");
+    assert_eq!(v["kind"]["HighCoupling"]["fan_out"], 12);
+    assert!(
+        v["kind"]["HighCoupling"]["component"]
+            .as_str()
+            .unwrap()
+            .ends_with("internal/handler/router"),
+        "got: {v}"
+    );
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("router/router.go"),
+        "got: {v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Routers under the threshold pass
+// ----------------------------------------------------------------------------
+#[test]
+fn router_under_threshold_does_not_fire() {
+    let violations = high_coupling_violations("god-router");
+    assert!(
+        violations
+            .iter()
+            .all(|v| !v["kind"]["HighCoupling"]["component"]
+                .as_str()
+                .unwrap()
+                .ends_with("admin")),
+        "admin router wires 3 handlers, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn high_coupling_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("god-router"),
+            "--format",
+            "json",
+            "--config",
+        ])
+        .arg(&config)
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    let fired = parsed["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|v| v["rule"] == "D004");
+    assert!(!fired, "D004 should be opt-in");
+}
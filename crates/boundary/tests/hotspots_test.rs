@@ -0,0 +1,114 @@
+/// Acceptance tests for `boundary hotspots`: files ranked by git commit count
+/// times violation count.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+/// Run git in `dir`, returning false when git is unavailable or fails.
+fn git(dir: &Path, args: &[&str]) -> bool {
+    Command::new("git")
+        .args([
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+fn hotspots_json(root: &Path) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["hotspots", &root.to_string_lossy(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary hotspots");
+    assert!(
+        output.status.success(),
+        "hotspots failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    parsed["hotspots"]
+        .as_array()
+        .expect("should have hotspots array")
+        .clone()
+}
+
+// ----------------------------------------------------------------------------
+// Commits to a file with violations multiply its score
+// ----------------------------------------------------------------------------
+#[test]
+fn changed_file_with_violations_ranks_first() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+    let root = dir.path();
+    if !git(root, &["init", "-q"]) || !git(root, &["add", "-A"]) {
+        println!("git unavailable — skipping hotspots test");
+        return;
+    }
+    assert!(git(root, &["commit", "-q", "-m", "initial"]));
+    let bad = root.join("internal/domain/user/bad_dependency.go");
+    for i in 0..2 {
+        let mut content = std::fs::read_to_string(&bad).unwrap();
+        content.push_str(&format!("\n// change {i}\n"));
+        std::fs::write(&bad, content).unwrap();
+        assert!(git(root, &["commit", "-q", "-am", "change"]));
+    }
+
+    let hotspots = hotspots_json(root);
+    let first = hotspots.first().expect("at least one hotspot");
+    assert!(
+        first["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("bad_dependency.go"),
+        "{first}"
+    );
+    assert_eq!(first["commits"], 3, "{first}");
+    let violations = first["violations"].as_u64().unwrap();
+    assert!(violations > 0);
+    assert_eq!(first["score"], 3 * violations, "{first}");
+}
+
+// ----------------------------------------------------------------------------
+// Outside git every file has zero commits
+// ----------------------------------------------------------------------------
+#[test]
+fn outside_git_reports_zero_commits() {
+    let dir = copy_fixture_to_tempdir("sample-go-project");
+
+    let hotspots = hotspots_json(dir.path());
+    assert!(!hotspots.is_empty());
+    for h in &hotspots {
+        assert_eq!(h["commits"], 0, "{h}");
+        assert_eq!(h["score"], 0, "{h}");
+    }
+}
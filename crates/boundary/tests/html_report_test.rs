@@ -0,0 +1,72 @@
+/// Acceptance tests for `--format html`: a standalone HTML report for sharing.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+// ----------------------------------------------------------------------------
+// analyze renders score, layers and linked violations in one offline page
+// ----------------------------------------------------------------------------
+#[test]
+fn analyze_html_is_self_contained() {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture("sample-go-project"), "--format", "html"])
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(output.status.success());
+    let html = String::from_utf8_lossy(&output.stdout);
+
+    assert!(html.starts_with("<!DOCTYPE html>"), "{html}");
+    assert!(html.contains("<style>"));
+    assert!(!html.contains("<link "), "CSS must be inline: {html}");
+    assert!(html.contains("<h2>Score</h2>"));
+    assert!(html.contains("<h2>Components by Layer</h2>"));
+    assert!(html.contains("<h3>L001 "), "{html}");
+    assert!(
+        html.contains("bad_dependency.go#L4\">"),
+        "violation rows should link to file:line: {html}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// check appends the pass/fail result and keeps its exit code
+// ----------------------------------------------------------------------------
+#[test]
+fn check_html_reports_failure() {
+    let output = boundary_cmd()
+        .args(["check", &fixture("sample-go-project"), "--format", "html"])
+        .output()
+        .expect("failed to run boundary check");
+    assert!(!output.status.success());
+    let html = String::from_utf8_lossy(&output.stdout);
+    assert!(html.contains("CHECK FAILED"), "{html}");
+    assert!(html.trim_end().ends_with("</html>"));
+}
+
+// ----------------------------------------------------------------------------
+// Commands without a single analysis result reject html
+// ----------------------------------------------------------------------------
+#[test]
+fn html_is_rejected_for_multi_service_reports() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("sample-go-project"),
+            "--per-service",
+            "--format",
+            "html",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--format html is only supported by analyze and check"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,89 @@
+/// Acceptance tests for `--include-config`.
+///
+/// Verifies that the effective configuration is recorded in JSON output under
+/// `config`, and that it is omitted unless requested.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+/// A project whose `.boundary.toml` uses non-default layer patterns.
+fn project_with_custom_layers() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("create temp dir");
+    std::fs::write(
+        dir.path().join(".boundary.toml"),
+        r#"[layers]
+domain = ["**/core/**"]
+application = ["**/app/**"]
+infrastructure = ["**/adapters/**"]
+presentation = ["**/http/**"]
+"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(dir.path().join("core")).unwrap();
+    std::fs::write(
+        dir.path().join("core/user.go"),
+        "package core\n\ntype User struct {\n\tID string\n}\n",
+    )
+    .unwrap();
+    dir
+}
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = boundary_cmd()
+        .args(args)
+        .output()
+        .expect("failed to run boundary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("invalid JSON: {e}\noutput: {stdout}"))
+}
+
+// ----------------------------------------------------------------------------
+// analyze: emitted layer patterns match the loaded config
+// ----------------------------------------------------------------------------
+#[test]
+fn include_config_emits_loaded_layer_patterns() {
+    let project = project_with_custom_layers();
+    let path = project.path().to_str().unwrap();
+    let json = run_json(&["analyze", path, "--format", "json", "--include-config"]);
+
+    let layers = &json["config"]["layers"];
+    assert_eq!(
+        layers["domain"],
+        serde_json::json!(["**/core/**"]),
+        "{json}"
+    );
+    assert_eq!(layers["application"], serde_json::json!(["**/app/**"]));
+    assert_eq!(
+        layers["infrastructure"],
+        serde_json::json!(["**/adapters/**"])
+    );
+    assert_eq!(layers["presentation"], serde_json::json!(["**/http/**"]));
+}
+
+#[test]
+fn config_omitted_without_flag() {
+    let project = project_with_custom_layers();
+    let path = project.path().to_str().unwrap();
+    let json = run_json(&["analyze", path, "--format", "json"]);
+
+    assert!(json.get("config").is_none(), "unexpected config: {json}");
+}
+
+// ----------------------------------------------------------------------------
+// check: config sits alongside the pass/fail metadata
+// ----------------------------------------------------------------------------
+#[test]
+fn check_includes_config() {
+    let project = project_with_custom_layers();
+    let path = project.path().to_str().unwrap();
+    let json = run_json(&["check", path, "--format", "json", "--include-config"]);
+
+    assert!(json["check"]["passed"].is_boolean(), "{json}");
+    assert_eq!(
+        json["config"]["layers"]["domain"],
+        serde_json::json!(["**/core/**"])
+    );
+}
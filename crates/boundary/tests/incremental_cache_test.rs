@@ -0,0 +1,99 @@
+/// Acceptance tests for `--incremental`: cache entries are keyed to a hash of the
+/// file content, so an edited file is re-parsed instead of served from the cache.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn write(root: &Path, rel: &str, contents: &str) {
+    let path = root.join(rel);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+fn incremental_component_count(root: &Path) -> u64 {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &root.to_string_lossy(),
+            "--incremental",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    parsed["component_count"]
+        .as_u64()
+        .expect("should have component_count")
+}
+
+fn cached_hash(root: &Path, rel: &str) -> String {
+    let cache = std::fs::read_to_string(root.join(".boundary/cache.json"))
+        .expect("incremental run should write the cache");
+    let parsed: serde_json::Value = serde_json::from_str(&cache).unwrap();
+    parsed["files"][rel]["hash"]
+        .as_str()
+        .expect("cache entry should have a hash")
+        .to_string()
+}
+
+// ----------------------------------------------------------------------------
+// Editing a file between incremental runs invalidates its cache entry
+// ----------------------------------------------------------------------------
+#[test]
+fn modified_file_is_reparsed() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    write(root, "go.mod", "module example.com/shop\n\ngo 1.21\n");
+    write(
+        root,
+        "domain/order.go",
+        "package domain\n\ntype Order struct {\n\tID string\n}\n",
+    );
+
+    let before = incremental_component_count(root);
+    let first_hash = cached_hash(root, "domain/order.go");
+    assert!(
+        !first_hash.is_empty(),
+        "cache entry should store a real hash"
+    );
+
+    write(
+        root,
+        "domain/order.go",
+        "package domain\n\ntype Order struct {\n\tID string\n}\n\n\
+         type Invoice struct {\n\tID string\n}\n",
+    );
+
+    let after = incremental_component_count(root);
+    assert_eq!(
+        after,
+        before + 1,
+        "the new struct should be extracted, not served from the stale cache entry"
+    );
+    assert_ne!(cached_hash(root, "domain/order.go"), first_hash);
+}
+
+#[test]
+fn unchanged_file_keeps_its_cache_entry() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    write(root, "go.mod", "module example.com/shop\n\ngo 1.21\n");
+    write(
+        root,
+        "domain/order.go",
+        "package domain\n\ntype Order struct {\n\tID string\n}\n",
+    );
+
+    let before = incremental_component_count(root);
+    let first_hash = cached_hash(root, "domain/order.go");
+
+    assert_eq!(incremental_component_count(root), before);
+    assert_eq!(cached_hash(root, "domain/order.go"), first_hash);
+}
@@ -0,0 +1,92 @@
+/// Acceptance tests for the L005 field-type check.
+///
+/// The check is opt-in via `detect_infra_field_types`; the `infra-field-types`
+/// fixture enables it and declares a domain `User` holding a `*sql.DB` next to an
+/// `Account` with only primitive fields and a `Session` whose `*sql.DB` is unexported.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn field_type_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("infra-field-types");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L005"))
+        .filter(|v| {
+            v["kind"]["DomainInfrastructureLeak"]["detail"]
+                .as_str()
+                .is_some_and(|d| d.contains("has infrastructure type"))
+        })
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A domain field typed *sql.DB fires; primitive fields do not
+// ----------------------------------------------------------------------------
+#[test]
+fn domain_field_of_sql_db_fires() {
+    let violations = field_type_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one field-type violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert_eq!(
+        v["kind"]["DomainInfrastructureLeak"]["detail"],
+        "domain type User.DB has infrastructure type *sql.DB"
+    );
+    assert!(
+        !v["message"].as_str().unwrap().contains("Account"),
+        "primitive-only Account should pass: {v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// An unexported field of an infrastructure type is not exposed, so it passes
+// ----------------------------------------------------------------------------
+#[test]
+fn private_field_of_sql_db_passes() {
+    let violations = field_type_violations(&[]);
+    assert!(
+        violations
+            .iter()
+            .all(|v| !v["message"].as_str().unwrap().contains("Session")),
+        "unexported Session.db should pass, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn field_type_check_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = field_type_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "field-type check should be opt-in, got: {violations:?}"
+    );
+}
@@ -0,0 +1,74 @@
+/// Acceptance tests for the L005 port-parameter check.
+///
+/// The check is opt-in via `detect_infra_port_parameters`; the
+/// `infra-port-params` fixture enables it and declares a domain repository port
+/// whose `Save` takes a `*sql.Tx`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn port_parameter_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("infra-port-params");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L005"))
+        .filter(|v| {
+            v["kind"]["DomainInfrastructureLeak"]["detail"]
+                .as_str()
+                .is_some_and(|d| d.contains("takes infrastructure type"))
+        })
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A port method taking *sql.Tx fires; context.Context does not
+// ----------------------------------------------------------------------------
+#[test]
+fn port_method_taking_sql_tx_fires() {
+    let violations = port_parameter_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one port-parameter violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert_eq!(
+        v["kind"]["DomainInfrastructureLeak"]["detail"],
+        "domain port OrderRepository.Save takes infrastructure type *sql.Tx"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn port_parameter_check_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = port_parameter_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "port-parameter check should be opt-in, got: {violations:?}"
+    );
+}
@@ -15,6 +15,11 @@ fn java_fixture_path() -> String {
     format!("{manifest_dir}/tests/fixtures/sample-java-project/")
 }
 
+fn cpp_fixture_path() -> String {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    format!("{manifest_dir}/tests/fixtures/sample-cpp-project/")
+}
+
 fn rust_fixture_path() -> String {
     let manifest_dir = env!("CARGO_MANIFEST_DIR");
     format!("{manifest_dir}/tests/fixtures/sample-rust-project/")
@@ -113,6 +118,83 @@ fn test_init_refuses_overwrite() {
     );
 }
 
+#[test]
+fn test_init_detect_globs_layer_directories() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let domain = dir.path().join("internal/domain/user");
+    std::fs::create_dir_all(&domain).unwrap();
+    std::fs::write(domain.join("user.go"), "package user\n").unwrap();
+    std::fs::create_dir_all(dir.path().join("internal/infra/postgres")).unwrap();
+
+    let output = boundary_cmd()
+        .args(["init", "--detect"])
+        .arg(dir.path())
+        .output()
+        .expect("failed to run boundary init --detect");
+    assert!(
+        output.status.success(),
+        "init --detect should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let content = std::fs::read_to_string(dir.path().join(".boundary.toml")).unwrap();
+    let config: toml::Value = toml::from_str(&content).expect("detected config should parse");
+    assert_eq!(
+        config["project"]["languages"].as_array().unwrap(),
+        &[toml::Value::from("go")]
+    );
+    assert_eq!(
+        config["layers"]["domain"].as_array().unwrap(),
+        &[toml::Value::from("internal/domain/**")]
+    );
+    assert_eq!(
+        config["layers"]["infrastructure"].as_array().unwrap(),
+        &[toml::Value::from("internal/infra/**")]
+    );
+    assert!(
+        config["layers"].get("presentation").is_none(),
+        "undetected layers should keep defaults: {content}"
+    );
+}
+
+#[test]
+fn test_config_dump_loads_as_config() {
+    let output = boundary_cmd()
+        .args(["config"])
+        .output()
+        .expect("failed to run boundary config");
+    assert!(output.status.success(), "config should succeed");
+
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let config_path = dir.path().join("reference.toml");
+    std::fs::write(&config_path, &output.stdout).unwrap();
+
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture_path(),
+            "--config",
+            config_path.to_str().unwrap(),
+            "--format",
+            "json",
+            "--include-config",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "dumped config should load: {stderr}"
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["config"]["rules"]["max_repository_methods"], 10);
+    assert_eq!(
+        json["config"]["layers"]["domain"],
+        serde_json::json!(["**/domain/**", "**/entity/**", "**/model/**"])
+    );
+}
+
 #[test]
 fn test_analyze_json_output() {
     let output = boundary_cmd()
@@ -362,6 +444,55 @@ fn test_analyze_java_json() {
     );
 }
 
+// ==================== C/C++ analyzer tests ====================
+
+#[test]
+fn test_check_cpp_violations() {
+    let output = boundary_cmd()
+        .args(["check", &cpp_fixture_path(), "--fail-on", "error"])
+        .output()
+        .expect("failed to run boundary check on C++ fixture");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "expected exit code 1 for C++ violations, got {:?}: {stdout}",
+        output.status.code()
+    );
+    assert!(
+        stdout.contains("L001"),
+        "domain including an infrastructure header should be L001: {stdout}"
+    );
+}
+
+#[test]
+fn test_analyze_cpp_json() {
+    let output = boundary_cmd()
+        .args(["analyze", &cpp_fixture_path(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze --format json on C++ fixture");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "C++ JSON analyze should succeed: {stdout}"
+    );
+
+    let parsed: serde_json::Value =
+        serde_json::from_str(stdout.trim()).expect("output should be valid JSON");
+    assert_eq!(
+        parsed["metrics"]["components_by_kind"]["port"].as_u64(),
+        Some(1),
+        "abstract UserRepository should be a port: {stdout}"
+    );
+    assert!(
+        parsed["dependency_count"].as_u64().unwrap() > 0,
+        "local includes should become dependencies"
+    );
+}
+
 // ==================== Rust analyzer tests ====================
 
 #[test]
@@ -542,6 +673,59 @@ fn test_score_rust_fixture() {
     assert_score_near(iface, 100.0, 1.0, "rust interface_coverage");
 }
 
+#[test]
+fn test_worst_layer_pair() {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture_path(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    let pair = &parsed["metrics"]["worst_layer_pair"];
+    assert_eq!(pair["from"], "Domain", "{pair}");
+    assert_eq!(pair["to"], "Infrastructure", "{pair}");
+    assert_eq!(pair["count"], 1, "{pair}");
+
+    let output = boundary_cmd()
+        .args(["analyze", &fixture_path()])
+        .output()
+        .expect("failed to run boundary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Most violating direction: domain → infrastructure (1 edge)"),
+        "text report should name the worst layer pair: {stdout}"
+    );
+}
+
+#[test]
+fn test_layer_metrics_go_fixture() {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture_path(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    let layers = parsed["metrics"]["layer_metrics"]
+        .as_array()
+        .expect("should have layer_metrics");
+    let domain = &layers[0];
+    assert_eq!(domain["layer"], "Domain", "{domain}");
+    // The service and the repository depend on the domain; bad_dependency.go reaches out.
+    assert_eq!(domain["afferent_coupling"], 2, "{domain}");
+    assert_eq!(domain["efferent_coupling"], 1, "{domain}");
+    assert_eq!(domain["instability"], 0.33, "{domain}");
+    for layer in layers {
+        let (a, i, d) = (
+            layer["abstractness"].as_f64().unwrap(),
+            layer["instability"].as_f64().unwrap(),
+            layer["distance"].as_f64().unwrap(),
+        );
+        assert!((d - (a + i - 1.0).abs()).abs() < 0.011, "{layer}");
+    }
+}
+
 #[test]
 fn test_score_not_all_100() {
     // Regression test: ensure fixtures with violations don't score a perfect 100.
@@ -0,0 +1,121 @@
+/// Acceptance tests for PA013: interface coverage below `min_interface_coverage`.
+///
+/// The `interface-coverage-threshold` fixture has three ports and five infrastructure
+/// adapters, two of which return concrete types, for 60% interface coverage. Its other
+/// findings are warnings, so only the coverage minimum can fail `check`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+fn coverage_violations(config: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("interface-coverage-threshold"),
+            "--config",
+            config,
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    assert_eq!(parsed["score"]["interface_coverage"], 60.0);
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA013"))
+        .cloned()
+        .collect()
+}
+
+fn check_exit_code(config: &str) -> Option<i32> {
+    boundary_cmd()
+        .args([
+            "check",
+            &fixture("interface-coverage-threshold"),
+            "--config",
+            config,
+        ])
+        .output()
+        .expect("failed to run boundary check")
+        .status
+        .code()
+}
+
+// ----------------------------------------------------------------------------
+// 60% coverage fails an 80% minimum
+// ----------------------------------------------------------------------------
+#[test]
+fn coverage_below_minimum_is_flagged() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "[rules]\nmin_interface_coverage = 80.0\n");
+
+    let violations = coverage_violations(&config);
+    let [v] = violations.as_slice() else {
+        panic!("expected one PA013 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert_eq!(v["rule_name"], "low-interface-coverage");
+    let kind = &v["kind"]["LowInterfaceCoverage"];
+    assert_eq!(kind["coverage"], 60.0);
+    assert_eq!(kind["minimum"], 80.0);
+    assert_eq!(kind["ports"], 3);
+    assert_eq!(kind["adapters"], 5);
+}
+
+#[test]
+fn check_fails_below_minimum_without_other_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let without = config_with(&dir, "");
+    assert_eq!(
+        check_exit_code(&without),
+        Some(0),
+        "the fixture has no errors of its own"
+    );
+
+    let with = config_with(&dir, "[rules]\nmin_interface_coverage = 80.0\n");
+    assert_eq!(check_exit_code(&with), Some(1));
+}
+
+// ----------------------------------------------------------------------------
+// Coverage at the minimum passes; the check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn coverage_at_minimum_passes() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "[rules]\nmin_interface_coverage = 60.0\n");
+
+    let violations = coverage_violations(&config);
+    assert!(violations.is_empty(), "got: {violations:?}");
+}
+
+#[test]
+fn interface_coverage_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "");
+
+    let violations = coverage_violations(&config);
+    assert!(
+        violations.is_empty(),
+        "interface coverage check should be opt-in, got: {violations:?}"
+    );
+}
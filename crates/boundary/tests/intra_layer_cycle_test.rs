@@ -0,0 +1,68 @@
+/// Acceptance tests for D003 (intra-layer-cycle).
+///
+/// The rule is opt-in via `detect_intra_layer_cycles`; the `intra-layer-cycle`
+/// fixture enables it and holds two domain packages that import each other plus
+/// a third that depends on them without closing a cycle.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn intra_layer_cycle_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("D003"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Mutually importing domain packages fire D003
+// ----------------------------------------------------------------------------
+#[test]
+fn mutually_importing_domain_packages_fire() {
+    let violations = intra_layer_cycle_violations("intra-layer-cycle");
+    let [v] = violations.as_slice() else {
+        panic!("expected one D003 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["kind"]["IntraLayerCycle"]["layer"], "Domain");
+    let packages: Vec<&str> = v["kind"]["IntraLayerCycle"]["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|p| p.as_str())
+        .collect();
+    assert_eq!(packages.len(), 2, "packages: {packages:?}");
+    assert!(packages[0].ends_with("domain/customer"), "{packages:?}");
+    assert!(packages[1].ends_with("domain/order"), "{packages:?}");
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn intra_layer_cycle_disabled_by_default() {
+    let violations = intra_layer_cycle_violations("sample-go-project");
+    assert!(
+        violations.is_empty(),
+        "D003 should be opt-in, got: {violations:?}"
+    );
+}
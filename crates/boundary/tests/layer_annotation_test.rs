@@ -0,0 +1,65 @@
+/// Acceptance tests for in-source layer annotations.
+///
+/// In the `layer-annotation` fixture, `internal/shared/money.go` sits in a directory
+/// no layer pattern matches but declares `// boundary:layer domain`;
+/// `internal/common/clock.go` has no annotation. Both import infrastructure.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .clone()
+}
+
+fn in_file<'a>(violations: &'a [serde_json::Value], file: &str) -> Vec<&'a serde_json::Value> {
+    violations
+        .iter()
+        .filter(|v| v["location"]["file"].as_str().unwrap_or("").ends_with(file))
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// An annotated Go file in an ambiguous directory is classified by its annotation
+// ----------------------------------------------------------------------------
+#[test]
+fn go_annotation_classifies_file_as_domain() {
+    let violations = violations("layer-annotation");
+    let money = in_file(&violations, "shared/money.go");
+
+    let l001 = money
+        .iter()
+        .find(|v| v["rule"] == "L001")
+        .unwrap_or_else(|| panic!("annotated domain file should fire L001, got: {money:?}"));
+    assert_eq!(l001["kind"]["LayerBoundary"]["from_layer"], "Domain");
+    assert_eq!(l001["kind"]["LayerBoundary"]["to_layer"], "Infrastructure");
+}
+
+// ----------------------------------------------------------------------------
+// Files without an annotation keep path-based classification
+// ----------------------------------------------------------------------------
+#[test]
+fn unannotated_file_stays_unclassified() {
+    let violations = violations("layer-annotation");
+    let clock = in_file(&violations, "common/clock.go");
+    assert!(
+        clock.is_empty(),
+        "unclassified file should not fire layer rules, got: {clock:?}"
+    );
+}
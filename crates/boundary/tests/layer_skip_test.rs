@@ -0,0 +1,88 @@
+/// Acceptance tests for L009: dependencies that jump over a layer.
+///
+/// The check is opt-in via `detect_layer_skips`; the `layer-skip` fixture enables it
+/// and holds a domain package that imports the presentation layer directly.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn skip_violations(fixture_name: &str, extra_args: &[&str]) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .args(extra_args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"] == "L009")
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A domain -> presentation edge skips the application layer
+// ----------------------------------------------------------------------------
+#[test]
+fn domain_to_presentation_reports_skipped_application() {
+    let violations = skip_violations("layer-skip", &[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one L009 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    let kind = &v["kind"]["LayerSkip"];
+    assert_eq!(kind["from"], "Domain");
+    assert_eq!(kind["to"], "Presentation");
+    assert_eq!(kind["skipped"], serde_json::json!(["Application"]));
+    assert!(
+        v["message"]
+            .as_str()
+            .unwrap()
+            .contains("skipping application"),
+        "message: {}",
+        v["message"]
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Adjacent layers are not skips
+// ----------------------------------------------------------------------------
+#[test]
+fn adjacent_layer_edges_are_not_flagged() {
+    let violations = skip_violations("layer-skip", &[]);
+    assert!(
+        violations
+            .iter()
+            .all(|v| v["kind"]["LayerSkip"]["from"] == "Domain"),
+        "only the domain edge should be a skip, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Layer skips are disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn layer_skips_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = skip_violations("layer-skip", &["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "layer skips should be opt-in, got: {violations:?}"
+    );
+}
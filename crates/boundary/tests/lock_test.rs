@@ -0,0 +1,192 @@
+/// Acceptance tests for `boundary lock` and `check --lock`.
+///
+/// The `adapter-coupling` fixture is copied to a temp dir, locked, then changed so
+/// `check --lock` can compare it against the approved architecture.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+/// Copy a fixture to a fresh temp directory so tests can add files and a lock
+/// without touching the checked-in fixture tree.
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(format!(
+        "{}/tests/fixtures/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    ));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+fn write_lock(dir: &Path) {
+    let output = boundary_cmd()
+        .arg("lock")
+        .arg(dir)
+        .arg("--write")
+        .output()
+        .expect("failed to run boundary lock");
+    assert!(
+        output.status.success(),
+        "lock --write failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn locked_check(dir: &Path) -> (bool, Vec<serde_json::Value>) {
+    let output = boundary_cmd()
+        .arg("check")
+        .arg(dir)
+        .args(["--format", "json", "--lock"])
+        .arg(dir.join("boundary.lock"))
+        .output()
+        .expect("failed to run boundary check");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    let drift = parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"] == "D005")
+        .cloned()
+        .collect();
+    (output.status.success(), drift)
+}
+
+// ----------------------------------------------------------------------------
+// lock --write records the current components and cross-layer edges
+// ----------------------------------------------------------------------------
+#[test]
+fn lock_write_creates_lock_file() {
+    let dir = copy_fixture_to_tempdir("adapter-coupling");
+    write_lock(dir.path());
+
+    let lock = std::fs::read_to_string(dir.path().join("boundary.lock")).unwrap();
+    assert!(lock.contains("version = 1"), "got:\n{lock}");
+    assert!(lock.contains("PostgresRepo"), "got:\n{lock}");
+    assert!(lock.contains("[[edges]]"), "got:\n{lock}");
+}
+
+// ----------------------------------------------------------------------------
+// An unchanged project passes the locked check
+// ----------------------------------------------------------------------------
+#[test]
+fn unchanged_project_passes_locked_check() {
+    let dir = copy_fixture_to_tempdir("adapter-coupling");
+    write_lock(dir.path());
+
+    let (passed, drift) = locked_check(dir.path());
+    assert!(drift.is_empty(), "got: {drift:?}");
+    assert!(passed);
+}
+
+// ----------------------------------------------------------------------------
+// A lock written from one directory holds when checking from another
+// ----------------------------------------------------------------------------
+#[test]
+fn lock_holds_across_working_directories() {
+    let dir = copy_fixture_to_tempdir("adapter-coupling");
+    let parent = dir.path().parent().unwrap();
+    let name = dir.path().file_name().unwrap().to_str().unwrap();
+
+    let output = boundary_cmd()
+        .current_dir(parent)
+        .args(["lock", name, "--write"])
+        .output()
+        .expect("failed to run boundary lock");
+    assert!(output.status.success());
+    let lock = std::fs::read_to_string(dir.path().join("boundary.lock")).unwrap();
+    assert!(
+        !lock.contains(name),
+        "lock IDs should be project-relative:\n{lock}"
+    );
+
+    let output = boundary_cmd()
+        .current_dir(dir.path())
+        .args(["check", ".", "--format", "json", "--lock", "boundary.lock"])
+        .output()
+        .expect("failed to run boundary check");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("D005"), "got:\n{stdout}");
+    assert!(output.status.success());
+}
+
+// ----------------------------------------------------------------------------
+// A cross-layer edge missing from the lock fails the locked check
+// ----------------------------------------------------------------------------
+#[test]
+fn new_edge_fails_locked_check() {
+    let dir = copy_fixture_to_tempdir("adapter-coupling");
+    write_lock(dir.path());
+
+    // The Redis adapter starts importing the domain: allowed by the layer rules,
+    // but not part of the approved architecture.
+    std::fs::write(
+        dir.path().join("internal/infrastructure/redis/carts.go"),
+        r#"package redis
+
+import "example.com/shop/internal/domain/cart"
+
+func CartKey(c *cart.Cart) string {
+	return "cart:" + c.ID
+}
+"#,
+    )
+    .unwrap();
+
+    let (passed, drift) = locked_check(dir.path());
+    assert!(!passed, "drift should fail the check");
+    assert_eq!(drift.len(), 1, "got: {drift:?}");
+    assert_eq!(drift[0]["severity"], "error");
+    let message = drift[0]["message"].as_str().unwrap();
+    assert!(
+        message.contains("infrastructure/redis") && message.contains("domain/cart"),
+        "got: {message}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Drift links follow doc_base_url like every other rule
+// ----------------------------------------------------------------------------
+#[test]
+fn drift_doc_url_follows_doc_base_url() {
+    let dir = copy_fixture_to_tempdir("adapter-coupling");
+    write_lock(dir.path());
+    std::fs::write(
+        dir.path().join(".boundary.toml"),
+        "[rules]\ndetect_adapter_coupling = true\n\
+         doc_base_url = \"https://wiki.example.com/architecture/rules\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("internal/infrastructure/redis/carts.go"),
+        r#"package redis
+
+import "example.com/shop/internal/domain/cart"
+
+func CartKey(c *cart.Cart) string {
+	return "cart:" + c.ID
+}
+"#,
+    )
+    .unwrap();
+
+    let (_, drift) = locked_check(dir.path());
+    assert_eq!(drift.len(), 1, "got: {drift:?}");
+    assert_eq!(
+        drift[0]["doc_url"],
+        "https://wiki.example.com/architecture/rules#d005"
+    );
+}
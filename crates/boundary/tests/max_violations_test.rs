@@ -0,0 +1,190 @@
+/// Acceptance tests for `--max-violations`.
+///
+/// Verifies that the reported violation list is capped at the N most severe
+/// entries, with a truncation indicator in text and JSON output.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+// ----------------------------------------------------------------------------
+// JSON: exactly N violations plus `truncated` and `total`
+// ----------------------------------------------------------------------------
+#[test]
+fn max_violations_truncates_json() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("domain-imports-infra"),
+            "--format",
+            "json",
+            "--max-violations",
+            "2",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    let violations = parsed["violations"].as_array().expect("violations array");
+    assert_eq!(violations.len(), 2, "got: {stdout}");
+    assert_eq!(parsed["truncated"], true);
+    let total = parsed["total"].as_u64().expect("total count");
+    assert!(total > 2, "total should count every violation, got {total}");
+}
+
+// ----------------------------------------------------------------------------
+// Text: exactly N violations plus an "… and M more" footer
+// ----------------------------------------------------------------------------
+#[test]
+fn max_violations_truncates_text() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("domain-imports-infra"),
+            "--compact",
+            "--max-violations",
+            "2",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let shown = stdout.lines().filter(|l| l.contains("Docs:")).count();
+    assert_eq!(shown, 2, "expected two violations, got: {stdout}");
+    assert!(stdout.contains("… and 2 more"), "missing footer: {stdout}");
+}
+
+// ----------------------------------------------------------------------------
+// No truncation indicator when the cap is not reached
+// ----------------------------------------------------------------------------
+#[test]
+fn max_violations_above_count_is_not_truncated() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("domain-imports-infra"),
+            "--format",
+            "json",
+            "--max-violations",
+            "100",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    assert!(parsed.get("truncated").is_none());
+    assert!(parsed.get("total").is_none());
+}
+
+// ----------------------------------------------------------------------------
+// check still fails on violations that were truncated out of the report
+// ----------------------------------------------------------------------------
+#[test]
+fn max_violations_does_not_change_check_outcome() {
+    let output = boundary_cmd()
+        .args([
+            "check",
+            &fixture("domain-imports-infra"),
+            "--max-violations",
+            "0",
+        ])
+        .output()
+        .expect("failed to run boundary check");
+
+    assert_eq!(output.status.code(), Some(1));
+}
+
+// ----------------------------------------------------------------------------
+// The reported pass/fail matches the exit code, truncated or not
+// ----------------------------------------------------------------------------
+#[test]
+fn truncated_check_reports_every_failing_violation() {
+    let run = |extra: &[&str]| {
+        let output = boundary_cmd()
+            .args([
+                "check",
+                &fixture("domain-imports-infra"),
+                "--format",
+                "json",
+            ])
+            .args(extra)
+            .output()
+            .expect("failed to run boundary check");
+        assert_eq!(output.status.code(), Some(1));
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        serde_json::from_str::<serde_json::Value>(&stdout).expect("output should be valid JSON")
+    };
+
+    let full = run(&["--max-violations", "0"]);
+    assert_eq!(full["check"]["passed"], false);
+    let summary = run(&["--max-violations", "0", "--summary-only"]);
+    assert_eq!(summary["passed"], false);
+    assert_eq!(
+        full["check"]["failing_violation_count"],
+        summary["failing_count"]
+    );
+    assert_eq!(full["check"]["failing_violation_count"], 3);
+}
+
+// ----------------------------------------------------------------------------
+// --max-violations also caps the report printed for a --no-regression failure
+// ----------------------------------------------------------------------------
+#[test]
+fn max_violations_truncates_regression_report() {
+    let src = std::path::PathBuf::from(fixture("domain-imports-infra"));
+    let dir = tempfile::tempdir().unwrap();
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.unwrap();
+        let dest = dir.path().join(entry.path().strip_prefix(&src).unwrap());
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    // A previous run at a perfect score with no violations.
+    let history = dir.path().join(".boundary/history.ndjson");
+    std::fs::create_dir_all(history.parent().unwrap()).unwrap();
+    std::fs::write(
+        history,
+        r#"{"timestamp":"2024-01-01T00:00:00Z","git_commit":null,"git_branch":null,"result":{"score":{"overall":100.0,"structural_presence":100.0,"layer_conformance":100.0,"dependency_compliance":100.0,"interface_coverage":100.0},"violations":[],"component_count":3,"dependency_count":0,"files_analyzed":3}}
+"#,
+    )
+    .unwrap();
+
+    let output = boundary_cmd()
+        .args([
+            "check",
+            dir.path().to_str().unwrap(),
+            "--no-regression",
+            "--format",
+            "json",
+            "--max-violations",
+            "2",
+        ])
+        .output()
+        .expect("failed to run boundary check");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("regression detected"), "got: {stderr}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    assert_eq!(parsed["violations"].as_array().unwrap().len(), 2);
+    assert_eq!(parsed["truncated"], true);
+    assert!(
+        stderr.contains("Violations: 0 -> 4"),
+        "regression should count every violation, got: {stderr}"
+    );
+}
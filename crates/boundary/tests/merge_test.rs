@@ -0,0 +1,99 @@
+/// Acceptance tests for `boundary merge`.
+///
+/// Saves JSON reports for two fixtures, then merges them into a single
+/// multi-service report.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn save_report(fixture_name: &str, out: &Path) -> serde_json::Value {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json", "-o"])
+        .arg(out)
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(
+        output.status.success(),
+        "analyze failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_str(&std::fs::read_to_string(out).unwrap()).expect("report should be JSON")
+}
+
+// ----------------------------------------------------------------------------
+// Two saved reports merge into one aggregate
+// ----------------------------------------------------------------------------
+#[test]
+fn merge_two_reports_into_aggregate() {
+    let dir = tempfile::tempdir().unwrap();
+    let go = dir.path().join("go.json");
+    let java = dir.path().join("java.json");
+    let go_report = save_report("sample-go-project", &go);
+    let java_report = save_report("sample-java-project", &java);
+
+    let output = boundary_cmd()
+        .args(["merge", "--format", "json"])
+        .arg(&go)
+        .arg(&java)
+        .output()
+        .expect("failed to run boundary merge");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let merged: serde_json::Value = serde_json::from_str(&stdout).expect("merged JSON");
+
+    let services = merged["services"].as_array().expect("services array");
+    let names: Vec<&str> = services
+        .iter()
+        .filter_map(|s| s["service_name"].as_str())
+        .collect();
+    assert_eq!(names, ["go", "java"]);
+
+    let components = |r: &serde_json::Value| r["component_count"].as_u64().unwrap();
+    assert_eq!(
+        components(&merged["aggregate"]),
+        components(&go_report) + components(&java_report)
+    );
+    let violations = |r: &serde_json::Value| r["violations"].as_array().unwrap().len();
+    assert_eq!(
+        violations(&merged["aggregate"]),
+        violations(&go_report) + violations(&java_report)
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Reports from another schema version are rejected
+// ----------------------------------------------------------------------------
+#[test]
+fn merge_rejects_schema_version_mismatch() {
+    let dir = tempfile::tempdir().unwrap();
+    let current = dir.path().join("current.json");
+    let mut report = save_report("sample-go-project", &current);
+
+    let future = dir.path().join("future.json");
+    report["schema_version"] = 99.into();
+    std::fs::write(&future, report.to_string()).unwrap();
+
+    let output = boundary_cmd()
+        .arg("merge")
+        .arg(&current)
+        .arg(&future)
+        .output()
+        .expect("failed to run boundary merge");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("future.json") && stderr.contains("schema version 99"),
+        "stderr: {stderr}"
+    );
+}
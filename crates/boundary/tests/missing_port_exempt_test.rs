@@ -0,0 +1,115 @@
+/// Acceptance tests for `[rules.missing_port] exempt_patterns`.
+///
+/// In the `missing-port-exempt` fixture, neither the `OrderPublisher` event adapter nor the
+/// `OrderRepository` has a port. A classification rule makes publishers adapters, so both
+/// trigger PA001 unless exempted.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+const PUBLISHERS_ARE_ADAPTERS: &str = r#"
+[[classification.rules]]
+name = "Publisher$"
+kind = "adapter"
+"#;
+
+fn analyze(config: &str) -> serde_json::Value {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("missing-port-exempt"),
+            "--config",
+            config,
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(&stdout).expect("output should be valid JSON")
+}
+
+/// Names of the adapters PA001 reports.
+fn missing_port_adapters(result: &serde_json::Value) -> Vec<String> {
+    let mut adapters: Vec<String> = result["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA001"))
+        .map(|v| {
+            v["kind"]["MissingPort"]["adapter_name"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    adapters.sort();
+    adapters
+}
+
+// ----------------------------------------------------------------------------
+// Without exemptions every adapter needs a port
+// ----------------------------------------------------------------------------
+#[test]
+fn adapters_without_ports_are_flagged() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, PUBLISHERS_ARE_ADAPTERS);
+
+    let result = analyze(&config);
+    assert_eq!(
+        missing_port_adapters(&result),
+        ["OrderPublisher", "OrderRepository"]
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Name globs exempt matching adapters only
+// ----------------------------------------------------------------------------
+#[test]
+fn name_pattern_exempts_publisher_but_not_repository() {
+    let dir = tempfile::tempdir().unwrap();
+    let baseline = analyze(&config_with(&dir, PUBLISHERS_ARE_ADAPTERS));
+    let config = config_with(
+        &dir,
+        &format!(
+            "{PUBLISHERS_ARE_ADAPTERS}\n[rules.missing_port]\nexempt_patterns = [\"*Publisher\"]\n"
+        ),
+    );
+
+    let result = analyze(&config);
+    assert_eq!(missing_port_adapters(&result), ["OrderRepository"]);
+    assert_eq!(
+        result["score"], baseline["score"],
+        "exemptions should not change the score"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Path globs work too
+// ----------------------------------------------------------------------------
+#[test]
+fn path_pattern_exempts_adapters_in_matching_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(
+        &dir,
+        &format!(
+            "{PUBLISHERS_ARE_ADAPTERS}\n[rules.missing_port]\nexempt_patterns = [\"**/events/**\"]\n"
+        ),
+    );
+
+    let result = analyze(&config);
+    assert_eq!(missing_port_adapters(&result), ["OrderRepository"]);
+}
@@ -0,0 +1,74 @@
+/// Acceptance tests for L008 (mixed-architecture-modes).
+///
+/// The rule is opt-in via `detect_mixed_architecture_modes`; the `mixed-modes`
+/// fixture enables it. Its billing context runs infrastructure in Active Record
+/// mode and domain in DDD mode, while shipping is Active Record throughout.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn mixed_mode_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L008"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A context with components in two modes fires once
+// ----------------------------------------------------------------------------
+#[test]
+fn context_with_two_modes_fires() {
+    let violations = mixed_mode_violations("mixed-modes");
+    let [v] = violations.as_slice() else {
+        panic!("expected one L008 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "info");
+    let kind = &v["kind"]["MixedArchitectureModes"];
+    assert!(
+        kind["context"]
+            .as_str()
+            .unwrap()
+            .ends_with("services/billing"),
+        "{v}"
+    );
+    assert_eq!(kind["modes"], serde_json::json!(["ddd", "active-record"]));
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("billing/infrastructure/invoice_record.go"),
+        "{v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn mixed_modes_disabled_by_default() {
+    let violations = mixed_mode_violations("sample-go-project");
+    assert!(
+        violations.is_empty(),
+        "L008 should be opt-in, got: {violations:?}"
+    );
+}
@@ -149,3 +149,60 @@ fn text_table_shows_em_dash_for_service_with_suppressed_score() {
         "service with suppressed score must not show '0.0'; got: {minimal_line}"
     );
 }
+
+/// With `service_marker = "go.mod"`, every directory holding a `go.mod` is a service,
+/// whatever its depth, and is named by its path from the project root.
+#[test]
+fn service_marker_discovers_services_at_any_depth() {
+    let result = analyze_per_service_json("service-markers");
+    let names: Vec<&str> = result["services"]
+        .as_array()
+        .expect("output should have a 'services' array")
+        .iter()
+        .filter_map(|s| s["service_name"].as_str())
+        .collect();
+    assert_eq!(names, ["auth", "platform/billing/api"]);
+}
+
+/// The JSON `shared_module_graph` lists, per shared module, each importing service
+/// with its import sites.
+#[test]
+fn shared_module_graph_lists_importing_services_and_sites() {
+    let result = analyze_per_service_json("fr24-monorepo");
+    let graph = result["shared_module_graph"]
+        .as_array()
+        .expect("output should have a 'shared_module_graph' array");
+    let logger = graph
+        .iter()
+        .find(|m| m["path"] == "github.com/example/fr24/shared/logger")
+        .unwrap_or_else(|| panic!("shared logger missing from graph: {graph:?}"));
+
+    let importers: Vec<(&str, Vec<(String, u64)>)> = logger["importers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| {
+            let sites = i["locations"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|l| {
+                    let file = l["file"].as_str().unwrap();
+                    let tail = file.split("services/").last().unwrap().to_string();
+                    (tail, l["line"].as_u64().unwrap())
+                })
+                .collect();
+            (i["service"].as_str().unwrap(), sites)
+        })
+        .collect();
+    assert_eq!(
+        importers,
+        [
+            ("auth", vec![("auth/infrastructure/repo.go".to_string(), 5)]),
+            (
+                "order",
+                vec![("order/infrastructure/store.go".to_string(), 5)]
+            ),
+        ]
+    );
+}
@@ -0,0 +1,77 @@
+/// Acceptance tests for L007 (pass-through-service).
+///
+/// The rule is opt-in via `detect_pass_through_services`; the `pass-through-service`
+/// fixture enables it in its `.boundary.toml`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn pass_through_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L007"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A service that only forwards to one repository fires L007
+// ----------------------------------------------------------------------------
+#[test]
+fn service_forwarding_to_single_repository_fires() {
+    let violations = pass_through_violations("pass-through-service");
+    let v = violations
+        .iter()
+        .find(|v| v["kind"]["PassThroughService"]["service_name"] == "OrderService")
+        .expect("OrderService should have an L007 violation");
+
+    assert_eq!(v["severity"], "info");
+    assert_eq!(
+        v["kind"]["PassThroughService"]["repository_name"],
+        "OrderRepository"
+    );
+    assert_eq!(v["kind"]["PassThroughService"]["method_count"], 2);
+}
+
+// ----------------------------------------------------------------------------
+// A service coordinating several ports is not flagged
+// ----------------------------------------------------------------------------
+#[test]
+fn service_with_several_dependencies_does_not_fire() {
+    let violations = pass_through_violations("pass-through-service");
+    assert!(
+        !violations
+            .iter()
+            .any(|v| v["kind"]["PassThroughService"]["service_name"] == "CheckoutService"),
+        "CheckoutService depends on orders and payments, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn pass_through_service_disabled_by_default() {
+    let violations = pass_through_violations("full-ddd-module");
+    assert!(
+        violations.is_empty(),
+        "L007 should be opt-in, got: {violations:?}"
+    );
+}
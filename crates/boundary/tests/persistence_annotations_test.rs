@@ -0,0 +1,83 @@
+/// Acceptance tests for the L005 persistence-annotation check.
+///
+/// The check is opt-in via `detect_persistence_annotations`; the `persistence-annotations`
+/// fixture enables it. The domain `OrderService.place` is annotated `@Transactional`,
+/// while the application-layer `PlaceOrderUseCase.execute` carries the same annotation
+/// legitimately.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn annotation_violations(extra: &[&str]) -> Vec<serde_json::Value> {
+    let path = fixture("persistence-annotations");
+    let mut args = vec!["analyze", path.as_str(), "--format", "json"];
+    args.extend_from_slice(extra);
+    let output = boundary_cmd()
+        .args(&args)
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| {
+            v["rule"].as_str() == Some("L005")
+                && v["message"]
+                    .as_str()
+                    .is_some_and(|m| m.contains("persistence annotation"))
+        })
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// @Transactional on a domain service fires; on a use case it does not
+// ----------------------------------------------------------------------------
+#[test]
+fn transactional_domain_method_is_flagged() {
+    let violations = annotation_violations(&[]);
+    let [v] = violations.as_slice() else {
+        panic!("expected one persistence annotation violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert_eq!(
+        v["kind"]["DomainInfrastructureLeak"]["detail"],
+        "domain method OrderService.place is annotated @Transactional"
+    );
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("OrderService.java"),
+        "location: {}",
+        v["location"]
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The check is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn persistence_annotations_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("empty.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = annotation_violations(&["--config", config.to_str().unwrap()]);
+    assert!(
+        violations.is_empty(),
+        "persistence annotation check should be opt-in, got: {violations:?}"
+    );
+}
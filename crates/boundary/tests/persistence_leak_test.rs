@@ -0,0 +1,57 @@
+/// Acceptance tests for L005 outside the domain layer.
+///
+/// The `persistence-leak` fixture has an application service and an
+/// infrastructure store that both import `database/sql`.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn leak_violations(fixture_name: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture(fixture_name), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L005"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// Application importing database/sql fires, infrastructure does not
+// ----------------------------------------------------------------------------
+#[test]
+fn application_importing_database_sql_fires() {
+    let violations = leak_violations("persistence-leak");
+    let [v] = violations.as_slice() else {
+        panic!("expected one L005 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "error");
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("application/billing/service.go"),
+        "{v}"
+    );
+    let detail = v["kind"]["DomainInfrastructureLeak"]["detail"]
+        .as_str()
+        .unwrap();
+    assert!(detail.starts_with("application "), "detail: {detail}");
+    assert!(detail.contains("database/sql"), "detail: {detail}");
+}
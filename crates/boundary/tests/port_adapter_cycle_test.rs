@@ -0,0 +1,95 @@
+/// Acceptance tests for PA009 (port-adapter-cycle).
+///
+/// The rule is opt-in via `detect_port_adapter_cycles`; the `port-adapter-cycle`
+/// fixture enables it. `domain/user` imports `infrastructure/postgres`, whose
+/// `PostgresUserRepository` implements the `UserRepository` port, while
+/// `domain/order` never imports the package of its in-memory adapter.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn port_adapter_cycle_violations(config: Option<&std::path::Path>) -> Vec<serde_json::Value> {
+    let mut cmd = boundary_cmd();
+    cmd.args([
+        "analyze",
+        &fixture("port-adapter-cycle"),
+        "--format",
+        "json",
+    ]);
+    if let Some(config) = config {
+        cmd.arg("--config").arg(config);
+    }
+    let output = cmd.output().expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA009"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A port package importing its adapter's package fires
+// ----------------------------------------------------------------------------
+#[test]
+fn port_package_importing_its_adapter_fires() {
+    let violations = port_adapter_cycle_violations(None);
+    assert_eq!(violations.len(), 1, "got: {violations:?}");
+
+    let v = &violations[0];
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["kind"]["PortAdapterCycle"]["port"], "UserRepository");
+    assert_eq!(
+        v["kind"]["PortAdapterCycle"]["adapter"],
+        "PostgresUserRepository"
+    );
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("domain/user/user.go"),
+        "got: {v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// A port whose package does not import its adapter passes
+// ----------------------------------------------------------------------------
+#[test]
+fn one_way_port_and_adapter_pass() {
+    let violations = port_adapter_cycle_violations(None);
+    assert!(
+        violations
+            .iter()
+            .all(|v| v["kind"]["PortAdapterCycle"]["port"] != "OrderRepository"),
+        "got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// The rule is disabled unless configured
+// ----------------------------------------------------------------------------
+#[test]
+fn port_adapter_cycle_disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, "").unwrap();
+
+    let violations = port_adapter_cycle_violations(Some(&config));
+    assert!(
+        violations.is_empty(),
+        "PA009 should be opt-in: {violations:?}"
+    );
+}
@@ -256,3 +256,79 @@ fn progress_track_and_no_regression_appends_snapshot() {
         "snapshot history should contain 2 entries after --track appends: {history}"
     );
 }
+
+// ----------------------------------------------------------------------------
+// Scenario: --show-classification-changes reports a component that changed layer
+// Given a snapshot recorded with "boundary check . --track"
+// And the domain directory is then classified as application
+// When I run "boundary check . --show-classification-changes"
+// Then stderr lists the domain components as moved from domain to application
+// ----------------------------------------------------------------------------
+#[test]
+fn progress_classification_changes_report_layer_change() {
+    let tmpdir = copy_fixture_to_tempdir("full-ddd-module");
+    let path = tmpdir.path().to_str().unwrap();
+
+    let track = boundary_cmd()
+        .args(["check", path, "--track"])
+        .output()
+        .expect("failed to run boundary check --track");
+    assert!(track.status.success(), "check --track should exit 0");
+
+    std::fs::write(
+        tmpdir.path().join(".boundary.toml"),
+        "[layers]\napplication = [\"**/domain/**\", \"**/application/**\"]\ndomain = [\"**/core/**\"]\n",
+    )
+    .unwrap();
+
+    let output = boundary_cmd()
+        .args(["check", path, "--show-classification-changes"])
+        .output()
+        .expect("failed to run boundary check --show-classification-changes");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Classification changes since the last snapshot"),
+        "should report changes: {stderr}"
+    );
+    let moved: Vec<&str> = stderr
+        .lines()
+        .filter(|l| l.trim_start().starts_with('~'))
+        .collect();
+    assert!(
+        !moved.is_empty(),
+        "should list changed components: {stderr}"
+    );
+    for line in &moved {
+        assert!(
+            line.contains(":domain::")
+                && line.contains("domain")
+                && line.contains("-> application"),
+            "only domain components should move to application: {line}"
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Scenario: --show-classification-changes without a snapshot explains what to do
+// ----------------------------------------------------------------------------
+#[test]
+fn progress_classification_changes_without_history() {
+    let tmpdir = copy_fixture_to_tempdir("full-ddd-module");
+
+    let output = boundary_cmd()
+        .args([
+            "check",
+            tmpdir.path().to_str().unwrap(),
+            "--show-classification-changes",
+        ])
+        .output()
+        .expect("failed to run boundary check --show-classification-changes");
+
+    assert!(output.status.success(), "the report is informational only");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("boundary check --track"),
+        "should suggest recording a snapshot: {stderr}"
+    );
+}
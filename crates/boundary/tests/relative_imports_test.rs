@@ -0,0 +1,110 @@
+/// Acceptance tests for relative imports (`./x`, `../../domain/user`).
+///
+/// Relative specifiers are resolved against the importing file before they are
+/// classified, so they land in the layer of the directory they name, including
+/// layers defined only by `[layers]` globs.
+use std::path::Path;
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn write(root: &Path, rel: &str, contents: &str) {
+    let path = root.join(rel);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, contents).unwrap();
+}
+
+/// A TypeScript project: `<domain>/user.ts` holds an entity and its port,
+/// `<infra>/db/user-repo.ts` implements the port, and `<domain>/audit.ts` reaches
+/// into infrastructure.
+fn ts_project(domain: &str, infra: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    write(root, "package.json", "{\"name\": \"shop\"}\n");
+    write(
+        root,
+        &format!("src/{domain}/user.ts"),
+        "export class User {\n  constructor(public id: string) {}\n}\n\n\
+         export interface UserRepository {\n  save(user: User): Promise<void>;\n}\n",
+    );
+    write(
+        root,
+        &format!("src/{infra}/db/user-repo.ts"),
+        &format!(
+            "import {{ User, UserRepository }} from \"../../{domain}/user\";\n\n\
+             export class PgUserRepository implements UserRepository {{\n  \
+             async save(user: User): Promise<void> {{}}\n}}\n"
+        ),
+    );
+    write(
+        root,
+        &format!("src/{domain}/audit.ts"),
+        &format!(
+            "import {{ PgUserRepository }} from \"../{infra}/db/user-repo\";\n\n\
+             export class Audit {{\n  constructor(private repo: PgUserRepository) {{}}\n}}\n"
+        ),
+    );
+    dir
+}
+
+fn analyze(root: &Path) -> serde_json::Value {
+    let output = boundary_cmd()
+        .args(["analyze", &root.to_string_lossy(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    serde_json::from_str(&stdout).expect("output should be valid JSON")
+}
+
+fn l001_messages(parsed: &serde_json::Value) -> Vec<String> {
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"] == "L001")
+        .map(|v| v["message"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// `../../domain/user` from infrastructure is an internal domain dependency
+// ----------------------------------------------------------------------------
+#[test]
+fn parent_relative_import_resolves_to_domain() {
+    let dir = ts_project("domain", "infrastructure");
+    let parsed = analyze(dir.path());
+
+    let matrix = &parsed["metrics"]["layer_coupling"]["matrix"];
+    assert_eq!(
+        matrix["infrastructure"]["domain"], 1,
+        "the repository's import should be a counted infrastructure -> domain edge: {matrix}"
+    );
+    assert_eq!(matrix["domain"]["infrastructure"], 1, "{matrix}");
+    assert_eq!(
+        l001_messages(&parsed),
+        ["domain layer depends on infrastructure layer (import: ../infrastructure/db/user-repo)"]
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Relative imports match layers defined only by globs
+// ----------------------------------------------------------------------------
+#[test]
+fn relative_import_matches_custom_layer_globs() {
+    let dir = ts_project("core", "adapters");
+    write(
+        dir.path(),
+        ".boundary.toml",
+        "[layers]\ndomain = [\"src/core/**\"]\ninfrastructure = [\"src/adapters/**\"]\n",
+    );
+    let parsed = analyze(dir.path());
+
+    let matrix = &parsed["metrics"]["layer_coupling"]["matrix"];
+    assert_eq!(matrix["infrastructure"]["domain"], 1, "{matrix}");
+    assert_eq!(
+        l001_messages(&parsed),
+        ["domain layer depends on infrastructure layer (import: ../adapters/db/user-repo)"]
+    );
+}
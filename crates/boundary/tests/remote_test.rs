@@ -0,0 +1,166 @@
+/// Acceptance tests for analyzing a git URL (`remote` feature).
+///
+/// Local tests clone a throwaway repository over `file://`; the network test is
+/// ignored by default (`cargo test --features remote -- --ignored`).
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+#[cfg(feature = "remote")]
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// A git repository holding `name`'s files on its default branch and a `release` branch.
+#[cfg(feature = "remote")]
+fn fixture_repo(name: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.unwrap();
+        let dest = dir.path().join(entry.path().strip_prefix(&src).unwrap());
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir.path())
+            .status()
+            .expect("failed to run git");
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "-A"]);
+    git(&["commit", "--quiet", "-m", "init"]);
+    git(&["branch", "release"]);
+    dir
+}
+
+#[cfg(feature = "remote")]
+fn file_url(dir: &tempfile::TempDir) -> String {
+    format!("file://{}", dir.path().display())
+}
+
+// ----------------------------------------------------------------------------
+// Without the feature, a URL fails with a clear message
+// ----------------------------------------------------------------------------
+#[cfg(not(feature = "remote"))]
+#[test]
+fn url_requires_remote_feature() {
+    let output = boundary_cmd()
+        .args(["analyze", "https://github.com/org/repo"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("`remote` feature"),
+        "should explain the missing feature, got: {stderr}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// --ref only makes sense with a URL
+// ----------------------------------------------------------------------------
+#[test]
+fn ref_without_url_is_rejected() {
+    let output = boundary_cmd()
+        .args(["analyze", ".", "--ref", "main"])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--ref requires PATH to be a git URL"),
+        "got: {stderr}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Cloned repositories are analyzed with repo-relative paths
+// ----------------------------------------------------------------------------
+#[cfg(feature = "remote")]
+#[test]
+fn analyzes_cloned_repository_with_relative_paths() {
+    let repo = fixture_repo("sample-go-project");
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &file_url(&repo),
+            "--ref",
+            "release",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "analyze failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    let violations = parsed["violations"].as_array().unwrap();
+    assert!(!violations.is_empty(), "sample project has violations");
+    for v in violations {
+        let file = v["location"]["file"].as_str().unwrap();
+        assert!(
+            file.starts_with("./") && !file.contains("boundary-remote-"),
+            "paths should be relative to the repository, got: {file}"
+        );
+    }
+}
+
+#[cfg(feature = "remote")]
+#[test]
+fn clone_failure_is_reported() {
+    let repo = fixture_repo("sample-go-project");
+    let output = boundary_cmd()
+        .args(["check", &file_url(&repo), "--ref", "no-such-branch"])
+        .output()
+        .expect("failed to run boundary check");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("failed to clone"), "got: {stderr}");
+    assert!(stderr.contains("no-such-branch"), "got: {stderr}");
+}
+
+// ----------------------------------------------------------------------------
+// A public repository over the network
+// ----------------------------------------------------------------------------
+#[cfg(feature = "remote")]
+#[test]
+#[ignore = "clones from GitHub"]
+fn analyzes_public_github_repository() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            "https://github.com/golang/example",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    assert!(
+        output.status.success(),
+        "analyze failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("output should be valid JSON");
+    assert!(parsed["score"]["overall"].is_number(), "got: {parsed}");
+}
@@ -1,7 +1,7 @@
 /// Acceptance tests for Phase 2: config-based rule configuration.
 ///
 /// Verifies severity overrides via rule IDs, path-specific ignores,
-/// and rule ID precedence over category names.
+/// rule ID precedence over category names, and path-scoped severities.
 use std::process::Command;
 
 fn boundary_cmd() -> Command {
@@ -159,3 +159,88 @@ fn non_ignored_violations_remain() {
         "L001 violations should not be suppressed (only L005 is ignored)"
     );
 }
+
+// ----------------------------------------------------------------------------
+// Path-scoped severities: L001 under legacy/** is a warning, elsewhere an error
+// ----------------------------------------------------------------------------
+#[test]
+fn scoped_override_downgrades_layer_boundary_under_legacy() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("rule-scope-overrides"),
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    let l001: Vec<_> = parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L001"))
+        .collect();
+    assert_eq!(l001.len(), 2, "expected one L001 per tree, got: {l001:?}");
+
+    for v in &l001 {
+        let file = v["location"]["file"].as_str().unwrap();
+        let expected = if file.contains("/legacy/") {
+            "warning"
+        } else {
+            "error"
+        };
+        assert_eq!(v["severity"], expected, "unexpected severity for {file}");
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Override scopes are anchored at the project root
+// ----------------------------------------------------------------------------
+#[test]
+fn scoped_override_ignores_nested_directory_of_same_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let write = |rel: &str, content: &str| {
+        let path = dir.path().join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    };
+    write(
+        ".boundary.toml",
+        "[[rules.overrides]]\nscope = \"legacy/**\"\n\n\
+         [rules.overrides.severities]\nlayer_boundary = \"warning\"\n",
+    );
+    write(
+        "internal/infrastructure/postgres/repo.go",
+        "package postgres\n\ntype UserRepository struct{}\n",
+    );
+    write(
+        "lib/legacy/domain/order/order.go",
+        "package order\n\nimport (\n\t_ \"github.com/example/app/internal/infrastructure/postgres\"\n)\n\n\
+         type Order struct {\n\tID string\n}\n",
+    );
+
+    let output = boundary_cmd()
+        .args(["analyze", dir.path().to_str().unwrap(), "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    let l001: Vec<_> = parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L001"))
+        .collect();
+    assert_eq!(l001.len(), 1, "got: {l001:?}");
+    assert_eq!(
+        l001[0]["severity"], "error",
+        "lib/legacy is not the root's legacy/: {l001:?}"
+    );
+}
@@ -0,0 +1,63 @@
+/// Acceptance tests for `--format sarif`: SARIF 2.1.0 for GitHub code scanning.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn sarif(command: &str, fixture_name: &str) -> (serde_json::Value, bool) {
+    let output = boundary_cmd()
+        .args([command, &fixture(fixture_name), "--format", "sarif"])
+        .output()
+        .expect("failed to run boundary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed = serde_json::from_str(&stdout)
+        .unwrap_or_else(|e| panic!("output should be valid JSON ({e}): {stdout}"));
+    (parsed, output.status.success())
+}
+
+// ----------------------------------------------------------------------------
+// Each violation is a result pointing at a registered rule
+// ----------------------------------------------------------------------------
+#[test]
+fn analyze_sarif_results_reference_rules() {
+    let (sarif, success) = sarif("analyze", "sample-go-project");
+    assert!(success);
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "boundary");
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let results = run["results"].as_array().unwrap();
+    assert!(!results.is_empty());
+    for result in results {
+        let index = result["ruleIndex"].as_u64().unwrap() as usize;
+        assert_eq!(rules[index]["id"], result["ruleId"], "{result}");
+        assert!(["error", "warning", "note"].contains(&result["level"].as_str().unwrap()));
+    }
+
+    let l001 = results
+        .iter()
+        .find(|r| r["ruleId"] == "L001")
+        .expect("fixture has an L001 violation");
+    let location = &l001["locations"][0]["physicalLocation"];
+    assert!(location["artifactLocation"]["uri"]
+        .as_str()
+        .unwrap()
+        .ends_with("internal/domain/user/bad_dependency.go"));
+    assert_eq!(location["region"]["startLine"], 4);
+}
+
+// ----------------------------------------------------------------------------
+// check keeps its exit code
+// ----------------------------------------------------------------------------
+#[test]
+fn check_sarif_fails_on_errors() {
+    let (sarif, success) = sarif("check", "sample-go-project");
+    assert!(!success);
+    assert!(!sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,69 @@
+/// Acceptance tests for `[project] threads` and `max_in_flight_files`: streaming parsed
+/// files into the graph merge must produce the same result as the batch path.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Analyze `fixture_name` as JSON once per config. The configs share a directory, and with
+/// it the project root, so any difference in the reports comes from the merge path.
+fn analyze_json(fixture_name: &str, configs: &[&str]) -> Vec<String> {
+    let dir = tempfile::tempdir().unwrap();
+    configs
+        .iter()
+        .enumerate()
+        .map(|(i, config)| {
+            let config_path = dir.path().join(format!("boundary-{i}.toml"));
+            std::fs::write(&config_path, config).unwrap();
+
+            let output = boundary_cmd()
+                .args([
+                    "analyze",
+                    &fixture(fixture_name),
+                    "--format",
+                    "json",
+                    "--config",
+                ])
+                .arg(&config_path)
+                .output()
+                .expect("failed to run boundary analyze");
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert!(output.status.success(), "stderr: {stderr}");
+            String::from_utf8_lossy(&output.stdout).into_owned()
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Streaming vs batch
+// ---------------------------------------------------------------------------
+
+/// With one file in flight and several threads, results arrive out of order; the
+/// report must still match the batch path byte for byte.
+#[test]
+fn streaming_merge_matches_batch() {
+    let streaming = "[project]\nthreads = 3\nmax_in_flight_files = 1\n";
+    for name in [
+        "sample-go-project",
+        "component-instability",
+        "full-ddd-module",
+    ] {
+        let reports = analyze_json(name, &["", streaming]);
+        assert_eq!(
+            reports[0], reports[1],
+            "streaming output differs for {name}"
+        );
+    }
+}
+
+/// A single thread is accepted and gives the same report.
+#[test]
+fn single_thread_matches_default() {
+    let reports = analyze_json("sample-go-project", &["", "[project]\nthreads = 1\n"]);
+    assert_eq!(reports[0], reports[1]);
+}
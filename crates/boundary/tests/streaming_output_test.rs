@@ -0,0 +1,68 @@
+/// Acceptance tests for `analyze --output`.
+///
+/// JSON written to a file is streamed; it must match what `analyze` prints
+/// to stdout byte for byte.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn buffered_and_streamed(extra_args: &[&str]) -> (Vec<u8>, Vec<u8>) {
+    let path = fixture("domain-imports-infra");
+
+    let buffered = boundary_cmd()
+        .args(["analyze", &path, "--format", "json"])
+        .args(extra_args)
+        .output()
+        .expect("failed to run boundary analyze");
+    assert!(buffered.status.success());
+
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let out_file = dir.path().join("report.json");
+    let streamed = boundary_cmd()
+        .args(["analyze", &path, "--format", "json"])
+        .args(extra_args)
+        .arg("--output")
+        .arg(&out_file)
+        .output()
+        .expect("failed to run boundary analyze --output");
+    assert!(streamed.status.success());
+    assert!(
+        streamed.stdout.is_empty(),
+        "nothing should be printed when --output is set"
+    );
+
+    let written = std::fs::read(&out_file).expect("output file should exist");
+    (buffered.stdout, written)
+}
+
+// ----------------------------------------------------------------------------
+// Streamed pretty JSON matches stdout byte for byte
+// ----------------------------------------------------------------------------
+#[test]
+fn streamed_json_matches_buffered() {
+    let (buffered, streamed) = buffered_and_streamed(&[]);
+    let _: serde_json::Value =
+        serde_json::from_slice(&streamed).expect("streamed output should be valid JSON");
+    assert_eq!(
+        String::from_utf8_lossy(&streamed),
+        String::from_utf8_lossy(&buffered)
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Streamed compact JSON matches stdout byte for byte
+// ----------------------------------------------------------------------------
+#[test]
+fn streamed_compact_json_matches_buffered() {
+    let (buffered, streamed) = buffered_and_streamed(&["--compact"]);
+    assert_eq!(
+        String::from_utf8_lossy(&streamed),
+        String::from_utf8_lossy(&buffered)
+    );
+}
@@ -0,0 +1,90 @@
+/// Acceptance tests for `[project].style` rule presets.
+///
+/// The `missing-port-fix` fixture has adapters without matching ports, so PA001 fires
+/// under the default rules. Presets decide whether that rule runs at all.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+fn missing_port_violations(config: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("missing-port-fix"),
+            "--config",
+            config,
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("PA001"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// mvc turns off the missing-port rule, hexagonal keeps it on
+// ----------------------------------------------------------------------------
+#[test]
+fn mvc_style_disables_missing_port_rule() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "[project]\nstyle = \"mvc\"\n");
+
+    let violations = missing_port_violations(&config);
+    assert!(
+        violations.is_empty(),
+        "mvc should not flag missing ports, got: {violations:?}"
+    );
+}
+
+#[test]
+fn hexagonal_style_enables_missing_port_rule() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "[project]\nstyle = \"hexagonal\"\n");
+
+    let violations = missing_port_violations(&config);
+    assert!(
+        !violations.is_empty(),
+        "hexagonal should flag adapters without ports"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Explicit [rules] settings win over the preset
+// ----------------------------------------------------------------------------
+#[test]
+fn explicit_rule_overrides_style() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(
+        &dir,
+        "[project]\nstyle = \"mvc\"\n\n[rules]\ndetect_missing_ports = true\n",
+    );
+
+    let violations = missing_port_violations(&config);
+    assert!(
+        !violations.is_empty(),
+        "detect_missing_ports = true should override the mvc preset"
+    );
+}
@@ -0,0 +1,111 @@
+/// Acceptance tests for L011: test doubles leaking into production code.
+///
+/// In the `test-double-leak` fixture, the application `CheckoutService` imports the
+/// `mocks` package and wires a `MockPaymentGateway` in place of a real gateway.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn config_with(dir: &tempfile::TempDir, contents: &str) -> String {
+    let config = dir.path().join("boundary.toml");
+    std::fs::write(&config, contents).unwrap();
+    config.to_string_lossy().into_owned()
+}
+
+fn test_double_violations(config: &str) -> Vec<serde_json::Value> {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("test-double-leak"),
+            "--config",
+            config,
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("failed to run boundary analyze");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+
+    parsed["violations"]
+        .as_array()
+        .expect("should have violations array")
+        .iter()
+        .filter(|v| v["rule"].as_str() == Some("L011"))
+        .cloned()
+        .collect()
+}
+
+// ----------------------------------------------------------------------------
+// A production service importing a mock is flagged
+// ----------------------------------------------------------------------------
+#[test]
+fn production_import_of_mock_is_flagged() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "[rules]\ndetect_test_doubles = true\n");
+
+    let violations = test_double_violations(&config);
+    let [v] = violations.as_slice() else {
+        panic!("expected one L011 violation, got: {violations:?}");
+    };
+
+    assert_eq!(v["severity"], "warning");
+    assert_eq!(v["rule_name"], "test-double-in-production");
+    let kind = &v["kind"]["TestDoubleInProduction"];
+    assert_eq!(kind["test_double"], "MockPaymentGateway");
+    assert!(
+        kind["component"]
+            .as_str()
+            .unwrap()
+            .ends_with("application/checkout"),
+        "should name the importing package, got: {kind}"
+    );
+    assert!(
+        v["location"]["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("checkout/service.go"),
+        "should point at the import, got: {v}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Patterns are configurable
+// ----------------------------------------------------------------------------
+#[test]
+fn custom_patterns_replace_defaults() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(
+        &dir,
+        "[rules]\ndetect_test_doubles = true\ntest_double_patterns = [\"InMemory*\"]\n",
+    );
+
+    let violations = test_double_violations(&config);
+    assert!(
+        violations.is_empty(),
+        "MockPaymentGateway does not match InMemory*, got: {violations:?}"
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Off by default
+// ----------------------------------------------------------------------------
+#[test]
+fn disabled_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = config_with(&dir, "");
+
+    let violations = test_double_violations(&config);
+    assert!(
+        violations.is_empty(),
+        "L011 should be opt-in, got: {violations:?}"
+    );
+}
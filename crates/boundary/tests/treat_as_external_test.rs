@@ -0,0 +1,104 @@
+/// Acceptance tests for `[project].treat_as_external`.
+///
+/// The `treat-as-external` fixture marks `**/generated/**` as external. Its generated
+/// payments client is unclassified, so if it were scored it would drag structural
+/// presence and dependency compliance down.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn copy_fixture_to_tempdir(name: &str) -> tempfile::TempDir {
+    let tmpdir = tempfile::tempdir().expect("failed to create temp dir");
+    let src = std::path::PathBuf::from(fixture(name));
+    for entry in walkdir::WalkDir::new(&src) {
+        let entry = entry.expect("failed to read dir entry");
+        let rel = entry.path().strip_prefix(&src).unwrap();
+        let dest = tmpdir.path().join(rel);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&dest).unwrap();
+        } else {
+            std::fs::copy(entry.path(), &dest).unwrap();
+        }
+    }
+    tmpdir
+}
+
+fn analyze_score(path: &str) -> serde_json::Value {
+    let output = boundary_cmd()
+        .args(["analyze", path, "--format", "json"])
+        .output()
+        .expect("failed to run boundary analyze");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("output should be valid JSON");
+    parsed["score"].clone()
+}
+
+// ----------------------------------------------------------------------------
+// Components under a treat_as_external glob are marked external
+// ----------------------------------------------------------------------------
+#[test]
+fn generated_components_are_marked_external() {
+    let output = boundary_cmd()
+        .args([
+            "diagram",
+            &fixture("treat-as-external"),
+            "--diagram-type",
+            "cytoscape",
+        ])
+        .output()
+        .expect("failed to run boundary diagram");
+    assert!(output.status.success());
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("cytoscape output should be valid JSON");
+    let nodes = parsed["elements"]["nodes"].as_array().unwrap();
+
+    let node = |name: &str| {
+        nodes
+            .iter()
+            .find(|n| n["data"]["id"].as_str().unwrap().ends_with(name))
+            .unwrap_or_else(|| panic!("no node {name}: {parsed}"))
+    };
+    assert_eq!(
+        node("/generated/paymentsapi::Client")["data"]["external"],
+        true
+    );
+    assert_eq!(
+        node("/application::CheckoutService")["data"]["external"],
+        false
+    );
+}
+
+// ----------------------------------------------------------------------------
+// Generated code does not affect the score
+// ----------------------------------------------------------------------------
+#[test]
+fn generated_components_do_not_affect_score() {
+    let with_generated = analyze_score(&fixture("treat-as-external"));
+
+    let dir = copy_fixture_to_tempdir("treat-as-external");
+    std::fs::remove_dir_all(dir.path().join("internal/generated")).unwrap();
+    let without_generated = analyze_score(dir.path().to_str().unwrap());
+
+    assert_eq!(with_generated["structural_presence"], 100.0);
+    assert_eq!(with_generated, without_generated);
+}
+
+// ----------------------------------------------------------------------------
+// Without the setting, generated code is scored like any other code
+// ----------------------------------------------------------------------------
+#[test]
+fn generated_components_scored_without_setting() {
+    let dir = copy_fixture_to_tempdir("treat-as-external");
+    std::fs::remove_file(dir.path().join(".boundary.toml")).unwrap();
+    let score = analyze_score(dir.path().to_str().unwrap());
+
+    let structural_presence = score["structural_presence"].as_f64().unwrap();
+    assert!(structural_presence < 100.0, "got: {score}");
+}
@@ -0,0 +1,226 @@
+/// Acceptance tests for `--webhook`: POST the JSON result to an HTTP endpoint.
+///
+/// Delivery needs the `http` feature. Run `cargo test --features http --test webhook_test`
+/// to include the tests against a local mock server.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+// ---------------------------------------------------------------------------
+// Option validation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn invalid_webhook_header_is_rejected() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("sample-go-project"),
+            "--webhook",
+            "http://127.0.0.1:9/hook",
+            "--webhook-header",
+            "no-colon",
+        ])
+        .output()
+        .expect("failed to run boundary");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("invalid --webhook-header"),
+        "stderr: {stderr}"
+    );
+}
+
+#[cfg(not(feature = "http"))]
+#[test]
+fn webhook_requires_http_feature() {
+    let output = boundary_cmd()
+        .args([
+            "analyze",
+            &fixture("sample-go-project"),
+            "--webhook",
+            "http://127.0.0.1:9/hook",
+        ])
+        .output()
+        .expect("failed to run boundary");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("`http` feature"), "stderr: {stderr}");
+}
+
+// ---------------------------------------------------------------------------
+// Delivery (http feature)
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "http")]
+mod delivery {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    /// A request received by [`mock_server`].
+    struct Received {
+        request_line: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl Received {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    /// Accept one request on a free local port, answer 200 and hand it back.
+    fn mock_server() -> (String, mpsc::Receiver<Received>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                let (name, value) = line.split_once(':').unwrap();
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+            let length: usize = headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+                .map_or(0, |(_, v)| v.parse().unwrap());
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                .unwrap();
+            tx.send(Received {
+                request_line: request_line.trim_end().to_string(),
+                headers,
+                body: String::from_utf8(body).unwrap(),
+            })
+            .unwrap();
+        });
+        (url, rx)
+    }
+
+    /// A local URL nothing listens on.
+    fn closed_url() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        drop(listener);
+        url
+    }
+
+    #[test]
+    fn analyze_posts_json_result_with_header() {
+        let (url, received) = mock_server();
+        let output = boundary_cmd()
+            .args([
+                "analyze",
+                &fixture("sample-go-project"),
+                "--webhook",
+                &url,
+                "--webhook-header",
+                "Authorization: Bearer secret",
+            ])
+            .output()
+            .expect("failed to run boundary");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "stderr: {stderr}");
+
+        let request = received
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .expect("mock server should receive the result");
+        assert_eq!(request.request_line, "POST /hook HTTP/1.1");
+        assert_eq!(request.header("authorization"), Some("Bearer secret"));
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        let body: serde_json::Value =
+            serde_json::from_str(&request.body).expect("body should be JSON");
+        assert!(body["violations"].is_array(), "body: {body}");
+        assert!(body["component_count"].is_number(), "body: {body}");
+    }
+
+    #[test]
+    fn check_posts_check_result() {
+        let (url, received) = mock_server();
+        let output = boundary_cmd()
+            .args([
+                "check",
+                &fixture("sample-go-project"),
+                "--fail-on",
+                "error",
+                "--webhook",
+                &url,
+            ])
+            .output()
+            .expect("failed to run boundary");
+        let request = received
+            .recv_timeout(std::time::Duration::from_secs(10))
+            .expect("mock server should receive the result");
+        let body: serde_json::Value =
+            serde_json::from_str(&request.body).expect("body should be JSON");
+        assert_eq!(
+            body["check"]["passed"].as_bool(),
+            Some(output.status.success()),
+            "body: {body}"
+        );
+    }
+
+    #[test]
+    fn unreachable_webhook_warns_but_succeeds() {
+        let output = boundary_cmd()
+            .args([
+                "analyze",
+                &fixture("sample-go-project"),
+                "--webhook",
+                &closed_url(),
+            ])
+            .output()
+            .expect("failed to run boundary");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "stderr: {stderr}");
+        assert!(
+            stderr.contains("Warning: failed to deliver results to webhook"),
+            "stderr: {stderr}"
+        );
+    }
+
+    #[test]
+    fn unreachable_required_webhook_fails() {
+        let output = boundary_cmd()
+            .args([
+                "analyze",
+                &fixture("sample-go-project"),
+                "--webhook",
+                &closed_url(),
+                "--webhook-required",
+            ])
+            .output()
+            .expect("failed to run boundary");
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("failed to deliver results to webhook"),
+            "stderr: {stderr}"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+/// Acceptance tests for `[[zones]]`: named parts of a repository scored with their own
+/// layers and scoring, then aggregated like services.
+///
+/// Run `cargo test --test zones_test` to check the current state.
+use std::process::Command;
+
+fn boundary_cmd() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_boundary"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn analyze_per_zone_json(fixture_name: &str) -> serde_json::Value {
+    let path = fixture(fixture_name);
+    let output = boundary_cmd()
+        .args(["analyze", &path, "--per-zone", "--format", "json"])
+        .output()
+        .unwrap_or_else(|e| panic!("failed to run boundary on {fixture_name}: {e}"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        output.status.success(),
+        "boundary failed on {fixture_name}: stderr={stderr}"
+    );
+    serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("invalid JSON from {fixture_name}: {e}\noutput: {stdout}"))
+}
+
+fn zone<'a>(result: &'a serde_json::Value, name: &str) -> &'a serde_json::Value {
+    result["services"]
+        .as_array()
+        .expect("output should have a 'services' array")
+        .iter()
+        .find(|z| z["service_name"] == name)
+        .unwrap_or_else(|| panic!("zone '{name}' missing from output: {result}"))
+}
+
+// ---------------------------------------------------------------------------
+// Per-zone scoring
+// ---------------------------------------------------------------------------
+
+/// Zones are reported in config order, each with its own score.
+#[test]
+fn each_zone_is_reported_with_a_score() {
+    let result = analyze_per_zone_json("zones");
+    let names: Vec<&str> = result["services"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|z| z["service_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["orders", "admin"]);
+    for name in names {
+        assert!(
+            zone(&result, name)["result"]["score"]["overall"].is_number(),
+            "zone '{name}' should be scored"
+        );
+    }
+}
+
+/// Both zones persist a model from domain through infrastructure. The DDD zone flags it;
+/// the admin zone's own active-record layers allow it.
+#[test]
+fn zones_use_their_own_layer_config() {
+    let result = analyze_per_zone_json("zones");
+    let orders = zone(&result, "orders")["result"]["violations"]
+        .as_array()
+        .unwrap();
+    assert!(
+        orders.iter().any(|v| v["severity"] == "error"),
+        "orders zone should flag domain importing infrastructure; got: {orders:?}"
+    );
+    let admin = zone(&result, "admin")["result"]["violations"]
+        .as_array()
+        .unwrap();
+    assert!(
+        admin.is_empty(),
+        "admin zone is active-record and should have no violations; got: {admin:?}"
+    );
+}
+
+/// The admin zone weighs interface coverage at zero, so the same sub-scores give it a
+/// different overall score from the orders zone.
+#[test]
+fn zones_use_their_own_scoring_weights() {
+    let result = analyze_per_zone_json("zones");
+    let orders = &zone(&result, "orders")["result"]["score"];
+    let admin = &zone(&result, "admin")["result"]["score"];
+    assert_eq!(orders["layer_conformance"], admin["layer_conformance"]);
+    assert_eq!(orders["interface_coverage"], admin["interface_coverage"]);
+
+    let overall = |score: &serde_json::Value| score["overall"].as_f64().unwrap();
+    assert!(
+        (overall(orders) - overall(admin)).abs() > 1.0,
+        "zone weights should change the overall score: orders={}, admin={}",
+        overall(orders),
+        overall(admin)
+    );
+}
+
+/// The aggregate is the component-weighted average of the zone scores and carries every
+/// zone's violations.
+#[test]
+fn aggregate_combines_zone_scores() {
+    let result = analyze_per_zone_json("zones");
+    let zones = result["services"].as_array().unwrap();
+    let total: f64 = zones
+        .iter()
+        .map(|z| z["result"]["component_count"].as_f64().unwrap())
+        .sum();
+    let expected: f64 = zones
+        .iter()
+        .map(|z| {
+            z["result"]["score"]["overall"].as_f64().unwrap()
+                * z["result"]["component_count"].as_f64().unwrap()
+                / total
+        })
+        .sum();
+    let aggregate = result["aggregate"]["score"]["overall"].as_f64().unwrap();
+    assert!(
+        (aggregate - expected).abs() < 0.01,
+        "aggregate {aggregate} should be the weighted zone average {expected}"
+    );
+
+    let zone_violations: usize = zones
+        .iter()
+        .map(|z| z["result"]["violations"].as_array().unwrap().len())
+        .sum();
+    assert_eq!(
+        result["aggregate"]["violations"].as_array().unwrap().len(),
+        zone_violations
+    );
+}
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// `--per-zone` without any `[[zones]]` fails with a hint.
+#[test]
+fn per_zone_without_zones_fails() {
+    let output = boundary_cmd()
+        .args(["analyze", &fixture("fr24-monorepo"), "--per-zone"])
+        .output()
+        .expect("failed to run boundary");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no zones configured"),
+        "expected a missing-zones error; got: {stderr}"
+    );
+}
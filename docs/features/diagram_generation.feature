@@ -34,3 +34,10 @@ Feature: Diagram Generation (FR-13)
     And the output does not contain synthetic "<file>" nodes
     Note: File-level import edges (file->package) involve only synthetic nodes and are
           filtered out; named component-to-component violation edges are shown when present
+
+  Scenario: External dependencies are drawn only when requested
+    Given a Go project that imports the third-party package "github.com/gin-gonic/gin"
+    When I run "boundary diagram . --diagram-type layers"
+    Then the output does not contain "github.com/gin-gonic/gin"
+    When I run "boundary diagram . --diagram-type layers --include-external"
+    Then the output contains an "External" subgraph with a "github.com/gin-gonic/gin" node